@@ -1,26 +1,33 @@
 mod inner;
+mod snapshot;
 #[cfg(test)]
 mod tests;
 
-use crate::Activity;
+use crate::{Activity, ActivityChain, ActivitySessions, ActivityTemplate, Entity, Group};
 
 use super::computation::{
-    activities_into_computation_data::index_to_id_map, id_computation::generate_next_id,
-    separate_thread_activity_computation::SeparateThreadActivityComputation,
+    activities_into_computation_data::index_to_id_map,
+    id_computation::generate_next_id,
+    separate_thread_activity_computation::{
+        invalidate_activities, SeparateThreadActivityComputation,
+    },
 };
 
 use felix_datatypes::{
-    ActivityBeginningMinutes, ActivityId, InsertionCost, Rgba, Time,
+    ActivityBeginningMinutes, ActivityId, BreakRules, Details, Duration, DurationObjective,
+    EntityId, GroupId, InsertionCost, Rgba, TimeInterval, TimeOfDay, TimePreferences,
     WorkHoursAndActivityDurationsSorted,
 };
 
-use felix_errors::Result;
+use felix_errors::{does_not_exist::DoesNotExist, name_taken::NameTaken, Result};
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{btree_map::Entry, BTreeMap, BTreeSet, HashMap};
 use std::sync::{Arc, Mutex};
 
-pub(crate) type ActivitiesAndOldInsertionBeginnings = HashMap<ActivityId, Time>;
+pub use snapshot::ActivitiesSnapshot;
+
+pub(crate) type ActivitiesAndOldInsertionBeginnings = HashMap<ActivityId, TimeOfDay>;
 
 /// Manages the collection of activities.
 /// Makes sures there are no id duplicates.
@@ -30,6 +37,12 @@ pub struct Activities {
     // Writing in the activities to update the insertion costs asynchronously
     // Reading the activities collection to do this computation asynchronously
     activities: Arc<Mutex<Vec<Activity>>>,
+    /// Color given to the activities of a category when they join it.
+    #[serde(default)]
+    category_colors: BTreeMap<String, Rgba>,
+    /// Templates from which activities with the same metadata are created.
+    #[serde(default)]
+    templates: BTreeMap<String, ActivityTemplate>,
     #[serde(skip)]
     separate_thread_computation: SeparateThreadActivityComputation,
     #[serde(skip)]
@@ -50,6 +63,8 @@ impl Activities {
 
         Activities {
             activities,
+            category_colors: BTreeMap::new(),
+            templates: BTreeMap::new(),
             separate_thread_computation,
             activities_removed_because_duration_increased: ActivitiesAndOldInsertionBeginnings::new(
             ),
@@ -92,7 +107,7 @@ impl Activities {
     }
 
     /// Getter for activities which were removed from the schedule because their duration
-    /// increased.
+    /// increased or because they no longer fit in the work hours.
     pub fn get_activities_removed_because_duration_increased(
         &self,
     ) -> ActivitiesAndOldInsertionBeginnings {
@@ -158,24 +173,24 @@ impl Activities {
     /// # Panics
     ///
     /// Panics if the activity with given ID does not exist.
-    pub fn add_entity(&mut self, id: ActivityId, entity: String) -> Result<()> {
+    pub fn add_entity(&mut self, id: ActivityId, entity: &Entity) -> Result<()> {
         self.mutate_activity(id, |a| a.metadata.add_entity(entity))?;
         self.update_incompatible_activities();
         Ok(())
     }
 
-    /// Adds an entity in every activity which contains the given group.
-    pub fn add_entity_to_activities_with_group(&mut self, group_name: &str, entity_name: String) {
+    /// Adds an entity in every activity which contains the group with given id.
+    pub fn add_entity_to_activities_with_group(&mut self, group_id: GroupId, entity: &Entity) {
         for activity in self
             .activities
             .lock()
             .unwrap()
             .iter_mut()
-            .filter(|activity| activity.groups_sorted().contains(&group_name.into()))
+            .filter(|activity| activity.metadata.group_ids().contains(&group_id))
         {
             // We do not care about errors : we want the activity to contain the entity, if it
             // is already the case, it is fine
-            let _ = activity.metadata.add_entity(entity_name.clone());
+            let _ = activity.metadata.add_entity(entity);
         }
         self.update_incompatible_activities();
     }
@@ -189,10 +204,10 @@ impl Activities {
     /// # Panics
     ///
     /// Panics if the activity with given ID does not exist.
-    pub fn remove_entity(&mut self, id: ActivityId, entity: &str) -> Result<()> {
+    pub fn remove_entity(&mut self, id: ActivityId, entity: &Entity) -> Result<()> {
         self.mutate_activity(id, |a| -> Result<()> {
             a.metadata.remove_entity(entity)?;
-            if a.metadata.entities_sorted().is_empty() {
+            if a.metadata.entity_ids().is_empty() {
                 // TODO comment out this line and make tests fail
                 *a.computation_data.insertion_costs().lock().unwrap() = Some(Vec::new());
             }
@@ -203,8 +218,8 @@ impl Activities {
     }
 
     // TODO remove this ! All operations should pass by the data collection.
-    /// Removes the entity with given name from all activities.
-    pub fn remove_entity_from_all(&mut self, entity: &str) {
+    /// Removes the given entity from all activities.
+    pub fn remove_entity_from_all(&mut self, entity: &Entity) {
         let ids = self
             .activities
             .lock()
@@ -216,30 +231,66 @@ impl Activities {
         for id in ids {
             // We don't care about the result : if the entity is not
             // taking part in the activity, that is what we want in the first place
-            let _ = self.remove_entity(id, &entity);
+            let _ = self.remove_entity(id, entity);
+            let _ = self.remove_optional_entity(id, entity);
         }
     }
 
-    /// Renames the entity with given name in all activities.
-    pub fn rename_entity_in_all(&mut self, old_name: &str, new_name: String) {
-        for activity in self.activities.lock().unwrap().iter_mut() {
-            // We don't care about the result : if the entity is not
-            // taking part in the activity, it does not need to be renamed
-            let _ = activity.metadata.rename_entity(old_name, new_name.clone());
-        }
+    /// Adds an optional participant to the activity with the given id.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is already taking part in the activity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub fn add_optional_entity(&mut self, id: ActivityId, entity: &Entity) -> Result<()> {
+        self.mutate_activity(id, |a| a.metadata.add_optional_entity(entity))
+    }
+
+    /// Removes an optional participant from the activity with the given id.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not an optional participant of the activity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub fn remove_optional_entity(&mut self, id: ActivityId, entity: &Entity) -> Result<()> {
+        self.mutate_activity(id, |a| a.metadata.remove_optional_entity(entity))
+    }
+
+    /// Sets the minimum and maximum number of participants of the activity with the given id.
+    /// Checks are done by the Data module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub fn set_participant_limits(
+        &mut self,
+        id: ActivityId,
+        min_participants: Option<usize>,
+        max_participants: Option<usize>,
+    ) {
+        self.mutate_activity(id, |a| {
+            a.metadata
+                .set_participant_limits(min_participants, max_participants)
+        });
     }
 
-    /// Adds the group with the given name to the activity with given id.
+    /// Adds the given group to the activity with given id.
     ///
     /// # Errors
     ///
     /// Returns Err if the activity is not found or if
     /// the group is already taking part in the activity.
-    pub fn add_group(&mut self, id: ActivityId, group_name: String) -> Result<()> {
-        self.mutate_activity(id, |a| a.metadata.add_group(group_name))
+    pub fn add_group(&mut self, id: ActivityId, group: &Group) -> Result<()> {
+        self.mutate_activity(id, |a| a.metadata.add_group(group))
     }
 
-    /// Removes the group with the given name from the activity with given id.
+    /// Removes the given group from the activity with given id.
     ///
     /// # Errors
     ///
@@ -248,36 +299,63 @@ impl Activities {
     /// # Panics
     ///
     /// Panics if the activity with given ID does not exist.
-    pub fn remove_group(&mut self, id: ActivityId, group_name: &str) -> Result<()> {
-        self.mutate_activity(id, |a| a.metadata.remove_group(group_name))?;
+    pub fn remove_group(&mut self, id: ActivityId, group: &Group) -> Result<()> {
+        self.mutate_activity(id, |a| a.metadata.remove_group(group))?;
         self.update_incompatible_activities();
         Ok(())
     }
 
-    /// Renames the group with given name in all activities.
-    pub fn rename_group_in_all(&mut self, old_name: &str, new_name: String) {
-        for activity in self.activities.lock().unwrap().iter_mut() {
-            // We don't care about the result : if the entity is not
-            // taking part in the activity, it does not need to be renamed
-            let _ = activity.metadata.rename_group(old_name, new_name.clone());
-        }
-    }
-
     /// Sets the duration of the activity with the given id.
     ///
     /// # Panics
     ///
     /// Panics if the activity with given ID does not exist.
-    pub fn set_duration(&mut self, id: ActivityId, duration: Time) {
+    pub fn set_duration(&mut self, id: ActivityId, duration: Duration) {
         self.mutate_activity(id, |a| {
             a.computation_data.set_duration(duration);
             // Empty duration => Set insertion costs to computed but empty
-            if duration == Time::new(0, 0) {
+            if duration == Duration::default() {
                 *a.computation_data.insertion_costs().lock().unwrap() = Some(Vec::new());
             }
         });
     }
 
+    /// Sets the maximum duration of the activity with the given id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub fn set_max_duration(&mut self, id: ActivityId, max_duration: Option<Duration>) {
+        self.mutate_activity(id, |a| a.computation_data.set_max_duration(max_duration));
+    }
+
+    /// Sets the duration objective of the activity with the given id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub fn set_duration_objective(
+        &mut self,
+        id: ActivityId,
+        duration_objective: DurationObjective,
+    ) {
+        self.mutate_activity(id, |a| {
+            a.computation_data
+                .set_duration_objective(duration_objective)
+        });
+    }
+
+    /// Sets the transition buffer of the activity with the given id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub fn set_transition_buffer(&mut self, id: ActivityId, transition_buffer: Duration) {
+        self.mutate_activity(id, |a| {
+            a.computation_data.set_transition_buffer(transition_buffer)
+        });
+    }
+
     /// Sets the color of the activity with the given id.
     ///
     /// # Panics
@@ -287,6 +365,215 @@ impl Activities {
         self.mutate_activity(id, |a| a.metadata.set_color(color));
     }
 
+    /// Sets the category of the activity with the given id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub fn set_category(&mut self, id: ActivityId, category: Option<String>) {
+        self.mutate_activity(id, |a| a.metadata.set_category(category));
+    }
+
+    /// Returns the categories used by activities or given a color, sorted by name.
+    #[must_use]
+    pub fn categories(&self) -> Vec<String> {
+        self.activities
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(Activity::category)
+            .chain(self.category_colors.keys().cloned())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Returns the color of the given category, if one was set.
+    #[must_use]
+    pub fn category_color(&self, category: &str) -> Option<Rgba> {
+        self.category_colors.get(category).copied()
+    }
+
+    /// Sets the color of the given category. None removes the color.
+    pub fn set_category_color(&mut self, category: String, color: Option<Rgba>) {
+        if let Some(color) = color {
+            self.category_colors.insert(category, color);
+        } else {
+            self.category_colors.remove(&category);
+        }
+    }
+
+    /// Returns the names of the activity templates, sorted.
+    #[must_use]
+    pub fn template_names(&self) -> Vec<String> {
+        self.templates.keys().cloned().collect()
+    }
+
+    /// Returns a copy of the activity template with the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the template does not exist.
+    pub fn template(&self, name: &str) -> Result<ActivityTemplate> {
+        match self.templates.get(name) {
+            None => Err(DoesNotExist::activity_template_does_not_exist(name)),
+            Some(template) => Ok(template.clone()),
+        }
+    }
+
+    /// Adds the given activity template with the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the name is already taken by another template.
+    pub fn add_template(&mut self, name: String, template: ActivityTemplate) -> Result<()> {
+        match self.templates.entry(name) {
+            Entry::Occupied(entry) => Err(NameTaken::name_taken_by_activity_template(
+                entry.key().clone(),
+            )),
+            Entry::Vacant(entry) => {
+                entry.insert(template);
+                Ok(())
+            }
+        }
+    }
+
+    /// Removes the activity template with the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the template does not exist.
+    pub fn remove_template(&mut self, name: &str) -> Result<()> {
+        if self.templates.remove(name).is_some() {
+            Ok(())
+        } else {
+            Err(DoesNotExist::activity_template_does_not_exist(name))
+        }
+    }
+
+    /// Removes the entity with given id from all activity templates.
+    pub fn remove_entity_from_templates(&mut self, entity_id: EntityId) {
+        for template in self.templates.values_mut() {
+            template.remove_entity(entity_id);
+        }
+    }
+
+    /// Removes the group with given id from all activity templates.
+    pub fn remove_group_from_templates(&mut self, group_id: GroupId) {
+        for template in self.templates.values_mut() {
+            template.remove_group(group_id);
+        }
+    }
+
+    /// Sets the details of the activity with the given id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub fn set_details(&mut self, id: ActivityId, details: Details) {
+        self.mutate_activity(id, |a| a.metadata.set_details(details));
+    }
+
+    /// Sets the description of the activity with the given id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub fn set_description(&mut self, id: ActivityId, description: String) {
+        self.mutate_activity(id, |a| a.metadata.set_description(description));
+    }
+
+    /// Sets the location of the activity with the given id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub fn set_location(&mut self, id: ActivityId, location: String) {
+        self.mutate_activity(id, |a| a.metadata.set_location(location));
+    }
+
+    /// Links the given sessions together. Every session knows the others.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the sessions does not exist.
+    pub fn link_sessions(&mut self, sessions: ActivitySessions) {
+        for &id in sessions.session_ids() {
+            let sessions = sessions.clone();
+            self.mutate_activity(id, |a| a.metadata.set_sessions(Some(sessions)));
+        }
+    }
+
+    /// Unlinks the sessions with given ids, which become independent activities again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the sessions does not exist.
+    pub fn unlink_sessions(&mut self, session_ids: &[ActivityId]) {
+        for &id in session_ids {
+            self.mutate_activity(id, |a| a.metadata.set_sessions(None));
+        }
+    }
+
+    /// Links the given activities so that they always begin at the same time.
+    /// Every activity knows the others.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the activities does not exist.
+    pub fn link_simultaneous(&mut self, ids: &[ActivityId]) {
+        for &id in ids {
+            let others = ids
+                .iter()
+                .copied()
+                .filter(|&other_id| other_id != id)
+                .collect();
+            self.mutate_activity(id, |a| a.metadata.set_simultaneous_activity_ids(others));
+        }
+    }
+
+    /// Unlinks the activity with given id from the activities which begin at the same time.
+    /// The other activities stay linked together.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the activities does not exist.
+    pub fn unlink_simultaneous(&mut self, id: ActivityId) {
+        for other_id in self.get_by_id(id).simultaneous_activity_ids() {
+            self.mutate_activity(other_id, |a| {
+                let mut others = a.metadata.simultaneous_activity_ids().clone();
+                others.remove(&id);
+                a.metadata.set_simultaneous_activity_ids(others);
+            });
+        }
+        self.mutate_activity(id, |a| {
+            a.metadata.set_simultaneous_activity_ids(BTreeSet::new())
+        });
+    }
+
+    /// Chains the given activities together. Every activity of the chain knows the others.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the activities does not exist.
+    pub fn link_chain(&mut self, chain: ActivityChain) {
+        for &id in chain.activity_ids() {
+            let chain = chain.clone();
+            self.mutate_activity(id, |a| a.metadata.set_chain(Some(chain)));
+        }
+    }
+
+    /// Unchains the activities with given ids, which become independent activities again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the activities does not exist.
+    pub fn unlink_chain(&mut self, ids: &[ActivityId]) {
+        for &id in ids {
+            self.mutate_activity(id, |a| a.metadata.set_chain(None));
+        }
+    }
+
     /// Triggers the computation of new possible beginnings for the given activities.
     pub fn trigger_update_possible_activity_beginnings(
         &mut self,
@@ -299,6 +586,11 @@ impl Activities {
             );
     }
 
+    /// Forgets the possible beginnings of the activities until they are computed again.
+    pub fn invalidate_possible_activity_beginnings(&mut self) {
+        invalidate_activities(self.activities.clone());
+    }
+
     /// Inserts the activity with the given beginning.
     /// If None is given, the activity is removed from the schedule.
     /// Checks are done by the Data module.
@@ -306,10 +598,10 @@ impl Activities {
     /// # Panics
     ///
     /// Panics if the activity with given ID is not found.
-    pub fn insert_activity(&mut self, id: ActivityId, beginning: Option<Time>) {
+    pub fn insert_activity(&mut self, id: ActivityId, beginning: Option<TimeOfDay>) {
         self.mutate_activity(id, |a| {
             a.computation_data.insert(beginning);
-            if a.metadata.entities_sorted().is_empty() {
+            if a.metadata.entity_ids().is_empty() {
                 // No participants => Set insertion costs to computed but empty
                 *a.computation_data.insertion_costs().lock().unwrap() = Some(Vec::new());
             }
@@ -333,8 +625,60 @@ impl Activities {
         });
     }
 
+    /// Updates the time preferences of the participants of the activity with given id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity does not exist.
+    pub fn update_preferences_of_participants_of_activity(
+        &mut self,
+        id: ActivityId,
+        preferences: Vec<TimePreferences>,
+    ) {
+        self.mutate_activity(id, |activity| {
+            activity
+                .computation_data
+                .update_preferences_of_participants(preferences)
+        });
+    }
+
+    /// Updates the break rules of the participants of the activity with given id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity does not exist.
+    pub fn update_break_rules_of_participants_of_activity(
+        &mut self,
+        id: ActivityId,
+        break_rules: HashMap<EntityId, BreakRules>,
+    ) {
+        self.mutate_activity(id, |activity| {
+            activity
+                .computation_data
+                .update_break_rules_of_participants(break_rules)
+        });
+    }
+
+    /// Updates the work hours of the optional participants of the activity with given id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity does not exist.
+    pub fn update_work_hours_of_optional_participants_of_activity(
+        &mut self,
+        id: ActivityId,
+        work_hours: HashMap<EntityId, Vec<TimeInterval>>,
+    ) {
+        self.mutate_activity(id, |activity| {
+            activity
+                .computation_data
+                .update_work_hours_of_optional_participants(work_hours)
+        });
+    }
+
     /// Keeps the insertion time of an activity which was removed due to an increase of its
-    /// duration. The activity will then be inserted in the closest spot if possible.
+    /// duration or to a change of work hours.
+    /// The activity will then be inserted in the closest spot if possible.
     ///
     /// # Panics
     ///
@@ -360,9 +704,9 @@ impl Activities {
     pub fn get_closest_spot_to_insert_activity(
         &mut self,
         id: ActivityId,
-        ideal_beginning: Time,
+        ideal_beginning: TimeOfDay,
         possible_beginnings: Vec<InsertionCost>,
-    ) -> Option<Time> {
+    ) -> Option<TimeOfDay> {
         // We remove this activity from the list of activities to insert back.
         self.activities_removed_because_duration_increased
             .remove(&id);
@@ -395,17 +739,24 @@ impl Activities {
         self.state_of_activities_before_autoinsertion_launched = self.get_not_sorted();
     }
 
-    /// Associates each computation data to its rightful activity then overwrites it.
-    pub fn overwrite_insertion_data(&mut self, insertion_data: Vec<ActivityBeginningMinutes>) {
+    /// Associates each beginning found by autoinsertion to its rightful activity.
+    #[must_use]
+    pub fn autoinsertion_beginnings(
+        &self,
+        insertion_data: Vec<ActivityBeginningMinutes>,
+    ) -> Vec<(ActivityId, TimeOfDay)> {
         let index_to_id_map =
             index_to_id_map(&self.state_of_activities_before_autoinsertion_launched);
-        for (index, insertion) in insertion_data.into_iter().enumerate() {
-            let id = index_to_id_map[&index];
-            self.mutate_activity(id, |a| {
-                a.computation_data
-                    .insert(Some(Time::from_total_minutes(insertion)))
-            });
-        }
+        insertion_data
+            .into_iter()
+            .enumerate()
+            .map(|(index, insertion)| {
+                (
+                    index_to_id_map[&index],
+                    TimeOfDay::from_total_minutes(insertion),
+                )
+            })
+            .collect()
     }
 }
 
@@ -414,6 +765,8 @@ impl Clone for Activities {
     fn clone(&self) -> Self {
         Activities {
             activities: self.activities.clone(),
+            category_colors: self.category_colors.clone(),
+            templates: self.templates.clone(),
             separate_thread_computation: SeparateThreadActivityComputation::default(),
             activities_removed_because_duration_increased:
                 ActivitiesAndOldInsertionBeginnings::default(),
@@ -427,6 +780,8 @@ impl PartialEq for Activities {
         // Clone to make sure that both are not locked at the same time if under the same mutex
         let activities = self.activities.lock().unwrap().clone();
         activities == *other.activities.lock().unwrap()
+            && self.category_colors == other.category_colors
+            && self.templates == other.templates
     }
 }
 
@@ -0,0 +1,51 @@
+//! Snapshots of the activities collection, used to restore a previous state.
+
+use super::Activities;
+use crate::{Activity, ActivityTemplate};
+
+use felix_datatypes::Rgba;
+
+use std::collections::BTreeMap;
+
+/// Copy of the activities, category colors and templates at a given time.
+///
+/// The activities of a snapshot do not share their insertion costs with the collection :
+/// the computation thread never writes into a snapshot.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ActivitiesSnapshot {
+    activities: Vec<Activity>,
+    category_colors: BTreeMap<String, Rgba>,
+    templates: BTreeMap<String, ActivityTemplate>,
+}
+
+impl Activities {
+    /// Returns a copy of the current state of the collection.
+    #[must_use]
+    pub fn snapshot(&self) -> ActivitiesSnapshot {
+        ActivitiesSnapshot {
+            activities: detached(self.get_not_sorted()),
+            category_colors: self.category_colors.clone(),
+            templates: self.templates.clone(),
+        }
+    }
+
+    /// Replaces the activities, category colors and templates with the ones of the snapshot.
+    ///
+    /// The activities which were waiting to be inserted back are forgotten.
+    /// The possible beginnings of the activities must be computed again.
+    pub fn restore(&mut self, snapshot: &ActivitiesSnapshot) {
+        *self.activities.lock().unwrap() = detached(snapshot.activities.clone());
+        self.category_colors = snapshot.category_colors.clone();
+        self.templates = snapshot.templates.clone();
+        self.activities_removed_because_duration_increased.clear();
+        self.update_incompatible_activities();
+    }
+}
+
+/// Gives each activity its own copy of its insertion costs.
+fn detached(mut activities: Vec<Activity>) -> Vec<Activity> {
+    for activity in &mut activities {
+        activity.computation_data.detach_insertion_costs();
+    }
+    activities
+}
@@ -1,6 +1,8 @@
 use super::super::super::Entities;
 use super::super::computation::activities_into_computation_data::activities_into_computation_data;
 use super::*;
+use crate::SessionRules;
+use felix_datatypes::TimeInterval;
 
 use std::collections::BTreeSet;
 
@@ -11,21 +13,17 @@ fn incompatible_ids() {
     let id_b = activity_collection.add("b".to_owned()).id();
 
     let mut entities = Entities::new();
-    let entity_a = "A".to_owned();
-    let entity_b = "B".to_owned();
-    entities
-        .add(entity_a.clone())
-        .expect("Could not add entity");
-    entities
-        .add(entity_b.clone())
-        .expect("Could not add entity");
+    let entity_a_id = entities.add("A".to_owned()).expect("Could not add entity");
+    let entity_a = entities.get_by_id(entity_a_id);
+    let entity_b_id = entities.add("B".to_owned()).expect("Could not add entity");
+    let entity_b = entities.get_by_id(entity_b_id);
 
     // Insert the same entity in both activities
     activity_collection
-        .add_entity(id_a, entity_a.clone())
+        .add_entity(id_a, &entity_a)
         .expect("Could not add entity to activity");
     activity_collection
-        .add_entity(id_b, entity_a.clone())
+        .add_entity(id_b, &entity_a)
         .expect("Could not add entity to activity");
 
     // At this point : id_a contains {a}, id_b contains {a}
@@ -61,7 +59,7 @@ fn incompatible_ids() {
 
     // Add non-confictual entity
     activity_collection
-        .add_entity(id_a, entity_b.clone())
+        .add_entity(id_a, &entity_b)
         .expect("Could not add entity to activity");
 
     // At this point : id_a contains {b}, id_b contains {a}
@@ -79,7 +77,7 @@ fn incompatible_ids() {
 
     // Add conflictual entity again
     activity_collection
-        .add_entity(id_b, entity_b)
+        .add_entity(id_b, &entity_b)
         .expect("Could not add entity to activity");
 
     // At this point : id_a contains {b}, id_b contains {a, b}
@@ -99,7 +97,7 @@ fn incompatible_ids() {
     // Add third activity
     let id_c = activity_collection.add("c".to_owned()).id();
     activity_collection
-        .add_entity(id_c, entity_a)
+        .add_entity(id_c, &entity_a)
         .expect("Could not add entity to activity");
 
     // At this point : id_a contains {b}, id_b contains {a, b}, id_c contains {a}
@@ -137,17 +135,23 @@ fn test_activities_into_computation_data() {
 
     // We will add one participant to each activity, if we don't, then the activity will be
     // filtered out
-    let participant = "Participant".to_string();
+    let mut entities = Entities::new();
+    let participant_id = entities
+        .add("Participant".to_owned())
+        .expect("Could not add entity");
+    let participant = entities.get_by_id(participant_id);
 
     // Ids are [0, 1, 3]
     activity_collection.mutate_activity(0, |activity1| {
         activity1
             .computation_data
             .set_incompatible_activity_ids(vec![3]);
-        activity1.computation_data.set_duration(Time::new(0, 30));
+        activity1
+            .computation_data
+            .set_duration(Duration::new(0, 30));
         activity1
             .metadata
-            .add_entity(participant.clone())
+            .add_entity(&participant)
             .expect("Could not add entity");
         *activity1.computation_data.insertion_costs().lock().unwrap() = Some(Vec::new());
     });
@@ -158,10 +162,12 @@ fn test_activities_into_computation_data() {
         activity2
             .computation_data
             .set_incompatible_activity_ids(vec![0, 3]);
-        activity2.computation_data.set_duration(Time::new(0, 20));
+        activity2
+            .computation_data
+            .set_duration(Duration::new(0, 20));
         activity2
             .metadata
-            .add_entity(participant.clone())
+            .add_entity(&participant)
             .expect("Could not add entity");
         *activity2.computation_data.insertion_costs().lock().unwrap() = Some(Vec::new());
     });
@@ -172,11 +178,15 @@ fn test_activities_into_computation_data() {
             .computation_data
             .set_incompatible_activity_ids(vec![1]);
 
-        activity3.computation_data.set_duration(Time::new(0, 15));
-        activity3.computation_data.insert(Some(Time::new(1, 0)));
+        activity3
+            .computation_data
+            .set_duration(Duration::new(0, 15));
+        activity3
+            .computation_data
+            .insert(Some(TimeOfDay::new(1, 0)));
         activity3
             .metadata
-            .add_entity(participant.clone())
+            .add_entity(&participant)
             .expect("Could not add entity");
         *activity3.computation_data.insertion_costs().lock().unwrap() = Some(Vec::new());
     });
@@ -264,6 +274,245 @@ fn test_activities_into_computation_data() {
     assert_eq!(insertion_data.len(), 1);
 }
 
+#[test]
+fn test_sessions_together_in_computation_data() {
+    let mut activity_collection = Activities::new();
+    activity_collection.add("0".to_owned());
+    activity_collection.add("1".to_owned());
+    activity_collection.add("2".to_owned());
+
+    let mut entities = Entities::new();
+    let participant_id = entities
+        .add("Participant".to_owned())
+        .expect("Could not add entity");
+    let participant = entities.get_by_id(participant_id);
+    // Activity 0 is the hardest to insert, then activity 1, then activity 2
+    for (id, duration, incompatible_ids) in &[
+        (0, Duration::new(0, 40), vec![1, 2]),
+        (1, Duration::new(0, 30), vec![0, 2]),
+        (2, Duration::new(0, 10), vec![0, 1]),
+    ] {
+        activity_collection.mutate_activity(*id, |activity| {
+            activity
+                .computation_data
+                .set_incompatible_activity_ids(incompatible_ids.clone());
+            activity.computation_data.set_duration(*duration);
+            activity
+                .metadata
+                .add_entity(&participant)
+                .expect("Could not add entity");
+            *activity.computation_data.insertion_costs().lock().unwrap() = Some(Vec::new());
+        });
+    }
+
+    // Activities 0 and 2 are sessions of the same activity
+    activity_collection.link_sessions(ActivitySessions::new(
+        vec![0, 2],
+        SessionRules {
+            consecutive: true,
+            ..Default::default()
+        },
+    ));
+
+    let (static_data, _) = activities_into_computation_data(&activity_collection.get_not_sorted());
+
+    // The sessions are placed together even though activity 1 is harder to insert than session 2
+    let index_to_id_translation = index_to_id_map(&activity_collection.get_not_sorted());
+    assert_eq!(index_to_id_translation[&0], 0);
+    assert_eq!(index_to_id_translation[&1], 2);
+    assert_eq!(index_to_id_translation[&2], 1);
+
+    assert_eq!(static_data[0].index_of_previous_session, None);
+    assert_eq!(static_data[0].index_of_next_session, Some(1));
+    assert_eq!(static_data[1].index_of_previous_session, Some(0));
+    assert_eq!(static_data[1].index_of_next_session, None);
+    assert_eq!(static_data[2].index_of_previous_session, None);
+    assert_eq!(static_data[2].index_of_next_session, None);
+}
+
+#[test]
+fn test_occurrences_spaced_in_computation_data() {
+    let mut activity_collection = Activities::new();
+    activity_collection.add("Snack".to_owned());
+    activity_collection.add("Snack".to_owned());
+
+    let mut entities = Entities::new();
+    let participant_id = entities
+        .add("Participant".to_owned())
+        .expect("Could not add entity");
+    let participant = entities.get_by_id(participant_id);
+    for id in 0..2 {
+        activity_collection.mutate_activity(id, |activity| {
+            activity.computation_data.set_duration(Duration::new(0, 30));
+            activity
+                .metadata
+                .add_entity(&participant)
+                .expect("Could not add entity");
+            *activity.computation_data.insertion_costs().lock().unwrap() = Some(Vec::new());
+        });
+    }
+    activity_collection.link_sessions(ActivitySessions::occurrences(
+        vec![0, 1],
+        Duration::new(2, 0),
+    ));
+
+    let (static_data, _) = activities_into_computation_data(&activity_collection.get_not_sorted());
+
+    assert_eq!(static_data[0].index_of_next_session, Some(1));
+    assert_eq!(static_data[1].index_of_previous_session, Some(0));
+    assert_eq!(static_data[0].min_spacing_between_sessions_minutes, 120);
+    assert_eq!(static_data[1].min_spacing_between_sessions_minutes, 120);
+}
+
+#[test]
+fn test_flexible_duration_in_computation_data() {
+    let mut activity_collection = Activities::new();
+    activity_collection.add("Lunch".to_owned());
+
+    let mut entities = Entities::new();
+    let participant_id = entities
+        .add("Participant".to_owned())
+        .expect("Could not add entity");
+    let participant = entities.get_by_id(participant_id);
+    activity_collection.mutate_activity(0, |activity| {
+        activity.computation_data.set_duration(Duration::new(0, 30));
+        activity
+            .computation_data
+            .set_max_duration(Some(Duration::new(1, 0)));
+        activity
+            .metadata
+            .add_entity(&participant)
+            .expect("Could not add entity");
+        *activity.computation_data.insertion_costs().lock().unwrap() = Some(vec![
+            InsertionCost::new(TimeOfDay::new(0, 0), 0, Duration::new(0, 30)),
+            InsertionCost::new(TimeOfDay::new(0, 10), 0, Duration::new(1, 0)),
+        ]);
+    });
+
+    let (static_data, _) = activities_into_computation_data(&activity_collection.get_not_sorted());
+    assert_eq!(static_data[0].duration_minutes, 30);
+    assert_eq!(static_data[0].duration_at(0), 30);
+    assert_eq!(static_data[0].duration_at(10), 60);
+
+    // The activity lasts as long as its insertion cost states
+    activity_collection.insert_activity(0, Some(TimeOfDay::new(0, 10)));
+    assert_eq!(
+        activity_collection.get_by_id(0).insertion_interval(),
+        Some(TimeInterval::new(
+            TimeOfDay::new(0, 10),
+            TimeOfDay::new(1, 10)
+        ))
+    );
+}
+
+#[test]
+fn test_simultaneous_activities_together_in_computation_data() {
+    let mut activity_collection = Activities::new();
+    activity_collection.add("0".to_owned());
+    activity_collection.add("1".to_owned());
+    activity_collection.add("2".to_owned());
+
+    // Activity 0 is the hardest to insert, then activity 1, then activity 2
+    let mut entities = Entities::new();
+    for (id, duration, participant) in &[
+        (0, Duration::new(0, 40), "Participant0"),
+        (1, Duration::new(0, 30), "Participant1"),
+        (2, Duration::new(0, 10), "Participant2"),
+    ] {
+        let participant_id = entities
+            .add(participant.to_string())
+            .expect("Could not add entity");
+        let participant = entities.get_by_id(participant_id);
+        activity_collection.mutate_activity(*id, |activity| {
+            activity.computation_data.set_duration(*duration);
+            activity
+                .metadata
+                .add_entity(&participant)
+                .expect("Could not add entity");
+            *activity.computation_data.insertion_costs().lock().unwrap() = Some(Vec::new());
+        });
+    }
+
+    // Activities 0 and 2 begin at the same time
+    activity_collection.link_simultaneous(&[0, 2]);
+
+    let (static_data, _) = activities_into_computation_data(&activity_collection.get_not_sorted());
+
+    // The simultaneous activities are placed together
+    let index_to_id_translation = index_to_id_map(&activity_collection.get_not_sorted());
+    assert_eq!(index_to_id_translation[&0], 0);
+    assert_eq!(index_to_id_translation[&1], 2);
+    assert_eq!(index_to_id_translation[&2], 1);
+
+    assert_eq!(static_data[0].indexes_of_simultaneous_activities, vec![1]);
+    assert_eq!(static_data[1].indexes_of_simultaneous_activities, vec![0]);
+    assert!(static_data[2].indexes_of_simultaneous_activities.is_empty());
+
+    activity_collection.unlink_simultaneous(2);
+    assert!(activity_collection
+        .get_by_id(0)
+        .simultaneous_activity_ids()
+        .is_empty());
+}
+
+#[test]
+fn test_chained_activities_as_block_in_computation_data() {
+    let mut activity_collection = Activities::new();
+    activity_collection.add("0".to_owned());
+    activity_collection.add("1".to_owned());
+    activity_collection.add("2".to_owned());
+
+    let mut entities = Entities::new();
+    let participant_id = entities
+        .add("Participant".to_owned())
+        .expect("Could not add entity");
+    let participant = entities.get_by_id(participant_id);
+    for (id, duration, incompatible_ids, beginnings) in &[
+        (0, Duration::new(0, 10), vec![1, 2], vec![0, 10, 20]),
+        (1, Duration::new(0, 20), vec![0, 2], vec![15, 25, 30]),
+        (2, Duration::new(0, 30), vec![0, 1], vec![0, 30]),
+    ] {
+        activity_collection.mutate_activity(*id, |activity| {
+            activity
+                .computation_data
+                .set_incompatible_activity_ids(incompatible_ids.clone());
+            activity.computation_data.set_duration(*duration);
+            activity
+                .metadata
+                .add_entity(&participant)
+                .expect("Could not add entity");
+            *activity.computation_data.insertion_costs().lock().unwrap() = Some(
+                beginnings
+                    .iter()
+                    .map(|&minutes| InsertionCost {
+                        beginning: TimeOfDay::from_total_minutes(minutes),
+                        cost: 0,
+                        duration: *duration,
+                    })
+                    .collect(),
+            );
+        });
+    }
+
+    // Activity 1 begins 5 minutes after the end of activity 0
+    activity_collection.link_chain(ActivityChain::new(vec![0, 1], vec![Duration::new(0, 5)]));
+
+    let (static_data, _) = activities_into_computation_data(&activity_collection.get_not_sorted());
+    let index_to_id_translation = index_to_id_map(&activity_collection.get_not_sorted());
+
+    // The chain is a single block of 35 minutes
+    assert_eq!(static_data.len(), 2);
+    assert_eq!(index_to_id_translation[&0], 0);
+    assert_eq!(index_to_id_translation[&1], 2);
+    assert_eq!(static_data[0].duration_minutes, 35);
+    assert_eq!(
+        static_data[0].possible_insertion_beginnings_minutes_sorted,
+        vec![0, 10].into_iter().collect()
+    );
+    assert_eq!(static_data[0].indexes_of_incompatible_activities, vec![1]);
+    assert_eq!(static_data[1].indexes_of_incompatible_activities, vec![0]);
+}
+
 /// The purpose of this test is to make sure that activities which are ordered differently in data
 /// (by order of addition)
 /// and in felix-computation-api (by difficulty of insertion) are not inverted.
@@ -276,8 +525,12 @@ fn test_insertion_costs_of_activity() {
 
     let activity1_insertion_costs = (0..=10)
         .step_by(5)
-        .map(|n_minutes| Time::from_total_minutes(n_minutes))
-        .map(|beginning| InsertionCost { beginning, cost: 0 })
+        .map(TimeOfDay::from_total_minutes)
+        .map(|beginning| InsertionCost {
+            beginning,
+            cost: 0,
+            duration: Duration::new(0, 30),
+        })
         .collect::<Vec<_>>();
 
     activity_collection.mutate_activity(0, |activity1| {
@@ -285,7 +538,9 @@ fn test_insertion_costs_of_activity() {
             .computation_data
             .set_incompatible_activity_ids(vec![]);
 
-        activity1.computation_data.set_duration(Time::new(0, 30));
+        activity1
+            .computation_data
+            .set_duration(Duration::new(0, 30));
         *activity1.computation_data.insertion_costs().lock().unwrap() =
             Some(activity1_insertion_costs.clone());
     });
@@ -295,13 +550,21 @@ fn test_insertion_costs_of_activity() {
             .computation_data
             .set_incompatible_activity_ids(vec![0, 3]);
 
-        activity2.computation_data.set_duration(Time::new(0, 20));
-        activity2.computation_data.insert(Some(Time::new(2, 0)));
+        activity2
+            .computation_data
+            .set_duration(Duration::new(0, 20));
+        activity2
+            .computation_data
+            .insert(Some(TimeOfDay::new(2, 0)));
     });
 
     activity_collection.mutate_activity(2, |activity3| {
-        activity3.computation_data.set_duration(Time::new(0, 20));
-        activity3.computation_data.insert(Some(Time::new(1, 0)));
+        activity3
+            .computation_data
+            .set_duration(Duration::new(0, 20));
+        activity3
+            .computation_data
+            .insert(Some(TimeOfDay::new(1, 0)));
     });
 
     // Activity 1 will be reordered internally.
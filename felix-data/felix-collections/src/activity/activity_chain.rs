@@ -0,0 +1,78 @@
+use felix_datatypes::{ActivityId, Duration};
+
+use serde::{Deserialize, Serialize};
+
+/// Links activities which must take place one right after the other.
+///
+/// Each activity begins exactly when the previous one ends, plus a fixed offset.
+/// The computation treats the chain as a single block whose duration covers
+/// all of its activities and offsets.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ActivityChain {
+    activity_ids: Vec<ActivityId>,
+    /// Time between the end of an activity and the beginning of the next one.
+    offsets: Vec<Duration>,
+}
+
+impl ActivityChain {
+    /// Creates a new chain. The ids are given in order.
+    /// There is one offset less than activities: the offset at index i separates
+    /// activities i and i + 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of offsets does not match the number of activities.
+    #[must_use]
+    pub fn new(activity_ids: Vec<ActivityId>, offsets: Vec<Duration>) -> ActivityChain {
+        assert_eq!(
+            activity_ids.len(),
+            offsets.len() + 1,
+            "There must be one offset between each pair of chained activities"
+        );
+        ActivityChain {
+            activity_ids,
+            offsets,
+        }
+    }
+
+    /// Simple getter for the ids of the activities, in order.
+    #[must_use]
+    pub fn activity_ids(&self) -> &Vec<ActivityId> {
+        &self.activity_ids
+    }
+
+    /// Simple getter for the offsets between consecutive activities.
+    #[must_use]
+    pub fn offsets(&self) -> &Vec<Duration> {
+        &self.offsets
+    }
+
+    /// Returns the id of the first activity of the chain.
+    #[must_use]
+    pub fn head_id(&self) -> ActivityId {
+        self.activity_ids[0]
+    }
+
+    /// Returns the position of the activity with given id, if it is part of the chain.
+    #[must_use]
+    pub fn index_of(&self, id: ActivityId) -> Option<usize> {
+        self.activity_ids
+            .iter()
+            .position(|&activity_id| activity_id == id)
+    }
+
+    /// Given the durations of the activities in chain order, returns the time between
+    /// the beginning of the chain and the beginning of each activity.
+    #[must_use]
+    pub fn shifts(&self, durations: &[Duration]) -> Vec<Duration> {
+        let mut shift = Duration::default();
+        let mut shifts = Vec::with_capacity(durations.len());
+        for (index, &duration) in durations.iter().enumerate() {
+            shifts.push(shift);
+            if let Some(&offset) = self.offsets.get(index) {
+                shift += duration + offset;
+            }
+        }
+        shifts
+    }
+}
@@ -1,8 +1,10 @@
 use felix_datatypes::{
-    ActivityId, ActivityInsertionCosts, Time, TimeInterval, WorkHoursAndActivityDurationsSorted,
+    ActivityId, ActivityInsertionCosts, BreakRules, Duration, DurationObjective, EntityId,
+    TimeInterval, TimeOfDay, TimePreferences, WorkHoursAndActivityDurationsSorted,
 };
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use std::sync::{Arc, Mutex};
 
@@ -10,8 +12,17 @@ use std::sync::{Arc, Mutex};
 /// incompatible activities, possible insertion times.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActivityComputationData {
-    duration: Time,
+    duration: Duration,
+    /// If Some, the activity may last anywhere between its duration and this one.
+    #[serde(default)]
+    max_duration: Option<Duration>,
+    /// Which duration of the range to choose when inserting the activity.
+    #[serde(default)]
+    duration_objective: DurationObjective,
     insertion_interval: Option<TimeInterval>,
+    /// Minimum free time between this activity and any incompatible activity.
+    #[serde(default)]
+    transition_buffer: Duration,
 
     /// Kept in a arc because updated when necessary in separate threads.
     /// None means not computed yet (invalidated).
@@ -21,17 +32,31 @@ pub struct ActivityComputationData {
 
     incompatible_activity_ids: Vec<ActivityId>,
     schedules_of_participants: Vec<WorkHoursAndActivityDurationsSorted>,
+    #[serde(default)]
+    preferences_of_participants: Vec<TimePreferences>,
+    /// Only participants with break rules are stored.
+    #[serde(default)]
+    break_rules_of_participants: HashMap<EntityId, BreakRules>,
+    /// Used to know which optional participants are free to attend the activity.
+    #[serde(default)]
+    work_hours_of_optional_participants: HashMap<EntityId, Vec<TimeInterval>>,
 }
 
 impl ActivityComputationData {
     /// Creates new computation data.
     pub fn new() -> ActivityComputationData {
         ActivityComputationData {
-            duration: Time::new(0, 0),
+            duration: Duration::default(),
+            max_duration: None,
+            duration_objective: DurationObjective::default(),
             insertion_interval: None,
+            transition_buffer: Duration::default(),
             insertion_costs: Arc::new(Mutex::new(Some(Vec::new()))),
             incompatible_activity_ids: Vec::new(),
             schedules_of_participants: Vec::new(),
+            preferences_of_participants: Vec::new(),
+            break_rules_of_participants: HashMap::new(),
+            work_hours_of_optional_participants: HashMap::new(),
         }
     }
 
@@ -39,16 +64,56 @@ impl ActivityComputationData {
 
     /// Simple getter for the duration.
     #[must_use]
-    pub fn duration(&self) -> Time {
+    pub fn duration(&self) -> Duration {
         self.duration
     }
 
+    /// Returns the longest duration the activity may have.
+    /// If the activity has no duration range, this is its duration.
+    #[must_use]
+    pub fn max_duration(&self) -> Duration {
+        self.max_duration.map_or(self.duration, |max_duration| {
+            max_duration.max(self.duration)
+        })
+    }
+
+    /// Simple getter for the duration objective.
+    #[must_use]
+    pub fn duration_objective(&self) -> DurationObjective {
+        self.duration_objective
+    }
+
+    /// Returns the duration the activity would have if inserted at the given time.
+    /// This is the duration of the insertion cost at this time if it is in range,
+    /// else the duration of the activity.
+    #[must_use]
+    pub fn duration_if_inserted_at(&self, insertion_time: TimeOfDay) -> Duration {
+        self.insertion_costs
+            .lock()
+            .unwrap()
+            .as_ref()
+            .and_then(|insertion_costs| {
+                insertion_costs
+                    .iter()
+                    .find(|insertion_cost| insertion_cost.beginning == insertion_time)
+                    .map(|insertion_cost| insertion_cost.duration)
+            })
+            .filter(|&duration| duration >= self.duration && duration <= self.max_duration())
+            .unwrap_or(self.duration)
+    }
+
     /// Simple getter for the insertion interval.
     #[must_use]
     pub fn insertion_interval(&self) -> Option<TimeInterval> {
         self.insertion_interval
     }
 
+    /// Simple getter for the transition buffer.
+    #[must_use]
+    pub fn transition_buffer(&self) -> Duration {
+        self.transition_buffer
+    }
+
     /// Simple getter for insertion costs.
     #[must_use]
     pub fn insertion_costs(&self) -> Arc<Mutex<ActivityInsertionCosts>> {
@@ -66,6 +131,24 @@ impl ActivityComputationData {
         &self.schedules_of_participants
     }
 
+    /// Simple getter for the time preferences of participants.
+    #[must_use]
+    pub fn preferences_of_participants(&self) -> &Vec<TimePreferences> {
+        &self.preferences_of_participants
+    }
+
+    /// Simple getter for the break rules of participants.
+    #[must_use]
+    pub fn break_rules_of_participants(&self) -> &HashMap<EntityId, BreakRules> {
+        &self.break_rules_of_participants
+    }
+
+    /// Simple getter for the work hours of optional participants.
+    #[must_use]
+    pub fn work_hours_of_optional_participants(&self) -> &HashMap<EntityId, Vec<TimeInterval>> {
+        &self.work_hours_of_optional_participants
+    }
+
     // *** Setters ***
 
     /// Simple setter for the duration.
@@ -73,18 +156,42 @@ impl ActivityComputationData {
     /// If the duration is shorter than the current one, updates the current insertion time.
     /// If the duration is greater, we don't know where the activity will fit : It is the
     /// responsibility of higher level collections to deal with the change in insertion time.
-    pub fn set_duration(&mut self, duration: Time) {
-        if duration < self.duration && duration != Time::new(0, 0) {
+    pub fn set_duration(&mut self, duration: Duration) {
+        if duration < self.duration && duration != Duration::default() {
             if let Some(insertion_interval) = self.insertion_interval {
+                // An activity with a duration range keeps its length if it is still in range
+                let max_duration = self
+                    .max_duration
+                    .map_or(duration, |max_duration| max_duration.max(duration));
                 self.insertion_interval = Some(TimeInterval::new(
                     insertion_interval.beginning(),
-                    insertion_interval.beginning() + duration,
+                    insertion_interval.beginning()
+                        + insertion_interval.duration().min(max_duration),
                 ));
             }
         }
         self.duration = duration;
     }
 
+    /// Simple setter for the maximum duration.
+    /// If None is given, the activity lasts exactly its duration.
+    ///
+    /// Does not update the insertion interval : It is the responsibility of higher level
+    /// collections to deal with the change in insertion time.
+    pub fn set_max_duration(&mut self, max_duration: Option<Duration>) {
+        self.max_duration = max_duration;
+    }
+
+    /// Simple setter for the duration objective.
+    pub fn set_duration_objective(&mut self, duration_objective: DurationObjective) {
+        self.duration_objective = duration_objective;
+    }
+
+    /// Simple setter for the transition buffer.
+    pub fn set_transition_buffer(&mut self, transition_buffer: Duration) {
+        self.transition_buffer = transition_buffer;
+    }
+
     /// Simple setter for incompatible activity ids.
     ///
     /// Does not perform any checks, the activities collection does it.
@@ -100,19 +207,50 @@ impl ActivityComputationData {
         self.schedules_of_participants = schedules;
     }
 
+    /// Simple setter for time preferences of participants.
+    pub fn update_preferences_of_participants(&mut self, preferences: Vec<TimePreferences>) {
+        self.preferences_of_participants = preferences;
+    }
+
+    /// Simple setter for break rules of participants.
+    pub fn update_break_rules_of_participants(
+        &mut self,
+        break_rules: HashMap<EntityId, BreakRules>,
+    ) {
+        self.break_rules_of_participants = break_rules;
+    }
+
+    /// Simple setter for work hours of optional participants.
+    pub fn update_work_hours_of_optional_participants(
+        &mut self,
+        work_hours: HashMap<EntityId, Vec<TimeInterval>>,
+    ) {
+        self.work_hours_of_optional_participants = work_hours;
+    }
+
+    /// Gives the activity its own copy of its insertion costs, which it shared with the
+    /// activity it was cloned from.
+    pub fn detach_insertion_costs(&mut self) {
+        let insertion_costs = self.insertion_costs.lock().unwrap().clone();
+        self.insertion_costs = Arc::new(Mutex::new(insertion_costs));
+    }
+
     /// Inserts the activity at given time.
     /// If None is given, the activity is removed from the schedule.
     ///
+    /// The activity lasts as long as its insertion cost at this time states.
+    /// If there is none, it lasts its duration.
+    ///
     /// Does not perform any checks, data should be sanitized above.
     ///
     /// # Panics
     ///
-    /// Panics if the insertion time + duration Time is invalid.
-    pub fn insert(&mut self, insertion_time: Option<Time>) {
+    /// Panics if the insertion time + duration is invalid.
+    pub fn insert(&mut self, insertion_time: Option<TimeOfDay>) {
         if let Some(insertion_time) = insertion_time {
             self.insertion_interval = Some(TimeInterval::new(
                 insertion_time,
-                insertion_time + self.duration,
+                insertion_time + self.duration_if_inserted_at(insertion_time),
             ));
         } else {
             self.insertion_interval = None;
@@ -126,7 +264,10 @@ impl PartialEq for ActivityComputationData {
     fn eq(&self, other: &Self) -> bool {
         // Don't check for possible activity insertions because they are asynchronously calculated
         self.duration == other.duration
+            && self.max_duration == other.max_duration
+            && self.duration_objective == other.duration_objective
             && self.insertion_interval == other.insertion_interval
+            && self.transition_buffer == other.transition_buffer
             && self.incompatible_activity_ids == other.incompatible_activity_ids
     }
 }
@@ -135,7 +276,10 @@ impl Hash for ActivityComputationData {
     fn hash<H: Hasher>(&self, state: &mut H) {
         // Don't hash possible activity insertions because they are asynchronously calculated
         self.duration.hash(state);
+        self.max_duration.hash(state);
+        self.duration_objective.hash(state);
         self.insertion_interval.hash(state);
+        self.transition_buffer.hash(state);
         self.incompatible_activity_ids.hash(state);
     }
 }
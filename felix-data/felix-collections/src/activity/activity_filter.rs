@@ -0,0 +1,57 @@
+use crate::Activity;
+
+use std::collections::BTreeSet;
+
+/// Selects activities by tag and by category.
+///
+/// An activity matches if it has at least one of the tags and belongs to one of
+/// the categories. An empty set of tags or categories does not filter anything.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ActivityFilter {
+    pub tags: BTreeSet<String>,
+    pub categories: BTreeSet<String>,
+}
+
+impl ActivityFilter {
+    /// Creates a filter which keeps only activities with the given tag.
+    #[must_use]
+    pub fn with_tag<S>(tag: S) -> ActivityFilter
+    where
+        S: Into<String>,
+    {
+        ActivityFilter {
+            tags: std::iter::once(tag.into()).collect(),
+            categories: BTreeSet::new(),
+        }
+    }
+
+    /// Creates a filter which keeps only activities of the given category.
+    #[must_use]
+    pub fn with_category<S>(category: S) -> ActivityFilter
+    where
+        S: Into<String>,
+    {
+        ActivityFilter {
+            tags: BTreeSet::new(),
+            categories: std::iter::once(category.into()).collect(),
+        }
+    }
+
+    /// Returns true if the filter keeps every activity.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.categories.is_empty()
+    }
+
+    /// Returns true if the given activity is kept by the filter.
+    #[must_use]
+    pub fn matches(&self, activity: &Activity) -> bool {
+        let matches_tags =
+            self.tags.is_empty() || self.tags.iter().any(|tag| activity.details().has_tag(tag));
+        let matches_categories = self.categories.is_empty()
+            || activity
+                .category()
+                .is_some_and(|category| self.categories.contains(&category));
+        matches_tags && matches_categories
+    }
+}
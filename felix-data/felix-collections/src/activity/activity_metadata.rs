@@ -1,5 +1,8 @@
-use felix_datatypes::{ActivityId, Rgba};
-use felix_errors::{already_in::AlreadyIn, name_taken::NameTaken, not_in::NotIn, Result};
+use super::{ActivityChain, ActivitySessions};
+use crate::{Entity, Group};
+
+use felix_datatypes::{ActivityId, Details, EntityId, GroupId, Rgba};
+use felix_errors::{already_in::AlreadyIn, not_in::NotIn, Result};
 
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeSet;
@@ -7,6 +10,8 @@ use std::hash::Hash;
 
 /// Simple structure holding non-computation related data : id, name, entities.
 ///
+/// Entities and groups are stored by id so that they can be renamed freely.
+///
 /// We directly store incompatible activities in the ActivityComputationData which is why
 /// the entities are not directly computation-related.
 #[derive(Debug, Clone, PartialEq, Hash, Serialize, Deserialize)]
@@ -14,9 +19,30 @@ pub struct ActivityMetadata {
     id: ActivityId,
     name: String,
     // BTreeSet implements Hash
-    entities: BTreeSet<String>,
-    groups: BTreeSet<String>,
+    entities: BTreeSet<EntityId>,
+    groups: BTreeSet<GroupId>,
     display_color: Rgba,
+    #[serde(default)]
+    sessions: Option<ActivitySessions>,
+    #[serde(default)]
+    simultaneous_activity_ids: BTreeSet<ActivityId>,
+    #[serde(default)]
+    chain: Option<ActivityChain>,
+    #[serde(default)]
+    category: Option<String>,
+    #[serde(default)]
+    details: Details,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    location: String,
+    /// Entities which attend the activity only if they are free. Never mandatory participants.
+    #[serde(default)]
+    optional_entities: BTreeSet<EntityId>,
+    #[serde(default)]
+    min_participants: Option<usize>,
+    #[serde(default)]
+    max_participants: Option<usize>,
 }
 
 impl ActivityMetadata {
@@ -37,6 +63,16 @@ impl ActivityMetadata {
             entities: BTreeSet::new(),
             groups: BTreeSet::new(),
             display_color: DEFAULT_COLOR,
+            sessions: None,
+            simultaneous_activity_ids: BTreeSet::new(),
+            chain: None,
+            category: None,
+            details: Details::default(),
+            description: String::new(),
+            location: String::new(),
+            optional_entities: BTreeSet::new(),
+            min_participants: None,
+            max_participants: None,
         }
     }
 
@@ -54,26 +90,16 @@ impl ActivityMetadata {
         &self.name
     }
 
-    /// Simple getter for the entities, sorted by name.
-    #[must_use]
-    pub fn entities_sorted(&self) -> Vec<String> {
-        let mut entities_vec: Vec<String> = self.entities.iter().cloned().collect();
-        entities_vec.sort();
-        entities_vec
-    }
-
-    /// Simple getter for the entities, sorted by name.
+    /// Simple getter for the ids of the entities.
     #[must_use]
-    pub fn groups_sorted(&self) -> Vec<String> {
-        let mut groups_vec: Vec<String> = self.groups.iter().cloned().collect();
-        groups_vec.sort();
-        groups_vec
+    pub fn entity_ids(&self) -> &BTreeSet<EntityId> {
+        &self.entities
     }
 
-    /// Getter for the entities, not sorted.
+    /// Simple getter for the ids of the groups.
     #[must_use]
-    pub fn entities_as_set(&self) -> &BTreeSet<String> {
-        &self.entities
+    pub fn group_ids(&self) -> &BTreeSet<GroupId> {
+        &self.groups
     }
 
     /// Getter for the color.
@@ -82,6 +108,67 @@ impl ActivityMetadata {
         self.display_color
     }
 
+    /// Simple getter for the sessions, if the activity was split.
+    #[must_use]
+    pub fn sessions(&self) -> &Option<ActivitySessions> {
+        &self.sessions
+    }
+
+    /// Simple getter for the ids of the activities which must begin at the same time
+    /// as this one.
+    #[must_use]
+    pub fn simultaneous_activity_ids(&self) -> &BTreeSet<ActivityId> {
+        &self.simultaneous_activity_ids
+    }
+
+    /// Simple getter for the chain, if the activity is chained to other activities.
+    #[must_use]
+    pub fn chain(&self) -> &Option<ActivityChain> {
+        &self.chain
+    }
+
+    /// Simple getter for the category, used by load limits.
+    #[must_use]
+    pub fn category(&self) -> &Option<String> {
+        &self.category
+    }
+
+    /// Simple getter for the details (notes, email, tags, custom fields).
+    #[must_use]
+    pub fn details(&self) -> &Details {
+        &self.details
+    }
+
+    /// Simple getter for the description.
+    #[must_use]
+    pub fn description(&self) -> &String {
+        &self.description
+    }
+
+    /// Simple getter for the location.
+    #[must_use]
+    pub fn location(&self) -> &String {
+        &self.location
+    }
+
+    /// Simple getter for the ids of the optional participants.
+    #[must_use]
+    pub fn optional_entity_ids(&self) -> &BTreeSet<EntityId> {
+        &self.optional_entities
+    }
+
+    /// Simple getter for the minimum number of participants.
+    #[must_use]
+    pub fn min_participants(&self) -> Option<usize> {
+        self.min_participants
+    }
+
+    /// Simple getter for the maximum number of participants.
+    #[must_use]
+    pub fn max_participants(&self) -> Option<usize> {
+        self.max_participants
+    }
+
     // *** Setters ***
 
     // No setter for the id. The id should be unique and never change.
@@ -92,16 +179,20 @@ impl ActivityMetadata {
     }
 
     /// Adds an entity to the activity.
-    /// The entities are always sorted.
+    /// If the entity was an optional participant, it becomes mandatory.
     ///
     /// # Errors
     ///
     /// Returns Err if the entity is already taking part in the activity.
-    pub fn add_entity(&mut self, entity: String) -> Result<()> {
-        if self.entities.insert(entity.clone()) {
+    pub fn add_entity(&mut self, entity: &Entity) -> Result<()> {
+        if self.entities.insert(entity.id()) {
+            self.optional_entities.remove(&entity.id());
             Ok(())
         } else {
-            Err(AlreadyIn::entity_already_in_activity(entity, self.name()))
+            Err(AlreadyIn::entity_already_in_activity(
+                entity.name(),
+                self.name(),
+            ))
         }
     }
 
@@ -110,77 +201,69 @@ impl ActivityMetadata {
     /// # Errors
     ///
     /// Returns Err if the entity is not taking part in the activity.
-    pub fn remove_entity(&mut self, entity: &str) -> Result<()> {
-        if self.entities.remove(entity) {
+    pub fn remove_entity(&mut self, entity: &Entity) -> Result<()> {
+        if self.entities.remove(&entity.id()) {
             Ok(())
         } else {
-            Err(NotIn::entity_not_in_activity(entity, self.name()))
+            Err(NotIn::entity_not_in_activity(entity.name(), self.name()))
         }
     }
 
-    /// Renames an entity in the activity.
+    /// Adds an optional participant to the activity.
     ///
     /// # Errors
     ///
-    /// Returns Err if the entity is not taking part in the activity or if
-    /// an entity with the new name is already taking part in the activity.
-    pub fn rename_entity(&mut self, old_name: &str, new_name: String) -> Result<()> {
-        if self.entities.contains(&new_name) {
-            return Err(NameTaken::name_taken_by_entity(new_name));
-        };
-
-        if self.entities.remove(old_name) {
-            self.entities.insert(new_name);
+    /// Returns Err if the entity is already taking part in the activity.
+    pub fn add_optional_entity(&mut self, entity: &Entity) -> Result<()> {
+        if !self.entities.contains(&entity.id()) && self.optional_entities.insert(entity.id()) {
             Ok(())
         } else {
-            Err(NotIn::entity_not_in_activity(old_name, self.name()))
+            Err(AlreadyIn::entity_already_in_activity(
+                entity.name(),
+                self.name(),
+            ))
         }
     }
 
-    /// Add a group to the activity.
+    /// Removes an optional participant from the activity.
     ///
     /// # Errors
     ///
-    /// Returns Err if the group is already taking part in the activity.
-    pub fn add_group(&mut self, group: String) -> Result<()> {
-        if self.groups.contains(&group) {
-            Err(AlreadyIn::group_already_in_activity(group, self.name()))
-        } else {
-            self.groups.insert(group);
+    /// Returns Err if the entity is not an optional participant of the activity.
+    pub fn remove_optional_entity(&mut self, entity: &Entity) -> Result<()> {
+        if self.optional_entities.remove(&entity.id()) {
             Ok(())
+        } else {
+            Err(NotIn::entity_not_in_activity(entity.name(), self.name()))
         }
     }
 
-    /// Removes a group from the activity.
+    /// Add a group to the activity.
     ///
     /// # Errors
     ///
     /// Returns Err if the group is already taking part in the activity.
-    pub fn remove_group(&mut self, group: &str) -> Result<()> {
-        if self.groups.contains(group) {
-            self.groups.remove(group);
+    pub fn add_group(&mut self, group: &Group) -> Result<()> {
+        if self.groups.insert(group.id()) {
             Ok(())
         } else {
-            Err(NotIn::group_not_in_activity(group, self.name()))
+            Err(AlreadyIn::group_already_in_activity(
+                group.name(),
+                self.name(),
+            ))
         }
     }
 
-    /// Renames the group in the activity.
+    /// Removes a group from the activity.
     ///
     /// # Errors
     ///
-    /// Returns Err if the group is not taking part in the activity or
-    /// if a group with this name is already present in the activity.
-    pub fn rename_group(&mut self, old_name: &str, new_name: String) -> Result<()> {
-        if self.groups.contains(&new_name) {
-            return Err(NameTaken::name_taken_by_group(new_name));
-        };
-
-        if self.groups.remove(old_name) {
-            self.groups.insert(new_name);
+    /// Returns Err if the group is not taking part in the activity.
+    pub fn remove_group(&mut self, group: &Group) -> Result<()> {
+        if self.groups.remove(&group.id()) {
             Ok(())
         } else {
-            Err(NotIn::group_not_in_activity(old_name, self.name()))
+            Err(NotIn::group_not_in_activity(group.name(), self.name()))
         }
     }
 
@@ -188,6 +271,51 @@ impl ActivityMetadata {
     pub fn set_color(&mut self, color: Rgba) {
         self.display_color = color;
     }
+
+    /// Sets the sessions which the activity is part of.
+    pub fn set_sessions(&mut self, sessions: Option<ActivitySessions>) {
+        self.sessions = sessions;
+    }
+
+    /// Sets the ids of the activities which must begin at the same time as this one.
+    pub fn set_simultaneous_activity_ids(&mut self, ids: BTreeSet<ActivityId>) {
+        self.simultaneous_activity_ids = ids;
+    }
+
+    /// Sets the chain which the activity is part of.
+    pub fn set_chain(&mut self, chain: Option<ActivityChain>) {
+        self.chain = chain;
+    }
+
+    /// Sets the category of the activity.
+    pub fn set_category(&mut self, category: Option<String>) {
+        self.category = category;
+    }
+
+    /// Sets the details of the activity.
+    pub fn set_details(&mut self, details: Details) {
+        self.details = details;
+    }
+
+    /// Sets the description of the activity.
+    pub fn set_description(&mut self, description: String) {
+        self.description = description;
+    }
+
+    /// Sets the location of the activity.
+    pub fn set_location(&mut self, location: String) {
+        self.location = location;
+    }
+
+    /// Sets the minimum and maximum number of participants of the activity.
+    pub fn set_participant_limits(
+        &mut self,
+        min_participants: Option<usize>,
+        max_participants: Option<usize>,
+    ) {
+        self.min_participants = min_participants;
+        self.max_participants = max_participants;
+    }
 }
 
 impl Eq for ActivityMetadata {}
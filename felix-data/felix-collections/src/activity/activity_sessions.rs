@@ -0,0 +1,131 @@
+use felix_datatypes::{ActivityId, Duration, MIN_TIME_DISCRETIZATION};
+
+use serde::{Deserialize, Serialize};
+
+/// Rules which the sessions of a split activity must follow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SessionRules {
+    /// No session can be shorter than this duration.
+    pub min_session_duration: Duration,
+    /// All sessions must take place on the same day.
+    /// The schedule currently spans a single day, so this rule always holds.
+    pub same_day: bool,
+    /// The sessions must take place in order, each one after the end of the previous one.
+    pub consecutive: bool,
+}
+
+impl Default for SessionRules {
+    fn default() -> Self {
+        SessionRules {
+            min_session_duration: MIN_TIME_DISCRETIZATION,
+            same_day: false,
+            consecutive: false,
+        }
+    }
+}
+
+/// Links the sessions of an activity which was split into several sessions.
+///
+/// Each session is an activity of its own. The first session is the main one:
+/// it keeps the id of the activity which was split.
+///
+/// The sessions of a recurring activity are its occurrences: each one lasts as long
+/// as the activity and they are spaced by a minimum duration.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ActivitySessions {
+    session_ids: Vec<ActivityId>,
+    rules: SessionRules,
+    #[serde(default)]
+    min_spacing_between_occurrences: Option<Duration>,
+}
+
+impl ActivitySessions {
+    /// Creates new activity sessions. The ids are given in the order of the sessions.
+    #[must_use]
+    pub fn new(session_ids: Vec<ActivityId>, rules: SessionRules) -> ActivitySessions {
+        ActivitySessions {
+            session_ids,
+            rules,
+            min_spacing_between_occurrences: None,
+        }
+    }
+
+    /// Creates the occurrences of a recurring activity. The ids are given in the order of the
+    /// occurrences, which must take place one after the other.
+    #[must_use]
+    pub fn occurrences(occurrence_ids: Vec<ActivityId>, min_spacing: Duration) -> ActivitySessions {
+        ActivitySessions {
+            session_ids: occurrence_ids,
+            rules: SessionRules {
+                consecutive: true,
+                ..SessionRules::default()
+            },
+            min_spacing_between_occurrences: Some(min_spacing),
+        }
+    }
+
+    /// Returns true if the sessions are the occurrences of a recurring activity.
+    #[must_use]
+    pub fn is_recurring(&self) -> bool {
+        self.min_spacing_between_occurrences.is_some()
+    }
+
+    /// Returns the minimum free time between the end of a session and the beginning
+    /// of the next one. Only occurrences of a recurring activity need to be spaced.
+    #[must_use]
+    pub fn min_spacing(&self) -> Duration {
+        self.min_spacing_between_occurrences.unwrap_or_default()
+    }
+
+    /// Simple getter for the ids of the sessions, in order.
+    #[must_use]
+    pub fn session_ids(&self) -> &Vec<ActivityId> {
+        &self.session_ids
+    }
+
+    /// Simple getter for the rules of the sessions.
+    #[must_use]
+    pub fn rules(&self) -> SessionRules {
+        self.rules
+    }
+
+    /// Returns the id of the main session, which is the first one.
+    #[must_use]
+    pub fn main_session_id(&self) -> ActivityId {
+        self.session_ids[0]
+    }
+
+    /// Returns the position of the session with given id, if it is one of the sessions.
+    #[must_use]
+    pub fn index_of(&self, id: ActivityId) -> Option<usize> {
+        self.session_ids
+            .iter()
+            .position(|&session_id| session_id == id)
+    }
+
+    /// Returns the id of the session which must take place right before the session
+    /// with given id, if the sessions are consecutive.
+    #[must_use]
+    pub fn previous_session_of(&self, id: ActivityId) -> Option<ActivityId> {
+        if self.rules.consecutive {
+            self.index_of(id)
+                .filter(|&index| index > 0)
+                .map(|index| self.session_ids[index - 1])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the id of the session which must take place right after the session
+    /// with given id, if the sessions are consecutive.
+    #[must_use]
+    pub fn next_session_of(&self, id: ActivityId) -> Option<ActivityId> {
+        if self.rules.consecutive {
+            self.index_of(id)
+                .and_then(|index| self.session_ids.get(index + 1))
+                .copied()
+        } else {
+            None
+        }
+    }
+}
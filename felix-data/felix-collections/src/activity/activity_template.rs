@@ -0,0 +1,169 @@
+use crate::Activity;
+
+use felix_datatypes::{Details, Duration, DurationObjective, EntityId, GroupId, Rgba};
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// Metadata shared by activities stamped out of the same template :
+/// name, duration range, color, category, details, participants and participant limits.
+///
+/// Templates are built from existing activities. Sessions, insertion,
+/// simultaneity and chains are not part of a template.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityTemplate {
+    activity_name: String,
+    duration: Duration,
+    #[serde(default)]
+    max_duration: Duration,
+    #[serde(default)]
+    duration_objective: DurationObjective,
+    transition_buffer: Duration,
+    color: Rgba,
+    category: Option<String>,
+    details: Details,
+    description: String,
+    location: String,
+    entities: BTreeSet<EntityId>,
+    groups: BTreeSet<GroupId>,
+    #[serde(default)]
+    optional_entities: BTreeSet<EntityId>,
+    #[serde(default)]
+    min_participants: Option<usize>,
+    #[serde(default)]
+    max_participants: Option<usize>,
+}
+
+impl From<&Activity> for ActivityTemplate {
+    fn from(activity: &Activity) -> Self {
+        ActivityTemplate {
+            activity_name: activity.name(),
+            duration: activity.duration(),
+            max_duration: activity.max_duration(),
+            duration_objective: activity.duration_objective(),
+            transition_buffer: activity.transition_buffer(),
+            color: activity.color(),
+            category: activity.category(),
+            details: activity.details().clone(),
+            description: activity.description(),
+            location: activity.location(),
+            entities: activity.entity_ids().into_iter().collect(),
+            groups: activity.group_ids().into_iter().collect(),
+            optional_entities: activity.optional_entity_ids().into_iter().collect(),
+            min_participants: activity.min_participants(),
+            max_participants: activity.max_participants(),
+        }
+    }
+}
+
+impl ActivityTemplate {
+    // *** Getters ***
+
+    /// Simple getter for the name given to the activities of the template,
+    /// before numbering.
+    #[must_use]
+    pub fn activity_name(&self) -> String {
+        self.activity_name.clone()
+    }
+
+    /// Simple getter for the duration.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Simple getter for the maximum duration.
+    /// Equal to or shorter than the duration if the activities have no duration range.
+    #[must_use]
+    pub fn max_duration(&self) -> Duration {
+        self.max_duration
+    }
+
+    /// Simple getter for the duration objective.
+    #[must_use]
+    pub fn duration_objective(&self) -> DurationObjective {
+        self.duration_objective
+    }
+
+    /// Simple getter for the transition buffer.
+    #[must_use]
+    pub fn transition_buffer(&self) -> Duration {
+        self.transition_buffer
+    }
+
+    /// Simple getter for the color.
+    #[must_use]
+    pub fn color(&self) -> Rgba {
+        self.color
+    }
+
+    /// Simple getter for the category.
+    #[must_use]
+    pub fn category(&self) -> Option<String> {
+        self.category.clone()
+    }
+
+    /// Simple getter for the details.
+    #[must_use]
+    pub fn details(&self) -> &Details {
+        &self.details
+    }
+
+    /// Simple getter for the description.
+    #[must_use]
+    pub fn description(&self) -> String {
+        self.description.clone()
+    }
+
+    /// Simple getter for the location.
+    #[must_use]
+    pub fn location(&self) -> String {
+        self.location.clone()
+    }
+
+    /// Simple getter for the ids of the entities.
+    /// Use the Data object to get their names.
+    #[must_use]
+    pub fn entity_ids(&self) -> Vec<EntityId> {
+        self.entities.iter().copied().collect()
+    }
+
+    /// Simple getter for the ids of the groups.
+    /// Use the Data object to get their names.
+    #[must_use]
+    pub fn group_ids(&self) -> Vec<GroupId> {
+        self.groups.iter().copied().collect()
+    }
+
+    /// Simple getter for the ids of the optional participants.
+    /// Use the Data object to get their names.
+    #[must_use]
+    pub fn optional_entity_ids(&self) -> Vec<EntityId> {
+        self.optional_entities.iter().copied().collect()
+    }
+
+    /// Simple getter for the minimum number of participants.
+    #[must_use]
+    pub fn min_participants(&self) -> Option<usize> {
+        self.min_participants
+    }
+
+    /// Simple getter for the maximum number of participants.
+    #[must_use]
+    pub fn max_participants(&self) -> Option<usize> {
+        self.max_participants
+    }
+
+    // *** Setters ***
+
+    /// Removes the entity with given id from the template.
+    pub(crate) fn remove_entity(&mut self, entity_id: EntityId) {
+        self.entities.remove(&entity_id);
+        self.optional_entities.remove(&entity_id);
+    }
+
+    /// Removes the group with given id from the template.
+    pub(crate) fn remove_group(&mut self, group_id: GroupId) {
+        self.groups.remove(&group_id);
+    }
+}
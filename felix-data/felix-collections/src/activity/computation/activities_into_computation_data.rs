@@ -1,12 +1,14 @@
 use crate::Activity;
-use felix_computation_api::structs::ActivityComputationStaticData;
-use felix_datatypes::{ActivityBeginningMinutes, ActivityId};
+use felix_computation_api::structs::{ActivityComputationStaticData, ParticipantBreakRules};
+use felix_datatypes::{ActivityBeginningMinutes, ActivityId, Duration};
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 /// Returns data ready for auto-insertion of all activities.
 ///
 /// The ids of incompatible activities are turned into indexes.
+/// Chained activities are turned into a single block, stored at the index of the first
+/// activity of the chain.
 #[must_use]
 pub fn activities_into_computation_data(
     activities: &[Activity],
@@ -20,40 +22,126 @@ pub fn activities_into_computation_data(
 
     let sorted_activities = activities_sorted_filtered_for_computation(activities);
 
-    let ids = sorted_activities
+    let chained_activities_of_each_block = sorted_activities
         .iter()
-        .map(|other| other.metadata.id())
+        .map(|activity| chained_activities_with_shifts(activity, activities))
         .collect::<Vec<_>>();
 
-    for activity in sorted_activities {
+    // Every activity of a chain is translated into the index of its block
+    let mut index_of_id = HashMap::new();
+    for (index, chained_activities) in chained_activities_of_each_block.iter().enumerate() {
+        for (chained_activity, _shift) in chained_activities {
+            index_of_id.insert(chained_activity.id(), index);
+        }
+    }
+
+    for (index, activity) in sorted_activities.iter().enumerate() {
         let computation_data = &activity.computation_data;
-        let incompatible_ids = computation_data.incompatible_activity_ids();
+        let chained_activities = &chained_activities_of_each_block[index];
 
         // Translate incompatible ids into incompatible indexes
         // This is not the most efficient but this operation is not critical:
         // the computation should be optimized, not this
-        let mut incompatible_indexes: Vec<ActivityId> = Vec::with_capacity(incompatible_ids.len());
-        for (index_of_other, id_of_other) in ids.iter().enumerate() {
-            // We don't care if we compare ourselves to ourselves,
-            // we cannot be incompatible with ourselves
-            if incompatible_ids.contains(&id_of_other) {
-                incompatible_indexes.push(index_of_other);
-            }
-        }
-
-        let possible_insertion_beginnings_minutes_sorted = activity
-            .insertion_costs()
-            .expect(
-                "Fetching computation even though activity beginnings have not been computed yet",
-            )
+        let incompatible_indexes = chained_activities
             .iter()
-            .map(|insertion_cost| insertion_cost.beginning.total_minutes())
+            .flat_map(|(chained_activity, _shift)| chained_activity.incompatible_activity_ids())
+            .filter_map(|id| index_of_id.get(&id).copied())
+            // We cannot be incompatible with ourselves
+            .filter(|&index_of_other| index_of_other != index)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
             .collect();
 
+        // The block can begin where every chained activity can begin
+        let possible_insertion_beginnings_minutes_sorted = chained_activities
+            .iter()
+            .map(|(chained_activity, shift)| {
+                chained_activity
+                    .insertion_costs()
+                    .expect(
+                        "Fetching computation even though activity beginnings have not been computed yet",
+                    )
+                    .iter()
+                    .filter_map(|insertion_cost| insertion_cost.beginning.checked_sub(*shift).ok())
+                    .map(|beginning| beginning.total_minutes())
+                    .collect::<BTreeSet<_>>()
+            })
+            .fold(None, |intersection: Option<BTreeSet<_>>, beginnings| {
+                Some(match intersection {
+                    Some(intersection) => intersection.intersection(&beginnings).copied().collect(),
+                    None => beginnings,
+                })
+            })
+            .unwrap_or_default();
+
+        let (last_activity, last_shift) = chained_activities
+            .last()
+            .expect("A block contains at least one activity");
+
+        // Activities with a duration range do not last as long at every beginning.
+        // Chains always last their duration.
+        let mut durations_given_beginning_minutes = HashMap::new();
+        if activity.metadata.chain().is_none() {
+            for insertion_cost in activity.insertion_costs().iter().flatten() {
+                if insertion_cost.duration != activity.duration() {
+                    durations_given_beginning_minutes.insert(
+                        insertion_cost.beginning.total_minutes(),
+                        insertion_cost.duration.total_minutes(),
+                    );
+                }
+            }
+            if let Some(insertion_interval) = computation_data.insertion_interval() {
+                durations_given_beginning_minutes.insert(
+                    insertion_interval.beginning().total_minutes(),
+                    insertion_interval.duration().total_minutes(),
+                );
+            }
+        }
+
         let static_data = ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted,
             indexes_of_incompatible_activities: incompatible_indexes,
-            duration_minutes: computation_data.duration().total_minutes(),
+            duration_minutes: (*last_shift + last_activity.duration()).total_minutes(),
+            durations_given_beginning_minutes,
+            transition_buffer_minutes: chained_activities
+                .iter()
+                .map(|(chained_activity, _shift)| chained_activity.transition_buffer())
+                .max()
+                .unwrap_or_default()
+                .total_minutes(),
+            // The preferences of the participants of a chain apply to the whole block
+            preferences_of_participants: chained_activities
+                .iter()
+                .flat_map(|(chained_activity, _shift)| {
+                    chained_activity
+                        .computation_data
+                        .preferences_of_participants()
+                        .clone()
+                })
+                .collect(),
+            break_rules_of_participants: participant_break_rules(
+                index,
+                &chained_activities_of_each_block,
+            ),
+            index_of_previous_session: activity.sessions().and_then(|sessions| {
+                sessions
+                    .previous_session_of(activity.id())
+                    .and_then(|id| index_of_id.get(&id).copied())
+            }),
+            index_of_next_session: activity.sessions().and_then(|sessions| {
+                sessions
+                    .next_session_of(activity.id())
+                    .and_then(|id| index_of_id.get(&id).copied())
+            }),
+            min_spacing_between_sessions_minutes: activity
+                .sessions()
+                .map_or(0, |sessions| sessions.min_spacing().total_minutes()),
+            indexes_of_simultaneous_activities: activity
+                .metadata
+                .simultaneous_activity_ids()
+                .iter()
+                .filter_map(|id| index_of_id.get(id).copied())
+                .collect(),
         };
 
         static_data_vec.push(static_data);
@@ -74,6 +162,76 @@ pub fn activities_into_computation_data(
     (static_data_vec, insertion_data_vec)
 }
 
+/// Returns the activities of the block which begins with the given activity, along with
+/// the time between the beginning of the block and their own beginning.
+///
+/// If the activity is not chained, the block only contains the activity itself.
+#[must_use]
+pub(crate) fn chained_activities_with_shifts<'a>(
+    activity: &Activity,
+    activities: &'a [Activity],
+) -> Vec<(&'a Activity, Duration)> {
+    let find = |id| {
+        activities
+            .iter()
+            .find(|other| other.id() == id)
+            .expect("Chained activity does not exist")
+    };
+
+    if let Some(chain) = activity.metadata.chain() {
+        let chained_activities = chain
+            .activity_ids()
+            .iter()
+            .map(|&id| find(id))
+            .collect::<Vec<_>>();
+        let durations = chained_activities
+            .iter()
+            .map(|chained_activity| chained_activity.duration())
+            .collect::<Vec<_>>();
+
+        chained_activities
+            .into_iter()
+            .zip(chain.shifts(&durations))
+            .collect()
+    } else {
+        vec![(find(activity.id()), Duration::default())]
+    }
+}
+
+/// Returns the break rules of the participants of the block with given index, along with the
+/// indexes of the other blocks in which they take part.
+fn participant_break_rules(
+    index: usize,
+    chained_activities_of_each_block: &[Vec<(&Activity, Duration)>],
+) -> Vec<ParticipantBreakRules> {
+    let mut participants = HashSet::new();
+    chained_activities_of_each_block[index]
+        .iter()
+        .flat_map(|(chained_activity, _shift)| {
+            chained_activity
+                .computation_data
+                .break_rules_of_participants()
+                .iter()
+        })
+        // Participants of several chained activities are only counted once
+        .filter(|(participant, _)| participants.insert(*participant))
+        .map(|(participant, break_rules)| ParticipantBreakRules {
+            break_rules: break_rules.clone(),
+            indexes_of_activities: chained_activities_of_each_block
+                .iter()
+                .enumerate()
+                .filter(|(index_of_other, other_chained_activities)| {
+                    *index_of_other != index
+                        && other_chained_activities.iter().any(|(other, _shift)| {
+                            other.metadata.entity_ids().contains(participant)
+                        })
+                })
+                .map(|(index_of_other, _)| index_of_other)
+                .collect(),
+        })
+        .collect()
+}
+
 /// Given a number of activities, returns the index -> id conversion performed when activities are
 /// turned into computation data.
 #[must_use]
@@ -95,28 +253,94 @@ pub fn index_to_id_map(activities: &[Activity]) -> HashMap<usize, ActivityId> {
 pub fn activities_sorted_filtered_for_computation(activities: &[Activity]) -> Vec<Activity> {
     // Split inserted and non inserted activities.
     // Inserted activities are put first as the insertion order is fixed.
-    let inserted_activities = activities
-        .iter()
+    // Chained activities are represented by the first activity of their chain
+    let blocks = activities.iter().filter(|activity| {
+        activity
+            .metadata
+            .chain()
+            .as_ref()
+            .map_or(true, |chain| chain.head_id() == activity.id())
+    });
+
+    let inserted_activities = blocks
+        .clone()
         // Inserted activities always have a non-null duration and participants
         .filter(|activity| activity.computation_data.insertion_interval().is_some());
 
-    let mut non_inserted_activities = activities
-        .iter()
+    let mut non_inserted_activities = blocks
         .filter(|activity| {
-            activity.computation_data.insertion_interval().is_none() && activity.can_be_inserted()
+            activity.computation_data.insertion_interval().is_none()
+                && chained_activities_with_shifts(activity, activities)
+                    .iter()
+                    .all(|(chained_activity, _shift)| chained_activity.can_be_inserted())
         })
         .collect::<Vec<_>>();
 
     // Harder to insert activities should be inserted first - insertion order is fixed
+    // Chains are as long as their whole block
     non_inserted_activities.sort_by_key(|activity| {
+        let block_duration = chained_activities_with_shifts(activity, activities)
+            .last()
+            .map_or(activity.duration(), |(last_activity, shift)| {
+                *shift + last_activity.duration()
+            });
         std::cmp::Reverse(
-            activity.computation_data.duration().total_minutes() as usize
+            block_duration.total_minutes() as usize
                 * activity.computation_data.incompatible_activity_ids().len(),
         )
     });
 
+    // Sessions of a split activity are inserted together, in order
+    let mut non_inserted_activities_with_sessions_together: Vec<&Activity> =
+        Vec::with_capacity(non_inserted_activities.len());
+    for activity in &non_inserted_activities {
+        if non_inserted_activities_with_sessions_together
+            .iter()
+            .any(|other| other.id() == activity.id())
+        {
+            // Already added with the other sessions
+            continue;
+        }
+
+        if let Some(sessions) = activity.metadata.sessions() {
+            for &session_id in sessions.session_ids() {
+                if let Some(session) = non_inserted_activities
+                    .iter()
+                    .find(|other| other.id() == session_id)
+                {
+                    non_inserted_activities_with_sessions_together.push(session);
+                }
+            }
+        } else {
+            non_inserted_activities_with_sessions_together.push(activity);
+        }
+    }
+
+    // Simultaneous activities are inserted together
+    let mut non_inserted_activities_with_simultaneous_together: Vec<&Activity> =
+        Vec::with_capacity(non_inserted_activities.len());
+    for activity in &non_inserted_activities_with_sessions_together {
+        if non_inserted_activities_with_simultaneous_together
+            .iter()
+            .any(|other| other.id() == activity.id())
+        {
+            // Already added with the simultaneous activities
+            continue;
+        }
+
+        non_inserted_activities_with_simultaneous_together.push(activity);
+        for &simultaneous_id in activity.metadata.simultaneous_activity_ids() {
+            if let Some(simultaneous_activity) = non_inserted_activities_with_sessions_together
+                .iter()
+                .find(|other| other.id() == simultaneous_id)
+            {
+                non_inserted_activities_with_simultaneous_together.push(simultaneous_activity);
+            }
+        }
+    }
+
     inserted_activities
-        .chain(non_inserted_activities)
+        .chain(non_inserted_activities_with_simultaneous_together)
         .cloned()
         .collect()
 }
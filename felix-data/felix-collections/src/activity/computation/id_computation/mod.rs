@@ -39,8 +39,8 @@ pub fn compute_incompatible_ids(
         .filter(|other_metadata| {
             metadata.id() != other_metadata.id()
                 && metadata
-                    .entities_as_set()
-                    .intersection(other_metadata.entities_as_set())
+                    .entity_ids()
+                    .intersection(other_metadata.entity_ids())
                     .next()
                     != None
         })
@@ -1,8 +1,9 @@
-use felix_datatypes::{ActivityBeginningsGivenDurationMinutes, Time};
+use felix_datatypes::{ActivityBeginningsGivenDurationMinutes, Duration, TimeOfDay};
 
 use std::collections::{HashMap, HashSet};
 
-pub type ActivityBeginningsGivenDuration = HashMap<Time, HashSet<Time>>;
+/// The key is the minimum duration of the activity and the duration it is inserted with.
+pub type ActivityBeginningsGivenDuration = HashMap<(Duration, Duration), HashSet<TimeOfDay>>;
 
 /// Translates ActivityBeginningsGivenDurationMinutes to ActivityBeginningsGivenDuration (hours AND
 /// minutes.
@@ -10,13 +11,18 @@ pub fn new_activity_beginnings_given_duration(
     activity_beginnings_given_duration_minutes: ActivityBeginningsGivenDurationMinutes,
 ) -> ActivityBeginningsGivenDuration {
     let mut res = ActivityBeginningsGivenDuration::new();
-    for (activity_duration, possible_beginnings) in activity_beginnings_given_duration_minutes {
+    for ((min_duration, activity_duration), possible_beginnings) in
+        activity_beginnings_given_duration_minutes
+    {
         res.insert(
-            Time::from_total_minutes(activity_duration),
+            (
+                Duration::from_total_minutes(min_duration),
+                Duration::from_total_minutes(activity_duration),
+            ),
             possible_beginnings
                 .iter()
                 .map(|&possible_beginning_minutes| {
-                    Time::from_total_minutes(possible_beginning_minutes)
+                    TimeOfDay::from_total_minutes(possible_beginning_minutes)
                 })
                 .collect(),
         );
@@ -1,11 +1,14 @@
 use crate::Activity;
-use felix_datatypes::{InsertionCost, Time};
+use felix_datatypes::{
+    Duration, InsertionCost, TimeInterval, TimeOfDay, MIN_TIME_DISCRETIZATION_MINUTES,
+};
 
 use felix_computation_api::compute_insertion_costs;
 
 use super::{
     super::activities_into_computation_data::{
         activities_into_computation_data, activities_sorted_filtered_for_computation,
+        chained_activities_with_shifts,
     },
     PossibleBeginningsPool,
 };
@@ -27,12 +30,17 @@ pub(super) fn poll_and_fuse_possible_beginnings(
     possible_beginnings_pool: Arc<Mutex<PossibleBeginningsPool>>,
 ) -> bool {
     if let Ok(activities) = activities.lock() {
-        // Sort and filter activities in computation form
-        let activities_sorted_filtered = activities_sorted_filtered_for_computation(&activities);
+        // Chained activities are not in computation form on their own,
+        // but their possible beginnings are needed to compute the ones of their chain
+        let activities_which_can_be_inserted = activities
+            .iter()
+            .filter(|activity| activity.can_be_inserted())
+            .cloned()
+            .collect::<Vec<_>>();
 
         let maybe_all_possible_beginnings_for_each_activity = possible_beginnings_for_activities(
             possible_beginnings_pool,
-            &activities_sorted_filtered,
+            &activities_which_can_be_inserted,
         );
         if let Some(all_possible_beginnings_for_each_activity) =
             maybe_all_possible_beginnings_for_each_activity
@@ -40,11 +48,11 @@ pub(super) fn poll_and_fuse_possible_beginnings(
             // Intersect all possible beginnings for each activity
             merge_beginnings_of_all_participants_of_each_activity(
                 all_possible_beginnings_for_each_activity,
-                &activities_sorted_filtered,
+                &activities_which_can_be_inserted,
             );
 
             // Once every merge has been done, compute insertion costs
-            compute_insertion_costs_for_each_activity(&activities_sorted_filtered);
+            compute_insertion_costs_for_each_activity(&activities);
         }
         true
     } else {
@@ -55,31 +63,40 @@ pub(super) fn poll_and_fuse_possible_beginnings(
 /// Fetches the possible beginnings of every activity, not taking conflicts into account.
 /// If one result has not been computed, returns None (activities have been modified and this
 /// function will be called again once the results have been computed).
-/// Each activity has a Vec of HashSet of time, one per entity.
+/// Each activity has a Vec of HashSet of time per entity, one set for each duration it may have
+/// (see possible_durations_of).
 #[must_use]
 fn possible_beginnings_for_activities(
     possible_beginnings_pool: Arc<Mutex<PossibleBeginningsPool>>,
     activities: &[Activity],
-) -> Option<Vec<Vec<HashSet<Time>>>> {
+) -> Option<Vec<Vec<Vec<HashSet<TimeOfDay>>>>> {
     let pool = possible_beginnings_pool.lock().unwrap();
 
     activities
         .iter()
         .map(|activity| {
+            let possible_durations = possible_durations_of(activity);
             // Get possible beginnings
             activity
                 .computation_data
                 .schedules_of_participants()
                 .iter()
                 .map(|work_hours_and_activity_durations| {
-                    // HashMap<WorkHoursAndActivityDurationsSorted, HashMap<Time, HashSet<Time>>
+                    // HashMap<WorkHoursAndActivityDurationsSorted, HashMap<(Duration, Duration), HashSet<TimeOfDay>>
                     pool.get(work_hours_and_activity_durations)
-                        // HashMap<Time, HashSet<Time>>
-                        .and_then(|possible_beginnings_given_duration| {
-                            possible_beginnings_given_duration
-                                .get(&activity.duration())
-                                .cloned()
-                            // HashSet<Time>
+                        // HashMap<(Duration, Duration), HashSet<TimeOfDay>>
+                        .map(|possible_beginnings_given_duration| {
+                            possible_durations
+                                .iter()
+                                .map(|&duration| {
+                                    // Durations which fit nowhere are not stored
+                                    possible_beginnings_given_duration
+                                        .get(&(activity.duration(), duration))
+                                        .cloned()
+                                        .unwrap_or_default()
+                                })
+                                // Vec<HashSet<TimeOfDay>>
+                                .collect()
                         })
                     // Bring option out of the vec
                 })
@@ -88,13 +105,32 @@ fn possible_beginnings_for_activities(
         .collect()
 }
 
+/// Returns every duration the activity may have, in ascending order.
+/// Chained activities always last their duration so that the chain keeps its shape.
+#[must_use]
+fn possible_durations_of(activity: &Activity) -> Vec<Duration> {
+    if activity.chain().is_some() {
+        return vec![activity.duration()];
+    }
+    (activity.duration().total_minutes()..=activity.max_duration().total_minutes())
+        .step_by(MIN_TIME_DISCRETIZATION_MINUTES.into())
+        .map(Duration::from_total_minutes)
+        .collect()
+}
+
 /// For each activity in the activity slice, fuses the possible beginnings of all its
 /// participant (each participant has a set of times in which they can put the activity).
+/// If the activity may have several durations, the duration chosen for each beginning is the
+/// best one according to its duration objective among the ones which fit all participants.
+/// Only this duration is kept for the beginning, and it assumes that the other activities of
+/// the participants last their minimum duration (see find_possible_beginnings).
+/// If the activity needs a minimum number of participants, beginnings at which too few
+/// optional participants are free are discarded.
 /// The result is stored directly in the activity.
 ///
 /// The activities and possible beginnings are parallel arrays.
 fn merge_beginnings_of_all_participants_of_each_activity(
-    mut all_possible_beginnings: Vec<Vec<HashSet<Time>>>,
+    mut all_possible_beginnings: Vec<Vec<Vec<HashSet<TimeOfDay>>>>,
     activities: &[Activity],
 ) {
     assert!(activities.len() == all_possible_beginnings.len());
@@ -103,29 +139,65 @@ fn merge_beginnings_of_all_participants_of_each_activity(
     for (possible_beginnings_for_this_activity, activity) in
         all_possible_beginnings.iter_mut().zip(activities)
     {
-        // Sort sets by ascending size so that fewer checks are done for intersections
-        possible_beginnings_for_this_activity.sort_by_key(|a| a.len());
+        let possible_durations = possible_durations_of(activity);
 
-        let first_set = possible_beginnings_for_this_activity.first();
+        // For each duration, the beginnings which fit every participant
+        let beginnings_for_each_duration = possible_durations
+            .iter()
+            .enumerate()
+            .map(|(duration_index, _)| {
+                let mut sets = possible_beginnings_for_this_activity
+                    .iter_mut()
+                    .map(|sets_of_participant| {
+                        std::mem::take(&mut sets_of_participant[duration_index])
+                    })
+                    .collect::<Vec<_>>();
+                // Sort sets by ascending size so that fewer checks are done for intersections
+                sets.sort_by_key(|a| a.len());
 
-        let insertion_scores = Some(if let Some(first_set) = first_set {
-            first_set
-                .iter()
-                .filter(|time| {
-                    possible_beginnings_for_this_activity[1..]
+                if let Some(first_set) = sets.first() {
+                    first_set
                         .iter()
-                        .all(|set| set.contains(time))
-                })
-                // Map into dummy scores to fetch computation and to calculate scores properly
-                .map(|&time| InsertionCost {
-                    beginning: time,
-                    cost: 0,
+                        .filter(|time| sets[1..].iter().all(|set| set.contains(time)))
+                        .copied()
+                        .collect::<HashSet<_>>()
+                } else {
+                    // Possible beginnings have been computed and there are none
+                    HashSet::new()
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let beginnings = beginnings_for_each_duration
+            .iter()
+            .flatten()
+            .copied()
+            .collect::<HashSet<_>>();
+        let insertion_scores = Some(
+            beginnings
+                .into_iter()
+                .filter_map(|time| {
+                    let best_duration = activity.duration_objective().best_duration(
+                        possible_durations
+                            .iter()
+                            .zip(&beginnings_for_each_duration)
+                            .filter(|(&duration, beginnings)| {
+                                beginnings.contains(&time)
+                                    && has_enough_participants_if_inserted_at(
+                                        activity, activities, time, duration,
+                                    )
+                            })
+                            .map(|(duration, _)| duration.total_minutes()),
+                    )?;
+                    // Map into dummy scores to fetch computation and to calculate scores properly
+                    Some(InsertionCost {
+                        beginning: time,
+                        cost: 0,
+                        duration: Duration::from_total_minutes(best_duration),
+                    })
                 })
-                .collect()
-        } else {
-            // Possible beginnings have been computed and there are none
-            Vec::<InsertionCost>::new()
-        });
+                .collect::<Vec<_>>(),
+        );
 
         // Check if any thread panicked while holding the mutex
         if let Ok(mut costs) = activity.computation_data.insertion_costs().lock() {
@@ -134,42 +206,132 @@ fn merge_beginnings_of_all_participants_of_each_activity(
     }
 }
 
+/// Returns true if the activity has at least its minimum number of participants
+/// if inserted at the given time with the given duration.
+///
+/// Optional participants count if the activity fits in their work hours and they do not take
+/// part in an overlapping inserted activity.
+#[must_use]
+fn has_enough_participants_if_inserted_at(
+    activity: &Activity,
+    activities: &[Activity],
+    beginning: TimeOfDay,
+    duration: Duration,
+) -> bool {
+    let min_participants = if let Some(min_participants) = activity.min_participants() {
+        min_participants
+    } else {
+        return true;
+    };
+    let mandatory_participants = activity.entity_ids().len();
+    if mandatory_participants >= min_participants {
+        return true;
+    }
+
+    let interval = TimeInterval::new(beginning, beginning + duration);
+    let free_optional_participants = activity
+        .computation_data
+        .work_hours_of_optional_participants()
+        .iter()
+        .filter(|(entity_id, work_hours)| {
+            work_hours
+                .iter()
+                .any(|work_interval| work_interval.contains_interval(interval))
+                && !activities.iter().any(|other| {
+                    other.id() != activity.id()
+                        && other.entity_ids().contains(entity_id)
+                        && other
+                            .insertion_interval()
+                            .iter()
+                            .any(|other_interval| other_interval.overlaps_with(&interval))
+                })
+        })
+        .count();
+    mandatory_participants + free_optional_participants >= min_participants
+}
+
 /// For each activity, compute its insertion scores and stores them directly in the activity.
 /// If the activity is inserted, this function acts as if the activity isn't.
-fn compute_insertion_costs_for_each_activity(activities: &[Activity]) {
-    let (static_data, insertion_data) = activities_into_computation_data(activities);
+///
+/// The insertion scores of a chain are computed once, then shifted for each of its activities.
+fn compute_insertion_costs_for_each_activity(all_activities: &[Activity]) {
+    let (static_data, insertion_data) = activities_into_computation_data(all_activities);
 
     // We can iterate in the right order because activities are sorted the same way as they
     // are in computation form
+    let activities = activities_sorted_filtered_for_computation(all_activities);
+    let insertion_costs_minutes_for_each_activity = activities
+        .iter()
+        .enumerate()
+        .map(|(index, activity)| {
+            if activity.insertion_interval().is_some() {
+                // Activity is inserted. Act as if it isn't !
+
+                // Remove the activity from insertion data
+                let mut insertion_data = insertion_data.clone();
+                insertion_data.remove(index);
+
+                // To keep parallel arrays coherent, move the activity to the end of the static data.
+                let mut static_data = static_data.clone();
+                let mut activity = static_data.remove(index);
+                // Simultaneous activities move along with it
+                activity.indexes_of_simultaneous_activities.clear();
+                static_data.push(activity);
+
+                // Update the index of the activity
+                let index = static_data.len() - 1;
+                compute_insertion_costs(&static_data, &insertion_data, index)
+            } else {
+                compute_insertion_costs(&static_data, &insertion_data, index)
+            }
+        })
+        .collect::<Vec<_>>();
+
     for (index, activity) in activities.iter().enumerate() {
-        let insertion_costs_minutes = if activity.insertion_interval().is_some() {
-            // Activity is inserted. Act as if it isn't !
-
-            // Remove the activity from insertion data
-            let mut insertion_data = insertion_data.clone();
-            insertion_data.remove(index);
-
-            // To keep parallel arrays coherent, move the activity to the end of the static data.
-            let mut static_data = static_data.clone();
-            let activity = static_data.remove(index);
-            static_data.push(activity);
-
-            // Update the index of the activity
-            let index = static_data.len() - 1;
-            compute_insertion_costs(&static_data, &insertion_data, index)
-        } else {
-            compute_insertion_costs(&static_data, &insertion_data, index)
-        };
-
-        let insertion_costs = insertion_costs_minutes
-            .into_iter()
-            .map(|insertion_cost_minutes| {
-                InsertionCost::from_insertion_cost_minutes(insertion_cost_minutes)
+        // Simultaneous activities can only be inserted where all of them fit
+        let indexes_of_simultaneous_activities = static_data[index]
+            .indexes_of_simultaneous_activities
+            .clone();
+
+        let insertion_costs = insertion_costs_minutes_for_each_activity[index]
+            .iter()
+            .filter_map(|insertion_cost_minutes| {
+                indexes_of_simultaneous_activities.iter().try_fold(
+                    *insertion_cost_minutes,
+                    |mut insertion_cost_minutes, &simultaneous_index| {
+                        insertion_costs_minutes_for_each_activity[simultaneous_index]
+                            .iter()
+                            .find(|other| {
+                                other.beginning_minutes == insertion_cost_minutes.beginning_minutes
+                            })
+                            .map(|other| {
+                                insertion_cost_minutes.cost += other.cost;
+                                insertion_cost_minutes
+                            })
+                    },
+                )
             })
-            .collect();
+            .map(InsertionCost::from_insertion_cost_minutes)
+            .collect::<Vec<_>>();
 
-        if let Ok(mut costs) = activity.computation_data.insertion_costs().lock() {
-            *costs = Some(insertion_costs);
+        for (chained_activity, shift) in chained_activities_with_shifts(activity, all_activities) {
+            let shifted_insertion_costs = insertion_costs
+                .iter()
+                .map(|insertion_cost| InsertionCost {
+                    beginning: insertion_cost.beginning + shift,
+                    cost: insertion_cost.cost,
+                    // Chained activities do not take the length of the whole chain
+                    duration: if chained_activity.chain().is_some() {
+                        chained_activity.duration()
+                    } else {
+                        insertion_cost.duration
+                    },
+                })
+                .collect();
+
+            if let Ok(mut costs) = chained_activity.computation_data.insertion_costs().lock() {
+                *costs = Some(shifted_insertion_costs);
+            }
         }
     }
 }
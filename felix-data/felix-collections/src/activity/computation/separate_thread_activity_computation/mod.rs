@@ -133,7 +133,7 @@ impl SeparateThreadActivityComputation {
 }
 
 /// Sets the possible insertion costs of the activities to None.
-fn invalidate_activities(activities: Arc<Mutex<Vec<Activity>>>) {
+pub(crate) fn invalidate_activities(activities: Arc<Mutex<Vec<Activity>>>) {
     // TODO move this elsewhere
     for activity in activities
         .lock()
@@ -1,17 +1,29 @@
 mod activities;
+mod activity_chain;
 mod activity_computation_data;
+mod activity_filter;
 mod activity_metadata;
+mod activity_sessions;
+mod activity_template;
 mod computation;
 
-use felix_datatypes::{ActivityId, ActivityInsertionCosts, Rgba, Time, TimeInterval};
+use felix_datatypes::{
+    ActivityId, ActivityInsertionCosts, Details, Duration, DurationObjective, EntityId, GroupId,
+    Rgba, TimeInterval, TimeOfDay,
+};
 
 use activity_computation_data::ActivityComputationData;
 
-pub use activities::Activities;
+pub use activities::{Activities, ActivitiesSnapshot};
+pub use activity_chain::ActivityChain;
+pub use activity_filter::ActivityFilter;
 pub use activity_metadata::ActivityMetadata;
+pub use activity_sessions::{ActivitySessions, SessionRules};
+pub use activity_template::ActivityTemplate;
 pub use computation::activities_into_computation_data::{
     activities_into_computation_data, activities_sorted_filtered_for_computation,
 };
+pub(crate) use computation::id_computation::generate_next_id;
 
 use serde::{Deserialize, Serialize};
 
@@ -53,24 +65,87 @@ impl Activity {
         self.metadata.name().clone()
     }
 
-    /// Simple getter for the entities. The entities are sorted by name.
+    /// Simple getter for the ids of the entities.
+    /// Use the Data object to get their names.
+    #[must_use]
+    pub fn entity_ids(&self) -> Vec<EntityId> {
+        self.metadata.entity_ids().iter().copied().collect()
+    }
+
+    /// Simple getter for the ids of the optional participants, which attend the activity
+    /// only if they are free.
+    /// Use the Data object to get their names.
+    #[must_use]
+    pub fn optional_entity_ids(&self) -> Vec<EntityId> {
+        self.metadata
+            .optional_entity_ids()
+            .iter()
+            .copied()
+            .collect()
+    }
+
+    /// Simple getter for the minimum number of participants, optional participants included.
+    /// Returns None if there is no minimum.
     #[must_use]
-    pub fn entities_sorted(&self) -> Vec<String> {
-        self.metadata.entities_sorted()
+    pub fn min_participants(&self) -> Option<usize> {
+        self.metadata.min_participants()
     }
 
-    /// Simple getter for the groups. The groups are sorted by name.
+    /// Simple getter for the maximum number of participants, optional participants included.
+    /// Returns None if there is no maximum.
     #[must_use]
-    pub fn groups_sorted(&self) -> Vec<String> {
-        self.metadata.groups_sorted()
+    pub fn max_participants(&self) -> Option<usize> {
+        self.metadata.max_participants()
+    }
+
+    /// Simple getter for the ids of the groups.
+    /// Use the Data object to get their names.
+    #[must_use]
+    pub fn group_ids(&self) -> Vec<GroupId> {
+        self.metadata.group_ids().iter().copied().collect()
     }
 
     /// Simple getter for the duration.
     #[must_use]
-    pub fn duration(&self) -> Time {
+    pub fn duration(&self) -> Duration {
         self.computation_data.duration()
     }
 
+    /// Returns the longest duration the activity may have.
+    /// If the activity has no duration range, this is its duration.
+    #[must_use]
+    pub fn max_duration(&self) -> Duration {
+        self.computation_data.max_duration()
+    }
+
+    /// Returns the duration the activity would have if inserted at the given time.
+    /// Activities with a duration range last as long as their insertion cost at this time
+    /// states.
+    #[must_use]
+    pub fn duration_if_inserted_at(&self, time: TimeOfDay) -> Duration {
+        self.computation_data.duration_if_inserted_at(time)
+    }
+
+    /// Returns true if the activity may last anywhere in a duration range.
+    #[must_use]
+    pub fn has_flexible_duration(&self) -> bool {
+        self.max_duration() > self.duration()
+    }
+
+    /// Simple getter for the duration objective, used to choose the duration of activities
+    /// with a duration range.
+    #[must_use]
+    pub fn duration_objective(&self) -> DurationObjective {
+        self.computation_data.duration_objective()
+    }
+
+    /// Simple getter for the minimum free time between this activity and any
+    /// incompatible activity.
+    #[must_use]
+    pub fn transition_buffer(&self) -> Duration {
+        self.computation_data.transition_buffer()
+    }
+
     /// Simple getter for the insertion interval.
     /// Returns None if the activity is not inserted.
     #[must_use]
@@ -81,7 +156,7 @@ impl Activity {
     /// Returns true if the activity has a non-null duration and at least one participant.
     #[must_use]
     pub fn can_be_inserted(&self) -> bool {
-        self.duration() > Time::new(0, 0) && !self.entities_sorted().is_empty()
+        self.duration() > Duration::default() && !self.metadata.entity_ids().is_empty()
     }
 
     /// Simple getter for the color.
@@ -96,6 +171,77 @@ impl Activity {
         self.computation_data.incompatible_activity_ids()
     }
 
+    /// Simple getter for the sessions.
+    /// Returns None if the activity was not split into sessions.
+    #[must_use]
+    pub fn sessions(&self) -> Option<ActivitySessions> {
+        self.metadata.sessions().clone()
+    }
+
+    /// Returns the id of the main session of the activity.
+    /// If the activity was not split, this is its own id.
+    #[must_use]
+    pub fn main_session_id(&self) -> ActivityId {
+        self.metadata
+            .sessions()
+            .as_ref()
+            .map_or(self.id(), ActivitySessions::main_session_id)
+    }
+
+    /// Returns the number of times the activity takes place.
+    /// If the activity is not recurring, it takes place once.
+    #[must_use]
+    pub fn number_of_occurrences(&self) -> usize {
+        self.metadata
+            .sessions()
+            .as_ref()
+            .filter(|sessions| sessions.is_recurring())
+            .map_or(1, |sessions| sessions.session_ids().len())
+    }
+
+    /// Simple getter for the ids of the activities which must begin at the same time
+    /// as this one. The ids are sorted.
+    #[must_use]
+    pub fn simultaneous_activity_ids(&self) -> Vec<ActivityId> {
+        self.metadata
+            .simultaneous_activity_ids()
+            .iter()
+            .copied()
+            .collect()
+    }
+
+    /// Simple getter for the chain.
+    /// Returns None if the activity is not chained to other activities.
+    #[must_use]
+    pub fn chain(&self) -> Option<ActivityChain> {
+        self.metadata.chain().clone()
+    }
+
+    /// Simple getter for the category.
+    /// Returns None if the activity has no category.
+    #[must_use]
+    pub fn category(&self) -> Option<String> {
+        self.metadata.category().clone()
+    }
+
+    /// Simple getter for the details (notes, email, tags, custom fields).
+    #[must_use]
+    pub fn details(&self) -> &Details {
+        self.metadata.details()
+    }
+
+    /// Simple getter for the description. Empty if the activity has no description.
+    #[must_use]
+    pub fn description(&self) -> String {
+        self.metadata.description().clone()
+    }
+
+    /// Simple getter for the location. Empty if the activity has no location.
+    #[must_use]
+    pub fn location(&self) -> String {
+        self.metadata.location().clone()
+    }
+
     /// Returns the possible insertion times with their respective costs.
     /// If None is returned, then they haven't been computed yet.
     #[must_use]
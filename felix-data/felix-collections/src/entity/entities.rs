@@ -1,12 +1,16 @@
+use crate::activity::generate_next_id;
 use crate::Entity;
+use felix_datatypes::{Details, EntityId, LoadLimits};
 use felix_errors::{does_not_exist::DoesNotExist, name_taken::NameTaken, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::hash_map::{Entry, HashMap};
+use std::collections::HashMap;
 
 /// Manages the entities. Makes sure there are no duplicates.
+///
+/// Entities are stored by id. Their names are unique as well.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Entities {
-    entities: HashMap<String, Entity>,
+    entities: HashMap<EntityId, Entity>,
 }
 
 impl Entities {
@@ -32,54 +36,94 @@ impl Entities {
     ///
     /// Returns Err if the entity does not exist.
     pub fn get_by_name(&self, name: &str) -> Result<Entity> {
-        match self.entities.get(name) {
+        match self.entities.values().find(|entity| entity.name == name) {
             Some(entity) => Ok(entity.clone()),
             None => Err(DoesNotExist::entity_does_not_exist(name)),
         }
     }
 
-    // This function may be useful when entities hold more data than their name (e.g. a count)
+    /// Returns a copy of the entity with the given id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entity does not exist.
+    #[must_use]
+    pub fn get_by_id(&self, id: EntityId) -> Entity {
+        self.entities
+            .get(&id)
+            .expect("Asking for entity which does not exist")
+            .clone()
+    }
 
-    // Returns a mutable reference to the entity with the given name.
-    //
-    // # Errors
-    //
-    // Returns Err if the entity does not exist.
-    //
-    // Keep this function private !
-    // No mutable access to elements of the collection should be granted.
-    //fn get_mut_by_name(&mut self, name: &str) -> Result<&mut Entity> {
-    //    match self.entities.get_mut(name) {
-    //        Some(entity) => Ok(entity),
-    //        None => Err(DoesNotExist::entity_does_not_exist(name)),
-    //    }
-    //}
+    /// Returns the names of the entities with the given ids, sorted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the entities does not exist.
+    #[must_use]
+    pub fn names_sorted<'a, I>(&self, ids: I) -> Vec<String>
+    where
+        I: IntoIterator<Item = &'a EntityId>,
+    {
+        let mut names = ids
+            .into_iter()
+            .map(|id| {
+                self.entities
+                    .get(id)
+                    .expect("Asking for entity which does not exist")
+                    .name()
+            })
+            .collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+
+    /// Returns a mutable reference to the entity with the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity does not exist.
+    ///
+    /// Keep this function private !
+    /// No mutable access to elements of the collection should be granted.
+    fn get_mut_by_name(&mut self, name: &str) -> Result<&mut Entity> {
+        match self
+            .entities
+            .values_mut()
+            .find(|entity| entity.name == name)
+        {
+            Some(entity) => Ok(entity),
+            None => Err(DoesNotExist::entity_does_not_exist(name)),
+        }
+    }
 
     /// Adds an entity with the given name.
+    /// Automatically assigns a unique id.
+    /// Returns the id of the new entity.
     ///
     /// # Errors
     ///
     /// Returns Err if the name is already taken.
-    pub fn add(&mut self, name: String) -> Result<()> {
-        match self.entities.entry(name.clone()) {
-            Entry::Occupied(_) => Err(NameTaken::name_taken_by_entity(name)),
-            Entry::Vacant(v) => {
-                v.insert(Entity::new(name));
-                Ok(())
-            }
+    pub fn add(&mut self, name: String) -> Result<EntityId> {
+        if self.get_by_name(&name).is_ok() {
+            Err(NameTaken::name_taken_by_entity(name))
+        } else {
+            let id = generate_next_id(self.entities.keys().copied().collect());
+            self.entities.insert(id, Entity::new(id, name));
+            Ok(id)
         }
     }
 
     /// Removes the entity with the given name.
+    /// Returns the id of the removed entity.
     ///
     /// # Errors
     ///
     /// Returns Err if the entity does not exist.
-    pub fn remove(&mut self, name: &str) -> Result<()> {
-        match self.entities.remove(name) {
-            Some(_) => Ok(()),
-            None => Err(DoesNotExist::entity_does_not_exist(name)),
-        }
+    pub fn remove(&mut self, name: &str) -> Result<EntityId> {
+        let id = self.get_by_name(name)?.id();
+        self.entities.remove(&id);
+        Ok(id)
     }
 
     /// Renames the entity with the given name.
@@ -88,21 +132,33 @@ impl Entities {
     ///
     /// Returns Err if the entity does not exist or if the new name is already taken.
     pub fn set_name_of(&mut self, old_name: &str, new_name: String) -> Result<()> {
-        match self.entities.entry(new_name.clone()) {
-            Entry::Occupied(_) => Err(NameTaken::name_taken_by_entity(new_name)),
-            Entry::Vacant(_) => {
-                // We have to change the key and the value
-                match self.entities.remove(old_name) {
-                    Some(mut entity) => {
-                        entity.set_name(new_name.clone());
-                        self.entities.insert(new_name, entity);
-                        Ok(())
-                    }
-                    None => Err(DoesNotExist::entity_does_not_exist(old_name)),
-                }
-            }
+        if self.get_by_name(&new_name).is_ok() {
+            Err(NameTaken::name_taken_by_entity(new_name))
+        } else {
+            self.get_mut_by_name(old_name)?.set_name(new_name);
+            Ok(())
         }
     }
+
+    /// Sets the load limits of the entity with the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity does not exist.
+    pub fn set_load_limits_of(&mut self, name: &str, load_limits: LoadLimits) -> Result<()> {
+        self.get_mut_by_name(name)?.set_load_limits(load_limits);
+        Ok(())
+    }
+
+    /// Sets the details of the entity with the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity does not exist.
+    pub fn set_details_of(&mut self, name: &str, details: Details) -> Result<()> {
+        self.get_mut_by_name(name)?.set_details(details);
+        Ok(())
+    }
 }
 
 impl Default for Entities {
@@ -1,5 +1,6 @@
 mod entities;
 
+use felix_datatypes::{Details, EntityId, LoadLimits};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 
@@ -14,34 +15,75 @@ pub use entities::Entities;
 /// does not have the gift of ubiquity.
 ///
 /// Entities have unique names. An entity may not have the same name as a group.
+/// Entities are referenced by their id, which never changes, so that renaming an entity
+/// does not affect the activities, groups and work hours it is part of.
 ///
 /// This structure is read-only. If you wish to create or modify an entity, use the Data object.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Entity {
+    id: EntityId,
     name: EntityName,
+    #[serde(default)]
+    load_limits: LoadLimits,
+    #[serde(default)]
+    details: Details,
 }
 
 impl Entity {
-    /// Creates a new entity with the given name.
+    /// Creates a new entity with the given id and name.
     #[must_use]
-    fn new(name: String) -> Entity {
-        Entity { name }
+    fn new(id: EntityId, name: String) -> Entity {
+        Entity {
+            id,
+            name,
+            load_limits: LoadLimits::default(),
+            details: Details::default(),
+        }
     }
 
     // *** Getters ***
     // This is the only public API. To modify an entity, users must use the Data API.
+    /// Simple getter for the unique id.
+    #[must_use]
+    pub fn id(&self) -> EntityId {
+        self.id
+    }
+
     /// Simple getter for the name.
     #[must_use]
     pub fn name(&self) -> String {
         self.name.clone()
     }
 
+    /// Simple getter for the load limits of the entity only.
+    /// The limits of the groups it belongs to are not taken into account.
+    #[must_use]
+    pub fn load_limits(&self) -> &LoadLimits {
+        &self.load_limits
+    }
+
+    /// Simple getter for the details (notes, email, tags, custom fields).
+    #[must_use]
+    pub fn details(&self) -> &Details {
+        &self.details
+    }
+
     // *** Private Setters ***
 
     /// Sets the name of the entity.
     fn set_name(&mut self, name: String) {
         self.name = name;
     }
+
+    /// Sets the load limits of the entity.
+    fn set_load_limits(&mut self, load_limits: LoadLimits) {
+        self.load_limits = load_limits;
+    }
+
+    /// Sets the details of the entity.
+    fn set_details(&mut self, details: Details) {
+        self.details = details;
+    }
 }
 
 impl Ord for Entity {
@@ -1,16 +1,21 @@
 use super::Group;
-use felix_errors::{does_not_exist::DoesNotExist, name_taken::NameTaken, Result};
+use crate::activity::generate_next_id;
+use crate::Entity;
+use felix_datatypes::{Details, EntityId, GroupId, LoadLimits};
+use felix_errors::{
+    does_not_exist::DoesNotExist, group_cycle::GroupCycle, name_taken::NameTaken, Result,
+};
 use serde::{Deserialize, Serialize};
-use std::collections::hash_map::Entry;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 /// Manages groups.
 ///
-/// A group has a unique name and contains entities.
+/// A group has a unique id, a unique name and contains entities and other groups.
 /// A group may not have the same name as an entity.
+/// Groups never contain themselves, directly or not.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Groups {
-    groups: HashMap<String, Group>,
+    groups: HashMap<GroupId, Group>,
 }
 
 impl Groups {
@@ -36,73 +41,203 @@ impl Groups {
     ///
     /// Returns Err if the group does not exist.
     pub fn get_by_name(&self, name: &str) -> Result<Group> {
-        match self.groups.get(name) {
+        match self.groups.values().find(|group| group.name == name) {
             Some(group) => Ok(group.clone()),
             None => Err(DoesNotExist::group_does_not_exist(name)),
         }
     }
 
+    /// Returns a copy of the group with the given id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the group does not exist.
+    #[must_use]
+    pub fn get_by_id(&self, id: GroupId) -> Group {
+        self.groups
+            .get(&id)
+            .expect("Asking for group which does not exist")
+            .clone()
+    }
+
+    /// Returns the names of the groups with the given ids, sorted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the groups does not exist.
+    #[must_use]
+    pub fn names_sorted<'a, I>(&self, ids: I) -> Vec<String>
+    where
+        I: IntoIterator<Item = &'a GroupId>,
+    {
+        let mut names = ids
+            .into_iter()
+            .map(|id| {
+                self.groups
+                    .get(id)
+                    .expect("Asking for group which does not exist")
+                    .name()
+            })
+            .collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+
+    /// Returns the ids of the members of the group with the given id:
+    /// its entities and the members of its subgroups, recursively.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the group does not exist.
+    #[must_use]
+    pub fn member_ids(&self, id: GroupId) -> BTreeSet<EntityId> {
+        self.ids_of_groups_contained_in(id)
+            .into_iter()
+            .flat_map(|group_id| self.get_by_id(group_id).entities)
+            .collect()
+    }
+
+    /// Returns the id of the group with the given id and the ids of every group which
+    /// contains it, directly or not.
+    #[must_use]
+    pub fn ids_of_groups_containing_group(&self, id: GroupId) -> BTreeSet<GroupId> {
+        let mut ids = BTreeSet::new();
+        let mut to_visit = vec![id];
+        while let Some(group_id) = to_visit.pop() {
+            if ids.insert(group_id) {
+                to_visit.extend(
+                    self.groups
+                        .values()
+                        .filter(|group| group.subgroups.contains(&group_id))
+                        .map(Group::id),
+                );
+            }
+        }
+        ids
+    }
+
+    /// Returns the ids of the groups of which the entity with given id is a member,
+    /// directly or through subgroups.
+    #[must_use]
+    pub fn ids_of_groups_containing_entity(&self, entity_id: EntityId) -> BTreeSet<GroupId> {
+        self.groups
+            .values()
+            .filter(|group| group.entities.contains(&entity_id))
+            .flat_map(|group| self.ids_of_groups_containing_group(group.id()))
+            .collect()
+    }
+
+    /// Returns the id of the group with the given id and the ids of its subgroups,
+    /// recursively.
+    fn ids_of_groups_contained_in(&self, id: GroupId) -> BTreeSet<GroupId> {
+        let mut ids = BTreeSet::new();
+        let mut to_visit = vec![id];
+        while let Some(group_id) = to_visit.pop() {
+            if ids.insert(group_id) {
+                to_visit.extend(self.get_by_id(group_id).subgroups);
+            }
+        }
+        ids
+    }
+
+    /// Returns a mutable reference to the group with the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the group does not exist.
+    ///
+    /// Keep this function private !
+    /// No mutable access to elements of the collection should be granted.
+    fn get_mut_by_name(&mut self, name: &str) -> Result<&mut Group> {
+        match self.groups.values_mut().find(|group| group.name == name) {
+            Some(group) => Ok(group),
+            None => Err(DoesNotExist::group_does_not_exist(name)),
+        }
+    }
+
     /// Adds a new group with the given name.
+    /// Automatically assigns a unique id.
+    /// Returns the id of the new group.
     ///
     /// # Errors
     ///
     /// Returns Err if a group with the same name exists.
-    pub fn add(&mut self, name: String) -> Result<()> {
-        match self.groups.entry(name.clone()) {
-            Entry::Occupied(_) => Err(NameTaken::name_taken_by_group(name)),
-            Entry::Vacant(v) => {
-                v.insert(Group::new(name));
-                Ok(())
-            }
+    pub fn add(&mut self, name: String) -> Result<GroupId> {
+        if self.get_by_name(&name).is_ok() {
+            Err(NameTaken::name_taken_by_group(name))
+        } else {
+            let id = generate_next_id(self.groups.keys().copied().collect());
+            self.groups.insert(id, Group::new(id, name));
+            Ok(id)
         }
     }
 
     /// Removes the group with the given name.
+    /// Returns the id of the removed group.
     ///
     /// # Errors
     ///
     /// Returns Err if the group is not found.
-    pub fn remove(&mut self, name: &str) -> Result<()> {
-        match self.groups.remove(name) {
-            Some(_) => Ok(()),
-            None => Err(DoesNotExist::group_does_not_exist(name)),
+    pub fn remove(&mut self, name: &str) -> Result<GroupId> {
+        let id = self.get_by_name(name)?.id();
+        self.groups.remove(&id);
+        for group in self.groups.values_mut() {
+            group.subgroups.remove(&id);
         }
+        Ok(id)
     }
 
-    /// Adds a new entity to the group with given name.
+    /// Adds the given entity to the group with given name.
     ///
     /// # Errors
     ///
     /// Returns Err if the group is not found or if the entity is already in the group.
-    pub fn add_entity_to_group(&mut self, group_name: &str, entity_name: String) -> Result<()> {
-        match self.groups.get_mut(group_name) {
-            Some(group) => {
-                group.add_entity(entity_name)?;
-                Ok(())
-            }
-            None => Err(DoesNotExist::group_does_not_exist(group_name)),
-        }
+    pub fn add_entity_to_group(&mut self, group_name: &str, entity: &Entity) -> Result<()> {
+        self.get_mut_by_name(group_name)?.add_entity(entity)
     }
 
-    /// Removes the entity from the group with given name.
+    /// Removes the given entity from the group with given name.
     ///
     /// # Errors
     ///
-    /// Returns Err if the group is not found or if the entity is already in the group.
-    pub fn remove_entity_from_group(&mut self, group_name: &str, entity_name: &str) -> Result<()> {
-        match self.groups.get_mut(group_name) {
-            Some(group) => {
-                group.remove_entity(entity_name)?;
-                Ok(())
-            }
-            None => Err(DoesNotExist::group_does_not_exist(group_name)),
+    /// Returns Err if the group is not found or if the entity is not in the group.
+    pub fn remove_entity_from_group(&mut self, group_name: &str, entity: &Entity) -> Result<()> {
+        self.get_mut_by_name(group_name)?.remove_entity(entity)
+    }
+
+    /// Adds the given subgroup to the group with given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the group is not found, if the subgroup is already in the group
+    /// or if the group is the subgroup or one of its members.
+    pub fn add_group_to_group(&mut self, group_name: &str, subgroup: &Group) -> Result<()> {
+        let group = self.get_by_name(group_name)?;
+        if group.id() == subgroup.id() {
+            return Err(GroupCycle::contains_itself(group.name()));
+        }
+        if self
+            .ids_of_groups_containing_group(group.id())
+            .contains(&subgroup.id())
+        {
+            return Err(GroupCycle::would_loop(group.name(), subgroup.name()));
         }
+        self.get_mut_by_name(group_name)?.add_subgroup(subgroup)
     }
 
-    /// Removes the entity with given name from all groups.
-    pub fn remove_entity_from_all(&mut self, entity: &str) {
+    /// Removes the given subgroup from the group with given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the group is not found or if the subgroup is not in the group.
+    pub fn remove_group_from_group(&mut self, group_name: &str, subgroup: &Group) -> Result<()> {
+        self.get_mut_by_name(group_name)?.remove_subgroup(subgroup)
+    }
+
+    /// Removes the entity with given id from all groups.
+    pub fn remove_entity_from_all(&mut self, entity_id: EntityId) {
         for group in self.groups.values_mut() {
-            let _ = group.remove_entity(entity);
+            group.entities.remove(&entity_id);
         }
     }
 
@@ -112,29 +247,32 @@ impl Groups {
     ///
     /// Returns Err if the group is not found or if a group already has this name.
     pub fn set_name_of(&mut self, old_name: &str, new_name: String) -> Result<()> {
-        match self.groups.entry(new_name.clone()) {
-            Entry::Occupied(_) => Err(NameTaken::name_taken_by_group(new_name)),
-            Entry::Vacant(_) => {
-                // We have to change the key and the value
-                match self.groups.remove(old_name) {
-                    Some(mut group) => {
-                        group.set_name(new_name.clone());
-                        self.groups.insert(new_name, group);
-                        Ok(())
-                    }
-                    None => Err(DoesNotExist::group_does_not_exist(old_name)),
-                }
-            }
+        if self.get_by_name(&new_name).is_ok() {
+            Err(NameTaken::name_taken_by_group(new_name))
+        } else {
+            self.get_mut_by_name(old_name)?.set_name(new_name);
+            Ok(())
         }
     }
 
-    /// Renames the entity with given name in all groups.
-    pub fn rename_entity_in_all(&mut self, old_name: &str, new_name: String) {
-        for group in self.groups.values_mut() {
-            // We don't care about the result : it is fine if the entity is not
-            // taking part in the activity, this will yield no conflict when it is renamed
-            let _ = group.rename_entity(old_name, new_name.clone());
-        }
+    /// Sets the load limits of the group with the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the group is not found.
+    pub fn set_load_limits_of(&mut self, name: &str, load_limits: LoadLimits) -> Result<()> {
+        self.get_mut_by_name(name)?.set_load_limits(load_limits);
+        Ok(())
+    }
+
+    /// Sets the details of the group with the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the group is not found.
+    pub fn set_details_of(&mut self, name: &str, details: Details) -> Result<()> {
+        self.get_mut_by_name(name)?.set_details(details);
+        Ok(())
     }
 }
 
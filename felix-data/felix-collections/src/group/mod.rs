@@ -1,43 +1,88 @@
 mod groups;
 pub use groups::Groups;
 
+use crate::Entity;
+use felix_datatypes::{Details, EntityId, GroupId, LoadLimits};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
+use std::collections::BTreeSet;
 
-use felix_errors::{already_in::AlreadyIn, name_taken::NameTaken, not_in::NotIn, Result};
+use felix_errors::{already_in::AlreadyIn, not_in::NotIn, Result};
 
-/// A group is an aggregation of entities.
+/// A group is an aggregation of entities and of other groups (its subgroups).
+///
+/// The members of a group are its entities and the members of its subgroups, recursively.
+/// A group cannot contain itself, directly or not.
 ///
 /// Groups have unique names. A group may not have the same name as an entity.
+/// Groups and their members are referenced by their ids, which never change.
 ///
 /// This structure is read-only. If you wish to create or modify a group, use the Data object.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Group {
+    id: GroupId,
     name: String,
-    entities: Vec<String>,
+    entities: BTreeSet<EntityId>,
+    #[serde(default)]
+    subgroups: BTreeSet<GroupId>,
+    #[serde(default)]
+    load_limits: LoadLimits,
+    #[serde(default)]
+    details: Details,
 }
 
 impl Group {
-    /// Creates a new group with the given name.
-    fn new(name: String) -> Group {
+    /// Creates a new group with the given id and name.
+    fn new(id: GroupId, name: String) -> Group {
         Group {
+            id,
             name,
-            entities: Vec::new(),
+            entities: BTreeSet::new(),
+            subgroups: BTreeSet::new(),
+            load_limits: LoadLimits::default(),
+            details: Details::default(),
         }
     }
 
     // *** Getters ***
     // This is the only public API, no setters. To modify a group, use the inner field.
+    /// Simple getter for the unique id.
+    #[must_use]
+    pub fn id(&self) -> GroupId {
+        self.id
+    }
+
     /// Simple getter for the name.
     #[must_use]
     pub fn name(&self) -> String {
         self.name.clone()
     }
 
-    /// Getter for the entities, sorted by name.
+    /// Getter for the ids of the entities which were added to the group directly.
+    /// Members of subgroups are not included.
+    /// Use the Data object to get their names.
+    #[must_use]
+    pub fn entity_ids(&self) -> &BTreeSet<EntityId> {
+        &self.entities
+    }
+
+    /// Getter for the ids of the groups which were added to the group directly.
+    /// Use the Data object to get their names.
+    #[must_use]
+    pub fn subgroup_ids(&self) -> &BTreeSet<GroupId> {
+        &self.subgroups
+    }
+
+    /// Simple getter for the load limits which apply to each member of the group.
     #[must_use]
-    pub fn entities_sorted(&self) -> Vec<String> {
-        self.entities.clone()
+    pub fn load_limits(&self) -> &LoadLimits {
+        &self.load_limits
+    }
+
+    /// Simple getter for the details (notes, email, tags, custom fields).
+    #[must_use]
+    pub fn details(&self) -> &Details {
+        &self.details
     }
 
     // *** Private Setters ***
@@ -46,13 +91,14 @@ impl Group {
     /// # Errors
     ///
     /// Returns Err if the entity is already in the group.
-    fn add_entity(&mut self, entity_name: String) -> Result<()> {
-        if self.entities.contains(&entity_name) {
-            Err(AlreadyIn::entity_already_in_group(entity_name, self.name()))
-        } else {
-            self.entities.push(entity_name);
-            self.entities.sort();
+    fn add_entity(&mut self, entity: &Entity) -> Result<()> {
+        if self.entities.insert(entity.id()) {
             Ok(())
+        } else {
+            Err(AlreadyIn::entity_already_in_group(
+                entity.name(),
+                self.name(),
+            ))
         }
     }
 
@@ -61,37 +107,56 @@ impl Group {
     /// # Errors
     ///
     /// Returns Err if the entity is not in the group.
-    fn remove_entity(&mut self, entity_name: &str) -> Result<()> {
-        if let Some(position) = self.entities.iter().position(|name| name == entity_name) {
-            self.entities.remove(position);
-            self.entities.sort();
+    fn remove_entity(&mut self, entity: &Entity) -> Result<()> {
+        if self.entities.remove(&entity.id()) {
             Ok(())
         } else {
-            Err(NotIn::entity_not_in_group(entity_name, self.name()))
+            Err(NotIn::entity_not_in_group(entity.name(), self.name()))
         }
     }
 
-    /// Sets the name of the group.
-    fn set_name(&mut self, name: String) {
-        self.name = name;
+    /// Adds a subgroup to the group.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the subgroup is already in the group.
+    fn add_subgroup(&mut self, subgroup: &Group) -> Result<()> {
+        if self.subgroups.insert(subgroup.id()) {
+            Ok(())
+        } else {
+            Err(AlreadyIn::group_already_in_group(
+                subgroup.name(),
+                self.name(),
+            ))
+        }
     }
 
-    /// Renames an entity in the group.
+    /// Removes a subgroup from the group.
     ///
     /// # Errors
     ///
-    /// Returns Err if the entity is not part of the group or if
-    /// an entity with the new name is already in the group.
-    fn rename_entity(&mut self, old_name: &str, new_name: String) -> Result<()> {
-        if self.entities.contains(&new_name) {
-            return Err(NameTaken::name_taken_by_entity(new_name));
-        };
-
-        self.remove_entity(old_name)?;
-
-        self.entities.push(new_name);
-        self.entities.sort();
-        Ok(())
+    /// Returns Err if the subgroup is not in the group.
+    fn remove_subgroup(&mut self, subgroup: &Group) -> Result<()> {
+        if self.subgroups.remove(&subgroup.id()) {
+            Ok(())
+        } else {
+            Err(NotIn::group_not_in_group(subgroup.name(), self.name()))
+        }
+    }
+
+    /// Sets the name of the group.
+    fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    /// Sets the load limits which apply to each member of the group.
+    fn set_load_limits(&mut self, load_limits: LoadLimits) {
+        self.load_limits = load_limits;
+    }
+
+    /// Sets the details of the group.
+    fn set_details(&mut self, details: Details) {
+        self.details = details;
     }
 }
 
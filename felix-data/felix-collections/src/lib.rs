@@ -3,7 +3,10 @@ mod entity;
 mod group;
 mod work_hours;
 
-pub use activity::{Activities, Activity};
+pub use activity::{
+    Activities, ActivitiesSnapshot, Activity, ActivityChain, ActivityFilter, ActivitySessions,
+    ActivityTemplate, SessionRules,
+};
 pub use entity::{Entities, Entity, EntityName};
 pub use group::{Group, Groups};
 pub use work_hours::WorkHours;
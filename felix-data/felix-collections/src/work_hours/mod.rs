@@ -1,19 +1,47 @@
-use crate::EntityName;
-use felix_datatypes::TimeInterval;
-use felix_errors::{does_not_exist::DoesNotExist, Result};
+use felix_datatypes::{
+    BreakRules, Duration, EntityId, GroupId, MandatoryBreak, TimeInterval, TimePreferences,
+};
+use felix_errors::{
+    does_not_exist::DoesNotExist, interval_overlaps::IntervalOverlaps, name_taken::NameTaken,
+    Result,
+};
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{btree_map::Entry, BTreeMap, HashMap};
 
 mod work_intervals;
 use work_intervals::WorkIntervals;
 
 /// Contains work hours represented as time intervals.
 /// Stays sorted by ascending order and prevents work intervals from overlapping.
+///
+/// Also contains the time preferences of entities (preferred and disliked intervals),
+/// the intervals during which entities are unavailable,
+/// the named work hour templates shared by entities, the work hours of groups
+/// and the break rules which apply to everyone or to specific entities.
+/// Entities and groups are referenced by their ids.
+/// Entities without custom work hours, preferences, unavailability or custom break rules
+/// are not registered, neither are groups without work hours.
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub struct WorkHours {
     global_work_intervals: WorkIntervals,
-    custom_work_intervals: HashMap<EntityName, WorkIntervals>,
+    custom_work_intervals: HashMap<EntityId, WorkIntervals>,
+    #[serde(default)]
+    preferred_intervals: HashMap<EntityId, WorkIntervals>,
+    #[serde(default)]
+    disliked_intervals: HashMap<EntityId, WorkIntervals>,
+    #[serde(default)]
+    unavailable_intervals: HashMap<EntityId, WorkIntervals>,
+    #[serde(default)]
+    work_hour_templates: BTreeMap<String, WorkIntervals>,
+    #[serde(default)]
+    assigned_work_hour_templates: HashMap<EntityId, String>,
+    #[serde(default)]
+    group_work_intervals: HashMap<GroupId, WorkIntervals>,
+    #[serde(default)]
+    global_break_rules: BreakRules,
+    #[serde(default)]
+    custom_break_rules: HashMap<EntityId, BreakRules>,
 }
 
 impl WorkHours {
@@ -23,6 +51,14 @@ impl WorkHours {
         WorkHours {
             global_work_intervals: WorkIntervals::new(),
             custom_work_intervals: HashMap::new(),
+            preferred_intervals: HashMap::new(),
+            disliked_intervals: HashMap::new(),
+            unavailable_intervals: HashMap::new(),
+            work_hour_templates: BTreeMap::new(),
+            assigned_work_hour_templates: HashMap::new(),
+            group_work_intervals: HashMap::new(),
+            global_break_rules: BreakRules::default(),
+            custom_break_rules: HashMap::new(),
         }
     }
 
@@ -65,113 +101,602 @@ impl WorkHours {
             .update_work_interval(old_interval, new_interval)
     }
 
-    /// Add empty custom work hours for the given entity.
+    /// Unregisters the custom work hours of an entity. This should be done when an entity is
+    /// removed.
+    pub fn remove_custom_work_hours_of(&mut self, entity_id: EntityId) {
+        self.custom_work_intervals.remove(&entity_id);
+    }
+
+    /// Adds a work interval to the entity with the given id.
     ///
-    /// # Panics
+    /// The existence of the entity is not checked.
     ///
-    /// Panics if the entity name already exists as a key.
-    pub fn add_empty_custom_work_intervals_for(&mut self, entity_name: String) {
-        assert!(
-            self.custom_work_intervals.get(&entity_name).is_none(),
-            "The custom work hours of {} are already registered",
-            &entity_name
-        );
+    /// # Errors
+    ///
+    /// Returns Err if the work interval overlaps with another.
+    pub fn add_custom_work_interval_for(
+        &mut self,
+        entity_id: EntityId,
+        interval: TimeInterval,
+    ) -> Result<()> {
         self.custom_work_intervals
-            .insert(entity_name, WorkIntervals::new());
+            .entry(entity_id)
+            .or_insert_with(WorkIntervals::new)
+            .add_work_interval(interval)
     }
 
-    /// Updates the key for the custom work hours of an entity whose name changed.
+    /// Removes a work interval from the entity with the given id.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if the entity whose name changed is not found.
-    pub fn rename_entity_for_custom_work_hours(&mut self, old_name: &str, new_name: String) {
-        let custom_work_intervals = self
-            .custom_work_intervals
-            .remove(old_name)
-            .unwrap_or_else(|| panic!("The custom work hours of {} are not registered", old_name));
-        self.custom_work_intervals
-            .insert(new_name, custom_work_intervals);
+    /// Returns Err if the work interval is not found.
+    pub fn remove_custom_work_interval_for(
+        &mut self,
+        entity_id: EntityId,
+        interval: TimeInterval,
+    ) -> Result<()> {
+        remove_interval_of(&mut self.custom_work_intervals, entity_id, interval)
     }
 
-    /// Unregisters the custom work hours of an entity. This should be done when an entity is
+    /// Updates the given interval for the entity with the given id.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the work interval is not found
+    /// or if the new work interval overlaps with others.
+    pub fn update_custom_work_interval_for(
+        &mut self,
+        entity_id: EntityId,
+        old_interval: TimeInterval,
+        new_interval: TimeInterval,
+    ) -> Result<()> {
+        match self.custom_work_intervals.get_mut(&entity_id) {
+            None => Err(DoesNotExist::interval_does_not_exist(old_interval)),
+            Some(custom_work_intervals) => {
+                custom_work_intervals.update_work_interval(old_interval, new_interval)
+            }
+        }
+    }
+
+    /// Returns the custom work hours of the entity with the given id.
+    ///
+    /// If the entity does not have custom work hours, the resulting vector will be empty.
+    /// The existence of the entity is not checked.
+    #[must_use]
+    pub fn custom_work_intervals_of(&self, entity_id: EntityId) -> Vec<TimeInterval> {
+        intervals_of(&self.custom_work_intervals, entity_id)
+    }
+
+    /// Returns the preferred intervals of the entity with the given id.
+    ///
+    /// The existence of the entity is not checked.
+    #[must_use]
+    pub fn preferred_intervals_of(&self, entity_id: EntityId) -> Vec<TimeInterval> {
+        intervals_of(&self.preferred_intervals, entity_id)
+    }
+
+    /// Returns the disliked intervals of the entity with the given id.
+    ///
+    /// The existence of the entity is not checked.
+    #[must_use]
+    pub fn disliked_intervals_of(&self, entity_id: EntityId) -> Vec<TimeInterval> {
+        intervals_of(&self.disliked_intervals, entity_id)
+    }
+
+    /// Returns the time preferences of the entity with the given id.
+    ///
+    /// The existence of the entity is not checked.
+    #[must_use]
+    pub fn time_preferences_of(&self, entity_id: EntityId) -> TimePreferences {
+        TimePreferences::new(
+            self.preferred_intervals_of(entity_id),
+            self.disliked_intervals_of(entity_id),
+        )
+    }
+
+    /// Adds a preferred interval for the entity with the given id.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the interval overlaps with another preferred or disliked interval.
+    pub fn add_preferred_interval_for(
+        &mut self,
+        entity_id: EntityId,
+        interval: TimeInterval,
+    ) -> Result<()> {
+        check_no_overlap(&self.disliked_intervals_of(entity_id), interval)?;
+        self.preferred_intervals
+            .entry(entity_id)
+            .or_insert_with(WorkIntervals::new)
+            .add_work_interval(interval)
+    }
+
+    /// Adds a disliked interval for the entity with the given id.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the interval overlaps with another preferred or disliked interval.
+    pub fn add_disliked_interval_for(
+        &mut self,
+        entity_id: EntityId,
+        interval: TimeInterval,
+    ) -> Result<()> {
+        check_no_overlap(&self.preferred_intervals_of(entity_id), interval)?;
+        self.disliked_intervals
+            .entry(entity_id)
+            .or_insert_with(WorkIntervals::new)
+            .add_work_interval(interval)
+    }
+
+    /// Removes a preferred interval from the entity with the given id.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the interval is not found.
+    pub fn remove_preferred_interval_for(
+        &mut self,
+        entity_id: EntityId,
+        interval: TimeInterval,
+    ) -> Result<()> {
+        remove_interval_of(&mut self.preferred_intervals, entity_id, interval)
+    }
+
+    /// Removes a disliked interval from the entity with the given id.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the interval is not found.
+    pub fn remove_disliked_interval_for(
+        &mut self,
+        entity_id: EntityId,
+        interval: TimeInterval,
+    ) -> Result<()> {
+        remove_interval_of(&mut self.disliked_intervals, entity_id, interval)
+    }
+
+    /// Unregisters the time preferences of an entity. This should be done when an entity is
     /// removed.
+    pub fn remove_preferences_of(&mut self, entity_id: EntityId) {
+        self.preferred_intervals.remove(&entity_id);
+        self.disliked_intervals.remove(&entity_id);
+    }
+
+    /// Returns the intervals during which the entity with the given id is unavailable.
     ///
-    /// # Panics
+    /// The existence of the entity is not checked.
+    #[must_use]
+    pub fn unavailable_intervals_of(&self, entity_id: EntityId) -> Vec<TimeInterval> {
+        intervals_of(&self.unavailable_intervals, entity_id)
+    }
+
+    /// Adds an interval during which the entity with the given id is unavailable.
     ///
-    /// Panics if the entity has no custom work hours (not even empty ones).
-    pub fn remove_custom_work_hours_of(&mut self, entity_name: &str) {
-        self.custom_work_intervals
-            .remove(entity_name)
-            .unwrap_or_else(|| {
-                panic!(
-                    "The custom work hours of {} are not registered",
-                    entity_name
-                )
-            });
+    /// # Errors
+    ///
+    /// Returns Err if the interval overlaps with another unavailable interval.
+    pub fn add_unavailable_interval_for(
+        &mut self,
+        entity_id: EntityId,
+        interval: TimeInterval,
+    ) -> Result<()> {
+        self.unavailable_intervals
+            .entry(entity_id)
+            .or_insert_with(WorkIntervals::new)
+            .add_work_interval(interval)
     }
 
-    /// Adds a work interval to the entity with the given name.
+    /// Removes an interval during which the entity with the given id is unavailable.
     ///
     /// # Errors
     ///
-    /// Returns Err if the entity does not exist or if the work interval overlaps with another.
-    pub fn add_custom_work_interval_for(
+    /// Returns Err if the interval is not found.
+    pub fn remove_unavailable_interval_for(
         &mut self,
-        entity_name: &str,
+        entity_id: EntityId,
         interval: TimeInterval,
     ) -> Result<()> {
-        match self.custom_work_intervals.get_mut(entity_name) {
-            None => Err(DoesNotExist::entity_does_not_exist(entity_name)),
-            Some(custom_work_intervals) => custom_work_intervals.add_work_interval(interval),
+        remove_interval_of(&mut self.unavailable_intervals, entity_id, interval)
+    }
+
+    /// Updates an interval during which the entity with the given id is unavailable.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the interval is not found or if the new interval overlaps with
+    /// another unavailable interval.
+    pub fn update_unavailable_interval_for(
+        &mut self,
+        entity_id: EntityId,
+        old_interval: TimeInterval,
+        new_interval: TimeInterval,
+    ) -> Result<()> {
+        match self.unavailable_intervals.get_mut(&entity_id) {
+            None => Err(DoesNotExist::interval_does_not_exist(old_interval)),
+            Some(intervals) => intervals.update_work_interval(old_interval, new_interval),
         }
     }
 
-    /// Removes a work interval from the entity with the given name.
+    /// Unregisters the unavailable intervals of an entity. This should be done when an entity
+    /// is removed.
+    pub fn remove_unavailability_of(&mut self, entity_id: EntityId) {
+        self.unavailable_intervals.remove(&entity_id);
+    }
+
+    /// Returns the names of the work hour templates, sorted.
+    #[must_use]
+    pub fn work_hour_template_names(&self) -> Vec<String> {
+        self.work_hour_templates.keys().cloned().collect()
+    }
+
+    /// Returns the work intervals of the template with the given name.
     ///
     /// # Errors
     ///
-    /// Returns Err if the entity does not exist or if the work interval is not found.
-    pub fn remove_custom_work_interval_for(
+    /// Returns Err if the template does not exist.
+    pub fn work_hour_template(&self, name: &str) -> Result<Vec<TimeInterval>> {
+        match self.work_hour_templates.get(name) {
+            None => Err(DoesNotExist::work_hour_template_does_not_exist(name)),
+            Some(intervals) => Ok(intervals.work_intervals().clone()),
+        }
+    }
+
+    /// Adds an empty work hour template with the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the name is already taken by another template.
+    pub fn add_work_hour_template(&mut self, name: String) -> Result<()> {
+        match self.work_hour_templates.entry(name) {
+            Entry::Occupied(entry) => Err(NameTaken::name_taken_by_work_hour_template(
+                entry.key().clone(),
+            )),
+            Entry::Vacant(entry) => {
+                entry.insert(WorkIntervals::new());
+                Ok(())
+            }
+        }
+    }
+
+    /// Removes the work hour template with the given name.
+    /// Entities which were assigned the template are unassigned.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the template does not exist.
+    pub fn remove_work_hour_template(&mut self, name: &str) -> Result<()> {
+        if self.work_hour_templates.remove(name).is_some() {
+            self.assigned_work_hour_templates
+                .retain(|_, template_name| template_name != name);
+            Ok(())
+        } else {
+            Err(DoesNotExist::work_hour_template_does_not_exist(name))
+        }
+    }
+
+    /// Renames the work hour template with the given name, and its assignments.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the template does not exist or if the new name is already taken.
+    pub fn rename_work_hour_template(&mut self, old_name: &str, new_name: String) -> Result<()> {
+        if self.work_hour_templates.contains_key(&new_name) {
+            return Err(NameTaken::name_taken_by_work_hour_template(new_name));
+        }
+        match self.work_hour_templates.remove(old_name) {
+            None => Err(DoesNotExist::work_hour_template_does_not_exist(old_name)),
+            Some(intervals) => {
+                self.work_hour_templates.insert(new_name.clone(), intervals);
+                for template_name in self.assigned_work_hour_templates.values_mut() {
+                    if template_name == old_name {
+                        *template_name = new_name.clone();
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Adds the given work interval to the template with the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the template does not exist or if the interval overlaps with another.
+    pub fn add_work_hour_template_interval(
         &mut self,
-        entity_name: &str,
+        name: &str,
         interval: TimeInterval,
     ) -> Result<()> {
-        match self.custom_work_intervals.get_mut(entity_name) {
-            None => Err(DoesNotExist::entity_does_not_exist(entity_name)),
-            Some(custom_work_intervals) => custom_work_intervals.remove_work_interval(interval),
+        match self.work_hour_templates.get_mut(name) {
+            None => Err(DoesNotExist::work_hour_template_does_not_exist(name)),
+            Some(intervals) => intervals.add_work_interval(interval),
         }
     }
 
-    /// Updates the given interval for the given entity.
+    /// Removes the given work interval from the template with the given name.
     ///
     /// # Errors
     ///
-    /// Returns Err if the entity does not exist ,if the work interval is not found
-    /// or if the new work interval overlaps with others.
-    pub fn update_custom_work_interval_for(
+    /// Returns Err if the template does not exist or if the interval is not found.
+    pub fn remove_work_hour_template_interval(
+        &mut self,
+        name: &str,
+        interval: TimeInterval,
+    ) -> Result<()> {
+        match self.work_hour_templates.get_mut(name) {
+            None => Err(DoesNotExist::work_hour_template_does_not_exist(name)),
+            Some(intervals) => intervals.remove_work_interval(interval),
+        }
+    }
+
+    /// Updates the given work interval of the template with the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the template does not exist, if the interval is not found
+    /// or if the new interval overlaps with another.
+    pub fn update_work_hour_template_interval(
         &mut self,
-        entity_name: &str,
+        name: &str,
         old_interval: TimeInterval,
         new_interval: TimeInterval,
     ) -> Result<()> {
-        match self.custom_work_intervals.get_mut(entity_name) {
-            None => Err(DoesNotExist::entity_does_not_exist(entity_name)),
-            Some(custom_work_intervals) => {
-                custom_work_intervals.update_work_interval(old_interval, new_interval)
+        match self.work_hour_templates.get_mut(name) {
+            None => Err(DoesNotExist::work_hour_template_does_not_exist(name)),
+            Some(intervals) => intervals.update_work_interval(old_interval, new_interval),
+        }
+    }
+
+    /// Returns the name of the template assigned to the entity with the given id, if any.
+    ///
+    /// The existence of the entity is not checked.
+    #[must_use]
+    pub fn work_hour_template_of(&self, entity_id: EntityId) -> Option<String> {
+        self.assigned_work_hour_templates.get(&entity_id).cloned()
+    }
+
+    /// Returns the ids of the entities which are assigned the template with the given name.
+    #[must_use]
+    pub fn entities_with_work_hour_template(&self, name: &str) -> Vec<EntityId> {
+        self.assigned_work_hour_templates
+            .iter()
+            .filter(|(_, template_name)| *template_name == name)
+            .map(|(&entity_id, _)| entity_id)
+            .collect()
+    }
+
+    /// Assigns the template with the given name to the entity with the given id.
+    /// None unassigns the current template.
+    ///
+    /// The existence of the entity is not checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the template does not exist.
+    pub fn assign_work_hour_template(
+        &mut self,
+        entity_id: EntityId,
+        template_name: Option<String>,
+    ) -> Result<()> {
+        match template_name {
+            None => {
+                self.assigned_work_hour_templates.remove(&entity_id);
+                Ok(())
+            }
+            Some(template_name) => {
+                if self.work_hour_templates.contains_key(&template_name) {
+                    self.assigned_work_hour_templates
+                        .insert(entity_id, template_name);
+                    Ok(())
+                } else {
+                    Err(DoesNotExist::work_hour_template_does_not_exist(
+                        template_name,
+                    ))
+                }
             }
         }
     }
 
-    /// Returns the custom work hours of the entity with the given name.
+    /// Unassigns the template of an entity. This should be done when an entity is removed.
+    pub fn remove_work_hour_template_of(&mut self, entity_id: EntityId) {
+        self.assigned_work_hour_templates.remove(&entity_id);
+    }
+
+    /// Returns the work hours of the group with the given id.
+    ///
+    /// If the group does not have work hours, the resulting vector will be empty.
+    /// The existence of the group is not checked.
+    #[must_use]
+    pub fn group_work_intervals_of(&self, group_id: GroupId) -> Vec<TimeInterval> {
+        intervals_of(&self.group_work_intervals, group_id)
+    }
+
+    /// Adds a work interval to the group with the given id.
     ///
     /// # Errors
     ///
-    /// Returns Err if the entity is not registered (we assume it does not exist).
-    pub fn custom_work_intervals_of(&self, entity_name: &str) -> Result<Vec<TimeInterval>> {
-        match self.custom_work_intervals.get(entity_name) {
-            None => Err(DoesNotExist::entity_does_not_exist(entity_name)),
-            Some(custom_work_intervals) => Ok(custom_work_intervals.work_intervals().clone()),
+    /// Returns Err if the interval overlaps with the other work intervals of the group.
+    pub fn add_group_work_interval_for(
+        &mut self,
+        group_id: GroupId,
+        interval: TimeInterval,
+    ) -> Result<()> {
+        self.group_work_intervals
+            .entry(group_id)
+            .or_insert_with(WorkIntervals::new)
+            .add_work_interval(interval)
+    }
+
+    /// Removes a work interval from the group with the given id.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the interval is not found.
+    pub fn remove_group_work_interval_for(
+        &mut self,
+        group_id: GroupId,
+        interval: TimeInterval,
+    ) -> Result<()> {
+        remove_interval_of(&mut self.group_work_intervals, group_id, interval)
+    }
+
+    /// Updates a work interval of the group with the given id.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the interval is not found or if the new interval overlaps with
+    /// the other work intervals of the group.
+    pub fn update_group_work_interval_for(
+        &mut self,
+        group_id: GroupId,
+        old_interval: TimeInterval,
+        new_interval: TimeInterval,
+    ) -> Result<()> {
+        match self.group_work_intervals.get_mut(&group_id) {
+            None => Err(DoesNotExist::interval_does_not_exist(old_interval)),
+            Some(intervals) => intervals.update_work_interval(old_interval, new_interval),
+        }
+    }
+
+    /// Unregisters the work hours of a group. This should be done when a group is removed.
+    pub fn remove_group_work_hours_of(&mut self, group_id: GroupId) {
+        self.group_work_intervals.remove(&group_id);
+    }
+
+    /// Returns the break rules which apply to everyone.
+    #[must_use]
+    pub fn global_break_rules(&self) -> &BreakRules {
+        &self.global_break_rules
+    }
+
+    /// Returns the break rules which apply only to the entity with the given id.
+    ///
+    /// The existence of the entity is not checked.
+    #[must_use]
+    pub fn custom_break_rules_of(&self, entity_id: EntityId) -> BreakRules {
+        self.custom_break_rules
+            .get(&entity_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Returns the break rules which apply to the entity with the given id :
+    /// both global and custom rules are taken into account.
+    ///
+    /// The existence of the entity is not checked.
+    #[must_use]
+    pub fn break_rules_of(&self, entity_id: EntityId) -> BreakRules {
+        self.global_break_rules
+            .combined_with(&self.custom_break_rules_of(entity_id))
+    }
+
+    /// Sets the maximum continuous work of everyone. None means no limit.
+    pub fn set_max_continuous_work(&mut self, max_continuous_work: Option<Duration>) {
+        self.global_break_rules
+            .set_max_continuous_work(max_continuous_work);
+    }
+
+    /// Adds a mandatory break for everyone.
+    pub fn add_mandatory_break(&mut self, mandatory_break: MandatoryBreak) {
+        self.global_break_rules.add_mandatory_break(mandatory_break);
+    }
+
+    /// Removes a mandatory break which applies to everyone.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the mandatory break is not found.
+    pub fn remove_mandatory_break(&mut self, mandatory_break: MandatoryBreak) -> Result<()> {
+        if self
+            .global_break_rules
+            .remove_mandatory_break(mandatory_break)
+        {
+            Ok(())
+        } else {
+            Err(DoesNotExist::interval_does_not_exist(
+                mandatory_break.window(),
+            ))
+        }
+    }
+
+    /// Sets the maximum continuous work of the entity with given id. None means that only the
+    /// global limit applies.
+    pub fn set_max_continuous_work_for(
+        &mut self,
+        entity_id: EntityId,
+        max_continuous_work: Option<Duration>,
+    ) {
+        self.custom_break_rules
+            .entry(entity_id)
+            .or_default()
+            .set_max_continuous_work(max_continuous_work);
+    }
+
+    /// Adds a mandatory break for the entity with given id.
+    pub fn add_mandatory_break_for(
+        &mut self,
+        entity_id: EntityId,
+        mandatory_break: MandatoryBreak,
+    ) {
+        self.custom_break_rules
+            .entry(entity_id)
+            .or_default()
+            .add_mandatory_break(mandatory_break);
+    }
+
+    /// Removes a mandatory break from the entity with given id.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the mandatory break is not found.
+    pub fn remove_mandatory_break_for(
+        &mut self,
+        entity_id: EntityId,
+        mandatory_break: MandatoryBreak,
+    ) -> Result<()> {
+        if self
+            .custom_break_rules
+            .get_mut(&entity_id)
+            .map_or(false, |break_rules| {
+                break_rules.remove_mandatory_break(mandatory_break)
+            })
+        {
+            Ok(())
+        } else {
+            Err(DoesNotExist::interval_does_not_exist(
+                mandatory_break.window(),
+            ))
+        }
+    }
+
+    /// Unregisters the custom break rules of an entity. This should be done when an entity is
+    /// removed.
+    pub fn remove_break_rules_of(&mut self, entity_id: EntityId) {
+        self.custom_break_rules.remove(&entity_id);
+    }
+
+    /// Gives the entity with id `kept_id` the custom work hours, time preferences,
+    /// unavailability, template and custom break rules of the entity with id `removed_id`,
+    /// for each of them which it does not have. This should be done when two entities are
+    /// merged.
+    ///
+    /// The existence of the entities is not checked.
+    pub fn carry_over_settings(&mut self, removed_id: EntityId, kept_id: EntityId) {
+        carry_over_intervals(&mut self.custom_work_intervals, removed_id, kept_id);
+        if self.time_preferences_of(kept_id) == TimePreferences::default() {
+            carry_over_intervals(&mut self.preferred_intervals, removed_id, kept_id);
+            carry_over_intervals(&mut self.disliked_intervals, removed_id, kept_id);
+        }
+        carry_over_intervals(&mut self.unavailable_intervals, removed_id, kept_id);
+        if !self.assigned_work_hour_templates.contains_key(&kept_id) {
+            if let Some(template_name) = self.work_hour_template_of(removed_id) {
+                self.assigned_work_hour_templates
+                    .insert(kept_id, template_name);
+            }
+        }
+        if self.custom_break_rules_of(kept_id) == BreakRules::default() {
+            if let Some(break_rules) = self.custom_break_rules.get(&removed_id).cloned() {
+                self.custom_break_rules.insert(kept_id, break_rules);
+            }
         }
     }
 }
@@ -181,3 +706,56 @@ impl Default for WorkHours {
         Self::new()
     }
 }
+
+/// Returns the intervals registered for the given entity, or an empty vector.
+fn intervals_of(
+    intervals: &HashMap<EntityId, WorkIntervals>,
+    entity_id: EntityId,
+) -> Vec<TimeInterval> {
+    intervals
+        .get(&entity_id)
+        .map(|intervals| intervals.work_intervals().clone())
+        .unwrap_or_default()
+}
+
+/// Registers the intervals of the removed entity for the kept entity if it has none.
+fn carry_over_intervals(
+    intervals: &mut HashMap<EntityId, WorkIntervals>,
+    removed_id: EntityId,
+    kept_id: EntityId,
+) {
+    if intervals_of(intervals, kept_id).is_empty() {
+        if let Some(removed_intervals) = intervals.get(&removed_id).cloned() {
+            intervals.insert(kept_id, removed_intervals);
+        }
+    }
+}
+
+/// Removes the given interval from the intervals registered for the given entity.
+///
+/// # Errors
+///
+/// Returns Err if the interval is not found.
+fn remove_interval_of(
+    intervals: &mut HashMap<EntityId, WorkIntervals>,
+    entity_id: EntityId,
+    interval: TimeInterval,
+) -> Result<()> {
+    match intervals.get_mut(&entity_id) {
+        None => Err(DoesNotExist::interval_does_not_exist(interval)),
+        Some(intervals) => intervals.remove_work_interval(interval),
+    }
+}
+
+/// Checks that the given interval does not overlap with any of the other intervals.
+///
+/// # Errors
+///
+/// Returns Err if the interval overlaps with another.
+fn check_no_overlap(others: &[TimeInterval], interval: TimeInterval) -> Result<()> {
+    if others.iter().any(|other| other.overlaps_with(&interval)) {
+        Err(IntervalOverlaps::new())
+    } else {
+        Ok(())
+    }
+}
@@ -9,78 +9,91 @@ fn bench_autoinsert_light(c: &mut Criterion) {
             possible_insertion_beginnings_minutes_sorted: (0..100).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![1, 2, 3, 4, 5],
             duration_minutes: 25,
+            ..Default::default()
         },
         // 1
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..500).step_by(15).collect(),
             indexes_of_incompatible_activities: vec![0, 2, 3, 4],
             duration_minutes: 30,
+            ..Default::default()
         },
         // 2
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..1000).step_by(35).collect(),
             indexes_of_incompatible_activities: vec![0, 1, 3, 4],
             duration_minutes: 25,
+            ..Default::default()
         },
         // 3
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..100).step_by(10).collect(),
             indexes_of_incompatible_activities: vec![0, 1, 2, 4],
             duration_minutes: 25,
+            ..Default::default()
         },
         // 4
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..200).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![0, 1, 2, 3],
             duration_minutes: 20,
+            ..Default::default()
         },
         // 5
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..300).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![0],
             duration_minutes: 70,
+            ..Default::default()
         },
         // 6
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (100..500).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![8, 9],
             duration_minutes: 35,
+            ..Default::default()
         },
         // 7
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (50..200).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![8, 9],
             duration_minutes: 25,
+            ..Default::default()
         },
         // 8
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..100).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![6, 7, 9],
             duration_minutes: 15,
+            ..Default::default()
         },
         // 9
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..100).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![6, 7, 10, 11],
             duration_minutes: 10,
+            ..Default::default()
         },
         // 10
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (50..100).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![9, 11],
             duration_minutes: 20,
+            ..Default::default()
         },
         // 11
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (50..200).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![9, 10],
             duration_minutes: 15,
+            ..Default::default()
         },
         // 12
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..100).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![],
             duration_minutes: 100,
+            ..Default::default()
         },
     ];
     let insertion_data = vec![];
@@ -102,108 +115,126 @@ fn bench_autoinsert_heavy(c: &mut Criterion) {
             possible_insertion_beginnings_minutes_sorted: (0..1000).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![1, 2, 3, 4, 5],
             duration_minutes: 30,
+            ..Default::default()
         },
         // 1
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..5000).step_by(15).collect(),
             indexes_of_incompatible_activities: vec![0, 2, 3, 4, 6],
             duration_minutes: 30,
+            ..Default::default()
         },
         // 2
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..1000).step_by(35).collect(),
             indexes_of_incompatible_activities: vec![0, 1, 3, 4, 7],
             duration_minutes: 25,
+            ..Default::default()
         },
         // 3
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..100).step_by(10).collect(),
             indexes_of_incompatible_activities: vec![0, 1, 2, 4],
             duration_minutes: 25,
+            ..Default::default()
         },
         // 4
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..200).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![0, 1, 2, 3],
             duration_minutes: 20,
+            ..Default::default()
         },
         // 5
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..300).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![0],
             duration_minutes: 70,
+            ..Default::default()
         },
         // 6
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (100..500).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![8, 9, 1],
             duration_minutes: 35,
+            ..Default::default()
         },
         // 7
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (50..200).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![8, 9, 2],
             duration_minutes: 25,
+            ..Default::default()
         },
         // 8
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..100).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![6, 7, 9],
             duration_minutes: 15,
+            ..Default::default()
         },
         // 9
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..100).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![6, 7, 10, 11],
             duration_minutes: 10,
+            ..Default::default()
         },
         // 10
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (50..100).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![9, 11, 14],
             duration_minutes: 20,
+            ..Default::default()
         },
         // 11
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (50..200).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![9, 10, 12, 13],
             duration_minutes: 15,
+            ..Default::default()
         },
         // 12
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (50..200).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![9, 10, 11, 13],
             duration_minutes: 15,
+            ..Default::default()
         },
         // 13
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (50..200).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![9, 10, 12, 13],
             duration_minutes: 15,
+            ..Default::default()
         },
         // 14
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..100).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![10, 15],
             duration_minutes: 50,
+            ..Default::default()
         },
         // 15
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..100).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![14],
             duration_minutes: 50,
+            ..Default::default()
         },
         // 16
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..100).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![],
             duration_minutes: 100,
+            ..Default::default()
         },
         // 17
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..100).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![],
             duration_minutes: 100,
+            ..Default::default()
         },
     ];
     let insertion_data = vec![];
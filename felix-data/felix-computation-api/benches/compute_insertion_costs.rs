@@ -17,26 +17,31 @@ fn create_data() -> (
             possible_insertion_beginnings_minutes_sorted: (0..1000).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![1, 2, 3, 4],
             duration_minutes: 10,
+            ..Default::default()
         },
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (100..200).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![0, 3],
             duration_minutes: 100,
+            ..Default::default()
         },
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (1000..2000).step_by(10).collect(),
             indexes_of_incompatible_activities: vec![0, 4],
             duration_minutes: 150,
+            ..Default::default()
         },
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (150..300).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![0, 1],
             duration_minutes: 30,
+            ..Default::default()
         },
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (500..700).step_by(10).collect(),
             indexes_of_incompatible_activities: vec![0, 2],
             duration_minutes: 55,
+            ..Default::default()
         },
     ];
 
@@ -17,7 +17,18 @@ fn bench_find_possible_beginnings(c: &mut Criterion) {
                     WorkHourInMinutes::new(800, 935),
                     WorkHourInMinutes::new(250, 450),
                 ],
-                &[15, 15, 20, 20, 30, 30, 40, 45, 60, 80],
+                &[
+                    (15, 15),
+                    (15, 15),
+                    (20, 20),
+                    (20, 20),
+                    (30, 30),
+                    (30, 30),
+                    (40, 40),
+                    (45, 45),
+                    (60, 60),
+                    (80, 80),
+                ],
             )
         })
     });
@@ -29,7 +40,20 @@ fn bench_find_possible_beginnings(c: &mut Criterion) {
                     WorkHourInMinutes::new(250, 550),
                     WorkHourInMinutes::new(800, 1235),
                 ],
-                &[25, 25, 30, 30, 40, 40, 45, 45, 60, 80, 90, 120],
+                &[
+                    (25, 25),
+                    (25, 25),
+                    (30, 30),
+                    (30, 30),
+                    (40, 40),
+                    (40, 40),
+                    (45, 45),
+                    (45, 45),
+                    (60, 60),
+                    (80, 80),
+                    (90, 90),
+                    (120, 120),
+                ],
             )
         })
     });
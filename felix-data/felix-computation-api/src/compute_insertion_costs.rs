@@ -1,6 +1,6 @@
 use crate::structs::ActivityComputationStaticData;
 use felix_datatypes::{
-    ActivityBeginningMinutes, InsertionCostsMinutes, MIN_TIME_DISCRETIZATION_MINUTES,
+    ActivityBeginningMinutes, Cost, InsertionCostsMinutes, MIN_TIME_DISCRETIZATION_MINUTES,
 };
 
 use std::collections::BTreeSet;
@@ -66,11 +66,11 @@ pub fn get_activity_beginnings_with_conflicts(
         .clone();
 
     // 1 - Fetch invalid beginnings
-    // Offset with the duration of the activity
+    // Offset with the longest duration of the activity
     // (e.g. if 11:00 - 12:00 is taken and our duration is 00:30, we cannot insert the activity
     // at 10:50.
     let offset_check_before_activity =
-        activity_static_data.duration_minutes - MIN_TIME_DISCRETIZATION_MINUTES;
+        activity_static_data.max_duration_minutes() - MIN_TIME_DISCRETIZATION_MINUTES;
 
     for (incompatible_beginning, incompatible_end) in activity_static_data
         .indexes_of_incompatible_activities
@@ -82,30 +82,107 @@ pub fn get_activity_beginnings_with_conflicts(
             insertion_data.get(index).map(|incompatible_beginning| {
                 // The activity is inserted.
                 // Use it to filter out conflicts
-                unsafe {
-                    (
-                        *incompatible_beginning,
-                        incompatible_beginning + static_data.get_unchecked(index).duration_minutes,
-                    )
-                }
+                let incompatible_static_data = unsafe { static_data.get_unchecked(index) };
+                // Keep the largest transition buffer of both activities free around it
+                let buffer = activity_static_data
+                    .transition_buffer_minutes
+                    .max(incompatible_static_data.transition_buffer_minutes);
+                (
+                    incompatible_beginning.saturating_sub(buffer),
+                    incompatible_beginning
+                        + incompatible_static_data.duration_at(*incompatible_beginning)
+                        + buffer,
+                )
             })
         })
     {
         // Make sure the activity will not overlap with invalid intervals (for its whole duration)
-        let incompatible_beginning = if incompatible_beginning < offset_check_before_activity {
-            0
-        } else {
-            incompatible_beginning - offset_check_before_activity
-        };
+        let first_beginning_to_check =
+            incompatible_beginning.saturating_sub(offset_check_before_activity);
 
         // 2 - Remove invalid beginnings
+        // The duration depends on the beginning: check the end of the activity precisely
         for beginning in activity_static_data
             .possible_insertion_beginnings_minutes_sorted
-            .range(incompatible_beginning..incompatible_end)
+            .range(first_beginning_to_check..incompatible_end)
+            .filter(|&&beginning| {
+                beginning + activity_static_data.duration_at(beginning) > incompatible_beginning
+            })
         {
             possible_beginnings.remove(&beginning);
         }
     }
+
+    // 3 - Remove beginnings which do not respect the break rules of participants
+    for participant in &activity_static_data.break_rules_of_participants {
+        let inserted_activities_of_participant = participant
+            .indexes_of_activities
+            .iter()
+            .copied()
+            .filter(|&index| index < index_of_activity)
+            .filter_map(|index| {
+                insertion_data.get(index).map(|&beginning| {
+                    (beginning, beginning + static_data[index].duration_at(beginning))
+                })
+            })
+            .collect::<Vec<_>>();
+
+        possible_beginnings = possible_beginnings
+            .into_iter()
+            .filter(|&beginning| {
+                let mut activities = inserted_activities_of_participant.clone();
+                activities.push((
+                    beginning,
+                    beginning + activity_static_data.duration_at(beginning),
+                ));
+                participant.break_rules.are_respected_by(activities)
+            })
+            .collect();
+    }
+
+    // 4 - Keep the sessions of split activities in order, spaced if they are recurring
+    let min_spacing = activity_static_data.min_spacing_between_sessions_minutes;
+    if let Some(end_of_previous_session) = activity_static_data
+        .index_of_previous_session
+        .filter(|&index| index < index_of_activity)
+        .and_then(|index| {
+            insertion_data
+                .get(index)
+                .map(|&beginning| beginning + static_data[index].duration_at(beginning))
+        })
+    {
+        possible_beginnings =
+            possible_beginnings.split_off(&(end_of_previous_session + min_spacing));
+    }
+
+    if let Some(beginning_of_next_session) = activity_static_data
+        .index_of_next_session
+        .filter(|&index| index < index_of_activity)
+        .and_then(|index| insertion_data.get(index).copied())
+    {
+        possible_beginnings = possible_beginnings
+            .into_iter()
+            .filter(|&beginning| {
+                beginning + activity_static_data.duration_at(beginning) + min_spacing
+                    <= beginning_of_next_session
+            })
+            .collect();
+    }
+
+    // 5 - Simultaneous activities share the same beginning
+    if let Some(&beginning_of_simultaneous_activity) = activity_static_data
+        .indexes_of_simultaneous_activities
+        .iter()
+        .copied()
+        .filter(|&index| index < index_of_activity)
+        .find_map(|index| insertion_data.get(index))
+    {
+        possible_beginnings = if possible_beginnings.contains(&beginning_of_simultaneous_activity) {
+            std::iter::once(beginning_of_simultaneous_activity).collect()
+        } else {
+            BTreeSet::new()
+        };
+    }
     possible_beginnings
 }
 
@@ -132,12 +209,17 @@ pub fn get_activity_insertion_costs(
 
     // Copy u16
     for beginning in possible_beginnings.iter().copied() {
-        let end = beginning + activity_static_data.duration_minutes;
+        let duration = activity_static_data.duration_at(beginning);
+        let end = beginning + duration;
 
         // Treat usize as float with 4 digits precision
         const SIGNIFICANT_DIGIT_MULTIPLIER: usize = 10_000;
-        // Baseline
-        let mut cost = 0;
+        // Baseline : how much participants like this beginning
+        let mut cost: Cost = activity_static_data
+            .preferences_of_participants
+            .iter()
+            .map(|preferences| preferences.cost_of_insertion(beginning, duration))
+            .sum();
         let mut beginning_will_block_other_activities = false;
 
         for (
@@ -156,17 +238,31 @@ pub fn get_activity_insertion_costs(
                 )
             })
         {
-            let offset_check_before_activity = incompatible_activities_static_data.duration_minutes
+            // The incompatible activity may last longer when it begins earlier, so look as far
+            // back as its longest duration and keep only the beginnings which overlap
+            let offset_check_before_activity = incompatible_activities_static_data
+                .max_duration_minutes()
                 - MIN_TIME_DISCRETIZATION_MINUTES;
 
-            let beginning_with_duration_offset = if beginning < offset_check_before_activity {
-                0
-            } else {
-                beginning - offset_check_before_activity
-            };
+            let buffer = activity_static_data
+                .transition_buffer_minutes
+                .max(incompatible_activities_static_data.transition_buffer_minutes);
+
+            let beginning_with_duration_offset =
+                if beginning < offset_check_before_activity + buffer {
+                    0
+                } else {
+                    beginning - offset_check_before_activity - buffer
+                };
 
             let nb_beginnings_blocked = incompatible_activities_insertions_with_conflict
-                .range(beginning_with_duration_offset..end)
+                .range(beginning_with_duration_offset..end + buffer)
+                .filter(|&&other_beginning| {
+                    other_beginning
+                        + incompatible_activities_static_data.duration_at(other_beginning)
+                        + buffer
+                        > beginning
+                })
                 .count();
 
             let nb_possible_beginnings = incompatible_activities_insertions_with_conflict.len();
@@ -188,11 +284,27 @@ pub fn get_activity_insertion_costs(
                     / nb_remaining_beginnings;
             }
         }
+        // Simultaneous activities which are not inserted yet must be able to begin with it
+        if activity_static_data
+            .indexes_of_simultaneous_activities
+            .iter()
+            .copied()
+            .filter(|&index| insertion_data.len() <= index)
+            .any(|index| unsafe {
+                !possible_insertions_with_conflicts
+                    .get_unchecked(index - insertion_data.len())
+                    .contains(&beginning)
+            })
+        {
+            beginning_will_block_other_activities = true;
+        }
+
         // The activity can be inserted
         if !beginning_will_block_other_activities {
             cost_for_all_beginnings.push(InsertionCostsMinutes {
                 beginning_minutes: beginning,
                 cost,
+                duration_minutes: duration,
             });
         }
     }
@@ -18,15 +18,25 @@ use felix_datatypes::{
 use itertools::Itertools;
 use std::collections::HashSet;
 
-/// Given the work hour beginnings, ends and durations, and activity durations,
+/// Given the work hour beginnings, ends and durations, and activity duration ranges,
 /// finds every possible starting time for every activity duration so that every activity
 /// can be inserted in one schedule.
 ///
+/// Each activity is given as (minimum duration, maximum duration).
+/// Every duration of the range is tried in steps of MIN_TIME_DISCRETIZATION_MINUTES,
+/// the other activities taking their minimum duration.
+/// The result is keyed by (minimum duration, tried duration).
+///
+/// As the other activities are only checked with their minimum duration, several activities
+/// may each be given beginnings with their longest duration although these durations do not
+/// all fit together. Once one of them is inserted, the beginnings of the others are computed
+/// again with the time it actually takes.
+///
 /// Activity durations MUST BE SORTED IN ASCENDING ORDER.
 /// Work hours (beginning, end, durations) MUST BE SORTED IN ASCENDING ORDER.
 pub fn find_possible_beginnings(
     work_hours: &[WorkHourInMinutes],
-    activity_durations: &[u16],
+    activity_durations: &[(u16, u16)],
 ) -> ActivityBeginningsGivenDurationMinutes {
     debug_assert!(is_sorted(activity_durations));
 
@@ -39,84 +49,131 @@ pub fn find_possible_beginnings(
     // Init result
     let mut activity_beginnings = ActivityBeginningsGivenDurationMinutes::new();
 
-    let n_activity_durations = activity_durations.len();
+    // The other activities are inserted with their minimum duration
+    let min_activity_durations = activity_durations
+        .iter()
+        .map(|&(min_duration, _)| min_duration)
+        .collect::<Vec<_>>();
 
     // 1 - Compute all possible sums of activity durations (see tests)
     // Activity durations need to be sorted so that compute_all_sums output is sorted
-    let all_duration_sums = compute_all_sums(&activity_durations);
+    let all_duration_sums = compute_all_sums(&min_activity_durations);
     let time_which_can_be_wasted =
-        work_hour_durations.iter().sum::<u16>() - activity_durations.iter().sum::<u16>();
+        work_hour_durations.iter().sum::<u16>() - min_activity_durations.iter().sum::<u16>();
 
     // 2 - Try to put every different duration in every possible starting time and check if the
     //   rest of the durations can be put in the rest of the work hours.
     //   If it is possible, then the starting time is added to the result.
 
     // It is faster to copy u16 than to use references
-    for (activity_index, activity_duration) in activity_durations
+    for (activity_index, (min_duration, max_duration)) in activity_durations
         .iter()
         .copied()
         .enumerate()
         // Must call enumerate before unique_by so that indexes stay aligned
         // (otherwise, each time .unique filters, indexes are offset by one)
-        .unique_by(|index_duration| index_duration.1)
+        .unique_by(|index_durations| index_durations.1)
     {
-        let mut possible_beginnings = HashSet::new();
-        // The filter acts as both an early stop and safety
-        // (prevents overflow in u16 substraction work_hour_duration - activity_duration)
-        for (work_hour_index, work_hour_duration) in work_hour_durations
-            .iter()
-            .copied()
-            .enumerate()
-            .filter(|&index_duration_tuple| index_duration_tuple.1 >= activity_duration)
+        for activity_duration in (min_duration..=max_duration.max(min_duration))
+            .step_by(MIN_TIME_DISCRETIZATION_MINUTES.into())
         {
-            // Check only the first half of the work hour because of symmetry
-            let last_time_we_need_to_check = (work_hour_duration - activity_duration) / 2;
-
-            // Iterate over each possible starting time in the work hour
-            // Note the inclusive range (a..=b) because we want to take into account the
-            //    last last_time_we_need_to_check
-            for mins_from_start in
-                (0..=last_time_we_need_to_check).step_by(MIN_TIME_DISCRETIZATION_MINUTES.into())
-            {
-                let mut new_work_hour_durations = work_hour_durations.to_vec();
-                // Reduce the duration of the work interval by the duration of the activity
-
-                new_work_hour_durations[work_hour_index] -= activity_duration + mins_from_start;
-                if mins_from_start != 0 {
-                    // We have to put back the minutes we took above in a separate duration
-                    // because we split the work hour in two
-                    new_work_hour_durations.push(mins_from_start);
-                }
+            if activity_beginnings.contains_key(&(min_duration, activity_duration)) {
+                // Another activity with the same minimum duration already computed this
+                continue;
+            }
+            // The time the activity takes on top of its minimum duration cannot be wasted
+            let extra_duration = activity_duration - min_duration;
+            if extra_duration > time_which_can_be_wasted {
+                break;
+            }
 
-                // Sort to use the biggest work hours first.
-                // Sort ascending because we take the last element of the work hours each time.
-                new_work_hour_durations.sort_unstable();
-
-                // Check if the rest of the activities fit in the schedule.
-                if can_fit_in_schedule(
-                    n_activity_durations,
-                    &all_duration_sums,
-                    &new_work_hour_durations,
-                    time_which_can_be_wasted,
-                    [activity_index]
-                        .iter()
-                        .map(|&i| i as u16)
-                        .collect::<HashSet<_>>(),
-                ) {
-                    let work_hour = work_hours[work_hour_index];
-                    // The rest of the activities fit in the schedule.
-                    // This insertion time is valid for the given duration.
-                    possible_beginnings.insert(work_hour.beginning + mins_from_start);
-                    // Add the symmetry
-                    possible_beginnings.insert(work_hour.end - mins_from_start - activity_duration);
-                }
+            let possible_beginnings = possible_beginnings_for_duration(
+                work_hours,
+                &work_hour_durations,
+                activity_durations.len(),
+                &all_duration_sums,
+                time_which_can_be_wasted - extra_duration,
+                activity_index,
+                activity_duration,
+            );
+            // If the activity fits nowhere, longer durations will not fit either
+            let fits_nowhere = possible_beginnings.is_empty();
+            activity_beginnings.insert((min_duration, activity_duration), possible_beginnings);
+            if fits_nowhere {
+                break;
             }
         }
-        activity_beginnings.insert(activity_duration, possible_beginnings);
     }
     activity_beginnings
 }
 
+/// Tries to put the activity with given index and duration in every possible starting time
+/// and returns the starting times for which the rest of the activities fit in the rest
+/// of the work hours.
+fn possible_beginnings_for_duration(
+    work_hours: &[WorkHourInMinutes],
+    work_hour_durations: &[u16],
+    n_activity_durations: usize,
+    all_duration_sums: &[SumAndDurationIndexes],
+    time_which_can_be_wasted: u16,
+    activity_index: usize,
+    activity_duration: u16,
+) -> HashSet<u16> {
+    let mut possible_beginnings = HashSet::new();
+    // The filter acts as both an early stop and safety
+    // (prevents overflow in u16 substraction work_hour_duration - activity_duration)
+    for (work_hour_index, work_hour_duration) in work_hour_durations
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|&index_duration_tuple| index_duration_tuple.1 >= activity_duration)
+    {
+        // Check only the first half of the work hour because of symmetry
+        let last_time_we_need_to_check = (work_hour_duration - activity_duration) / 2;
+
+        // Iterate over each possible starting time in the work hour
+        // Note the inclusive range (a..=b) because we want to take into account the
+        //    last last_time_we_need_to_check
+        for mins_from_start in
+            (0..=last_time_we_need_to_check).step_by(MIN_TIME_DISCRETIZATION_MINUTES.into())
+        {
+            let mut new_work_hour_durations = work_hour_durations.to_vec();
+            // Reduce the duration of the work interval by the duration of the activity
+
+            new_work_hour_durations[work_hour_index] -= activity_duration + mins_from_start;
+            if mins_from_start != 0 {
+                // We have to put back the minutes we took above in a separate duration
+                // because we split the work hour in two
+                new_work_hour_durations.push(mins_from_start);
+            }
+
+            // Sort to use the biggest work hours first.
+            // Sort ascending because we take the last element of the work hours each time.
+            new_work_hour_durations.sort_unstable();
+
+            // Check if the rest of the activities fit in the schedule.
+            if can_fit_in_schedule(
+                n_activity_durations,
+                all_duration_sums,
+                &new_work_hour_durations,
+                time_which_can_be_wasted,
+                [activity_index]
+                    .iter()
+                    .map(|&i| i as u16)
+                    .collect::<HashSet<_>>(),
+            ) {
+                let work_hour = work_hours[work_hour_index];
+                // The rest of the activities fit in the schedule.
+                // This insertion time is valid for the given duration.
+                possible_beginnings.insert(work_hour.beginning + mins_from_start);
+                // Add the symmetry
+                possible_beginnings.insert(work_hour.end - mins_from_start - activity_duration);
+            }
+        }
+    }
+    possible_beginnings
+}
+
 /// Given an array of durations, computes all possible sums using every combination.
 /// The sums are sorted decreasingly if the durations are sorted increasingly.
 ///
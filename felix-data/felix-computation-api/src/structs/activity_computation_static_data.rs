@@ -1,8 +1,51 @@
-use std::collections::BTreeSet;
+use crate::structs::ParticipantBreakRules;
+use felix_datatypes::TimePreferences;
 
-#[derive(Clone, Debug)]
+use std::collections::{BTreeSet, HashMap};
+
+#[derive(Clone, Debug, Default)]
 pub struct ActivityComputationStaticData {
     pub possible_insertion_beginnings_minutes_sorted: BTreeSet<u16>,
     pub indexes_of_incompatible_activities: Vec<usize>,
     pub duration_minutes: u16,
+    /// Durations of the activity for the beginnings at which it does not last duration_minutes.
+    /// Used by activities which may last anywhere in a duration range.
+    pub durations_given_beginning_minutes: HashMap<u16, u16>,
+    /// Minimum free time between this activity and any incompatible activity.
+    pub transition_buffer_minutes: u16,
+    /// Soft preferences of each participant. They make insertion times more or less costly.
+    pub preferences_of_participants: Vec<TimePreferences>,
+    /// Break rules of the participants which have any.
+    pub break_rules_of_participants: Vec<ParticipantBreakRules>,
+    /// If the activity is a session which must take place after another one,
+    /// index of the session which comes right before it.
+    pub index_of_previous_session: Option<usize>,
+    /// If the activity is a session which must take place before another one,
+    /// index of the session which comes right after it.
+    pub index_of_next_session: Option<usize>,
+    /// Minimum free time between this session and the sessions which come right before
+    /// and after it. Used to space the occurrences of recurring activities.
+    pub min_spacing_between_sessions_minutes: u16,
+    /// Indexes of the activities which must have the same beginning as this one.
+    pub indexes_of_simultaneous_activities: Vec<usize>,
+}
+
+impl ActivityComputationStaticData {
+    /// Returns the duration of the activity if it begins at the given time.
+    #[must_use]
+    pub fn duration_at(&self, beginning: u16) -> u16 {
+        self.durations_given_beginning_minutes
+            .get(&beginning)
+            .copied()
+            .unwrap_or(self.duration_minutes)
+    }
+
+    /// Returns the longest duration the activity may have.
+    #[must_use]
+    pub fn max_duration_minutes(&self) -> u16 {
+        self.durations_given_beginning_minutes
+            .values()
+            .copied()
+            .fold(self.duration_minutes, u16::max)
+    }
 }
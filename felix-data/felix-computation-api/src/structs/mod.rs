@@ -1,7 +1,9 @@
 mod activity_computation_static_data;
 pub mod autoinsertion;
+mod participant_break_rules;
 mod sum_and_duration_indexes;
 
 pub use activity_computation_static_data::ActivityComputationStaticData;
 pub use autoinsertion::AutoinsertionThreadHandle;
+pub use participant_break_rules::ParticipantBreakRules;
 pub use sum_and_duration_indexes::SumAndDurationIndexes;
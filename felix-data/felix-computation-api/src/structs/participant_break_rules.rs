@@ -0,0 +1,9 @@
+use felix_datatypes::BreakRules;
+
+/// Break rules of one participant of an activity.
+#[derive(Clone, Debug)]
+pub struct ParticipantBreakRules {
+    pub break_rules: BreakRules,
+    /// Indexes of the other activities of the participant.
+    pub indexes_of_activities: Vec<usize>,
+}
@@ -9,6 +9,7 @@ fn test_autoinsert_everything_inserted() {
         possible_insertion_beginnings_minutes_sorted: (0..=0).collect(),
         indexes_of_incompatible_activities: vec![],
         duration_minutes: 20,
+        ..Default::default()
     }];
     let insertion_data = vec![0];
     let handle = autoinsert(&static_data, &insertion_data);
@@ -28,6 +29,7 @@ fn test_autoinsert_instant_result_1() {
         possible_insertion_beginnings_minutes_sorted: (0..=0).collect(),
         indexes_of_incompatible_activities: vec![],
         duration_minutes: 20,
+        ..Default::default()
     }];
     let insertion_data = vec![];
     let handle = autoinsert(&static_data, &insertion_data);
@@ -48,11 +50,13 @@ fn test_autoinsert_instant_result_2() {
             possible_insertion_beginnings_minutes_sorted: (0..=10).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![1],
             duration_minutes: 10,
+            ..Default::default()
         },
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..=10).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![0],
             duration_minutes: 20,
+            ..Default::default()
         },
     ];
     let insertion_data = vec![];
@@ -74,36 +78,42 @@ fn test_basic_autoinsert() {
             possible_insertion_beginnings_minutes_sorted: (0..=1000).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![1, 2],
             duration_minutes: 10,
+            ..Default::default()
         },
         // 1
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..=10).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![0],
             duration_minutes: 20,
+            ..Default::default()
         },
         // 2
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..=100).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![0, 3],
             duration_minutes: 10,
+            ..Default::default()
         },
         // 3
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (50..=200).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![2],
             duration_minutes: 20,
+            ..Default::default()
         },
         // 4
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..=10).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![5],
             duration_minutes: 10,
+            ..Default::default()
         },
         // 5
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..=10).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![4],
             duration_minutes: 20,
+            ..Default::default()
         },
     ];
     let insertion_data = vec![0];
@@ -122,6 +132,7 @@ fn test_autoinsert_instant_no_solution() {
         possible_insertion_beginnings_minutes_sorted: BTreeSet::new(),
         indexes_of_incompatible_activities: vec![],
         duration_minutes: 15,
+        ..Default::default()
     }];
     let insertion_data = vec![];
 
@@ -137,17 +148,20 @@ fn test_autoinsert_no_solution() {
             possible_insertion_beginnings_minutes_sorted: (0..=100).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![1],
             duration_minutes: 15,
+            ..Default::default()
         },
         // These activities are incompatible no matter what comes before them
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..=10).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![2],
             duration_minutes: 15,
+            ..Default::default()
         },
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: (0..=10).step_by(5).collect(),
             indexes_of_incompatible_activities: vec![1],
             duration_minutes: 20,
+            ..Default::default()
         },
     ];
     let insertion_data = vec![];
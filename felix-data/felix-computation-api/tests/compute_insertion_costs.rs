@@ -1,8 +1,11 @@
 use felix_computation_api::{
     compute_insertion_costs, compute_insertion_costs::get_activity_beginnings_with_conflicts,
-    structs::ActivityComputationStaticData,
+    structs::{ActivityComputationStaticData, ParticipantBreakRules},
+};
+use felix_datatypes::{
+    BreakRules, Duration, InsertionCostsMinutes, TimeInterval, TimeOfDay, TimePreferences,
+    PREFERENCE_COST_PER_MINUTE,
 };
-use felix_datatypes::InsertionCostsMinutes;
 
 use std::collections::BTreeSet;
 
@@ -13,11 +16,13 @@ fn test_filter_conflicts() {
             possible_insertion_beginnings_minutes_sorted: btreeset_from_slice(&[]), // We don't care
             indexes_of_incompatible_activities: vec![2],
             duration_minutes: 10,
+            ..Default::default()
         },
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: btreeset_from_slice(&[]), // We don't care
             indexes_of_incompatible_activities: vec![2],
             duration_minutes: 15,
+            ..Default::default()
         },
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: btreeset_from_slice(&[
@@ -25,6 +30,7 @@ fn test_filter_conflicts() {
             ]),
             indexes_of_incompatible_activities: vec![0, 1],
             duration_minutes: 10,
+            ..Default::default()
         },
     ];
 
@@ -48,18 +54,21 @@ fn test_filter_conflicts_real_life() {
             possible_insertion_beginnings_minutes_sorted: btreeset_from_slice(&[]), // We don't care
             indexes_of_incompatible_activities: vec![3, 1, 2],
             duration_minutes: 35,
+            ..Default::default()
         },
         // 1
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: btreeset_from_slice(&[]), // We don't care
             indexes_of_incompatible_activities: vec![3, 0, 2],
             duration_minutes: 35,
+            ..Default::default()
         },
         // 2
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: btreeset_from_slice(&[]), // We don't care
             indexes_of_incompatible_activities: vec![3, 0, 1],
             duration_minutes: 25,
+            ..Default::default()
         },
         // 3
         ActivityComputationStaticData {
@@ -69,6 +78,7 @@ fn test_filter_conflicts_real_life() {
                 .collect::<BTreeSet<_>>(),
             indexes_of_incompatible_activities: vec![0, 1, 2],
             duration_minutes: 40,
+            ..Default::default()
         },
     ];
 
@@ -80,10 +90,220 @@ fn test_filter_conflicts_real_life() {
         vec![InsertionCostsMinutes {
             beginning_minutes: 515,
             cost: 0,
+            duration_minutes: 40,
         }]
     );
 }
 
+#[test]
+fn test_filter_conflicts_with_transition_buffer() {
+    let static_data = vec![
+        ActivityComputationStaticData {
+            possible_insertion_beginnings_minutes_sorted: btreeset_from_slice(&[]), // We don't care
+            indexes_of_incompatible_activities: vec![1],
+            duration_minutes: 30,
+            ..Default::default()
+        },
+        ActivityComputationStaticData {
+            possible_insertion_beginnings_minutes_sorted: (0..=120).step_by(5).collect(),
+            indexes_of_incompatible_activities: vec![0],
+            duration_minutes: 30,
+            transition_buffer_minutes: 15,
+            ..Default::default()
+        },
+    ];
+
+    let insertion_data = vec![0];
+
+    // The inserted activity ends at 30, 15 minutes are needed before the next one
+    let expected = (45..=120).step_by(5).collect::<BTreeSet<_>>();
+    assert_eq!(
+        get_activity_beginnings_with_conflicts(&static_data, &insertion_data, 1),
+        expected
+    );
+}
+
+#[test]
+fn test_filter_conflicts_variable_duration() {
+    let static_data = vec![
+        ActivityComputationStaticData {
+            possible_insertion_beginnings_minutes_sorted: btreeset_from_slice(&[]), // We don't care
+            indexes_of_incompatible_activities: vec![1],
+            duration_minutes: 30,
+            ..Default::default()
+        },
+        ActivityComputationStaticData {
+            possible_insertion_beginnings_minutes_sorted: btreeset_from_slice(&[0, 10, 20, 30]),
+            indexes_of_incompatible_activities: vec![0],
+            duration_minutes: 20,
+            durations_given_beginning_minutes: [(0, 60), (20, 45)].iter().copied().collect(),
+            ..Default::default()
+        },
+    ];
+
+    let insertion_data = vec![60];
+
+    // Beginning at 20, the activity lasts until 65
+    let insertion_costs = compute_insertion_costs(&static_data, &insertion_data, 1);
+    assert_eq!(
+        insertion_costs
+            .iter()
+            .map(|insertion_cost| (insertion_cost.beginning_minutes, insertion_cost.duration_minutes))
+            .collect::<Vec<_>>(),
+        vec![(0, 60), (10, 20), (30, 20)]
+    );
+}
+
+#[test]
+fn test_filter_break_rules() {
+    let static_data = vec![
+        ActivityComputationStaticData {
+            possible_insertion_beginnings_minutes_sorted: btreeset_from_slice(&[]), // We don't care
+            indexes_of_incompatible_activities: vec![1],
+            duration_minutes: 60,
+            ..Default::default()
+        },
+        ActivityComputationStaticData {
+            possible_insertion_beginnings_minutes_sorted: (0..=180).step_by(30).collect(),
+            indexes_of_incompatible_activities: vec![0],
+            duration_minutes: 60,
+            break_rules_of_participants: vec![ParticipantBreakRules {
+                break_rules: BreakRules::new(Some(Duration::new(1, 30)), Vec::new()),
+                indexes_of_activities: vec![0],
+            }],
+            ..Default::default()
+        },
+    ];
+
+    let insertion_data = vec![0];
+
+    // 0 and 30 overlap with the inserted activity,
+    // 60 would make two hours of continuous work
+    let expected = btreeset_from_slice(&[90, 120, 150, 180]);
+    assert_eq!(
+        get_activity_beginnings_with_conflicts(&static_data, &insertion_data, 1),
+        expected
+    );
+}
+
+#[test]
+fn test_filter_sessions_order() {
+    let static_data = vec![
+        ActivityComputationStaticData {
+            possible_insertion_beginnings_minutes_sorted: btreeset_from_slice(&[]), // We don't care
+            duration_minutes: 60,
+            index_of_next_session: Some(1),
+            ..Default::default()
+        },
+        ActivityComputationStaticData {
+            possible_insertion_beginnings_minutes_sorted: (0..=180).step_by(30).collect(),
+            duration_minutes: 60,
+            index_of_previous_session: Some(0),
+            ..Default::default()
+        },
+    ];
+
+    let insertion_data = vec![60];
+
+    // The previous session ends at 120
+    let expected = btreeset_from_slice(&[120, 150, 180]);
+    assert_eq!(
+        get_activity_beginnings_with_conflicts(&static_data, &insertion_data, 1),
+        expected
+    );
+}
+
+#[test]
+fn test_filter_sessions_min_spacing() {
+    let static_data = vec![
+        ActivityComputationStaticData {
+            possible_insertion_beginnings_minutes_sorted: btreeset_from_slice(&[]), // We don't care
+            duration_minutes: 60,
+            index_of_next_session: Some(2),
+            min_spacing_between_sessions_minutes: 60,
+            ..Default::default()
+        },
+        ActivityComputationStaticData {
+            possible_insertion_beginnings_minutes_sorted: btreeset_from_slice(&[]), // We don't care
+            duration_minutes: 60,
+            index_of_previous_session: Some(2),
+            min_spacing_between_sessions_minutes: 60,
+            ..Default::default()
+        },
+        ActivityComputationStaticData {
+            possible_insertion_beginnings_minutes_sorted: (0..=600).step_by(60).collect(),
+            duration_minutes: 60,
+            index_of_previous_session: Some(0),
+            index_of_next_session: Some(1),
+            min_spacing_between_sessions_minutes: 60,
+            ..Default::default()
+        },
+    ];
+
+    let insertion_data = vec![60, 480];
+
+    // The previous occurrence ends at 120 and the next one begins at 480
+    let expected = btreeset_from_slice(&[180, 240, 300, 360]);
+    assert_eq!(
+        get_activity_beginnings_with_conflicts(&static_data, &insertion_data, 2),
+        expected
+    );
+}
+
+#[test]
+fn test_filter_simultaneous_activities() {
+    let static_data = vec![
+        ActivityComputationStaticData {
+            possible_insertion_beginnings_minutes_sorted: btreeset_from_slice(&[]), // We don't care
+            duration_minutes: 60,
+            indexes_of_simultaneous_activities: vec![1],
+            ..Default::default()
+        },
+        ActivityComputationStaticData {
+            possible_insertion_beginnings_minutes_sorted: (0..=180).step_by(30).collect(),
+            duration_minutes: 30,
+            indexes_of_simultaneous_activities: vec![0],
+            ..Default::default()
+        },
+    ];
+
+    let insertion_data = vec![60];
+
+    // The simultaneous activity begins at 60
+    let expected = btreeset_from_slice(&[60]);
+    assert_eq!(
+        get_activity_beginnings_with_conflicts(&static_data, &insertion_data, 1),
+        expected
+    );
+}
+
+#[test]
+fn test_insertion_costs_simultaneous_activities() {
+    let static_data = vec![
+        ActivityComputationStaticData {
+            possible_insertion_beginnings_minutes_sorted: btreeset_from_slice(&[0, 30, 60, 90]),
+            duration_minutes: 30,
+            indexes_of_simultaneous_activities: vec![1],
+            ..Default::default()
+        },
+        ActivityComputationStaticData {
+            possible_insertion_beginnings_minutes_sorted: btreeset_from_slice(&[30, 90, 120]),
+            duration_minutes: 30,
+            indexes_of_simultaneous_activities: vec![0],
+            ..Default::default()
+        },
+    ];
+
+    let insertion_data = Vec::new();
+
+    // Only the beginnings which both activities can take are kept
+    let beginnings = compute_insertion_costs(&static_data, &insertion_data, 0)
+        .iter()
+        .map(|insertion_cost| insertion_cost.beginning_minutes)
+        .collect::<Vec<_>>();
+    assert_eq!(beginnings, vec![30, 90]);
+}
+
 #[test]
 fn test_insertion_costs_simplest() {
     let static_data = vec![
@@ -93,11 +313,13 @@ fn test_insertion_costs_simplest() {
             ]),
             indexes_of_incompatible_activities: vec![1],
             duration_minutes: 10,
+            ..Default::default()
         },
         ActivityComputationStaticData {
             possible_insertion_beginnings_minutes_sorted: btreeset_from_slice(&[0, 5, 10, 20]),
             indexes_of_incompatible_activities: vec![0],
             duration_minutes: 15,
+            ..Default::default()
         },
     ];
 
@@ -133,6 +355,73 @@ fn test_insertion_costs_simplest() {
                                            // -> 2 blocked * 1 incompatible activities
 }
 
+#[test]
+fn test_insertion_costs_incompatible_activity_variable_duration() {
+    let static_data = vec![
+        ActivityComputationStaticData {
+            possible_insertion_beginnings_minutes_sorted: btreeset_from_slice(&[20, 30, 50]),
+            indexes_of_incompatible_activities: vec![1],
+            duration_minutes: 10,
+            ..Default::default()
+        },
+        ActivityComputationStaticData {
+            possible_insertion_beginnings_minutes_sorted: btreeset_from_slice(&[0, 10, 40]),
+            indexes_of_incompatible_activities: vec![0],
+            duration_minutes: 10,
+            durations_given_beginning_minutes: [(0, 30)].iter().copied().collect(),
+            ..Default::default()
+        },
+    ];
+
+    let insertion_costs = compute_insertion_costs(&static_data, &[], 0)
+        .iter()
+        .map(|insertion_cost| insertion_cost.cost)
+        .collect::<Vec<_>>();
+
+    assert_eq!(insertion_costs[0], 5000); // 20 Blocks 0, which lasts until 30
+                                          // -> 1 blocked * 1 incompatible activities
+    assert_eq!(insertion_costs[1], 0); // 30 Blocks nothing
+    assert_eq!(insertion_costs[2], 0); // 50 Blocks nothing
+}
+
+#[test]
+fn test_insertion_costs_with_preferences() {
+    let static_data = vec![ActivityComputationStaticData {
+        possible_insertion_beginnings_minutes_sorted: btreeset_from_slice(&[0, 10, 20, 30, 40]),
+        indexes_of_incompatible_activities: vec![],
+        duration_minutes: 20,
+        preferences_of_participants: vec![
+            // Dislikes 00:00 - 00:30
+            TimePreferences::new(
+                Vec::new(),
+                vec![TimeInterval::new(TimeOfDay::new(0, 0), TimeOfDay::new(0, 30))],
+            ),
+            // Prefers 00:20 - 01:00
+            TimePreferences::new(
+                vec![TimeInterval::new(TimeOfDay::new(0, 20), TimeOfDay::new(1, 0))],
+                Vec::new(),
+            ),
+        ],
+        ..Default::default()
+    }];
+
+    let insertion_costs = compute_insertion_costs(&static_data, &[], 0)
+        .iter()
+        .map(|insertion_cost| insertion_cost.cost)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        insertion_costs,
+        vec![
+            40 * PREFERENCE_COST_PER_MINUTE, // 20 disliked + 20 not preferred
+            30 * PREFERENCE_COST_PER_MINUTE, // 20 disliked + 10 not preferred
+            10 * PREFERENCE_COST_PER_MINUTE, // 10 disliked
+            0,
+            0,
+        ]
+    );
+}
+
 fn btreeset_from_slice(slice: &[u16]) -> BTreeSet<u16> {
     slice.iter().map(|&i| i as u16).collect::<BTreeSet<_>>()
 }
@@ -66,12 +66,12 @@ fn test_case_can_fit_in_schedule(
 #[test]
 fn test_find_possible_beginnings() {
     // Activity fits perfectly in the work hour
-    let res = find_possible_beginnings(&[WorkHourInMinutes::new(10, 30)], &[20]);
+    let res = find_possible_beginnings(&[WorkHourInMinutes::new(10, 30)], &[(20, 20)]);
     let expected = activity_beginnings_given_duration(&[20], &[&[10]]);
     assert_eq!(res, expected);
 
     // Two activities with same duration - tests symmetry
-    let res = find_possible_beginnings(&[WorkHourInMinutes::new(10, 30)], &[10, 10]);
+    let res = find_possible_beginnings(&[WorkHourInMinutes::new(10, 30)], &[(10, 10), (10, 10)]);
     let expected = activity_beginnings_given_duration(&[10], &[&[10, 20]]);
     assert_eq!(res, expected);
 
@@ -84,7 +84,7 @@ fn test_find_possible_beginnings() {
             WorkHourInMinutes::new(200, 220),
             WorkHourInMinutes::new(300, 400),
         ],
-        &[100],
+        &[(100, 100)],
     );
     let expected = activity_beginnings_given_duration(&[100], &[&[300]]);
     assert_eq!(res, expected);
@@ -95,7 +95,7 @@ fn test_find_possible_beginnings() {
             WorkHourInMinutes::new(1300, 1400),
             WorkHourInMinutes::new(1000, 1200),
         ],
-        &[50, 150],
+        &[(50, 50), (150, 150)],
     );
     let expected = activity_beginnings_given_duration(
         &[50, 150],
@@ -118,24 +118,64 @@ fn test_find_possible_beginnings() {
             WorkHourInMinutes::new(300, 350),
             WorkHourInMinutes::new(100, 200),
         ],
-        &[125],
+        &[(125, 125)],
     );
     let expected = activity_beginnings_given_duration(&[125], &[&[]]);
     assert_eq!(res, expected);
 
     // Result which used to be a problem - bug has been resolved since then, but keep it
-    let res = find_possible_beginnings(&[WorkHourInMinutes::new(480, 700)], &[20, 35, 40, 45]);
+    let res = find_possible_beginnings(
+        &[WorkHourInMinutes::new(480, 700)],
+        &[(20, 20), (35, 35), (40, 40), (45, 45)],
+    );
     let expected =
         activity_beginnings_given_duration(&[40], &[&(480..=660).step_by(5).collect::<Vec<u16>>()]);
-    assert_eq!(res[&40], expected[&40]);
+    assert_eq!(res[&(40, 40)], expected[&(40, 40)]);
 
     // Duplicate values
-    let res = find_possible_beginnings(&[WorkHourInMinutes::new(480, 615)], &[25, 35, 35, 40]);
+    let res = find_possible_beginnings(
+        &[WorkHourInMinutes::new(480, 615)],
+        &[(25, 25), (35, 35), (35, 35), (40, 40)],
+    );
     let expected = activity_beginnings_given_duration(&[40], &[&[480, 505, 515, 540, 550, 575]]);
-    assert_eq!(res[&40], expected[&40])
+    assert_eq!(res[&(40, 40)], expected[&(40, 40)]);
+
+    // Work hour crossing midnight (22:00 - 26:00)
+    let res = find_possible_beginnings(
+        &[WorkHourInMinutes::new(1320, 1560)],
+        &[(120, 120), (120, 120)],
+    );
+    let expected = activity_beginnings_given_duration(&[120], &[&[1320, 1440]]);
+    assert_eq!(res, expected);
+}
+
+#[test]
+fn test_find_possible_beginnings_duration_range() {
+    // Every duration of the range fits in the work hour
+    let res = find_possible_beginnings(&[WorkHourInMinutes::new(10, 70)], &[(30, 60)]);
+    assert_eq!(
+        res[&(30, 30)],
+        hashset_from_slice(&(10..=40).step_by(5).collect::<Vec<u16>>())
+    );
+    assert_eq!(
+        res[&(30, 45)],
+        hashset_from_slice(&(10..=25).step_by(5).collect::<Vec<u16>>())
+    );
+    assert_eq!(res[&(30, 60)], hashset_from_slice(&[10]));
+    assert_eq!(res.len(), 7);
+
+    // The range is longer than the work hour
+    let res = find_possible_beginnings(&[WorkHourInMinutes::new(10, 70)], &[(50, 90)]);
+    assert_eq!(res[&(50, 60)], hashset_from_slice(&[10]));
+    assert!(!res.contains_key(&(50, 65)));
+
+    // Another activity takes the time the flexible activity could have taken
+    let res = find_possible_beginnings(&[WorkHourInMinutes::new(10, 70)], &[(30, 30), (30, 60)]);
+    let expected = activity_beginnings_given_duration(&[30], &[&[10, 40]]);
+    assert_eq!(res, expected);
 }
 
-/// Given activity durations and possible beginnings for each duration (parallel slices),
+/// Given fixed activity durations and possible beginnings for each duration (parallel slices),
 /// create the corresponding ActivityBeginningsGivenDurationMinutes struct.
 fn activity_beginnings_given_duration(
     activity_durations: &[u16],
@@ -143,7 +183,10 @@ fn activity_beginnings_given_duration(
 ) -> ActivityBeginningsGivenDurationMinutes {
     let mut res = ActivityBeginningsGivenDurationMinutes::new();
     for (index, duration) in activity_durations.iter().enumerate() {
-        res.insert(*duration, hashset_from_slice(possible_beginnings[index]));
+        res.insert(
+            (*duration, *duration),
+            hashset_from_slice(possible_beginnings[index]),
+        );
     }
     res
 }
@@ -0,0 +1,172 @@
+use crate::{ActivityBeginningMinutes, Duration, TimeInterval};
+
+use serde::{Deserialize, Serialize};
+
+/// A break which must be taken inside of a time window,
+/// e.g. at least 00:45 free between 11:30 and 14:00.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MandatoryBreak {
+    window: TimeInterval,
+    duration: Duration,
+}
+
+impl MandatoryBreak {
+    /// Creates a new mandatory break.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the duration is null or longer than the window.
+    #[must_use]
+    pub fn new(window: TimeInterval, duration: Duration) -> MandatoryBreak {
+        assert!(
+            duration > Duration::default() && duration <= window.duration(),
+            "A mandatory break must fit in its window"
+        );
+        MandatoryBreak { window, duration }
+    }
+
+    /// Simple getter for the window.
+    #[must_use]
+    pub fn window(&self) -> TimeInterval {
+        self.window
+    }
+
+    /// Simple getter for the duration.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns true if the break can be taken given the (beginning, end) intervals in minutes
+    /// of the activities of an entity, sorted by beginning.
+    fn can_be_taken(&self, activities_sorted: &[(ActivityBeginningMinutes, u16)]) -> bool {
+        let window_beginning = self.window.beginning().total_minutes();
+        let window_end = self.window.end().total_minutes();
+        let duration = self.duration.total_minutes();
+
+        let mut free_since = window_beginning;
+        for &(beginning, end) in activities_sorted {
+            if beginning >= window_end {
+                break;
+            }
+            if beginning >= free_since + duration {
+                return true;
+            }
+            free_since = free_since.max(end);
+        }
+        window_end >= free_since + duration
+    }
+}
+
+/// Rules which prevent an entity from being booked for too long without a break.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BreakRules {
+    max_continuous_work: Option<Duration>,
+    mandatory_breaks: Vec<MandatoryBreak>,
+}
+
+impl BreakRules {
+    #[must_use]
+    pub fn new(
+        max_continuous_work: Option<Duration>,
+        mandatory_breaks: Vec<MandatoryBreak>,
+    ) -> BreakRules {
+        BreakRules {
+            max_continuous_work,
+            mandatory_breaks,
+        }
+    }
+
+    /// Simple getter for the maximum time an entity can spend in back-to-back activities.
+    #[must_use]
+    pub fn max_continuous_work(&self) -> Option<Duration> {
+        self.max_continuous_work
+    }
+
+    /// Simple getter for the mandatory breaks.
+    #[must_use]
+    pub fn mandatory_breaks(&self) -> &Vec<MandatoryBreak> {
+        &self.mandatory_breaks
+    }
+
+    /// Returns true if there are no rules.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.max_continuous_work.is_none() && self.mandatory_breaks.is_empty()
+    }
+
+    /// Simple setter for the maximum continuous work.
+    pub fn set_max_continuous_work(&mut self, max_continuous_work: Option<Duration>) {
+        self.max_continuous_work = max_continuous_work;
+    }
+
+    /// Adds a mandatory break.
+    pub fn add_mandatory_break(&mut self, mandatory_break: MandatoryBreak) {
+        self.mandatory_breaks.push(mandatory_break);
+    }
+
+    /// Removes the given mandatory break.
+    /// Returns false if it was not found.
+    pub fn remove_mandatory_break(&mut self, mandatory_break: MandatoryBreak) -> bool {
+        if let Some(index) = self
+            .mandatory_breaks
+            .iter()
+            .position(|&other| other == mandatory_break)
+        {
+            self.mandatory_breaks.remove(index);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Combines two sets of rules : the strictest maximum continuous work is kept
+    /// and every mandatory break must be taken.
+    #[must_use]
+    pub fn combined_with(&self, other: &BreakRules) -> BreakRules {
+        let max_continuous_work = match (self.max_continuous_work, other.max_continuous_work) {
+            (Some(max), Some(other_max)) => Some(max.min(other_max)),
+            (max, other_max) => max.or(other_max),
+        };
+        let mut mandatory_breaks = self.mandatory_breaks.clone();
+        mandatory_breaks.extend(other.mandatory_breaks.iter().copied());
+        BreakRules::new(max_continuous_work, mandatory_breaks)
+    }
+
+    /// Returns true if the rules are respected by the given (beginning, end) intervals in minutes.
+    /// The intervals must be the activities of a single entity and must not overlap.
+    #[must_use]
+    pub fn are_respected_by(&self, mut activities: Vec<(ActivityBeginningMinutes, u16)>) -> bool {
+        activities.sort_unstable();
+        self.max_continuous_work_is_respected_by(&activities)
+            && self
+                .mandatory_breaks
+                .iter()
+                .all(|mandatory_break| mandatory_break.can_be_taken(&activities))
+    }
+
+    fn max_continuous_work_is_respected_by(
+        &self,
+        activities_sorted: &[(ActivityBeginningMinutes, u16)],
+    ) -> bool {
+        if let Some(max_continuous_work) = self.max_continuous_work {
+            let max_continuous_work = max_continuous_work.total_minutes();
+            let mut block: Option<(u16, u16)> = None;
+            for &(beginning, end) in activities_sorted {
+                block = match block {
+                    // Back-to-back activities are part of the same block
+                    Some((block_beginning, block_end)) if beginning <= block_end => {
+                        Some((block_beginning, block_end.max(end)))
+                    }
+                    _ => Some((beginning, end)),
+                };
+                if let Some((block_beginning, block_end)) = block {
+                    if block_end - block_beginning > max_continuous_work {
+                        return false;
+                    }
+                }
+            }
+        }
+        true
+    }
+}
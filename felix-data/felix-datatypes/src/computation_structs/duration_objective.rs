@@ -0,0 +1,28 @@
+use crate::ActivityDurationMinutes;
+
+use serde::{Deserialize, Serialize};
+
+/// Which duration to choose when an activity may last anywhere in a duration range.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DurationObjective {
+    /// The longer the activity, the better.
+    #[default]
+    Longest,
+    /// The shorter the activity, the better.
+    Shortest,
+}
+
+impl DurationObjective {
+    /// Returns the best duration among the given ones.
+    /// Returns None if no duration is given.
+    #[must_use]
+    pub fn best_duration<I>(self, durations: I) -> Option<ActivityDurationMinutes>
+    where
+        I: IntoIterator<Item = ActivityDurationMinutes>,
+    {
+        match self {
+            DurationObjective::Longest => durations.into_iter().max(),
+            DurationObjective::Shortest => durations.into_iter().min(),
+        }
+    }
+}
@@ -1,3 +1,9 @@
+mod break_rules;
+mod duration_objective;
+mod time_preferences;
 mod work_hours_and_activity_durations_sorted;
 
+pub use break_rules::{BreakRules, MandatoryBreak};
+pub use duration_objective::DurationObjective;
+pub use time_preferences::{TimePreferences, PREFERENCE_COST_PER_MINUTE};
 pub use work_hours_and_activity_durations_sorted::WorkHoursAndActivityDurationsSorted;
@@ -0,0 +1,82 @@
+use crate::{ActivityBeginningMinutes, ActivityDurationMinutes, Cost, TimeInterval};
+
+use serde::{Deserialize, Serialize};
+
+/// Cost added for each minute of an activity which is spent in a disliked interval
+/// or outside of the preferred intervals of a participant.
+pub const PREFERENCE_COST_PER_MINUTE: Cost = 100;
+
+/// Soft time preferences of an entity.
+///
+/// Unlike work hours, preferences do not forbid any insertion time : they only make
+/// some insertion times more expensive than others.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TimePreferences {
+    preferred_intervals: Vec<TimeInterval>,
+    disliked_intervals: Vec<TimeInterval>,
+}
+
+impl TimePreferences {
+    #[must_use]
+    pub fn new(
+        preferred_intervals: Vec<TimeInterval>,
+        disliked_intervals: Vec<TimeInterval>,
+    ) -> TimePreferences {
+        TimePreferences {
+            preferred_intervals,
+            disliked_intervals,
+        }
+    }
+
+    /// Simple getter for the preferred intervals.
+    #[must_use]
+    pub fn preferred_intervals(&self) -> &Vec<TimeInterval> {
+        &self.preferred_intervals
+    }
+
+    /// Simple getter for the disliked intervals.
+    #[must_use]
+    pub fn disliked_intervals(&self) -> &Vec<TimeInterval> {
+        &self.disliked_intervals
+    }
+
+    /// Returns true if there are no preferred nor disliked intervals.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.preferred_intervals.is_empty() && self.disliked_intervals.is_empty()
+    }
+
+    /// Returns the cost of an activity inserted at the given beginning.
+    ///
+    /// Every minute spent in a disliked interval costs PREFERENCE\_COST\_PER\_MINUTE.
+    /// If there are preferred intervals, every minute spent outside of them costs
+    /// PREFERENCE\_COST\_PER\_MINUTE as well.
+    #[must_use]
+    pub fn cost_of_insertion(
+        &self,
+        beginning: ActivityBeginningMinutes,
+        duration: ActivityDurationMinutes,
+    ) -> Cost {
+        let end = beginning + duration;
+        let minutes_disliked = minutes_spent_in(&self.disliked_intervals, beginning, end);
+        let minutes_not_preferred = if self.preferred_intervals.is_empty() {
+            0
+        } else {
+            duration - minutes_spent_in(&self.preferred_intervals, beginning, end)
+        };
+        (minutes_disliked + minutes_not_preferred) as Cost * PREFERENCE_COST_PER_MINUTE
+    }
+}
+
+/// Returns the number of minutes of [beginning, end[ which are spent in the given intervals.
+/// The intervals must not overlap.
+fn minutes_spent_in(intervals: &[TimeInterval], beginning: u16, end: u16) -> u16 {
+    intervals
+        .iter()
+        .map(|interval| {
+            let overlap_beginning = beginning.max(interval.beginning().total_minutes());
+            let overlap_end = end.min(interval.end().total_minutes());
+            overlap_end.saturating_sub(overlap_beginning)
+        })
+        .sum()
+}
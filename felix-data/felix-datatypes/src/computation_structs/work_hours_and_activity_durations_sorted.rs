@@ -1,22 +1,29 @@
-use crate::{Time, TimeInterval, WorkHourInMinutes};
+use crate::{Duration, TimeInterval, WorkHourInMinutes};
 
 use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct WorkHoursAndActivityDurationsSorted {
     work_hours: Vec<TimeInterval>,
-    activity_durations: Vec<Time>,
+    activity_durations: Vec<Duration>,
+    /// Maximum duration of each activity, parallel to activity_durations.
+    #[serde(default)]
+    max_activity_durations: Vec<Duration>,
 }
 
 impl WorkHoursAndActivityDurationsSorted {
+    /// Creates a new struct from the work hours and the (minimum, maximum) duration
+    /// of each activity.
     pub fn new(
         mut work_hours: Vec<TimeInterval>,
-        mut activity_durations: Vec<Time>,
+        mut activity_durations: Vec<(Duration, Duration)>,
     ) -> WorkHoursAndActivityDurationsSorted {
         work_hours.sort_by_key(|a| a.duration());
         activity_durations.sort();
+        let (activity_durations, max_activity_durations) = activity_durations.into_iter().unzip();
         WorkHoursAndActivityDurationsSorted {
             work_hours,
             activity_durations,
+            max_activity_durations,
         }
     }
 
@@ -32,10 +39,23 @@ impl WorkHoursAndActivityDurationsSorted {
             .collect()
     }
 
-    pub fn activity_durations_in_minutes(&self) -> Vec<u16> {
+    /// Returns the (minimum, maximum) duration of each activity in minutes,
+    /// sorted by minimum duration.
+    pub fn activity_durations_in_minutes(&self) -> Vec<(u16, u16)> {
         self.activity_durations
             .iter()
-            .map(|activity_duration| activity_duration.total_minutes())
+            .enumerate()
+            .map(|(index, activity_duration)| {
+                let max_duration = self
+                    .max_activity_durations
+                    .get(index)
+                    .copied()
+                    .unwrap_or(*activity_duration);
+                (
+                    activity_duration.total_minutes(),
+                    max_duration.total_minutes(),
+                )
+            })
             .collect()
     }
 }
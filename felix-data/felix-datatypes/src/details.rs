@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Free-form information attached to an entity, a group or an activity.
+///
+/// Details have no effect on scheduling. They are shown to the user, exported
+/// and can be used to filter entities, groups and activities.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Details {
+    pub notes: String,
+    /// None means no email address.
+    pub email: Option<String>,
+    pub tags: BTreeSet<String>,
+    /// User-defined fields, stored by field name.
+    pub custom_fields: BTreeMap<String, String>,
+}
+
+impl Details {
+    /// Returns true if the details contain the given tag.
+    #[must_use]
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.contains(tag)
+    }
+
+    /// Returns the value of the custom field with the given name, if it is set.
+    #[must_use]
+    pub fn custom_field(&self, name: &str) -> Option<&String> {
+        self.custom_fields.get(name)
+    }
+
+    /// Returns true if the given text appears in the notes, the email, a tag
+    /// or a custom field value. The search is case insensitive.
+    #[must_use]
+    pub fn contains_text(&self, text: &str) -> bool {
+        let text = text.to_lowercase();
+        let contains = |s: &str| s.to_lowercase().contains(&text);
+
+        contains(&self.notes)
+            || self.email.as_deref().is_some_and(contains)
+            || self.tags.iter().any(|tag| contains(tag))
+            || self.custom_fields.values().any(|value| contains(value))
+    }
+}
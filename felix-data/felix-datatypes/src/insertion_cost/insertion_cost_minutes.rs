@@ -1,9 +1,11 @@
-use crate::{ActivityBeginningMinutes, Cost};
+use crate::{ActivityBeginningMinutes, ActivityDurationMinutes, Cost};
 
-/// A simple struct holding the beginning of an activity in minutes and its cost.
+/// A simple struct holding the beginning of an activity in minutes, its cost
+/// and the duration of the activity if inserted at this beginning.
 /// The higher the cost, the more the activtiy blocks other activities.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct InsertionCostsMinutes {
     pub beginning_minutes: ActivityBeginningMinutes,
     pub cost: Cost,
+    pub duration_minutes: ActivityDurationMinutes,
 }
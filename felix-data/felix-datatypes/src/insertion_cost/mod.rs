@@ -1,20 +1,26 @@
 pub mod insertion_cost_minutes;
 
-use crate::{InsertionCostsMinutes, Time};
+use crate::{Duration, InsertionCostsMinutes, TimeOfDay};
 
 use std::cmp::Ordering;
 
-/// Simple struct holding an insertion time and its cost.
+/// Simple struct holding an insertion time, its cost and the duration the activity
+/// would have if inserted at this time.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct InsertionCost {
-    pub beginning: Time,
+    pub beginning: TimeOfDay,
     pub cost: usize,
+    pub duration: Duration,
 }
 
 impl InsertionCost {
     #[must_use]
-    pub fn new(beginning: Time, cost: usize) -> InsertionCost {
-        InsertionCost { beginning, cost }
+    pub fn new(beginning: TimeOfDay, cost: usize, duration: Duration) -> InsertionCost {
+        InsertionCost {
+            beginning,
+            cost,
+            duration,
+        }
     }
 
     #[must_use]
@@ -22,8 +28,9 @@ impl InsertionCost {
         insertion_cost_minutes: InsertionCostsMinutes,
     ) -> InsertionCost {
         InsertionCost {
-            beginning: Time::from_total_minutes(insertion_cost_minutes.beginning_minutes),
+            beginning: TimeOfDay::from_total_minutes(insertion_cost_minutes.beginning_minutes),
             cost: insertion_cost_minutes.cost,
+            duration: Duration::from_total_minutes(insertion_cost_minutes.duration_minutes),
         }
     }
 }
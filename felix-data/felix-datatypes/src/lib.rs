@@ -1,24 +1,35 @@
 use std::collections::{HashMap, HashSet};
 
 mod computation_structs;
+mod details;
 mod insertion_cost;
+mod load_limits;
 mod rgba;
 mod time;
 
-pub use computation_structs::WorkHoursAndActivityDurationsSorted;
+pub use computation_structs::{
+    BreakRules, DurationObjective, MandatoryBreak, TimePreferences,
+    WorkHoursAndActivityDurationsSorted, PREFERENCE_COST_PER_MINUTE,
+};
+pub use details::Details;
 pub use insertion_cost::{insertion_cost_minutes::InsertionCostsMinutes, InsertionCost};
+pub use load_limits::LoadLimits;
 pub use rgba::Rgba;
 pub use time::{
-    Time, TimeInterval, WorkHourInMinutes, MIN_TIME_DISCRETIZATION, MIN_TIME_DISCRETIZATION_MINUTES,
+    Duration, TimeError, TimeInterval, TimeOfDay, WorkHourInMinutes, MAX_TIME,
+    MIN_TIME_DISCRETIZATION, MIN_TIME_DISCRETIZATION_MINUTES, ONE_DAY,
 };
 
 /// Each entity has a set of possible insertion times for every activity duration it has.
+/// The key is the minimum duration of the activity and the duration it is inserted with.
 /// Times are represented in total minutes.
 pub type ActivityBeginningsGivenDurationMinutes =
-    HashMap<ActivityDurationMinutes, HashSet<ActivityBeginningMinutes>>;
+    HashMap<(ActivityDurationMinutes, ActivityDurationMinutes), HashSet<ActivityBeginningMinutes>>;
 
 pub type ActivityInsertionCosts = Option<Vec<InsertionCost>>;
 pub type ActivityId = usize;
+pub type EntityId = usize;
+pub type GroupId = usize;
 pub type ActivityDurationMinutes = u16;
 pub type ActivityBeginningMinutes = u16;
 pub type Cost = usize;
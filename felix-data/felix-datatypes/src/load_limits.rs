@@ -0,0 +1,46 @@
+use crate::Duration;
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Caps on the daily load of an entity.
+///
+/// Limits set on a group apply to each of its members.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LoadLimits {
+    /// Maximum time spent in activities. None means no limit.
+    pub max_activity_time: Option<Duration>,
+    /// Maximum number of activities of each category.
+    pub max_activities_per_category: BTreeMap<String, usize>,
+}
+
+impl LoadLimits {
+    /// Returns the strictest combination of both limits.
+    #[must_use]
+    pub fn combined_with(&self, other: &LoadLimits) -> LoadLimits {
+        let max_activity_time = match (self.max_activity_time, other.max_activity_time) {
+            (Some(max), Some(other_max)) => Some(std::cmp::min(max, other_max)),
+            (max, None) | (None, max) => max,
+        };
+
+        let mut max_activities_per_category = self.max_activities_per_category.clone();
+        for (category, &other_max) in &other.max_activities_per_category {
+            max_activities_per_category
+                .entry(category.clone())
+                .and_modify(|max| *max = std::cmp::min(*max, other_max))
+                .or_insert(other_max);
+        }
+
+        LoadLimits {
+            max_activity_time,
+            max_activities_per_category,
+        }
+    }
+
+    /// Returns the maximum number of activities of the given category.
+    /// None means no limit.
+    #[must_use]
+    pub fn max_activities_of_category(&self, category: &str) -> Option<usize> {
+        self.max_activities_per_category.get(category).copied()
+    }
+}
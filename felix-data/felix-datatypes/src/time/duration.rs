@@ -0,0 +1,178 @@
+use super::{
+    check_hours_and_minutes, hours_and_minutes_from_total_minutes, parse_hours_and_minutes,
+};
+use crate::{TimeError, MIN_TIME_DISCRETIZATION_MINUTES};
+
+use serde::{Deserialize, Serialize};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::str::FromStr;
+
+/// Duration with minute precision, e.g. the duration of an activity.
+///
+/// Any Duration structure should be kept in [00:00, 48:00] and be a multiple of
+/// MIN\_TIME\_DISCRETIZATION. The operators (addition/substraction) panic if these constraints
+/// are violated, in particular if the result is negative ;
+/// use the checked methods to get a Result instead.
+///
+/// Serialized the same way as a TimeOfDay.
+///
+/// Made to be small and copyable, 2-bytes long :
+/// ```
+/// use felix_datatypes::Duration;
+/// use std::mem::size_of;
+/// assert_eq!(size_of::<Duration>(), 2);
+/// ```
+#[derive(
+    Hash, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Default, Serialize, Deserialize,
+)]
+pub struct Duration {
+    hours: i8,
+    minutes: i8,
+}
+
+impl Duration {
+    /// Creates a new duration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the duration is invalid, i.e. not in [00:00, 48:00]
+    /// and not a multiple of MIN\_TIME\_DISCRETIZATION.
+    #[must_use]
+    pub fn new(hours: i8, minutes: i8) -> Duration {
+        assert!(
+            check_hours_and_minutes(hours, minutes).is_ok(),
+            "Duration must be kept in [00:00, 48:00] and be a multiple of MIN_TIME_DISCRETIZATION"
+        );
+        Duration { hours, minutes }
+    }
+
+    /// Creates a new duration without any check. Used for constants.
+    pub(super) const fn from_parts(hours: i8, minutes: i8) -> Duration {
+        Duration { hours, minutes }
+    }
+
+    /// Creates a new duration from a total number of minutes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting duration is invalid, i.e. not in [00:00, 48:00]
+    #[must_use]
+    pub fn from_total_minutes(total_minutes: u16) -> Duration {
+        let hours = (total_minutes / 60) as i8;
+        let minutes = (total_minutes % 60) as i8;
+        Duration::new(hours, minutes)
+    }
+
+    /// Simple getter for the hours.
+    #[must_use]
+    pub fn hours(&self) -> i8 {
+        self.hours
+    }
+
+    /// Simple getter for the minutes.
+    #[must_use]
+    pub fn minutes(&self) -> i8 {
+        self.minutes
+    }
+
+    /// Returns the total number of minutes in the given duration.
+    #[must_use]
+    pub fn total_minutes(&self) -> u16 {
+        self.minutes as u16 + self.hours as u16 * 60
+    }
+
+    /// Returns the number of times MIN\_TIME\_DISCRETIZATION fits in the duration.
+    #[must_use]
+    pub fn n_times_min_discretization(&self) -> i32 {
+        self.total_minutes() as i32 / MIN_TIME_DISCRETIZATION_MINUTES as i32
+    }
+
+    /// Adds both durations.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the result is longer than 48:00.
+    pub fn checked_add(self, other: Duration) -> Result<Duration, TimeError> {
+        let (hours, minutes) = hours_and_minutes_from_total_minutes(
+            self.total_minutes() as i32 + other.total_minutes() as i32,
+        )?;
+        Ok(Duration { hours, minutes })
+    }
+
+    /// Substracts the other duration.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the result is negative.
+    pub fn checked_sub(self, other: Duration) -> Result<Duration, TimeError> {
+        let total_minutes = self
+            .total_minutes()
+            .checked_sub(other.total_minutes())
+            .ok_or(TimeError::OutOfRange)?;
+        let (hours, minutes) = hours_and_minutes_from_total_minutes(total_minutes as i32)?;
+        Ok(Duration { hours, minutes })
+    }
+}
+
+impl Add for Duration {
+    type Output = Duration;
+    fn add(self, other: Duration) -> Duration {
+        self.checked_add(other)
+            .expect("The resulting duration must be in [00:00, 48:00]")
+    }
+}
+
+impl AddAssign for Duration {
+    fn add_assign(&mut self, other: Duration) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Duration {
+    type Output = Duration;
+    fn sub(self, other: Duration) -> Duration {
+        self.checked_sub(other)
+            .expect("Cannot perform a - b where a < b : negative results are not allowed")
+    }
+}
+
+impl SubAssign for Duration {
+    fn sub_assign(&mut self, other: Duration) {
+        *self = *self - other;
+    }
+}
+
+impl<'a> Sum<&'a Self> for Duration {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Duration::default(), |a, &b| a + b)
+    }
+}
+
+impl Sum<Self> for Duration {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Duration::default(), |a, b| a + b)
+    }
+}
+
+impl FromStr for Duration {
+    type Err = TimeError;
+
+    /// Parses a duration written as "1:30", "01:30", "1h30" or "1h".
+    fn from_str(string: &str) -> Result<Duration, TimeError> {
+        let (hours, minutes) = parse_hours_and_minutes(string)?;
+        Ok(Duration { hours, minutes })
+    }
+}
+
+impl std::fmt::Display for Duration {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:02}:{:02}", self.hours, self.minutes)
+    }
+}
@@ -0,0 +1,41 @@
+use crate::MIN_TIME_DISCRETIZATION_MINUTES;
+
+use gettextrs::gettext as tr;
+use std::error::Error;
+use std::fmt;
+
+/// Returned by the checked operations on times of day and durations, and when parsing them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeError {
+    /// The result is not in [00:00, 48:00].
+    OutOfRange,
+    /// The result is not a multiple of MIN\_TIME\_DISCRETIZATION.
+    NotDiscretized,
+    /// The string does not follow any of the accepted formats.
+    InvalidFormat(String),
+}
+
+impl fmt::Display for TimeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TimeError::OutOfRange => {
+                write!(f, "{}", tr("The time must be kept in [00:00, 48:00]."))
+            }
+            TimeError::NotDiscretized => write!(
+                f,
+                "{} {} {}",
+                tr("The time must be a multiple of"),
+                MIN_TIME_DISCRETIZATION_MINUTES,
+                tr("minutes.")
+            ),
+            TimeError::InvalidFormat(string) => write!(
+                f,
+                "'{}' {}",
+                string,
+                tr("is not a valid time. Valid examples are 8:05, 8h05 and 8h.")
+            ),
+        }
+    }
+}
+
+impl Error for TimeError {}
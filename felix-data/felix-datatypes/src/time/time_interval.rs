@@ -1,4 +1,4 @@
-use crate::{Time, MIN_TIME_DISCRETIZATION};
+use crate::{Duration, TimeOfDay, MIN_TIME_DISCRETIZATION, ONE_DAY};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 
@@ -14,8 +14,8 @@ use std::cmp::Ordering;
 /// ```
 #[derive(Eq, PartialEq, Copy, Clone, Debug, Hash, Serialize, Deserialize)]
 pub struct TimeInterval {
-    beginning: Time,
-    end: Time,
+    beginning: TimeOfDay,
+    end: TimeOfDay,
 }
 
 impl TimeInterval {
@@ -26,41 +26,71 @@ impl TimeInterval {
     /// Panics if the interval is too short (or beginning is after the end),
     /// which translates to (end - beginning < MIN\_TIME\_DISCRETIZATION).
     #[must_use]
-    pub fn new(beginning: Time, end: Time) -> TimeInterval {
+    pub fn new(beginning: TimeOfDay, end: TimeOfDay) -> TimeInterval {
         assert!(
-            end - beginning >= MIN_TIME_DISCRETIZATION,
+            end.checked_duration_since(beginning)
+                .is_ok_and(|duration| duration >= MIN_TIME_DISCRETIZATION),
             "A time interval cannot have beginning >= end"
         );
         TimeInterval { beginning, end }
     }
 
+    /// Creates a new TimeInterval which may cross midnight.
+    ///
+    /// If the end is not after the beginning, it is taken on the next day :
+    /// (22:00, 02:00) creates the interval 22:00 - 26:00.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interval is too short or if its end is after MAX\_TIME.
+    #[must_use]
+    pub fn overnight(beginning: TimeOfDay, end: TimeOfDay) -> TimeInterval {
+        let end = if end <= beginning { end + ONE_DAY } else { end };
+        TimeInterval::new(beginning, end)
+    }
+
     /// Simple getter for the beginning.
     #[must_use]
-    pub fn beginning(&self) -> Time {
+    pub fn beginning(&self) -> TimeOfDay {
         self.beginning
     }
 
     /// Simple getter for the end.
     #[must_use]
-    pub fn end(&self) -> Time {
+    pub fn end(&self) -> TimeOfDay {
         self.end
     }
 
     /// Calculates and returns the duration.
     #[must_use]
-    pub fn duration(&self) -> Time {
+    pub fn duration(&self) -> Duration {
         self.end - self.beginning
     }
 
+    /// Returns true if the time interval ends on the next day, i.e. after midnight.
+    #[must_use]
+    pub fn crosses_midnight(&self) -> bool {
+        self.end.total_minutes() > ONE_DAY.total_minutes()
+    }
+
     /// Returns true if the time intervals overlap with each other.
     #[must_use]
     pub fn overlaps_with(&self, other: &TimeInterval) -> bool {
         self.beginning < other.end && self.end > other.beginning
     }
 
+    /// Returns true if less than the given margin separates the time intervals.
+    /// Overlapping intervals are always too close.
+    #[must_use]
+    pub fn is_closer_than(&self, other: &TimeInterval, margin: Duration) -> bool {
+        let margin = margin.total_minutes();
+        self.beginning.total_minutes() < other.end.total_minutes() + margin
+            && other.beginning.total_minutes() < self.end.total_minutes() + margin
+    }
+
     /// Returns true if the time interval contains the given time.
     #[must_use]
-    pub fn contains(&self, time: Time) -> bool {
+    pub fn contains(&self, time: TimeOfDay) -> bool {
         self.beginning <= time && self.end > time
     }
 
@@ -0,0 +1,242 @@
+use super::{
+    check_hours_and_minutes, hours_and_minutes_from_total_minutes, parse_hours_and_minutes,
+    MAX_HOURS,
+};
+use crate::{Duration, TimeError, MIN_TIME_DISCRETIZATION};
+
+use serde::{Deserialize, Serialize};
+use std::convert::TryInto;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::str::FromStr;
+
+/// Time of day with minute precision, e.g. the beginning of an activity.
+///
+/// Any TimeOfDay structure should be kept in [00:00, 48:00] and be a multiple of
+/// MIN\_TIME\_DISCRETIZATION. Times after 24:00 belong to the next day.
+/// The operators (addition/substraction of a Duration) panic if these constraints are violated ;
+/// use the checked methods to get a Result instead.
+///
+/// Made to be small and copyable, 2-bytes long :
+/// ```
+/// use felix_datatypes::TimeOfDay;
+/// use std::mem::size_of;
+/// assert_eq!(size_of::<TimeOfDay>(), 2);
+/// ```
+#[derive(
+    Hash, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Default, Serialize, Deserialize,
+)]
+pub struct TimeOfDay {
+    hours: i8,
+    minutes: i8,
+}
+
+impl TimeOfDay {
+    /// Creates a new time of day.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the time is invalid, i.e. not in [00:00, 48:00]
+    /// and not a multiple of MIN\_TIME\_DISCRETIZATION.
+    #[must_use]
+    pub fn new(hours: i8, minutes: i8) -> TimeOfDay {
+        assert!(
+            check_hours_and_minutes(hours, minutes).is_ok(),
+            "Time must be kept in [00:00, 48:00] and be a multiple of MIN_TIME_DISCRETIZATION"
+        );
+        TimeOfDay { hours, minutes }
+    }
+
+    /// Creates a new time of day without any check. Used for constants.
+    pub(super) const fn from_parts(hours: i8, minutes: i8) -> TimeOfDay {
+        TimeOfDay { hours, minutes }
+    }
+
+    /// Creates a new time of day composed of the sum of n times the MIN\_TIME\_DISCRETIZATION
+    /// constant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting time is invalid, i.e. not in [00:00, 48:00]
+    #[must_use]
+    pub fn from_n_times_min_discretization(n_times_min_discretization: i32) -> TimeOfDay {
+        let total_minutes = n_times_min_discretization * MIN_TIME_DISCRETIZATION.minutes() as i32;
+        TimeOfDay::from_total_minutes(total_minutes.try_into().expect("Overflow error !"))
+    }
+
+    /// Creates a new time of day from a total number of minutes since midnight.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting time is invalid, i.e. not in [00:00, 48:00]
+    #[must_use]
+    pub fn from_total_minutes(total_minutes: u16) -> TimeOfDay {
+        let hours = (total_minutes / 60) as i8;
+        let minutes = (total_minutes % 60) as i8;
+        TimeOfDay::new(hours, minutes)
+    }
+
+    /// Simple getter for the hours.
+    #[must_use]
+    pub fn hours(&self) -> i8 {
+        self.hours
+    }
+
+    /// Simple getter for the minutes.
+    #[must_use]
+    pub fn minutes(&self) -> i8 {
+        self.minutes
+    }
+
+    /// Returns the total number of minutes since midnight.
+    #[must_use]
+    pub fn total_minutes(&self) -> u16 {
+        self.minutes as u16 + self.hours as u16 * 60
+    }
+
+    /// Returns the number of times MIN\_TIME\_DISCRETIZATION fits in the time since midnight.
+    #[must_use]
+    pub fn n_times_min_discretization(&self) -> i32 {
+        self.minutes as i32 / MIN_TIME_DISCRETIZATION.minutes() as i32
+            + self.hours as i32 * (60 / MIN_TIME_DISCRETIZATION.minutes() as i32)
+    }
+
+    /// Adds the given duration.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the result is after 48:00.
+    pub fn checked_add(self, duration: Duration) -> Result<TimeOfDay, TimeError> {
+        let (hours, minutes) = hours_and_minutes_from_total_minutes(
+            self.total_minutes() as i32 + duration.total_minutes() as i32,
+        )?;
+        Ok(TimeOfDay { hours, minutes })
+    }
+
+    /// Substracts the given duration.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the result is before 00:00.
+    pub fn checked_sub(self, duration: Duration) -> Result<TimeOfDay, TimeError> {
+        let total_minutes = self
+            .total_minutes()
+            .checked_sub(duration.total_minutes())
+            .ok_or(TimeError::OutOfRange)?;
+        let (hours, minutes) = hours_and_minutes_from_total_minutes(total_minutes as i32)?;
+        Ok(TimeOfDay { hours, minutes })
+    }
+
+    /// Returns the duration elapsed between the given earlier time and this one.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the given time is after this one.
+    pub fn checked_duration_since(self, earlier: TimeOfDay) -> Result<Duration, TimeError> {
+        self.total_minutes()
+            .checked_sub(earlier.total_minutes())
+            .map(Duration::from_total_minutes)
+            .ok_or(TimeError::OutOfRange)
+    }
+
+    /// Adds or substracts hours.
+    /// Hours can be negative to substract.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is invalid, i.e. not in [00:00, 48:00]
+    /// and not a multiple of MIN\_TIME\_DISCRETIZATION.
+    pub fn add_hours(&mut self, hours: i8) {
+        let new_hours = self.hours + hours;
+        assert!(
+            (0..MAX_HOURS).contains(&new_hours),
+            "The resulting time must be in [00:00, 48:00]"
+        );
+        self.hours = new_hours;
+    }
+
+    /// Adds or substracts minutes.
+    /// Minutes can be negative for substraction but should not go over 60.
+    ///
+    /// # Panics
+    ///
+    /// Panics if minutes.abs() >= 60 or if the result is invalid, i.e. not in [00:00, 48:00]
+    /// and not a multiple of MIN\_TIME\_DISCRETIZATION.
+    pub fn add_minutes(&mut self, minutes: i8) {
+        assert!(
+            minutes.abs() < 60,
+            "If you wish to add more than 60 minutes, use add_hours_and_minutes"
+        );
+        let mut sum_minutes = self.minutes + minutes;
+        if sum_minutes >= 60 {
+            sum_minutes -= 60;
+            self.add_hours(1);
+        } else if sum_minutes < 0 {
+            sum_minutes += 60;
+            self.add_hours(-1);
+        }
+        self.minutes = sum_minutes;
+    }
+
+    /// Convenience method which adds or substracts hours and minutes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if minutes.abs() >= 60 or if the result is invalid, i.e. [00:00, 48:00]
+    /// and not a multiple of MIN\_TIME\_DISCRETIZATION.
+    pub fn add_hours_and_minutes(&mut self, hours: i8, minutes: i8) {
+        self.add_minutes(minutes);
+        self.add_hours(hours);
+    }
+}
+
+impl Add<Duration> for TimeOfDay {
+    type Output = TimeOfDay;
+    fn add(self, duration: Duration) -> TimeOfDay {
+        self.checked_add(duration)
+            .expect("The resulting time must be in [00:00, 48:00]")
+    }
+}
+
+impl AddAssign<Duration> for TimeOfDay {
+    fn add_assign(&mut self, duration: Duration) {
+        *self = *self + duration;
+    }
+}
+
+impl Sub<Duration> for TimeOfDay {
+    type Output = TimeOfDay;
+    fn sub(self, duration: Duration) -> TimeOfDay {
+        self.checked_sub(duration)
+            .expect("Cannot perform a - b where a < b : negative results are not allowed")
+    }
+}
+
+impl SubAssign<Duration> for TimeOfDay {
+    fn sub_assign(&mut self, duration: Duration) {
+        *self = *self - duration;
+    }
+}
+
+impl Sub for TimeOfDay {
+    type Output = Duration;
+    fn sub(self, other: TimeOfDay) -> Duration {
+        self.checked_duration_since(other)
+            .expect("Cannot perform a - b where a < b : negative results are not allowed")
+    }
+}
+
+impl FromStr for TimeOfDay {
+    type Err = TimeError;
+
+    /// Parses a time of day written as "8:05", "08:05", "8h05" or "8h".
+    fn from_str(string: &str) -> Result<TimeOfDay, TimeError> {
+        let (hours, minutes) = parse_hours_and_minutes(string)?;
+        Ok(TimeOfDay { hours, minutes })
+    }
+}
+
+impl std::fmt::Display for TimeOfDay {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:02}:{:02}", self.hours, self.minutes)
+    }
+}
@@ -0,0 +1,69 @@
+use felix_datatypes::{BreakRules, Duration, MandatoryBreak, TimeInterval, TimeOfDay};
+
+fn minutes(hours: u16, minutes: u16) -> u16 {
+    hours * 60 + minutes
+}
+
+#[test]
+fn no_rules_always_respected() {
+    let rules = BreakRules::default();
+    assert!(rules.is_empty());
+    assert!(rules.are_respected_by(vec![(minutes(8, 0), minutes(18, 0))]));
+}
+
+#[test]
+fn max_continuous_work() {
+    let rules = BreakRules::new(Some(Duration::new(3, 0)), Vec::new());
+    // 3 hours back-to-back
+    assert!(rules.are_respected_by(vec![
+        (minutes(9, 0), minutes(11, 0)),
+        (minutes(8, 0), minutes(9, 0)),
+    ]));
+    // 3 hours 30 back-to-back
+    assert!(!rules.are_respected_by(vec![
+        (minutes(8, 0), minutes(9, 0)),
+        (minutes(9, 0), minutes(11, 30)),
+    ]));
+    // 3 hours 30 with a break in between
+    assert!(rules.are_respected_by(vec![
+        (minutes(8, 0), minutes(9, 0)),
+        (minutes(9, 5), minutes(11, 35)),
+    ]));
+}
+
+#[test]
+fn mandatory_break() {
+    let lunch_break = MandatoryBreak::new(
+        TimeInterval::new(TimeOfDay::new(11, 30), TimeOfDay::new(14, 0)),
+        Duration::new(0, 45),
+    );
+    let rules = BreakRules::new(None, vec![lunch_break]);
+
+    // Free from 12:30 to 13:15
+    assert!(rules.are_respected_by(vec![
+        (minutes(11, 0), minutes(12, 30)),
+        (minutes(13, 15), minutes(14, 0)),
+    ]));
+    // Free from 13:15 to 14:00
+    assert!(rules.are_respected_by(vec![(minutes(11, 0), minutes(13, 15))]));
+    // Free from 12:30 to 13:00 and from 13:30 to 14:00
+    assert!(!rules.are_respected_by(vec![
+        (minutes(11, 0), minutes(12, 30)),
+        (minutes(13, 0), minutes(13, 30)),
+        (minutes(14, 0), minutes(15, 0)),
+    ]));
+}
+
+#[test]
+fn combined_rules() {
+    let lunch_break = MandatoryBreak::new(
+        TimeInterval::new(TimeOfDay::new(11, 30), TimeOfDay::new(14, 0)),
+        Duration::new(0, 45),
+    );
+    let global_rules = BreakRules::new(Some(Duration::new(3, 0)), vec![lunch_break]);
+    let custom_rules = BreakRules::new(Some(Duration::new(2, 0)), Vec::new());
+
+    let rules = global_rules.combined_with(&custom_rules);
+    assert_eq!(rules.max_continuous_work(), Some(Duration::new(2, 0)));
+    assert_eq!(rules.mandatory_breaks(), &vec![lunch_break]);
+}
@@ -0,0 +1,54 @@
+use felix_datatypes::{TimeInterval, TimeOfDay, TimePreferences, PREFERENCE_COST_PER_MINUTE};
+
+#[test]
+fn no_preferences_costs_nothing() {
+    let preferences = TimePreferences::default();
+    assert!(preferences.is_empty());
+    assert_eq!(preferences.cost_of_insertion(8 * 60, 60), 0);
+}
+
+#[test]
+fn disliked_intervals_cost() {
+    let preferences = TimePreferences::new(
+        Vec::new(),
+        vec![TimeInterval::new(
+            TimeOfDay::new(12, 0),
+            TimeOfDay::new(14, 0),
+        )],
+    );
+    // Outside of the disliked interval
+    assert_eq!(preferences.cost_of_insertion(8 * 60, 60), 0);
+    // Half in the disliked interval
+    assert_eq!(
+        preferences.cost_of_insertion(11 * 60 + 30, 60),
+        30 * PREFERENCE_COST_PER_MINUTE
+    );
+    // Fully in the disliked interval
+    assert_eq!(
+        preferences.cost_of_insertion(12 * 60, 60),
+        60 * PREFERENCE_COST_PER_MINUTE
+    );
+}
+
+#[test]
+fn preferred_intervals_cost() {
+    let preferences = TimePreferences::new(
+        vec![
+            TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(9, 0)),
+            TimeInterval::new(TimeOfDay::new(9, 0), TimeOfDay::new(10, 0)),
+        ],
+        Vec::new(),
+    );
+    // Fully in the preferred intervals, even if they are split
+    assert_eq!(preferences.cost_of_insertion(8 * 60 + 30, 60), 0);
+    // Half outside of the preferred intervals
+    assert_eq!(
+        preferences.cost_of_insertion(9 * 60 + 30, 60),
+        30 * PREFERENCE_COST_PER_MINUTE
+    );
+    // Fully outside of the preferred intervals
+    assert_eq!(
+        preferences.cost_of_insertion(14 * 60, 60),
+        60 * PREFERENCE_COST_PER_MINUTE
+    );
+}
@@ -0,0 +1,42 @@
+use felix_datatypes::Details;
+
+fn details() -> Details {
+    let mut details = Details {
+        notes: "Allergic to peanuts".to_owned(),
+        email: Some("paul@example.com".to_owned()),
+        ..Details::default()
+    };
+    details.tags.insert("Counsellor".to_owned());
+    details
+        .custom_fields
+        .insert("Phone".to_owned(), "0612345678".to_owned());
+    details
+}
+
+#[test]
+fn has_tag() {
+    let details = details();
+    assert!(details.has_tag("Counsellor"));
+    assert!(!details.has_tag("Cook"));
+}
+
+#[test]
+fn custom_field() {
+    let details = details();
+    assert_eq!(
+        details.custom_field("Phone"),
+        Some(&"0612345678".to_owned())
+    );
+    assert_eq!(details.custom_field("Room"), None);
+}
+
+#[test]
+fn contains_text_searches_every_field() {
+    let details = details();
+    assert!(details.contains_text("peanuts"));
+    assert!(details.contains_text("EXAMPLE.COM"));
+    assert!(details.contains_text("counsel"));
+    assert!(details.contains_text("0612"));
+    assert!(!details.contains_text("Phone"));
+    assert!(!Details::default().contains_text("peanuts"));
+}
@@ -0,0 +1,61 @@
+use felix_datatypes::{Duration, LoadLimits};
+
+#[test]
+fn default_has_no_limits() {
+    let limits = LoadLimits::default();
+    assert_eq!(limits.max_activity_time, None);
+    assert_eq!(limits.max_activities_of_category("Sport"), None);
+}
+
+#[test]
+fn combined_with_keeps_strictest_time() {
+    let limits = LoadLimits {
+        max_activity_time: Some(Duration::new(5, 0)),
+        ..LoadLimits::default()
+    };
+    let other = LoadLimits {
+        max_activity_time: Some(Duration::new(3, 0)),
+        ..LoadLimits::default()
+    };
+    assert_eq!(
+        limits.combined_with(&other).max_activity_time,
+        Some(Duration::new(3, 0))
+    );
+    assert_eq!(
+        limits
+            .combined_with(&LoadLimits::default())
+            .max_activity_time,
+        Some(Duration::new(5, 0))
+    );
+    assert_eq!(
+        LoadLimits::default()
+            .combined_with(&other)
+            .max_activity_time,
+        Some(Duration::new(3, 0))
+    );
+}
+
+#[test]
+fn combined_with_keeps_strictest_category_counts() {
+    let mut limits = LoadLimits::default();
+    limits
+        .max_activities_per_category
+        .insert("Sport".to_owned(), 2);
+    limits
+        .max_activities_per_category
+        .insert("Meeting".to_owned(), 3);
+
+    let mut other = LoadLimits::default();
+    other
+        .max_activities_per_category
+        .insert("Sport".to_owned(), 1);
+    other
+        .max_activities_per_category
+        .insert("Music".to_owned(), 4);
+
+    let combined = limits.combined_with(&other);
+    assert_eq!(combined.max_activities_of_category("Sport"), Some(1));
+    assert_eq!(combined.max_activities_of_category("Meeting"), Some(3));
+    assert_eq!(combined.max_activities_of_category("Music"), Some(4));
+    assert_eq!(combined.max_activities_of_category("Lunch"), None);
+}
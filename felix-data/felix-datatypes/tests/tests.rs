@@ -1,4 +1,11 @@
+mod computation_structs {
+    mod break_rules;
+    mod time_preferences;
+}
+mod details;
+mod load_limits;
 mod time {
-    mod time;
+    mod duration;
     mod time_interval;
+    mod time_of_day;
 }
@@ -0,0 +1,109 @@
+use felix_datatypes::{Duration, TimeError};
+use std::panic::catch_unwind;
+
+#[test]
+fn std_ord() {
+    assert!(Duration::new(2, 30) < Duration::new(3, 0));
+    assert!(Duration::new(2, 30) < Duration::new(2, 35));
+}
+
+#[test]
+fn std_op() {
+    // Add with wrap
+    assert_eq!(
+        Duration::new(1, 40) + Duration::new(1, 30),
+        Duration::new(3, 10)
+    );
+    // Sub with wrap
+    assert_eq!(
+        Duration::new(10, 0) - Duration::new(9, 20),
+        Duration::new(0, 40)
+    );
+
+    // AddAssign
+    let mut duration = Duration::new(1, 0);
+    duration += Duration::new(1, 30);
+    assert_eq!(duration, Duration::new(2, 30));
+    // SubAssign
+    duration -= Duration::new(1, 30);
+    assert_eq!(duration, Duration::new(1, 0));
+
+    // Invalid operations
+    assert!(catch_unwind(|| { Duration::new(2, 35) + Duration::new(46, 0) }).is_err());
+    assert!(catch_unwind(|| { Duration::new(2, 35) - Duration::new(3, 0) }).is_err());
+}
+
+#[test]
+fn checked_op() {
+    assert_eq!(
+        Duration::new(1, 0).checked_add(Duration::new(1, 30)),
+        Ok(Duration::new(2, 30))
+    );
+    assert_eq!(
+        Duration::new(2, 40).checked_sub(Duration::new(1, 30)),
+        Ok(Duration::new(1, 10))
+    );
+
+    // Invalid operations
+    assert_eq!(
+        Duration::new(2, 35).checked_add(Duration::new(46, 0)),
+        Err(TimeError::OutOfRange)
+    );
+    assert_eq!(
+        Duration::new(2, 35).checked_sub(Duration::new(3, 0)),
+        Err(TimeError::OutOfRange)
+    );
+}
+
+#[test]
+fn std_sum() {
+    let durations = [Duration::new(0, 30); 3];
+    assert_eq!(durations.iter().sum::<Duration>(), Duration::new(1, 30));
+    assert_eq!(
+        durations.iter().cloned().sum::<Duration>(),
+        Duration::new(1, 30)
+    );
+    assert_eq!(Vec::new().iter().sum::<Duration>(), Duration::default());
+}
+
+#[test]
+fn invalid_new() {
+    assert!(catch_unwind(|| { Duration::new(49, 0) }).is_err());
+    assert!(catch_unwind(|| { Duration::new(0, 60) }).is_err());
+    assert!(catch_unwind(|| { Duration::new(-1, 0) }).is_err());
+    assert!(catch_unwind(|| { Duration::new(0, 7) }).is_err());
+}
+
+#[test]
+fn from_str() {
+    assert_eq!("1h30".parse(), Ok(Duration::new(1, 30)));
+    assert_eq!("1H30".parse(), Ok(Duration::new(1, 30)));
+    assert_eq!("1:30".parse(), Ok(Duration::new(1, 30)));
+    assert_eq!("8h".parse(), Ok(Duration::new(8, 0)));
+    assert_eq!("0h45".parse(), Ok(Duration::new(0, 45)));
+
+    // Invalid strings
+    assert_eq!(
+        "1.5".parse::<Duration>(),
+        Err(TimeError::InvalidFormat("1.5".to_owned()))
+    );
+    assert_eq!("0h33".parse::<Duration>(), Err(TimeError::NotDiscretized));
+}
+
+#[test]
+fn display() {
+    assert_eq!(format!("{}", Duration::new(1, 5)), "01:05");
+    assert_eq!(
+        TimeError::InvalidFormat("1.5".to_owned()).to_string(),
+        "'1.5' is not a valid time. Valid examples are 8:05, 8h05 and 8h."
+    );
+}
+
+#[test]
+fn from_total_minutes() {
+    assert_eq!(
+        Duration::from_total_minutes(60 * 4 + 35),
+        Duration::new(4, 35)
+    );
+    assert_eq!(Duration::new(1, 15).n_times_min_discretization(), 12 + 3);
+}
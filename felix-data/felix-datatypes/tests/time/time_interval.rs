@@ -1,21 +1,22 @@
-use felix_datatypes::{Time, TimeInterval};
+use felix_datatypes::{Duration, TimeInterval, TimeOfDay};
 use std::panic::catch_unwind;
 
 #[test]
 fn duration() {
-    let interval = TimeInterval::new(Time::new(10, 0), Time::new(11, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(10, 0), TimeOfDay::new(11, 0));
     assert_eq!(
         interval.duration(),
-        Time::new(1, 0),
+        Duration::new(1, 0),
         "Duration was not calculated right"
     );
 }
 
 #[test]
 fn overlap() {
-    let ten_to_eleven = TimeInterval::new(Time::new(10, 0), Time::new(11, 0));
-    let tenthirty_to_eleventhirty = TimeInterval::new(Time::new(10, 30), Time::new(11, 30));
-    let eleven_to_twelve = TimeInterval::new(Time::new(11, 0), Time::new(12, 0));
+    let ten_to_eleven = TimeInterval::new(TimeOfDay::new(10, 0), TimeOfDay::new(11, 0));
+    let tenthirty_to_eleventhirty =
+        TimeInterval::new(TimeOfDay::new(10, 30), TimeOfDay::new(11, 30));
+    let eleven_to_twelve = TimeInterval::new(TimeOfDay::new(11, 0), TimeOfDay::new(12, 0));
 
     assert_eq!(
         ten_to_eleven.overlaps_with(&tenthirty_to_eleventhirty),
@@ -29,19 +30,38 @@ fn overlap() {
     );
 }
 
+#[test]
+fn is_closer_than() {
+    let ten_to_eleven = TimeInterval::new(TimeOfDay::new(10, 0), TimeOfDay::new(11, 0));
+    let eleven_to_twelve = TimeInterval::new(TimeOfDay::new(11, 0), TimeOfDay::new(12, 0));
+    let eleven_fifteen_to_twelve = TimeInterval::new(TimeOfDay::new(11, 15), TimeOfDay::new(12, 0));
+
+    assert!(ten_to_eleven.is_closer_than(&eleven_to_twelve, Duration::new(0, 5)));
+    assert!(eleven_to_twelve.is_closer_than(&ten_to_eleven, Duration::new(0, 5)));
+    assert!(
+        !ten_to_eleven.is_closer_than(&eleven_to_twelve, Duration::new(0, 0)),
+        "Adjacent intervals are too close without margin"
+    );
+    assert!(ten_to_eleven.is_closer_than(&eleven_fifteen_to_twelve, Duration::new(0, 20)));
+    assert!(
+        !ten_to_eleven.is_closer_than(&eleven_fifteen_to_twelve, Duration::new(0, 15)),
+        "Intervals separated by the exact margin are too close"
+    );
+}
+
 #[test]
 fn contains() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(9, 0));
-    assert_eq!(interval.contains(Time::new(7, 0)), false);
-    assert!(interval.contains(Time::new(8, 0)));
-    assert!(interval.contains(Time::new(8, 30)));
-    assert_eq!(interval.contains(Time::new(9, 0)), false);
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(9, 0));
+    assert!(!interval.contains(TimeOfDay::new(7, 0)));
+    assert!(interval.contains(TimeOfDay::new(8, 0)));
+    assert!(interval.contains(TimeOfDay::new(8, 30)));
+    assert!(!interval.contains(TimeOfDay::new(9, 0)));
 }
 
 #[test]
 fn contains_interval() {
-    let interval1 = TimeInterval::new(Time::new(8, 0), Time::new(9, 0));
-    let interval2 = TimeInterval::new(Time::new(8, 0), Time::new(8, 30));
+    let interval1 = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(9, 0));
+    let interval2 = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(8, 30));
 
     assert!(interval1.contains_interval(interval2));
     assert!(interval1.contains_interval(interval1));
@@ -50,23 +70,54 @@ fn contains_interval() {
 
 #[test]
 fn invalid_new() {
-    catch_unwind(|| TimeInterval::new(Time::new(10, 0), Time::new(9, 0)))
+    catch_unwind(|| TimeInterval::new(TimeOfDay::new(10, 0), TimeOfDay::new(9, 0)))
         .expect_err("Created TimeInterval with beginning > end");
-    catch_unwind(|| TimeInterval::new(Time::new(10, 0), Time::new(10, 0)))
+    catch_unwind(|| TimeInterval::new(TimeOfDay::new(10, 0), TimeOfDay::new(10, 0)))
         .expect_err("Created TimeInterval of duration 0");
-    catch_unwind(|| TimeInterval::new(Time::new(10, 0), Time::new(10, 27)))
+    catch_unwind(|| TimeInterval::new(TimeOfDay::new(10, 0), TimeOfDay::new(10, 27)))
         .expect_err("Created TimeInterval which is not a multiple of MIN_TIME_DISCRETIZATION");
 }
 
+#[test]
+fn overnight() {
+    let night_shift = TimeInterval::overnight(TimeOfDay::new(22, 0), TimeOfDay::new(2, 0));
+    assert_eq!(night_shift.beginning(), TimeOfDay::new(22, 0));
+    assert_eq!(night_shift.end(), TimeOfDay::new(26, 0));
+    assert_eq!(night_shift.duration(), Duration::new(4, 0));
+    assert!(night_shift.crosses_midnight());
+    assert!(night_shift.contains(TimeOfDay::new(25, 0)));
+
+    // Intervals which do not cross midnight are left as is
+    let evening = TimeInterval::overnight(TimeOfDay::new(20, 0), TimeOfDay::new(24, 0));
+    assert_eq!(
+        evening,
+        TimeInterval::new(TimeOfDay::new(20, 0), TimeOfDay::new(24, 0))
+    );
+    assert!(!evening.crosses_midnight());
+    assert!(evening.overlaps_with(&night_shift));
+
+    // An end equal to the beginning is taken on the next day
+    assert_eq!(
+        TimeInterval::overnight(TimeOfDay::new(8, 0), TimeOfDay::new(8, 0)).duration(),
+        Duration::new(24, 0)
+    );
+}
+
+#[test]
+fn invalid_overnight() {
+    catch_unwind(|| TimeInterval::overnight(TimeOfDay::new(30, 0), TimeOfDay::new(29, 0)))
+        .expect_err("Created overnight TimeInterval ending after MAX_TIME");
+}
+
 #[test]
 fn std_cmp() {
-    let interval1 = TimeInterval::new(Time::new(8, 0), Time::new(10, 0));
-    let interval2 = TimeInterval::new(Time::new(11, 0), Time::new(13, 0));
+    let interval1 = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(10, 0));
+    let interval2 = TimeInterval::new(TimeOfDay::new(11, 0), TimeOfDay::new(13, 0));
     assert!(interval1 < interval2, "TimeInterval comparison is broken");
 }
 
 #[test]
 fn display() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 15));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 15));
     assert_eq!(format!("{}", interval), "08:00 - 12:15");
 }
@@ -0,0 +1,195 @@
+use felix_datatypes::{Duration, TimeError, TimeOfDay, MAX_TIME};
+use std::panic::catch_unwind;
+
+#[test]
+fn std_eq() {
+    assert!(TimeOfDay::new(2, 30) == TimeOfDay::new(2, 30));
+    assert!(TimeOfDay::new(2, 30) != TimeOfDay::new(1, 0));
+}
+
+#[test]
+fn std_ord() {
+    // Compare with hours
+    assert!(TimeOfDay::new(2, 30) < TimeOfDay::new(3, 0));
+    // Compare with seconds
+    assert!(TimeOfDay::new(2, 30) < TimeOfDay::new(2, 35));
+}
+
+#[test]
+fn std_op() {
+    // Add without wrap
+    assert_eq!(
+        TimeOfDay::new(1, 0) + Duration::new(1, 30),
+        TimeOfDay::new(2, 30)
+    );
+    // Add with wrap
+    assert_eq!(
+        TimeOfDay::new(1, 40) + Duration::new(1, 30),
+        TimeOfDay::new(3, 10)
+    );
+    // Sub without wrap
+    assert_eq!(
+        TimeOfDay::new(2, 40) - Duration::new(1, 30),
+        TimeOfDay::new(1, 10)
+    );
+    // Sub with wrap
+    assert_eq!(
+        TimeOfDay::new(10, 0) - Duration::new(9, 20),
+        TimeOfDay::new(0, 40)
+    );
+    // Difference between two times of day
+    assert_eq!(
+        TimeOfDay::new(10, 0) - TimeOfDay::new(9, 20),
+        Duration::new(0, 40)
+    );
+
+    // AddAssign
+    let mut time = TimeOfDay::new(1, 40);
+    time += Duration::new(1, 30);
+    assert_eq!(time, TimeOfDay::new(3, 10));
+    // SubAssign
+    time = TimeOfDay::new(10, 0);
+    time -= Duration::new(9, 20);
+    assert_eq!(time, TimeOfDay::new(0, 40));
+
+    // Invalid operations
+    assert!(catch_unwind(|| { TimeOfDay::new(2, 35) + Duration::new(46, 0) }).is_err());
+    assert!(catch_unwind(|| { TimeOfDay::new(2, 35) - Duration::new(3, 0) }).is_err());
+    assert!(catch_unwind(|| { TimeOfDay::new(2, 35) - TimeOfDay::new(3, 0) }).is_err());
+}
+
+#[test]
+fn checked_op() {
+    assert_eq!(
+        TimeOfDay::new(8, 0).checked_add(Duration::new(1, 30)),
+        Ok(TimeOfDay::new(9, 30))
+    );
+    assert_eq!(
+        TimeOfDay::new(8, 0).checked_sub(Duration::new(1, 30)),
+        Ok(TimeOfDay::new(6, 30))
+    );
+    assert_eq!(
+        TimeOfDay::new(8, 0).checked_duration_since(TimeOfDay::new(6, 30)),
+        Ok(Duration::new(1, 30))
+    );
+
+    // Invalid operations
+    assert_eq!(
+        TimeOfDay::new(2, 35).checked_add(Duration::new(46, 0)),
+        Err(TimeError::OutOfRange)
+    );
+    assert_eq!(
+        TimeOfDay::new(2, 35).checked_sub(Duration::new(3, 0)),
+        Err(TimeError::OutOfRange)
+    );
+    assert_eq!(
+        TimeOfDay::new(2, 35).checked_duration_since(TimeOfDay::new(3, 0)),
+        Err(TimeError::OutOfRange)
+    );
+}
+
+#[test]
+fn add_hours() {
+    let mut time = TimeOfDay::new(1, 0);
+    time.add_hours(3);
+    assert!(time == TimeOfDay::new(4, 0));
+    time.add_hours(-1);
+    assert!(time == TimeOfDay::new(3, 0));
+
+    // Invalid operations
+    assert!(catch_unwind(|| { TimeOfDay::new(1, 0).add_hours(-2) }).is_err());
+    assert!(catch_unwind(|| { TimeOfDay::new(1, 0).add_hours(47) }).is_err());
+}
+
+#[test]
+fn add_minutes() {
+    let mut time = TimeOfDay::new(1, 0);
+    time.add_minutes(55);
+    assert_eq!(time, TimeOfDay::new(1, 55));
+    time.add_minutes(-25);
+    assert_eq!(time, TimeOfDay::new(1, 30));
+    time.add_minutes(30);
+    assert_eq!(time, TimeOfDay::new(2, 0));
+
+    // Invalid operations
+    assert!(catch_unwind(|| { TimeOfDay::new(1, 0).add_minutes(61) }).is_err());
+    assert!(catch_unwind(|| { TimeOfDay::new(3, 0).add_minutes(-61) }).is_err());
+}
+
+#[test]
+fn invalid_new() {
+    assert!(catch_unwind(|| { TimeOfDay::new(48, 5) }).is_err());
+    assert!(catch_unwind(|| { TimeOfDay::new(49, 0) }).is_err());
+    assert!(catch_unwind(|| { TimeOfDay::new(0, 60) }).is_err());
+    assert!(catch_unwind(|| { TimeOfDay::new(-1, 0) }).is_err());
+    assert!(catch_unwind(|| { TimeOfDay::new(0, -1) }).is_err());
+}
+
+#[test]
+fn next_day() {
+    // Times after 24:00 belong to the next day
+    assert_eq!(
+        TimeOfDay::new(22, 0) + Duration::new(4, 0),
+        TimeOfDay::new(26, 0)
+    );
+    assert_eq!(TimeOfDay::new(26, 0).total_minutes(), 1560);
+    assert_eq!(TimeOfDay::from_total_minutes(1560), TimeOfDay::new(26, 0));
+    assert_eq!(TimeOfDay::new(48, 0), MAX_TIME);
+
+    let mut time = TimeOfDay::new(23, 30);
+    time.add_minutes(45);
+    assert_eq!(time, TimeOfDay::new(24, 15));
+}
+
+#[test]
+fn from_str() {
+    assert_eq!("8:05".parse(), Ok(TimeOfDay::new(8, 5)));
+    assert_eq!("08:05".parse(), Ok(TimeOfDay::new(8, 5)));
+    assert_eq!("8h05".parse(), Ok(TimeOfDay::new(8, 5)));
+    assert_eq!("8h".parse(), Ok(TimeOfDay::new(8, 0)));
+    assert_eq!(" 26:30 ".parse(), Ok(TimeOfDay::new(26, 30)));
+
+    // Invalid strings
+    for &string in &[
+        "", "8", "8:", "8:5", "8:005", "h30", "-1:00", "8:0a", "8h05min",
+    ] {
+        assert_eq!(
+            string.parse::<TimeOfDay>(),
+            Err(TimeError::InvalidFormat(string.to_owned()))
+        );
+    }
+    assert_eq!("8:07".parse::<TimeOfDay>(), Err(TimeError::NotDiscretized));
+    assert_eq!("8:60".parse::<TimeOfDay>(), Err(TimeError::OutOfRange));
+    assert_eq!("49:00".parse::<TimeOfDay>(), Err(TimeError::OutOfRange));
+}
+
+#[test]
+fn display() {
+    assert_eq!(format!("{}", TimeOfDay::new(1, 5)), "01:05");
+}
+
+#[test]
+fn n_times_min_discretization_5_minutes() {
+    // Assuming MIN_TIME_DISCRETIZATION = 5 minutes
+    let time = TimeOfDay::new(1, 15);
+    let expected = 12 + 3;
+    assert_eq!(time.n_times_min_discretization(), expected);
+}
+
+#[test]
+fn from_n_times_min_discretization() {
+    let n_times_min_discretization = 43;
+    // Assuming MIN_TIME_DISCRETIZATION = 5 minutes
+    let expected = TimeOfDay::new(3, 35);
+    assert_eq!(
+        TimeOfDay::from_n_times_min_discretization(n_times_min_discretization),
+        expected
+    );
+}
+
+#[test]
+fn from_total_minutes() {
+    let total_minutes = 60 * 4 + 35;
+    let expected = TimeOfDay::new(4, 35);
+    assert_eq!(TimeOfDay::from_total_minutes(total_minutes), expected);
+}
@@ -6,6 +6,7 @@ use std::fmt;
 pub enum WhySpotIsInvalid {
     BlockingActivity(String),
     OutsideOfWorkHours,
+    BreakRulesNotRespected,
 }
 
 /// Throw this error when the user adds an entity to an activity which is inserted
@@ -25,6 +26,10 @@ impl fmt::Display for AddEntityToInsertedActivityInvalidSpot {
             WhySpotIsInvalid::OutsideOfWorkHours => {
                 "it would be outside of their work hours".to_string()
             }
+
+            WhySpotIsInvalid::BreakRulesNotRespected => {
+                "they would not have the breaks they need".to_string()
+            }
         };
 
         write!(
@@ -77,6 +82,22 @@ impl AddEntityToInsertedActivityInvalidSpot {
         })
     }
 
+    #[must_use]
+    pub fn break_rules_not_respected<S1, S2>(
+        entity_name: S1,
+        activity_name: S2,
+    ) -> Box<AddEntityToInsertedActivityInvalidSpot>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Box::new(AddEntityToInsertedActivityInvalidSpot {
+            who: entity_name.into(),
+            in_what: activity_name.into(),
+            why: WhySpotIsInvalid::BreakRulesNotRespected,
+        })
+    }
+
     // Getters
     #[must_use]
     pub fn who(&self) -> String {
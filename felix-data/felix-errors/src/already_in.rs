@@ -78,6 +78,20 @@ impl AlreadyIn {
         })
     }
 
+    #[must_use]
+    pub fn group_already_in_group<S1, S2>(subgroup_name: S1, group_name: S2) -> Box<AlreadyIn>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Box::new(AlreadyIn {
+            what: EntityOrGroup::Group,
+            who: subgroup_name.into(),
+            in_what: ActivityOrGroup::Group,
+            in_who: group_name.into(),
+        })
+    }
+
     #[must_use]
     pub fn group_already_in_activity<S1, S2>(group_name: S1, activity_name: S2) -> Box<AlreadyIn>
     where
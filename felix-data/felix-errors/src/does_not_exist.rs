@@ -2,7 +2,7 @@ use gettextrs::gettext as tr;
 use std::error::Error;
 use std::fmt;
 
-use felix_datatypes::TimeInterval;
+use felix_datatypes::{ActivityId, TimeInterval};
 
 /// Defines the component type which does not exist.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -11,6 +11,8 @@ pub enum ComponentType {
     TimeInterval,
     Group,
     Activity,
+    WorkHourTemplate,
+    ActivityTemplate,
 }
 
 /// Throw this error when the user asked for a component which does not exist.
@@ -32,6 +34,8 @@ impl fmt::Display for DoesNotExist {
                 ComponentType::TimeInterval => tr("The interval"),
                 ComponentType::Group => tr("The group"),
                 ComponentType::Activity => tr("The activity with id"),
+                ComponentType::WorkHourTemplate => tr("The work hour template"),
+                ComponentType::ActivityTemplate => tr("The activity template"),
                 ComponentType::Entity => panic!("This case should have been treated above"),
             };
             write!(f, "{} '{}' {}.", what, self.who, does_not_exist)
@@ -73,6 +77,36 @@ impl DoesNotExist {
         })
     }
 
+    #[must_use]
+    pub fn activity_does_not_exist(id: ActivityId) -> Box<DoesNotExist> {
+        Box::new(DoesNotExist {
+            what: ComponentType::Activity,
+            who: id.to_string(),
+        })
+    }
+
+    #[must_use]
+    pub fn work_hour_template_does_not_exist<S>(name: S) -> Box<DoesNotExist>
+    where
+        S: Into<String>,
+    {
+        Box::new(DoesNotExist {
+            what: ComponentType::WorkHourTemplate,
+            who: name.into(),
+        })
+    }
+
+    #[must_use]
+    pub fn activity_template_does_not_exist<S>(name: S) -> Box<DoesNotExist>
+    where
+        S: Into<String>,
+    {
+        Box::new(DoesNotExist {
+            what: ComponentType::ActivityTemplate,
+            who: name.into(),
+        })
+    }
+
     // Getters
     #[must_use]
     pub fn what(&self) -> ComponentType {
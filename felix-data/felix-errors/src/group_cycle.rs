@@ -0,0 +1,78 @@
+use gettextrs::gettext as tr;
+use std::error::Error;
+use std::fmt;
+
+/// Throw this error when the user adds a group to itself or to one of its subgroups,
+/// directly or not.
+///
+/// The error is built from functions in the form 'reason(group_name, ...)'.
+#[derive(Debug, Clone)]
+pub struct GroupCycle {
+    group_name: String,
+    subgroup_name: String,
+}
+
+impl fmt::Display for GroupCycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let the_group = tr("The group");
+        if self.group_name == self.subgroup_name {
+            write!(
+                f,
+                "{} '{}' {}.",
+                the_group,
+                self.group_name,
+                tr("cannot contain itself")
+            )
+        } else {
+            write!(
+                f,
+                "{} '{}' {} '{}' {}.",
+                the_group,
+                self.group_name,
+                tr("cannot contain the group"),
+                self.subgroup_name,
+                tr("because it is one of its members")
+            )
+        }
+    }
+}
+
+impl Error for GroupCycle {}
+
+impl GroupCycle {
+    // Constructors
+    #[must_use]
+    pub fn contains_itself<S>(group_name: S) -> Box<GroupCycle>
+    where
+        S: Into<String>,
+    {
+        let group_name = group_name.into();
+        Box::new(GroupCycle {
+            subgroup_name: group_name.clone(),
+            group_name,
+        })
+    }
+
+    #[must_use]
+    pub fn would_loop<S1, S2>(group_name: S1, subgroup_name: S2) -> Box<GroupCycle>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Box::new(GroupCycle {
+            group_name: group_name.into(),
+            subgroup_name: subgroup_name.into(),
+        })
+    }
+
+    // Getters
+    #[must_use]
+    pub fn group_name(&self) -> String {
+        self.group_name.clone()
+    }
+
+    #[must_use]
+    pub fn subgroup_name(&self) -> String {
+        self.subgroup_name.clone()
+    }
+}
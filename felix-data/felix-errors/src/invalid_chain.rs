@@ -0,0 +1,120 @@
+use gettextrs::gettext as tr;
+use std::error::Error;
+use std::fmt;
+
+/// Defines the reason why the activities cannot be chained or unchained.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WhyInvalidChain {
+    AlreadyFollowed,
+    AlreadyPreceded,
+    WouldLoop,
+    NotChained,
+    Constrained,
+    Chained,
+}
+
+/// Throw this error when the user tries to chain activities in an invalid way,
+/// or to constrain a chained activity in another way.
+///
+/// The error is built from functions in the form 'reason(activity_name)'.
+#[derive(Debug, Clone)]
+pub struct InvalidChain {
+    reason: WhyInvalidChain,
+    activity_name: String,
+}
+
+impl fmt::Display for InvalidChain {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let reason = match self.reason {
+            WhyInvalidChain::AlreadyFollowed => tr("is already followed by another activity"),
+            WhyInvalidChain::AlreadyPreceded => tr("already follows another activity"),
+            WhyInvalidChain::WouldLoop => tr("cannot be chained to an activity of its own chain"),
+            WhyInvalidChain::NotChained => tr("is not chained to other activities"),
+            WhyInvalidChain::Constrained => tr("cannot be chained because it is split into sessions or begins at the same time as other activities"),
+            WhyInvalidChain::Chained => tr("cannot be split into sessions or begin at the same time as other activities because it is chained"),
+        };
+        write!(f, "'{}' {}.", self.activity_name, reason)
+    }
+}
+
+impl Error for InvalidChain {}
+
+impl InvalidChain {
+    // Constructors
+    #[must_use]
+    pub fn already_followed<S>(activity_name: S) -> Box<InvalidChain>
+    where
+        S: Into<String>,
+    {
+        Box::new(InvalidChain {
+            reason: WhyInvalidChain::AlreadyFollowed,
+            activity_name: activity_name.into(),
+        })
+    }
+
+    #[must_use]
+    pub fn already_preceded<S>(activity_name: S) -> Box<InvalidChain>
+    where
+        S: Into<String>,
+    {
+        Box::new(InvalidChain {
+            reason: WhyInvalidChain::AlreadyPreceded,
+            activity_name: activity_name.into(),
+        })
+    }
+
+    #[must_use]
+    pub fn would_loop<S>(activity_name: S) -> Box<InvalidChain>
+    where
+        S: Into<String>,
+    {
+        Box::new(InvalidChain {
+            reason: WhyInvalidChain::WouldLoop,
+            activity_name: activity_name.into(),
+        })
+    }
+
+    #[must_use]
+    pub fn not_chained<S>(activity_name: S) -> Box<InvalidChain>
+    where
+        S: Into<String>,
+    {
+        Box::new(InvalidChain {
+            reason: WhyInvalidChain::NotChained,
+            activity_name: activity_name.into(),
+        })
+    }
+
+    #[must_use]
+    pub fn constrained<S>(activity_name: S) -> Box<InvalidChain>
+    where
+        S: Into<String>,
+    {
+        Box::new(InvalidChain {
+            reason: WhyInvalidChain::Constrained,
+            activity_name: activity_name.into(),
+        })
+    }
+
+    #[must_use]
+    pub fn chained<S>(activity_name: S) -> Box<InvalidChain>
+    where
+        S: Into<String>,
+    {
+        Box::new(InvalidChain {
+            reason: WhyInvalidChain::Chained,
+            activity_name: activity_name.into(),
+        })
+    }
+
+    // Getters
+    #[must_use]
+    pub fn activity_name(&self) -> String {
+        self.activity_name.clone()
+    }
+
+    #[must_use]
+    pub fn why(&self) -> WhyInvalidChain {
+        self.reason
+    }
+}
@@ -2,14 +2,20 @@ use gettextrs::gettext as tr;
 use std::error::Error;
 use std::fmt;
 
-use felix_datatypes::{Time, MIN_TIME_DISCRETIZATION};
+use felix_datatypes::TimeOfDay;
 
 type ActivityName = String;
+type EntityName = String;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum WhyInvalid {
     OverlappingWithOtherInsertedActivity(ActivityName),
     CannotFitOrWouldBlockOtherActivities,
+    BreakRulesNotRespected(EntityName),
+    SessionsNotInOrder,
+    OccurrencesNotSpaced,
+    SimultaneousActivityCannotBegin(ActivityName),
+    NotEnoughParticipants,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -23,7 +29,7 @@ pub enum InvalidOrNotComputed {
 #[derive(Debug, Clone)]
 pub struct InvalidInsertion {
     who: String,
-    in_who: Time,
+    in_who: TimeOfDay,
     reason: InvalidOrNotComputed,
 }
 
@@ -45,6 +51,19 @@ impl fmt::Display for InvalidInsertion {
                     WhyInvalid::OverlappingWithOtherInsertedActivity(activity) => {
                         format!("{} '{}'", tr("it would overlap with"), activity)
                     }
+                    WhyInvalid::BreakRulesNotRespected(entity) => {
+                        format!("{} {}", entity, tr("would not have the breaks they need"))
+                    }
+                    WhyInvalid::SessionsNotInOrder => tr("its sessions would not be in order"),
+                    WhyInvalid::OccurrencesNotSpaced => {
+                        tr("its occurrences would not be in order or not spaced enough")
+                    }
+                    WhyInvalid::SimultaneousActivityCannotBegin(activity) => {
+                        format!("'{}' {}", activity, tr("cannot begin at the same time"))
+                    }
+                    WhyInvalid::NotEnoughParticipants => {
+                        tr("too few of its optional participants would be free")
+                    }
                 };
 
                 write!(
@@ -67,7 +86,7 @@ impl InvalidInsertion {
     #[must_use]
     pub fn cannot_fit_or_would_block_other_activities<S>(
         activity_name: S,
-        invalid_insertion_time: Time,
+        invalid_insertion_time: TimeOfDay,
     ) -> Box<InvalidInsertion>
     where
         S: Into<String>,
@@ -82,7 +101,7 @@ impl InvalidInsertion {
     #[must_use]
     pub fn would_overlap_with_activity<S1, S2>(
         activity_name: S1,
-        invalid_insertion_time: Time,
+        invalid_insertion_time: TimeOfDay,
         blocking_activity: S2,
     ) -> Box<InvalidInsertion>
     where
@@ -96,10 +115,89 @@ impl InvalidInsertion {
         )
     }
 
+    #[must_use]
+    pub fn would_not_respect_break_rules_of<S1, S2>(
+        activity_name: S1,
+        invalid_insertion_time: TimeOfDay,
+        entity_name: S2,
+    ) -> Box<InvalidInsertion>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Self::insertion_not_in_computed_insertions(
+            activity_name,
+            invalid_insertion_time,
+            WhyInvalid::BreakRulesNotRespected(entity_name.into()),
+        )
+    }
+
+    #[must_use]
+    pub fn would_not_keep_sessions_in_order<S>(
+        activity_name: S,
+        invalid_insertion_time: TimeOfDay,
+    ) -> Box<InvalidInsertion>
+    where
+        S: Into<String>,
+    {
+        Self::insertion_not_in_computed_insertions(
+            activity_name,
+            invalid_insertion_time,
+            WhyInvalid::SessionsNotInOrder,
+        )
+    }
+
+    #[must_use]
+    pub fn would_not_space_occurrences<S>(
+        activity_name: S,
+        invalid_insertion_time: TimeOfDay,
+    ) -> Box<InvalidInsertion>
+    where
+        S: Into<String>,
+    {
+        Self::insertion_not_in_computed_insertions(
+            activity_name,
+            invalid_insertion_time,
+            WhyInvalid::OccurrencesNotSpaced,
+        )
+    }
+
+    #[must_use]
+    pub fn simultaneous_activity_cannot_begin<S1, S2>(
+        activity_name: S1,
+        invalid_insertion_time: TimeOfDay,
+        simultaneous_activity: S2,
+    ) -> Box<InvalidInsertion>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Self::insertion_not_in_computed_insertions(
+            activity_name,
+            invalid_insertion_time,
+            WhyInvalid::SimultaneousActivityCannotBegin(simultaneous_activity.into()),
+        )
+    }
+
+    #[must_use]
+    pub fn would_not_have_enough_participants<S>(
+        activity_name: S,
+        invalid_insertion_time: TimeOfDay,
+    ) -> Box<InvalidInsertion>
+    where
+        S: Into<String>,
+    {
+        Self::insertion_not_in_computed_insertions(
+            activity_name,
+            invalid_insertion_time,
+            WhyInvalid::NotEnoughParticipants,
+        )
+    }
+
     #[must_use]
     fn insertion_not_in_computed_insertions<S>(
         activity_name: S,
-        invalid_insertion_time: Time,
+        invalid_insertion_time: TimeOfDay,
         reason: WhyInvalid,
     ) -> Box<InvalidInsertion>
     where
@@ -120,7 +218,7 @@ impl InvalidInsertion {
         Box::new(InvalidInsertion {
             who: activity_name.into(),
             reason: InvalidOrNotComputed::NotComputed,
-            in_who: MIN_TIME_DISCRETIZATION, // We don't care about the time
+            in_who: TimeOfDay::default(), // We don't care about the time
         })
     }
 
@@ -131,7 +229,7 @@ impl InvalidInsertion {
     }
 
     #[must_use]
-    pub fn in_who(&self) -> Time {
+    pub fn in_who(&self) -> TimeOfDay {
         self.in_who
     }
 
@@ -11,7 +11,7 @@ impl fmt::Display for InvalidInterval {
         write!(
             f,
             "{}.",
-            tr("This interval is not valid. The end must be greater than the beginning")
+            tr("This interval is not valid. The end must be different from the beginning")
         )
     }
 }
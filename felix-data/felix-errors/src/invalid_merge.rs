@@ -0,0 +1,104 @@
+use gettextrs::gettext as tr;
+use std::error::Error;
+use std::fmt;
+
+/// Defines the reason why two entities cannot be merged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WhyInvalidMerge {
+    SameEntity,
+    ActivitiesOverlap(String, String),
+}
+
+/// Throw this error when the user tries to merge two entities which cannot be merged.
+///
+/// The error is built from functions in the form 'reason(kept_entity_name, ...)'.
+#[derive(Debug, Clone)]
+pub struct InvalidMerge {
+    reason: WhyInvalidMerge,
+    kept_entity_name: String,
+    removed_entity_name: String,
+}
+
+impl fmt::Display for InvalidMerge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.reason {
+            WhyInvalidMerge::SameEntity => write!(
+                f,
+                "{} {}.",
+                self.kept_entity_name,
+                tr("cannot be merged with themselves")
+            ),
+            WhyInvalidMerge::ActivitiesOverlap(kept_activity_name, removed_activity_name) => {
+                write!(
+                    f,
+                    "{} {} {} {} '{}' {} '{}' {}.",
+                    self.kept_entity_name,
+                    tr("and"),
+                    self.removed_entity_name,
+                    tr("cannot be merged because the activities"),
+                    kept_activity_name,
+                    tr("and"),
+                    removed_activity_name,
+                    tr("would overlap")
+                )
+            }
+        }
+    }
+}
+
+impl Error for InvalidMerge {}
+
+impl InvalidMerge {
+    // Constructors
+    #[must_use]
+    pub fn same_entity<S>(entity_name: S) -> Box<InvalidMerge>
+    where
+        S: Into<String>,
+    {
+        let entity_name = entity_name.into();
+        Box::new(InvalidMerge {
+            reason: WhyInvalidMerge::SameEntity,
+            removed_entity_name: entity_name.clone(),
+            kept_entity_name: entity_name,
+        })
+    }
+
+    #[must_use]
+    pub fn activities_overlap<S1, S2, S3, S4>(
+        kept_entity_name: S1,
+        removed_entity_name: S2,
+        kept_activity_name: S3,
+        removed_activity_name: S4,
+    ) -> Box<InvalidMerge>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<String>,
+        S4: Into<String>,
+    {
+        Box::new(InvalidMerge {
+            reason: WhyInvalidMerge::ActivitiesOverlap(
+                kept_activity_name.into(),
+                removed_activity_name.into(),
+            ),
+            kept_entity_name: kept_entity_name.into(),
+            removed_entity_name: removed_entity_name.into(),
+        })
+    }
+
+    // Getters
+    #[must_use]
+    pub fn why(&self) -> WhyInvalidMerge {
+        self.reason.clone()
+    }
+
+    #[must_use]
+    pub fn kept_entity_name(&self) -> String {
+        self.kept_entity_name.clone()
+    }
+
+    #[must_use]
+    pub fn removed_entity_name(&self) -> String {
+        self.removed_entity_name.clone()
+    }
+}
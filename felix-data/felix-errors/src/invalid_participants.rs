@@ -0,0 +1,100 @@
+use gettextrs::gettext as tr;
+use std::error::Error;
+use std::fmt;
+
+/// Defines the reason why the participants of the activity are invalid.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WhyInvalidParticipants {
+    MinGreaterThanMax,
+    TooMany(usize),
+    TooFew(usize),
+}
+
+/// Throw this error when the number of participants of an activity would not respect
+/// its minimum and maximum number of participants.
+///
+/// The error is built from functions in the form 'reason(activity_name)'.
+#[derive(Debug, Clone)]
+pub struct InvalidParticipants {
+    reason: WhyInvalidParticipants,
+    activity_name: String,
+}
+
+impl fmt::Display for InvalidParticipants {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.reason {
+            WhyInvalidParticipants::MinGreaterThanMax => write!(
+                f,
+                "{} '{}' {}.",
+                tr("The minimum number of participants of"),
+                self.activity_name,
+                tr("cannot be greater than its maximum")
+            ),
+            WhyInvalidParticipants::TooMany(max_participants) => write!(
+                f,
+                "'{}' {} {} {}.",
+                self.activity_name,
+                tr("cannot have more than"),
+                max_participants,
+                tr("participants")
+            ),
+            WhyInvalidParticipants::TooFew(min_participants) => write!(
+                f,
+                "'{}' {} {} {}.",
+                self.activity_name,
+                tr("needs at least"),
+                min_participants,
+                tr("participants")
+            ),
+        }
+    }
+}
+
+impl Error for InvalidParticipants {}
+
+impl InvalidParticipants {
+    // Constructors
+    #[must_use]
+    pub fn min_greater_than_max<S>(activity_name: S) -> Box<InvalidParticipants>
+    where
+        S: Into<String>,
+    {
+        Box::new(InvalidParticipants {
+            reason: WhyInvalidParticipants::MinGreaterThanMax,
+            activity_name: activity_name.into(),
+        })
+    }
+
+    #[must_use]
+    pub fn too_many<S>(activity_name: S, max_participants: usize) -> Box<InvalidParticipants>
+    where
+        S: Into<String>,
+    {
+        Box::new(InvalidParticipants {
+            reason: WhyInvalidParticipants::TooMany(max_participants),
+            activity_name: activity_name.into(),
+        })
+    }
+
+    #[must_use]
+    pub fn too_few<S>(activity_name: S, min_participants: usize) -> Box<InvalidParticipants>
+    where
+        S: Into<String>,
+    {
+        Box::new(InvalidParticipants {
+            reason: WhyInvalidParticipants::TooFew(min_participants),
+            activity_name: activity_name.into(),
+        })
+    }
+
+    // Getters
+    #[must_use]
+    pub fn activity_name(&self) -> String {
+        self.activity_name.clone()
+    }
+
+    #[must_use]
+    pub fn why(&self) -> WhyInvalidParticipants {
+        self.reason
+    }
+}
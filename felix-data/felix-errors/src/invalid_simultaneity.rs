@@ -0,0 +1,121 @@
+use gettextrs::gettext as tr;
+use std::error::Error;
+use std::fmt;
+
+type EntityName = String;
+
+/// Defines the reason why the activities cannot begin at the same time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WhyInvalidSimultaneity {
+    TooFewActivities,
+    AlreadySimultaneous,
+    NotSimultaneous,
+    SharedParticipant(EntityName),
+}
+
+/// Throw this error when the user tries to make activities begin at the same time
+/// in an invalid way.
+///
+/// The error is built from functions in the form 'reason(activity_name)'.
+#[derive(Debug, Clone)]
+pub struct InvalidSimultaneity {
+    reason: WhyInvalidSimultaneity,
+    activity_name: String,
+}
+
+impl fmt::Display for InvalidSimultaneity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.reason {
+            WhyInvalidSimultaneity::TooFewActivities => write!(
+                f,
+                "'{}' {}.",
+                self.activity_name,
+                tr("must begin at the same time as at least one other activity")
+            ),
+            WhyInvalidSimultaneity::AlreadySimultaneous => write!(
+                f,
+                "'{}' {}.",
+                self.activity_name,
+                tr("already begins at the same time as other activities")
+            ),
+            WhyInvalidSimultaneity::NotSimultaneous => write!(
+                f,
+                "'{}' {}.",
+                self.activity_name,
+                tr("does not begin at the same time as other activities")
+            ),
+            WhyInvalidSimultaneity::SharedParticipant(entity_name) => write!(
+                f,
+                "{} {} '{}' {}.",
+                entity_name,
+                tr("cannot take part in"),
+                self.activity_name,
+                tr("because they take part in an activity which begins at the same time")
+            ),
+        }
+    }
+}
+
+impl Error for InvalidSimultaneity {}
+
+impl InvalidSimultaneity {
+    // Constructors
+    #[must_use]
+    pub fn too_few_activities<S>(activity_name: S) -> Box<InvalidSimultaneity>
+    where
+        S: Into<String>,
+    {
+        Box::new(InvalidSimultaneity {
+            reason: WhyInvalidSimultaneity::TooFewActivities,
+            activity_name: activity_name.into(),
+        })
+    }
+
+    #[must_use]
+    pub fn already_simultaneous<S>(activity_name: S) -> Box<InvalidSimultaneity>
+    where
+        S: Into<String>,
+    {
+        Box::new(InvalidSimultaneity {
+            reason: WhyInvalidSimultaneity::AlreadySimultaneous,
+            activity_name: activity_name.into(),
+        })
+    }
+
+    #[must_use]
+    pub fn not_simultaneous<S>(activity_name: S) -> Box<InvalidSimultaneity>
+    where
+        S: Into<String>,
+    {
+        Box::new(InvalidSimultaneity {
+            reason: WhyInvalidSimultaneity::NotSimultaneous,
+            activity_name: activity_name.into(),
+        })
+    }
+
+    #[must_use]
+    pub fn shared_participant<S1, S2>(
+        activity_name: S1,
+        entity_name: S2,
+    ) -> Box<InvalidSimultaneity>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Box::new(InvalidSimultaneity {
+            reason: WhyInvalidSimultaneity::SharedParticipant(entity_name.into()),
+            activity_name: activity_name.into(),
+        })
+    }
+
+    // Getters
+    #[must_use]
+    pub fn activity_name(&self) -> String {
+        self.activity_name.clone()
+    }
+
+    #[must_use]
+    pub fn why(&self) -> WhyInvalidSimultaneity {
+        self.reason.clone()
+    }
+}
@@ -0,0 +1,155 @@
+use gettextrs::gettext as tr;
+use std::error::Error;
+use std::fmt;
+
+use felix_datatypes::Duration;
+
+/// Defines the reason why the activity cannot be split or merged.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WhyInvalidSplit {
+    AlreadySplit,
+    NotSplit,
+    TooFewSessions,
+    SessionsTooShort(Duration),
+    Recurring,
+    TooFewOccurrences,
+}
+
+/// Throw this error when the user tries to split an activity into sessions
+/// or to make it recur in an invalid way.
+///
+/// The error is built from functions in the form 'reason(activity_name)'.
+#[derive(Debug, Clone)]
+pub struct InvalidSplit {
+    reason: WhyInvalidSplit,
+    activity_name: String,
+}
+
+impl fmt::Display for InvalidSplit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.reason {
+            WhyInvalidSplit::AlreadySplit => write!(
+                f,
+                "'{}' {}.",
+                self.activity_name,
+                tr("is already split into sessions")
+            ),
+            WhyInvalidSplit::NotSplit => write!(
+                f,
+                "'{}' {}.",
+                self.activity_name,
+                tr("is not split into sessions")
+            ),
+            WhyInvalidSplit::TooFewSessions => write!(
+                f,
+                "'{}' {}.",
+                self.activity_name,
+                tr("must be split into at least two sessions")
+            ),
+            WhyInvalidSplit::SessionsTooShort(min_session_duration) => write!(
+                f,
+                "'{}' {} {}.",
+                self.activity_name,
+                tr("cannot be split this way because its sessions would be shorter than"),
+                min_session_duration
+            ),
+            WhyInvalidSplit::Recurring => write!(
+                f,
+                "'{}' {}.",
+                self.activity_name,
+                tr("is a recurring activity")
+            ),
+            WhyInvalidSplit::TooFewOccurrences => write!(
+                f,
+                "'{}' {}.",
+                self.activity_name,
+                tr("must take place at least once")
+            ),
+        }
+    }
+}
+
+impl Error for InvalidSplit {}
+
+impl InvalidSplit {
+    // Constructors
+    #[must_use]
+    pub fn already_split<S>(activity_name: S) -> Box<InvalidSplit>
+    where
+        S: Into<String>,
+    {
+        Box::new(InvalidSplit {
+            reason: WhyInvalidSplit::AlreadySplit,
+            activity_name: activity_name.into(),
+        })
+    }
+
+    #[must_use]
+    pub fn not_split<S>(activity_name: S) -> Box<InvalidSplit>
+    where
+        S: Into<String>,
+    {
+        Box::new(InvalidSplit {
+            reason: WhyInvalidSplit::NotSplit,
+            activity_name: activity_name.into(),
+        })
+    }
+
+    #[must_use]
+    pub fn too_few_sessions<S>(activity_name: S) -> Box<InvalidSplit>
+    where
+        S: Into<String>,
+    {
+        Box::new(InvalidSplit {
+            reason: WhyInvalidSplit::TooFewSessions,
+            activity_name: activity_name.into(),
+        })
+    }
+
+    #[must_use]
+    pub fn sessions_too_short<S>(
+        activity_name: S,
+        min_session_duration: Duration,
+    ) -> Box<InvalidSplit>
+    where
+        S: Into<String>,
+    {
+        Box::new(InvalidSplit {
+            reason: WhyInvalidSplit::SessionsTooShort(min_session_duration),
+            activity_name: activity_name.into(),
+        })
+    }
+
+    #[must_use]
+    pub fn recurring<S>(activity_name: S) -> Box<InvalidSplit>
+    where
+        S: Into<String>,
+    {
+        Box::new(InvalidSplit {
+            reason: WhyInvalidSplit::Recurring,
+            activity_name: activity_name.into(),
+        })
+    }
+
+    #[must_use]
+    pub fn too_few_occurrences<S>(activity_name: S) -> Box<InvalidSplit>
+    where
+        S: Into<String>,
+    {
+        Box::new(InvalidSplit {
+            reason: WhyInvalidSplit::TooFewOccurrences,
+            activity_name: activity_name.into(),
+        })
+    }
+
+    // Getters
+    #[must_use]
+    pub fn activity_name(&self) -> String {
+        self.activity_name.clone()
+    }
+
+    #[must_use]
+    pub fn why(&self) -> WhyInvalidSplit {
+        self.reason
+    }
+}
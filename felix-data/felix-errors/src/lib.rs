@@ -4,9 +4,16 @@ pub mod change_work_hours_while_activity_inserted;
 pub mod does_not_exist;
 pub mod duration_too_short;
 pub mod empty_name;
+pub mod group_cycle;
 pub mod interval_overlaps;
+pub mod invalid_chain;
 pub mod invalid_insertion;
 pub mod invalid_interval;
+pub mod invalid_merge;
+pub mod invalid_participants;
+pub mod invalid_simultaneity;
+pub mod invalid_split;
+pub mod load_limit_exceeded;
 pub mod name_taken;
 pub mod not_enough_time;
 pub mod not_in;
@@ -0,0 +1,281 @@
+use gettextrs::gettext as tr;
+use std::error::Error;
+use std::fmt;
+
+type CategoryName = String;
+
+/// Defines the load limit which would be exceeded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExceededLoadLimit {
+    ActivityTime,
+    ActivitiesOfCategory(CategoryName),
+}
+
+/// Defines the reason why the load limit would be exceeded.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WhyLoadLimitExceeded {
+    ActivityAdded,
+    ActivityDurationIncreased,
+    CategoryChanged,
+    AddedToGroup,
+    LimitsLowered,
+    ActivitiesInserted,
+    MergedWith,
+    OccurrencesAdded,
+}
+
+/// Throw this error when the requested operation makes an entity exceed its load limits.
+///
+/// The error is built from functions in the form 'reason\_for(entity_name, limit)'.
+#[derive(Debug, Clone)]
+pub struct LoadLimitExceeded {
+    reason: WhyLoadLimitExceeded,
+    limit: ExceededLoadLimit,
+    entity_name: String,
+    // Activity or group name
+    associated_name: Option<String>,
+}
+
+impl fmt::Display for LoadLimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let limit = match &self.limit {
+            ExceededLoadLimit::ActivityTime => tr("would spend too much time in activities"),
+            ExceededLoadLimit::ActivitiesOfCategory(category) => format!(
+                "{} '{}'",
+                tr("would take part in too many activities of the category"),
+                category
+            ),
+        };
+
+        let reason = match self.reason {
+            WhyLoadLimitExceeded::ActivityAdded => {
+                let activity_name = self
+                    .associated_name
+                    .as_ref()
+                    .expect("Error when adding activity but activity name was not supplied");
+                format!("{} '{}'", tr("if they are added to"), activity_name)
+            }
+
+            WhyLoadLimitExceeded::ActivityDurationIncreased => {
+                let activity_name = self.associated_name.as_ref().expect(
+                    "Error when setting activity duration but activity name was not supplied",
+                );
+                format!(
+                    "{} '{}' {}",
+                    tr("if the duration of"),
+                    activity_name,
+                    tr("is increased")
+                )
+            }
+
+            WhyLoadLimitExceeded::CategoryChanged => {
+                let activity_name = self.associated_name.as_ref().expect(
+                    "Error when setting activity category but activity name was not supplied",
+                );
+                format!(
+                    "{} '{}' {}",
+                    tr("if the category of"),
+                    activity_name,
+                    tr("is changed")
+                )
+            }
+
+            WhyLoadLimitExceeded::AddedToGroup => {
+                let group_name = self
+                    .associated_name
+                    .as_ref()
+                    .expect("Error when adding to group but group name was not supplied");
+                format!(
+                    "{} '{}'",
+                    tr("if they take part in the activities of the group"),
+                    group_name
+                )
+            }
+
+            WhyLoadLimitExceeded::LimitsLowered => tr("if their load limits are lowered"),
+
+            WhyLoadLimitExceeded::ActivitiesInserted => tr("if every activity is inserted"),
+
+            WhyLoadLimitExceeded::MergedWith => {
+                let other_entity_name = self
+                    .associated_name
+                    .as_ref()
+                    .expect("Error when merging entities but entity name was not supplied");
+                format!("{} '{}'", tr("if they are merged with"), other_entity_name)
+            }
+
+            WhyLoadLimitExceeded::OccurrencesAdded => {
+                let activity_name = self
+                    .associated_name
+                    .as_ref()
+                    .expect("Error when adding occurrences but activity name was not supplied");
+                format!(
+                    "{} '{}' {}",
+                    tr("if"),
+                    activity_name,
+                    tr("takes place more often")
+                )
+            }
+        };
+
+        write!(f, "{} {} {}.", self.entity_name, limit, reason)
+    }
+}
+
+impl Error for LoadLimitExceeded {}
+
+impl LoadLimitExceeded {
+    // Constructors
+    #[must_use]
+    pub fn activity_added_for<S1, S2>(
+        entity_name: S1,
+        limit: ExceededLoadLimit,
+        activity_name: S2,
+    ) -> Box<LoadLimitExceeded>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Box::new(LoadLimitExceeded {
+            reason: WhyLoadLimitExceeded::ActivityAdded,
+            limit,
+            entity_name: entity_name.into(),
+            associated_name: Some(activity_name.into()),
+        })
+    }
+
+    #[must_use]
+    pub fn activity_duration_too_long_for<S1, S2>(
+        entity_name: S1,
+        limit: ExceededLoadLimit,
+        activity_name: S2,
+    ) -> Box<LoadLimitExceeded>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Box::new(LoadLimitExceeded {
+            reason: WhyLoadLimitExceeded::ActivityDurationIncreased,
+            limit,
+            entity_name: entity_name.into(),
+            associated_name: Some(activity_name.into()),
+        })
+    }
+
+    #[must_use]
+    pub fn category_changed_for<S1, S2>(
+        entity_name: S1,
+        limit: ExceededLoadLimit,
+        activity_name: S2,
+    ) -> Box<LoadLimitExceeded>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Box::new(LoadLimitExceeded {
+            reason: WhyLoadLimitExceeded::CategoryChanged,
+            limit,
+            entity_name: entity_name.into(),
+            associated_name: Some(activity_name.into()),
+        })
+    }
+
+    #[must_use]
+    pub fn added_to_group<S1, S2>(
+        entity_name: S1,
+        limit: ExceededLoadLimit,
+        group_name: S2,
+    ) -> Box<LoadLimitExceeded>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Box::new(LoadLimitExceeded {
+            reason: WhyLoadLimitExceeded::AddedToGroup,
+            limit,
+            entity_name: entity_name.into(),
+            associated_name: Some(group_name.into()),
+        })
+    }
+
+    #[must_use]
+    pub fn limits_lowered_for<S>(entity_name: S, limit: ExceededLoadLimit) -> Box<LoadLimitExceeded>
+    where
+        S: Into<String>,
+    {
+        Box::new(LoadLimitExceeded {
+            reason: WhyLoadLimitExceeded::LimitsLowered,
+            limit,
+            entity_name: entity_name.into(),
+            associated_name: None,
+        })
+    }
+
+    #[must_use]
+    pub fn activities_inserted_for<S>(
+        entity_name: S,
+        limit: ExceededLoadLimit,
+    ) -> Box<LoadLimitExceeded>
+    where
+        S: Into<String>,
+    {
+        Box::new(LoadLimitExceeded {
+            reason: WhyLoadLimitExceeded::ActivitiesInserted,
+            limit,
+            entity_name: entity_name.into(),
+            associated_name: None,
+        })
+    }
+
+    #[must_use]
+    pub fn merged_with<S1, S2>(
+        entity_name: S1,
+        limit: ExceededLoadLimit,
+        other_entity_name: S2,
+    ) -> Box<LoadLimitExceeded>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Box::new(LoadLimitExceeded {
+            reason: WhyLoadLimitExceeded::MergedWith,
+            limit,
+            entity_name: entity_name.into(),
+            associated_name: Some(other_entity_name.into()),
+        })
+    }
+
+    #[must_use]
+    pub fn occurrences_added_for<S1, S2>(
+        entity_name: S1,
+        limit: ExceededLoadLimit,
+        activity_name: S2,
+    ) -> Box<LoadLimitExceeded>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Box::new(LoadLimitExceeded {
+            reason: WhyLoadLimitExceeded::OccurrencesAdded,
+            limit,
+            entity_name: entity_name.into(),
+            associated_name: Some(activity_name.into()),
+        })
+    }
+
+    // Getters
+    #[must_use]
+    pub fn entity_name(&self) -> String {
+        self.entity_name.clone()
+    }
+
+    #[must_use]
+    pub fn limit(&self) -> ExceededLoadLimit {
+        self.limit.clone()
+    }
+
+    #[must_use]
+    pub fn why(&self) -> WhyLoadLimitExceeded {
+        self.reason
+    }
+}
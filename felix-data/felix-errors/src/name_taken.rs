@@ -7,6 +7,8 @@ use std::fmt;
 pub enum GroupOrEntity {
     Entity,
     Group,
+    WorkHourTemplate,
+    ActivityTemplate,
 }
 
 /// Throw this error when the new name of a component given by a user is already taken.
@@ -24,6 +26,8 @@ impl fmt::Display for NameTaken {
         let by = match self.by {
             GroupOrEntity::Entity => tr("an entity"),
             GroupOrEntity::Group => tr("a group"),
+            GroupOrEntity::WorkHourTemplate => tr("a work hour template"),
+            GroupOrEntity::ActivityTemplate => tr("an activity template"),
         };
         write!(
             f,
@@ -60,6 +64,26 @@ impl NameTaken {
         })
     }
 
+    pub fn name_taken_by_work_hour_template<S>(name: S) -> Box<NameTaken>
+    where
+        S: Into<String>,
+    {
+        Box::new(NameTaken {
+            by: GroupOrEntity::WorkHourTemplate,
+            name: name.into(),
+        })
+    }
+
+    pub fn name_taken_by_activity_template<S>(name: S) -> Box<NameTaken>
+    where
+        S: Into<String>,
+    {
+        Box::new(NameTaken {
+            by: GroupOrEntity::ActivityTemplate,
+            name: name.into(),
+        })
+    }
+
     // Getters
     pub fn by(&self) -> GroupOrEntity {
         self.by
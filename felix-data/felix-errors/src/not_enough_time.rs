@@ -9,6 +9,8 @@ pub enum WhyNotEnoughTime {
     ActivityAdded,
     ActivityDurationIncreased,
     AddedToGroup,
+    MergedWith,
+    OccurrencesAdded,
 }
 
 /// Throw this error when the requested operation leaves an entity with not enough time.
@@ -58,6 +60,27 @@ impl fmt::Display for NotEnoughTime {
                     group_name
                 )
             }
+
+            WhyNotEnoughTime::MergedWith => {
+                let other_entity_name = self
+                    .associated_name
+                    .as_ref()
+                    .expect("Error when merging entities but entity name was not supplied");
+                format!("{} '{}'", tr("if they are merged with"), other_entity_name)
+            }
+
+            WhyNotEnoughTime::OccurrencesAdded => {
+                let activity_name = self
+                    .associated_name
+                    .as_ref()
+                    .expect("Error when adding occurrences but activity name was not supplied");
+                format!(
+                    "{} '{}' {}",
+                    tr("if"),
+                    activity_name,
+                    tr("takes place more often")
+                )
+            }
         };
 
         write!(
@@ -124,6 +147,30 @@ impl NotEnoughTime {
         })
     }
 
+    pub fn merged_with<S1, S2>(entity_name: S1, other_entity_name: S2) -> Box<NotEnoughTime>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Box::new(NotEnoughTime {
+            reason: WhyNotEnoughTime::MergedWith,
+            entity_name: entity_name.into(),
+            associated_name: Some(other_entity_name.into()),
+        })
+    }
+
+    pub fn occurrences_added_for<S1, S2>(entity_name: S1, activity_name: S2) -> Box<NotEnoughTime>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Box::new(NotEnoughTime {
+            reason: WhyNotEnoughTime::OccurrencesAdded,
+            entity_name: entity_name.into(),
+            associated_name: Some(activity_name.into()),
+        })
+    }
+
     // Getters
     pub fn entity_name(&self) -> String {
         self.entity_name.clone()
@@ -23,6 +23,16 @@ fn en_display_outside_of_work_hours() {
         "Entity cannot be added to 'Activity' because it would be outside of their work hours."
     );
 }
+#[test]
+fn en_display_break_rules_not_respected() {
+    let error =
+        AddEntityToInsertedActivityInvalidSpot::break_rules_not_respected("Entity", "Activity");
+    assert_eq!(
+        error.to_string(),
+        "Entity cannot be added to 'Activity' because they would not have the breaks they need."
+    );
+}
+
 // TODO translate
 #[test]
 fn fr_display_blocking_activity() {}
@@ -30,6 +40,9 @@ fn fr_display_blocking_activity() {}
 #[test]
 fn fr_display_outside_of_work_hours() {}
 
+#[test]
+fn fr_display_break_rules_not_respected() {}
+
 #[test]
 fn getters() {
     let error = AddEntityToInsertedActivityInvalidSpot::blocking_activity(
@@ -48,4 +61,8 @@ fn getters() {
     assert_eq!(error.who(), "Entity");
     assert_eq!(error.in_what(), "Activity");
     assert_eq!(error.why(), WhySpotIsInvalid::OutsideOfWorkHours);
+
+    let error =
+        AddEntityToInsertedActivityInvalidSpot::break_rules_not_respected("Entity", "Activity");
+    assert_eq!(error.why(), WhySpotIsInvalid::BreakRulesNotRespected);
 }
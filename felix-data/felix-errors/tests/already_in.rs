@@ -21,6 +21,15 @@ fn en_display_group_already_in_activity() {
     );
 }
 
+#[test]
+fn en_display_group_already_in_group() {
+    let error = AlreadyIn::group_already_in_group("Cooks", "Staff");
+    assert_eq!(
+        error.to_string(),
+        "The group 'Cooks' is already in the group 'Staff'."
+    );
+}
+
 // TODO translate
 #[test]
 fn fr_display_entity_already_in_group() {}
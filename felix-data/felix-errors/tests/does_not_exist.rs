@@ -1,9 +1,9 @@
-use felix_datatypes::{Time, TimeInterval};
+use felix_datatypes::{TimeInterval, TimeOfDay};
 use felix_errors::does_not_exist::{ComponentType, DoesNotExist};
 
 #[test]
 fn en_display_interval_does_not_exist() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 30));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 30));
     let error = DoesNotExist::interval_does_not_exist(interval);
     assert_eq!(
         error.to_string(),
@@ -25,6 +25,35 @@ fn en_display_group_does_not_exist() {
     assert_eq!(error.to_string(), "The group 'Group Name' does not exist.");
 }
 
+#[test]
+fn en_display_activity_does_not_exist() {
+    let error = DoesNotExist::activity_does_not_exist(3);
+    assert_eq!(
+        error.to_string(),
+        "The activity with id '3' does not exist."
+    );
+}
+
+#[test]
+fn en_display_work_hour_template_does_not_exist() {
+    let name = "Template Name";
+    let error = DoesNotExist::work_hour_template_does_not_exist(name);
+    assert_eq!(
+        error.to_string(),
+        "The work hour template 'Template Name' does not exist."
+    );
+}
+
+#[test]
+fn en_display_activity_template_does_not_exist() {
+    let name = "Template Name";
+    let error = DoesNotExist::activity_template_does_not_exist(name);
+    assert_eq!(
+        error.to_string(),
+        "The activity template 'Template Name' does not exist."
+    );
+}
+
 // TODO translate
 #[test]
 fn fr_display_interval_does_not_exist() {}
@@ -35,9 +64,18 @@ fn fr_display_entity_does_not_exist() {}
 #[test]
 fn fr_display_group_does_not_exist() {}
 
+#[test]
+fn fr_display_activity_does_not_exist() {}
+
+#[test]
+fn fr_display_work_hour_template_does_not_exist() {}
+
+#[test]
+fn fr_display_activity_template_does_not_exist() {}
+
 #[test]
 fn interval_does_not_exist_getters() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 30));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 30));
     let error = DoesNotExist::interval_does_not_exist(interval);
     assert_eq!(error.what(), ComponentType::TimeInterval);
     assert_eq!(error.who(), "08:00 - 12:30");
@@ -58,3 +96,26 @@ fn group_does_not_exist_getters() {
     assert_eq!(error.what(), ComponentType::Group);
     assert_eq!(error.who(), "Group Name");
 }
+
+#[test]
+fn activity_does_not_exist_getters() {
+    let error = DoesNotExist::activity_does_not_exist(3);
+    assert_eq!(error.what(), ComponentType::Activity);
+    assert_eq!(error.who(), "3");
+}
+
+#[test]
+fn work_hour_template_does_not_exist_getters() {
+    let name = "Template Name";
+    let error = DoesNotExist::work_hour_template_does_not_exist(name);
+    assert_eq!(error.what(), ComponentType::WorkHourTemplate);
+    assert_eq!(error.who(), "Template Name");
+}
+
+#[test]
+fn activity_template_does_not_exist_getters() {
+    let name = "Template Name";
+    let error = DoesNotExist::activity_template_does_not_exist(name);
+    assert_eq!(error.what(), ComponentType::ActivityTemplate);
+    assert_eq!(error.who(), "Template Name");
+}
@@ -0,0 +1,33 @@
+use felix_errors::group_cycle::GroupCycle;
+
+#[test]
+fn en_display_contains_itself() {
+    let error = GroupCycle::contains_itself("Staff");
+    assert_eq!(
+        error.to_string(),
+        "The group 'Staff' cannot contain itself."
+    );
+}
+
+#[test]
+fn en_display_would_loop() {
+    let error = GroupCycle::would_loop("Cooks", "Staff");
+    assert_eq!(
+        error.to_string(),
+        "The group 'Cooks' cannot contain the group 'Staff' because it is one of its members."
+    );
+}
+
+// TODO translate
+#[test]
+fn fr_display_contains_itself() {}
+
+#[test]
+fn fr_display_would_loop() {}
+
+#[test]
+fn would_loop_getters() {
+    let error = GroupCycle::would_loop("Cooks", "Staff");
+    assert_eq!(error.group_name(), "Cooks");
+    assert_eq!(error.subgroup_name(), "Staff");
+}
@@ -0,0 +1,116 @@
+use felix_errors::invalid_chain::{InvalidChain, WhyInvalidChain};
+
+#[test]
+fn en_display_already_followed() {
+    let error = InvalidChain::already_followed("Activity");
+    assert_eq!(
+        error.to_string(),
+        "'Activity' is already followed by another activity."
+    );
+}
+
+#[test]
+fn en_display_already_preceded() {
+    let error = InvalidChain::already_preceded("Activity");
+    assert_eq!(
+        error.to_string(),
+        "'Activity' already follows another activity."
+    );
+}
+
+#[test]
+fn en_display_would_loop() {
+    let error = InvalidChain::would_loop("Activity");
+    assert_eq!(
+        error.to_string(),
+        "'Activity' cannot be chained to an activity of its own chain."
+    );
+}
+
+#[test]
+fn en_display_not_chained() {
+    let error = InvalidChain::not_chained("Activity");
+    assert_eq!(
+        error.to_string(),
+        "'Activity' is not chained to other activities."
+    );
+}
+
+#[test]
+fn en_display_constrained() {
+    let error = InvalidChain::constrained("Activity");
+    assert_eq!(
+        error.to_string(),
+        "'Activity' cannot be chained because it is split into sessions or begins at the same time as other activities."
+    );
+}
+
+#[test]
+fn en_display_chained() {
+    let error = InvalidChain::chained("Activity");
+    assert_eq!(
+        error.to_string(),
+        "'Activity' cannot be split into sessions or begin at the same time as other activities because it is chained."
+    );
+}
+
+// TODO translate
+#[test]
+fn fr_display_already_followed() {}
+
+#[test]
+fn fr_display_already_preceded() {}
+
+#[test]
+fn fr_display_would_loop() {}
+
+#[test]
+fn fr_display_not_chained() {}
+
+#[test]
+fn fr_display_constrained() {}
+
+#[test]
+fn fr_display_chained() {}
+
+#[test]
+fn already_followed_getters() {
+    let error = InvalidChain::already_followed("Activity");
+    assert_eq!(error.activity_name(), "Activity");
+    assert_eq!(error.why(), WhyInvalidChain::AlreadyFollowed);
+}
+
+#[test]
+fn already_preceded_getters() {
+    let error = InvalidChain::already_preceded("Activity");
+    assert_eq!(error.activity_name(), "Activity");
+    assert_eq!(error.why(), WhyInvalidChain::AlreadyPreceded);
+}
+
+#[test]
+fn would_loop_getters() {
+    let error = InvalidChain::would_loop("Activity");
+    assert_eq!(error.activity_name(), "Activity");
+    assert_eq!(error.why(), WhyInvalidChain::WouldLoop);
+}
+
+#[test]
+fn not_chained_getters() {
+    let error = InvalidChain::not_chained("Activity");
+    assert_eq!(error.activity_name(), "Activity");
+    assert_eq!(error.why(), WhyInvalidChain::NotChained);
+}
+
+#[test]
+fn constrained_getters() {
+    let error = InvalidChain::constrained("Activity");
+    assert_eq!(error.activity_name(), "Activity");
+    assert_eq!(error.why(), WhyInvalidChain::Constrained);
+}
+
+#[test]
+fn chained_getters() {
+    let error = InvalidChain::chained("Activity");
+    assert_eq!(error.activity_name(), "Activity");
+    assert_eq!(error.why(), WhyInvalidChain::Chained);
+}
@@ -1,10 +1,12 @@
-use felix_datatypes::Time;
+use felix_datatypes::TimeOfDay;
 use felix_errors::invalid_insertion::{InvalidInsertion, InvalidOrNotComputed, WhyInvalid};
 
 #[test]
 fn en_display_cannot_fit_or_would_block_other_activities() {
-    let error =
-        InvalidInsertion::cannot_fit_or_would_block_other_activities("Activity", Time::new(8, 0));
+    let error = InvalidInsertion::cannot_fit_or_would_block_other_activities(
+        "Activity",
+        TimeOfDay::new(8, 0),
+    );
     assert_eq!(
         error.to_string(),
         "Activity cannot be inserted with beginning 08:00 because this beginning is invalid or will cause problems in the future."
@@ -15,7 +17,7 @@ fn en_display_cannot_fit_or_would_block_other_activities() {
 fn en_display_would_overlap_with_activity() {
     let error = InvalidInsertion::would_overlap_with_activity(
         "Activity",
-        Time::new(8, 0),
+        TimeOfDay::new(8, 0),
         "Blocking Activity",
     );
     assert_eq!(
@@ -24,6 +26,61 @@ fn en_display_would_overlap_with_activity() {
     );
 }
 
+#[test]
+fn en_display_would_not_respect_break_rules() {
+    let error = InvalidInsertion::would_not_respect_break_rules_of(
+        "Activity",
+        TimeOfDay::new(8, 0),
+        "Entity",
+    );
+    assert_eq!(
+        error.to_string(),
+        "Activity cannot be inserted with beginning 08:00 because Entity would not have the breaks they need."
+    );
+}
+
+#[test]
+fn en_display_would_not_keep_sessions_in_order() {
+    let error =
+        InvalidInsertion::would_not_keep_sessions_in_order("Activity", TimeOfDay::new(8, 0));
+    assert_eq!(
+        error.to_string(),
+        "Activity cannot be inserted with beginning 08:00 because its sessions would not be in order."
+    );
+}
+
+#[test]
+fn en_display_would_not_space_occurrences() {
+    let error = InvalidInsertion::would_not_space_occurrences("Activity", TimeOfDay::new(8, 0));
+    assert_eq!(
+        error.to_string(),
+        "Activity cannot be inserted with beginning 08:00 because its occurrences would not be in order or not spaced enough."
+    );
+}
+
+#[test]
+fn en_display_simultaneous_activity_cannot_begin() {
+    let error = InvalidInsertion::simultaneous_activity_cannot_begin(
+        "Activity",
+        TimeOfDay::new(8, 0),
+        "Simultaneous Activity",
+    );
+    assert_eq!(
+        error.to_string(),
+        "Activity cannot be inserted with beginning 08:00 because 'Simultaneous Activity' cannot begin at the same time."
+    );
+}
+
+#[test]
+fn en_display_would_not_have_enough_participants() {
+    let error =
+        InvalidInsertion::would_not_have_enough_participants("Activity", TimeOfDay::new(8, 0));
+    assert_eq!(
+        error.to_string(),
+        "Activity cannot be inserted with beginning 08:00 because too few of its optional participants would be free."
+    );
+}
+
 #[test]
 fn en_display_insertion_not_computed() {
     let error = InvalidInsertion::insertions_not_computed_yet("Activity");
@@ -43,12 +100,29 @@ fn fr_display_cannot_fit_or_would_block_other_activities() {}
 #[test]
 fn fr_display_would_overlap_with_activity() {}
 
+#[test]
+fn fr_display_would_not_respect_break_rules() {}
+
+#[test]
+fn fr_display_would_not_keep_sessions_in_order() {}
+
+#[test]
+fn fr_display_would_not_space_occurrences() {}
+
+#[test]
+fn fr_display_simultaneous_activity_cannot_begin() {}
+
+#[test]
+fn fr_display_would_not_have_enough_participants() {}
+
 #[test]
 fn invalid_insertion_getters() {
-    let error =
-        InvalidInsertion::cannot_fit_or_would_block_other_activities("Activity", Time::new(8, 0));
+    let error = InvalidInsertion::cannot_fit_or_would_block_other_activities(
+        "Activity",
+        TimeOfDay::new(8, 0),
+    );
     assert_eq!(error.who(), "Activity");
-    assert_eq!(error.in_who(), Time::new(8, 0));
+    assert_eq!(error.in_who(), TimeOfDay::new(8, 0));
     assert_eq!(
         error.reason(),
         InvalidOrNotComputed::Invalid(WhyInvalid::CannotFitOrWouldBlockOtherActivities)
@@ -5,7 +5,7 @@ fn en_display_invalid_interval() {
     let error = InvalidInterval::new();
     assert_eq!(
         error.to_string(),
-        "This interval is not valid. The end must be greater than the beginning."
+        "This interval is not valid. The end must be different from the beginning."
     );
 }
 
@@ -0,0 +1,34 @@
+use felix_errors::invalid_merge::{InvalidMerge, WhyInvalidMerge};
+
+#[test]
+fn en_display_same_entity() {
+    let error = InvalidMerge::same_entity("Jean");
+    assert_eq!(error.to_string(), "Jean cannot be merged with themselves.");
+}
+
+#[test]
+fn en_display_activities_overlap() {
+    let error = InvalidMerge::activities_overlap("Jean", "Jean D.", "Lunch", "Meeting");
+    assert_eq!(
+        error.to_string(),
+        "Jean and Jean D. cannot be merged because the activities 'Lunch' and 'Meeting' would overlap."
+    );
+}
+
+// TODO translate
+#[test]
+fn fr_display_same_entity() {}
+
+#[test]
+fn fr_display_activities_overlap() {}
+
+#[test]
+fn activities_overlap_getters() {
+    let error = InvalidMerge::activities_overlap("Jean", "Jean D.", "Lunch", "Meeting");
+    assert_eq!(error.kept_entity_name(), "Jean");
+    assert_eq!(error.removed_entity_name(), "Jean D.");
+    assert_eq!(
+        error.why(),
+        WhyInvalidMerge::ActivitiesOverlap("Lunch".to_owned(), "Meeting".to_owned())
+    );
+}
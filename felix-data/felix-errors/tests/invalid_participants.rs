@@ -0,0 +1,59 @@
+use felix_errors::invalid_participants::{InvalidParticipants, WhyInvalidParticipants};
+
+#[test]
+fn en_display_min_greater_than_max() {
+    let error = InvalidParticipants::min_greater_than_max("Activity");
+    assert_eq!(
+        error.to_string(),
+        "The minimum number of participants of 'Activity' cannot be greater than its maximum."
+    );
+}
+
+#[test]
+fn en_display_too_many() {
+    let error = InvalidParticipants::too_many("Activity", 3);
+    assert_eq!(
+        error.to_string(),
+        "'Activity' cannot have more than 3 participants."
+    );
+}
+
+#[test]
+fn en_display_too_few() {
+    let error = InvalidParticipants::too_few("Activity", 4);
+    assert_eq!(
+        error.to_string(),
+        "'Activity' needs at least 4 participants."
+    );
+}
+
+// TODO translate
+#[test]
+fn fr_display_min_greater_than_max() {}
+
+#[test]
+fn fr_display_too_many() {}
+
+#[test]
+fn fr_display_too_few() {}
+
+#[test]
+fn min_greater_than_max_getters() {
+    let error = InvalidParticipants::min_greater_than_max("Activity");
+    assert_eq!(error.activity_name(), "Activity");
+    assert_eq!(error.why(), WhyInvalidParticipants::MinGreaterThanMax);
+}
+
+#[test]
+fn too_many_getters() {
+    let error = InvalidParticipants::too_many("Activity", 3);
+    assert_eq!(error.activity_name(), "Activity");
+    assert_eq!(error.why(), WhyInvalidParticipants::TooMany(3));
+}
+
+#[test]
+fn too_few_getters() {
+    let error = InvalidParticipants::too_few("Activity", 4);
+    assert_eq!(error.activity_name(), "Activity");
+    assert_eq!(error.why(), WhyInvalidParticipants::TooFew(4));
+}
@@ -0,0 +1,81 @@
+use felix_errors::invalid_simultaneity::{InvalidSimultaneity, WhyInvalidSimultaneity};
+
+#[test]
+fn en_display_too_few_activities() {
+    let error = InvalidSimultaneity::too_few_activities("Activity");
+    assert_eq!(
+        error.to_string(),
+        "'Activity' must begin at the same time as at least one other activity."
+    );
+}
+
+#[test]
+fn en_display_already_simultaneous() {
+    let error = InvalidSimultaneity::already_simultaneous("Activity");
+    assert_eq!(
+        error.to_string(),
+        "'Activity' already begins at the same time as other activities."
+    );
+}
+
+#[test]
+fn en_display_not_simultaneous() {
+    let error = InvalidSimultaneity::not_simultaneous("Activity");
+    assert_eq!(
+        error.to_string(),
+        "'Activity' does not begin at the same time as other activities."
+    );
+}
+
+#[test]
+fn en_display_shared_participant() {
+    let error = InvalidSimultaneity::shared_participant("Activity", "Entity");
+    assert_eq!(
+        error.to_string(),
+        "Entity cannot take part in 'Activity' because they take part in an activity which begins at the same time."
+    );
+}
+
+// TODO translate
+#[test]
+fn fr_display_too_few_activities() {}
+
+#[test]
+fn fr_display_already_simultaneous() {}
+
+#[test]
+fn fr_display_not_simultaneous() {}
+
+#[test]
+fn fr_display_shared_participant() {}
+
+#[test]
+fn too_few_activities_getters() {
+    let error = InvalidSimultaneity::too_few_activities("Activity");
+    assert_eq!(error.activity_name(), "Activity");
+    assert_eq!(error.why(), WhyInvalidSimultaneity::TooFewActivities);
+}
+
+#[test]
+fn already_simultaneous_getters() {
+    let error = InvalidSimultaneity::already_simultaneous("Activity");
+    assert_eq!(error.activity_name(), "Activity");
+    assert_eq!(error.why(), WhyInvalidSimultaneity::AlreadySimultaneous);
+}
+
+#[test]
+fn not_simultaneous_getters() {
+    let error = InvalidSimultaneity::not_simultaneous("Activity");
+    assert_eq!(error.activity_name(), "Activity");
+    assert_eq!(error.why(), WhyInvalidSimultaneity::NotSimultaneous);
+}
+
+#[test]
+fn shared_participant_getters() {
+    let error = InvalidSimultaneity::shared_participant("Activity", "Entity");
+    assert_eq!(error.activity_name(), "Activity");
+    assert_eq!(
+        error.why(),
+        WhyInvalidSimultaneity::SharedParticipant("Entity".to_owned())
+    );
+}
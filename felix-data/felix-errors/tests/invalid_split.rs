@@ -0,0 +1,114 @@
+use felix_datatypes::Duration;
+use felix_errors::invalid_split::{InvalidSplit, WhyInvalidSplit};
+
+#[test]
+fn en_display_already_split() {
+    let error = InvalidSplit::already_split("Activity");
+    assert_eq!(
+        error.to_string(),
+        "'Activity' is already split into sessions."
+    );
+}
+
+#[test]
+fn en_display_not_split() {
+    let error = InvalidSplit::not_split("Activity");
+    assert_eq!(error.to_string(), "'Activity' is not split into sessions.");
+}
+
+#[test]
+fn en_display_too_few_sessions() {
+    let error = InvalidSplit::too_few_sessions("Activity");
+    assert_eq!(
+        error.to_string(),
+        "'Activity' must be split into at least two sessions."
+    );
+}
+
+#[test]
+fn en_display_sessions_too_short() {
+    let error = InvalidSplit::sessions_too_short("Activity", Duration::new(1, 0));
+    assert_eq!(
+        error.to_string(),
+        "'Activity' cannot be split this way because its sessions would be shorter than 01:00."
+    );
+}
+
+#[test]
+fn en_display_recurring() {
+    let error = InvalidSplit::recurring("Activity");
+    assert_eq!(error.to_string(), "'Activity' is a recurring activity.");
+}
+
+#[test]
+fn en_display_too_few_occurrences() {
+    let error = InvalidSplit::too_few_occurrences("Activity");
+    assert_eq!(
+        error.to_string(),
+        "'Activity' must take place at least once."
+    );
+}
+
+// TODO translate
+#[test]
+fn fr_display_already_split() {}
+
+#[test]
+fn fr_display_not_split() {}
+
+#[test]
+fn fr_display_too_few_sessions() {}
+
+#[test]
+fn fr_display_sessions_too_short() {}
+
+#[test]
+fn fr_display_recurring() {}
+
+#[test]
+fn fr_display_too_few_occurrences() {}
+
+#[test]
+fn already_split_getters() {
+    let error = InvalidSplit::already_split("Activity");
+    assert_eq!(error.activity_name(), "Activity");
+    assert_eq!(error.why(), WhyInvalidSplit::AlreadySplit);
+}
+
+#[test]
+fn not_split_getters() {
+    let error = InvalidSplit::not_split("Activity");
+    assert_eq!(error.activity_name(), "Activity");
+    assert_eq!(error.why(), WhyInvalidSplit::NotSplit);
+}
+
+#[test]
+fn too_few_sessions_getters() {
+    let error = InvalidSplit::too_few_sessions("Activity");
+    assert_eq!(error.activity_name(), "Activity");
+    assert_eq!(error.why(), WhyInvalidSplit::TooFewSessions);
+}
+
+#[test]
+fn sessions_too_short_getters() {
+    let error = InvalidSplit::sessions_too_short("Activity", Duration::new(1, 0));
+    assert_eq!(error.activity_name(), "Activity");
+    assert_eq!(
+        error.why(),
+        WhyInvalidSplit::SessionsTooShort(Duration::new(1, 0))
+    );
+}
+
+#[test]
+fn recurring_getters() {
+    let error = InvalidSplit::recurring("Activity");
+    assert_eq!(error.activity_name(), "Activity");
+    assert_eq!(error.why(), WhyInvalidSplit::Recurring);
+}
+
+#[test]
+fn too_few_occurrences_getters() {
+    let error = InvalidSplit::too_few_occurrences("Activity");
+    assert_eq!(error.activity_name(), "Activity");
+    assert_eq!(error.why(), WhyInvalidSplit::TooFewOccurrences);
+}
@@ -0,0 +1,213 @@
+use felix_errors::load_limit_exceeded::{
+    ExceededLoadLimit, LoadLimitExceeded, WhyLoadLimitExceeded,
+};
+
+#[test]
+fn en_display_activity_added() {
+    let error = LoadLimitExceeded::activity_added_for(
+        "Entity Name",
+        ExceededLoadLimit::ActivityTime,
+        "Activity",
+    );
+    assert_eq!(
+        error.to_string(),
+        "Entity Name would spend too much time in activities if they are added to 'Activity'."
+    );
+}
+
+#[test]
+fn en_display_activity_duration_increased() {
+    let error = LoadLimitExceeded::activity_duration_too_long_for(
+        "Entity Name",
+        ExceededLoadLimit::ActivityTime,
+        "Activity",
+    );
+    assert_eq!(
+        error.to_string(),
+        "Entity Name would spend too much time in activities if the duration of 'Activity' is increased."
+    );
+}
+
+#[test]
+fn en_display_category_changed() {
+    let error = LoadLimitExceeded::category_changed_for(
+        "Entity Name",
+        ExceededLoadLimit::ActivitiesOfCategory("Sport".to_owned()),
+        "Activity",
+    );
+    assert_eq!(
+        error.to_string(),
+        "Entity Name would take part in too many activities of the category 'Sport' if the category of 'Activity' is changed."
+    );
+}
+
+#[test]
+fn en_display_added_to_group() {
+    let error = LoadLimitExceeded::added_to_group(
+        "Entity Name",
+        ExceededLoadLimit::ActivitiesOfCategory("Sport".to_owned()),
+        "Group",
+    );
+    assert_eq!(error.to_string(), "Entity Name would take part in too many activities of the category 'Sport' if they take part in the activities of the group 'Group'.");
+}
+
+#[test]
+fn en_display_limits_lowered() {
+    let error =
+        LoadLimitExceeded::limits_lowered_for("Entity Name", ExceededLoadLimit::ActivityTime);
+    assert_eq!(
+        error.to_string(),
+        "Entity Name would spend too much time in activities if their load limits are lowered."
+    );
+}
+
+#[test]
+fn en_display_activities_inserted() {
+    let error =
+        LoadLimitExceeded::activities_inserted_for("Entity Name", ExceededLoadLimit::ActivityTime);
+    assert_eq!(
+        error.to_string(),
+        "Entity Name would spend too much time in activities if every activity is inserted."
+    );
+}
+
+#[test]
+fn en_display_merged_with() {
+    let error = LoadLimitExceeded::merged_with(
+        "Entity Name",
+        ExceededLoadLimit::ActivityTime,
+        "Other Entity",
+    );
+    assert_eq!(
+        error.to_string(),
+        "Entity Name would spend too much time in activities if they are merged with 'Other Entity'."
+    );
+}
+
+#[test]
+fn en_display_occurrences_added() {
+    let error = LoadLimitExceeded::occurrences_added_for(
+        "Entity Name",
+        ExceededLoadLimit::ActivityTime,
+        "Activity",
+    );
+    assert_eq!(
+        error.to_string(),
+        "Entity Name would spend too much time in activities if 'Activity' takes place more often."
+    );
+}
+
+// TODO translate
+#[test]
+fn fr_display_activity_added() {}
+
+#[test]
+fn fr_display_activity_duration_increased() {}
+
+#[test]
+fn fr_display_category_changed() {}
+
+#[test]
+fn fr_display_added_to_group() {}
+
+#[test]
+fn fr_display_limits_lowered() {}
+
+#[test]
+fn fr_display_activities_inserted() {}
+
+#[test]
+fn fr_display_merged_with() {}
+
+#[test]
+fn fr_display_occurrences_added() {}
+
+#[test]
+fn activity_added_getters() {
+    let error = LoadLimitExceeded::activity_added_for(
+        "Entity Name",
+        ExceededLoadLimit::ActivityTime,
+        "Activity",
+    );
+    assert_eq!(error.entity_name(), "Entity Name");
+    assert_eq!(error.limit(), ExceededLoadLimit::ActivityTime);
+    assert_eq!(error.why(), WhyLoadLimitExceeded::ActivityAdded);
+}
+
+#[test]
+fn activity_duration_increased_getters() {
+    let error = LoadLimitExceeded::activity_duration_too_long_for(
+        "Entity Name",
+        ExceededLoadLimit::ActivityTime,
+        "Activity",
+    );
+    assert_eq!(error.entity_name(), "Entity Name");
+    assert_eq!(error.limit(), ExceededLoadLimit::ActivityTime);
+    assert_eq!(error.why(), WhyLoadLimitExceeded::ActivityDurationIncreased);
+}
+
+#[test]
+fn category_changed_getters() {
+    let error = LoadLimitExceeded::category_changed_for(
+        "Entity Name",
+        ExceededLoadLimit::ActivitiesOfCategory("Sport".to_owned()),
+        "Activity",
+    );
+    assert_eq!(error.entity_name(), "Entity Name");
+    assert_eq!(
+        error.limit(),
+        ExceededLoadLimit::ActivitiesOfCategory("Sport".to_owned())
+    );
+    assert_eq!(error.why(), WhyLoadLimitExceeded::CategoryChanged);
+}
+
+#[test]
+fn added_to_group_getters() {
+    let error =
+        LoadLimitExceeded::added_to_group("Entity Name", ExceededLoadLimit::ActivityTime, "Group");
+    assert_eq!(error.entity_name(), "Entity Name");
+    assert_eq!(error.limit(), ExceededLoadLimit::ActivityTime);
+    assert_eq!(error.why(), WhyLoadLimitExceeded::AddedToGroup);
+}
+
+#[test]
+fn limits_lowered_getters() {
+    let error =
+        LoadLimitExceeded::limits_lowered_for("Entity Name", ExceededLoadLimit::ActivityTime);
+    assert_eq!(error.entity_name(), "Entity Name");
+    assert_eq!(error.limit(), ExceededLoadLimit::ActivityTime);
+    assert_eq!(error.why(), WhyLoadLimitExceeded::LimitsLowered);
+}
+
+#[test]
+fn activities_inserted_getters() {
+    let error =
+        LoadLimitExceeded::activities_inserted_for("Entity Name", ExceededLoadLimit::ActivityTime);
+    assert_eq!(error.entity_name(), "Entity Name");
+    assert_eq!(error.limit(), ExceededLoadLimit::ActivityTime);
+    assert_eq!(error.why(), WhyLoadLimitExceeded::ActivitiesInserted);
+}
+
+#[test]
+fn merged_with_getters() {
+    let error = LoadLimitExceeded::merged_with(
+        "Entity Name",
+        ExceededLoadLimit::ActivityTime,
+        "Other Entity",
+    );
+    assert_eq!(error.entity_name(), "Entity Name");
+    assert_eq!(error.limit(), ExceededLoadLimit::ActivityTime);
+    assert_eq!(error.why(), WhyLoadLimitExceeded::MergedWith);
+}
+
+#[test]
+fn occurrences_added_getters() {
+    let error = LoadLimitExceeded::occurrences_added_for(
+        "Entity Name",
+        ExceededLoadLimit::ActivityTime,
+        "Activity",
+    );
+    assert_eq!(error.entity_name(), "Entity Name");
+    assert_eq!(error.limit(), ExceededLoadLimit::ActivityTime);
+    assert_eq!(error.why(), WhyLoadLimitExceeded::OccurrencesAdded);
+}
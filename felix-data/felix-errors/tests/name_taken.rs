@@ -18,6 +18,24 @@ fn en_display_name_taken_by_group() {
     );
 }
 
+#[test]
+fn en_display_name_taken_by_work_hour_template() {
+    let error = NameTaken::name_taken_by_work_hour_template("Template Name");
+    assert_eq!(
+        error.to_string(),
+        "The name 'Template Name' is already taken by a work hour template."
+    );
+}
+
+#[test]
+fn en_display_name_taken_by_activity_template() {
+    let error = NameTaken::name_taken_by_activity_template("Template Name");
+    assert_eq!(
+        error.to_string(),
+        "The name 'Template Name' is already taken by an activity template."
+    );
+}
+
 // TODO translate
 #[test]
 fn fr_display_name_taken_by_entity() {}
@@ -25,6 +43,12 @@ fn fr_display_name_taken_by_entity() {}
 #[test]
 fn fr_display_name_taken_by_group() {}
 
+#[test]
+fn fr_display_name_taken_by_work_hour_template() {}
+
+#[test]
+fn fr_display_name_taken_by_activity_template() {}
+
 #[test]
 fn name_taken_by_entity_getters() {
     let error = NameTaken::name_taken_by_entity("Entity Name");
@@ -38,3 +62,17 @@ fn name_taken_by_group_getters() {
     assert_eq!(error.by(), GroupOrEntity::Group);
     assert_eq!(error.name(), "Group Name");
 }
+
+#[test]
+fn name_taken_by_work_hour_template_getters() {
+    let error = NameTaken::name_taken_by_work_hour_template("Template Name");
+    assert_eq!(error.by(), GroupOrEntity::WorkHourTemplate);
+    assert_eq!(error.name(), "Template Name");
+}
+
+#[test]
+fn name_taken_by_activity_template_getters() {
+    let error = NameTaken::name_taken_by_activity_template("Template Name");
+    assert_eq!(error.by(), GroupOrEntity::ActivityTemplate);
+    assert_eq!(error.name(), "Template Name");
+}
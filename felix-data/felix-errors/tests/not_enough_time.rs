@@ -33,6 +33,24 @@ fn en_display_added_to_group() {
     assert_eq!(error.to_string(), "Entity Name will not have enough time if they take part in the activities of the group 'Group'.");
 }
 
+#[test]
+fn en_display_merged_with() {
+    let error = NotEnoughTime::merged_with("Entity Name", "Other Entity");
+    assert_eq!(
+        error.to_string(),
+        "Entity Name will not have enough time if they are merged with 'Other Entity'."
+    );
+}
+
+#[test]
+fn en_display_occurrences_added() {
+    let error = NotEnoughTime::occurrences_added_for("Entity Name", "Activity");
+    assert_eq!(
+        error.to_string(),
+        "Entity Name will not have enough time if 'Activity' takes place more often."
+    );
+}
+
 // TODO translate
 #[test]
 fn fr_display_activity_added() {}
@@ -46,6 +64,12 @@ fn fr_display_work_hours_shortened() {}
 #[test]
 fn fr_display_added_to_group() {}
 
+#[test]
+fn fr_display_merged_with() {}
+
+#[test]
+fn fr_display_occurrences_added() {}
+
 #[test]
 fn activity_added_getters() {
     let error = NotEnoughTime::activity_added_for("Entity Name", "Activity");
@@ -73,3 +97,17 @@ fn added_to_group_getters() {
     assert_eq!(error.entity_name(), "Entity Name");
     assert_eq!(error.why(), WhyNotEnoughTime::AddedToGroup);
 }
+
+#[test]
+fn merged_with_getters() {
+    let error = NotEnoughTime::merged_with("Entity Name", "Other Entity");
+    assert_eq!(error.entity_name(), "Entity Name");
+    assert_eq!(error.why(), WhyNotEnoughTime::MergedWith);
+}
+
+#[test]
+fn occurrences_added_getters() {
+    let error = NotEnoughTime::occurrences_added_for("Entity Name", "Activity");
+    assert_eq!(error.entity_name(), "Entity Name");
+    assert_eq!(error.why(), WhyNotEnoughTime::OccurrencesAdded);
+}
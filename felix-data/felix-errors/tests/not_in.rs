@@ -21,6 +21,15 @@ fn en_display_group_not_in_activity() {
     );
 }
 
+#[test]
+fn en_display_group_not_in_group() {
+    let error = NotIn::group_not_in_group("Cooks", "Staff");
+    assert_eq!(
+        error.to_string(),
+        "The group 'Cooks' is not in the group 'Staff'."
+    );
+}
+
 // TODO translate
 #[test]
 fn fr_display_entity_not_in_group() {}
@@ -6,7 +6,7 @@ use line::{Line, LineSplits};
 use lines_from_activities::extract_lines_from_activities;
 use pdf_sizes::{PdfSize, PdfSizes};
 
-use felix_collections::Activity;
+use felix_collections::{Activity, Entities};
 use std::path::PathBuf;
 
 const FONT_RGB: f64 = 0.0;
@@ -16,9 +16,14 @@ const INCH_TO_POINT_MULTIPLIER: f64 = 72.0;
 const A4_HEIGHT_IN_POINTS: f64 = 11.693 * INCH_TO_POINT_MULTIPLIER;
 const A4_WIDTH_IN_POINTS: f64 = 8.268 * INCH_TO_POINT_MULTIPLIER;
 
-pub fn generate_pdf(entity: String, activities: Vec<Activity>, output_dir: PathBuf) {
+pub fn generate_pdf(
+    entity: String,
+    activities: Vec<Activity>,
+    entities: &Entities,
+    output_dir: PathBuf,
+) {
     // 1. Format data
-    let pdf = Pdf::new(entity, activities);
+    let pdf = Pdf::new(entity, activities, entities);
 
     // 2. Print data
     pdf.render(output_dir);
@@ -37,10 +42,10 @@ struct Pdf {
 }
 
 impl Pdf {
-    pub fn new(entity: String, activities: Vec<Activity>) -> Pdf {
+    pub fn new(entity: String, activities: Vec<Activity>, entities: &Entities) -> Pdf {
         let mut pdf = Pdf {
             title: entity,
-            lines: extract_lines_from_activities(activities),
+            lines: extract_lines_from_activities(activities, entities),
 
             title_x_offset: 0.0,
             title_height: 0.0,
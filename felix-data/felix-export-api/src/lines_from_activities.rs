@@ -1,13 +1,13 @@
 use crate::Line;
 
-use felix_collections::Activity;
+use felix_collections::{Activity, Entities};
 
-pub(crate) fn extract_lines_from_activities(mut activities: Vec<Activity>) -> Vec<Line> {
+pub(crate) fn extract_lines_from_activities(
+    mut activities: Vec<Activity>,
+    entities: &Entities,
+) -> Vec<Line> {
     // Do not mention not inserted activities
-    activities = activities
-        .into_iter()
-        .filter(|activity| activity.insertion_interval().is_some())
-        .collect();
+    activities.retain(|activity| activity.insertion_interval().is_some());
 
     // Earlier activities go first
     activities.sort_by(|a, b| {
@@ -27,10 +27,32 @@ pub(crate) fn extract_lines_from_activities(mut activities: Vec<Activity>) -> Ve
                 .expect("Exporting uninserted activity")
                 .to_string()
                 + " : ",
-            activity_name_and_participants: activity.name()
+            activity_name_and_participants: name_with_location(activity)
                 + " ("
-                + &activity.entities_sorted().join(", ")
+                + &participants(activity, entities)
                 + ")",
         })
         .collect()
 }
+
+/// Returns the name of the activity followed by its location, if it has one.
+fn name_with_location(activity: &Activity) -> String {
+    let location = activity.location();
+    if location.is_empty() {
+        activity.name()
+    } else {
+        activity.name() + " @ " + &location
+    }
+}
+
+/// Returns the names of the participants of the activity.
+/// Optional participants, if any, are listed separately.
+fn participants(activity: &Activity, entities: &Entities) -> String {
+    let participants = entities.names_sorted(&activity.entity_ids()).join(", ");
+    let optional_participants = entities.names_sorted(&activity.optional_entity_ids());
+    if optional_participants.is_empty() {
+        participants
+    } else {
+        participants + "; optional: " + &optional_participants.join(", ")
+    }
+}
@@ -1,27 +1,36 @@
 use super::*;
-use felix_collections::Activities;
-use felix_datatypes::Time;
+use felix_collections::{Activities, Entities};
+use felix_datatypes::{Duration, TimeOfDay};
+
+fn make_entities() -> Entities {
+    let mut entities = Entities::new();
+    entities.add("Paul".to_string()).unwrap();
+    entities.add("Déborah".to_string()).unwrap();
+    entities
+}
 
 fn make_activities() -> Vec<Activity> {
-    let entity1 = "Paul".to_string();
-    let entity2 = "Déborah".to_string();
+    let entities = make_entities();
+    let entity1 = entities.get_by_name("Paul").unwrap();
+    let entity2 = entities.get_by_name("Déborah").unwrap();
 
     let mut activities = Activities::new();
     activities.add("I am an activity with a very long name".to_string());
 
     let id = activities.get_not_sorted()[0].id();
-    let duration = Time::new(0, 30);
-    let beginning = Time::new(8, 0);
-    activities.add_entity(id, entity1.clone()).unwrap();
-    activities.add_entity(id, entity2).unwrap();
+    let duration = Duration::new(0, 30);
+    let beginning = TimeOfDay::new(8, 0);
+    activities.add_entity(id, &entity1).unwrap();
+    activities.add_entity(id, &entity2).unwrap();
     activities.set_duration(id, duration);
     activities.insert_activity(id, Some(beginning));
 
     activities.add("Short-named activity".to_string());
     let id = activities.get_not_sorted()[1].id();
-    let duration = Time::new(0, 30);
-    let beginning = Time::new(9, 0);
-    activities.add_entity(id, entity1).unwrap();
+    activities.set_location(id, "Gym".to_string());
+    let duration = Duration::new(0, 30);
+    let beginning = TimeOfDay::new(9, 0);
+    activities.add_entity(id, &entity1).unwrap();
     activities.set_duration(id, duration);
     activities.insert_activity(id, Some(beginning));
 
@@ -44,7 +53,7 @@ fn test_pdf_size_always_coherent() {
     activities.append(&mut make_activities());
     activities.append(&mut make_activities());
 
-    let res = Pdf::new(entity, activities);
+    let res = Pdf::new(entity, activities, &make_entities());
     // Check that line is generated
 
     // TODO remove this later
@@ -60,7 +69,7 @@ fn generate_pdf_of_every_size_for_manual_checks() {
 fn test_lines_extracted_from_activities() {
     let activities = make_activities();
 
-    let lines = extract_lines_from_activities(activities.clone());
+    let lines = extract_lines_from_activities(activities.clone(), &make_entities());
 
     assert_eq!(lines.len(), activities.len());
     assert_eq!(lines.len(), 2);
@@ -71,7 +80,29 @@ fn test_lines_extracted_from_activities() {
     );
     assert_eq!(
         lines[1].print(),
-        "09:00 - 09:30 : Short-named activity (Paul)"
+        "09:00 - 09:30 : Short-named activity @ Gym (Paul)"
+    );
+}
+
+#[test]
+fn test_lines_mark_optional_participants() {
+    let entities = make_entities();
+    let mut activities = Activities::new();
+    let id = activities.add("Meeting".to_string()).id();
+    activities
+        .add_entity(id, &entities.get_by_name("Paul").unwrap())
+        .unwrap();
+    activities
+        .add_optional_entity(id, &entities.get_by_name("Déborah").unwrap())
+        .unwrap();
+    activities.set_duration(id, Duration::new(1, 0));
+    activities.insert_activity(id, Some(TimeOfDay::new(10, 0)));
+
+    let lines = extract_lines_from_activities(activities.get_not_sorted(), &entities);
+
+    assert_eq!(
+        lines[0].print(),
+        "10:00 - 11:00 : Meeting (Paul; optional: Déborah)"
     );
 }
 
@@ -79,7 +110,7 @@ fn test_lines_extracted_from_activities() {
 fn test_lines_split_if_too_long() {
     let activities = make_activities();
     let entity = "Paul".to_string();
-    let mut res = Pdf::new(entity, activities);
+    let mut res = Pdf::new(entity, activities, &make_entities());
 
     res.compute_line_breaks();
 
@@ -1,4 +1,4 @@
-use felix_data::{Data, Time, TimeInterval};
+use felix_data::{Data, Duration, LoadLimits, MandatoryBreak, TimeInterval, TimeOfDay};
 
 use crate::{Activity, Group};
 
@@ -59,6 +59,81 @@ impl DataBuilder {
         self
     }
 
+    #[must_use]
+    pub fn with_preferred_interval_for<S>(
+        mut self,
+        entity: S,
+        interval: TimeInterval,
+    ) -> DataBuilder
+    where
+        S: Into<String>,
+    {
+        self.data
+            .add_preferred_interval_for(entity, interval)
+            .expect("Could not add preferred interval");
+        self
+    }
+
+    #[must_use]
+    pub fn with_disliked_interval_for<S>(mut self, entity: S, interval: TimeInterval) -> DataBuilder
+    where
+        S: Into<String>,
+    {
+        self.data
+            .add_disliked_interval_for(entity, interval)
+            .expect("Could not add disliked interval");
+        self
+    }
+
+    /// Adds a work hour template with the given intervals.
+    #[must_use]
+    pub fn with_work_hour_template(
+        mut self,
+        name: &'static str,
+        intervals: Vec<TimeInterval>,
+    ) -> DataBuilder {
+        let name = self
+            .data
+            .add_work_hour_template(name)
+            .expect("Could not add work hour template");
+        for interval in intervals {
+            self.data
+                .add_work_hour_template_interval(&name, interval)
+                .expect("Could not add work hour template interval");
+        }
+        self
+    }
+
+    #[must_use]
+    pub fn with_work_hour_template_for<S>(
+        mut self,
+        entity: S,
+        template: &'static str,
+    ) -> DataBuilder
+    where
+        S: Into<String>,
+    {
+        self.data
+            .set_work_hour_template_of(entity, Some(template))
+            .expect("Could not set work hour template");
+        self
+    }
+
+    #[must_use]
+    pub fn with_unavailable_interval_for<S>(
+        mut self,
+        entity: S,
+        interval: TimeInterval,
+    ) -> DataBuilder
+    where
+        S: Into<String>,
+    {
+        self.data
+            .add_unavailable_interval_for(entity, interval)
+            .expect("Could not add unavailable interval");
+        self
+    }
+
     #[must_use]
     pub fn with_group(mut self, group: Group) -> DataBuilder {
         let group_name = self
@@ -81,6 +156,33 @@ impl DataBuilder {
         self
     }
 
+    #[must_use]
+    pub fn with_subgroup<S1, S2>(mut self, group: S1, subgroup: S2) -> DataBuilder
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        self.data
+            .add_group_to_group(group, subgroup)
+            .expect("Could not add group to group");
+        self
+    }
+
+    #[must_use]
+    pub fn with_group_work_interval_for<S>(
+        mut self,
+        group: S,
+        interval: TimeInterval,
+    ) -> DataBuilder
+    where
+        S: Into<String>,
+    {
+        self.data
+            .add_group_work_interval_for(group, interval)
+            .expect("Could not add group work interval");
+        self
+    }
+
     #[must_use]
     pub fn with_work_interval(mut self, work_interval: TimeInterval) -> DataBuilder {
         self.data
@@ -100,10 +202,63 @@ impl DataBuilder {
     /// Convenience functions which adds an interval from [00:00 to hours:00].
     #[must_use]
     pub fn with_work_interval_of_duration(self, hours: i8) -> DataBuilder {
-        let interval = TimeInterval::new(Time::new(0, 0), Time::new(hours, 0));
+        let interval = TimeInterval::new(TimeOfDay::new(0, 0), TimeOfDay::new(hours, 0));
         self.with_work_interval(interval)
     }
 
+    #[must_use]
+    pub fn with_max_continuous_work(mut self, max_continuous_work: Duration) -> DataBuilder {
+        self.data
+            .set_max_continuous_work(Some(max_continuous_work))
+            .expect("Could not set max continuous work");
+        self
+    }
+
+    #[must_use]
+    pub fn with_max_continuous_work_for<S>(
+        mut self,
+        entity: S,
+        max_continuous_work: Duration,
+    ) -> DataBuilder
+    where
+        S: Into<String>,
+    {
+        self.data
+            .set_max_continuous_work_for(entity, Some(max_continuous_work))
+            .expect("Could not set max continuous work");
+        self
+    }
+
+    #[must_use]
+    pub fn with_mandatory_break(mut self, mandatory_break: MandatoryBreak) -> DataBuilder {
+        self.data
+            .add_mandatory_break(mandatory_break)
+            .expect("Could not add mandatory break");
+        self
+    }
+
+    #[must_use]
+    pub fn with_load_limits_for<S>(mut self, entity: S, load_limits: LoadLimits) -> DataBuilder
+    where
+        S: Into<String>,
+    {
+        self.data
+            .set_entity_load_limits(entity, load_limits)
+            .expect("Could not set load limits");
+        self
+    }
+
+    #[must_use]
+    pub fn with_group_load_limits<S>(mut self, group: S, load_limits: LoadLimits) -> DataBuilder
+    where
+        S: Into<String>,
+    {
+        self.data
+            .set_group_load_limits(group, load_limits)
+            .expect("Could not set group load limits");
+        self
+    }
+
     #[must_use]
     pub fn with_activity(mut self, activity: Activity) -> DataBuilder {
         let id = self
@@ -112,6 +267,10 @@ impl DataBuilder {
             .expect("Could not add activity")
             .id();
 
+        self.data
+            .set_activity_category(id, activity.category)
+            .expect("Could not set activity category");
+
         for entity in activity.entities {
             self.data
                 .add_entity_to_activity(id, entity)
@@ -123,10 +282,19 @@ impl DataBuilder {
                 .add_group_to_activity(id, group)
                 .expect("Could not add group to activity");
         }
+        self.data
+            .set_activity_transition_buffer(id, activity.transition_buffer)
+            .expect("Could not set activity transition buffer");
         self.data
             .set_activity_duration(id, activity.duration)
             .expect("Could not set activity duration");
 
+        if activity.sessions > 1 {
+            self.data
+                .split_activity(id, activity.sessions, activity.session_rules)
+                .expect("Could not split activity");
+        }
+
         self.data.wait_for_possible_insertion_costs_computation(id);
 
         self.data
@@ -145,6 +313,61 @@ impl DataBuilder {
         self
     }
 
+    /// Links the activities with given names so that they begin at the same time.
+    #[must_use]
+    pub fn with_simultaneous_activities(
+        mut self,
+        activity_names: Vec<&'static str>,
+    ) -> DataBuilder {
+        let ids = activity_names
+            .into_iter()
+            .map(|name| {
+                self.data
+                    .activities_sorted()
+                    .into_iter()
+                    .find(|activity| activity.name() == name)
+                    .expect("Could not find activity")
+                    .id()
+            })
+            .collect::<Vec<_>>();
+
+        self.data
+            .link_simultaneous_activities(&ids)
+            .expect("Could not link simultaneous activities");
+
+        for id in ids {
+            self.data.wait_for_possible_insertion_costs_computation(id);
+        }
+        self
+    }
+
+    /// Chains the activity with name next_name right after the activity with name previous_name.
+    #[must_use]
+    pub fn with_chained_activities(
+        mut self,
+        previous_name: &'static str,
+        next_name: &'static str,
+        offset: Duration,
+    ) -> DataBuilder {
+        let id_of = |data: &Data, name| {
+            data.activities_sorted()
+                .into_iter()
+                .find(|activity| activity.name() == name)
+                .expect("Could not find activity")
+                .id()
+        };
+        let previous_id = id_of(&self.data, previous_name);
+        let next_id = id_of(&self.data, next_name);
+
+        self.data
+            .chain_activities(previous_id, next_id, offset)
+            .expect("Could not chain activities");
+
+        self.data
+            .wait_for_possible_insertion_costs_computation(previous_id);
+        self
+    }
+
     /// Consumes the data builder and returns the built Data object.
     #[must_use]
     pub fn into_data(self) -> Data {
@@ -1,4 +1,4 @@
-use felix_data::{Time, MIN_TIME_DISCRETIZATION};
+use felix_data::{Duration, SessionRules, TimeOfDay, MIN_TIME_DISCRETIZATION};
 
 #[derive(Default, Clone)]
 pub struct Group {
@@ -19,10 +19,14 @@ impl Group {
 #[derive(Clone)]
 pub struct Activity {
     pub name: &'static str,
-    pub duration: Time,
+    pub duration: Duration,
     pub entities: Vec<&'static str>,
     pub groups: Vec<&'static str>,
-    pub insertion_time: Option<Time>,
+    pub insertion_time: Option<TimeOfDay>,
+    pub transition_buffer: Duration,
+    pub sessions: usize,
+    pub session_rules: SessionRules,
+    pub category: Option<&'static str>,
 }
 
 impl Default for Activity {
@@ -33,6 +37,10 @@ impl Default for Activity {
             entities: Vec::new(),
             groups: Vec::new(),
             insertion_time: None,
+            transition_buffer: Duration::new(0, 0),
+            sessions: 1,
+            session_rules: SessionRules::default(),
+            category: None,
         }
     }
 }
@@ -1,7 +1,10 @@
 #[macro_use]
 extern crate felix_test_utils;
 
-use felix_data::{Time, TimeInterval, MIN_TIME_DISCRETIZATION};
+use felix_data::{
+    Duration, LoadLimits, MandatoryBreak, SessionRules, TimeInterval, TimeOfDay,
+    MIN_TIME_DISCRETIZATION,
+};
 use felix_test_utils::{Activity, DataBuilder, Group};
 
 #[test]
@@ -44,7 +47,7 @@ fn add_entities() {
 #[test]
 fn add_custom_interval_for() {
     let entity = "Entity";
-    let interval = TimeInterval::new(Time::new(1, 0), Time::new(5, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(1, 0), TimeOfDay::new(5, 0));
     test_ok!(
         data,
         DataBuilder::new()
@@ -64,11 +67,154 @@ fn add_custom_interval_for() {
     );
 }
 
+#[test]
+fn add_preferred_and_disliked_intervals_for() {
+    let entity = "Entity";
+    let preferred = TimeInterval::new(TimeOfDay::new(1, 0), TimeOfDay::new(5, 0));
+    let disliked = TimeInterval::new(TimeOfDay::new(7, 0), TimeOfDay::new(10, 0));
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_preferred_interval_for(entity, preferred)
+            .with_disliked_interval_for(entity, disliked),
+        {
+            let preferences = data
+                .time_preferences_of(entity)
+                .expect("Could not get time preferences of entity");
+            assert_eq!(preferences.preferred_intervals(), &vec![preferred]);
+            assert_eq!(preferences.disliked_intervals(), &vec![disliked]);
+        }
+    );
+}
+
+#[test]
+fn add_work_hour_template() {
+    let entity = "Entity";
+    let template = "Early Shift";
+    let interval = TimeInterval::new(TimeOfDay::new(6, 0), TimeOfDay::new(14, 0));
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_hour_template(template, vec![interval])
+            .with_work_hour_template_for(entity, template),
+        {
+            assert_eq!(data.work_hour_templates(), vec![template]);
+            assert_eq!(data.work_hour_template(template).unwrap(), vec![interval]);
+            assert_eq!(
+                data.work_hour_template_of(entity).unwrap(),
+                Some(template.to_owned())
+            );
+        }
+    );
+}
+
+#[test]
+fn add_unavailable_interval_for() {
+    let entity = "Entity";
+    let unavailable = TimeInterval::new(TimeOfDay::new(10, 0), TimeOfDay::new(11, 0));
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_unavailable_interval_for(entity, unavailable),
+        {
+            assert_eq!(
+                data.unavailable_intervals_of(entity)
+                    .expect("Could not get unavailable intervals of entity"),
+                vec![unavailable]
+            );
+        }
+    );
+}
+
+#[test]
+fn add_group_work_interval_for() {
+    let group = "Group";
+    let interval = TimeInterval::new(TimeOfDay::new(6, 0), TimeOfDay::new(14, 0));
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_group(Group::default(group))
+            .with_group_work_interval_for(group, interval),
+        {
+            assert_eq!(
+                data.group_work_hours_of(group)
+                    .expect("Could not get work hours of group"),
+                vec![interval]
+            );
+        }
+    );
+}
+
+#[test]
+fn add_break_rules() {
+    let entity = "Entity";
+    let lunch_break = MandatoryBreak::new(
+        TimeInterval::new(TimeOfDay::new(11, 30), TimeOfDay::new(14, 0)),
+        Duration::new(0, 45),
+    );
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_max_continuous_work(Duration::new(3, 0))
+            .with_max_continuous_work_for(entity, Duration::new(2, 0))
+            .with_mandatory_break(lunch_break),
+        {
+            let global_break_rules = data.global_break_rules();
+            assert_eq!(
+                global_break_rules.max_continuous_work(),
+                Some(Duration::new(3, 0))
+            );
+            assert_eq!(global_break_rules.mandatory_breaks(), &vec![lunch_break]);
+
+            let custom_break_rules = data
+                .custom_break_rules_of(entity)
+                .expect("Could not get break rules of entity");
+            assert_eq!(
+                custom_break_rules.max_continuous_work(),
+                Some(Duration::new(2, 0))
+            );
+        }
+    );
+}
+
+#[test]
+fn add_load_limits() {
+    let entity = "Entity";
+    let group = "Group";
+    let entity_limits = LoadLimits {
+        max_activity_time: Some(Duration::new(5, 0)),
+        ..LoadLimits::default()
+    };
+    let mut group_limits = LoadLimits::default();
+    group_limits
+        .max_activities_per_category
+        .insert("Sport".to_owned(), 2);
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_group(Group {
+                name: group,
+                entities: vec![entity]
+            })
+            .with_load_limits_for(entity, entity_limits.clone())
+            .with_group_load_limits(group, group_limits.clone()),
+        {
+            assert_eq!(data.entity(entity).unwrap().load_limits(), &entity_limits);
+            assert_eq!(data.group(group).unwrap().load_limits(), &group_limits);
+        }
+    );
+}
+
 #[test]
 fn add_custom_intervals_for() {
     let entity = "Entity";
-    let interval1 = TimeInterval::new(Time::new(1, 0), Time::new(5, 0));
-    let interval2 = TimeInterval::new(Time::new(7, 0), Time::new(10, 0));
+    let interval1 = TimeInterval::new(TimeOfDay::new(1, 0), TimeOfDay::new(5, 0));
+    let interval2 = TimeInterval::new(TimeOfDay::new(7, 0), TimeOfDay::new(10, 0));
     test_ok!(
         data,
         DataBuilder::new()
@@ -114,7 +260,8 @@ fn add_group() {
             assert_eq!(groups.len(), 1, "Group was not added");
             assert_eq!(groups[0].name(), group_name, "Group name is not right");
             assert_eq!(
-                groups[0].entities_sorted(),
+                data.entities_of_group(groups[0].name())
+                    .expect("Could not get group by name"),
                 vec![entity1, entity2],
                 "Entities were not added to the group"
             );
@@ -132,7 +279,7 @@ fn add_default_group() {
             let group = data.groups_sorted()[0];
             assert_eq!(group.name(), group_name, "Default group name is wrong");
             assert!(
-                group.entities_sorted().is_empty(),
+                group.entity_ids().is_empty(),
                 "Default group members is wrong"
             );
         }
@@ -151,11 +298,11 @@ fn add_groups() {
             assert_eq!(groups[0].name(), group1, "Group was not added correctly");
             assert_eq!(groups[1].name(), group2, "Group was not added correctly");
             assert!(
-                groups[0].entities_sorted().is_empty(),
+                groups[0].entity_ids().is_empty(),
                 "Default group members is wrong"
             );
             assert!(
-                groups[1].entities_sorted().is_empty(),
+                groups[1].entity_ids().is_empty(),
                 "Default group members is wrong"
             );
         }
@@ -164,7 +311,7 @@ fn add_groups() {
 
 #[test]
 fn add_work_interval() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     test_ok!(data, DataBuilder::new().with_work_interval(interval), {
         let intervals = data.work_hours();
         assert_eq!(intervals.len(), 1, "Interval was not added");
@@ -178,7 +325,7 @@ fn add_work_interval_with_duration() {
         data,
         DataBuilder::new().with_work_interval_of_duration(4),
         {
-            let expected = TimeInterval::new(Time::new(0, 0), Time::new(4, 0));
+            let expected = TimeInterval::new(TimeOfDay::new(0, 0), TimeOfDay::new(4, 0));
             assert_eq!(data.work_hours()[0], expected);
         }
     );
@@ -186,8 +333,8 @@ fn add_work_interval_with_duration() {
 
 #[test]
 fn add_work_intervals() {
-    let interval1 = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
-    let interval2 = TimeInterval::new(Time::new(13, 0), Time::new(14, 0));
+    let interval1 = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    let interval2 = TimeInterval::new(TimeOfDay::new(13, 0), TimeOfDay::new(14, 0));
     test_ok!(
         data,
         DataBuilder::new().with_work_intervals(vec![interval1, interval2]),
@@ -203,10 +350,10 @@ fn add_work_intervals() {
 #[test]
 fn add_activity() {
     let activity_name = "Activity Name";
-    let beginning = Time::new(0, 0);
-    let end = Time::new(6, 0);
+    let beginning = TimeOfDay::new(0, 0);
+    let end = TimeOfDay::new(6, 0);
     let work_interval = TimeInterval::new(beginning, end);
-    let duration = Time::new(5, 30);
+    let duration = Duration::new(5, 30);
     let (entity1, entity2) = ("Entity1", "Entity2");
     let (group1, group2) = ("Group1", "Group2");
     test_ok!(
@@ -221,6 +368,9 @@ fn add_activity() {
                 entities: vec![entity2, entity1],
                 groups: vec![group2, group1],
                 insertion_time: Some(beginning),
+                transition_buffer: Duration::new(0, 15),
+                category: Some("Sport"),
+                ..Default::default()
             }),
         {
             let activities = data.activities_sorted();
@@ -229,12 +379,12 @@ fn add_activity() {
             assert_eq!(activity.name(), activity_name, "Activity name is wrong");
             assert_eq!(activity.duration(), duration, "Activity name is wrong");
             assert_eq!(
-                activity.entities_sorted(),
+                data.entities_of_activity(activity.id()),
                 vec![entity1, entity2],
                 "Activity members is wrong"
             );
             assert_eq!(
-                activity.groups_sorted(),
+                data.groups_of_activity(activity.id()),
                 vec![group1, group2],
                 "Activity groups is wrong"
             );
@@ -243,6 +393,16 @@ fn add_activity() {
                 Some(TimeInterval::new(beginning, beginning + duration)),
                 "Activity was not inserted"
             );
+            assert_eq!(
+                activity.transition_buffer(),
+                Duration::new(0, 15),
+                "Activity transition buffer is wrong"
+            );
+            assert_eq!(
+                activity.category(),
+                Some("Sport".to_owned()),
+                "Activity category is wrong"
+            );
         }
     );
 }
@@ -265,11 +425,11 @@ fn add_default_activity() {
                 "Default activity duration is wrong"
             );
             assert!(
-                activity.entities_sorted().is_empty(),
+                activity.entity_ids().is_empty(),
                 "Default activity members is wrong"
             );
             assert!(
-                activity.groups_sorted().is_empty(),
+                activity.group_ids().is_empty(),
                 "Default activity groups is wrong"
             );
             assert!(
@@ -280,6 +440,91 @@ fn add_default_activity() {
     );
 }
 
+#[test]
+fn add_split_activity() {
+    let session_rules = SessionRules {
+        consecutive: true,
+        ..Default::default()
+    };
+    test_ok!(
+        data,
+        DataBuilder::new().with_activity(Activity {
+            duration: Duration::new(2, 0),
+            sessions: 2,
+            session_rules,
+            ..Default::default()
+        }),
+        {
+            let activities = data.activities_sorted();
+            assert_eq!(activities.len(), 2, "Activity was not split");
+            let sessions = activities[0].sessions().expect("Activity was not split");
+            assert_eq!(sessions.session_ids().len(), 2);
+            assert_eq!(sessions.rules(), session_rules, "Session rules are wrong");
+        }
+    );
+}
+
+#[test]
+fn add_simultaneous_activities() {
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_activities(vec![
+                Activity {
+                    name: "Activity1",
+                    ..Default::default()
+                },
+                Activity {
+                    name: "Activity2",
+                    ..Default::default()
+                },
+            ])
+            .with_simultaneous_activities(vec!["Activity1", "Activity2"]),
+        {
+            let activities = data.activities_sorted();
+            assert_eq!(
+                activities[0].simultaneous_activity_ids(),
+                vec![activities[1].id()],
+                "Activities were not linked"
+            );
+            assert_eq!(
+                activities[1].simultaneous_activity_ids(),
+                vec![activities[0].id()],
+                "Activities were not linked"
+            );
+        }
+    );
+}
+
+#[test]
+fn add_chained_activities() {
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_activities(vec![
+                Activity {
+                    name: "Activity1",
+                    ..Default::default()
+                },
+                Activity {
+                    name: "Activity2",
+                    ..Default::default()
+                },
+            ])
+            .with_chained_activities("Activity1", "Activity2", Duration::new(0, 10)),
+        {
+            let activities = data.activities_sorted();
+            let chain = activities[0].chain().expect("Activities were not chained");
+            assert_eq!(
+                chain.activity_ids(),
+                &vec![activities[0].id(), activities[1].id()]
+            );
+            assert_eq!(chain.offsets(), &vec![Duration::new(0, 10)]);
+            assert_eq!(activities[1].chain(), Some(chain));
+        }
+    );
+}
+
 #[test]
 fn add_activities() {
     test_ok!(
@@ -307,12 +552,12 @@ fn add_activities() {
                     "Activities were not added right"
                 );
                 assert_eq!(
-                    activity.entities_sorted(),
+                    data.entities_of_activity(activity.id()),
                     expected_entities,
                     "Activities were not added right"
                 );
                 assert_eq!(
-                    activity.groups_sorted(),
+                    data.groups_of_activity(activity.id()),
                     expected_groups,
                     "Activities were not added right"
                 );
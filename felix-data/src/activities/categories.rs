@@ -0,0 +1,87 @@
+use crate::errors::Result;
+use crate::{clean_string, Activity, ActivityFilter, ActivityId, Data, Rgba};
+
+/// Operations on the categories of activities and on filters by category and tag.
+///
+/// A category can be given a color. Activities take this color when they join the
+/// category or when the color of their category changes.
+impl Data {
+    /// Returns the categories used by activities or given a color, sorted by name.
+    #[must_use]
+    pub fn categories(&self) -> Vec<String> {
+        self.activities.categories()
+    }
+
+    /// Returns the color of the category with the formatted given name, if one was set.
+    #[must_use]
+    pub fn category_color<S>(&self, category: S) -> Option<Rgba>
+    where
+        S: Into<String>,
+    {
+        clean_string(category)
+            .ok()
+            .and_then(|category| self.activities.category_color(&category))
+    }
+
+    /// Sets the color of the category with the formatted given name.
+    /// Activities of the category take this color. None removes the color of the category,
+    /// activities keep their current color.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the formatted category is empty.
+    pub fn set_category_color<S>(&mut self, category: S, color: Option<Rgba>) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        self.record_as_single_step(|data| {
+            let category = clean_string(category)?;
+            data.activities.set_category_color(category.clone(), color);
+
+            if let Some(color) = color {
+                for activity in data
+                    .activities_of_category(category)
+                    .into_iter()
+                    .filter(|activity| activity.main_session_id() == activity.id())
+                {
+                    data.set_activity_color(activity.id(), color)?;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Returns the activities of the category with the formatted given name, sorted by name.
+    /// The sessions of split activities are all returned.
+    #[must_use]
+    pub fn activities_of_category<S>(&self, category: S) -> Vec<Activity>
+    where
+        S: Into<String>,
+    {
+        clean_string(category).map_or_else(
+            |_| Vec::new(),
+            |category| self.activities_filtered(&ActivityFilter::with_category(category)),
+        )
+    }
+
+    /// Returns the activities kept by the given filter, sorted by name.
+    #[must_use]
+    pub fn activities_filtered(&self, filter: &ActivityFilter) -> Vec<Activity> {
+        self.activities_sorted()
+            .into_iter()
+            .filter(|activity| filter.matches(activity))
+            .collect()
+    }
+
+    /// Gives the activity with given id the color of its category, if the category has one.
+    pub(super) fn apply_category_color(&mut self, id: ActivityId) -> Result<()> {
+        let color = self
+            .activity(id)
+            .category()
+            .and_then(|category| self.activities.category_color(&category));
+        if let Some(color) = color {
+            self.set_activity_color(id, color)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+use crate::errors::{invalid_chain::InvalidChain, Result};
+use crate::{ActivityChain, ActivityId, Data, Duration, TimeOfDay};
+
+/// Operations on activities which must take place one right after the other.
+///
+/// A chain is computed as a single block. Once the block is inserted, each of its
+/// activities is inserted at its own place inside of the block.
+impl Data {
+    /// Chains the activity with id next_id right after the activity with id previous_id:
+    /// it will begin exactly when the previous one ends, plus the given offset.
+    /// If the activities are already chained to others, both chains are joined.
+    ///
+    /// The activities of the chain are removed from the schedule.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the previous activity is already followed, if the next activity
+    /// already follows another one, if both activities are part of the same chain
+    /// or if one of them is split or begins at the same time as other activities.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the activities with given IDs does not exist.
+    pub fn chain_activities(
+        &mut self,
+        previous_id: ActivityId,
+        next_id: ActivityId,
+        offset: Duration,
+    ) -> Result<()> {
+        self.record_as_single_step(|data| -> Result<()> {
+            let previous_activity = data.activity(previous_id);
+            let next_activity = data.activity(next_id);
+
+            for activity in &[&previous_activity, &next_activity] {
+                if activity.sessions().is_some() || !activity.simultaneous_activity_ids().is_empty()
+                {
+                    return Err(InvalidChain::constrained(activity.name()));
+                }
+            }
+
+            let previous_chain = previous_activity
+                .chain()
+                .unwrap_or_else(|| ActivityChain::new(vec![previous_id], Vec::new()));
+            let next_chain = next_activity
+                .chain()
+                .unwrap_or_else(|| ActivityChain::new(vec![next_id], Vec::new()));
+
+            if previous_chain.index_of(next_id).is_some() {
+                return Err(InvalidChain::would_loop(next_activity.name()));
+            }
+            if previous_chain.activity_ids().last() != Some(&previous_id) {
+                return Err(InvalidChain::already_followed(previous_activity.name()));
+            }
+            if next_chain.head_id() != next_id {
+                return Err(InvalidChain::already_preceded(next_activity.name()));
+            }
+
+            // The activities of the chain will be inserted as a single block
+            for id in &[previous_id, next_id] {
+                if data.activity(*id).insertion_interval().is_some() {
+                    data.insert_activity(*id, None)?;
+                }
+            }
+
+            let activity_ids = previous_chain
+                .activity_ids()
+                .iter()
+                .chain(next_chain.activity_ids())
+                .copied()
+                .collect::<Vec<_>>();
+            let offsets = previous_chain
+                .offsets()
+                .iter()
+                .copied()
+                .chain(std::iter::once(offset))
+                .chain(next_chain.offsets().iter().copied())
+                .collect();
+
+            data.activities
+                .link_chain(ActivityChain::new(activity_ids.clone(), offsets));
+            data.queue_chained_activities_and_emit_changed(&activity_ids);
+            Ok(())
+        })
+    }
+
+    /// Removes the activity with given id from its chain.
+    /// The activities before and after it form two separate chains.
+    /// The activities stay in the schedule if they are inserted.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the activity is not chained to other activities.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub fn unchain_activity(&mut self, id: ActivityId) -> Result<()> {
+        let activity = self.activity(id);
+        let chain = activity
+            .chain()
+            .ok_or_else(|| InvalidChain::not_chained(activity.name()))?;
+        let index = chain
+            .index_of(id)
+            .expect("The activity is not part of its own chain");
+
+        self.unlink_chain_around(&chain, index);
+        self.queue_chained_activities_and_emit_changed(chain.activity_ids());
+        Ok(())
+    }
+
+    /// Returns the ids of the activities which are inserted and removed from the schedule
+    /// along with the activity with given id.
+    /// The given id always comes first.
+    #[must_use]
+    pub(super) fn ids_moving_with(&self, id: ActivityId) -> Vec<ActivityId> {
+        if let Some(chain) = self.activity(id).chain() {
+            std::iter::once(id)
+                .chain(
+                    chain
+                        .activity_ids()
+                        .iter()
+                        .copied()
+                        .filter(|&chained_id| chained_id != id),
+                )
+                .collect()
+        } else {
+            self.ids_beginning_with(id)
+        }
+    }
+
+    /// Returns the ids of the activities which are inserted along with the activity with given
+    /// id if it begins at the given time, along with their own beginning.
+    /// The given id always comes first.
+    #[must_use]
+    pub(super) fn beginnings_of_activities_moving_with(
+        &self,
+        id: ActivityId,
+        beginning: TimeOfDay,
+    ) -> Vec<(ActivityId, TimeOfDay)> {
+        if let Some(chain) = self.activity(id).chain() {
+            let durations = chain
+                .activity_ids()
+                .iter()
+                .map(|&chained_id| self.activity(chained_id).duration())
+                .collect::<Vec<_>>();
+            let shifts = chain.shifts(&durations);
+            let beginning_of_chain = beginning
+                - shifts[chain
+                    .index_of(id)
+                    .expect("The activity is not part of its own chain")];
+
+            let mut beginnings = chain
+                .activity_ids()
+                .iter()
+                .copied()
+                .zip(shifts.into_iter().map(|shift| beginning_of_chain + shift))
+                .collect::<Vec<_>>();
+            beginnings.sort_by_key(|&(chained_id, _)| chained_id != id);
+            beginnings
+        } else {
+            self.ids_beginning_with(id)
+                .into_iter()
+                .map(|id| (id, beginning))
+                .collect()
+        }
+    }
+
+    /// Removes the activity with given id from its chain, if it has one.
+    /// Used before the activity is removed.
+    pub(super) fn unchain_activity_if_chained(&mut self, id: ActivityId) {
+        if let Some(chain) = self.activity(id).chain() {
+            let index = chain
+                .index_of(id)
+                .expect("The activity is not part of its own chain");
+            self.unlink_chain_around(&chain, index);
+            self.queue_chained_activities_and_emit_changed(
+                &chain
+                    .activity_ids()
+                    .iter()
+                    .copied()
+                    .filter(|&chained_id| chained_id != id)
+                    .collect::<Vec<_>>(),
+            );
+        }
+    }
+
+    /// Unlinks the given chain, then links the activities before and after the given index
+    /// back together.
+    fn unlink_chain_around(&mut self, chain: &ActivityChain, index: usize) {
+        let activity_ids = chain.activity_ids();
+        let offsets = chain.offsets();
+        self.activities.unlink_chain(activity_ids);
+
+        if index >= 2 {
+            self.activities.link_chain(ActivityChain::new(
+                activity_ids[..index].to_vec(),
+                offsets[..index - 1].to_vec(),
+            ));
+        }
+        if index + 2 < activity_ids.len() {
+            self.activities.link_chain(ActivityChain::new(
+                activity_ids[index + 1..].to_vec(),
+                offsets[index + 1..].to_vec(),
+            ));
+        }
+    }
+
+    fn queue_chained_activities_and_emit_changed(&mut self, ids: &[ActivityId]) {
+        for &id in ids {
+            self.queue_activity_participants(self.activity(id));
+        }
+        for &id in ids {
+            self.events()
+                .borrow_mut()
+                .emit_activity_chain_changed(self, &self.activity(id));
+        }
+    }
+}
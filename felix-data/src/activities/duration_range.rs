@@ -0,0 +1,117 @@
+use crate::errors::{duration_too_short::DurationTooShort, Result};
+use crate::{ActivityId, Data, Duration, DurationObjective};
+
+/// Operations on activities which may last anywhere in a duration range,
+/// e.g. between 01:00 and 01:30, the longer the better.
+///
+/// The duration of the activity is the shortest duration of the range : free time is computed
+/// with it. Load limits are checked with the longest duration of the range so that they hold
+/// whichever duration the activity lasts. Once inserted, the activity lasts the duration of the
+/// range which best fits its duration objective at this time. Chained activities always last
+/// their duration.
+///
+/// Each duration of the range is checked while the other activities last their shortest
+/// duration, and each possible beginning keeps only its best duration. When a participant
+/// takes part in several activities with ranges, their best durations may therefore not fit
+/// together : once one of them is inserted, the possible beginnings of the others are computed
+/// again with the time it takes.
+impl Data {
+    /// Sets the longest duration of the activity with given id.
+    /// The activity may then last anywhere between its duration and this one.
+    /// If None is given, the activity lasts exactly its duration.
+    /// Every occurrence of a recurring activity has the same range.
+    ///
+    /// If the activity is inserted, it is removed from the schedule and will be inserted again
+    /// once its possible beginnings are computed.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the maximum duration is shorter than the duration of the activity
+    /// or if a participant would exceed its load limits with the maximum duration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity is not found.
+    pub fn set_activity_max_duration(
+        &mut self,
+        id: ActivityId,
+        max_duration: Option<Duration>,
+    ) -> Result<()> {
+        self.record_as_single_step(|data| -> Result<()> {
+            let activity = data.activity(id);
+            if let Some(max_duration) = max_duration {
+                if max_duration < activity.duration() {
+                    return Err(DurationTooShort::new());
+                }
+            }
+            // A range which only contains the duration is no range
+            let max_duration =
+                max_duration.filter(|&max_duration| max_duration > activity.duration());
+            if let Some(max_duration) = max_duration {
+                data.check_load_limits_respected_with_duration(id, max_duration)?;
+            }
+
+            for occurrence_id in data.occurrence_ids_of(id) {
+                data.remove_activity_to_insert_it_again(occurrence_id);
+                data.activities
+                    .set_max_duration(occurrence_id, max_duration);
+            }
+
+            if data.activity(id).can_be_inserted() {
+                data.queue_activity_participants(data.activity(id));
+            }
+
+            data.events()
+                .borrow_mut()
+                .emit_activity_duration_changed(data, &data.activity(id));
+
+            Ok(())
+        })
+    }
+
+    /// Sets which duration of its range the activity with given id lasts when inserted.
+    /// Every occurrence of a recurring activity has the same objective.
+    ///
+    /// If the activity is inserted, it is removed from the schedule and will be inserted again
+    /// once its possible beginnings are computed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity is not found.
+    pub fn set_activity_duration_objective(
+        &mut self,
+        id: ActivityId,
+        duration_objective: DurationObjective,
+    ) -> Result<()> {
+        self.record_as_single_step(|data| {
+            for occurrence_id in data.occurrence_ids_of(id) {
+                if data.activity(occurrence_id).has_flexible_duration() {
+                    data.remove_activity_to_insert_it_again(occurrence_id);
+                }
+                data.activities
+                    .set_duration_objective(occurrence_id, duration_objective);
+            }
+
+            if data.activity(id).can_be_inserted() {
+                data.queue_activity_participants(data.activity(id));
+            }
+
+            data.events()
+                .borrow_mut()
+                .emit_activity_duration_changed(data, &data.activity(id));
+
+            Ok(())
+        })
+    }
+
+    /// If the activity with given id is inserted, removes it from the schedule and remembers
+    /// where it was so that it is inserted again in the closest spot once its possible
+    /// beginnings are computed.
+    pub(super) fn remove_activity_to_insert_it_again(&mut self, id: ActivityId) {
+        if self.activity(id).insertion_interval().is_some() {
+            self.activities.store_activity_was_inserted(id);
+            self.insert_activity(id, None)
+                .expect("Could not remove activity from schedule. This is a bug.");
+        }
+    }
+}
@@ -2,10 +2,11 @@
 
 use crate::errors::{
     add_entity_to_inserted_activity_invalid_spot::AddEntityToInsertedActivityInvalidSpot,
+    invalid_participants::InvalidParticipants, invalid_simultaneity::InvalidSimultaneity,
     not_enough_time::NotEnoughTime, Result,
 };
-use crate::Time;
-use crate::{ActivityId, Data};
+use crate::Duration;
+use crate::{ActivityId, Data, EntityId};
 
 impl Data {
     /// Returns the first entity which does not have enough time to change the duration of the
@@ -21,7 +22,7 @@ impl Data {
     pub(super) fn check_entity_without_enough_time_to_set_duration(
         &self,
         id: ActivityId,
-        new_duration: Time,
+        new_duration: Duration,
     ) -> Result<()> {
         let activity = self.activity(id);
         let current_duration = activity.duration();
@@ -29,10 +30,13 @@ impl Data {
         if new_duration <= current_duration {
             Ok(())
         } else {
-            // Duration is longer - check if it conflicts with entity's schedule
-            let required_free_time = new_duration - current_duration; // > 0
-            if let Some(entity_name) = activity
-                .entities_sorted()
+            // Duration is longer - check if it conflicts with entity's schedule.
+            // Every occurrence of a recurring activity gets longer.
+            let required_free_time: Duration = (0..activity.number_of_occurrences())
+                .map(|_| new_duration - current_duration) // > 0
+                .sum();
+            if let Some(entity_name) = self
+                .entities_of_activity(id)
                 .iter()
                 // Call to expect() : we are sure that all entities in the activity exist.
                 .find(|entity_name| {
@@ -68,8 +72,14 @@ impl Data {
         entity_name: &str,
     ) -> Result<()> {
         let free_time = self.free_time_of(entity_name)?;
+        // The entity takes part in every session of the activity
+        let duration: Duration = self
+            .session_ids_of(activity_id)
+            .into_iter()
+            .map(|id| self.activity(id).duration())
+            .sum();
 
-        if free_time >= self.activity(activity_id).duration() {
+        if free_time >= duration {
             Ok(())
         } else {
             let activity = self.activity(activity_id);
@@ -80,6 +90,45 @@ impl Data {
         }
     }
 
+    /// Checks that every participant of the activity with given id has enough time left
+    /// for it to take place the given number of times.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if a participant does not have enough time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub(super) fn check_has_enough_time_for_occurrences(
+        &self,
+        id: ActivityId,
+        number_of_occurrences: usize,
+    ) -> Result<()> {
+        let activity = self.activity(id);
+        let required_free_time: Duration = (activity.number_of_occurrences()
+            ..number_of_occurrences)
+            .map(|_| activity.duration())
+            .sum();
+
+        if let Some(entity_name) = self
+            .entities_of_activity(id)
+            .into_iter()
+            .find(|entity_name| {
+                self.free_time_of(entity_name)
+                    .expect("Could not get entity participating in an activity")
+                    < required_free_time
+            })
+        {
+            Err(NotEnoughTime::occurrences_added_for(
+                entity_name,
+                activity.name(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
     /// Checks that no activity of the entity overlaps with the given activity's insertion slot.
     ///
     /// # Errors
@@ -108,10 +157,14 @@ impl Data {
                         && other_activity.insertion_interval().is_some()
                 })
                 .find(|other_activity| {
-                    insertion_interval.overlaps_with(
+                    insertion_interval.is_closer_than(
                         &other_activity
                             .insertion_interval()
                             .expect("Filtering only inserted activities did not work"),
+                        std::cmp::max(
+                            activity.transition_buffer(),
+                            other_activity.transition_buffer(),
+                        ),
                     )
                 })
             {
@@ -129,6 +182,45 @@ impl Data {
         }
     }
 
+    /// Checks that the break rules of the entity are respected if the entity is added to the
+    /// given activity.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the activity is inserted and the entity would not have the breaks
+    /// they need.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub(super) fn check_break_rules_respected_with_activity(
+        &self,
+        activity_id: ActivityId,
+        entity_name: &str,
+    ) -> Result<()> {
+        let activity = self.activity(activity_id);
+
+        if let Some(insertion_interval) = activity.insertion_interval() {
+            if self.break_rules_respected_if_inserted_at(
+                entity_name,
+                &activity,
+                insertion_interval.beginning(),
+            ) {
+                Ok(())
+            } else {
+                Err(
+                    AddEntityToInsertedActivityInvalidSpot::break_rules_not_respected(
+                        entity_name,
+                        activity.name(),
+                    ),
+                )
+            }
+        } else {
+            // The activity is not inserted
+            Ok(())
+        }
+    }
+
     /// Checks that the activity, if inserted, fits into the entity's work hours.
     ///
     /// # Errors
@@ -172,4 +264,70 @@ impl Data {
             Ok(())
         }
     }
+
+    /// Checks that the entity does not take part in an activity which begins at the same time
+    /// as the activity with given id.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity takes part in a simultaneous activity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub(crate) fn check_entity_not_in_simultaneous_activities(
+        &self,
+        id: ActivityId,
+        entity_name: &str,
+    ) -> Result<()> {
+        let activity = self.activity(id);
+        if activity
+            .simultaneous_activity_ids()
+            .into_iter()
+            .any(|simultaneous_id| {
+                self.entities_of_activity(simultaneous_id)
+                    .iter()
+                    .any(|other_entity_name| other_entity_name == entity_name)
+            })
+        {
+            Err(InvalidSimultaneity::shared_participant(
+                activity.name(),
+                entity_name,
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Checks that the activity with given id does not exceed its maximum number of
+    /// participants if the given entities become mandatory participants.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the activity would have too many participants.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub(super) fn check_max_participants_respected_with(
+        &self,
+        id: ActivityId,
+        entity_ids: &[EntityId],
+    ) -> Result<()> {
+        let activity = self.activity(id);
+        if let Some(max_participants) = activity.max_participants() {
+            let mandatory_entity_ids = activity.entity_ids();
+            let new_participants = entity_ids
+                .iter()
+                .filter(|entity_id| !mandatory_entity_ids.contains(entity_id))
+                .count();
+            if mandatory_entity_ids.len() + new_participants > max_participants {
+                return Err(InvalidParticipants::too_many(
+                    activity.name(),
+                    max_participants,
+                ));
+            }
+        }
+        Ok(())
+    }
 }
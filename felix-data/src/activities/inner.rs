@@ -1,13 +1,16 @@
 //! Helper functions for activity implementation of data.
 
 use crate::errors::Result;
-use crate::Time;
-use crate::{Activity, ActivityId, Data, TimeInterval, WorkHoursAndActivityDurationsSorted};
+use crate::TimeOfDay;
+use crate::{
+    Activity, ActivityId, Data, EntityId, TimeInterval, WorkHoursAndActivityDurationsSorted,
+};
 
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 
 impl Data {
-    /// Returns all entities which participate in the given activity in more than one group.
+    /// Returns all entities which participate in the given activity through the given group
+    /// only.
     ///
     /// # Errors
     ///
@@ -17,25 +20,23 @@ impl Data {
         activity_id: ActivityId,
         group_name: &str,
     ) -> Result<HashSet<String>> {
-        let all_participating_groups = self.activity(activity_id).groups_sorted();
-        let entities_of_group = self
-            .group(group_name)?
-            .entities_sorted()
+        let group = self.group(group_name)?;
+        let entities_in_other_groups = self
+            .activity(activity_id)
+            .group_ids()
             .into_iter()
-            .collect::<HashSet<_>>();
+            .filter(|&other_group_id| other_group_id != group.id())
+            .flat_map(|other_group_id| self.groups.member_ids(other_group_id))
+            .collect::<BTreeSet<EntityId>>();
 
-        let entities_in_other_groups = all_participating_groups
-            .iter()
-            .filter(|&other_group_name| other_group_name != group_name)
-            .flat_map(|group_name|
-                // Expect is safe to use here: we are sure that the activtiy contains valid groups
-                self.group(group_name).expect("Could not get group which is in an activity").entities_sorted()
-                )
-            .collect::<HashSet<String>>();
-
-        Ok(entities_of_group
-            .difference(&entities_in_other_groups)
-            .cloned()
+        Ok(self
+            .entities
+            .names_sorted(
+                self.groups
+                    .member_ids(group.id())
+                    .difference(&entities_in_other_groups),
+            )
+            .into_iter()
             .collect())
     }
 
@@ -45,18 +46,29 @@ impl Data {
     pub(super) fn incompatible_activity_inserted_at_time(
         &self,
         activity: &Activity,
-        time: Time,
+        time: TimeOfDay,
     ) -> Option<Activity> {
-        let hypothetical_insertion_iterval = TimeInterval::new(time, time + activity.duration());
+        let hypothetical_insertion_iterval =
+            TimeInterval::new(time, time + activity.duration_if_inserted_at(time));
+        let chained_ids = activity
+            .chain()
+            .map(|chain| chain.activity_ids().clone())
+            .unwrap_or_default();
         activity
             .incompatible_activity_ids()
             .iter()
+            // Chained activities are moved together, they cannot be in the way
+            .filter(|id| !chained_ids.contains(id))
             .filter_map(|&id| {
-                let activity = self.activity(id);
+                let other_activity = self.activity(id);
+                let transition_buffer = std::cmp::max(
+                    activity.transition_buffer(),
+                    other_activity.transition_buffer(),
+                );
 
-                if let Some(interval) = activity.insertion_interval() {
-                    if interval.overlaps_with(&hypothetical_insertion_iterval) {
-                        Some(activity)
+                if let Some(interval) = other_activity.insertion_interval() {
+                    if interval.is_closer_than(&hypothetical_insertion_iterval, transition_buffer) {
+                        Some(other_activity)
                     } else {
                         None
                     }
@@ -67,7 +79,160 @@ impl Data {
             .next()
     }
 
-    /// Given an entity, outputs their work hours and activity durations.
+    /// Returns the first activity which must begin at the same time as the activity with
+    /// given id but whose possible insertion times do not include the given time, if it exists.
+    #[must_use]
+    pub(super) fn simultaneous_activity_which_cannot_begin_at(
+        &self,
+        id: ActivityId,
+        time: TimeOfDay,
+    ) -> Option<Activity> {
+        self.activity(id)
+            .simultaneous_activity_ids()
+            .into_iter()
+            .map(|simultaneous_id| self.activity(simultaneous_id))
+            .find(|simultaneous_activity| {
+                simultaneous_activity
+                    .insertion_costs()
+                    .is_none_or(|insertion_costs| {
+                        !insertion_costs
+                            .iter()
+                            .any(|insertion_cost| insertion_cost.beginning == time)
+                    })
+            })
+    }
+
+    /// Returns the first activity which must begin at the same time as the given activity
+    /// but which would overlap with another activity or break the rules of its participants
+    /// if it began at the given time, if it exists.
+    #[must_use]
+    pub(super) fn simultaneous_activity_blocked_at(
+        &self,
+        activity: &Activity,
+        time: TimeOfDay,
+    ) -> Option<Activity> {
+        activity
+            .simultaneous_activity_ids()
+            .into_iter()
+            .map(|simultaneous_id| self.activity(simultaneous_id))
+            .find(|simultaneous_activity| {
+                self.incompatible_activity_inserted_at_time(simultaneous_activity, time)
+                    .is_some()
+                    || self
+                        .participant_without_breaks_if_inserted_at_time(simultaneous_activity, time)
+                        .is_some()
+            })
+    }
+
+    /// Returns the first participant of the activity whose break rules would not be respected
+    /// if the activity was inserted at the given time, if it exists.
+    #[must_use]
+    pub(crate) fn participant_without_breaks_if_inserted_at_time(
+        &self,
+        activity: &Activity,
+        time: TimeOfDay,
+    ) -> Option<String> {
+        let entities = self.entities.names_sorted(&activity.entity_ids());
+        entities.into_iter().find(|entity_name| {
+            !self.break_rules_respected_if_inserted_at(entity_name, activity, time)
+        })
+    }
+
+    /// Returns true if the break rules of the entity would be respected if the activity
+    /// was inserted at the given time, taking the inserted activities of the entity into account.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entity does not exist.
+    #[must_use]
+    pub(super) fn break_rules_respected_if_inserted_at(
+        &self,
+        entity_name: &str,
+        activity: &Activity,
+        time: TimeOfDay,
+    ) -> bool {
+        let entity_id = self
+            .entity(entity_name)
+            .expect("Checking break rules of an entity which does not exist")
+            .id();
+        let mut activities_of_entity = self
+            .activities_of(entity_name)
+            .expect("Checking break rules of an entity which does not exist")
+            .into_iter()
+            .filter(|other| other.id() != activity.id())
+            .filter_map(|other| other.insertion_interval())
+            .map(|interval| {
+                (
+                    interval.beginning().total_minutes(),
+                    interval.end().total_minutes(),
+                )
+            })
+            .collect::<Vec<_>>();
+        activities_of_entity.push((
+            time.total_minutes(),
+            (time + activity.duration_if_inserted_at(time)).total_minutes(),
+        ));
+
+        self.work_hours
+            .break_rules_of(entity_id)
+            .are_respected_by(activities_of_entity)
+    }
+
+    /// Returns the ids of all sessions of the activity with given id, in order.
+    /// If the activity was not split, returns its id only.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    #[must_use]
+    pub(crate) fn session_ids_of(&self, id: ActivityId) -> Vec<ActivityId> {
+        self.activity(id)
+            .sessions()
+            .map_or(vec![id], |sessions| sessions.session_ids().clone())
+    }
+
+    /// Returns the ids of all occurrences of the activity with given id, in order.
+    /// If the activity is not recurring, returns its id only.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    #[must_use]
+    pub(crate) fn occurrence_ids_of(&self, id: ActivityId) -> Vec<ActivityId> {
+        self.activity(id)
+            .sessions()
+            .filter(|sessions| sessions.is_recurring())
+            .map_or(vec![id], |sessions| sessions.session_ids().clone())
+    }
+
+    /// Returns true if the sessions of the activity would still be in order if it was inserted
+    /// at the given time, spaced if they are the occurrences of a recurring activity.
+    /// Always true if the sessions do not need to be consecutive.
+    #[must_use]
+    pub(super) fn sessions_in_order_if_inserted_at(
+        &self,
+        activity: &Activity,
+        time: TimeOfDay,
+    ) -> bool {
+        activity.sessions().is_none_or(|sessions| {
+            let min_spacing = sessions.min_spacing();
+            let after_previous_session = sessions
+                .previous_session_of(activity.id())
+                .and_then(|id| self.activity(id).insertion_interval())
+                .is_none_or(|interval| interval.end() + min_spacing <= time);
+            let before_next_session = sessions
+                .next_session_of(activity.id())
+                .and_then(|id| self.activity(id).insertion_interval())
+                .is_none_or(|interval| {
+                    time + activity.duration_if_inserted_at(time) + min_spacing
+                        <= interval.beginning()
+                });
+            after_previous_session && before_next_session
+        })
+    }
+
+    /// Given an entity, outputs their work hours and the (minimum, maximum) duration of their
+    /// activities.
     ///
     /// # Panics
     ///
@@ -81,7 +246,7 @@ impl Data {
             .activities_of(entity)
             .expect("The entity name is empty")
             .iter()
-            .map(|activity| activity.duration())
+            .map(|activity| (activity.duration(), activity.max_duration()))
             .collect::<Vec<_>>();
 
         WorkHoursAndActivityDurationsSorted::new(work_hours, activity_durations)
@@ -1,12 +1,20 @@
+mod categories;
+mod chains;
+mod duration_range;
 mod error_checks;
 mod inner;
+mod occurrences;
+mod optional_participants;
 mod queue_for_computation;
+mod sessions;
+mod simultaneity;
+mod templates;
 
 use super::helpers::clean_string;
 
 use crate::{
-    errors::{invalid_insertion::InvalidInsertion, Result},
-    Activity, ActivityBeginningMinutes, ActivityId, Data, Rgba, Time,
+    errors::{invalid_insertion::InvalidInsertion, invalid_split::InvalidSplit, Result},
+    Activity, ActivityBeginningMinutes, ActivityId, Data, Duration, Rgba, TimeOfDay,
 };
 use felix_collections::activity::{
     activities_into_computation_data, activities_sorted_filtered_for_computation,
@@ -41,19 +49,41 @@ impl Data {
     ///
     /// # Errors
     ///
-    /// Returns err if the entity name is empty after sanitization.
+    /// Returns err if the entity name is empty after sanitization or if the entity is not found.
     pub fn activities_of<S>(&self, entity_name: S) -> Result<Vec<Activity>>
     where
         S: Into<String>,
     {
-        let entity_name = clean_string(entity_name)?;
+        let entity_id = self.entity(entity_name)?.id();
         Ok(self
             .activities_not_sorted()
             .into_iter()
-            .filter(|activity| activity.entities_sorted().contains(&entity_name))
+            .filter(|activity| activity.entity_ids().contains(&entity_id))
             .collect())
     }
 
+    /// Returns the names of the entities taking part in the activity with given id,
+    /// sorted by name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity is not found.
+    #[must_use]
+    pub fn entities_of_activity(&self, id: ActivityId) -> Vec<String> {
+        self.entities.names_sorted(&self.activity(id).entity_ids())
+    }
+
+    /// Returns the names of the groups taking part in the activity with given id,
+    /// sorted by name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity is not found.
+    #[must_use]
+    pub fn groups_of_activity(&self, id: ActivityId) -> Vec<String> {
+        self.groups.names_sorted(&self.activity(id).group_ids())
+    }
+
     /// Waits until the insertion costs of an activity have been computed.
     pub fn wait_for_possible_insertion_costs_computation(&self, id: ActivityId) {
         while self.activity(id).insertion_costs().is_none() {
@@ -83,34 +113,44 @@ impl Data {
     }
 
     /// Removes the activity with the given id.
+    /// If the activity was split, all of its sessions are removed.
     /// Returns the position of the removed activity in the array sorted by name.
     ///
     /// # Panics
     ///
     /// Panics if the activity with given ID does not exist.
     pub fn remove_activity(&mut self, id: ActivityId) {
-        let activities = self.activities_sorted();
-        let position_of_removed_activity = activities
-            .into_iter()
-            .position(|activity| activity.id() == id)
-            .expect("The activity with given id does not exist");
-
-        let impacted_entities = self.activity(id).entities_sorted();
-        self.activities.remove(id);
+        self.record_as_single_step(|data| {
+            let activities = data.activities_sorted();
+            let position_of_removed_activity = activities
+                .into_iter()
+                .position(|activity| activity.id() == id)
+                .expect("The activity with given id does not exist");
+
+            let impacted_entities = data.entities_of_activity(id);
+            data.unchain_activity_if_chained(id);
+            for session_id in data.session_ids_of(id) {
+                data.activities.unlink_simultaneous(session_id);
+                data.activities.remove(session_id);
+            }
 
-        self.queue_entities(impacted_entities);
+            data.queue_entities(impacted_entities);
 
-        self.events()
-            .borrow_mut()
-            .emit_activity_removed(self, position_of_removed_activity);
+            data.events()
+                .borrow_mut()
+                .emit_activity_removed(data, position_of_removed_activity);
+        })
     }
 
     /// Adds the entity with given name to the activity with given id.
+    /// If the entity is an optional participant of the activity, it becomes mandatory.
     ///
     /// # Errors
     ///
     /// Returns Err if the entity is not found,
-    /// if the entity does not have enough time left
+    /// if the entity does not have enough time left,
+    /// if the entity would exceed its load limits,
+    /// if the activity would exceed its maximum number of participants
     /// or the entity is already taking part in the activity.
     ///
     /// # Panics
@@ -121,11 +161,21 @@ impl Data {
         S: Into<String>,
     {
         let entity_name = clean_string(entity_name)?;
+        self.check_max_participants_respected_with(id, &[self.entity(&entity_name)?.id()])?;
         self.check_has_enough_time_for_activity(id, &entity_name)?;
-        self.check_no_activity_of_the_entity_is_overlapping(id, &entity_name)?;
-        self.check_activity_inside_of_work_hours(id, &entity_name)?;
+        self.check_load_limits_respected_with_activity(id, &entity_name)?;
+        let session_ids = self.session_ids_of(id);
+        for &session_id in &session_ids {
+            self.check_no_activity_of_the_entity_is_overlapping(session_id, &entity_name)?;
+            self.check_activity_inside_of_work_hours(session_id, &entity_name)?;
+            self.check_break_rules_respected_with_activity(session_id, &entity_name)?;
+            self.check_entity_not_in_simultaneous_activities(session_id, &entity_name)?;
+        }
 
-        self.activities.add_entity(id, entity_name.clone())?;
+        let entity = self.entity(&entity_name)?;
+        for session_id in session_ids {
+            self.activities.add_entity(session_id, &entity)?;
+        }
         self.queue_entities(vec![entity_name]);
 
         self.events()
@@ -145,33 +195,48 @@ impl Data {
     where
         S: Into<String>,
     {
-        // Check that the entity exists and get it formatted
-        let entity_name = self.entity(entity_name)?.name();
-        // Remove the entity from the activity
-        self.activities.remove_entity(id, &entity_name)?;
-
-        // Queue the entity which was just removed
-        // TODO add test here: entity schedule changes when entity removed from one activity
-        // After adding tests, comment self.queue_entities line and see if tests fail
-        self.queue_entities(vec![entity_name]);
+        self.record_as_single_step(|data| {
+            // Check that the entity exists and get it formatted
+            let entity = data.entity(entity_name)?;
+            let entity_name = entity.name();
+            // Remove the entity from the activity and its other sessions
+            data.activities.remove_entity(id, &entity)?;
+            let other_session_ids = data
+                .session_ids_of(id)
+                .into_iter()
+                .filter(|&session_id| session_id != id)
+                .collect::<Vec<_>>();
+            for &session_id in &other_session_ids {
+                data.activities.remove_entity(session_id, &entity)?;
+            }
 
-        if self.activity(id).can_be_inserted() {
-            // Queue the activity because it has one less participant
-            self.queue_activity_participants(self.activity(id));
-        } else {
-            // Remove activity from schedule because it cannot be inserted anymore
-            self.insert_activity(id, None)?;
-        }
+            // Queue the entity which was just removed
+            // TODO add test here: entity schedule changes when entity removed from one activity
+            // After adding tests, comment data.queue_entities line and see if tests fail
+            data.queue_entities(vec![entity_name]);
 
-        self.events()
-            .borrow_mut()
-            .emit_entity_removed_from_activity(self, &self.activity(id));
-        Ok(())
+            if data.activity(id).can_be_inserted() {
+                // Queue the activity because it has one less participant
+                data.queue_activity_participants(data.activity(id));
+            } else {
+                // Remove activity from schedule because it cannot be inserted anymore
+                for session_id in other_session_ids {
+                    data.insert_activity(session_id, None)?;
+                }
+                data.insert_activity(id, None)?;
+            }
+
+            data.events()
+                .borrow_mut()
+                .emit_entity_removed_from_activity(data, &data.activity(id));
+            Ok(())
+        })
     }
 
     /// Adds the group with the formatted given name to the activity with the given id.
     ///
-    /// Any activity currently in the group will be added to the activity.
+    /// Every member of the group, including the members of its subgroups,
+    /// is added to the activity.
     ///
     /// # Panics
     ///
@@ -180,39 +245,49 @@ impl Data {
     /// # Errors
     ///
     /// Returns Err if the group is not found,
-    /// if the name is empty
+    /// if the name is empty,
+    /// if a member of the group would exceed its load limits,
+    /// if the activity would exceed its maximum number of participants
     /// or if the group is already taking part in the activity.
     pub fn add_group_to_activity<S>(&mut self, id: ActivityId, group_name: S) -> Result<()>
     where
         S: Into<String>,
     {
-        // Check that the group exists and get name formatted
-        let group = self.group(group_name)?;
-        // Fetch group and entities here as copies (dropping group reference for borrow checker)
-        let entities = group.entities_sorted();
-        let group_name = group.name();
-
-        for entity_name in entities.iter() {
-            self.check_has_enough_time_for_activity(id, entity_name)?;
-        }
+        self.record_as_single_step(|data| {
+            // Check that the group exists and get name formatted
+            let group = data.group(group_name)?;
+            let member_ids = data.groups.member_ids(group.id());
+            let entities = data.entities.names_sorted(&member_ids);
+
+            data.check_max_participants_respected_with(
+                id,
+                &member_ids.iter().copied().collect::<Vec<_>>(),
+            )?;
+            for entity_name in entities.iter() {
+                data.check_has_enough_time_for_activity(id, entity_name)?;
+                data.check_load_limits_respected_with_activity(id, entity_name)?;
+            }
 
-        // Add each entity in the group to the activity.
-        // We do not care about the result: if the entity is already in the activity, it is fine.
-        for entity_name in entities {
-            let _ = self.add_entity_to_activity(id, entity_name);
-        }
+            // Add each entity in the group to the activity.
+            // We do not care about the result: if the entity is already in the activity, it is fine.
+            for entity_name in entities {
+                let _ = data.add_entity_to_activity(id, entity_name);
+            }
 
-        // Add the group to the activity
-        self.activities.add_group(id, clean_string(group_name)?)?;
+            // Add the group to the activity and its other sessions
+            for session_id in data.session_ids_of(id) {
+                data.activities.add_group(session_id, &group)?;
+            }
 
-        if self.activity(id).can_be_inserted() {
-            self.queue_activity_participants(self.activity(id));
-        }
+            if data.activity(id).can_be_inserted() {
+                data.queue_activity_participants(data.activity(id));
+            }
 
-        self.events()
-            .borrow_mut()
-            .emit_group_added_to_activity(self, &self.activity(id));
-        Ok(())
+            data.events()
+                .borrow_mut()
+                .emit_group_added_to_activity(data, &data.activity(id));
+            Ok(())
+        })
     }
 
     /// Removes the group with the formatted given name from the activity with the given id.
@@ -234,28 +309,36 @@ impl Data {
     where
         S: Into<String>,
     {
-        // Check that the group exists and get name formatted
-        let group_name = self.group(group_name)?.name();
-
-        self.activities.remove_group(id, &group_name)?;
+        self.record_as_single_step(|data| {
+            // Check that the group exists and get name formatted
+            let group = data.group(group_name)?;
+            let group_name = group.name();
+
+            data.activities.remove_group(id, &group)?;
+            for session_id in data.session_ids_of(id) {
+                if session_id != id {
+                    data.activities.remove_group(session_id, &group)?;
+                }
+            }
 
-        let entities_to_remove =
-            self.entities_participating_through_this_group_only(id, &group_name)?;
+            let entities_to_remove =
+                data.entities_participating_through_this_group_only(id, &group_name)?;
 
-        for entity_name in &entities_to_remove {
-            // The entity may already be out of the activity if excluded from group.
-            // Therefore, don't check for errors.
-            let _ = self.remove_entity_from_activity(id, entity_name);
-        }
+            for entity_name in &entities_to_remove {
+                // The entity may already be out of the activity if excluded from group.
+                // Therefore, don't check for errors.
+                let _ = data.remove_entity_from_activity(id, entity_name);
+            }
 
-        if self.activity(id).can_be_inserted() {
-            self.queue_activity_participants(self.activity(id));
-        }
+            if data.activity(id).can_be_inserted() {
+                data.queue_activity_participants(data.activity(id));
+            }
 
-        self.events()
-            .borrow_mut()
-            .emit_group_removed_from_activity(self, &self.activity(id));
-        Ok(())
+            data.events()
+                .borrow_mut()
+                .emit_group_removed_from_activity(data, &data.activity(id));
+            Ok(())
+        })
     }
 
     /// Sets the name of the activity with given id with the formatted given name.
@@ -274,7 +357,9 @@ impl Data {
         S: Into<String>,
     {
         let name = clean_string(name)?;
-        self.activities.set_name(id, name.clone());
+        for session_id in self.session_ids_of(id) {
+            self.activities.set_name(session_id, name.clone());
+        }
         self.events()
             .borrow_mut()
             .emit_activity_renamed(self, &self.activity(id));
@@ -282,6 +367,7 @@ impl Data {
     }
 
     /// Sets the duration of the activity with given id.
+    /// If the activity is recurring, all of its occurrences get the new duration.
     ///
     /// # Panics
     ///
@@ -289,43 +375,131 @@ impl Data {
     ///
     /// # Errors
     ///
-    /// Returns Err if an entity does not have enough time left.
-    pub fn set_activity_duration(&mut self, id: ActivityId, new_duration: Time) -> Result<()> {
-        // If the duration is longer than the previous one, check for conflicts
-        let activity = self.activity(id);
+    /// Returns Err if an entity does not have enough time left, if an entity would exceed
+    /// its load limits or if the activity is a session which would become too short.
+    pub fn set_activity_duration(&mut self, id: ActivityId, new_duration: Duration) -> Result<()> {
+        self.record_as_single_step(|data| -> Result<()> {
+            // If the duration is longer than the previous one, check for conflicts
+            let activity = data.activity(id);
+
+            if let Some(sessions) = activity.sessions() {
+                let min_session_duration = sessions.rules().min_session_duration;
+                if new_duration < min_session_duration {
+                    return Err(InvalidSplit::sessions_too_short(
+                        activity.name(),
+                        min_session_duration,
+                    ));
+                }
+            }
 
-        if new_duration > activity.duration() {
-            self.check_entity_without_enough_time_to_set_duration(id, new_duration)?;
-            // Remove the activity from the schedule if its duration is greater.
-            // Because we may not be sure that it will fit there again, we have to perform the
-            // computation in another thread before we can insert it again.
-            if activity.insertion_interval().is_some() {
-                // Remember that the activity was inserted because we will remove it from the
-                // schedule.
-                // Once we compute its possible beginnings, we will be able to put it back in the
-                // schedule.
-                self.activities.store_activity_was_inserted(id);
-                self.insert_activity(id, None)
-                    .expect("Could not remove activity from schedule. This is a bug.");
+            if new_duration > activity.duration() {
+                data.check_entity_without_enough_time_to_set_duration(id, new_duration)?;
+                // Load limits apply to the longest duration of the range
+                data.check_load_limits_respected_with_duration(
+                    id,
+                    new_duration.max(activity.max_duration()),
+                )?;
             }
-        } else if new_duration == Time::new(0, 0) && activity.insertion_interval().is_some() {
-            // Activity with empty duration cannot be inserted
-            self.insert_activity(id, None)
-                .expect("Could not remove activity from schedule. This is a bug.");
-        }
 
-        self.activities.set_duration(id, new_duration);
+            // Every occurrence of a recurring activity has the same duration
+            for occurrence_id in data.occurrence_ids_of(id) {
+                let occurrence = data.activity(occurrence_id);
+                if new_duration > occurrence.duration() {
+                    // Remove the activity from the schedule if its duration is greater.
+                    // Because we may not be sure that it will fit there again, we have to perform
+                    // the computation in another thread before we can insert it again.
+                    if occurrence.insertion_interval().is_some() {
+                        // Remember that the activity was inserted because we will remove it from
+                        // the schedule.
+                        // Once we compute its possible beginnings, we will be able to put it back
+                        // in the schedule.
+                        data.activities.store_activity_was_inserted(occurrence_id);
+                        data.insert_activity(occurrence_id, None)
+                            .expect("Could not remove activity from schedule. This is a bug.");
+                    }
+                } else if occurrence.chain().is_some()
+                    && new_duration != occurrence.duration()
+                    && occurrence.insertion_interval().is_some()
+                {
+                    // The other activities of the chain would not be in place anymore.
+                    // Insert the chain again once the possible beginnings are computed.
+                    data.activities.store_activity_was_inserted(occurrence_id);
+                    data.insert_activity(occurrence_id, None)
+                        .expect("Could not remove activity from schedule. This is a bug.");
+                } else if new_duration == Duration::new(0, 0)
+                    && occurrence.insertion_interval().is_some()
+                {
+                    // Activity with empty duration cannot be inserted
+                    data.insert_activity(occurrence_id, None)
+                        .expect("Could not remove activity from schedule. This is a bug.");
+                }
 
-        // Don't queue activity with no duration or participants
-        if self.activity(id).can_be_inserted() {
-            self.queue_activity_participants(self.activity(id));
-        }
+                data.activities.set_duration(occurrence_id, new_duration);
+            }
 
-        self.events()
-            .borrow_mut()
-            .emit_activity_duration_changed(self, &self.activity(id));
+            // Don't queue activity with no duration or participants
+            if data.activity(id).can_be_inserted() {
+                data.queue_activity_participants(data.activity(id));
+            }
 
-        Ok(())
+            data.events()
+                .borrow_mut()
+                .emit_activity_duration_changed(data, &data.activity(id));
+
+            Ok(())
+        })
+    }
+
+    /// Sets the minimum free time between the activity with given id and any incompatible
+    /// activity. If the activity was split, the buffer applies to all of its sessions.
+    /// If a session is inserted and is now too close to an incompatible activity,
+    /// it is removed from the schedule and will be inserted again once its possible
+    /// beginnings are computed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity is not found.
+    pub fn set_activity_transition_buffer(
+        &mut self,
+        id: ActivityId,
+        transition_buffer: Duration,
+    ) -> Result<()> {
+        self.record_as_single_step(|data| {
+            let session_ids = data.session_ids_of(id);
+            for &session_id in &session_ids {
+                data.activities
+                    .set_transition_buffer(session_id, transition_buffer);
+            }
+
+            for session_id in session_ids {
+                let session = data.activity(session_id);
+                if let Some(insertion_interval) = session.insertion_interval() {
+                    if data
+                        .incompatible_activity_inserted_at_time(
+                            &session,
+                            insertion_interval.beginning(),
+                        )
+                        .is_some()
+                    {
+                        // Remember that the activity was inserted because we will remove it from the
+                        // schedule.
+                        data.activities.store_activity_was_inserted(session_id);
+                        data.insert_activity(session_id, None)
+                            .expect("Could not remove activity from schedule. This is a bug.");
+                    }
+                }
+            }
+
+            if data.activity(id).can_be_inserted() {
+                data.queue_activity_participants(data.activity(id));
+            }
+
+            data.events()
+                .borrow_mut()
+                .emit_activity_transition_buffer_changed(data, &data.activity(id));
+
+            Ok(())
+        })
     }
 
     /// Sets the color of the activity with given id.
@@ -334,7 +508,9 @@ impl Data {
     ///
     /// Panics if the activity is not found.
     pub fn set_activity_color(&mut self, id: ActivityId, color: Rgba) -> Result<()> {
-        self.activities.set_color(id, color);
+        for session_id in self.session_ids_of(id) {
+            self.activities.set_color(session_id, color);
+        }
         let activity = self.activity(id);
         self.events()
             .borrow_mut()
@@ -342,6 +518,36 @@ impl Data {
         Ok(())
     }
 
+    /// Sets the category of the activity with given id, and of its other sessions.
+    /// Categories are used by load limits and filters. If the category has a color,
+    /// the activity takes this color. None removes the category.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the formatted category is empty
+    /// or if a participant would exceed its load limits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity is not found.
+    pub fn set_activity_category<S>(&mut self, id: ActivityId, category: Option<S>) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        self.record_as_single_step(|data| {
+            let category = category.map(clean_string).transpose()?;
+            data.check_load_limits_respected_with_category(id, &category)?;
+            for session_id in data.session_ids_of(id) {
+                data.activities.set_category(session_id, category.clone());
+            }
+            let activity = data.activity(id);
+            data.events()
+                .borrow_mut()
+                .emit_activity_category_changed(data, &activity);
+            data.apply_category_color(id)
+        })
+    }
+
     /// Tries to insert the activity with given id with the given beginning.
     /// If None is given, the activity is removed from the schedule.
     ///
@@ -352,85 +558,152 @@ impl Data {
     /// # Panics
     ///
     /// Panics if the activity does not exist.
-    pub fn insert_activity(&mut self, id: ActivityId, insertion_time: Option<Time>) -> Result<()> {
-        if let Some(insertion_time) = insertion_time {
-            // We want to insert the activity
-            if let Some(possible_insertion_costs) = self.activity(id).insertion_costs() {
-                if possible_insertion_costs
-                    .iter()
-                    .any(|insertion_cost| insertion_cost.beginning == insertion_time)
-                {
-                    self.activities.insert_activity(id, Some(insertion_time));
-                    self.events()
-                        .borrow_mut()
-                        .emit_activity_inserted(self, &self.activity(id));
-                    self.queue_activity_participants(self.activity(id));
-                    Ok(())
-                } else {
-                    // We cannot insert the activity - find out why
-                    let activity = self.activity(id);
-                    if let Some(blocking_activity) =
-                        self.incompatible_activity_inserted_at_time(&activity, insertion_time)
+    pub fn insert_activity(
+        &mut self,
+        id: ActivityId,
+        insertion_time: Option<TimeOfDay>,
+    ) -> Result<()> {
+        self.record_as_single_step(|data| -> Result<()> {
+            if let Some(insertion_time) = insertion_time {
+                // We want to insert the activity
+                if let Some(possible_insertion_costs) = data.activity(id).insertion_costs() {
+                    if possible_insertion_costs
+                        .iter()
+                        .any(|insertion_cost| insertion_cost.beginning == insertion_time)
                     {
-                        Err(InvalidInsertion::would_overlap_with_activity(
-                            activity.name(),
-                            insertion_time,
-                            blocking_activity.name(),
-                        ))
+                        if let Some(simultaneous_activity) =
+                            data.simultaneous_activity_which_cannot_begin_at(id, insertion_time)
+                        {
+                            return Err(InvalidInsertion::simultaneous_activity_cannot_begin(
+                                data.activity(id).name(),
+                                insertion_time,
+                                simultaneous_activity.name(),
+                            ));
+                        }
+
+                        // Simultaneous and chained activities are moved along with the activity
+                        for (id, beginning) in
+                            data.beginnings_of_activities_moving_with(id, insertion_time)
+                        {
+                            data.activities.insert_activity(id, Some(beginning));
+                            data.events()
+                                .borrow_mut()
+                                .emit_activity_inserted(data, &data.activity(id));
+                            data.queue_activity_participants(data.activity(id));
+                        }
+                        Ok(())
                     } else {
-                        Err(
-                            InvalidInsertion::cannot_fit_or_would_block_other_activities(
+                        // We cannot insert the activity - find out why
+                        let activity = data.activity(id);
+                        if let Some(blocking_activity) =
+                            data.incompatible_activity_inserted_at_time(&activity, insertion_time)
+                        {
+                            Err(InvalidInsertion::would_overlap_with_activity(
                                 activity.name(),
                                 insertion_time,
-                            ),
-                        )
+                                blocking_activity.name(),
+                            ))
+                        } else if let Some(entity_name) = data
+                            .participant_without_breaks_if_inserted_at_time(
+                                &activity,
+                                insertion_time,
+                            )
+                        {
+                            Err(InvalidInsertion::would_not_respect_break_rules_of(
+                                activity.name(),
+                                insertion_time,
+                                entity_name,
+                            ))
+                        } else if !data.sessions_in_order_if_inserted_at(&activity, insertion_time)
+                        {
+                            if activity.number_of_occurrences() > 1 {
+                                Err(InvalidInsertion::would_not_space_occurrences(
+                                    activity.name(),
+                                    insertion_time,
+                                ))
+                            } else {
+                                Err(InvalidInsertion::would_not_keep_sessions_in_order(
+                                    activity.name(),
+                                    insertion_time,
+                                ))
+                            }
+                        } else if let Some(simultaneous_activity) =
+                            data.simultaneous_activity_blocked_at(&activity, insertion_time)
+                        {
+                            Err(InvalidInsertion::simultaneous_activity_cannot_begin(
+                                activity.name(),
+                                insertion_time,
+                                simultaneous_activity.name(),
+                            ))
+                        } else if !data
+                            .has_enough_participants_if_inserted_at(&activity, insertion_time)
+                        {
+                            Err(InvalidInsertion::would_not_have_enough_participants(
+                                activity.name(),
+                                insertion_time,
+                            ))
+                        } else {
+                            Err(
+                                InvalidInsertion::cannot_fit_or_would_block_other_activities(
+                                    activity.name(),
+                                    insertion_time,
+                                ),
+                            )
+                        }
                     }
+                } else {
+                    // Computation is not finished
+                    Err(InvalidInsertion::insertions_not_computed_yet(
+                        data.activity(id).name(),
+                    ))
                 }
             } else {
-                // Computation is not finished
-                Err(InvalidInsertion::insertions_not_computed_yet(
-                    self.activity(id).name(),
-                ))
-            }
-        } else {
-            // TODO split function
-            // Remove activity from schedule
-            self.activities.insert_activity(id, None);
-            self.events()
-                .borrow_mut()
-                .emit_activity_inserted(self, &self.activity(id));
+                // TODO split function
+                // Remove activity from schedule, along with simultaneous and chained activities
+                for id in data.ids_moving_with(id) {
+                    data.activities.insert_activity(id, None);
+                    data.events()
+                        .borrow_mut()
+                        .emit_activity_inserted(data, &data.activity(id));
 
-            // TODO remove condition and always queue (check done it queue)
-            // If the activity has no entities or no duration, it is useless to queue it.
-            // This also makes sure that its insertion costs are not invalidated here.
-            if self.activity(id).can_be_inserted() {
-                self.queue_activity_participants(self.activity(id));
+                    // TODO remove condition and always queue (check done it queue)
+                    // If the activity has no entities or no duration, it is useless to queue it.
+                    // This also makes sure that its insertion costs are not invalidated here.
+                    if data.activity(id).can_be_inserted() {
+                        data.queue_activity_participants(data.activity(id));
+                    }
+                }
+                Ok(())
             }
-            Ok(())
-        }
+        })
     }
 
-    /// If activities were removed from the schedule because their duration was increased, insert
-    /// them back into the schedule in the closest spot we find.
+    /// If activities were removed from the schedule because their duration was increased
+    /// or because they no longer fit in the work hours or the break rules, insert them back
+    /// into the schedule in the closest spot we find.
+    /// Activities which cannot be inserted in that spot are left out of the schedule.
     pub fn insert_activities_removed_because_duration_increased_in_closest_spot(&mut self) {
-        let activity_ids_and_old_beginnings = self
-            .activities
-            .get_activities_removed_because_duration_increased();
-
-        for (id, old_beginning) in activity_ids_and_old_beginnings {
-            if let Some(possible_insertion_times) = self.activity(id).insertion_costs() {
-                // Possible insertion times have been computed
-                if let Some(closest_spot) = self.activities.get_closest_spot_to_insert_activity(
-                    id,
-                    old_beginning,
-                    possible_insertion_times,
-                ) {
-                    // Activity can be inserted
-                    self.insert_activity(id, Some(closest_spot))
-                        .expect("Activity could not be inserted, but we computed that it could");
+        self.record_as_single_step(|data| {
+            let activity_ids_and_old_beginnings = data
+                .activities
+                .get_activities_removed_because_duration_increased();
+
+            for (id, old_beginning) in activity_ids_and_old_beginnings {
+                if let Some(possible_insertion_times) = data.activity(id).insertion_costs() {
+                    // Possible insertion times have been computed
+                    if let Some(closest_spot) = data.activities.get_closest_spot_to_insert_activity(
+                        id,
+                        old_beginning,
+                        possible_insertion_times,
+                    ) {
+                        // The insertion costs do not account for every constraint (simultaneous
+                        // activities, load limits), so the insertion may still fail. The
+                        // activity then stays out of the schedule and the others are inserted.
+                        let _ = data.insert_activity(id, Some(closest_spot));
+                    }
                 }
             }
-        }
+        })
     }
 
     /// Starts autoinsertion in a separate thread and returns a mpsc::receiver handle for the
@@ -438,8 +711,10 @@ impl Data {
     ///
     /// # Errors
     ///
-    /// Returns Err if the insertions have not been computed yet.
+    /// Returns Err if the insertions have not been computed yet
+    /// or if an entity exceeds its load limits.
     pub fn start_autoinsertion(&mut self) -> Result<AutoinsertionThreadHandle> {
+        self.check_load_limits_respected()?;
         // Poll insertion data
         self.activities.save_current_state_for_autoinsertion();
         let activities = activities_sorted_filtered_for_computation(&self.activities_not_sorted());
@@ -452,15 +727,47 @@ impl Data {
                 activity_not_computed_yet.name(),
             ))
         } else {
-            let (static_data, insertion_data) = activities_into_computation_data(&activities);
+            let (static_data, insertion_data) =
+                activities_into_computation_data(&self.activities_not_sorted());
 
             Ok(autoinsert(&static_data, &insertion_data))
         }
     }
 
     /// Applies the result of autoinsertion to the activities.
+    /// The result gives the beginnings of the activities in the order they had
+    /// when autoinsertion was started.
     pub fn apply_autoinsertion_result(&mut self, insertion_data: Vec<ActivityBeginningMinutes>) {
-        self.activities.overwrite_insertion_data(insertion_data);
+        let beginnings = self.activities.autoinsertion_beginnings(insertion_data);
+        self.apply_autoinsertion_beginnings(beginnings);
+    }
+
+    /// Inserts the activities with given ids at the given beginnings found by autoinsertion.
+    /// Chains are inserted as blocks: the other activities of each chain are put back
+    /// in place inside of their block.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the activities does not exist.
+    pub fn apply_autoinsertion_beginnings(&mut self, beginnings: Vec<(ActivityId, TimeOfDay)>) {
+        for (id, beginning) in beginnings {
+            self.activities.insert_activity(id, Some(beginning));
+        }
+
+        for activity in self.activities_not_sorted() {
+            if let (Some(chain), Some(insertion_interval)) =
+                (activity.chain(), activity.insertion_interval())
+            {
+                if chain.head_id() == activity.id() {
+                    for (id, beginning) in self.beginnings_of_activities_moving_with(
+                        activity.id(),
+                        insertion_interval.beginning(),
+                    ) {
+                        self.activities.insert_activity(id, Some(beginning));
+                    }
+                }
+            }
+        }
         self.events().borrow_mut().emit_autoinsertion_done(self);
     }
 
@@ -0,0 +1,116 @@
+use crate::errors::{invalid_chain::InvalidChain, invalid_split::InvalidSplit, Result};
+use crate::{ActivityId, ActivitySessions, Data, Duration};
+
+/// Operations on recurring activities.
+///
+/// A recurring activity takes place several times a day, spaced by a minimum duration.
+/// Each occurrence is a session of the activity which lasts as long as the activity.
+/// The occurrences are inserted separately but must take place in order.
+/// The first occurrence keeps the id of the activity.
+impl Data {
+    /// Sets the number of times the activity with given id takes place and the minimum
+    /// free time between the end of an occurrence and the beginning of the next one.
+    /// Occurrences are added or removed at the end. With a single occurrence,
+    /// the activity is not recurring anymore.
+    ///
+    /// The occurrences are removed from the schedule.
+    /// Returns the ids of the occurrences, in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the activity is split or chained, if the number of occurrences is zero,
+    /// if a participant does not have enough time left or would exceed its load limits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub fn set_activity_occurrences(
+        &mut self,
+        id: ActivityId,
+        number_of_occurrences: usize,
+        min_spacing: Duration,
+    ) -> Result<Vec<ActivityId>> {
+        self.record_as_single_step(|data| -> Result<Vec<ActivityId>> {
+            let activity = data.activity(data.activity(id).main_session_id());
+            if let Some(sessions) = activity.sessions() {
+                if !sessions.is_recurring() {
+                    return Err(InvalidSplit::already_split(activity.name()));
+                }
+            }
+            if activity.chain().is_some() {
+                return Err(InvalidChain::chained(activity.name()));
+            }
+            if number_of_occurrences == 0 {
+                return Err(InvalidSplit::too_few_occurrences(activity.name()));
+            }
+            data.check_has_enough_time_for_occurrences(activity.id(), number_of_occurrences)?;
+            data.check_load_limits_respected_with_occurrences(
+                activity.id(),
+                number_of_occurrences,
+            )?;
+
+            let mut occurrence_ids = data.session_ids_of(activity.id());
+            for &occurrence_id in &occurrence_ids {
+                if data.activity(occurrence_id).insertion_interval().is_some() {
+                    data.insert_activity(occurrence_id, None)?;
+                }
+            }
+
+            for &occurrence_id in occurrence_ids.iter().skip(number_of_occurrences) {
+                data.activities.unlink_simultaneous(occurrence_id);
+                data.activities.remove(occurrence_id);
+            }
+            occurrence_ids.truncate(number_of_occurrences);
+
+            while occurrence_ids.len() < number_of_occurrences {
+                let occurrence_id = data.activities.add(activity.name()).id();
+                for entity_id in activity.entity_ids() {
+                    data.activities
+                        .add_entity(occurrence_id, &data.entities.get_by_id(entity_id))?;
+                }
+                for group_id in activity.group_ids() {
+                    data.activities
+                        .add_group(occurrence_id, &data.groups.get_by_id(group_id))?;
+                }
+                for entity_id in activity.optional_entity_ids() {
+                    data.activities
+                        .add_optional_entity(occurrence_id, &data.entities.get_by_id(entity_id))?;
+                }
+                data.activities.set_participant_limits(
+                    occurrence_id,
+                    activity.min_participants(),
+                    activity.max_participants(),
+                );
+                data.activities.set_color(occurrence_id, activity.color());
+                data.activities
+                    .set_category(occurrence_id, activity.category());
+                data.activities
+                    .set_transition_buffer(occurrence_id, activity.transition_buffer());
+                data.activities
+                    .set_duration(occurrence_id, activity.duration());
+                if activity.has_flexible_duration() {
+                    data.activities
+                        .set_max_duration(occurrence_id, Some(activity.max_duration()));
+                }
+                data.activities
+                    .set_duration_objective(occurrence_id, activity.duration_objective());
+                occurrence_ids.push(occurrence_id);
+            }
+
+            if number_of_occurrences == 1 {
+                data.activities.unlink_sessions(&occurrence_ids);
+            } else {
+                data.activities.link_sessions(ActivitySessions::occurrences(
+                    occurrence_ids.clone(),
+                    min_spacing,
+                ));
+            }
+
+            data.queue_activity_participants(data.activity(activity.id()));
+            data.events()
+                .borrow_mut()
+                .emit_activity_sessions_changed(data, &data.activity(activity.id()));
+            Ok(occurrence_ids)
+        })
+    }
+}
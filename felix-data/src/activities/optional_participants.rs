@@ -0,0 +1,300 @@
+use crate::errors::{invalid_participants::InvalidParticipants, Result};
+use crate::{Activity, ActivityId, Data, EntityId, TimeInterval, TimeOfDay};
+
+/// Operations on optional participants and participant limits.
+///
+/// Optional participants attend the activity only if they are free : the activity fits in
+/// their work hours and they do not take part in an overlapping activity. They do not
+/// prevent the activity from being inserted and their free time is not affected.
+///
+/// The minimum and maximum number of participants include optional participants.
+/// An activity which needs more participants than its mandatory ones can only be inserted
+/// where enough optional participants are free.
+/// Optional participants beyond the maximum do not attend.
+impl Data {
+    /// Returns the names of the optional participants of the activity with given id,
+    /// sorted by name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity is not found.
+    #[must_use]
+    pub fn optional_entities_of_activity(&self, id: ActivityId) -> Vec<String> {
+        self.entities
+            .names_sorted(&self.activity(id).optional_entity_ids())
+    }
+
+    /// Returns the activities in which the given entity is an optional participant.
+    ///
+    /// # Errors
+    ///
+    /// Returns err if the entity name is empty after sanitization or if the entity is not found.
+    pub fn optional_activities_of<S>(&self, entity_name: S) -> Result<Vec<Activity>>
+    where
+        S: Into<String>,
+    {
+        let entity_id = self.entity(entity_name)?.id();
+        Ok(self
+            .activities_not_sorted()
+            .into_iter()
+            .filter(|activity| activity.optional_entity_ids().contains(&entity_id))
+            .collect())
+    }
+
+    /// Adds the entity with given name as an optional participant of the activity
+    /// with given id and of its other sessions.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found
+    /// or if the entity is already taking part in the activity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub fn add_optional_entity_to_activity<S>(
+        &mut self,
+        id: ActivityId,
+        entity_name: S,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let entity = self.entity(entity_name)?;
+        self.activities.add_optional_entity(id, &entity)?;
+        for session_id in self.session_ids_of(id) {
+            if session_id != id {
+                self.activities.add_optional_entity(session_id, &entity)?;
+            }
+        }
+
+        if self.activity(id).can_be_inserted() {
+            self.queue_activity_participants(self.activity(id));
+        }
+
+        self.events()
+            .borrow_mut()
+            .emit_entity_added_to_activity(self, &self.activity(id));
+        Ok(())
+    }
+
+    /// Removes the entity with given name from the optional participants of the activity
+    /// with given id and of its other sessions.
+    ///
+    /// If the activity needs more participants than its mandatory ones, its inserted sessions
+    /// are removed from the schedule and will be inserted again once their possible
+    /// beginnings are computed.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found
+    /// or if the entity is not an optional participant of the activity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub fn remove_optional_entity_from_activity<S>(
+        &mut self,
+        id: ActivityId,
+        entity_name: S,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        self.record_as_single_step(|data| {
+            let entity = data.entity(entity_name)?;
+            data.activities.remove_optional_entity(id, &entity)?;
+            for session_id in data.session_ids_of(id) {
+                if session_id != id {
+                    data.activities
+                        .remove_optional_entity(session_id, &entity)?;
+                }
+            }
+
+            if data.activity(id).min_participants().is_some() {
+                for session_id in data.session_ids_of(id) {
+                    data.remove_activity_to_insert_it_again(session_id);
+                }
+            }
+            if data.activity(id).can_be_inserted() {
+                data.queue_activity_participants(data.activity(id));
+            }
+
+            data.events()
+                .borrow_mut()
+                .emit_entity_removed_from_activity(data, &data.activity(id));
+            Ok(())
+        })
+    }
+
+    /// Sets the minimum and maximum number of participants of the activity with given id
+    /// and of its other sessions. None means there is no limit.
+    ///
+    /// If a minimum is set, the inserted sessions are removed from the schedule and will be
+    /// inserted again once their possible beginnings are computed.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the minimum is greater than the maximum,
+    /// if the activity has more mandatory participants than the maximum
+    /// or if the activity has fewer participants, optional ones included, than the minimum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub fn set_activity_participant_limits(
+        &mut self,
+        id: ActivityId,
+        min_participants: Option<usize>,
+        max_participants: Option<usize>,
+    ) -> Result<()> {
+        self.record_as_single_step(|data| -> Result<()> {
+            let activity = data.activity(id);
+            if let (Some(min_participants), Some(max_participants)) =
+                (min_participants, max_participants)
+            {
+                if min_participants > max_participants {
+                    return Err(InvalidParticipants::min_greater_than_max(activity.name()));
+                }
+            }
+            if let Some(max_participants) = max_participants {
+                if activity.entity_ids().len() > max_participants {
+                    return Err(InvalidParticipants::too_many(
+                        activity.name(),
+                        max_participants,
+                    ));
+                }
+            }
+            if let Some(min_participants) = min_participants {
+                if activity.entity_ids().len() + activity.optional_entity_ids().len()
+                    < min_participants
+                {
+                    return Err(InvalidParticipants::too_few(
+                        activity.name(),
+                        min_participants,
+                    ));
+                }
+            }
+
+            for session_id in data.session_ids_of(id) {
+                if min_participants.is_some() {
+                    data.remove_activity_to_insert_it_again(session_id);
+                }
+                data.activities.set_participant_limits(
+                    session_id,
+                    min_participants,
+                    max_participants,
+                );
+            }
+
+            if data.activity(id).can_be_inserted() {
+                data.queue_activity_participants(data.activity(id));
+            }
+
+            data.events()
+                .borrow_mut()
+                .emit_activity_participant_limits_changed(data, &data.activity(id));
+            Ok(())
+        })
+    }
+
+    /// Returns the names of the optional participants who attend the activity with given id,
+    /// sorted by name. If the activity is not inserted, nobody attends it.
+    ///
+    /// An optional participant who could attend several overlapping activities attends
+    /// the one which begins first.
+    /// If the activity would exceed its maximum number of participants, the first
+    /// optional participants in alphabetical order attend.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    #[must_use]
+    pub fn optional_entities_attending_activity(&self, id: ActivityId) -> Vec<String> {
+        let activity = self.activity(id);
+        let interval = if let Some(interval) = activity.insertion_interval() {
+            interval
+        } else {
+            return Vec::new();
+        };
+
+        let activities = self.activities_not_sorted();
+        let attending_ids = activity
+            .optional_entity_ids()
+            .into_iter()
+            .filter(|&entity_id| self.optional_entity_is_free_during(entity_id, interval, id))
+            .filter(|&entity_id| {
+                // Overlapping activities which begin first take the participant
+                !activities.iter().any(|other| {
+                    if let Some(other_interval) = other.insertion_interval() {
+                        other.id() != id
+                            && other.optional_entity_ids().contains(&entity_id)
+                            && other_interval.overlaps_with(&interval)
+                            && (other_interval.beginning(), other.id()) < (interval.beginning(), id)
+                            && self.optional_entity_is_free_during(
+                                entity_id,
+                                other_interval,
+                                other.id(),
+                            )
+                    } else {
+                        false
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut attending = self.entities.names_sorted(&attending_ids);
+        if let Some(max_participants) = activity.max_participants() {
+            attending.truncate(max_participants.saturating_sub(activity.entity_ids().len()));
+        }
+        attending
+    }
+
+    /// Returns true if the activity would have at least its minimum number of participants
+    /// if inserted at the given time.
+    pub(super) fn has_enough_participants_if_inserted_at(
+        &self,
+        activity: &Activity,
+        time: TimeOfDay,
+    ) -> bool {
+        let min_participants = if let Some(min_participants) = activity.min_participants() {
+            min_participants
+        } else {
+            return true;
+        };
+
+        let interval = TimeInterval::new(time, time + activity.duration_if_inserted_at(time));
+        let free_optional_participants = activity
+            .optional_entity_ids()
+            .into_iter()
+            .filter(|&entity_id| {
+                self.optional_entity_is_free_during(entity_id, interval, activity.id())
+            })
+            .count();
+        activity.entity_ids().len() + free_optional_participants >= min_participants
+    }
+
+    /// Returns true if the entity with given id works during the given interval and does not
+    /// take part in an inserted activity which overlaps with it, apart from the activity
+    /// with given id.
+    fn optional_entity_is_free_during(
+        &self,
+        entity_id: EntityId,
+        interval: TimeInterval,
+        id: ActivityId,
+    ) -> bool {
+        let entity_name = self.entities.get_by_id(entity_id).name();
+        self.work_hours_of(entity_name)
+            .expect("Optional participant does not exist")
+            .iter()
+            .any(|work_interval| work_interval.contains_interval(interval))
+            && !self.activities_not_sorted().iter().any(|other| {
+                other.id() != id
+                    && other.entity_ids().contains(&entity_id)
+                    && other
+                        .insertion_interval()
+                        .iter()
+                        .any(|other_interval| other_interval.overlaps_with(&interval))
+            })
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Time;
+use crate::Duration;
 use crate::{Activity, Data, WorkHoursAndActivityDurationsSorted};
 
 use std::collections::{HashMap, HashSet};
@@ -13,7 +13,7 @@ impl Data {
     }
 
     /// Queues up every entity to compute the possible beginnings of their entities.
-    fn queue_every_activity_for_beginning_computation(&mut self) {
+    pub(crate) fn queue_every_activity_for_beginning_computation(&mut self) {
         let entity_names = self
             .entities_sorted()
             .iter()
@@ -25,20 +25,13 @@ impl Data {
 
     /// Starts the computation of the possible beginnings of activities of entities whose work
     /// hours were modified.
+    /// Entities with custom work hours, with a work hour template or with groups which have
+    /// work hours are not concerned.
     pub(crate) fn queue_entities_on_global_work_hour_change(&mut self) {
         let entities_to_queue = self
             .entities_sorted()
             .iter()
-            .filter(|entity| {
-                self.custom_work_hours_of(entity.name())
-                    .unwrap_or_else(|_| {
-                        panic!(
-                            "The custom work hours of {} are not registered",
-                            entity.name()
-                        )
-                    })
-                    .is_empty()
-            })
+            .filter(|entity| self.follows_global_work_hours(&entity.name()))
             .map(|entity| entity.name())
             .collect::<Vec<_>>();
         self.queue_entities(entities_to_queue);
@@ -47,15 +40,20 @@ impl Data {
     /// Starts the computation of the possible beginnings of the given activity.
     pub(crate) fn queue_activity_participants(&mut self, activity: Activity) {
         // Queue every entity of the activity
-        self.queue_entities(activity.entities_sorted());
+        self.queue_entities(self.entities.names_sorted(&activity.entity_ids()));
     }
 
     /// Starts the computation of the possible beginnings of the activities of the given entities.
+    /// If a batch is being applied, the computation starts when the batch is committed.
     ///
     /// # Panics
     ///
     /// Panics if one of the entities does not exist.
     pub(crate) fn queue_entities(&mut self, entities: Vec<String>) {
+        if self.defer_queue_entities(&entities) {
+            return;
+        }
+
         // For each activity
         // Gather all of its participants
         let activities_to_update = self.activities_of_entities_with_non_empty_duration(&entities);
@@ -79,7 +77,8 @@ impl Data {
         );
     }
 
-    /// Returns all activities in which at least one entity in the given slice participates.
+    /// Returns all activities in which at least one entity in the given slice participates,
+    /// optional participation included.
     /// Does not return activities with empty duration (0 minutes, 0 hours).
     fn activities_of_entities_with_non_empty_duration(
         &self,
@@ -90,8 +89,13 @@ impl Data {
             .flat_map(|entity| {
                 self.activities_of(entity)
                     .expect("Entity name is empty - this is a bug")
+                    .into_iter()
+                    .chain(
+                        self.optional_activities_of(entity)
+                            .expect("Entity name is empty - this is a bug"),
+                    )
             })
-            .filter(|activity| activity.duration() > Time::new(0, 0))
+            .filter(|activity| activity.duration() > Duration::new(0, 0))
             .collect()
     }
 
@@ -103,7 +107,7 @@ impl Data {
     ) -> HashMap<String, WorkHoursAndActivityDurationsSorted> {
         let entities = activities
             .iter()
-            .flat_map(|activity| activity.entities_sorted())
+            .flat_map(|activity| self.entities.names_sorted(&activity.entity_ids()))
             .collect::<HashSet<_>>();
 
         entities
@@ -119,24 +123,64 @@ impl Data {
 
     /// Given a list of activities and the schedules of all participants
     /// (all activities included),
-    /// fills each activity with the schedules of their participants.
+    /// fills each activity with the schedules, time preferences and break rules of their
+    /// participants, and with the work hours of their optional participants.
     fn update_schedules_of_participants_of_activities(
         &mut self,
         activities: &HashSet<Activity>,
         schedules: &HashMap<String, WorkHoursAndActivityDurationsSorted>,
     ) {
         for activity in activities {
-            let schedules_of_participants_of_this_activity = activity
-                .entities_sorted()
+            let participants = activity
+                .entity_ids()
+                .into_iter()
+                .map(|entity_id| self.entities.get_by_id(entity_id))
+                .collect::<Vec<_>>();
+            let schedules_of_participants_of_this_activity = participants
                 .iter()
-                .map(|entity| schedules[entity].clone())
+                .map(|entity| schedules[&entity.name()].clone())
                 .collect::<Vec<_>>();
+            let preferences_of_participants_of_this_activity = participants
+                .iter()
+                .map(|entity| self.work_hours.time_preferences_of(entity.id()))
+                .collect::<Vec<_>>();
+            let break_rules_of_participants_of_this_activity = participants
+                .iter()
+                .map(|entity| (entity.id(), self.work_hours.break_rules_of(entity.id())))
+                .filter(|(_, break_rules)| !break_rules.is_empty())
+                .collect::<HashMap<_, _>>();
+            let work_hours_of_optional_participants_of_this_activity = activity
+                .optional_entity_ids()
+                .into_iter()
+                .map(|entity_id| {
+                    let entity = self.entities.get_by_id(entity_id);
+                    let work_hours = self
+                        .work_hours_of(entity.name())
+                        .expect("Entity does not exist");
+                    (entity_id, work_hours)
+                })
+                .collect::<HashMap<_, _>>();
 
             self.activities
                 .update_schedules_of_participants_of_activity(
                     activity.id(),
                     schedules_of_participants_of_this_activity,
                 );
+            self.activities
+                .update_preferences_of_participants_of_activity(
+                    activity.id(),
+                    preferences_of_participants_of_this_activity,
+                );
+            self.activities
+                .update_break_rules_of_participants_of_activity(
+                    activity.id(),
+                    break_rules_of_participants_of_this_activity,
+                );
+            self.activities
+                .update_work_hours_of_optional_participants_of_activity(
+                    activity.id(),
+                    work_hours_of_optional_participants_of_this_activity,
+                );
         }
     }
 }
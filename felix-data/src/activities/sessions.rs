@@ -0,0 +1,166 @@
+use crate::errors::{invalid_chain::InvalidChain, invalid_split::InvalidSplit, Result};
+use crate::{ActivityId, ActivitySessions, Data, Duration, SessionRules, MIN_TIME_DISCRETIZATION};
+
+/// Operations on activities split into several sessions.
+///
+/// Each session is an activity of its own with the same name, participants and color.
+/// The first session keeps the id of the activity which was split.
+impl Data {
+    /// Splits the activity with given id into the given number of sessions.
+    /// The duration of the activity is shared as evenly as possible between the sessions.
+    /// Sessions have no duration range.
+    ///
+    /// If the activity is inserted, it is removed from the schedule.
+    /// Returns the ids of the sessions, in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the activity is already split, recurring or chained, if there are less than
+    /// two sessions or if the sessions would be shorter than the minimum session duration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub fn split_activity(
+        &mut self,
+        id: ActivityId,
+        number_of_sessions: usize,
+        rules: SessionRules,
+    ) -> Result<Vec<ActivityId>> {
+        self.record_as_single_step(|data| -> Result<Vec<ActivityId>> {
+            let activity = data.activity(id);
+            if activity.number_of_occurrences() > 1 {
+                return Err(InvalidSplit::recurring(activity.name()));
+            }
+            if activity.sessions().is_some() {
+                return Err(InvalidSplit::already_split(activity.name()));
+            }
+            if activity.chain().is_some() {
+                return Err(InvalidChain::chained(activity.name()));
+            }
+            if number_of_sessions < 2 {
+                return Err(InvalidSplit::too_few_sessions(activity.name()));
+            }
+
+            // Share the duration in units of MIN_TIME_DISCRETIZATION.
+            // The first sessions take the remaining units.
+            let min_session_duration =
+                std::cmp::max(rules.min_session_duration, MIN_TIME_DISCRETIZATION);
+            let units = (activity.duration().total_minutes()
+                / MIN_TIME_DISCRETIZATION.total_minutes()) as usize;
+            let shortest_session_duration = Duration::from_total_minutes(
+                (units / number_of_sessions) as u16 * MIN_TIME_DISCRETIZATION.total_minutes(),
+            );
+            if shortest_session_duration < min_session_duration {
+                return Err(InvalidSplit::sessions_too_short(
+                    activity.name(),
+                    min_session_duration,
+                ));
+            }
+
+            let session_durations = (0..number_of_sessions)
+                .map(|index| {
+                    if index < units % number_of_sessions {
+                        shortest_session_duration + MIN_TIME_DISCRETIZATION
+                    } else {
+                        shortest_session_duration
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            if activity.insertion_interval().is_some() {
+                data.insert_activity(id, None)?;
+            }
+            data.activities.set_duration(id, session_durations[0]);
+            // Sessions last exactly their duration
+            data.activities.set_max_duration(id, None);
+
+            let mut session_ids = vec![id];
+            for &duration in session_durations.iter().skip(1) {
+                let session_id = data.activities.add(activity.name()).id();
+                for entity_id in activity.entity_ids() {
+                    data.activities
+                        .add_entity(session_id, &data.entities.get_by_id(entity_id))?;
+                }
+                for group_id in activity.group_ids() {
+                    data.activities
+                        .add_group(session_id, &data.groups.get_by_id(group_id))?;
+                }
+                for entity_id in activity.optional_entity_ids() {
+                    data.activities
+                        .add_optional_entity(session_id, &data.entities.get_by_id(entity_id))?;
+                }
+                data.activities.set_participant_limits(
+                    session_id,
+                    activity.min_participants(),
+                    activity.max_participants(),
+                );
+                data.activities.set_color(session_id, activity.color());
+                data.activities
+                    .set_transition_buffer(session_id, activity.transition_buffer());
+                data.activities.set_duration(session_id, duration);
+                session_ids.push(session_id);
+            }
+
+            data.activities
+                .link_sessions(ActivitySessions::new(session_ids.clone(), rules));
+
+            data.queue_activity_participants(data.activity(id));
+            data.events()
+                .borrow_mut()
+                .emit_activity_sessions_changed(data, &data.activity(id));
+            Ok(session_ids)
+        })
+    }
+
+    /// Merges the sessions of the activity with given id back into a single activity,
+    /// which keeps the id of the main session and the total duration of the sessions.
+    ///
+    /// The activity is removed from the schedule.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the activity is not split or if it is recurring.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub fn merge_activity_sessions(&mut self, id: ActivityId) -> Result<()> {
+        self.record_as_single_step(|data| -> Result<()> {
+            let activity = data.activity(id);
+            let sessions = activity
+                .sessions()
+                .ok_or_else(|| InvalidSplit::not_split(activity.name()))?;
+            if sessions.is_recurring() {
+                return Err(InvalidSplit::recurring(activity.name()));
+            }
+
+            let main_session_id = sessions.main_session_id();
+            let total_duration: Duration = sessions
+                .session_ids()
+                .iter()
+                .map(|&session_id| data.activity(session_id).duration())
+                .sum();
+
+            if data
+                .activity(main_session_id)
+                .insertion_interval()
+                .is_some()
+            {
+                data.insert_activity(main_session_id, None)?;
+            }
+            data.activities.unlink_sessions(&[main_session_id]);
+            for &session_id in sessions.session_ids().iter().skip(1) {
+                data.activities.remove(session_id);
+            }
+            data.activities
+                .set_duration(main_session_id, total_duration);
+
+            data.queue_activity_participants(data.activity(main_session_id));
+            data.events()
+                .borrow_mut()
+                .emit_activity_sessions_changed(data, &data.activity(main_session_id));
+            Ok(())
+        })
+    }
+}
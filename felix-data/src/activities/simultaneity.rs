@@ -0,0 +1,125 @@
+use crate::errors::{
+    invalid_chain::InvalidChain, invalid_simultaneity::InvalidSimultaneity, Result,
+};
+use crate::{ActivityId, Data};
+
+/// Operations on activities which must begin at the same time.
+///
+/// Simultaneous activities are parallel tracks: they cannot share participants
+/// and they are always inserted, moved and removed from the schedule together.
+impl Data {
+    /// Links the activities with given ids so that they always begin at the same time.
+    ///
+    /// The activities which are inserted are removed from the schedule.
+    /// Ids given several times are only linked once.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if there are less than two different activities,
+    /// if one of them already begins at the same time as other activities or is chained,
+    /// or if an entity takes part in several of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the activities with given IDs does not exist.
+    pub fn link_simultaneous_activities(&mut self, ids: &[ActivityId]) -> Result<()> {
+        self.record_as_single_step(|data| -> Result<()> {
+            // An activity cannot begin at the same time as itself
+            let ids = ids.iter().fold(Vec::new(), |mut unique_ids, &id| {
+                if !unique_ids.contains(&id) {
+                    unique_ids.push(id);
+                }
+                unique_ids
+            });
+            let activities = ids.iter().map(|&id| data.activity(id)).collect::<Vec<_>>();
+            if activities.len() < 2 {
+                return Err(InvalidSimultaneity::too_few_activities(
+                    activities
+                        .first()
+                        .map(|activity| activity.name())
+                        .unwrap_or_default(),
+                ));
+            }
+
+            for (index, activity) in activities.iter().enumerate() {
+                if !activity.simultaneous_activity_ids().is_empty() {
+                    return Err(InvalidSimultaneity::already_simultaneous(activity.name()));
+                }
+                if activity.chain().is_some() {
+                    return Err(InvalidChain::chained(activity.name()));
+                }
+
+                // Simultaneous activities cannot share participants
+                let entities = data.entities_of_activity(activity.id());
+                for other_activity in activities.iter().skip(index + 1) {
+                    if let Some(entity_name) = data
+                        .entities_of_activity(other_activity.id())
+                        .into_iter()
+                        .find(|entity_name| entities.contains(entity_name))
+                    {
+                        return Err(InvalidSimultaneity::shared_participant(
+                            other_activity.name(),
+                            entity_name,
+                        ));
+                    }
+                }
+            }
+
+            for activity in &activities {
+                if activity.insertion_interval().is_some() {
+                    data.activities.insert_activity(activity.id(), None);
+                    data.events()
+                        .borrow_mut()
+                        .emit_activity_inserted(data, &data.activity(activity.id()));
+                }
+            }
+
+            data.activities.link_simultaneous(&ids);
+            data.queue_simultaneous_activities_and_emit_changed(&ids);
+            Ok(())
+        })
+    }
+
+    /// Unlinks the activity with given id from the activities which begin at the same time.
+    /// The activity stays in the schedule if it is inserted.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the activity does not begin at the same time as other activities.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub fn unlink_simultaneous_activity(&mut self, id: ActivityId) -> Result<()> {
+        let activity = self.activity(id);
+        let mut ids = activity.simultaneous_activity_ids();
+        if ids.is_empty() {
+            return Err(InvalidSimultaneity::not_simultaneous(activity.name()));
+        }
+
+        self.activities.unlink_simultaneous(id);
+        ids.push(id);
+        self.queue_simultaneous_activities_and_emit_changed(&ids);
+        Ok(())
+    }
+
+    /// Returns the id of the given activity followed by the ids of the activities
+    /// which begin at the same time.
+    #[must_use]
+    pub(super) fn ids_beginning_with(&self, id: ActivityId) -> Vec<ActivityId> {
+        std::iter::once(id)
+            .chain(self.activity(id).simultaneous_activity_ids())
+            .collect()
+    }
+
+    fn queue_simultaneous_activities_and_emit_changed(&mut self, ids: &[ActivityId]) {
+        for &id in ids {
+            self.queue_activity_participants(self.activity(id));
+        }
+        for &id in ids {
+            self.events()
+                .borrow_mut()
+                .emit_simultaneous_activities_changed(self, &self.activity(id));
+        }
+    }
+}
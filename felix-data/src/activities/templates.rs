@@ -0,0 +1,241 @@
+use crate::errors::Result;
+use crate::{clean_string, Activity, ActivityId, ActivityTemplate, Data};
+
+use std::collections::BTreeSet;
+
+/// Operations on activity templates and duplication of activities.
+///
+/// A template stores the metadata of an activity (name, duration, color, category, details,
+/// entities, optional participants and groups) so that similar activities can be created
+/// at once. Created activities are numbered after the name of the activity (e.g. "Lunch 2")
+/// and are not inserted. Sessions, simultaneity and chains are not copied.
+impl Data {
+    /// Returns the names of the activity templates, sorted.
+    #[must_use]
+    pub fn activity_templates(&self) -> Vec<String> {
+        self.activities.template_names()
+    }
+
+    /// Returns the activity template with the formatted given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the formatted name is empty or if the template does not exist.
+    pub fn activity_template<S>(&self, name: S) -> Result<ActivityTemplate>
+    where
+        S: Into<String>,
+    {
+        self.activities.template(&clean_string(name)?)
+    }
+
+    /// Adds an activity template with the formatted given name, built from the activity
+    /// with given id.
+    ///
+    /// Returns the formatted name of the template.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the formatted name is empty or if it is taken by another template.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub fn add_activity_template<S>(&mut self, name: S, id: ActivityId) -> Result<String>
+    where
+        S: Into<String>,
+    {
+        let name = clean_string(name)?;
+        let template = ActivityTemplate::from(&self.activity(id));
+        self.activities.add_template(name.clone(), template)?;
+        self.events()
+            .borrow_mut()
+            .emit_activity_templates_changed(self);
+        Ok(name)
+    }
+
+    /// Removes the activity template with the formatted given name.
+    /// Activities created from the template are not affected.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the formatted name is empty or if the template does not exist.
+    pub fn remove_activity_template<S>(&mut self, name: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        self.activities.remove_template(&clean_string(name)?)?;
+        self.events()
+            .borrow_mut()
+            .emit_activity_templates_changed(self);
+        Ok(())
+    }
+
+    /// Creates the given number of activities from the template with the formatted given name.
+    /// Returns the created activities.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the template does not exist,
+    /// if a participant would not have enough time
+    /// or would exceed its load limits. No activity is created in this case.
+    pub fn add_activities_from_template<S>(
+        &mut self,
+        name: S,
+        count: usize,
+    ) -> Result<Vec<Activity>>
+    where
+        S: Into<String>,
+    {
+        let template = self.activity_template(name)?;
+        let names = self.numbered_activity_names(&template.activity_name(), 1, count);
+        self.add_activities_like(&template, names)
+    }
+
+    /// Creates the given number of copies of the activity with given id.
+    /// Returns the copies.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if a participant would not have enough time
+    /// or would exceed its load limits. No copy is created in this case.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub fn duplicate_activity(&mut self, id: ActivityId, count: usize) -> Result<Vec<Activity>> {
+        let template = ActivityTemplate::from(&self.activity(id));
+        let names = self.numbered_activity_names(&template.activity_name(), 2, count);
+        self.add_activities_like(&template, names)
+    }
+
+    /// Returns the given number of names made of the given name followed by a number,
+    /// beginning at the given number. Names taken by activities are skipped.
+    fn numbered_activity_names(&self, name: &str, first: usize, count: usize) -> Vec<String> {
+        let taken_names = self
+            .activities_not_sorted()
+            .iter()
+            .map(Activity::name)
+            .collect::<BTreeSet<_>>();
+        (first..)
+            .map(|number| format!("{} {}", name, number))
+            .filter(|numbered_name| !taken_names.contains(numbered_name))
+            .take(count)
+            .collect()
+    }
+
+    /// Creates activities with the given names and the metadata of the given template.
+    /// Emits a single event for all activities.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if a participant would not have enough time
+    /// or would exceed its load limits. No activity is created in this case.
+    fn add_activities_like(
+        &mut self,
+        template: &ActivityTemplate,
+        names: Vec<String>,
+    ) -> Result<Vec<Activity>> {
+        if names.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let ids = names
+            .into_iter()
+            .map(|name| {
+                let id = self.activities.add(name).id();
+                self.activities.set_duration(id, template.duration());
+                self.activities.set_max_duration(
+                    id,
+                    Some(template.max_duration()).filter(|&max| max > template.duration()),
+                );
+                self.activities
+                    .set_duration_objective(id, template.duration_objective());
+                self.activities
+                    .set_transition_buffer(id, template.transition_buffer());
+                self.activities.set_color(id, template.color());
+                self.activities.set_category(id, template.category());
+                self.activities.set_details(id, template.details().clone());
+                self.activities.set_description(id, template.description());
+                self.activities.set_location(id, template.location());
+                self.activities.set_participant_limits(
+                    id,
+                    template.min_participants(),
+                    template.max_participants(),
+                );
+                id
+            })
+            .collect::<Vec<_>>();
+
+        let participants = match self.add_participants_of_template(template, &ids) {
+            Ok(participants) => participants,
+            Err(error) => {
+                for &id in &ids {
+                    self.activities.remove(id);
+                }
+                return Err(error);
+            }
+        };
+        self.queue_entities(participants);
+
+        let activities = ids
+            .into_iter()
+            .map(|id| self.activity(id))
+            .collect::<Vec<_>>();
+        self.events()
+            .borrow_mut()
+            .emit_activities_added(self, &activities);
+        Ok(activities)
+    }
+
+    /// Adds the entities and groups of the template, and the members of these groups,
+    /// to the activities with given ids.
+    /// Returns the names of the participants.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if a participant would not have enough time
+    /// or would exceed its load limits.
+    fn add_participants_of_template(
+        &mut self,
+        template: &ActivityTemplate,
+        ids: &[ActivityId],
+    ) -> Result<Vec<String>> {
+        let groups = template
+            .group_ids()
+            .into_iter()
+            .map(|group_id| self.groups.get_by_id(group_id))
+            .collect::<Vec<_>>();
+        let entity_ids = template
+            .entity_ids()
+            .into_iter()
+            .chain(
+                groups
+                    .iter()
+                    .flat_map(|group| self.groups.member_ids(group.id())),
+            )
+            .collect::<BTreeSet<_>>();
+        let entities = entity_ids
+            .iter()
+            .map(|&entity_id| self.entities.get_by_id(entity_id))
+            .collect::<Vec<_>>();
+
+        for &id in ids {
+            for entity in &entities {
+                self.check_has_enough_time_for_activity(id, &entity.name())?;
+                self.check_load_limits_respected_with_activity(id, &entity.name())?;
+                self.activities.add_entity(id, entity)?;
+            }
+            for group in &groups {
+                self.activities.add_group(id, group)?;
+            }
+            for entity_id in template.optional_entity_ids() {
+                // Optional participants who joined through a group are mandatory
+                if !entity_ids.contains(&entity_id) {
+                    self.activities
+                        .add_optional_entity(id, &self.entities.get_by_id(entity_id))?;
+                }
+            }
+        }
+        Ok(self.entities.names_sorted(&entity_ids))
+    }
+}
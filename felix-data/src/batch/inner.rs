@@ -0,0 +1,12 @@
+//! State of a batch of changes being applied.
+
+use crate::EntityId;
+
+use std::collections::BTreeSet;
+
+/// Changes of data being applied at once.
+#[derive(Debug, Default)]
+pub(crate) struct Batch {
+    /// Entities whose activities need new possible beginnings once the batch is committed.
+    pub entities_to_queue: BTreeSet<EntityId>,
+}
@@ -0,0 +1,104 @@
+pub(crate) mod inner;
+
+use crate::errors::Result;
+use crate::history::inner::DataState;
+use crate::Data;
+use inner::Batch;
+
+/// Operations on batches of changes.
+///
+/// While a batch is applied, the possible beginnings of activities are not computed and
+/// the callbacks of events are not called. Both happen once, when the batch is committed.
+impl Data {
+    /// Applies the given changes at once.
+    ///
+    /// If the changes succeed, the possible beginnings of the modified activities are computed
+    /// once and a `batch_applied` event is emitted with the events of the changes.
+    /// If any change fails, every change of the batch is rolled back and no event is emitted.
+    ///
+    /// A batch applied inside another batch is committed with it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first change which fails.
+    pub fn batch<T>(&mut self, changes: impl FnOnce(&mut Data) -> Result<T>) -> Result<T> {
+        if self.batch.is_some() {
+            // The outermost batch commits the changes
+            return self.apply_or_roll_back(changes);
+        }
+
+        self.batch = Some(Batch::default());
+        self.events.borrow_mut().start_deferring();
+        let result = self.apply_or_roll_back(changes);
+        let batch = self
+            .batch
+            .take()
+            .expect("The batch was removed while being applied");
+        let events = self.events.borrow_mut().stop_deferring();
+
+        let entities_to_queue = self
+            .entities_sorted()
+            .iter()
+            .filter(|entity| batch.entities_to_queue.contains(&entity.id()))
+            .map(|entity| entity.name())
+            .collect::<Vec<_>>();
+        self.queue_entities(entities_to_queue);
+
+        if result.is_ok() && !events.is_empty() {
+            self.events().borrow_mut().emit_batch_applied(self, &events);
+        }
+        result
+    }
+
+    /// Applies the given changes as a single step of the history.
+    /// If they fail, every change they made is rolled back.
+    pub(crate) fn apply_or_roll_back<T>(
+        &mut self,
+        changes: impl FnOnce(&mut Data) -> Result<T>,
+    ) -> Result<T> {
+        let state_before_changes = self.state();
+        self.record_as_single_step(|data| {
+            let result = changes(data);
+            if result.is_err() && data.state() != state_before_changes {
+                data.roll_back(state_before_changes);
+            }
+            result
+        })
+    }
+
+    /// Replaces the current state of data with the given one after changes failed.
+    fn roll_back(&mut self, state: DataState) {
+        self.set_state(state);
+        // Computations running during the changes updated the discarded activities
+        self.queue_every_activity_for_beginning_computation();
+        if self.batch.is_none() {
+            // Callbacks were called for the discarded changes
+            self.events().borrow_mut().emit_data_restored(self);
+        }
+    }
+
+    /// Keeps the given entities to compute the possible beginnings of their activities
+    /// once the batch is committed.
+    /// Until then, the possible beginnings of activities are unknown, so that no activity is
+    /// inserted according to beginnings computed before the changes.
+    /// Returns false if no batch is being applied.
+    pub(crate) fn defer_queue_entities(&mut self, entities: &[String]) -> bool {
+        if self.batch.is_none() {
+            return false;
+        }
+
+        let entity_ids = entities
+            .iter()
+            .map(|entity_name| {
+                self.entity(entity_name)
+                    .expect("Queueing an entity which does not exist")
+                    .id()
+            })
+            .collect::<Vec<_>>();
+        if let Some(batch) = &mut self.batch {
+            batch.entities_to_queue.extend(entity_ids);
+        }
+        self.activities.invalidate_possible_activity_beginnings();
+        true
+    }
+}
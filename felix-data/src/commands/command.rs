@@ -0,0 +1,381 @@
+//! Every change which can be applied to data.
+
+use crate::{
+    ActivityId, Details, Duration, DurationObjective, LoadLimits, MandatoryBreak, Rgba,
+    SessionRules, TimeInterval, TimeOfDay,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// A change to apply to data with `Data::apply`.
+///
+/// Each command calls the `Data` method of the same name with the same parameters,
+/// e.g. `Command::AddEntityToGroup { group_name, entity_name }` calls
+/// `data.add_entity_to_group(group_name, entity_name)`.
+///
+/// Commands can be serialized to be saved, replayed or sent to another process.
+///
+/// The result of autoinsertion is applied with `Command::ApplyAutoinsertionBeginnings`, which
+/// gives the beginning of each activity by id : `apply_autoinsertion_result` refers to the
+/// activities by their position when autoinsertion was started, which cannot be replayed.
+/// Starting the computation module or autoinsertion and forgetting the activities to insert
+/// back do not modify data and have no command.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Command {
+    // Entities
+    AddEntity {
+        name: String,
+    },
+    RemoveEntity {
+        name: String,
+    },
+    SetEntityName {
+        old_name: String,
+        new_name: String,
+    },
+    MergeEntities {
+        kept_name: String,
+        removed_name: String,
+    },
+    SetEntityDetails {
+        entity_name: String,
+        details: Details,
+    },
+    SetEntityLoadLimits {
+        entity_name: String,
+        load_limits: LoadLimits,
+    },
+
+    // Groups
+    AddGroup {
+        name: String,
+    },
+    RemoveGroup {
+        name: String,
+    },
+    SetGroupName {
+        old_name: String,
+        new_name: String,
+    },
+    AddEntityToGroup {
+        group_name: String,
+        entity_name: String,
+    },
+    RemoveEntityFromGroup {
+        group_name: String,
+        entity_name: String,
+    },
+    AddGroupToGroup {
+        group_name: String,
+        subgroup_name: String,
+    },
+    RemoveGroupFromGroup {
+        group_name: String,
+        subgroup_name: String,
+    },
+    SetGroupDetails {
+        group_name: String,
+        details: Details,
+    },
+    SetGroupLoadLimits {
+        group_name: String,
+        load_limits: LoadLimits,
+    },
+
+    // Activities
+    AddActivity {
+        name: String,
+    },
+    RemoveActivity {
+        id: ActivityId,
+    },
+    SetActivityName {
+        id: ActivityId,
+        name: String,
+    },
+    SetActivityDuration {
+        id: ActivityId,
+        duration: Duration,
+    },
+    SetActivityMaxDuration {
+        id: ActivityId,
+        max_duration: Option<Duration>,
+    },
+    SetActivityDurationObjective {
+        id: ActivityId,
+        duration_objective: DurationObjective,
+    },
+    SetActivityTransitionBuffer {
+        id: ActivityId,
+        transition_buffer: Duration,
+    },
+    SetActivityColor {
+        id: ActivityId,
+        color: Rgba,
+    },
+    SetActivityCategory {
+        id: ActivityId,
+        category: Option<String>,
+    },
+    SetCategoryColor {
+        category: String,
+        color: Option<Rgba>,
+    },
+    SetActivityDetails {
+        id: ActivityId,
+        details: Details,
+    },
+    SetActivityDescription {
+        id: ActivityId,
+        description: String,
+    },
+    SetActivityLocation {
+        id: ActivityId,
+        location: String,
+    },
+    AddEntityToActivity {
+        id: ActivityId,
+        entity_name: String,
+    },
+    RemoveEntityFromActivity {
+        id: ActivityId,
+        entity_name: String,
+    },
+    AddOptionalEntityToActivity {
+        id: ActivityId,
+        entity_name: String,
+    },
+    RemoveOptionalEntityFromActivity {
+        id: ActivityId,
+        entity_name: String,
+    },
+    SetActivityParticipantLimits {
+        id: ActivityId,
+        min_participants: Option<usize>,
+        max_participants: Option<usize>,
+    },
+    AddGroupToActivity {
+        id: ActivityId,
+        group_name: String,
+    },
+    RemoveGroupFromActivity {
+        id: ActivityId,
+        group_name: String,
+    },
+    InsertActivity {
+        id: ActivityId,
+        insertion_time: Option<TimeOfDay>,
+    },
+    InsertActivitiesRemovedBecauseDurationIncreasedInClosestSpot,
+    ApplyAutoinsertionBeginnings {
+        beginnings: Vec<(ActivityId, TimeOfDay)>,
+    },
+    SplitActivity {
+        id: ActivityId,
+        number_of_sessions: usize,
+        rules: SessionRules,
+    },
+    MergeActivitySessions {
+        id: ActivityId,
+    },
+    SetActivityOccurrences {
+        id: ActivityId,
+        number_of_occurrences: usize,
+        min_spacing: Duration,
+    },
+    LinkSimultaneousActivities {
+        ids: Vec<ActivityId>,
+    },
+    UnlinkSimultaneousActivity {
+        id: ActivityId,
+    },
+    ChainActivities {
+        previous_id: ActivityId,
+        next_id: ActivityId,
+        offset: Duration,
+    },
+    UnchainActivity {
+        id: ActivityId,
+    },
+    AddActivityTemplate {
+        name: String,
+        id: ActivityId,
+    },
+    RemoveActivityTemplate {
+        name: String,
+    },
+    AddActivitiesFromTemplate {
+        name: String,
+        count: usize,
+    },
+    DuplicateActivity {
+        id: ActivityId,
+        count: usize,
+    },
+
+    // Work hours
+    AddWorkInterval {
+        interval: TimeInterval,
+    },
+    RemoveWorkInterval {
+        interval: TimeInterval,
+    },
+    UpdateWorkInterval {
+        old_interval: TimeInterval,
+        new_interval: TimeInterval,
+    },
+    AddCustomWorkIntervalFor {
+        entity_name: String,
+        interval: TimeInterval,
+    },
+    RemoveCustomWorkIntervalFor {
+        entity_name: String,
+        interval: TimeInterval,
+    },
+    UpdateCustomWorkIntervalFor {
+        entity_name: String,
+        old_interval: TimeInterval,
+        new_interval: TimeInterval,
+    },
+    AddGroupWorkIntervalFor {
+        group_name: String,
+        interval: TimeInterval,
+    },
+    RemoveGroupWorkIntervalFor {
+        group_name: String,
+        interval: TimeInterval,
+    },
+    UpdateGroupWorkIntervalFor {
+        group_name: String,
+        old_interval: TimeInterval,
+        new_interval: TimeInterval,
+    },
+    AddWorkHourTemplate {
+        name: String,
+    },
+    RemoveWorkHourTemplate {
+        name: String,
+    },
+    SetWorkHourTemplateName {
+        old_name: String,
+        new_name: String,
+    },
+    AddWorkHourTemplateInterval {
+        name: String,
+        interval: TimeInterval,
+    },
+    RemoveWorkHourTemplateInterval {
+        name: String,
+        interval: TimeInterval,
+    },
+    UpdateWorkHourTemplateInterval {
+        name: String,
+        old_interval: TimeInterval,
+        new_interval: TimeInterval,
+    },
+    SetWorkHourTemplateOf {
+        entity_name: String,
+        template_name: Option<String>,
+    },
+    AddPreferredIntervalFor {
+        entity_name: String,
+        interval: TimeInterval,
+    },
+    RemovePreferredIntervalFor {
+        entity_name: String,
+        interval: TimeInterval,
+    },
+    AddDislikedIntervalFor {
+        entity_name: String,
+        interval: TimeInterval,
+    },
+    RemoveDislikedIntervalFor {
+        entity_name: String,
+        interval: TimeInterval,
+    },
+    AddUnavailableIntervalFor {
+        entity_name: String,
+        interval: TimeInterval,
+    },
+    RemoveUnavailableIntervalFor {
+        entity_name: String,
+        interval: TimeInterval,
+    },
+    UpdateUnavailableIntervalFor {
+        entity_name: String,
+        old_interval: TimeInterval,
+        new_interval: TimeInterval,
+    },
+
+    // Break rules
+    SetMaxContinuousWork {
+        max_continuous_work: Option<Duration>,
+    },
+    AddMandatoryBreak {
+        mandatory_break: MandatoryBreak,
+    },
+    RemoveMandatoryBreak {
+        mandatory_break: MandatoryBreak,
+    },
+    SetMaxContinuousWorkFor {
+        entity_name: String,
+        max_continuous_work: Option<Duration>,
+    },
+    AddMandatoryBreakFor {
+        entity_name: String,
+        mandatory_break: MandatoryBreak,
+    },
+    RemoveMandatoryBreakFor {
+        entity_name: String,
+        mandatory_break: MandatoryBreak,
+    },
+
+    // History
+    Undo,
+    Redo,
+}
+
+impl Command {
+    /// Returns the ids of the activities which the command modifies.
+    #[must_use]
+    pub fn activity_ids(&self) -> Vec<ActivityId> {
+        match self {
+            Command::RemoveActivity { id }
+            | Command::SetActivityName { id, .. }
+            | Command::SetActivityDuration { id, .. }
+            | Command::SetActivityMaxDuration { id, .. }
+            | Command::SetActivityDurationObjective { id, .. }
+            | Command::SetActivityTransitionBuffer { id, .. }
+            | Command::SetActivityColor { id, .. }
+            | Command::SetActivityCategory { id, .. }
+            | Command::SetActivityDetails { id, .. }
+            | Command::SetActivityDescription { id, .. }
+            | Command::SetActivityLocation { id, .. }
+            | Command::AddEntityToActivity { id, .. }
+            | Command::RemoveEntityFromActivity { id, .. }
+            | Command::AddOptionalEntityToActivity { id, .. }
+            | Command::RemoveOptionalEntityFromActivity { id, .. }
+            | Command::SetActivityParticipantLimits { id, .. }
+            | Command::AddGroupToActivity { id, .. }
+            | Command::RemoveGroupFromActivity { id, .. }
+            | Command::InsertActivity { id, .. }
+            | Command::SplitActivity { id, .. }
+            | Command::MergeActivitySessions { id }
+            | Command::SetActivityOccurrences { id, .. }
+            | Command::UnlinkSimultaneousActivity { id }
+            | Command::UnchainActivity { id }
+            | Command::AddActivityTemplate { id, .. }
+            | Command::DuplicateActivity { id, .. } => vec![*id],
+            Command::LinkSimultaneousActivities { ids } => ids.clone(),
+            Command::ApplyAutoinsertionBeginnings { beginnings } => {
+                beginnings.iter().map(|&(id, _)| id).collect()
+            }
+            Command::ChainActivities {
+                previous_id,
+                next_id,
+                ..
+            } => vec![*previous_id, *next_id],
+            _ => Vec::new(),
+        }
+    }
+}
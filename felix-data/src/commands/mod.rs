@@ -0,0 +1,313 @@
+mod command;
+
+use crate::errors::{does_not_exist::DoesNotExist, Result};
+use crate::events::Event;
+use crate::Data;
+
+pub use command::Command;
+
+/// Operations on commands, which give a single entry point to every change of data.
+impl Data {
+    /// Applies the given command and returns the events it emitted, in order.
+    ///
+    /// The changes made by the command are undone at once.
+    /// If the command fails, every change it made is rolled back.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if one of the activities of the command does not exist
+    /// or if the method called by the command fails.
+    pub fn apply(&mut self, command: Command) -> Result<Vec<Event>> {
+        let activity_ids = self
+            .activities_not_sorted()
+            .iter()
+            .map(|activity| activity.id())
+            .collect::<Vec<_>>();
+        if let Some(id) = command
+            .activity_ids()
+            .into_iter()
+            .find(|id| !activity_ids.contains(id))
+        {
+            return Err(DoesNotExist::activity_does_not_exist(id));
+        }
+
+        self.events.borrow_mut().start_log();
+        let result = self.apply_or_roll_back(|data| data.apply_command(command));
+        let events = self.events.borrow_mut().stop_log();
+        result.map(|_| events)
+    }
+
+    /// Calls the method matching the given command.
+    fn apply_command(&mut self, command: Command) -> Result<()> {
+        match command {
+            // Entities
+            Command::AddEntity { name } => self.add_entity(name).map(|_| ()),
+            Command::RemoveEntity { name } => self.remove_entity(name),
+            Command::SetEntityName { old_name, new_name } => {
+                self.set_entity_name(old_name, new_name).map(|_| ())
+            }
+            Command::MergeEntities {
+                kept_name,
+                removed_name,
+            } => self.merge_entities(kept_name, removed_name),
+            Command::SetEntityDetails {
+                entity_name,
+                details,
+            } => self.set_entity_details(entity_name, details),
+            Command::SetEntityLoadLimits {
+                entity_name,
+                load_limits,
+            } => self.set_entity_load_limits(entity_name, load_limits),
+
+            // Groups
+            Command::AddGroup { name } => self.add_group(name).map(|_| ()),
+            Command::RemoveGroup { name } => self.remove_group(name),
+            Command::SetGroupName { old_name, new_name } => {
+                self.set_group_name(old_name, new_name).map(|_| ())
+            }
+            Command::AddEntityToGroup {
+                group_name,
+                entity_name,
+            } => self.add_entity_to_group(group_name, entity_name),
+            Command::RemoveEntityFromGroup {
+                group_name,
+                entity_name,
+            } => self.remove_entity_from_group(group_name, entity_name),
+            Command::AddGroupToGroup {
+                group_name,
+                subgroup_name,
+            } => self.add_group_to_group(group_name, subgroup_name),
+            Command::RemoveGroupFromGroup {
+                group_name,
+                subgroup_name,
+            } => self.remove_group_from_group(group_name, subgroup_name),
+            Command::SetGroupDetails {
+                group_name,
+                details,
+            } => self.set_group_details(group_name, details),
+            Command::SetGroupLoadLimits {
+                group_name,
+                load_limits,
+            } => self.set_group_load_limits(group_name, load_limits),
+
+            // Activities
+            Command::AddActivity { name } => self.add_activity(name).map(|_| ()),
+            Command::RemoveActivity { id } => {
+                self.remove_activity(id);
+                Ok(())
+            }
+            Command::SetActivityName { id, name } => self.set_activity_name(id, name).map(|_| ()),
+            Command::SetActivityDuration { id, duration } => {
+                self.set_activity_duration(id, duration)
+            }
+            Command::SetActivityMaxDuration { id, max_duration } => {
+                self.set_activity_max_duration(id, max_duration)
+            }
+            Command::SetActivityDurationObjective {
+                id,
+                duration_objective,
+            } => self.set_activity_duration_objective(id, duration_objective),
+            Command::SetActivityTransitionBuffer {
+                id,
+                transition_buffer,
+            } => self.set_activity_transition_buffer(id, transition_buffer),
+            Command::SetActivityColor { id, color } => self.set_activity_color(id, color),
+            Command::SetActivityCategory { id, category } => {
+                self.set_activity_category(id, category)
+            }
+            Command::SetCategoryColor { category, color } => {
+                self.set_category_color(category, color)
+            }
+            Command::SetActivityDetails { id, details } => self.set_activity_details(id, details),
+            Command::SetActivityDescription { id, description } => {
+                self.set_activity_description(id, description)
+            }
+            Command::SetActivityLocation { id, location } => {
+                self.set_activity_location(id, location)
+            }
+            Command::AddEntityToActivity { id, entity_name } => {
+                self.add_entity_to_activity(id, entity_name)
+            }
+            Command::RemoveEntityFromActivity { id, entity_name } => {
+                self.remove_entity_from_activity(id, entity_name)
+            }
+            Command::AddOptionalEntityToActivity { id, entity_name } => {
+                self.add_optional_entity_to_activity(id, entity_name)
+            }
+            Command::RemoveOptionalEntityFromActivity { id, entity_name } => {
+                self.remove_optional_entity_from_activity(id, entity_name)
+            }
+            Command::SetActivityParticipantLimits {
+                id,
+                min_participants,
+                max_participants,
+            } => self.set_activity_participant_limits(id, min_participants, max_participants),
+            Command::AddGroupToActivity { id, group_name } => {
+                self.add_group_to_activity(id, group_name)
+            }
+            Command::RemoveGroupFromActivity { id, group_name } => {
+                self.remove_group_from_activity(id, group_name)
+            }
+            Command::InsertActivity { id, insertion_time } => {
+                self.insert_activity(id, insertion_time)
+            }
+            Command::InsertActivitiesRemovedBecauseDurationIncreasedInClosestSpot => {
+                self.insert_activities_removed_because_duration_increased_in_closest_spot();
+                Ok(())
+            }
+            Command::ApplyAutoinsertionBeginnings { beginnings } => {
+                self.apply_autoinsertion_beginnings(beginnings);
+                Ok(())
+            }
+            Command::SplitActivity {
+                id,
+                number_of_sessions,
+                rules,
+            } => self
+                .split_activity(id, number_of_sessions, rules)
+                .map(|_| ()),
+            Command::MergeActivitySessions { id } => self.merge_activity_sessions(id),
+            Command::SetActivityOccurrences {
+                id,
+                number_of_occurrences,
+                min_spacing,
+            } => self
+                .set_activity_occurrences(id, number_of_occurrences, min_spacing)
+                .map(|_| ()),
+            Command::LinkSimultaneousActivities { ids } => self.link_simultaneous_activities(&ids),
+            Command::UnlinkSimultaneousActivity { id } => self.unlink_simultaneous_activity(id),
+            Command::ChainActivities {
+                previous_id,
+                next_id,
+                offset,
+            } => self.chain_activities(previous_id, next_id, offset),
+            Command::UnchainActivity { id } => self.unchain_activity(id),
+            Command::AddActivityTemplate { name, id } => {
+                self.add_activity_template(name, id).map(|_| ())
+            }
+            Command::RemoveActivityTemplate { name } => self.remove_activity_template(name),
+            Command::AddActivitiesFromTemplate { name, count } => {
+                self.add_activities_from_template(name, count).map(|_| ())
+            }
+            Command::DuplicateActivity { id, count } => {
+                self.duplicate_activity(id, count).map(|_| ())
+            }
+
+            // Work hours
+            Command::AddWorkInterval { interval } => self.add_work_interval(interval),
+            Command::RemoveWorkInterval { interval } => self.remove_work_interval(interval),
+            Command::UpdateWorkInterval {
+                old_interval,
+                new_interval,
+            } => self.update_work_interval(old_interval, new_interval),
+            Command::AddCustomWorkIntervalFor {
+                entity_name,
+                interval,
+            } => self.add_custom_work_interval_for(entity_name, interval),
+            Command::RemoveCustomWorkIntervalFor {
+                entity_name,
+                interval,
+            } => self.remove_custom_work_interval_for(entity_name, interval),
+            Command::UpdateCustomWorkIntervalFor {
+                entity_name,
+                old_interval,
+                new_interval,
+            } => self.update_custom_work_interval_for(entity_name, old_interval, new_interval),
+            Command::AddGroupWorkIntervalFor {
+                group_name,
+                interval,
+            } => self.add_group_work_interval_for(group_name, interval),
+            Command::RemoveGroupWorkIntervalFor {
+                group_name,
+                interval,
+            } => self.remove_group_work_interval_for(group_name, interval),
+            Command::UpdateGroupWorkIntervalFor {
+                group_name,
+                old_interval,
+                new_interval,
+            } => self.update_group_work_interval_for(group_name, old_interval, new_interval),
+            Command::AddWorkHourTemplate { name } => self.add_work_hour_template(name).map(|_| ()),
+            Command::RemoveWorkHourTemplate { name } => self.remove_work_hour_template(name),
+            Command::SetWorkHourTemplateName { old_name, new_name } => self
+                .set_work_hour_template_name(old_name, new_name)
+                .map(|_| ()),
+            Command::AddWorkHourTemplateInterval { name, interval } => {
+                self.add_work_hour_template_interval(name, interval)
+            }
+            Command::RemoveWorkHourTemplateInterval { name, interval } => {
+                self.remove_work_hour_template_interval(name, interval)
+            }
+            Command::UpdateWorkHourTemplateInterval {
+                name,
+                old_interval,
+                new_interval,
+            } => self.update_work_hour_template_interval(name, old_interval, new_interval),
+            Command::SetWorkHourTemplateOf {
+                entity_name,
+                template_name,
+            } => self.set_work_hour_template_of(entity_name, template_name),
+            Command::AddPreferredIntervalFor {
+                entity_name,
+                interval,
+            } => self.add_preferred_interval_for(entity_name, interval),
+            Command::RemovePreferredIntervalFor {
+                entity_name,
+                interval,
+            } => self.remove_preferred_interval_for(entity_name, interval),
+            Command::AddDislikedIntervalFor {
+                entity_name,
+                interval,
+            } => self.add_disliked_interval_for(entity_name, interval),
+            Command::RemoveDislikedIntervalFor {
+                entity_name,
+                interval,
+            } => self.remove_disliked_interval_for(entity_name, interval),
+            Command::AddUnavailableIntervalFor {
+                entity_name,
+                interval,
+            } => self.add_unavailable_interval_for(entity_name, interval),
+            Command::RemoveUnavailableIntervalFor {
+                entity_name,
+                interval,
+            } => self.remove_unavailable_interval_for(entity_name, interval),
+            Command::UpdateUnavailableIntervalFor {
+                entity_name,
+                old_interval,
+                new_interval,
+            } => self.update_unavailable_interval_for(entity_name, old_interval, new_interval),
+
+            // Break rules
+            Command::SetMaxContinuousWork {
+                max_continuous_work,
+            } => self.set_max_continuous_work(max_continuous_work),
+            Command::AddMandatoryBreak { mandatory_break } => {
+                self.add_mandatory_break(mandatory_break)
+            }
+            Command::RemoveMandatoryBreak { mandatory_break } => {
+                self.remove_mandatory_break(mandatory_break)
+            }
+            Command::SetMaxContinuousWorkFor {
+                entity_name,
+                max_continuous_work,
+            } => self.set_max_continuous_work_for(entity_name, max_continuous_work),
+            Command::AddMandatoryBreakFor {
+                entity_name,
+                mandatory_break,
+            } => self.add_mandatory_break_for(entity_name, mandatory_break),
+            Command::RemoveMandatoryBreakFor {
+                entity_name,
+                mandatory_break,
+            } => self.remove_mandatory_break_for(entity_name, mandatory_break),
+
+            // History
+            Command::Undo => {
+                self.undo();
+                Ok(())
+            }
+            Command::Redo => {
+                self.redo();
+                Ok(())
+            }
+        }
+    }
+}
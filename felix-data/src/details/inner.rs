@@ -0,0 +1,34 @@
+//! Helper functions for details implementation of data.
+
+use crate::errors::Result;
+use crate::{clean_string, Details};
+
+/// Formats the tags and custom field names of the given details and trims the other fields.
+/// An empty email is removed.
+///
+/// # Errors
+///
+/// Returns Err if a formatted tag or custom field name is empty.
+pub(super) fn clean_details(details: Details) -> Result<Details> {
+    let tags = details
+        .tags
+        .into_iter()
+        .map(clean_string)
+        .collect::<Result<_>>()?;
+    let custom_fields = details
+        .custom_fields
+        .into_iter()
+        .map(|(name, value)| Ok((clean_string(name)?, value.trim().to_owned())))
+        .collect::<Result<_>>()?;
+    let email = details
+        .email
+        .map(|email| email.trim().to_owned())
+        .filter(|email| !email.is_empty());
+
+    Ok(Details {
+        notes: details.notes.trim().to_owned(),
+        email,
+        tags,
+        custom_fields,
+    })
+}
@@ -0,0 +1,231 @@
+mod inner;
+
+use crate::errors::Result;
+use crate::{clean_string, Activity, ActivityFilter, ActivityId, Data, Details, Entity, Group};
+
+use inner::clean_details;
+
+/// Operations on details (notes, email, tags and custom fields) of entities, groups and
+/// activities, as well as on the description and location of activities.
+///
+/// Details have no effect on scheduling.
+impl Data {
+    /// Sets the details of the entity with the formatted given name.
+    ///
+    /// Tags and custom field names are formatted. An empty email removes the email.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found
+    /// or if a formatted tag or custom field name is empty.
+    pub fn set_entity_details<S>(&mut self, entity_name: S, details: Details) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let entity_name = self.entity(entity_name)?.name();
+        let details = clean_details(details)?;
+
+        self.entities.set_details_of(&entity_name, details)?;
+        let entity = self.entity(entity_name)?;
+        self.events()
+            .borrow_mut()
+            .emit_entity_details_changed(self, &entity);
+        Ok(())
+    }
+
+    /// Sets the details of the group with the formatted given name.
+    ///
+    /// Tags and custom field names are formatted. An empty email removes the email.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the group is not found
+    /// or if a formatted tag or custom field name is empty.
+    pub fn set_group_details<S>(&mut self, group_name: S, details: Details) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let group_name = self.group(group_name)?.name();
+        let details = clean_details(details)?;
+
+        self.groups.set_details_of(&group_name, details)?;
+        let group = self.group(group_name)?;
+        self.events()
+            .borrow_mut()
+            .emit_group_details_changed(self, &group);
+        Ok(())
+    }
+
+    /// Sets the details of the activity with given id, and of its other sessions.
+    ///
+    /// Tags and custom field names are formatted. An empty email removes the email.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if a formatted tag or custom field name is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity is not found.
+    pub fn set_activity_details(&mut self, id: ActivityId, details: Details) -> Result<()> {
+        let details = clean_details(details)?;
+        for session_id in self.session_ids_of(id) {
+            self.activities.set_details(session_id, details.clone());
+        }
+        self.notify_activity_details_changed(id);
+        Ok(())
+    }
+
+    /// Sets the description of the activity with given id, and of its other sessions.
+    /// Leading and trailing whitespace is removed.
+    ///
+    /// # Errors
+    ///
+    /// This function does not fail for now. It returns a Result to be consistent with the
+    /// other setters of activities.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity is not found.
+    pub fn set_activity_description<S>(&mut self, id: ActivityId, description: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let description = description.into().trim().to_owned();
+        for session_id in self.session_ids_of(id) {
+            self.activities
+                .set_description(session_id, description.clone());
+        }
+        self.notify_activity_details_changed(id);
+        Ok(())
+    }
+
+    /// Sets the location of the activity with given id, and of its other sessions.
+    /// Whitespace is formatted as in names, the case is kept.
+    ///
+    /// # Errors
+    ///
+    /// This function does not fail for now. It returns a Result to be consistent with the
+    /// other setters of activities.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity is not found.
+    pub fn set_activity_location<S>(&mut self, id: ActivityId, location: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let location = location
+            .into()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        for session_id in self.session_ids_of(id) {
+            self.activities.set_location(session_id, location.clone());
+        }
+        self.notify_activity_details_changed(id);
+        Ok(())
+    }
+
+    /// Returns the entities whose name or details contain the given text, sorted by name.
+    /// The search is case insensitive.
+    #[must_use]
+    pub fn entities_matching(&self, text: &str) -> Vec<&Entity> {
+        self.entities_sorted()
+            .into_iter()
+            .filter(|entity| {
+                contains_ignoring_case(&entity.name(), text) || entity.details().contains_text(text)
+            })
+            .collect()
+    }
+
+    /// Returns the groups whose name or details contain the given text, sorted by name.
+    /// The search is case insensitive.
+    #[must_use]
+    pub fn groups_matching(&self, text: &str) -> Vec<&Group> {
+        self.groups_sorted()
+            .into_iter()
+            .filter(|group| {
+                contains_ignoring_case(&group.name(), text) || group.details().contains_text(text)
+            })
+            .collect()
+    }
+
+    /// Returns the activities whose name, category, description, location or details contain
+    /// the given text, sorted by name. The search is case insensitive.
+    #[must_use]
+    pub fn activities_matching(&self, text: &str) -> Vec<Activity> {
+        self.activities_sorted()
+            .into_iter()
+            .filter(|activity| {
+                contains_ignoring_case(&activity.name(), text)
+                    || activity
+                        .category()
+                        .is_some_and(|category| contains_ignoring_case(&category, text))
+                    || contains_ignoring_case(&activity.description(), text)
+                    || contains_ignoring_case(&activity.location(), text)
+                    || activity.details().contains_text(text)
+            })
+            .collect()
+    }
+
+    /// Returns the entities with the formatted given tag, sorted by name.
+    #[must_use]
+    pub fn entities_with_tag<S>(&self, tag: S) -> Vec<&Entity>
+    where
+        S: Into<String>,
+    {
+        clean_string(tag).map_or_else(
+            |_| Vec::new(),
+            |tag| {
+                self.entities_sorted()
+                    .into_iter()
+                    .filter(|entity| entity.details().has_tag(&tag))
+                    .collect()
+            },
+        )
+    }
+
+    /// Returns the groups with the formatted given tag, sorted by name.
+    #[must_use]
+    pub fn groups_with_tag<S>(&self, tag: S) -> Vec<&Group>
+    where
+        S: Into<String>,
+    {
+        clean_string(tag).map_or_else(
+            |_| Vec::new(),
+            |tag| {
+                self.groups_sorted()
+                    .into_iter()
+                    .filter(|group| group.details().has_tag(&tag))
+                    .collect()
+            },
+        )
+    }
+
+    /// Returns the activities with the formatted given tag, sorted by name.
+    /// The sessions of split activities are all returned.
+    #[must_use]
+    pub fn activities_with_tag<S>(&self, tag: S) -> Vec<Activity>
+    where
+        S: Into<String>,
+    {
+        clean_string(tag).map_or_else(
+            |_| Vec::new(),
+            |tag| self.activities_filtered(&ActivityFilter::with_tag(tag)),
+        )
+    }
+
+    /// Emits the activity details changed event.
+    fn notify_activity_details_changed(&mut self, id: ActivityId) {
+        let activity = self.activity(id);
+        self.events()
+            .borrow_mut()
+            .emit_activity_details_changed(self, &activity);
+    }
+}
+
+/// Returns true if the given text appears in the string, ignoring case.
+fn contains_ignoring_case(s: &str, text: &str) -> bool {
+    s.to_lowercase().contains(&text.to_lowercase())
+}
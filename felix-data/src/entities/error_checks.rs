@@ -1,5 +1,9 @@
-use crate::errors::{name_taken::NameTaken, Result};
-use crate::Data;
+use crate::errors::{
+    invalid_merge::InvalidMerge, name_taken::NameTaken, not_enough_time::NotEnoughTime, Result,
+};
+use crate::{Activity, Data, Duration, Entity};
+
+use felix_collections::WorkHours;
 
 impl Data {
     /// Checks if the given name is taken by a group.
@@ -19,4 +23,96 @@ impl Data {
             Ok(())
         }
     }
+
+    /// Checks that the given entities are two different entities.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if both entities are the same.
+    pub(super) fn check_entities_can_be_merged(
+        &self,
+        kept: &Entity,
+        removed: &Entity,
+    ) -> Result<()> {
+        if kept.id() == removed.id() {
+            Err(InvalidMerge::same_entity(kept.name()))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Checks that the kept entity can take part in the given activities without any of its
+    /// inserted activities overlapping and without taking part in simultaneous activities.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if two inserted activities would overlap
+    /// or if the kept entity would take part in simultaneous activities.
+    pub(super) fn check_no_activities_overlap_when_merged(
+        &self,
+        kept: &Entity,
+        removed: &Entity,
+        added_activities: &[Activity],
+    ) -> Result<()> {
+        let inserted_activities_of_kept = self
+            .activities_of(kept.name())?
+            .into_iter()
+            .filter(|activity| activity.insertion_interval().is_some())
+            .collect::<Vec<_>>();
+
+        for added_activity in added_activities {
+            self.check_entity_not_in_simultaneous_activities(added_activity.id(), &kept.name())?;
+            if let Some(insertion_interval) = added_activity.insertion_interval() {
+                if let Some(blocking_activity) =
+                    inserted_activities_of_kept.iter().find(|activity| {
+                        insertion_interval.is_closer_than(
+                            &activity
+                                .insertion_interval()
+                                .expect("Filtering only inserted activities did not work"),
+                            std::cmp::max(
+                                activity.transition_buffer(),
+                                added_activity.transition_buffer(),
+                            ),
+                        )
+                    })
+                {
+                    return Err(InvalidMerge::activities_overlap(
+                        kept.name(),
+                        removed.name(),
+                        blocking_activity.name(),
+                        added_activity.name(),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the kept entity has enough time for its activities and the given added
+    /// activities once merged. It works during the work hours which apply to it with the given
+    /// work hours settings, as a member of the given groups.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the kept entity will not have enough time.
+    pub(super) fn check_has_enough_time_when_merged(
+        &self,
+        kept: &Entity,
+        removed: &Entity,
+        work_hours: &WorkHours,
+        new_group_names: &[String],
+        added_activities: &[Activity],
+    ) -> Result<()> {
+        let required_time = self.time_taken_by_activities(&kept.name())
+            + added_activities
+                .iter()
+                .map(Activity::duration)
+                .sum::<Duration>();
+
+        if self.available_time_with(work_hours, kept.id(), new_group_names) < required_time {
+            Err(NotEnoughTime::merged_with(kept.name(), removed.name()))
+        } else {
+            Ok(())
+        }
+    }
 }
@@ -0,0 +1,190 @@
+use crate::errors::Result;
+use crate::{Activity, Data, Details, Entity, GroupId, LoadLimits};
+
+use felix_collections::Groups;
+
+use std::collections::BTreeSet;
+
+/// Merging of duplicate entities.
+impl Data {
+    /// Merges the entity with the second formatted given name into the entity with the first
+    /// formatted given name, then removes it.
+    ///
+    /// The kept entity joins the groups and the activities of the removed entity.
+    /// It becomes an optional participant of the activities the removed entity attends
+    /// optionally, unless it already takes part in them.
+    /// The kept entity takes the custom work hours, time preferences, unavailability,
+    /// work hour template, custom break rules and load limits of the removed entity,
+    /// for each of them which it does not have.
+    /// Details are combined, the ones of the kept entity take precedence.
+    ///
+    /// Activities which no longer fit in the work hours of the kept entity or which break its
+    /// break rules are removed from the schedule.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if an entity is not found, if both names refer to the same entity,
+    /// if inserted activities of both entities would overlap,
+    /// if the kept entity would not have enough time for its activities
+    /// or would exceed its load limits.
+    pub fn merge_entities<S1, S2>(&mut self, kept_name: S1, removed_name: S2) -> Result<()>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        self.record_as_single_step(|data| {
+            let kept = data.entity(kept_name)?;
+            let removed = data.entity(removed_name)?;
+            data.check_entities_can_be_merged(&kept, &removed)?;
+
+            let (new_groups, joined_group_names) = data.groups_after_merge(&kept, &removed);
+            let joined_group_ids = new_groups
+                .ids_of_groups_containing_entity(kept.id())
+                .difference(&data.groups.ids_of_groups_containing_entity(kept.id()))
+                .copied()
+                .collect::<BTreeSet<GroupId>>();
+            let added_activities =
+                data.activities_added_by_merge(&kept, &removed, &joined_group_ids);
+
+            data.check_no_activities_overlap_when_merged(&kept, &removed, &added_activities)?;
+            let mut new_work_hours = data.work_hours.clone();
+            new_work_hours.carry_over_settings(removed.id(), kept.id());
+            let load_limits = if kept.load_limits() == &LoadLimits::default() {
+                removed.load_limits().clone()
+            } else {
+                kept.load_limits().clone()
+            };
+            let new_group_names =
+                new_groups.names_sorted(&new_groups.ids_of_groups_containing_entity(kept.id()));
+            data.check_has_enough_time_when_merged(
+                &kept,
+                &removed,
+                &new_work_hours,
+                &new_group_names,
+                &added_activities,
+            )?;
+            data.check_load_limits_respected_when_merged(
+                &kept.name(),
+                &removed.name(),
+                &load_limits,
+                &joined_group_ids,
+                &added_activities,
+            )?;
+
+            // Every check passed, merge
+            data.work_hours = new_work_hours;
+            let load_limits_changed = &load_limits != kept.load_limits();
+            data.entities
+                .set_load_limits_of(&kept.name(), load_limits)?;
+            data.apply_new_groups(new_groups, &std::iter::once(kept.id()).collect())?;
+            for activity in data.activities_of(removed.name())? {
+                if !activity.entity_ids().contains(&kept.id()) {
+                    data.activities.add_entity(activity.id(), &kept)?;
+                }
+            }
+            for activity in data.optional_activities_of(removed.name())? {
+                if !activity.entity_ids().contains(&kept.id())
+                    && !activity.optional_entity_ids().contains(&kept.id())
+                {
+                    data.activities.add_optional_entity(activity.id(), &kept)?;
+                }
+            }
+            data.entities.set_details_of(
+                &kept.name(),
+                merged_details(kept.details(), removed.details()),
+            )?;
+            data.remove_entity(removed.name())?;
+            data.uninsert_activities_breaking_break_rules();
+            data.notify_work_hours_of_entities_changed(vec![kept.name()]);
+            if load_limits_changed {
+                data.events().borrow_mut().emit_load_limits_changed(data);
+            }
+
+            let kept = data.entity(kept.name())?;
+            data.events()
+                .borrow_mut()
+                .emit_entity_details_changed(data, &kept);
+            for group_name in joined_group_names {
+                let group = data.group(group_name)?;
+                data.events()
+                    .borrow_mut()
+                    .emit_entity_added_to_group(data, &group);
+            }
+            for activity in added_activities
+                .into_iter()
+                .filter(|activity| activity.main_session_id() == activity.id())
+            {
+                let activity = data.activity(activity.id());
+                data.events()
+                    .borrow_mut()
+                    .emit_entity_added_to_activity(data, &activity);
+            }
+            Ok(())
+        })
+    }
+
+    /// Returns the groups in which the kept entity is added to the groups of the removed
+    /// entity, along with the names of the groups it joined directly.
+    fn groups_after_merge(&self, kept: &Entity, removed: &Entity) -> (Groups, Vec<String>) {
+        let mut new_groups = self.groups.clone();
+        let joined_group_names = self
+            .groups_sorted()
+            .into_iter()
+            .filter(|group| {
+                group.entity_ids().contains(&removed.id())
+                    && !group.entity_ids().contains(&kept.id())
+            })
+            .map(|group| group.name())
+            .collect::<Vec<_>>();
+        for group_name in &joined_group_names {
+            new_groups
+                .add_entity_to_group(group_name, kept)
+                .expect("The kept entity is not a direct member of the group");
+        }
+        (new_groups, joined_group_names)
+    }
+
+    /// Returns the activities the kept entity takes part in after the merge which it is
+    /// not taking part in yet : the activities of the removed entity
+    /// and the activities of the groups it joins.
+    fn activities_added_by_merge(
+        &self,
+        kept: &Entity,
+        removed: &Entity,
+        joined_group_ids: &BTreeSet<GroupId>,
+    ) -> Vec<Activity> {
+        self.activities_sorted()
+            .into_iter()
+            .filter(|activity| {
+                let entity_ids = activity.entity_ids();
+                !entity_ids.contains(&kept.id())
+                    && (entity_ids.contains(&removed.id())
+                        || activity
+                            .group_ids()
+                            .iter()
+                            .any(|group_id| joined_group_ids.contains(group_id)))
+            })
+            .collect()
+    }
+}
+
+/// Combines the details of two merged entities. The kept details take precedence.
+fn merged_details(kept: &Details, removed: &Details) -> Details {
+    let mut details = kept.clone();
+    if details.notes.is_empty() {
+        details.notes = removed.notes.clone();
+    } else if !removed.notes.is_empty() && removed.notes != details.notes {
+        details.notes = format!("{}\n{}", details.notes, removed.notes);
+    }
+    if details.email.is_none() {
+        details.email = removed.email.clone();
+    }
+    details.tags.extend(removed.tags.iter().cloned());
+    for (field, value) in &removed.custom_fields {
+        details
+            .custom_fields
+            .entry(field.clone())
+            .or_insert_with(|| value.clone());
+    }
+    details
+}
@@ -1,4 +1,5 @@
 mod error_checks;
+mod merge;
 
 use super::helpers::clean_string;
 use crate::errors::Result;
@@ -38,12 +39,8 @@ impl Data {
         let name = clean_string(name)?;
         // Check if a group has the same name
         self.check_name_taken_by_group(&name)?;
-        self.entities.add(name.clone())?;
-        self.work_hours
-            .add_empty_custom_work_intervals_for(name.clone());
-        let entity = self
-            .entity(&name)
-            .expect("Entity was just added so it exists");
+        let id = self.entities.add(name.clone())?;
+        let entity = self.entities.get_by_id(id);
         self.events().borrow_mut().emit_entity_added(self, &entity);
         Ok(name)
     }
@@ -65,12 +62,18 @@ impl Data {
             .into_iter()
             .position(|entity| entity.name() == name);
         // First, remove in entities to check for any error
-        self.entities.remove(&name)?;
+        let entity = self.entity(&name)?;
+        let id = self.entities.remove(&name)?;
         // If the entity was successfuly removed in entities, remove it
         // in all activities and groups
-        self.activities.remove_entity_from_all(&name);
-        self.groups.remove_entity_from_all(&name);
-        self.work_hours.remove_custom_work_hours_of(&name);
+        self.activities.remove_entity_from_all(&entity);
+        self.activities.remove_entity_from_templates(id);
+        self.groups.remove_entity_from_all(id);
+        self.work_hours.remove_custom_work_hours_of(id);
+        self.work_hours.remove_preferences_of(id);
+        self.work_hours.remove_break_rules_of(id);
+        self.work_hours.remove_unavailability_of(id);
+        self.work_hours.remove_work_hour_template_of(id);
         let position_of_removed_entity = position_of_removed_entity.expect(
             "If the entity was removed then it existed, therefore position should be valid",
         );
@@ -82,7 +85,7 @@ impl Data {
 
     /// Renames the entity with the formatted given name.
     ///
-    /// If the entity is taking part in any activity, it is renamed there as well.
+    /// Activities, groups and work hours refer to the entity by id, so they are not affected.
     /// Returns the formatted version of the given name.
     ///
     /// # Errors
@@ -96,20 +99,9 @@ impl Data {
         let new_name = clean_string(new_name)?;
         self.check_name_taken_by_group(&new_name)?;
 
-        // First, rename in entities to check for any error
         let old_name = clean_string(old_name)?;
         self.entities.set_name_of(&old_name, new_name.clone())?;
 
-        // Then, rename in group (group/activities order does not matter)
-        self.groups
-            .rename_entity_in_all(&old_name, new_name.clone());
-        // Then, rename in activities
-        self.activities
-            .rename_entity_in_all(&old_name, new_name.clone());
-        // Then, rename for custom work hours
-        self.work_hours
-            .rename_entity_for_custom_work_hours(&old_name, new_name.clone());
-
         let entity = self
             .entity(&new_name)
             .expect("Entity was renamed succesfuly so this is valid");
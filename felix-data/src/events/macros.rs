@@ -11,20 +11,63 @@ macro_rules! create_callback_vec {
 }
 
 macro_rules! create_events_struct {
-    ($events_name: ident: $($element: ident { $($param_type: ty),* }),*) => {
+    ($events_name: ident, $event_name: ident: $($element: ident { $($param_type: ty),* }),*) => {
         /// Manages events. Connects callbacks which are called in emit functions.
         ///
         /// PartialEq, Eq, Clone, Debug are implemented for compatibility with other structs which
         /// may contain this struct. They do not do anything relevant: the containing
         /// struct should behave as if this struct did not exist.
-        pub struct $events_name { $($element: create_callback_vec!($($param_type),*)),* }
+        pub struct $events_name {
+            $($element: create_callback_vec!($($param_type),*),)*
+            /// Events emitted since each open log was started, innermost last.
+            logs: Vec<Vec<$event_name>>,
+            /// Events whose callbacks are not called yet, if callbacks are deferred.
+            deferred: Option<Vec<$event_name>>,
+        }
+    }
+}
+
+macro_rules! create_event_enum {
+    ($event_name: ident: $($element: ident),*) => {
+        paste! {
+            /// Kind of an event, without its parameters.
+            #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+            pub enum $event_name { $([<$element:camel>]),* }
+        }
     }
 }
 
 macro_rules! create_events_new {
     ($events_name: ident: $($element: ident),*) => {
         pub fn new() -> $events_name {
-            $events_name {$($element: Vec::new()),* }
+            $events_name {$($element: Vec::new(),)* logs: Vec::new(), deferred: None }
+        }
+    };
+}
+
+macro_rules! create_events_log {
+    ($event_name: ident) => {
+        /// Starts keeping track of the emitted events.
+        /// Logs can be nested: an event is kept by every open log.
+        pub fn start_log(&mut self) {
+            self.logs.push(Vec::new());
+        }
+
+        /// Stops keeping track of the emitted events in the innermost log.
+        /// Returns the events emitted since that log was started.
+        pub fn stop_log(&mut self) -> Vec<$event_name> {
+            self.logs.pop().unwrap_or_default()
+        }
+
+        /// Stops calling the callbacks of the emitted events.
+        pub fn start_deferring(&mut self) {
+            self.deferred = Some(Vec::new());
+        }
+
+        /// Calls the callbacks of the emitted events again.
+        /// Returns the events emitted since callbacks were deferred.
+        pub fn stop_deferring(&mut self) -> Vec<$event_name> {
+            self.deferred.take().unwrap_or_default()
         }
     };
 }
@@ -42,10 +85,18 @@ macro_rules! create_connect_events {
 }
 
 macro_rules! create_emit_events {
-    ($($element: ident { $($param_name: ident : $param_type: ty),* }),*) => {
+    ($event_name: ident: $($element: ident { $($param_name: ident : $param_type: ty),* }),*) => {
         paste! {
             $(
         pub fn [<emit_ $element>](&mut self, data: &Data, $($param_name: $param_type),*) {
+            data.record_in_history();
+            for log in &mut self.logs {
+                log.push($event_name::[<$element:camel>]);
+            }
+            if let Some(deferred) = &mut self.deferred {
+                deferred.push($event_name::[<$element:camel>]);
+                return;
+            }
             for callback in &mut self.$element {
                 callback(data, $($param_name),*);
             }
@@ -55,11 +106,12 @@ macro_rules! create_emit_events {
 }
 
 macro_rules! create_events_impl {
-    ($events_name: ident: $($element: ident { $($param_name: ident : $param_type: ty),* }),*) => {
+    ($events_name: ident, $event_name: ident: $($element: ident { $($param_name: ident : $param_type: ty),* }),*) => {
         impl $events_name {
             create_events_new!($events_name: $($element),*);
+            create_events_log!($event_name);
             create_connect_events!($($element { $($param_type),* }),*);
-            create_emit_events!($($element { $($param_name: $param_type),* }),*);
+            create_emit_events!($event_name: $($element { $($param_name: $param_type),* }),*);
         }
 
         impl Default for $events_name {
@@ -96,7 +148,7 @@ macro_rules! create_events_impl {
 /// # Example
 ///
 ///```
-/// create_events!(Events:
+/// create_events!(Events, Event:
 ///     renamed {old_name: &str, new_name: &str},
 ///     something_changed {}
 /// )
@@ -107,6 +159,14 @@ macro_rules! create_events_impl {
 /// pub struct Events {
 ///     renamed: Vec<Box<dyn FnMut(&Data, &str, &str)>>,
 ///     something_changed: Vec<Box<dyn FnMut(&Data)>>,
+///     logs: Vec<Vec<Event>>,
+///     deferred: Option<Vec<Event>>,
+/// }
+///
+/// #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// pub enum Event {
+///     Renamed,
+///     SomethingChanged,
 /// }
 ///
 /// /// Structure holding all events.
@@ -115,9 +175,27 @@ macro_rules! create_events_impl {
 ///         Events {
 ///             renamed: Vec::new(),
 ///             something_changed: Vec::new(),
+///             logs: Vec::new(),
+///             deferred: None,
 ///         }
 ///     }
 ///
+///     pub fn start_log(&mut self) {
+///         self.logs.push(Vec::new());
+///     }
+///
+///     pub fn stop_log(&mut self) -> Vec<Event> {
+///         self.logs.pop().unwrap_or_default()
+///     }
+///
+///     pub fn start_deferring(&mut self) {
+///         self.deferred = Some(Vec::new());
+///     }
+///
+///     pub fn stop_deferring(&mut self) -> Vec<Event> {
+///         self.deferred.take().unwrap_or_default()
+///     }
+///
 ///     pub fn connect_renamed(&mut self,
 ///         callbacks: Vec<Box<dyn FnMut(&Data, &str, &str)>>) {
 ///         self.renamed.extend(callbacks);
@@ -132,12 +210,28 @@ macro_rules! create_events_impl {
 ///                         data: &Data,
 ///                         old_name: &str,
 ///                         new_name: &str) {
+///         data.record_in_history();
+///         for log in &mut self.logs {
+///             log.push(Event::Renamed);
+///         }
+///         if let Some(deferred) = &mut self.deferred {
+///             deferred.push(Event::Renamed);
+///             return;
+///         }
 ///         for callback in &mut self.renamed {
 ///             callback(data, old_name, new_name);
 ///         }
 ///     }
 ///
 ///     pub fn emit_something_changed(&mut self, data: &Data) {
+///         data.record_in_history();
+///         for log in &mut self.logs {
+///             log.push(Event::SomethingChanged);
+///         }
+///         if let Some(deferred) = &mut self.deferred {
+///             deferred.push(Event::SomethingChanged);
+///             return;
+///         }
 ///         for callback in &mut self.something_changed {
 ///             callback(data);
 ///         }
@@ -171,8 +265,9 @@ macro_rules! create_events_impl {
 /// }
 /// ```
 macro_rules! create_events {
-    ($events_name:ident :$($element: ident { $($param_name: ident : $param_type: ty),* } ),*) => {
-        create_events_struct!($events_name : $($element { $($param_type),* }),*);
-        create_events_impl!($events_name: $($element { $($param_name: $param_type),* }),*);
+    ($events_name:ident, $event_name:ident :$($element: ident { $($param_name: ident : $param_type: ty),* } ),*) => {
+        create_events_struct!($events_name, $event_name : $($element { $($param_type),* }),*);
+        create_event_enum!($event_name: $($element),*);
+        create_events_impl!($events_name, $event_name: $($element { $($param_name: $param_type),* }),*);
     };
 }
@@ -4,24 +4,35 @@ mod macros;
 use crate::{Activity, Data, Entity, Group};
 
 use paste::paste;
+use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use std::fmt;
 use std::rc::Rc;
 
 // Build the event struct with fields and accessers.
 // See macros for more info.
-create_events!(Events:
+create_events!(Events, Event:
     entity_added { new_entity: &Entity },
     entity_removed { position_of_removed_entity: usize, name_of_removed_entity: &str },
     entity_renamed { entity: &Entity, old_name: &str },
+    entity_details_changed { entity: &Entity },
     group_added {new_group: &Group},
     group_removed {position_of_removed_group: usize },
     group_renamed {group: &Group},
+    group_details_changed {group: &Group},
     activity_added { new_activity: &Activity},
+    activities_added { new_activities: &[Activity]},
     activity_removed { position_of_removed_activity: usize },
     activity_renamed { activity: &Activity},
     activity_duration_changed { activity: &Activity},
     activity_color_changed { activity: &Activity},
+    activity_category_changed { activity: &Activity},
+    activity_details_changed { activity: &Activity},
+    activity_transition_buffer_changed { activity: &Activity},
+    activity_participant_limits_changed { activity: &Activity},
+    activity_sessions_changed { activity: &Activity},
+    simultaneous_activities_changed { activity: &Activity},
+    activity_chain_changed { activity: &Activity},
     activity_inserted { activity: &Activity},
     entity_added_to_activity { activity: &Activity},
     entity_removed_from_activity { activity: &Activity},
@@ -29,8 +40,13 @@ create_events!(Events:
     entity_removed_from_group {group: &Group},
     group_added_to_activity { activity: &Activity},
     group_removed_from_activity { activity: &Activity},
+    activity_templates_changed {},
     work_hours_changed {},
-    autoinsertion_done {}
+    time_preferences_changed { entity: &Entity },
+    load_limits_changed {},
+    autoinsertion_done {},
+    data_restored {},
+    batch_applied { events: &[Event] }
 );
 
 /// Data implementation for events.
@@ -1,4 +1,4 @@
-use crate::Data;
+use crate::{ActivityFilter, Data};
 
 use felix_collections::PrintableActivities;
 use felix_export_api::generate_pdf;
@@ -8,20 +8,48 @@ use std::path::PathBuf;
 /// Functions related to exporting current data.
 impl Data {
     pub fn export_as_pdf(&self, output_dir: PathBuf) {
-        let printable_data = self.as_printable();
+        self.export_as_pdf_filtered(output_dir, &ActivityFilter::default());
+    }
+
+    /// Exports the schedule of each entity, keeping only the activities kept by the filter.
+    pub fn export_as_pdf_filtered(&self, output_dir: PathBuf, filter: &ActivityFilter) {
+        let printable_data = self.as_printable_filtered(filter);
 
         for (entity, activities) in printable_data {
-            generate_pdf(entity, activities, output_dir.clone());
+            generate_pdf(entity, activities, &self.entities, output_dir.clone());
         }
     }
 
     pub fn as_printable(&self) -> PrintableActivities {
+        self.as_printable_filtered(&ActivityFilter::default())
+    }
+
+    /// Returns the activities of each entity which are kept by the filter,
+    /// including the activities the entity attends as an optional participant.
+    /// Entities without such activities are left out unless the filter is empty.
+    pub fn as_printable_filtered(&self, filter: &ActivityFilter) -> PrintableActivities {
         let mut res = PrintableActivities::new();
         for entity in self.entities_sorted() {
             let activities_of_entity = self
                 .activities_of(entity.name())
-                .unwrap_or_else(|_| panic!("Could not get the activities of {}", entity.name()));
-            res.insert(entity.name(), activities_of_entity);
+                .unwrap_or_else(|_| panic!("Could not get the activities of {}", entity.name()))
+                .into_iter()
+                .chain(
+                    self.optional_activities_of(entity.name())
+                        .unwrap_or_else(|_| {
+                            panic!("Could not get the activities of {}", entity.name())
+                        })
+                        .into_iter()
+                        .filter(|activity| {
+                            self.optional_entities_attending_activity(activity.id())
+                                .contains(&entity.name())
+                        }),
+                )
+                .filter(|activity| filter.matches(activity))
+                .collect::<Vec<_>>();
+            if filter.is_empty() || !activities_of_entity.is_empty() {
+                res.insert(entity.name(), activities_of_entity);
+            }
         }
         res
     }
@@ -1,27 +1,86 @@
 //! Helper functions for groups implementation of data.
 
 use crate::errors::{name_taken::NameTaken, not_enough_time::NotEnoughTime, Result};
-use crate::Time;
-use crate::{Activity, Data};
+use crate::{Activity, Data, Duration, EntityId, GroupId};
+
+use felix_collections::Groups;
+
+use std::collections::BTreeSet;
 
 impl Data {
-    /// Checks that the given entity has enough time to be added to the group.
+    /// Checks that the members with given ids can be members of the groups they belong to
+    /// in the given new groups. The change of groups is caused by the group with given name.
+    ///
+    /// The work hours of each member may change with its groups. Members who join groups
+    /// take part in their activities and their load limits apply.
+    /// Returns the names of the members whose work hours change.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if a member would not have enough time for its activities
+    /// or would exceed its load limits.
+    pub(super) fn check_members_allow_new_groups(
+        &self,
+        new_groups: &Groups,
+        member_ids: &BTreeSet<EntityId>,
+        group_name: &str,
+    ) -> Result<Vec<String>> {
+        let mut members_with_new_work_hours = Vec::new();
+        for &entity_id in member_ids {
+            let entity_name = self.entities.get_by_id(entity_id).name();
+            let new_group_ids = new_groups.ids_of_groups_containing_entity(entity_id);
+            let new_group_names = new_groups.names_sorted(&new_group_ids);
+
+            if self.check_work_hours_allow_groups_of(&entity_name, &new_group_names)? {
+                members_with_new_work_hours.push(entity_name.clone());
+            }
+
+            let joined_group_ids = new_group_ids
+                .difference(&self.groups.ids_of_groups_containing_entity(entity_id))
+                .copied()
+                .collect::<BTreeSet<_>>();
+            if !joined_group_ids.is_empty() {
+                self.check_has_enough_time_for_groups(
+                    group_name,
+                    &entity_name,
+                    &joined_group_ids,
+                    &new_group_names,
+                )?;
+                self.check_load_limits_respected_in_groups(
+                    group_name,
+                    &entity_name,
+                    &joined_group_ids,
+                )?;
+            }
+        }
+        Ok(members_with_new_work_hours)
+    }
+
+    /// Checks that the given entity has enough time to join the groups with given ids,
+    /// for instance when it is added to the group with given name.
+    /// The work hours of its new groups are taken into account.
     ///
     /// # Errors
     ///
-    /// Returns Err if the entity name is empty, if the entity is not found
-    /// or if the entity will not have enough time for the group's activities.
-    pub(super) fn check_has_enough_time_for_group(
+    /// Returns Err if the entity is not found
+    /// or if the entity will not have enough time for the activities of the groups.
+    fn check_has_enough_time_for_groups(
         &self,
         group_name: &str,
         entity_name: &str,
+        joined_group_ids: &BTreeSet<GroupId>,
+        new_group_names: &[String],
     ) -> Result<()> {
+        let entity_id = self.entity(entity_name)?.id();
         let entity_should_be_added_to_activity = |activity: &Activity| {
-            activity.groups_sorted().contains(&group_name.into())
-                && !activity.entities_sorted().contains(&entity_name.into())
+            activity
+                .group_ids()
+                .iter()
+                .any(|group_id| joined_group_ids.contains(group_id))
+                && !activity.entity_ids().contains(&entity_id)
         };
 
-        let duration_of_added_activities: Time = self
+        let duration_of_added_activities: Duration = self
             .activities_sorted()
             .iter()
             .filter_map(|activity| {
@@ -33,7 +92,7 @@ impl Data {
             })
             .sum();
 
-        let free_time = self.free_time_of(entity_name)?;
+        let free_time = self.free_time_with_groups_of(entity_name, new_group_names)?;
         if free_time >= duration_of_added_activities {
             Ok(())
         } else {
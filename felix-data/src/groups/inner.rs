@@ -1,35 +1,29 @@
-use crate::{ActivityId, Data};
+use crate::errors::Result;
+use crate::{ActivityId, Data, EntityId, GroupId};
+
+use felix_collections::Groups;
+
+use std::collections::BTreeSet;
 
 impl Data {
-    pub(super) fn ids_of_activities_in_which_entity_is_participating_only_through_this_group(
+    /// Returns the ids of the activities which the entity takes part in only through the given
+    /// groups, which it left.
+    pub(super) fn ids_of_activities_in_which_entity_is_participating_only_through_groups(
         &self,
-        entity_name: &str,
-        group_name: &str,
+        entity_id: EntityId,
+        left_group_ids: &BTreeSet<GroupId>,
     ) -> Vec<ActivityId> {
-        let other_groups_of_entity: Vec<String> = self
-            .groups_sorted()
-            .into_iter()
-            .filter_map(|group| {
-                if group.name() != group_name
-                    && group.entities_sorted().contains(&entity_name.into())
-                {
-                    Some(group.name())
-                } else {
-                    None
-                }
-            })
-            .collect();
+        let groups_of_entity = self.groups.ids_of_groups_containing_entity(entity_id);
 
         self.activities_sorted()
             .iter()
             .filter_map(|activity| {
-                let entities = activity.entities_sorted();
-                let groups = activity.groups_sorted();
-                if entities.contains(&entity_name.into())
-                    && groups.contains(&group_name.into())
-                    && !groups
-                        .into_iter()
-                        .any(|group_name| other_groups_of_entity.contains(&group_name))
+                let groups = activity.group_ids();
+                // The other sessions of a split activity follow its main session
+                if activity.main_session_id() == activity.id()
+                    && activity.entity_ids().contains(&entity_id)
+                    && groups.iter().any(|id| left_group_ids.contains(id))
+                    && !groups.iter().any(|id| groups_of_entity.contains(id))
                 {
                     Some(activity.id())
                 } else {
@@ -38,4 +32,47 @@ impl Data {
             })
             .collect()
     }
+
+    /// Replaces the groups with the given groups, in which the members with given ids may
+    /// have joined or left groups.
+    ///
+    /// Members take part in the activities of the groups they joined.
+    /// They leave the activities in which they were participating only through the groups
+    /// they left.
+    ///
+    /// The new groups must have been checked beforehand.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if a member cannot be removed from an activity.
+    pub(crate) fn apply_new_groups(
+        &mut self,
+        new_groups: Groups,
+        member_ids: &BTreeSet<EntityId>,
+    ) -> Result<()> {
+        let old_groups = std::mem::replace(&mut self.groups, new_groups);
+
+        for &entity_id in member_ids {
+            let entity = self.entities.get_by_id(entity_id);
+            let old_group_ids = old_groups.ids_of_groups_containing_entity(entity_id);
+            let new_group_ids = self.groups.ids_of_groups_containing_entity(entity_id);
+
+            for &group_id in new_group_ids.difference(&old_group_ids) {
+                self.activities
+                    .add_entity_to_activities_with_group(group_id, &entity);
+            }
+
+            let left_group_ids = old_group_ids
+                .difference(&new_group_ids)
+                .copied()
+                .collect::<BTreeSet<_>>();
+            for id in self.ids_of_activities_in_which_entity_is_participating_only_through_groups(
+                entity_id,
+                &left_group_ids,
+            ) {
+                self.remove_entity_from_activity(id, entity.name())?;
+            }
+        }
+        Ok(())
+    }
 }
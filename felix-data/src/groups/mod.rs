@@ -25,6 +25,48 @@ impl Data {
         self.groups.get_by_name(&clean_string(name)?)
     }
 
+    /// Returns the names of the entities which were added to the group with the formatted
+    /// given name, sorted by name. Members of its subgroups are not included.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the formatted name is empty or if the group does not exist.
+    pub fn entities_of_group<S>(&self, name: S) -> Result<Vec<String>>
+    where
+        S: Into<String>,
+    {
+        Ok(self.entities.names_sorted(self.group(name)?.entity_ids()))
+    }
+
+    /// Returns the names of the groups which were added to the group with the formatted
+    /// given name, sorted by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the formatted name is empty or if the group does not exist.
+    pub fn subgroups_of_group<S>(&self, name: S) -> Result<Vec<String>>
+    where
+        S: Into<String>,
+    {
+        Ok(self.groups.names_sorted(self.group(name)?.subgroup_ids()))
+    }
+
+    /// Returns the names of all members of the group with the formatted given name:
+    /// its entities and the members of its subgroups, recursively. Sorted by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the formatted name is empty or if the group does not exist.
+    pub fn members_of_group<S>(&self, name: S) -> Result<Vec<String>>
+    where
+        S: Into<String>,
+    {
+        let group = self.group(name)?;
+        Ok(self
+            .entities
+            .names_sorted(&self.groups.member_ids(group.id())))
+    }
+
     /// Adds a new group with the given formatted name.
     ///
     /// # Errors
@@ -38,10 +80,8 @@ impl Data {
         let name = clean_string(name)?;
         self.check_name_taken_by_entity(&name)?;
 
-        self.groups.add(name.clone())?;
-        let group = self
-            .group(&name)
-            .expect("Group was just added so it should exist");
+        let id = self.groups.add(name.clone())?;
+        let group = self.groups.get_by_id(id);
         self.events().borrow_mut().emit_group_added(self, &group);
         Ok(name)
     }
@@ -49,85 +89,116 @@ impl Data {
     /// Removes a group with the given formatted name.
     ///
     /// If the group is taking part in any activity, it is removed from them.
+    /// It is removed from the groups which contain it.
+    /// Its members no longer work during the work hours of the group.
     ///
     /// # Errors
     ///
-    /// Returns Err if the group does not exist or if the formatted name is empty.
+    /// Returns Err if the group does not exist, if the formatted name is empty,
+    /// or if a member would not have enough time without the work hours of the group.
     pub fn remove_group<S>(&mut self, name: S) -> Result<()>
     where
         S: Into<String>,
     {
-        let name = clean_string(name)?;
-        let position_of_removed_group = self
-            .groups_sorted()
-            .into_iter()
-            .position(|group| group.name() == name);
-
-        // Remove group in all activities
-        for id in self
-            .activities_not_sorted()
-            .iter()
-            .map(|activity| activity.id())
-            .collect::<Vec<_>>()
-        {
-            // If the group is already out of the activity, ok
-            let _ = self.remove_group_from_activity(id, &name);
-        }
-
-        self.groups.remove(&name)?;
-        let position_of_removed_group =
-            position_of_removed_group.expect("Group was removed so it should have existed");
-
-        self.events()
-            .borrow_mut()
-            .emit_group_removed(self, position_of_removed_group);
-        Ok(())
+        self.record_as_single_step(|data| {
+            let name = clean_string(name)?;
+            let position_of_removed_group = data
+                .groups_sorted()
+                .into_iter()
+                .position(|group| group.name() == name);
+            let mut new_groups = data.groups.clone();
+            let id = new_groups.remove(&name)?;
+            let member_ids = data.groups.member_ids(id);
+            let members_with_new_work_hours =
+                data.check_members_allow_new_groups(&new_groups, &member_ids, &name)?;
+
+            // Remove group in all activities
+            for activity_id in data
+                .activities_not_sorted()
+                .iter()
+                .map(|activity| activity.id())
+                .collect::<Vec<_>>()
+            {
+                // If the group is already out of the activity, ok
+                let _ = data.remove_group_from_activity(activity_id, &name);
+            }
+
+            data.apply_new_groups(new_groups, &member_ids)?;
+            data.work_hours.remove_group_work_hours_of(id);
+            data.activities.remove_group_from_templates(id);
+            let position_of_removed_group =
+                position_of_removed_group.expect("Group was removed so it should have existed");
+
+            data.events()
+                .borrow_mut()
+                .emit_group_removed(data, position_of_removed_group);
+            if !members_with_new_work_hours.is_empty() {
+                data.notify_work_hours_of_entities_changed(members_with_new_work_hours);
+            }
+            Ok(())
+        })
     }
 
     /// Adds the entity with the given name to the group with the given name.
     /// Every name is formatted before use.
     ///
+    /// The entity also becomes a member of the groups which contain the group.
+    ///
     /// # Errors
     ///
     /// Returns Err if the group does not exist, if any formatted name is empty,
     /// if the entity does not have enough time for the activities of the group,
+    /// if the entity would not have enough time with the work hours of the group,
+    /// if the entity would exceed its load limits or those of the group,
     /// if the entity does not exist or if the entity is already part of the group.
     pub fn add_entity_to_group<S1, S2>(&mut self, group_name: S1, entity_name: S2) -> Result<()>
     where
         S1: Into<String>,
         S2: Into<String>,
     {
-        // Check if the entity exists and format name
-        let entity_name = self.entity(entity_name)?.name();
-        let group_name = clean_string(group_name)?;
-
-        // If the groups takes part in activities in which the entity does not,
-        // we need to make sure the entity has time for them.
-        self.check_has_enough_time_for_group(&group_name, &entity_name)?;
-        self.groups
-            .add_entity_to_group(&group_name, entity_name.clone())?;
-        // Add the entity to every activity of the group
-        self.activities
-            .add_entity_to_activities_with_group(&group_name, entity_name);
-        let group = self
-            .group(&group_name)
-            .expect("We just added an entity, therefore the group exists");
-        self.events()
-            .borrow_mut()
-            .emit_entity_added_to_group(self, &group);
-        Ok(())
+        self.record_as_single_step(|data| {
+            // Check if the entity exists and format name
+            let entity = data.entity(entity_name)?;
+            let entity_name = entity.name();
+            let group_name = clean_string(group_name)?;
+
+            let mut new_groups = data.groups.clone();
+            new_groups.add_entity_to_group(&group_name, &entity)?;
+            let member_ids = std::iter::once(entity.id()).collect();
+            // If the group has work hours, they may apply to the entity.
+            // If the group takes part in activities in which the entity does not,
+            // we need to make sure the entity has time for them.
+            let work_hours_changed = !data
+                .check_members_allow_new_groups(&new_groups, &member_ids, &group_name)?
+                .is_empty();
+            data.apply_new_groups(new_groups, &member_ids)?;
+
+            let group = data
+                .group(&group_name)
+                .expect("We just added an entity, therefore the group exists");
+            data.events()
+                .borrow_mut()
+                .emit_entity_added_to_group(data, &group);
+            if work_hours_changed {
+                data.notify_work_hours_of_entities_changed(vec![entity_name]);
+            }
+            Ok(())
+        })
     }
 
     /// Removes the entity with the given name from the group with the given name.
     /// Every name is formatted before use.
     ///
-    /// The entity is removed from any activity in which its group participates,
-    /// unless it is participating in the activity through another group.
+    /// The entity also leaves the groups which contain the group, unless it is still a member
+    /// of them through another group.
+    /// The entity is removed from any activity in which it participates only through the
+    /// groups it left.
     ///
     /// # Errors
     ///
     /// Returns Err if the group does not exist, if any formatted name is empty,
-    /// if the entity does not exist or if the entity is not part of the group.
+    /// if the entity does not exist, if the entity is not part of the group
+    /// or if the entity would not have enough time without the work hours of the group.
     pub fn remove_entity_from_group<S1, S2>(
         &mut self,
         group_name: S1,
@@ -137,35 +208,124 @@ impl Data {
         S1: Into<String>,
         S2: Into<String>,
     {
-        // Check if the entity exists & format name
-        let entity_name = self.entity(entity_name)?.name();
-        let group_name = self.group(group_name)?.name();
-
-        self.groups
-            .remove_entity_from_group(&group_name, &entity_name)?;
-
-        // Remove the entity from activities in which it is participating through the group
-        let activity_ids_in_which_to_remove_entity = self
-            .ids_of_activities_in_which_entity_is_participating_only_through_this_group(
-                &entity_name,
-                &group_name,
-            );
-        for id in activity_ids_in_which_to_remove_entity {
-            self.remove_entity_from_activity(id, &entity_name)?;
-        }
-        let group = self
-            .group(&group_name)
-            .expect("We just removed an entity, therefore the group exists");
-        self.events()
-            .borrow_mut()
-            .emit_entity_removed_from_group(self, &group);
-        Ok(())
+        self.record_as_single_step(|data| {
+            // Check if the entity exists & format name
+            let entity = data.entity(entity_name)?;
+            let entity_name = entity.name();
+            let group_name = data.group(group_name)?.name();
+
+            let mut new_groups = data.groups.clone();
+            new_groups.remove_entity_from_group(&group_name, &entity)?;
+            let member_ids = std::iter::once(entity.id()).collect();
+            let work_hours_changed = !data
+                .check_members_allow_new_groups(&new_groups, &member_ids, &group_name)?
+                .is_empty();
+            data.apply_new_groups(new_groups, &member_ids)?;
+
+            let group = data
+                .group(&group_name)
+                .expect("We just removed an entity, therefore the group exists");
+            data.events()
+                .borrow_mut()
+                .emit_entity_removed_from_group(data, &group);
+            if work_hours_changed {
+                data.notify_work_hours_of_entities_changed(vec![entity_name]);
+            }
+            Ok(())
+        })
+    }
+
+    /// Adds the group with the given subgroup name to the group with the given name.
+    /// Every name is formatted before use.
+    ///
+    /// The members of the subgroup become members of the group and of the groups which
+    /// contain it. They take part in the activities of these groups.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if one of the groups does not exist, if any formatted name is empty,
+    /// if the subgroup is already part of the group,
+    /// if the group is the subgroup or one of its members (a group cannot contain itself),
+    /// if a member would not have enough time for the activities or with the work hours
+    /// of its new groups or if a member would exceed its load limits.
+    pub fn add_group_to_group<S1, S2>(&mut self, group_name: S1, subgroup_name: S2) -> Result<()>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        self.record_as_single_step(|data| {
+            let group_name = data.group(group_name)?.name();
+            let subgroup = data.group(subgroup_name)?;
+
+            let mut new_groups = data.groups.clone();
+            new_groups.add_group_to_group(&group_name, &subgroup)?;
+            let member_ids = data.groups.member_ids(subgroup.id());
+            let members_with_new_work_hours =
+                data.check_members_allow_new_groups(&new_groups, &member_ids, &group_name)?;
+            data.apply_new_groups(new_groups, &member_ids)?;
+
+            let group = data
+                .group(&group_name)
+                .expect("We just added a subgroup, therefore the group exists");
+            data.events()
+                .borrow_mut()
+                .emit_entity_added_to_group(data, &group);
+            if !members_with_new_work_hours.is_empty() {
+                data.notify_work_hours_of_entities_changed(members_with_new_work_hours);
+            }
+            Ok(())
+        })
+    }
+
+    /// Removes the group with the given subgroup name from the group with the given name.
+    /// Every name is formatted before use.
+    ///
+    /// The members of the subgroup leave the group and the groups which contain it, unless
+    /// they are still members of them through another group. They are removed from any
+    /// activity in which they participate only through the groups they left.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if one of the groups does not exist, if any formatted name is empty,
+    /// if the subgroup is not part of the group
+    /// or if a member would not have enough time without the work hours of the group.
+    pub fn remove_group_from_group<S1, S2>(
+        &mut self,
+        group_name: S1,
+        subgroup_name: S2,
+    ) -> Result<()>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        self.record_as_single_step(|data| {
+            let group_name = data.group(group_name)?.name();
+            let subgroup = data.group(subgroup_name)?;
+
+            let mut new_groups = data.groups.clone();
+            new_groups.remove_group_from_group(&group_name, &subgroup)?;
+            let member_ids = data.groups.member_ids(subgroup.id());
+            let members_with_new_work_hours =
+                data.check_members_allow_new_groups(&new_groups, &member_ids, &group_name)?;
+            data.apply_new_groups(new_groups, &member_ids)?;
+
+            let group = data
+                .group(&group_name)
+                .expect("We just removed a subgroup, therefore the group exists");
+            data.events()
+                .borrow_mut()
+                .emit_entity_removed_from_group(data, &group);
+            if !members_with_new_work_hours.is_empty() {
+                data.notify_work_hours_of_entities_changed(members_with_new_work_hours);
+            }
+            Ok(())
+        })
     }
 
     /// Renames the group with the given name.
     /// Every name is formatted before use.
     ///
-    /// If the group is taking part in activities, it is renamed there as well.
+    /// Activities and work hours refer to the group by id, so they are not affected.
     /// Returns the formatted new name of the group.
     /// # Errors
     ///
@@ -179,13 +339,9 @@ impl Data {
         let new_name = clean_string(new_name)?;
         self.check_name_taken_by_entity(&new_name)?;
 
-        // First, rename in entities to check for any error
         let old_name = clean_string(old_name)?;
         self.groups.set_name_of(&old_name, new_name.clone())?;
 
-        // Then, rename in activities
-        self.activities
-            .rename_group_in_all(&old_name, new_name.clone());
         let group = self
             .group(&new_name)
             .expect("Group was renamed so it should exist");
@@ -0,0 +1,107 @@
+//! Undo and redo stacks of data.
+
+use felix_collections::{ActivitiesSnapshot, Entities, Groups, WorkHours};
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Maximum number of changes which can be undone.
+const MAX_UNDO_STEPS: usize = 100;
+
+/// Copy of every collection of data at a given time.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct DataState {
+    pub work_hours: WorkHours,
+    pub entities: Entities,
+    pub groups: Groups,
+    pub activities: ActivitiesSnapshot,
+}
+
+/// Keeps the previous states of data to undo and redo changes.
+///
+/// A change is recorded each time data emits an event, unless several changes
+/// are grouped into one step.
+#[derive(Debug, Default)]
+pub(crate) struct History {
+    /// State of data after the last recorded change.
+    current: DataState,
+    undo_stack: Vec<DataState>,
+    redo_stack: Vec<DataState>,
+    /// Number of groups of changes being recorded. Changes are recorded when it reaches 0.
+    group_depth: usize,
+}
+
+impl History {
+    /// Returns true if there is a change to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    /// Returns true if there is an undone change to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Returns true if changes are being grouped into one step.
+    pub fn is_grouping(&self) -> bool {
+        self.group_depth > 0
+    }
+
+    /// Records the given state as a new step if it differs from the current one.
+    /// Changes which were undone cannot be redone anymore.
+    pub fn record(&mut self, state: DataState) {
+        if state == self.current {
+            return;
+        }
+        let previous_state = std::mem::replace(&mut self.current, state);
+        self.undo_stack.push(previous_state);
+        if self.undo_stack.len() > MAX_UNDO_STEPS {
+            self.undo_stack.remove(0);
+        }
+        self.redo_stack.clear();
+    }
+
+    /// Returns the state before the last change, which becomes the current one.
+    pub fn undo(&mut self) -> Option<DataState> {
+        let previous_state = self.undo_stack.pop()?;
+        let undone_state = std::mem::replace(&mut self.current, previous_state.clone());
+        self.redo_stack.push(undone_state);
+        Some(previous_state)
+    }
+
+    /// Returns the state of the last undone change, which becomes the current one.
+    pub fn redo(&mut self) -> Option<DataState> {
+        let next_state = self.redo_stack.pop()?;
+        let redone_state = std::mem::replace(&mut self.current, next_state.clone());
+        self.undo_stack.push(redone_state);
+        Some(next_state)
+    }
+
+    /// Forgets every change and starts again from the given state.
+    pub fn clear(&mut self, current: DataState) {
+        self.current = current;
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+    }
+}
+
+/// Groups the changes recorded while it lives into one step.
+///
+/// The group ends when it is dropped, even if the changes panicked.
+pub(crate) struct HistoryGroup {
+    history: Rc<RefCell<History>>,
+}
+
+impl HistoryGroup {
+    /// Starts grouping changes into one step.
+    pub fn begin(history: Rc<RefCell<History>>) -> HistoryGroup {
+        history.borrow_mut().group_depth += 1;
+        HistoryGroup { history }
+    }
+}
+
+impl Drop for HistoryGroup {
+    fn drop(&mut self) {
+        self.history.borrow_mut().group_depth -= 1;
+    }
+}
@@ -0,0 +1,101 @@
+pub(crate) mod inner;
+
+use crate::Data;
+use inner::{DataState, HistoryGroup};
+
+/// Operations on the history of changes (undo and redo).
+///
+/// A change is recorded each time data emits an event. Operations which emit several events
+/// are grouped into one change with `record_as_single_step`.
+impl Data {
+    /// Returns true if there is a change to undo.
+    #[must_use]
+    pub fn can_undo(&self) -> bool {
+        self.history.borrow().can_undo()
+    }
+
+    /// Returns true if there is an undone change to redo.
+    #[must_use]
+    pub fn can_redo(&self) -> bool {
+        self.history.borrow().can_redo()
+    }
+
+    /// Restores the state of data before the last change.
+    /// Returns false if there is nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let previous_state = self.history.borrow_mut().undo();
+        if let Some(previous_state) = previous_state {
+            self.restore_state(previous_state);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Restores the state of data before the last undo.
+    /// Returns false if there is nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let next_state = self.history.borrow_mut().redo();
+        if let Some(next_state) = next_state {
+            self.restore_state(next_state);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Performs the given changes and records them as one change,
+    /// which is undone and redone at once.
+    pub fn record_as_single_step<T>(&mut self, changes: impl FnOnce(&mut Data) -> T) -> T {
+        let group = HistoryGroup::begin(self.history.clone());
+        let result = changes(self);
+        drop(group);
+        self.record_in_history();
+        result
+    }
+
+    /// Forgets every change. The current state cannot be undone.
+    pub fn clear_history(&mut self) {
+        let current_state = self.state();
+        self.history.borrow_mut().clear(current_state);
+    }
+
+    /// Records the current state as a new change if it was modified,
+    /// unless changes are being grouped.
+    /// Called each time an event is emitted.
+    pub(crate) fn record_in_history(&self) {
+        if self.history.borrow().is_grouping() {
+            return;
+        }
+        let current_state = self.state();
+        self.history.borrow_mut().record(current_state);
+    }
+
+    /// Returns a copy of the current state of data.
+    pub(crate) fn state(&self) -> DataState {
+        DataState {
+            work_hours: self.work_hours.clone(),
+            entities: self.entities.clone(),
+            groups: self.groups.clone(),
+            activities: self.activities.snapshot(),
+        }
+    }
+
+    /// Replaces the current state of data with the given one.
+    /// Does not compute the possible beginnings of activities nor emit events.
+    pub(crate) fn set_state(&mut self, state: DataState) {
+        self.work_hours = state.work_hours;
+        self.entities = state.entities;
+        self.groups = state.groups;
+        self.activities.restore(&state.activities);
+    }
+
+    /// Replaces the current state of data with the given one, then computes the possible
+    /// beginnings of every activity again.
+    fn restore_state(&mut self, state: DataState) {
+        self.set_state(state);
+        self.queue_every_activity_for_beginning_computation();
+
+        self.events().borrow_mut().emit_data_restored(self);
+    }
+}
@@ -11,33 +11,46 @@
 //! * Any organization with resources to manage (rooms, meetings...)
 
 mod activities;
+mod batch;
+mod commands;
+mod details;
 mod entities;
 mod events;
 mod export;
 mod groups;
 mod helpers;
+mod history;
+mod load_limits;
+mod serialization;
 mod work_hours;
 
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use batch::inner::Batch;
 use felix_collections::{Activities, Entities, Groups, WorkHours};
+use history::inner::History;
 
 use felix_datatypes::{ActivityBeginningMinutes, WorkHoursAndActivityDurationsSorted};
 
 pub use felix_datatypes::{
-    ActivityId, ActivityInsertionCosts, InsertionCost, Rgba, Time, TimeInterval,
-    MIN_TIME_DISCRETIZATION,
+    ActivityId, ActivityInsertionCosts, BreakRules, Details, Duration, DurationObjective, EntityId,
+    GroupId, InsertionCost, LoadLimits, MandatoryBreak, Rgba, TimeError, TimeInterval, TimeOfDay,
+    TimePreferences, MAX_TIME, MIN_TIME_DISCRETIZATION, ONE_DAY,
 };
 
 pub use felix_errors as errors;
 
-pub use felix_collections::{Activity, Entity, Group};
+pub use felix_collections::{
+    Activity, ActivityChain, ActivityFilter, ActivitySessions, ActivityTemplate, Entity, Group,
+    SessionRules,
+};
 
 pub use felix_computation_api::structs::AutoinsertionThreadHandle;
 
-pub use events::Events;
+pub use commands::Command;
+pub use events::{Event, Events};
 pub use helpers::clean_string;
 
 /// Stores, calculates and maintains coherency between entities, work hours and activities.
@@ -49,11 +62,11 @@ pub use helpers::clean_string;
 /// Add, remove and modify work intervals :
 ///
 /// ```
-/// use felix_data::{Data, Time, TimeInterval};
+/// use felix_data::{Data, TimeInterval, TimeOfDay};
 /// let mut data = Data::new();
 ///
-/// let morning_shift = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
-/// let afternoon_shift = TimeInterval::new(Time::new(14, 0), Time::new(18, 0));
+/// let morning_shift = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+/// let afternoon_shift = TimeInterval::new(TimeOfDay::new(14, 0), TimeOfDay::new(18, 0));
 ///
 /// // Intervals are automatically sorted, the order of addition does not matter
 /// data.add_work_interval(afternoon_shift).unwrap();
@@ -63,7 +76,7 @@ pub use helpers::clean_string;
 /// assert_eq!(work_hours[0], morning_shift);
 /// assert_eq!(work_hours[1], afternoon_shift);
 ///
-/// let new_morning_shift = TimeInterval::new(Time::new(9,0), Time::new(12, 0));
+/// let new_morning_shift = TimeInterval::new(TimeOfDay::new(9,0), TimeOfDay::new(12, 0));
 /// data.update_work_interval(morning_shift, new_morning_shift);
 ///
 /// data.remove_work_interval(new_morning_shift).unwrap();
@@ -74,12 +87,12 @@ pub use helpers::clean_string;
 /// Add, remove and modify entities :
 ///
 /// ```
-/// use felix_data::{Data, Time, TimeInterval};
+/// use felix_data::{Data, TimeInterval, TimeOfDay};
 /// let mut data = Data::new();
 ///
 /// let entity_name = data.add_entity("Bernard").unwrap();
 ///
-/// let custom_morning_shift = TimeInterval::new(Time::new(10, 0), Time::new(12, 0));
+/// let custom_morning_shift = TimeInterval::new(TimeOfDay::new(10, 0), TimeOfDay::new(12, 0));
 /// data.add_custom_work_interval_for(entity_name.clone(), custom_morning_shift);
 ///
 /// // new_name = "Jean" because set_entity_name formats it.
@@ -96,19 +109,20 @@ pub use helpers::clean_string;
 /// Add, remove and modify activities :
 ///
 /// ```
-/// use felix_data::{Data, Time, TimeInterval};
+/// use felix_data::{Data, Duration, TimeInterval, TimeOfDay};
 /// let mut data = Data::new();
 ///
 /// let activity_id = data.add_activity("My Activity").unwrap().id();
 /// let entity_name = data.add_entity("My Entity").unwrap();
 ///
-/// let morning_shift = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+/// let morning_shift = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
 /// data.add_work_interval(morning_shift).unwrap();
 ///
-/// data.set_activity_duration(activity_id, Time::new(1, 0));
+/// let duration: Duration = "1h".parse().unwrap();
+/// data.set_activity_duration(activity_id, duration);
 /// data.add_entity_to_activity(activity_id, entity_name);
 /// ```
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Debug)]
 pub struct Data {
     work_hours: WorkHours,
     entities: Entities,
@@ -116,6 +130,10 @@ pub struct Data {
     activities: Activities,
     #[serde(skip)]
     events: Rc<RefCell<Events>>,
+    #[serde(skip)]
+    history: Rc<RefCell<History>>,
+    #[serde(skip)]
+    batch: Option<Batch>,
 }
 
 impl Data {
@@ -127,6 +145,8 @@ impl Data {
             groups: Groups::new(),
             activities: Activities::new(),
             events: Rc::new(RefCell::new(Events::new())),
+            history: Rc::new(RefCell::new(History::default())),
+            batch: None,
         };
         data.init_computation_module();
         data
@@ -151,7 +171,7 @@ impl PartialEq for Data {
 
 impl Clone for Data {
     fn clone(&self) -> Self {
-        Data {
+        let mut data = Data {
             activities: self.activities.clone(),
             entities: self.entities.clone(),
             groups: self.groups.clone(),
@@ -159,6 +179,10 @@ impl Clone for Data {
 
             // We don't care about these, they don't hold actual data
             events: Rc::new(RefCell::new(Events::new())),
-        }
+            history: Rc::new(RefCell::new(History::default())),
+            batch: None,
+        };
+        data.clear_history();
+        data
     }
 }
@@ -0,0 +1,403 @@
+//! Helper functions for load limits implementation of data.
+
+use crate::errors::{
+    load_limit_exceeded::{ExceededLoadLimit, LoadLimitExceeded},
+    Result,
+};
+use crate::{Activity, ActivityId, Data, Duration, GroupId, LoadLimits};
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Load that an activity puts on each of its participants.
+///
+/// Sessions of a split activity each bring their duration but count as one activity
+/// of their category. Each occurrence of a recurring activity counts as an activity.
+/// Activities with a duration range bring their longest duration, which they may last
+/// once inserted, unless they are chained and always last their duration.
+#[derive(Debug, Clone)]
+struct ActivityLoad {
+    id: ActivityId,
+    main_session_id: ActivityId,
+    occurrence_index: usize,
+    duration: Duration,
+    category: Option<String>,
+}
+
+impl From<&Activity> for ActivityLoad {
+    fn from(activity: &Activity) -> Self {
+        ActivityLoad {
+            id: activity.id(),
+            main_session_id: activity.main_session_id(),
+            occurrence_index: activity
+                .sessions()
+                .filter(|sessions| sessions.is_recurring())
+                .and_then(|sessions| sessions.index_of(activity.id()))
+                .unwrap_or_default(),
+            duration: if activity.chain().is_some() {
+                activity.duration()
+            } else {
+                activity.max_duration()
+            },
+            category: activity.category(),
+        }
+    }
+}
+
+/// Returns the first load limit which is exceeded by the given activities, if any.
+fn first_exceeded_load_limit(
+    limits: &LoadLimits,
+    loads: &[ActivityLoad],
+) -> Option<ExceededLoadLimit> {
+    if let Some(max_activity_time) = limits.max_activity_time {
+        let activity_time: Duration = loads.iter().map(|load| load.duration).sum();
+        if activity_time > max_activity_time {
+            return Some(ExceededLoadLimit::ActivityTime);
+        }
+    }
+
+    let mut activities_per_category: BTreeMap<&String, BTreeSet<(ActivityId, usize)>> =
+        BTreeMap::new();
+    for load in loads {
+        if let Some(category) = &load.category {
+            activities_per_category
+                .entry(category)
+                .or_default()
+                .insert((load.main_session_id, load.occurrence_index));
+        }
+    }
+
+    activities_per_category
+        .into_iter()
+        .find(|(category, counted_activities)| {
+            limits
+                .max_activities_of_category(category)
+                .is_some_and(|max| counted_activities.len() > max)
+        })
+        .map(|(category, _)| ExceededLoadLimit::ActivitiesOfCategory(category.clone()))
+}
+
+impl Data {
+    /// Returns the load limits of the entity combined with the limits of its groups,
+    /// replacing the limits of the entity and of the given group with the given ones.
+    pub(super) fn load_limits_with(
+        &self,
+        entity_name: &str,
+        entity_limits: &LoadLimits,
+        replaced_group_limits: Option<(&str, &LoadLimits)>,
+    ) -> LoadLimits {
+        let entity_id = match self.entity(entity_name) {
+            Ok(entity) => entity.id(),
+            Err(_) => return entity_limits.clone(),
+        };
+        let group_ids = self.groups.ids_of_groups_containing_entity(entity_id);
+        self.groups_sorted()
+            .into_iter()
+            .filter(|group| group_ids.contains(&group.id()))
+            .fold(entity_limits.clone(), |limits, group| {
+                let group_limits = match replaced_group_limits {
+                    Some((group_name, group_limits)) if group_name == group.name() => group_limits,
+                    _ => group.load_limits(),
+                };
+                limits.combined_with(group_limits)
+            })
+    }
+
+    /// Returns the load of each activity of the entity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entity name is empty.
+    fn activity_loads_of(&self, entity_name: &str) -> Vec<ActivityLoad> {
+        self.activities_of(entity_name)
+            .expect("Entity name is empty - this is a bug")
+            .iter()
+            .map(ActivityLoad::from)
+            .collect()
+    }
+
+    /// Returns the first load limit exceeded by the entity with the given limits, if any.
+    pub(super) fn exceeded_load_limit_with(
+        &self,
+        entity_name: &str,
+        limits: &LoadLimits,
+    ) -> Option<ExceededLoadLimit> {
+        first_exceeded_load_limit(limits, &self.activity_loads_of(entity_name))
+    }
+
+    /// Checks that the entity does not exceed its load limits if it is added to the activity
+    /// with given id (and its other sessions).
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found or if a load limit would be exceeded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub(crate) fn check_load_limits_respected_with_activity(
+        &self,
+        activity_id: ActivityId,
+        entity_name: &str,
+    ) -> Result<()> {
+        let activity = self.activity(activity_id);
+        let entity_id = self.entity(entity_name)?.id();
+        if activity.entity_ids().contains(&entity_id) {
+            return Ok(());
+        }
+
+        let limits = self.load_limits_of(entity_name)?;
+        let mut loads = self.activity_loads_of(entity_name);
+        let session_ids = activity
+            .sessions()
+            .map_or(vec![activity_id], |sessions| sessions.session_ids().clone());
+        loads.extend(
+            session_ids
+                .into_iter()
+                .map(|id| ActivityLoad::from(&self.activity(id))),
+        );
+
+        match first_exceeded_load_limit(&limits, &loads) {
+            Some(limit) => Err(LoadLimitExceeded::activity_added_for(
+                entity_name,
+                limit,
+                activity.name(),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks that no participant of the activity exceeds its load limits if the longest
+    /// duration of the activity is set to the given duration.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if a load limit would be exceeded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub(crate) fn check_load_limits_respected_with_duration(
+        &self,
+        activity_id: ActivityId,
+        new_duration: Duration,
+    ) -> Result<()> {
+        let activity = self.activity(activity_id);
+        let occurrence_ids = self.occurrence_ids_of(activity_id);
+        for entity_name in self.entities_of_activity(activity_id) {
+            let limits = self
+                .load_limits_of(&entity_name)
+                .expect("Could not get entity participating in an activity");
+            let loads = self
+                .activity_loads_of(&entity_name)
+                .into_iter()
+                .map(|mut load| {
+                    // Every occurrence of a recurring activity gets longer
+                    if occurrence_ids.contains(&load.id) {
+                        load.duration = new_duration;
+                    }
+                    load
+                })
+                .collect::<Vec<_>>();
+
+            if let Some(limit) = first_exceeded_load_limit(&limits, &loads) {
+                return Err(LoadLimitExceeded::activity_duration_too_long_for(
+                    entity_name,
+                    limit,
+                    activity.name(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that no participant of the activity exceeds its load limits if the activity
+    /// takes place the given number of times.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if a load limit would be exceeded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub(crate) fn check_load_limits_respected_with_occurrences(
+        &self,
+        activity_id: ActivityId,
+        number_of_occurrences: usize,
+    ) -> Result<()> {
+        let activity = self.activity(activity_id);
+        let main_session_id = activity.main_session_id();
+        for entity_name in self.entities_of_activity(activity_id) {
+            let limits = self
+                .load_limits_of(&entity_name)
+                .expect("Could not get entity participating in an activity");
+            let mut loads = self
+                .activity_loads_of(&entity_name)
+                .into_iter()
+                .filter(|load| load.main_session_id != main_session_id)
+                .collect::<Vec<_>>();
+            loads.extend(
+                (0..number_of_occurrences).map(|occurrence_index| ActivityLoad {
+                    occurrence_index,
+                    ..ActivityLoad::from(&activity)
+                }),
+            );
+
+            if let Some(limit) = first_exceeded_load_limit(&limits, &loads) {
+                return Err(LoadLimitExceeded::occurrences_added_for(
+                    entity_name,
+                    limit,
+                    activity.name(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that no participant of the activity exceeds its load limits if the category
+    /// of the activity is set to the given category.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if a load limit would be exceeded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the activity with given ID does not exist.
+    pub(crate) fn check_load_limits_respected_with_category(
+        &self,
+        activity_id: ActivityId,
+        new_category: &Option<String>,
+    ) -> Result<()> {
+        let activity = self.activity(activity_id);
+        let main_session_id = activity.main_session_id();
+        for entity_name in self.entities_of_activity(activity_id) {
+            let limits = self
+                .load_limits_of(&entity_name)
+                .expect("Could not get entity participating in an activity");
+            let loads = self
+                .activity_loads_of(&entity_name)
+                .into_iter()
+                .map(|mut load| {
+                    if load.main_session_id == main_session_id {
+                        load.category = new_category.clone();
+                    }
+                    load
+                })
+                .collect::<Vec<_>>();
+
+            if let Some(limit) = first_exceeded_load_limit(&limits, &loads) {
+                return Err(LoadLimitExceeded::category_changed_for(
+                    entity_name,
+                    limit,
+                    activity.name(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the entity does not exceed its load limits if it joins the groups with
+    /// given ids, for instance when it is added to the group with given name.
+    /// The entity takes part in the activities of the joined groups and their limits
+    /// apply to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found or if a load limit would be exceeded.
+    pub(crate) fn check_load_limits_respected_in_groups(
+        &self,
+        group_name: &str,
+        entity_name: &str,
+        joined_group_ids: &BTreeSet<GroupId>,
+    ) -> Result<()> {
+        let entity_id = self.entity(entity_name)?.id();
+        let limits = joined_group_ids
+            .iter()
+            .map(|&group_id| self.groups.get_by_id(group_id))
+            .fold(self.load_limits_of(entity_name)?, |limits, group| {
+                limits.combined_with(group.load_limits())
+            });
+
+        let mut loads = self.activity_loads_of(entity_name);
+        loads.extend(
+            self.activities_not_sorted()
+                .iter()
+                .filter(|activity| {
+                    activity
+                        .group_ids()
+                        .iter()
+                        .any(|group_id| joined_group_ids.contains(group_id))
+                        && !activity.entity_ids().contains(&entity_id)
+                })
+                .map(ActivityLoad::from),
+        );
+
+        match first_exceeded_load_limit(&limits, &loads) {
+            Some(limit) => Err(LoadLimitExceeded::added_to_group(
+                entity_name,
+                limit,
+                group_name,
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks that the kept entity does not exceed its load limits once merged with the
+    /// removed entity. It has the given own limits, joins the groups with given ids
+    /// and takes part in the given added activities.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if a load limit would be exceeded.
+    pub(crate) fn check_load_limits_respected_when_merged(
+        &self,
+        kept_name: &str,
+        removed_name: &str,
+        kept_limits: &LoadLimits,
+        joined_group_ids: &BTreeSet<GroupId>,
+        added_activities: &[Activity],
+    ) -> Result<()> {
+        let limits = joined_group_ids
+            .iter()
+            .map(|&group_id| self.groups.get_by_id(group_id))
+            .fold(
+                self.load_limits_with(kept_name, kept_limits, None),
+                |limits, group| limits.combined_with(group.load_limits()),
+            );
+
+        let mut loads = self.activity_loads_of(kept_name);
+        loads.extend(added_activities.iter().map(ActivityLoad::from));
+
+        match first_exceeded_load_limit(&limits, &loads) {
+            Some(limit) => Err(LoadLimitExceeded::merged_with(
+                kept_name,
+                limit,
+                removed_name,
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks that no entity exceeds its load limits.
+    /// Entities can only exceed their limits if the data was modified outside of this API
+    /// (e.g. in a saved file).
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if an entity exceeds its load limits.
+    pub(crate) fn check_load_limits_respected(&self) -> Result<()> {
+        for entity in self.entities_sorted() {
+            let limits = self
+                .load_limits_of(entity.name())
+                .expect("Could not get existing entity");
+            if let Some(limit) = self.exceeded_load_limit_with(&entity.name(), &limits) {
+                return Err(LoadLimitExceeded::activities_inserted_for(
+                    entity.name(),
+                    limit,
+                ));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+mod inner;
+
+use crate::errors::{load_limit_exceeded::LoadLimitExceeded, Result};
+use crate::{Data, LoadLimits};
+
+/// Operations on load limits (maximum time spent in activities and maximum number of
+/// activities of each category).
+///
+/// Limits can be set for an entity or for a group. The limits of a group apply to each of
+/// its members, on top of their own limits.
+impl Data {
+    /// Returns the load limits which apply to the entity with the formatted given name,
+    /// its own limits and the limits of its groups combined.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found.
+    pub fn load_limits_of<S>(&self, entity_name: S) -> Result<LoadLimits>
+    where
+        S: Into<String>,
+    {
+        let entity = self.entity(entity_name)?;
+        Ok(self.load_limits_with(&entity.name(), entity.load_limits(), None))
+    }
+
+    /// Sets the load limits of the entity with the formatted given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found or if the entity already exceeds the new limits.
+    pub fn set_entity_load_limits<S>(
+        &mut self,
+        entity_name: S,
+        load_limits: LoadLimits,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let entity_name = self.entity(entity_name)?.name();
+        let limits = self.load_limits_with(&entity_name, &load_limits, None);
+        if let Some(limit) = self.exceeded_load_limit_with(&entity_name, &limits) {
+            return Err(LoadLimitExceeded::limits_lowered_for(entity_name, limit));
+        }
+
+        self.entities
+            .set_load_limits_of(&entity_name, load_limits)?;
+        self.events().borrow_mut().emit_load_limits_changed(self);
+        Ok(())
+    }
+
+    /// Sets the load limits of the group with the formatted given name.
+    /// These limits apply to each member of the group.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the group is not found or if a member of the group already exceeds
+    /// the new limits.
+    pub fn set_group_load_limits<S>(&mut self, group_name: S, load_limits: LoadLimits) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let group = self.group(group_name)?;
+        let group_name = group.name();
+        for entity_name in self
+            .entities
+            .names_sorted(&self.groups.member_ids(group.id()))
+        {
+            let entity = self
+                .entity(&entity_name)
+                .expect("Could not get entity which is part of a group");
+            let limits = self.load_limits_with(
+                &entity_name,
+                entity.load_limits(),
+                Some((&group_name, &load_limits)),
+            );
+            if let Some(limit) = self.exceeded_load_limit_with(&entity_name, &limits) {
+                return Err(LoadLimitExceeded::limits_lowered_for(entity_name, limit));
+            }
+        }
+
+        self.groups.set_load_limits_of(&group_name, load_limits)?;
+        self.events().borrow_mut().emit_load_limits_changed(self);
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+//! Deserialization of data, including the migration of files saved by older versions.
+//!
+//! Older versions referenced entities and groups by name everywhere.
+//! They are now referenced by id, so these files are migrated when loaded with
+//! `Data::from_json` : every entity and group is given an id (in alphabetical order)
+//! and every reference to its name is replaced by its id.
+//!
+//! Data saved by the current version can be loaded from any serde format
+//! with its `Deserialize` implementation, which does not migrate anything.
+
+use crate::{history::inner::History, Data, Events};
+use felix_collections::{Activities, Entities, Groups, WorkHours};
+
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Work hour maps which are keyed by entity.
+const WORK_HOURS_OF_ENTITIES: [&str; 6] = [
+    "custom_work_intervals",
+    "preferred_intervals",
+    "disliked_intervals",
+    "unavailable_intervals",
+    "assigned_work_hour_templates",
+    "custom_break_rules",
+];
+
+/// Work hour maps which are keyed by group.
+const WORK_HOURS_OF_GROUPS: [&str; 1] = ["group_work_intervals"];
+
+/// Serialized fields of Data.
+#[derive(Deserialize)]
+struct SerializedData {
+    work_hours: WorkHours,
+    entities: Entities,
+    groups: Groups,
+    activities: Activities,
+}
+
+impl<'de> Deserialize<'de> for Data {
+    fn deserialize<D>(deserializer: D) -> Result<Data, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let data = SerializedData::deserialize(deserializer)?;
+
+        let mut data = Data {
+            work_hours: data.work_hours,
+            entities: data.entities,
+            groups: data.groups,
+            activities: data.activities,
+            events: Rc::new(RefCell::new(Events::new())),
+            history: Rc::new(RefCell::new(History::default())),
+            batch: None,
+        };
+        data.clear_history();
+        Ok(data)
+    }
+}
+
+impl Data {
+    /// Loads data saved as JSON, including files saved by versions which referenced
+    /// entities and groups by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the given string is not valid JSON or does not describe data.
+    pub fn from_json(json: &str) -> serde_json::Result<Data> {
+        let mut value: Value = serde_json::from_str(json)?;
+        migrate_names_to_ids(&mut value);
+        Data::deserialize(value)
+    }
+}
+
+/// Replaces every reference to an entity or group name with its id
+/// if the data was saved by a version which referenced them by name.
+fn migrate_names_to_ids(data: &mut Value) {
+    let entity_ids = assign_ids(data.pointer_mut("/entities/entities"));
+    let group_ids = assign_ids(data.pointer_mut("/groups/groups"));
+    if entity_ids.is_empty() && group_ids.is_empty() {
+        return;
+    }
+
+    if let Some(Value::Object(groups)) = data.pointer_mut("/groups/groups") {
+        for group in groups.values_mut() {
+            replace_names_in_array(group.get_mut("entities"), &entity_ids);
+        }
+    }
+
+    if let Some(Value::Array(activities)) = data.pointer_mut("/activities/activities") {
+        for activity in activities {
+            replace_names_in_array(activity.pointer_mut("/metadata/entities"), &entity_ids);
+            replace_names_in_array(activity.pointer_mut("/metadata/groups"), &group_ids);
+            replace_names_in_keys(
+                activity.pointer_mut("/computation_data/break_rules_of_participants"),
+                &entity_ids,
+            );
+        }
+    }
+
+    if let Some(work_hours) = data.get_mut("work_hours") {
+        for field in &WORK_HOURS_OF_ENTITIES {
+            replace_names_in_keys(work_hours.get_mut(*field), &entity_ids);
+        }
+        for field in &WORK_HOURS_OF_GROUPS {
+            replace_names_in_keys(work_hours.get_mut(*field), &group_ids);
+        }
+    }
+}
+
+/// Gives an id to every element of the given collection which does not have one yet.
+/// Elements are numbered in alphabetical order and stored by id.
+///
+/// Returns the new id of each name. If the collection is already stored by id,
+/// returns an empty map.
+fn assign_ids(collection: Option<&mut Value>) -> HashMap<String, usize> {
+    let elements = match collection {
+        Some(Value::Object(elements)) => elements,
+        _ => return HashMap::new(),
+    };
+    if elements.values().all(|element| element.get("id").is_some()) {
+        return HashMap::new();
+    }
+
+    let mut names = elements.keys().cloned().collect::<Vec<_>>();
+    names.sort();
+    let ids = names
+        .into_iter()
+        .enumerate()
+        .map(|(id, name)| (name, id))
+        .collect::<HashMap<_, _>>();
+
+    *elements = std::mem::take(elements)
+        .into_iter()
+        .map(|(name, mut element)| {
+            let id = ids[&name];
+            if let Value::Object(fields) = &mut element {
+                fields.insert("id".to_owned(), Value::from(id));
+            }
+            (id.to_string(), element)
+        })
+        .collect::<Map<_, _>>();
+    ids
+}
+
+/// Replaces the names in the given array with their ids.
+/// Names without an id reference elements which do not exist anymore and are dropped.
+fn replace_names_in_array(array: Option<&mut Value>, ids: &HashMap<String, usize>) {
+    if let Some(Value::Array(names)) = array {
+        *names = names
+            .iter()
+            .filter_map(|name| name.as_str().and_then(|name| ids.get(name)))
+            .map(|&id| Value::from(id))
+            .collect();
+    }
+}
+
+/// Replaces the names used as keys of the given map with their ids.
+/// Names without an id reference elements which do not exist anymore and are dropped.
+fn replace_names_in_keys(map: Option<&mut Value>, ids: &HashMap<String, usize>) {
+    if let Some(Value::Object(map)) = map {
+        *map = std::mem::take(map)
+            .into_iter()
+            .filter_map(|(name, value)| ids.get(&name).map(|id| (id.to_string(), value)))
+            .collect();
+    }
+}
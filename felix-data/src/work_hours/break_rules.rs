@@ -0,0 +1,174 @@
+use crate::errors::{duration_too_short::DurationTooShort, Result};
+use crate::{BreakRules, Data, Duration, MandatoryBreak};
+
+/// Operations on break rules (maximum continuous work and mandatory breaks).
+///
+/// Global rules apply to every entity. Custom rules apply to one entity on top of the global
+/// ones.
+///
+/// When break rules change, the inserted activities whose participants would not have the breaks
+/// they need are removed from the schedule. As for work hours, they are inserted back in the
+/// closest spot once their possible beginnings are computed.
+impl Data {
+    /// Returns the break rules which apply to every entity.
+    #[must_use]
+    pub fn global_break_rules(&self) -> BreakRules {
+        self.work_hours.global_break_rules().clone()
+    }
+
+    /// Returns the break rules which apply only to the entity with the formatted given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found.
+    pub fn custom_break_rules_of<S>(&self, entity_name: S) -> Result<BreakRules>
+    where
+        S: Into<String>,
+    {
+        let entity_id = self.entity(entity_name)?.id();
+        Ok(self.work_hours.custom_break_rules_of(entity_id))
+    }
+
+    /// Returns the break rules which apply to the entity with the formatted given name,
+    /// global and custom rules combined.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found.
+    pub fn break_rules_of<S>(&self, entity_name: S) -> Result<BreakRules>
+    where
+        S: Into<String>,
+    {
+        let entity_id = self.entity(entity_name)?.id();
+        Ok(self.work_hours.break_rules_of(entity_id))
+    }
+
+    /// Sets the maximum time any entity can spend in back-to-back activities.
+    /// None means no limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the maximum is null.
+    pub fn set_max_continuous_work(&mut self, max_continuous_work: Option<Duration>) -> Result<()> {
+        check_max_continuous_work_not_null(max_continuous_work)?;
+        self.work_hours.set_max_continuous_work(max_continuous_work);
+        self.notify_break_rules_changed(None);
+        Ok(())
+    }
+
+    /// Adds a mandatory break for every entity.
+    ///
+    /// # Errors
+    ///
+    /// Does not fail for now; returns a Result like the other setters of break rules.
+    pub fn add_mandatory_break(&mut self, mandatory_break: MandatoryBreak) -> Result<()> {
+        self.work_hours.add_mandatory_break(mandatory_break);
+        self.notify_break_rules_changed(None);
+        Ok(())
+    }
+
+    /// Removes a mandatory break which applies to every entity.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the mandatory break is not found.
+    pub fn remove_mandatory_break(&mut self, mandatory_break: MandatoryBreak) -> Result<()> {
+        self.work_hours.remove_mandatory_break(mandatory_break)?;
+        self.notify_break_rules_changed(None);
+        Ok(())
+    }
+
+    /// Sets the maximum time the entity with the formatted given name can spend in back-to-back
+    /// activities. None means that only the global limit applies.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found or if the maximum is null.
+    pub fn set_max_continuous_work_for<S>(
+        &mut self,
+        entity_name: S,
+        max_continuous_work: Option<Duration>,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let entity = self.entity(entity_name)?;
+        check_max_continuous_work_not_null(max_continuous_work)?;
+        self.work_hours
+            .set_max_continuous_work_for(entity.id(), max_continuous_work);
+        self.notify_break_rules_changed(Some(entity.name()));
+        Ok(())
+    }
+
+    /// Adds a mandatory break for the entity with the formatted given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found.
+    pub fn add_mandatory_break_for<S>(
+        &mut self,
+        entity_name: S,
+        mandatory_break: MandatoryBreak,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let entity = self.entity(entity_name)?;
+        self.work_hours
+            .add_mandatory_break_for(entity.id(), mandatory_break);
+        self.notify_break_rules_changed(Some(entity.name()));
+        Ok(())
+    }
+
+    /// Removes a mandatory break from the entity with the formatted given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found or if the mandatory break is not found.
+    pub fn remove_mandatory_break_for<S>(
+        &mut self,
+        entity_name: S,
+        mandatory_break: MandatoryBreak,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let entity = self.entity(entity_name)?;
+        self.work_hours
+            .remove_mandatory_break_for(entity.id(), mandatory_break)?;
+        self.notify_break_rules_changed(Some(entity.name()));
+        Ok(())
+    }
+
+    /// Removes the activities which no longer respect the break rules from the schedule,
+    /// emits the work hours changed event and updates the insertion costs of the activities
+    /// of the concerned entity, or of every entity if None is given.
+    fn notify_break_rules_changed(&mut self, entity_name: Option<String>) {
+        self.record_as_single_step(|data| {
+            data.uninsert_activities_breaking_break_rules();
+            data.events().borrow_mut().emit_work_hours_changed(data);
+            let entities_to_queue = if let Some(entity_name) = entity_name {
+                vec![entity_name]
+            } else {
+                data.entities_sorted()
+                    .iter()
+                    .map(|entity| entity.name())
+                    .collect()
+            };
+            data.queue_entities(entities_to_queue);
+        })
+    }
+}
+
+/// Checks that the maximum continuous work is not null.
+///
+/// # Errors
+///
+/// Returns Err if the maximum continuous work is Some(00:00).
+fn check_max_continuous_work_not_null(max_continuous_work: Option<Duration>) -> Result<()> {
+    if max_continuous_work == Some(Duration::new(0, 0)) {
+        Err(DurationTooShort::new())
+    } else {
+        Ok(())
+    }
+}
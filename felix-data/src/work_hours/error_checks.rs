@@ -1,7 +1,7 @@
 //! Helper functions for work_hours implementation of data.
 
 use crate::errors::{does_not_exist::DoesNotExist, not_enough_time::NotEnoughTime, Result};
-use crate::Time;
+use crate::Duration;
 use crate::{Data, TimeInterval};
 
 impl Data {
@@ -12,8 +12,8 @@ impl Data {
     /// Returns Err if an entity is found.
     pub(super) fn check_entity_without_enough_time_to_update_interval(
         &self,
-        old_duration: Time,
-        new_duration: Time,
+        old_duration: Duration,
+        new_duration: Duration,
     ) -> Result<()> {
         if new_duration >= old_duration {
             Ok(())
@@ -34,7 +34,7 @@ impl Data {
     /// Returns Err if an entity is found.
     pub(super) fn check_entity_without_enough_time_to_remove_interval(
         &self,
-        interval_duration: Time,
+        interval_duration: Duration,
     ) -> Result<()> {
         if let Some(entity_name) = self.entity_with_free_time_less_than(interval_duration) {
             Err(NotEnoughTime::work_hours_shortened_for(entity_name))
@@ -45,7 +45,7 @@ impl Data {
 
     /// Given a required duration, returns the first entity which has less free time.
     #[must_use]
-    fn entity_with_free_time_less_than(&self, required_free_time: Time) -> Option<String> {
+    fn entity_with_free_time_less_than(&self, required_free_time: Duration) -> Option<String> {
         self.entities_sorted()
             .iter()
             .map(|entity| entity.name())
@@ -66,16 +66,18 @@ impl Data {
     pub(super) fn check_entity_will_have_enough_time_with_custom_interval(
         &self,
         entity_name: &str,
-        interval_duration: Time,
+        interval: TimeInterval,
     ) -> Result<()> {
         if self
             .custom_work_hours_of(entity_name)? // Check if entity exists here
             .is_empty()
         {
-            let activity_duration = self.time_taken_by_activities(&entity_name);
-            if interval_duration < activity_duration {
-                return Err(NotEnoughTime::work_hours_shortened_for(entity_name));
-            }
+            // The interval replaces the global or template work hours
+            self.check_entity_will_have_enough_time_with(
+                entity_name,
+                vec![interval],
+                &self.unavailable_intervals_of(entity_name)?,
+            )?;
         }
         Ok(())
     }
@@ -106,37 +108,29 @@ impl Data {
     ///
     /// Returns Err if the entity name is empty, the entity is not found or the entity will not
     /// have enough free time.
-    ///
-    /// # Panics
-    ///
-    /// Panics if interval\_duration is greater than the custom work hours's total duration.
     pub(super) fn check_entity_will_have_enough_time_after_deletion_of_interval(
         &self,
         entity_name: &str,
-        interval_duration: Time,
+        interval: TimeInterval,
     ) -> Result<()> {
         // Check if the entity has enough free time
         let custom_work_hours = self.custom_work_hours_of(entity_name)?;
-        let entity_time = if custom_work_hours.len() == 1 {
+        let work_hours = if custom_work_hours.len() == 1 {
             // This is the last custom work hours.
-            // We should check that the global work hours will suffice.
-            self.work_hours()
-                .iter()
-                .map(|interval| interval.duration())
-                .sum()
+            // We should check that the global or template work hours will suffice.
+            self.work_hours_without_custom_intervals_of(entity_name)
         } else {
             // We should check that the remaining custom work hours will suffice.
-            let time_before_deletion: Time = custom_work_hours
-                .iter()
-                .map(|interval| interval.duration())
-                .sum();
-            time_before_deletion - interval_duration
+            custom_work_hours
+                .into_iter()
+                .filter(|&custom_interval| custom_interval != interval)
+                .collect()
         };
-        if entity_time < self.time_taken_by_activities(entity_name) {
-            Err(NotEnoughTime::work_hours_shortened_for(entity_name))
-        } else {
-            Ok(())
-        }
+        self.check_entity_will_have_enough_time_with(
+            entity_name,
+            work_hours,
+            &self.unavailable_intervals_of(entity_name)?,
+        )
     }
 
     /// Checks if the entity will have enough time after time interval update.
@@ -148,8 +142,8 @@ impl Data {
     pub(super) fn check_entity_will_have_enough_time_after_update(
         &self,
         entity_name: &str,
-        old_duration: Time,
-        new_duration: Time,
+        old_duration: Duration,
+        new_duration: Duration,
     ) -> Result<()> {
         if new_duration >= old_duration {
             Ok(())
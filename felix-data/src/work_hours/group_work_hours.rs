@@ -0,0 +1,256 @@
+use super::inner::{total_duration, without_intervals};
+use crate::errors::{does_not_exist::DoesNotExist, Result};
+use crate::{Data, Duration, GroupId, TimeInterval};
+
+/// Operations on the work hours of groups.
+///
+/// The work hours of a group apply to every member which has neither custom work hours
+/// nor a work hour template, instead of the global work hours.
+/// If an entity is a member of several groups with work hours, it works during the intervals
+/// shared by all of them.
+impl Data {
+    /// Returns the work hours of the group with the formatted given name.
+    ///
+    /// If the group does not have work hours, the resulting vector will be empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the group is not found.
+    pub fn group_work_hours_of<S>(&self, group_name: S) -> Result<Vec<TimeInterval>>
+    where
+        S: Into<String>,
+    {
+        let group_id = self.group(group_name)?.id();
+        Ok(self.work_hours.group_work_intervals_of(group_id))
+    }
+
+    /// Adds a work interval to the group with the formatted given name.
+    /// The insertion costs of the activities of every member of the group are updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the group is not found,
+    /// if the interval overlaps with the other work intervals of the group
+    /// or if a member of the group would not have enough time left.
+    pub fn add_group_work_interval_for<S>(
+        &mut self,
+        group_name: S,
+        interval: TimeInterval,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let group = self.group(group_name)?;
+        let group_name = group.name();
+        let mut work_hours = self.work_hours.group_work_intervals_of(group.id());
+        work_hours.push(interval);
+        work_hours.sort_by_key(TimeInterval::beginning);
+        self.check_members_of_group_will_have_enough_time_with(&group_name, &work_hours)?;
+
+        self.work_hours
+            .add_group_work_interval_for(group.id(), interval)?;
+        self.notify_group_work_hours_changed(&group_name);
+        Ok(())
+    }
+
+    /// Removes the given work interval from the group with the formatted given name.
+    /// The insertion costs of the activities of every member of the group are updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the group or the interval is not found
+    /// or if a member of the group would not have enough time left.
+    pub fn remove_group_work_interval_for<S>(
+        &mut self,
+        group_name: S,
+        interval: TimeInterval,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let group = self.group(group_name)?;
+        let group_name = group.name();
+        let work_hours = self.check_group_has_work_interval(group.id(), interval)?;
+        let remaining_work_hours = work_hours
+            .into_iter()
+            .filter(|&other| other != interval)
+            .collect::<Vec<_>>();
+        self.check_members_of_group_will_have_enough_time_with(&group_name, &remaining_work_hours)?;
+
+        self.work_hours
+            .remove_group_work_interval_for(group.id(), interval)?;
+        self.notify_group_work_hours_changed(&group_name);
+        Ok(())
+    }
+
+    /// Replaces the given work interval of the group with the formatted given name.
+    /// The insertion costs of the activities of every member of the group are updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the group or the interval is not found,
+    /// if the new interval overlaps with the other work intervals of the group
+    /// or if a member of the group would not have enough time left.
+    pub fn update_group_work_interval_for<S>(
+        &mut self,
+        group_name: S,
+        old_interval: TimeInterval,
+        new_interval: TimeInterval,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let group = self.group(group_name)?;
+        let group_name = group.name();
+        let work_hours = self.check_group_has_work_interval(group.id(), old_interval)?;
+        let mut updated_work_hours = work_hours
+            .into_iter()
+            .map(|other| {
+                if other == old_interval {
+                    new_interval
+                } else {
+                    other
+                }
+            })
+            .collect::<Vec<_>>();
+        updated_work_hours.sort_by_key(TimeInterval::beginning);
+        self.check_members_of_group_will_have_enough_time_with(&group_name, &updated_work_hours)?;
+
+        self.work_hours
+            .update_group_work_interval_for(group.id(), old_interval, new_interval)?;
+        self.notify_group_work_hours_changed(&group_name);
+        Ok(())
+    }
+
+    /// Checks that the work hours of the entity allow it to be a member of exactly the given
+    /// groups : if its work hours change, the entity must still have enough time
+    /// for its activities.
+    ///
+    /// Returns true if the work hours of the entity change.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity does not exist
+    /// or if it would not have enough time with its new work hours.
+    pub(crate) fn check_work_hours_allow_groups_of(
+        &self,
+        entity_name: &str,
+        group_names: &[String],
+    ) -> Result<bool> {
+        if !self.custom_work_hours_of(entity_name)?.is_empty() {
+            return Ok(false);
+        }
+        let new_work_hours =
+            self.work_hours_with_groups_of(entity_name, self.work_hours_of_groups(group_names));
+        if new_work_hours == self.work_hours_without_custom_intervals_of(entity_name) {
+            Ok(false)
+        } else {
+            self.check_entity_will_have_enough_time_with(
+                entity_name,
+                new_work_hours,
+                &self.unavailable_intervals_of(entity_name)?,
+            )?;
+            Ok(true)
+        }
+    }
+
+    /// Returns the free time the entity would have if it were a member of exactly the given
+    /// groups.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity does not exist.
+    pub(crate) fn free_time_with_groups_of(
+        &self,
+        entity_name: &str,
+        group_names: &[String],
+    ) -> Result<Duration> {
+        let custom_work_hours = self.custom_work_hours_of(entity_name)?;
+        let work_hours = if custom_work_hours.is_empty() {
+            self.work_hours_with_groups_of(entity_name, self.work_hours_of_groups(group_names))
+        } else {
+            custom_work_hours
+        };
+        let total_duration = total_duration(&without_intervals(
+            work_hours,
+            &self.unavailable_intervals_of(entity_name)?,
+        ));
+        let activity_duration = self.time_taken_by_activities(entity_name);
+        Ok(if total_duration < activity_duration {
+            Duration::new(0, 0)
+        } else {
+            total_duration - activity_duration
+        })
+    }
+
+    /// Checks that the group has the given work interval.
+    /// Returns the work hours of the group.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the group does not have the given work interval.
+    fn check_group_has_work_interval(
+        &self,
+        group_id: GroupId,
+        interval: TimeInterval,
+    ) -> Result<Vec<TimeInterval>> {
+        let work_hours = self.work_hours.group_work_intervals_of(group_id);
+        if work_hours.contains(&interval) {
+            Ok(work_hours)
+        } else {
+            Err(DoesNotExist::interval_does_not_exist(interval))
+        }
+    }
+
+    /// Checks that every member of the group would still have enough time if the group had
+    /// the given work hours.
+    /// Members with custom work hours or with a template are not concerned.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if a member would not have enough time.
+    fn check_members_of_group_will_have_enough_time_with(
+        &self,
+        group_name: &str,
+        work_hours: &[TimeInterval],
+    ) -> Result<()> {
+        let group = self.group(group_name)?;
+        for entity_name in self
+            .entities
+            .names_sorted(&self.groups.member_ids(group.id()))
+        {
+            if self.custom_work_hours_of(&entity_name)?.is_empty() {
+                let group_work_hours = self
+                    .group_names_of(&entity_name)
+                    .iter()
+                    .map(|other_group_name| {
+                        if other_group_name == group_name {
+                            work_hours.to_vec()
+                        } else {
+                            self.group_work_hours_of(other_group_name)
+                                .expect("Could not get group of which the entity is a member")
+                        }
+                    })
+                    .collect();
+                self.check_entity_will_have_enough_time_with(
+                    &entity_name,
+                    self.work_hours_with_groups_of(&entity_name, group_work_hours),
+                    &self.unavailable_intervals_of(&entity_name)?,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Emits the work hours changed event and updates the insertion costs of the activities
+    /// of the members of the group.
+    fn notify_group_work_hours_changed(&mut self, group_name: &str) {
+        let group = self
+            .group(group_name)
+            .expect("Group work hours changed so the group exists");
+        let members = self
+            .entities
+            .names_sorted(&self.groups.member_ids(group.id()));
+        self.notify_work_hours_of_entities_changed(members);
+    }
+}
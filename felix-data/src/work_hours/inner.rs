@@ -1,36 +1,92 @@
-use crate::errors::{
-    change_work_hours_while_activity_inserted::ChangeWorkHoursWhileActivityInserted, Result,
-};
-use crate::Data;
-use crate::Time;
+use crate::errors::{not_enough_time::NotEnoughTime, Result};
+use crate::Duration;
+use crate::{Data, EntityId, TimeInterval};
+
+use felix_collections::WorkHours;
 
 impl Data {
     pub(super) fn notify_work_hours_changed(&mut self) {
-        self.events().borrow_mut().emit_work_hours_changed(self);
-        self.queue_entities_on_global_work_hour_change();
+        self.record_as_single_step(|data| {
+            data.uninsert_activities_outside_of_work_hours();
+            data.events().borrow_mut().emit_work_hours_changed(data);
+            data.queue_entities_on_global_work_hour_change();
+        })
     }
 
-    pub(super) fn check_no_activity_inserted(&self) -> Result<()> {
-        if self
-            .activities_not_sorted()
-            .iter()
-            .any(|activity| activity.insertion_interval().is_some())
-        {
-            Err(ChangeWorkHoursWhileActivityInserted::new())
-        } else {
-            Ok(())
+    /// Removes the activities which no longer fit from the schedule, emits the work hours
+    /// changed event and updates the insertion costs of the activities of the given entities.
+    pub(crate) fn notify_work_hours_of_entities_changed(&mut self, entities: Vec<String>) {
+        self.record_as_single_step(|data| {
+            data.uninsert_activities_outside_of_work_hours();
+            data.events().borrow_mut().emit_work_hours_changed(data);
+            data.queue_entities(entities);
+        })
+    }
+
+    /// Removes from the schedule the inserted activities which no longer fit in the work hours
+    /// of all of their participants.
+    ///
+    /// Their beginnings are remembered so that they can be inserted back in the closest spot
+    /// once their possible beginnings are computed, as is done when the duration of an activity
+    /// increases.
+    pub(crate) fn uninsert_activities_outside_of_work_hours(&mut self) {
+        for activity in self.activities_sorted() {
+            // The activity may have been removed along with a simultaneous or chained activity
+            if let Some(insertion_interval) = self.activity(activity.id()).insertion_interval() {
+                let participants = self.entities.names_sorted(&activity.entity_ids());
+                let fits_in_work_hours = participants.iter().all(|entity_name| {
+                    self.work_hours_of(entity_name)
+                        .expect("Participant of an activity does not exist")
+                        .iter()
+                        .any(|work_interval| work_interval.contains_interval(insertion_interval))
+                });
+                if !fits_in_work_hours {
+                    self.activities.store_activity_was_inserted(activity.id());
+                    self.insert_activity(activity.id(), None)
+                        .expect("Could not remove activity from schedule. This is a bug.");
+                }
+            }
+        }
+    }
+
+    /// Removes from the schedule the inserted activities whose participants would not have
+    /// the breaks they need anymore.
+    ///
+    /// As for work hours, their beginnings are remembered so that they can be inserted back
+    /// in the closest spot once their possible beginnings are computed.
+    pub(crate) fn uninsert_activities_breaking_break_rules(&mut self) {
+        for activity in self.activities_sorted() {
+            // The activity may have been removed along with a simultaneous or chained activity
+            let activity = self.activity(activity.id());
+            if let Some(insertion_interval) = activity.insertion_interval() {
+                if self
+                    .participant_without_breaks_if_inserted_at_time(
+                        &activity,
+                        insertion_interval.beginning(),
+                    )
+                    .is_some()
+                {
+                    self.activities.store_activity_was_inserted(activity.id());
+                    self.insert_activity(activity.id(), None)
+                        .expect("Could not remove activity from schedule. This is a bug.");
+                }
+            }
         }
     }
 
     /// Returns the time taken by the activities of an entity.
     ///
-    /// If the entity does not exist, returns Time(0, 0).
+    /// If the entity does not exist, returns Duration(0, 0).
     #[must_use]
-    pub(super) fn time_taken_by_activities(&self, entity_name: &str) -> Time {
+    pub(crate) fn time_taken_by_activities(&self, entity_name: &str) -> Duration {
+        let entity_id = match self.entity_id_of(entity_name) {
+            Some(entity_id) => entity_id,
+            None => return Duration::default(),
+        };
         self.activities_sorted()
             .iter()
             .filter_map(|activity| {
-                if activity.entities_sorted().contains(&entity_name.into()) {
+                if activity.entity_ids().contains(&entity_id) {
                     Some(activity.duration())
                 } else {
                     None
@@ -44,11 +100,242 @@ impl Data {
     /// # Errors
     ///
     /// Returns Err if the entity does not exist.
-    pub(super) fn total_available_time(&self, entity_name: &str) -> Result<Time> {
-        Ok(self
-            .work_hours_of(entity_name)?
+    pub(super) fn total_available_time(&self, entity_name: &str) -> Result<Duration> {
+        Ok(total_duration(&self.work_hours_of(entity_name)?))
+    }
+
+    /// Returns the work hours of the entity, not taking its unavailable intervals into
+    /// account : its custom work hours if it has any, else the work hours of its template
+    /// if it has one, else the work hours of its groups if they have any,
+    /// else the global work hours.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity does not exist.
+    pub(super) fn work_hours_before_unavailability_of(
+        &self,
+        entity_name: &str,
+    ) -> Result<Vec<TimeInterval>> {
+        let custom_work_hours = self.custom_work_hours_of(entity_name)?;
+        Ok(if custom_work_hours.is_empty() {
+            self.work_hours_without_custom_intervals_of(entity_name)
+        } else {
+            custom_work_hours
+        })
+    }
+
+    /// Returns the work hours which apply to the entity when it has no custom work hours :
+    /// the work hours of its template if it has one, else the work hours of its groups
+    /// if they have any, else the global work hours.
+    ///
+    /// The existence of the entity is not checked.
+    #[must_use]
+    pub(super) fn work_hours_without_custom_intervals_of(
+        &self,
+        entity_name: &str,
+    ) -> Vec<TimeInterval> {
+        self.work_hours_with_groups_of(
+            entity_name,
+            self.work_hours_of_groups(&self.group_names_of(entity_name)),
+        )
+    }
+
+    /// Returns the work hours which would apply to the entity without custom work hours
+    /// if its groups had the given work hours.
+    ///
+    /// The existence of the entity is not checked.
+    #[must_use]
+    pub(crate) fn work_hours_with_groups_of(
+        &self,
+        entity_name: &str,
+        group_work_hours: Vec<Vec<TimeInterval>>,
+    ) -> Vec<TimeInterval> {
+        self.entity_id_of(entity_name)
+            .and_then(|entity_id| self.work_hours.work_hour_template_of(entity_id))
+            .map_or_else(
+                || shared_work_hours(group_work_hours).unwrap_or_else(|| self.work_hours()),
+                |template_name| {
+                    self.work_hours
+                        .work_hour_template(&template_name)
+                        .expect("Assigned work hour template does not exist")
+                },
+            )
+    }
+
+    /// Returns the work hours which apply to the entity when it has neither custom work hours
+    /// nor a template : the work hours of its groups if they have any,
+    /// else the global work hours.
+    ///
+    /// The existence of the entity is not checked.
+    #[must_use]
+    pub(super) fn work_hours_without_template_of(&self, entity_name: &str) -> Vec<TimeInterval> {
+        shared_work_hours(self.work_hours_of_groups(&self.group_names_of(entity_name)))
+            .unwrap_or_else(|| self.work_hours())
+    }
+
+    /// Returns true if the entity works during the global work hours, i.e. if it has
+    /// no custom work hours, no template and no group with work hours.
+    ///
+    /// The existence of the entity is not checked.
+    #[must_use]
+    pub(crate) fn follows_global_work_hours(&self, entity_name: &str) -> bool {
+        self.entity_id_of(entity_name).is_none_or(|entity_id| {
+            self.work_hours
+                .custom_work_intervals_of(entity_id)
+                .is_empty()
+                && self.work_hours.work_hour_template_of(entity_id).is_none()
+        }) && shared_work_hours(self.work_hours_of_groups(&self.group_names_of(entity_name)))
+            .is_none()
+    }
+
+    /// Returns the id of the entity with the given name, if it exists.
+    #[must_use]
+    pub(super) fn entity_id_of(&self, entity_name: &str) -> Option<EntityId> {
+        self.entities
+            .get_by_name(entity_name)
+            .ok()
+            .map(|entity| entity.id())
+    }
+
+    /// Returns the names of the groups of which the entity is a member, directly or through
+    /// subgroups, sorted.
+    #[must_use]
+    pub(crate) fn group_names_of(&self, entity_name: &str) -> Vec<String> {
+        self.entity_id_of(entity_name)
+            .map_or_else(Vec::new, |entity_id| {
+                self.groups
+                    .names_sorted(&self.groups.ids_of_groups_containing_entity(entity_id))
+            })
+    }
+
+    /// Returns the work hours of each of the given groups.
+    /// Groups without work hours have empty work hours.
+    #[must_use]
+    pub(crate) fn work_hours_of_groups(&self, group_names: &[String]) -> Vec<Vec<TimeInterval>> {
+        group_names
             .iter()
-            .map(|interval| interval.duration())
-            .sum())
+            .filter_map(|group_name| self.groups.get_by_name(group_name).ok())
+            .map(|group| self.work_hours.group_work_intervals_of(group.id()))
+            .collect()
     }
+
+    /// Returns the time the entity with the given id would have for activities with the given
+    /// work hours if it were a member of the given groups, once its unavailable intervals
+    /// are removed.
+    ///
+    /// The existence of the entity is not checked.
+    #[must_use]
+    pub(crate) fn available_time_with(
+        &self,
+        work_hours: &WorkHours,
+        entity_id: EntityId,
+        group_names: &[String],
+    ) -> Duration {
+        let custom_work_hours = work_hours.custom_work_intervals_of(entity_id);
+        let entity_work_hours = if !custom_work_hours.is_empty() {
+            custom_work_hours
+        } else if let Some(template_name) = work_hours.work_hour_template_of(entity_id) {
+            work_hours
+                .work_hour_template(&template_name)
+                .expect("Assigned work hour template does not exist")
+        } else {
+            shared_work_hours(self.work_hours_of_groups(group_names))
+                .unwrap_or_else(|| work_hours.work_intervals().clone())
+        };
+        total_duration(&without_intervals(
+            entity_work_hours,
+            &work_hours.unavailable_intervals_of(entity_id),
+        ))
+    }
+
+    /// Checks that the entity has enough time for their activities with the given work hours,
+    /// once the given unavailable intervals are removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity will not have enough time.
+    pub(super) fn check_entity_will_have_enough_time_with(
+        &self,
+        entity_name: &str,
+        work_hours: Vec<TimeInterval>,
+        unavailable_intervals: &[TimeInterval],
+    ) -> Result<()> {
+        let available_time = total_duration(&without_intervals(work_hours, unavailable_intervals));
+        if available_time < self.time_taken_by_activities(entity_name) {
+            Err(NotEnoughTime::work_hours_shortened_for(entity_name))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Returns the total duration of the given intervals.
+#[must_use]
+pub(super) fn total_duration(intervals: &[TimeInterval]) -> Duration {
+    intervals.iter().map(TimeInterval::duration).sum()
+}
+
+/// Returns the intervals during which every group with work hours works,
+/// or None if none of the groups has work hours.
+#[must_use]
+pub(super) fn shared_work_hours(
+    group_work_hours: Vec<Vec<TimeInterval>>,
+) -> Option<Vec<TimeInterval>> {
+    group_work_hours
+        .into_iter()
+        .filter(|work_hours| !work_hours.is_empty())
+        .reduce(|shared_work_hours, work_hours| intersection(&shared_work_hours, &work_hours))
+}
+
+/// Returns the parts of time which are covered by both sorted sets of intervals.
+#[must_use]
+fn intersection(intervals: &[TimeInterval], other_intervals: &[TimeInterval]) -> Vec<TimeInterval> {
+    intervals
+        .iter()
+        .flat_map(|interval| {
+            other_intervals.iter().filter_map(move |other| {
+                let beginning = interval.beginning().max(other.beginning());
+                let end = interval.end().min(other.end());
+                if beginning < end {
+                    Some(TimeInterval::new(beginning, end))
+                } else {
+                    None
+                }
+            })
+        })
+        .collect()
+}
+
+/// Returns the given work hours without the given intervals.
+/// Work intervals which partly overlap with a removed interval are cut.
+#[must_use]
+pub(super) fn without_intervals(
+    work_hours: Vec<TimeInterval>,
+    removed_intervals: &[TimeInterval],
+) -> Vec<TimeInterval> {
+    removed_intervals
+        .iter()
+        .fold(work_hours, |work_hours, removed_interval| {
+            work_hours
+                .into_iter()
+                .flat_map(|interval| {
+                    if interval.overlaps_with(removed_interval) {
+                        let mut remaining_parts = Vec::new();
+                        if interval.beginning() < removed_interval.beginning() {
+                            remaining_parts.push(TimeInterval::new(
+                                interval.beginning(),
+                                removed_interval.beginning(),
+                            ));
+                        }
+                        if interval.end() > removed_interval.end() {
+                            remaining_parts
+                                .push(TimeInterval::new(removed_interval.end(), interval.end()));
+                        }
+                        remaining_parts
+                    } else {
+                        vec![interval]
+                    }
+                })
+                .collect()
+        })
 }
@@ -1,11 +1,23 @@
+mod break_rules;
 mod error_checks;
+mod group_work_hours;
 mod inner;
+mod templates;
+mod time_preferences;
+mod unavailability;
+
+use inner::without_intervals;
 
 use crate::errors::Result;
-use crate::Time;
+use crate::Duration;
 use crate::{clean_string, Data, TimeInterval};
 
 /// Operations on work hours
+///
+/// When work hours change, the inserted activities which no longer fit in the work hours of
+/// their participants are removed from the schedule. They are inserted back in the closest
+/// spot once their possible beginnings are computed
+/// (see `insert_activities_removed_because_duration_increased_in_closest_spot`).
 impl Data {
     /// Returns a copy of the work hours.
     #[must_use]
@@ -21,7 +33,6 @@ impl Data {
     ///
     /// Returns Err if the interval overlaps with the existing work intervals.
     pub fn add_work_interval(&mut self, interval: TimeInterval) -> Result<()> {
-        self.check_no_activity_inserted()?;
         self.work_hours.add_work_interval(interval)?;
         self.notify_work_hours_changed();
         Ok(())
@@ -34,7 +45,6 @@ impl Data {
     /// Returns Err if the time interval is not found or if the time interval can't be removed
     /// because an entity no longer has any time left.
     pub fn remove_work_interval(&mut self, interval: TimeInterval) -> Result<()> {
-        self.check_no_activity_inserted()?;
         self.check_entity_without_enough_time_to_remove_interval(interval.duration())?;
         self.work_hours.remove_work_interval(interval)?;
         self.notify_work_hours_changed();
@@ -52,7 +62,6 @@ impl Data {
         old_interval: TimeInterval,
         new_interval: TimeInterval,
     ) -> Result<()> {
-        self.check_no_activity_inserted()?;
         // If the interval is shorter, check that entities still have time left
         self.check_entity_without_enough_time_to_update_interval(
             old_interval.duration(),
@@ -68,12 +77,12 @@ impl Data {
     /// Returns the free time of an entity (total time in work hours - time taken by activities).
     ///
     /// The activities should never take more time than the total time ; should that happen,
-    /// Time::new(0, 0) is returned.
+    /// Duration::new(0, 0) is returned.
     ///
     /// # Errors
     ///
     /// Returns Err if the entity is not found.
-    pub fn free_time_of<S>(&self, entity_name: S) -> Result<Time>
+    pub fn free_time_of<S>(&self, entity_name: S) -> Result<Duration>
     where
         S: Into<String>,
     {
@@ -82,11 +91,9 @@ impl Data {
         // total_available_time checks if the entity exists
         let total_duration = self.total_available_time(&entity_name)?;
         let activity_duration = self.time_taken_by_activities(&entity_name);
-        Ok(if total_duration < activity_duration {
-            Time::new(0, 0)
-        } else {
-            total_duration - activity_duration
-        })
+        Ok(total_duration
+            .checked_sub(activity_duration)
+            .unwrap_or_default())
     }
 
     /// Returns the custom work hours of the entity with the formatted given name.
@@ -100,14 +107,16 @@ impl Data {
     where
         S: Into<String>,
     {
-        let entity_name = clean_string(entity_name)?;
-
-        self.work_hours.custom_work_intervals_of(&entity_name)
+        let entity_id = self.entity(entity_name)?.id();
+        Ok(self.work_hours.custom_work_intervals_of(entity_id))
     }
 
     /// Returns the work hours of the entity with the formatted given name.
     ///
-    /// If the entity has custom work hours, returns them, else returns the global work hours.
+    /// If the entity has custom work hours, returns them, else returns the work hours of its
+    /// template if it has one, else returns the work hours of its groups if they have any,
+    /// else returns the global work hours.
+    /// The intervals during which the entity is unavailable are removed.
     ///
     /// # Errors
     ///
@@ -116,12 +125,12 @@ impl Data {
     where
         S: Into<String>,
     {
-        let custom_work_hours = self.custom_work_hours_of(entity_name)?;
-        Ok(if custom_work_hours.is_empty() {
-            self.work_hours()
-        } else {
-            custom_work_hours
-        })
+        let entity = self.entity(entity_name)?;
+        let work_hours = self.work_hours_before_unavailability_of(&entity.name())?;
+        Ok(without_intervals(
+            work_hours,
+            &self.work_hours.unavailable_intervals_of(entity.id()),
+        ))
     }
 
     /// Adds a custom work interval for the entity with the formatted given name.
@@ -140,14 +149,10 @@ impl Data {
     {
         // If this intervals overrides the global work hours,
         // check if the entity has enough free time
-        let entity_name = clean_string(entity_name)?;
-        self.check_no_activity_inserted()?;
-        self.check_entity_will_have_enough_time_with_custom_interval(
-            &entity_name,
-            interval.duration(),
-        )?;
+        let entity = self.entity(entity_name)?;
+        self.check_entity_will_have_enough_time_with_custom_interval(&entity.name(), interval)?;
         self.work_hours
-            .add_custom_work_interval_for(&entity_name, interval)?;
+            .add_custom_work_interval_for(entity.id(), interval)?;
         self.notify_work_hours_changed();
 
         Ok(())
@@ -166,16 +171,13 @@ impl Data {
     where
         S: Into<String>,
     {
-        let entity_name = clean_string(entity_name)?;
+        let entity = self.entity(entity_name)?;
+        let entity_name = entity.name();
 
         self.check_entity_has_custom_interval(&entity_name, &interval)?;
-        self.check_no_activity_inserted()?;
-        self.check_entity_will_have_enough_time_after_deletion_of_interval(
-            &entity_name,
-            interval.duration(),
-        )?;
+        self.check_entity_will_have_enough_time_after_deletion_of_interval(&entity_name, interval)?;
         self.work_hours
-            .remove_custom_work_interval_for(&entity_name, interval)?;
+            .remove_custom_work_interval_for(entity.id(), interval)?;
 
         self.notify_work_hours_changed();
         Ok(())
@@ -198,8 +200,8 @@ impl Data {
     where
         S: Into<String>,
     {
-        let entity_name = clean_string(entity_name)?;
-        self.check_no_activity_inserted()?;
+        let entity = self.entity(entity_name)?;
+        let entity_name = entity.name();
         self.check_entity_has_custom_interval(&entity_name, &old_interval)?;
         self.check_entity_will_have_enough_time_after_update(
             &entity_name,
@@ -207,11 +209,8 @@ impl Data {
             new_interval.duration(),
         )?;
 
-        self.work_hours.update_custom_work_interval_for(
-            &entity_name,
-            old_interval,
-            new_interval,
-        )?;
+        self.work_hours
+            .update_custom_work_interval_for(entity.id(), old_interval, new_interval)?;
 
         self.notify_work_hours_changed();
         Ok(())
@@ -0,0 +1,292 @@
+use crate::errors::{does_not_exist::DoesNotExist, Result};
+use crate::{clean_string, Data, TimeInterval};
+
+/// Operations on work hour templates.
+///
+/// A template is a named set of work intervals shared by several entities
+/// (e.g. "Early Shift"). An entity with a template works during the intervals of the template
+/// instead of the work hours of its groups or the global work hours.
+/// Custom work intervals still take precedence over the template.
+impl Data {
+    /// Returns the names of the work hour templates, sorted.
+    #[must_use]
+    pub fn work_hour_templates(&self) -> Vec<String> {
+        self.work_hours.work_hour_template_names()
+    }
+
+    /// Returns the work intervals of the template with the formatted given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the formatted name is empty or if the template does not exist.
+    pub fn work_hour_template<S>(&self, name: S) -> Result<Vec<TimeInterval>>
+    where
+        S: Into<String>,
+    {
+        self.work_hours.work_hour_template(&clean_string(name)?)
+    }
+
+    /// Returns the name of the template of the entity with the formatted given name, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found.
+    pub fn work_hour_template_of<S>(&self, entity_name: S) -> Result<Option<String>>
+    where
+        S: Into<String>,
+    {
+        let entity_id = self.entity(entity_name)?.id();
+        Ok(self.work_hours.work_hour_template_of(entity_id))
+    }
+
+    /// Adds an empty work hour template with the formatted given name.
+    ///
+    /// Returns the formatted name of the template.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the formatted name is empty or if it is taken by another template.
+    pub fn add_work_hour_template<S>(&mut self, name: S) -> Result<String>
+    where
+        S: Into<String>,
+    {
+        let name = clean_string(name)?;
+        self.work_hours.add_work_hour_template(name.clone())?;
+        self.events().borrow_mut().emit_work_hours_changed(self);
+        Ok(name)
+    }
+
+    /// Removes the work hour template with the formatted given name.
+    /// The entities which were assigned the template work during the work hours of their groups
+    /// or the global work hours again.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the template does not exist
+    /// or if an entity of the template would not have enough time without it.
+    pub fn remove_work_hour_template<S>(&mut self, name: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let name = clean_string(name)?;
+        self.work_hours.work_hour_template(&name)?;
+        let entities = self.entities_with_work_hour_template(&name);
+        for entity_name in &entities {
+            if self.custom_work_hours_of(entity_name)?.is_empty() {
+                self.check_entity_will_have_enough_time_with(
+                    entity_name,
+                    self.work_hours_without_template_of(entity_name),
+                    &self.unavailable_intervals_of(entity_name)?,
+                )?;
+            }
+        }
+
+        self.work_hours.remove_work_hour_template(&name)?;
+        self.notify_work_hours_of_entities_changed(entities);
+        Ok(())
+    }
+
+    /// Renames the work hour template with the formatted given name.
+    /// The entities which were assigned the template keep it.
+    ///
+    /// Returns the formatted new name of the template.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if any formatted name is empty, if the template does not exist
+    /// or if the new name is taken by another template.
+    pub fn set_work_hour_template_name<S1, S2>(
+        &mut self,
+        old_name: S1,
+        new_name: S2,
+    ) -> Result<String>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let old_name = clean_string(old_name)?;
+        let new_name = clean_string(new_name)?;
+        self.work_hours
+            .rename_work_hour_template(&old_name, new_name.clone())?;
+        self.events().borrow_mut().emit_work_hours_changed(self);
+        Ok(new_name)
+    }
+
+    /// Adds the given work interval to the template with the formatted given name.
+    /// The insertion costs of the activities of every entity of the template are updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the template does not exist
+    /// or if the interval overlaps with the other intervals of the template.
+    pub fn add_work_hour_template_interval<S>(
+        &mut self,
+        name: S,
+        interval: TimeInterval,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let name = clean_string(name)?;
+        self.work_hours.work_hour_template(&name)?;
+        self.work_hours
+            .add_work_hour_template_interval(&name, interval)?;
+        self.notify_work_hours_of_entities_changed(self.entities_with_work_hour_template(&name));
+        Ok(())
+    }
+
+    /// Removes the given work interval from the template with the formatted given name.
+    /// The insertion costs of the activities of every entity of the template are updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the template or the interval does not exist
+    /// or if an entity of the template would not have enough time left.
+    pub fn remove_work_hour_template_interval<S>(
+        &mut self,
+        name: S,
+        interval: TimeInterval,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let name = clean_string(name)?;
+        let intervals = self.check_template_has_interval(&name, interval)?;
+        let remaining_intervals = intervals
+            .into_iter()
+            .filter(|&other| other != interval)
+            .collect::<Vec<_>>();
+        self.check_entities_of_template_will_have_enough_time_with(&name, &remaining_intervals)?;
+
+        self.work_hours
+            .remove_work_hour_template_interval(&name, interval)?;
+        self.notify_work_hours_of_entities_changed(self.entities_with_work_hour_template(&name));
+        Ok(())
+    }
+
+    /// Replaces the given work interval of the template with the formatted given name.
+    /// The insertion costs of the activities of every entity of the template are updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the template or the interval does not exist,
+    /// if the new interval overlaps with the other intervals of the template
+    /// or if an entity of the template would not have enough time left.
+    pub fn update_work_hour_template_interval<S>(
+        &mut self,
+        name: S,
+        old_interval: TimeInterval,
+        new_interval: TimeInterval,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let name = clean_string(name)?;
+        let intervals = self.check_template_has_interval(&name, old_interval)?;
+        let updated_intervals = intervals
+            .into_iter()
+            .map(|other| {
+                if other == old_interval {
+                    new_interval
+                } else {
+                    other
+                }
+            })
+            .collect::<Vec<_>>();
+        self.check_entities_of_template_will_have_enough_time_with(&name, &updated_intervals)?;
+
+        self.work_hours
+            .update_work_hour_template_interval(&name, old_interval, new_interval)?;
+        self.notify_work_hours_of_entities_changed(self.entities_with_work_hour_template(&name));
+        Ok(())
+    }
+
+    /// Assigns the template with the formatted given name to the entity with the formatted
+    /// given name. None removes the template of the entity, which then works during the
+    /// work hours of its groups or the global work hours again.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity or the template does not exist
+    /// or if the entity would not have enough time with its new work hours.
+    pub fn set_work_hour_template_of<S1, S2>(
+        &mut self,
+        entity_name: S1,
+        template_name: Option<S2>,
+    ) -> Result<()>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let entity = self.entity(entity_name)?;
+        let entity_name = entity.name();
+        let template_name = template_name.map(clean_string).transpose()?;
+        let new_work_hours = match &template_name {
+            Some(template_name) => self.work_hours.work_hour_template(template_name)?,
+            None => self.work_hours_without_template_of(&entity_name),
+        };
+        if self.custom_work_hours_of(&entity_name)?.is_empty() {
+            self.check_entity_will_have_enough_time_with(
+                &entity_name,
+                new_work_hours,
+                &self.work_hours.unavailable_intervals_of(entity.id()),
+            )?;
+        }
+
+        self.work_hours
+            .assign_work_hour_template(entity.id(), template_name)?;
+        self.notify_work_hours_of_entities_changed(vec![entity_name]);
+        Ok(())
+    }
+
+    /// Checks that the template exists and contains the given interval.
+    /// Returns the intervals of the template.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the template or the interval does not exist.
+    fn check_template_has_interval(
+        &self,
+        name: &str,
+        interval: TimeInterval,
+    ) -> Result<Vec<TimeInterval>> {
+        let intervals = self.work_hours.work_hour_template(name)?;
+        if intervals.contains(&interval) {
+            Ok(intervals)
+        } else {
+            Err(DoesNotExist::interval_does_not_exist(interval))
+        }
+    }
+
+    /// Checks that every entity which works during the hours of the template would still have
+    /// enough time if the template had the given intervals.
+    /// Entities with custom work hours are not concerned.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if an entity would not have enough time.
+    fn check_entities_of_template_will_have_enough_time_with(
+        &self,
+        name: &str,
+        intervals: &[TimeInterval],
+    ) -> Result<()> {
+        for entity_name in self.entities_with_work_hour_template(name) {
+            if self.custom_work_hours_of(&entity_name)?.is_empty() {
+                self.check_entity_will_have_enough_time_with(
+                    &entity_name,
+                    intervals.to_vec(),
+                    &self.unavailable_intervals_of(&entity_name)?,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the names of the entities which are assigned the template with the given name,
+    /// sorted.
+    #[must_use]
+    fn entities_with_work_hour_template(&self, name: &str) -> Vec<String> {
+        self.entities
+            .names_sorted(&self.work_hours.entities_with_work_hour_template(name))
+    }
+}
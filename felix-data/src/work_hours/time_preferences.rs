@@ -0,0 +1,141 @@
+use crate::errors::Result;
+use crate::{Data, TimeInterval, TimePreferences};
+
+/// Operations on the time preferences of entities.
+///
+/// Preferences never forbid an insertion : they make insertion times more or less costly.
+/// Therefore, they can be changed even if activities are inserted.
+impl Data {
+    /// Returns the preferred intervals of the entity with the formatted given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found.
+    pub fn preferred_intervals_of<S>(&self, entity_name: S) -> Result<Vec<TimeInterval>>
+    where
+        S: Into<String>,
+    {
+        let entity_id = self.entity(entity_name)?.id();
+        Ok(self.work_hours.preferred_intervals_of(entity_id))
+    }
+
+    /// Returns the disliked intervals of the entity with the formatted given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found.
+    pub fn disliked_intervals_of<S>(&self, entity_name: S) -> Result<Vec<TimeInterval>>
+    where
+        S: Into<String>,
+    {
+        let entity_id = self.entity(entity_name)?.id();
+        Ok(self.work_hours.disliked_intervals_of(entity_id))
+    }
+
+    /// Returns the preferred and disliked intervals of the entity with the formatted given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found.
+    pub fn time_preferences_of<S>(&self, entity_name: S) -> Result<TimePreferences>
+    where
+        S: Into<String>,
+    {
+        let entity_id = self.entity(entity_name)?.id();
+        Ok(self.work_hours.time_preferences_of(entity_id))
+    }
+
+    /// Adds a preferred interval for the entity with the formatted given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found or if the interval overlaps with another
+    /// preferred or disliked interval of the entity.
+    pub fn add_preferred_interval_for<S>(
+        &mut self,
+        entity_name: S,
+        interval: TimeInterval,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let entity = self.entity(entity_name)?;
+        self.work_hours
+            .add_preferred_interval_for(entity.id(), interval)?;
+        self.notify_time_preferences_changed(entity.name());
+        Ok(())
+    }
+
+    /// Removes the given preferred interval from the entity with the formatted given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found or if the interval is not found.
+    pub fn remove_preferred_interval_for<S>(
+        &mut self,
+        entity_name: S,
+        interval: TimeInterval,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let entity = self.entity(entity_name)?;
+        self.work_hours
+            .remove_preferred_interval_for(entity.id(), interval)?;
+        self.notify_time_preferences_changed(entity.name());
+        Ok(())
+    }
+
+    /// Adds a disliked interval for the entity with the formatted given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found or if the interval overlaps with another
+    /// preferred or disliked interval of the entity.
+    pub fn add_disliked_interval_for<S>(
+        &mut self,
+        entity_name: S,
+        interval: TimeInterval,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let entity = self.entity(entity_name)?;
+        self.work_hours
+            .add_disliked_interval_for(entity.id(), interval)?;
+        self.notify_time_preferences_changed(entity.name());
+        Ok(())
+    }
+
+    /// Removes the given disliked interval from the entity with the formatted given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found or if the interval is not found.
+    pub fn remove_disliked_interval_for<S>(
+        &mut self,
+        entity_name: S,
+        interval: TimeInterval,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let entity = self.entity(entity_name)?;
+        self.work_hours
+            .remove_disliked_interval_for(entity.id(), interval)?;
+        self.notify_time_preferences_changed(entity.name());
+        Ok(())
+    }
+
+    /// Emits the corresponding event and updates the insertion costs of the activities
+    /// of the entity.
+    fn notify_time_preferences_changed(&mut self, entity_name: String) {
+        let entity = self
+            .entity(&entity_name)
+            .expect("Notifying time preferences change of entity which does not exist");
+        self.events()
+            .borrow_mut()
+            .emit_time_preferences_changed(self, &entity);
+        self.queue_entities(vec![entity_name]);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::errors::{does_not_exist::DoesNotExist, Result};
+use crate::{Data, EntityId, TimeInterval};
+
+/// Operations on the intervals during which entities are unavailable.
+///
+/// Unavailable intervals are removed from the work hours of the entity, whether they are
+/// custom or global. Inserted activities which overlap with a new unavailable interval
+/// are removed from the schedule.
+impl Data {
+    /// Returns the intervals during which the entity with the formatted given name
+    /// is unavailable.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found.
+    pub fn unavailable_intervals_of<S>(&self, entity_name: S) -> Result<Vec<TimeInterval>>
+    where
+        S: Into<String>,
+    {
+        let entity_id = self.entity(entity_name)?.id();
+        Ok(self.work_hours.unavailable_intervals_of(entity_id))
+    }
+
+    /// Adds an interval during which the entity with the formatted given name is unavailable.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found,
+    /// if the interval overlaps with another unavailable interval of the entity
+    /// or if the entity does not have enough free time.
+    pub fn add_unavailable_interval_for<S>(
+        &mut self,
+        entity_name: S,
+        interval: TimeInterval,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let entity = self.entity(entity_name)?;
+        let entity_name = entity.name();
+        let mut unavailable_intervals = self.work_hours.unavailable_intervals_of(entity.id());
+        unavailable_intervals.push(interval);
+        self.check_entity_will_have_enough_time_with(
+            &entity_name,
+            self.work_hours_before_unavailability_of(&entity_name)?,
+            &unavailable_intervals,
+        )?;
+        self.work_hours
+            .add_unavailable_interval_for(entity.id(), interval)?;
+        self.notify_unavailability_changed(entity_name);
+        Ok(())
+    }
+
+    /// Removes the given unavailable interval from the entity with the formatted given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found or if the interval is not found.
+    pub fn remove_unavailable_interval_for<S>(
+        &mut self,
+        entity_name: S,
+        interval: TimeInterval,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let entity = self.entity(entity_name)?;
+        let entity_name = entity.name();
+        self.check_entity_has_unavailable_interval(entity.id(), interval)?;
+        self.work_hours
+            .remove_unavailable_interval_for(entity.id(), interval)?;
+        self.notify_unavailability_changed(entity_name);
+        Ok(())
+    }
+
+    /// Replaces the given unavailable interval with the new one for the entity with the
+    /// formatted given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the entity is not found, if the interval is not found,
+    /// if the new interval overlaps with another unavailable interval of the entity
+    /// or if the entity does not have enough free time.
+    pub fn update_unavailable_interval_for<S>(
+        &mut self,
+        entity_name: S,
+        old_interval: TimeInterval,
+        new_interval: TimeInterval,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let entity = self.entity(entity_name)?;
+        let entity_name = entity.name();
+        self.check_entity_has_unavailable_interval(entity.id(), old_interval)?;
+        let unavailable_intervals = self
+            .work_hours
+            .unavailable_intervals_of(entity.id())
+            .into_iter()
+            .map(|interval| {
+                if interval == old_interval {
+                    new_interval
+                } else {
+                    interval
+                }
+            })
+            .collect::<Vec<_>>();
+        self.check_entity_will_have_enough_time_with(
+            &entity_name,
+            self.work_hours_before_unavailability_of(&entity_name)?,
+            &unavailable_intervals,
+        )?;
+        self.work_hours
+            .update_unavailable_interval_for(entity.id(), old_interval, new_interval)?;
+        self.notify_unavailability_changed(entity_name);
+        Ok(())
+    }
+
+    /// Checks that the entity has the given unavailable interval.
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the interval is not found.
+    fn check_entity_has_unavailable_interval(
+        &self,
+        entity_id: EntityId,
+        interval: TimeInterval,
+    ) -> Result<()> {
+        if self
+            .work_hours
+            .unavailable_intervals_of(entity_id)
+            .contains(&interval)
+        {
+            Ok(())
+        } else {
+            Err(DoesNotExist::interval_does_not_exist(interval))
+        }
+    }
+
+    /// Emits the work hours changed event and updates the insertion costs of the activities
+    /// of the entity.
+    fn notify_unavailability_changed(&mut self, entity_name: String) {
+        self.notify_work_hours_of_entities_changed(vec![entity_name]);
+    }
+}
@@ -9,7 +9,7 @@
 //! - Set color
 //! - Activity insertion
 
-use felix_data::{Time, Rgba, TimeInterval};
+use felix_data::{Duration, Rgba, TimeInterval, TimeOfDay};
 use felix_test_utils::{Activity, DataBuilder};
 
 use std::collections::BTreeSet;
@@ -206,7 +206,7 @@ fn simple_set_activity_duration() {
         DataBuilder::new().with_activity(Activity::default()),
         {
             let id = data.activities_sorted()[0].id();
-            let duration = Time::new(1, 0);
+            let duration = Duration::new(1, 0);
             let activity_duration = data.activity(id).duration();
             assert_ne!(
                 activity_duration, duration,
@@ -227,7 +227,7 @@ fn set_activity_duration_invalid_id() {
         let mut data = DataBuilder::new()
             .with_activity(Activity::default())
             .into_data();
-        data.set_activity_duration(2, Time::new(1, 0)).unwrap();
+        data.set_activity_duration(2, Duration::new(1, 0)).unwrap();
     })
     .expect_err("Could set the duration of nonexistent activity");
 }
@@ -239,16 +239,16 @@ fn set_activity_duration_zero_remove_from_schedule_if_inserted() {
         data,
         DataBuilder::new()
             .with_entity(name)
-            .with_work_interval(TimeInterval::new(Time::new(8, 0), Time::new(12, 0)))
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0)))
             .with_activity(Activity {
                 entities: vec![name],
-                duration: Time::new(0, 10),
-                insertion_time: Some(Time::new(8, 0)),
+                duration: Duration::new(0, 10),
+                insertion_time: Some(TimeOfDay::new(8, 0)),
                 ..Default::default()
             }),
         {
             let id = data.activities_sorted()[0].id();
-            data.set_activity_duration(id, Time::new(0, 0))
+            data.set_activity_duration(id, Duration::new(0, 0))
                 .expect("Cannot set activity duration");
             assert!(
                 data.activity(id).insertion_interval().is_none(),
@@ -265,18 +265,18 @@ fn decrease_activity_duration_check_insertion_interval_updated() {
         data,
         DataBuilder::new()
             .with_entity(name)
-            .with_work_interval(TimeInterval::new(Time::new(8, 0), Time::new(9, 0)))
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(9, 0)))
             .with_activity(Activity {
-                duration: Time::new(1, 0),
+                duration: Duration::new(1, 0),
                 name: "Activity",
                 groups: Vec::new(),
                 entities: vec![name],
-                insertion_time: Some(Time::new(8, 0)),
+                insertion_time: Some(TimeOfDay::new(8, 0)),
                 ..Default::default()
             }),
         {
             let id = data.activities_sorted()[0].id();
-            let expected_insertion_interval = TimeInterval::new(Time::new(8, 00), Time::new(9, 0));
+            let expected_insertion_interval = TimeInterval::new(TimeOfDay::new(8, 00), TimeOfDay::new(9, 0));
             data.wait_for_possible_insertion_costs_computation(id);
 
             assert_eq!(
@@ -285,8 +285,8 @@ fn decrease_activity_duration_check_insertion_interval_updated() {
             );
 
             // Decrease the duration, check that the insertion interval is still valid
-            data.set_activity_duration(id, Time::new(0, 30)).unwrap();
-            let expected_insertion_interval = TimeInterval::new(Time::new(8, 00), Time::new(8, 30));
+            data.set_activity_duration(id, Duration::new(0, 30)).unwrap();
+            let expected_insertion_interval = TimeInterval::new(TimeOfDay::new(8, 00), TimeOfDay::new(8, 30));
             data.wait_for_possible_insertion_costs_computation(id);
 
             assert_eq!(
@@ -304,26 +304,26 @@ fn increase_activity_duration_check_insertion_interval_removed() {
         data,
         DataBuilder::new()
             .with_entity(name)
-            .with_work_interval(TimeInterval::new(Time::new(8, 0), Time::new(9, 0)))
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(9, 0)))
             .with_activity(Activity {
-                duration: Time::new(0, 30),
+                duration: Duration::new(0, 30),
                 name: "Activity",
                 groups: Vec::new(),
                 entities: vec![name],
-                insertion_time: Some(Time::new(8, 00)),
+                insertion_time: Some(TimeOfDay::new(8, 00)),
                 ..Default::default()
             }),
         {
             let id = data.activities_sorted()[0].id();
             let activity = data.activity(id);
-            let expected_insertion_interval = TimeInterval::new(Time::new(8, 00), Time::new(8, 30));
+            let expected_insertion_interval = TimeInterval::new(TimeOfDay::new(8, 00), TimeOfDay::new(8, 30));
             assert_eq!(
                 activity.insertion_interval(),
                 Some(expected_insertion_interval)
             );
 
             // Change the duration, check that the insertion interval is removed
-            data.set_activity_duration(id, Time::new(1, 0)).unwrap();
+            data.set_activity_duration(id, Duration::new(1, 0)).unwrap();
             let activity = data.activity(id);
             let expected_insertion_interval = None;
             assert_eq!(activity.insertion_interval(), expected_insertion_interval);
@@ -338,26 +338,26 @@ fn increase_activity_duration_then_insert_activity_automatically_in_closest_spot
         data,
         DataBuilder::new()
             .with_entity(name)
-            .with_work_interval(TimeInterval::new(Time::new(8, 0), Time::new(9, 0)))
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(9, 0)))
             .with_activity(Activity {
-                duration: Time::new(0, 30),
+                duration: Duration::new(0, 30),
                 name: "Activity",
                 groups: Vec::new(),
                 entities: vec![name],
-                insertion_time: Some(Time::new(8, 30)),
+                insertion_time: Some(TimeOfDay::new(8, 30)),
                 ..Default::default()
             }),
         {
             let id = data.activities_sorted()[0].id();
             let activity = data.activity(id);
-            let expected_insertion_interval = TimeInterval::new(Time::new(8, 30), Time::new(9, 00));
+            let expected_insertion_interval = TimeInterval::new(TimeOfDay::new(8, 30), TimeOfDay::new(9, 00));
             assert_eq!(
                 activity.insertion_interval(),
                 Some(expected_insertion_interval)
             );
 
             // Change the duration, check that the insertion interval is removed
-            data.set_activity_duration(id, Time::new(1, 0)).unwrap();
+            data.set_activity_duration(id, Duration::new(1, 0)).unwrap();
             let activity = data.activity(id);
             let expected_insertion_interval = None;
             assert_eq!(activity.insertion_interval(), expected_insertion_interval);
@@ -372,7 +372,7 @@ fn increase_activity_duration_then_insert_activity_automatically_in_closest_spot
                 .insertion_interval()
                 .expect("Activity was not reinserted in the schedule")
                 .beginning();
-            assert_eq!(insertion_beginning, Time::new(8, 0));
+            assert_eq!(insertion_beginning, TimeOfDay::new(8, 0));
         }
     );
 }
@@ -383,11 +383,11 @@ fn set_activity_duration_check_insertion_costs_updated() {
     test_ok!(
         data,
         DataBuilder::new()
-        .with_work_interval(TimeInterval::new(Time::new(8, 0), Time::new(12, 0)))
+        .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0)))
         .with_entity(name)
         .with_activity(Activity { 
             entities: vec![name],
-            duration: Time::new(1, 0),
+            duration: Duration::new(1, 0),
             ..Default::default()
         }),
         {
@@ -397,12 +397,12 @@ fn set_activity_duration_check_insertion_costs_updated() {
             assert!(data.activity(id).insertion_costs().unwrap().len() > 1);
 
             // Change duration and check that possible beginnings are updated
-            data.set_activity_duration(id, Time::new(4, 0)).expect("Could not set activtiy duration");
+            data.set_activity_duration(id, Duration::new(4, 0)).expect("Could not set activtiy duration");
 
             data.wait_for_possible_insertion_costs_computation(id);
             let insertion_costs = data.activity(id).insertion_costs().unwrap();
             assert_eq!(insertion_costs.len(), 1);
-            assert_eq!(insertion_costs[0].beginning, Time::new(8, 0));
+            assert_eq!(insertion_costs[0].beginning, TimeOfDay::new(8, 0));
         }
     );
 }
@@ -432,12 +432,12 @@ fn basic_set_color() {
 #[test]
 fn basic_insert_activity() {
     let (name1, name2) = ("Paul", "Antoine");
-    let activity_duration = Time::new(0, 30);
+    let activity_duration = Duration::new(0, 30);
     test_ok!(
         data,
         DataBuilder::new()
             .with_entities(vec![name1, name2])
-            .with_work_interval(TimeInterval::new(Time::new(8, 0), Time::new(12, 0)))
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0)))
             .with_activity(Activity {
                 name: "Activity",
                 entities: vec![name1, name2],
@@ -449,7 +449,7 @@ fn basic_insert_activity() {
             let id = data.activities_sorted()[0].id();
             data.wait_for_possible_insertion_costs_computation(id);
 
-            let beginning = Time::new(10, 0);
+            let beginning = TimeOfDay::new(10, 0);
             let expected_insertion_interval =
                 TimeInterval::new(beginning, beginning + activity_duration);
             assert!(data.insert_activity(id, Some(beginning)).is_ok());
@@ -466,12 +466,12 @@ fn basic_insert_activity() {
 #[test]
 fn basic_insert_activity_invalid_time() {
     let (name1, name2) = ("Paul", "Antoine");
-    let activity_duration = Time::new(0, 30);
+    let activity_duration = Duration::new(0, 30);
     test_err!(
         data,
         DataBuilder::new()
         .with_entities(vec![name1, name2])
-        .with_work_interval(TimeInterval::new(Time::new(8, 0), Time::new(12, 0)))
+        .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0)))
         .with_activity(Activity {
             name: "Activity",
             entities: vec![name1, name2],
@@ -483,7 +483,7 @@ fn basic_insert_activity_invalid_time() {
             let id = data.activities_sorted()[0].id();
             data.wait_for_possible_insertion_costs_computation(id);
 
-            let beginning = Time::new(14, 0);
+            let beginning = TimeOfDay::new(14, 0);
             data.insert_activity(id, Some(beginning))
         },
         "Activity cannot be inserted with beginning 14:00 because this beginning is invalid or will cause problems in the future.",
@@ -494,14 +494,14 @@ fn basic_insert_activity_invalid_time() {
 #[test]
 fn insert_activity_invalid_time_overlaps() {
     let (name1, name2) = ("Paul", "Antoine");
-    let activity_duration = Time::new(0, 30);
-    let beginning1 = Time::new(10, 20);
-    let beginning2 = Time::new(10, 0);
+    let activity_duration = Duration::new(0, 30);
+    let beginning1 = TimeOfDay::new(10, 20);
+    let beginning2 = TimeOfDay::new(10, 0);
     test_err!(
         data,
         DataBuilder::new()
             .with_entities(vec![name1, name2])
-            .with_work_interval(TimeInterval::new(Time::new(8, 0), Time::new(12, 0)))
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0)))
             .with_activities(vec![
                 Activity {
                     name: "Blocking Activity",
@@ -535,20 +535,20 @@ fn possible_insertion_times_takes_insertion_conflict_into_account() {
         data,
         DataBuilder::new()
             .with_entity(name)
-            .with_work_interval(TimeInterval::new(Time::new(10, 0), Time::new(13, 0)))
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(10, 0), TimeOfDay::new(13, 0)))
             .with_activities(vec![
                 Activity {
                     name: "Activity",
                     entities: vec![name],
-                    duration: Time::new(1, 0),
+                    duration: Duration::new(1, 0),
                     groups: Vec::new(),
-                    insertion_time: Some(Time::new(11, 0)),
+                    insertion_time: Some(TimeOfDay::new(11, 0)),
                     ..Default::default()
                 },
                 Activity {
                     name: "Activity2",
                     entities: vec![name],
-                    duration: Time::new(1, 0),
+                    duration: Duration::new(1, 0),
                     groups: Vec::new(),
                     ..Default::default()
                 }
@@ -563,7 +563,7 @@ fn possible_insertion_times_takes_insertion_conflict_into_account() {
                        .insertion_costs()
                         .unwrap().iter().map(|insertion_cost| insertion_cost.beginning)
                         .collect::<BTreeSet<_>>(),
-                       [Time::new(10, 0), Time::new(12, 0)].iter().copied().collect::<BTreeSet<_>>(),
+                       [TimeOfDay::new(10, 0), TimeOfDay::new(12, 0)].iter().copied().collect::<BTreeSet<_>>(),
                "Insertion times with conflicts with inserted activities were not calculated right.");
         }
     );
@@ -578,13 +578,13 @@ fn possible_insertion_times_takes_heterogeneous_work_hours_of_participants_into_
             .with_entities(vec![name1, name2])
             .with_custom_work_interval_for(
                 name1,
-                TimeInterval::new(Time::new(9, 0), Time::new(11, 0))
+                TimeInterval::new(TimeOfDay::new(9, 0), TimeOfDay::new(11, 0))
             )
-            .with_work_interval(TimeInterval::new(Time::new(10, 0), Time::new(13, 0)))
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(10, 0), TimeOfDay::new(13, 0)))
             .with_activity(Activity {
                 name: "Activity",
                 entities: vec![name1, name2],
-                duration: Time::new(1, 0),
+                duration: Duration::new(1, 0),
                 groups: Vec::new(),
                 ..Default::default()
             }),
@@ -597,7 +597,7 @@ fn possible_insertion_times_takes_heterogeneous_work_hours_of_participants_into_
             assert_eq!(data.activity(id).insertion_costs()
                        .unwrap().iter().map(|insertion_cost| insertion_cost.beginning)
                        .collect::<BTreeSet<_>>(),
-                      [Time::new(10, 0)].iter().copied().collect::<BTreeSet<_>>(),
+                      [TimeOfDay::new(10, 0)].iter().copied().collect::<BTreeSet<_>>(),
               "Insertion times with conflicts with inserted activities were not calculated right.");
         }
     );
@@ -610,19 +610,19 @@ fn activities_with_empty_duration_not_taken_into_account_in_insertion_costs() {
         data,
         DataBuilder::new()
             .with_entities(vec![name1])
-            .with_work_interval(TimeInterval::new(Time::new(10, 0), Time::new(13, 0)))
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(10, 0), TimeOfDay::new(13, 0)))
             .with_activities(vec![
                 Activity {
                     name: "Activity1",
                     entities: vec![name1],
-                    duration: Time::new(1, 0),
+                    duration: Duration::new(1, 0),
                     groups: Vec::new(),
                     ..Default::default()
                 },
                 Activity {
                     name: "Activity2",
                     entities: vec![name1],
-                    duration: Time::new(0, 0),
+                    duration: Duration::new(0, 0),
                     groups: Vec::new(),
                     ..Default::default()
                 }
@@ -652,18 +652,18 @@ fn activities_with_zero_participants_not_taken_into_account_in_insertion_costs()
         data,
         DataBuilder::new()
             .with_entities(vec![name1])
-            .with_work_interval(TimeInterval::new(Time::new(10, 0), Time::new(13, 0)))
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(10, 0), TimeOfDay::new(13, 0)))
             .with_activities(vec![
                 Activity {
                     name: "Activity1",
                     entities: vec![name1],
-                    duration: Time::new(1, 0),
+                    duration: Duration::new(1, 0),
                     groups: Vec::new(),
                     ..Default::default()
                 },
                 Activity {
                     name: "Activity2",
-                    duration: Time::new(1, 0),
+                    duration: Duration::new(1, 0),
                     groups: Vec::new(),
                     ..Default::default()
                 }
@@ -697,10 +697,10 @@ fn possible_insertion_costs_compute_possible_insertions_of_incompatible_activiti
         data,
         DataBuilder::new()
             .with_entities(vec![name1, name2])
-            .with_work_interval(TimeInterval::new(Time::new(9, 0), Time::new(11, 0)))
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(9, 0), TimeOfDay::new(11, 0)))
             .with_activity(Activity {
                 entities: vec![name1, name2],
-                duration: Time::new(0, 10),
+                duration: Duration::new(0, 10),
                 ..Default::default()
             }),
         {
@@ -708,7 +708,7 @@ fn possible_insertion_costs_compute_possible_insertions_of_incompatible_activiti
             data.add_activity("Other activity")
                 .expect("Could not add activity");
             let other_id = data.activities_sorted()[1].id();
-            data.set_activity_duration(other_id, Time::new(1, 0))
+            data.set_activity_duration(other_id, Duration::new(1, 0))
                 .expect("Could not set activity duration");
             data.add_entity_to_activity(other_id, name1)
                 .expect("Could not add participant to activity");
@@ -734,18 +734,18 @@ fn possible_insertion_costs_updated_when_incompatible_activity_inserted() {
         data,
         DataBuilder::new()
         .with_entities(vec![name1])
-        .with_work_interval(TimeInterval::new(Time::new(8, 0), Time::new(12, 0)))
+        .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0)))
         .with_activities(vec![
          Activity {
             name: "Activity1",
             entities: vec![name1],
-            duration: Time::new(1, 0),
+            duration: Duration::new(1, 0),
             ..Default::default()
         },
         Activity {
             name: "Activity2",
             entities: vec![name1],
-            duration: Time::new(1, 0),
+            duration: Duration::new(1, 0),
             ..Default::default()
         }]
         ),
@@ -753,11 +753,11 @@ fn possible_insertion_costs_updated_when_incompatible_activity_inserted() {
             let id1 = data.activities_sorted()[0].id();
             let id2 = data.activities_sorted()[1].id();
 
-            data.insert_activity(id1, Some(Time::new(8, 30))).expect("Could not insert activity");
+            data.insert_activity(id1, Some(TimeOfDay::new(8, 30))).expect("Could not insert activity");
 
             data.wait_for_possible_insertion_costs_computation(id2);
 
-            data.insert_activity(id2, Some(Time::new(8, 0)))
+            data.insert_activity(id2, Some(TimeOfDay::new(8, 0)))
         },
         "Activity2 cannot be inserted with beginning 08:00 because it would overlap with 'Activity1'.",
         "Possible insertion costs were not updated when activity was inserted"
@@ -771,19 +771,19 @@ fn possible_insertion_costs_updated_when_incompatible_activity_removed_from_sche
         data,
         DataBuilder::new()
         .with_entities(vec![entity])
-        .with_work_interval(TimeInterval::new(Time::new(8, 0), Time::new(12, 0)))
+        .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0)))
         .with_activities(vec![
          Activity {
             name: "Activity1",
             entities: vec![entity],
-            duration: Time::new(1, 0),
+            duration: Duration::new(1, 0),
             ..Default::default()
         },
         Activity {
             name: "Activity2",
             entities: vec![entity],
-            duration: Time::new(1, 0),
-            insertion_time: Some(Time::new(10, 0)),
+            duration: Duration::new(1, 0),
+            insertion_time: Some(TimeOfDay::new(10, 0)),
             ..Default::default()
         }]
         ),
@@ -792,13 +792,13 @@ fn possible_insertion_costs_updated_when_incompatible_activity_removed_from_sche
             let id2 = data.activities_sorted()[1].id();
 
             // Interval taken by activity2
-            assert!(data.activity(id1).insertion_costs().expect("Insertion costs not computed").iter().all(|insertion_cost| insertion_cost.beginning != Time::new(10, 0)));
+            assert!(data.activity(id1).insertion_costs().expect("Insertion costs not computed").iter().all(|insertion_cost| insertion_cost.beginning != TimeOfDay::new(10, 0)));
 
             data.insert_activity(id2, None).expect("Could not remove activity from schedule");
             data.wait_for_possible_insertion_costs_computation(id2);
 
             // Interval was freed
-            assert!(data.activity(id1).insertion_costs().expect("Insertion costs not computed").iter().any(|insertion_cost| insertion_cost.beginning == Time::new(10, 0)));
+            assert!(data.activity(id1).insertion_costs().expect("Insertion costs not computed").iter().any(|insertion_cost| insertion_cost.beginning == TimeOfDay::new(10, 0)));
         }
     );
 }
@@ -810,18 +810,18 @@ fn possible_insertion_costs_updated_when_not_inserted_incompatible_activity_dura
         data,
         DataBuilder::new()
         .with_entities(vec![entity])
-        .with_work_interval(TimeInterval::new(Time::new(8, 0), Time::new(12, 0)))
+        .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0)))
         .with_activities(vec![
          Activity {
             name: "Activity1",
             entities: vec![entity],
-            duration: Time::new(1, 0),
+            duration: Duration::new(1, 0),
             ..Default::default()
         },
         Activity {
             name: "Activity2",
             entities: vec![entity],
-            duration: Time::new(1, 0),
+            duration: Duration::new(1, 0),
             ..Default::default()
         }]
         ),
@@ -830,13 +830,13 @@ fn possible_insertion_costs_updated_when_not_inserted_incompatible_activity_dura
             let id2 = data.activities_sorted()[1].id();
 
             // Interval not taken by activity2 YET
-            assert!(data.activity(id1).insertion_costs().expect("Insertion costs not computed").iter().any(|insertion_cost| insertion_cost.beginning == Time::new(10, 0)));
+            assert!(data.activity(id1).insertion_costs().expect("Insertion costs not computed").iter().any(|insertion_cost| insertion_cost.beginning == TimeOfDay::new(10, 0)));
 
-            data.set_activity_duration(id2, Time::new(3, 0)).expect("Could not set activity duration");
+            data.set_activity_duration(id2, Duration::new(3, 0)).expect("Could not set activity duration");
             data.wait_for_possible_insertion_costs_computation(id1);
 
             // Interval taken
-            assert!(data.activity(id1).insertion_costs().expect("Insertion costs not computed").iter().all(|insertion_cost| insertion_cost.beginning != Time::new(10, 0)));
+            assert!(data.activity(id1).insertion_costs().expect("Insertion costs not computed").iter().all(|insertion_cost| insertion_cost.beginning != TimeOfDay::new(10, 0)));
         }
     );
 }
@@ -848,19 +848,19 @@ fn possible_insertion_costs_updated_when_inserted_incompatible_activity_duration
         data,
         DataBuilder::new()
         .with_entities(vec![entity])
-        .with_work_interval(TimeInterval::new(Time::new(8, 0), Time::new(12, 0)))
+        .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0)))
         .with_activities(vec![
          Activity {
             name: "Activity1",
             entities: vec![entity],
-            duration: Time::new(1, 0),
+            duration: Duration::new(1, 0),
             ..Default::default()
         },
         Activity {
             name: "Activity2",
             entities: vec![entity],
-            duration: Time::new(1, 0),
-            insertion_time: Some(Time::new(8, 0)),
+            duration: Duration::new(1, 0),
+            insertion_time: Some(TimeOfDay::new(8, 0)),
             ..Default::default()
         }]
         ),
@@ -869,9 +869,9 @@ fn possible_insertion_costs_updated_when_inserted_incompatible_activity_duration
             let id2 = data.activities_sorted()[1].id();
 
             // Interval not taken by activity2 YET
-            assert!(data.activity(id1).insertion_costs().expect("Insertion costs not computed").iter().any(|insertion_cost| insertion_cost.beginning == Time::new(9, 0)));
+            assert!(data.activity(id1).insertion_costs().expect("Insertion costs not computed").iter().any(|insertion_cost| insertion_cost.beginning == TimeOfDay::new(9, 0)));
 
-            data.set_activity_duration(id2, Time::new(2, 0)).expect("Could not set activity duration");
+            data.set_activity_duration(id2, Duration::new(2, 0)).expect("Could not set activity duration");
             data.wait_for_possible_insertion_costs_computation(id1);
             data.wait_for_possible_insertion_costs_computation(id2);
 
@@ -880,7 +880,7 @@ fn possible_insertion_costs_updated_when_inserted_incompatible_activity_duration
             data.wait_for_possible_insertion_costs_computation(id2);
 
             // Interval taken
-            assert!(data.activity(id1).insertion_costs().expect("Insertion costs not computed").iter().all(|insertion_cost| insertion_cost.beginning != Time::new(9, 0)));
+            assert!(data.activity(id1).insertion_costs().expect("Insertion costs not computed").iter().all(|insertion_cost| insertion_cost.beginning != TimeOfDay::new(9, 0)));
         }
     );
 }
@@ -892,18 +892,18 @@ fn autoinsertion_launches_and_ignores_activities_with_zero_duration() {
         data,
         DataBuilder::new()
             .with_entities(vec![name1])
-            .with_work_interval(TimeInterval::new(Time::new(8, 0), Time::new(12, 0)))
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0)))
             .with_activities(vec![
                 Activity {
                     name: "Activity1",
                     entities: vec![name1],
-                    duration: Time::new(1, 0),
+                    duration: Duration::new(1, 0),
                     ..Default::default()
                 },
                 Activity {
                     name: "Activity2",
                     entities: vec![name1],
-                    duration: Time::new(0, 0),
+                    duration: Duration::new(0, 0),
                     ..Default::default()
                 }
             ]),
@@ -929,17 +929,17 @@ fn autoinsertion_launches_and_ignores_activities_with_no_participants() {
         data,
         DataBuilder::new()
             .with_entities(vec![name1])
-            .with_work_interval(TimeInterval::new(Time::new(8, 0), Time::new(12, 0)))
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0)))
             .with_activities(vec![
                 Activity {
                     name: "Activity1",
                     entities: vec![name1],
-                    duration: Time::new(1, 0),
+                    duration: Duration::new(1, 0),
                     ..Default::default()
                 },
                 Activity {
                     name: "Activity2",
-                    duration: Time::new(1, 0),
+                    duration: Duration::new(1, 0),
                     ..Default::default()
                 }
             ]),
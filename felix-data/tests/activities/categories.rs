@@ -0,0 +1,216 @@
+//! Categories and tags of activities.
+//!
+//! Includes:
+//! - Colors of categories
+//! - Queries of activities, entities and groups by category and tag
+//! - Filters combining tags and categories
+
+use felix_data::{ActivityFilter, Details, Rgba};
+use felix_test_utils::{Activity, DataBuilder, Group};
+
+const RED: Rgba = Rgba {
+    red: 1.0,
+    green: 0.0,
+    blue: 0.0,
+    alpha: 1.0,
+};
+
+fn details_with_tag(tag: &str) -> Details {
+    let mut details = Details::default();
+    details.tags.insert(tag.to_owned());
+    details
+}
+
+#[test]
+fn set_category_color_colors_activities_of_category() {
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_activity(Activity {
+                name: "Football",
+                category: Some("Sports"),
+                ..Default::default()
+            })
+            .with_activity(Activity {
+                name: "Lunch",
+                category: Some("Meals"),
+                ..Default::default()
+            }),
+        {
+            let lunch_color = data.activities_sorted()[1].color();
+            data.set_category_color("sports", Some(RED))
+                .expect("Could not set category color");
+
+            let activities = data.activities_sorted();
+            assert_eq!(activities[0].color(), RED, "Activity was not colored");
+            assert_eq!(
+                activities[1].color(),
+                lunch_color,
+                "Activity of another category was colored"
+            );
+            assert_eq!(data.category_color("Sports"), Some(RED));
+            assert_eq!(data.categories(), vec!["Meals", "Sports"]);
+        }
+    );
+}
+
+#[test]
+fn activity_takes_color_when_joining_category() {
+    test_ok!(
+        data,
+        DataBuilder::new().with_activity(Activity::default()),
+        {
+            data.set_category_color("Sports", Some(RED))
+                .expect("Could not set category color");
+            assert_eq!(data.categories(), vec!["Sports"]);
+
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_category(id, Some("Sports"))
+                .expect("Could not set activity category");
+            assert_eq!(data.activity(id).color(), RED);
+        }
+    );
+}
+
+#[test]
+fn remove_category_color() {
+    test_ok!(
+        data,
+        DataBuilder::new().with_activity(Activity {
+            category: Some("Sports"),
+            ..Default::default()
+        }),
+        {
+            data.set_category_color("Sports", Some(RED))
+                .expect("Could not set category color");
+            data.set_category_color("Sports", None)
+                .expect("Could not remove category color");
+            assert_eq!(data.category_color("Sports"), None);
+            assert_eq!(
+                data.activities_sorted()[0].color(),
+                RED,
+                "Activity color changed when the category color was removed"
+            );
+        }
+    );
+}
+
+#[test]
+fn set_color_of_empty_category() {
+    test_err!(
+        data,
+        DataBuilder::new(),
+        data.set_category_color(" ", Some(RED)),
+        "The given name is empty.",
+        "Could set the color of an empty category"
+    );
+}
+
+#[test]
+fn activities_of_category() {
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_activity(Activity {
+                name: "Football",
+                category: Some("Sports"),
+                ..Default::default()
+            })
+            .with_activity(Activity {
+                name: "Lunch",
+                category: Some("Meals"),
+                ..Default::default()
+            }),
+        {
+            let activities = data.activities_of_category("sports");
+            assert_eq!(activities.len(), 1);
+            assert_eq!(activities[0].name(), "Football");
+            assert!(data.activities_of_category("Workshops").is_empty());
+        }
+    );
+}
+
+#[test]
+fn activities_entities_and_groups_with_tag() {
+    let (entity1, entity2, group) = ("Anna", "Paul", "Band");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entities(vec![entity1, entity2])
+            .with_group(Group::default(group))
+            .with_activity(Activity {
+                name: "Football",
+                ..Default::default()
+            })
+            .with_activity(Activity {
+                name: "Lunch",
+                ..Default::default()
+            }),
+        {
+            data.set_entity_details(entity2, details_with_tag("Outdoor"))
+                .expect("Could not set entity details");
+            data.set_group_details(group, details_with_tag("Outdoor"))
+                .expect("Could not set group details");
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_details(id, details_with_tag("Outdoor"))
+                .expect("Could not set activity details");
+
+            let entities = data
+                .entities_with_tag("outdoor")
+                .iter()
+                .map(|entity| entity.name())
+                .collect::<Vec<_>>();
+            assert_eq!(entities, vec![entity2]);
+            assert_eq!(data.groups_with_tag("outdoor").len(), 1);
+            let activities = data.activities_with_tag("outdoor");
+            assert_eq!(activities.len(), 1);
+            assert_eq!(activities[0].name(), "Football");
+            assert!(data.activities_with_tag("indoor").is_empty());
+        }
+    );
+}
+
+#[test]
+fn filter_activities_by_tag_and_category() {
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_activity(Activity {
+                name: "Football",
+                category: Some("Sports"),
+                ..Default::default()
+            })
+            .with_activity(Activity {
+                name: "Swimming",
+                category: Some("Sports"),
+                ..Default::default()
+            })
+            .with_activity(Activity {
+                name: "Picnic",
+                category: Some("Meals"),
+                ..Default::default()
+            }),
+        {
+            for activity in data.activities_sorted() {
+                if activity.name() != "Swimming" {
+                    data.set_activity_details(activity.id(), details_with_tag("Outdoor"))
+                        .expect("Could not set activity details");
+                }
+            }
+
+            let mut filter = ActivityFilter::with_tag("Outdoor");
+            assert_eq!(data.activities_filtered(&filter).len(), 2);
+
+            filter.categories.insert("Sports".to_owned());
+            let activities = data.activities_filtered(&filter);
+            assert_eq!(activities.len(), 1);
+            assert_eq!(activities[0].name(), "Football");
+
+            assert_eq!(
+                data.activities_filtered(&ActivityFilter::default()).len(),
+                3,
+                "Empty filter did not keep every activity"
+            );
+        }
+    );
+}
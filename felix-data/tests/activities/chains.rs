@@ -0,0 +1,365 @@
+//! Activities which must take place one right after the other.
+//!
+//! Includes:
+//! - Chaining and unchaining activities
+//! - Possible insertion costs of chained activities
+//! - Insertion and removal from the schedule of chained activities
+//! - Autoinsertion of chains as single blocks
+
+use felix_data::{Duration, TimeInterval, TimeOfDay};
+use felix_test_utils::{Activity, DataBuilder};
+
+/// A briefing followed by a field trip, with the same participant.
+fn briefing_and_field_trip() -> DataBuilder {
+    DataBuilder::new()
+        .with_entity("Entity")
+        .with_work_interval(TimeInterval::new(
+            TimeOfDay::new(8, 0),
+            TimeOfDay::new(12, 0),
+        ))
+        .with_activities(vec![
+            Activity {
+                name: "Briefing",
+                entities: vec!["Entity"],
+                duration: Duration::new(0, 30),
+                ..Default::default()
+            },
+            Activity {
+                name: "Field Trip",
+                entities: vec!["Entity"],
+                duration: Duration::new(2, 0),
+                ..Default::default()
+            },
+        ])
+}
+
+#[test]
+fn simple_chain_activities() {
+    test_ok!(data, briefing_and_field_trip(), {
+        let activities = data.activities_sorted();
+        let (briefing_id, field_trip_id) = (activities[0].id(), activities[1].id());
+
+        data.chain_activities(briefing_id, field_trip_id, Duration::new(0, 15))
+            .expect("Could not chain activities");
+
+        let chain = data
+            .activity(field_trip_id)
+            .chain()
+            .expect("Activities were not chained");
+        assert_eq!(chain.activity_ids(), &vec![briefing_id, field_trip_id]);
+        assert_eq!(chain.offsets(), &vec![Duration::new(0, 15)]);
+        assert_eq!(data.activity(briefing_id).chain(), Some(chain));
+    });
+}
+
+#[test]
+fn chain_activities_joins_chains() {
+    test_ok!(
+        data,
+        briefing_and_field_trip()
+            .with_activity(Activity {
+                name: "Debriefing",
+                ..Default::default()
+            })
+            .with_chained_activities("Briefing", "Field Trip", Duration::new(0, 0)),
+        {
+            let activities = data.activities_sorted();
+            let (briefing_id, debriefing_id, field_trip_id) =
+                (activities[0].id(), activities[1].id(), activities[2].id());
+
+            data.chain_activities(field_trip_id, debriefing_id, Duration::new(0, 5))
+                .expect("Could not chain activities");
+
+            let chain = data
+                .activity(briefing_id)
+                .chain()
+                .expect("Activities were not chained");
+            assert_eq!(
+                chain.activity_ids(),
+                &vec![briefing_id, field_trip_id, debriefing_id]
+            );
+            assert_eq!(
+                chain.offsets(),
+                &vec![Duration::new(0, 0), Duration::new(0, 5)]
+            );
+        }
+    );
+}
+
+#[test]
+fn chain_activity_already_followed() {
+    test_err!(
+        data,
+        briefing_and_field_trip()
+            .with_activity(Activity {
+                name: "Lunch",
+                ..Default::default()
+            })
+            .with_chained_activities("Briefing", "Field Trip", Duration::new(0, 0)),
+        {
+            let activities = data.activities_sorted();
+            data.chain_activities(activities[0].id(), activities[2].id(), Duration::new(0, 0))
+        },
+        "'Briefing' is already followed by another activity.",
+        "Could chain two activities after the same one"
+    );
+}
+
+#[test]
+fn chain_activity_already_preceded() {
+    test_err!(
+        data,
+        briefing_and_field_trip()
+            .with_activity(Activity {
+                name: "Lunch",
+                ..Default::default()
+            })
+            .with_chained_activities("Briefing", "Field Trip", Duration::new(0, 0)),
+        {
+            let activities = data.activities_sorted();
+            data.chain_activities(activities[2].id(), activities[1].id(), Duration::new(0, 0))
+        },
+        "'Field Trip' already follows another activity.",
+        "Could chain two activities before the same one"
+    );
+}
+
+#[test]
+fn chain_activities_in_a_loop() {
+    test_err!(
+        data,
+        briefing_and_field_trip().with_chained_activities(
+            "Briefing",
+            "Field Trip",
+            Duration::new(0, 0)
+        ),
+        {
+            let activities = data.activities_sorted();
+            data.chain_activities(activities[1].id(), activities[0].id(), Duration::new(0, 0))
+        },
+        "'Briefing' cannot be chained to an activity of its own chain.",
+        "Could chain activities in a loop"
+    );
+}
+
+#[test]
+fn chain_split_activity() {
+    test_err!(
+        data,
+        briefing_and_field_trip().with_activity(Activity {
+            name: "Workshop",
+            duration: Duration::new(2, 0),
+            sessions: 2,
+            ..Default::default()
+        }),
+        {
+            let activities = data.activities_sorted();
+            data.chain_activities(activities[0].id(), activities[2].id(), Duration::new(0, 0))
+        },
+        "'Workshop' cannot be chained because it is split into sessions or begins at the same time as other activities.",
+        "Could chain a split activity"
+    );
+}
+
+#[test]
+fn split_chained_activity() {
+    test_err!(
+        data,
+        briefing_and_field_trip().with_chained_activities("Briefing", "Field Trip", Duration::new(0, 0)),
+        {
+            let id = data.activities_sorted()[1].id();
+            data.split_activity(id, 2, Default::default())
+        },
+        "'Field Trip' cannot be split into sessions or begin at the same time as other activities because it is chained.",
+        "Could split a chained activity"
+    );
+}
+
+#[test]
+fn chained_activities_possible_insertion_costs() {
+    test_ok!(
+        data,
+        briefing_and_field_trip().with_chained_activities(
+            "Briefing",
+            "Field Trip",
+            Duration::new(0, 15)
+        ),
+        {
+            let activities = data.activities_sorted();
+            let beginnings_of = |id| {
+                data.activity(id)
+                    .insertion_costs()
+                    .expect("Insertion costs were not computed")
+                    .iter()
+                    .map(|insertion_cost| insertion_cost.beginning)
+                    .collect::<Vec<_>>()
+            };
+
+            // The whole block lasts 02:45
+            let briefing_beginnings = beginnings_of(activities[0].id());
+            assert_eq!(briefing_beginnings.first(), Some(&TimeOfDay::new(8, 0)));
+            assert_eq!(briefing_beginnings.last(), Some(&TimeOfDay::new(9, 15)));
+
+            // The field trip begins 00:45 after the briefing
+            let field_trip_beginnings = beginnings_of(activities[1].id());
+            assert_eq!(field_trip_beginnings.first(), Some(&TimeOfDay::new(8, 45)));
+            assert_eq!(field_trip_beginnings.last(), Some(&TimeOfDay::new(10, 0)));
+        }
+    );
+}
+
+#[test]
+fn insert_chained_activity_moves_all() {
+    test_ok!(
+        data,
+        briefing_and_field_trip().with_chained_activities(
+            "Briefing",
+            "Field Trip",
+            Duration::new(0, 15)
+        ),
+        {
+            let activities = data.activities_sorted();
+            let (briefing_id, field_trip_id) = (activities[0].id(), activities[1].id());
+
+            data.insert_activity(field_trip_id, Some(TimeOfDay::new(9, 0)))
+                .expect("Could not insert activity");
+            assert_eq!(
+                data.activity(briefing_id).insertion_interval(),
+                Some(TimeInterval::new(
+                    TimeOfDay::new(8, 15),
+                    TimeOfDay::new(8, 45)
+                )),
+                "The previous activity was not inserted"
+            );
+
+            data.wait_for_possible_insertion_costs_computation(briefing_id);
+            data.insert_activity(briefing_id, Some(TimeOfDay::new(9, 0)))
+                .expect("Could not move activity");
+            assert_eq!(
+                data.activity(field_trip_id).insertion_interval(),
+                Some(TimeInterval::new(
+                    TimeOfDay::new(9, 45),
+                    TimeOfDay::new(11, 45)
+                )),
+                "The next activity was not moved"
+            );
+
+            data.insert_activity(field_trip_id, None)
+                .expect("Could not remove activity from schedule");
+            assert_eq!(
+                data.activity(briefing_id).insertion_interval(),
+                None,
+                "The previous activity was not removed from the schedule"
+            );
+        }
+    );
+}
+
+#[test]
+fn unchain_activity_in_the_middle() {
+    test_ok!(
+        data,
+        briefing_and_field_trip()
+            .with_activities(vec![
+                Activity {
+                    name: "Debriefing",
+                    ..Default::default()
+                },
+                Activity {
+                    name: "Lunch",
+                    ..Default::default()
+                }
+            ])
+            .with_chained_activities("Briefing", "Field Trip", Duration::new(0, 0))
+            .with_chained_activities("Field Trip", "Debriefing", Duration::new(0, 5))
+            .with_chained_activities("Debriefing", "Lunch", Duration::new(0, 10)),
+        {
+            let activities = data.activities_sorted();
+            let (briefing_id, debriefing_id, field_trip_id, lunch_id) = (
+                activities[0].id(),
+                activities[1].id(),
+                activities[2].id(),
+                activities[3].id(),
+            );
+
+            data.unchain_activity(field_trip_id)
+                .expect("Could not unchain activity");
+
+            assert_eq!(data.activity(briefing_id).chain(), None);
+            assert_eq!(data.activity(field_trip_id).chain(), None);
+            let chain = data
+                .activity(lunch_id)
+                .chain()
+                .expect("Remaining activities were unchained");
+            assert_eq!(chain.activity_ids(), &vec![debriefing_id, lunch_id]);
+            assert_eq!(chain.offsets(), &vec![Duration::new(0, 10)]);
+        }
+    );
+}
+
+#[test]
+fn unchain_activity_which_is_not_chained() {
+    test_err!(
+        data,
+        briefing_and_field_trip(),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.unchain_activity(id)
+        },
+        "'Briefing' is not chained to other activities.",
+        "Could unchain an activity which is not chained"
+    );
+}
+
+#[test]
+fn remove_chained_activity() {
+    test_ok!(
+        data,
+        briefing_and_field_trip().with_chained_activities(
+            "Briefing",
+            "Field Trip",
+            Duration::new(0, 0)
+        ),
+        {
+            let activities = data.activities_sorted();
+            data.remove_activity(activities[0].id());
+            assert_eq!(data.activity(activities[1].id()).chain(), None);
+        }
+    );
+}
+
+#[test]
+fn autoinsert_chained_activities() {
+    test_ok!(
+        data,
+        briefing_and_field_trip()
+            .with_activity(Activity {
+                name: "Meeting",
+                entities: vec!["Entity"],
+                duration: Duration::new(1, 0),
+                ..Default::default()
+            })
+            .with_chained_activities("Briefing", "Field Trip", Duration::new(0, 15)),
+        {
+            let result = data
+                .start_autoinsertion()
+                .expect("Could not start autoinsertion")
+                .get_final_result()
+                .expect("Autoinsertion failed");
+            data.apply_autoinsertion_result(result);
+
+            let activities = data.activities_sorted();
+            let briefing = activities[0]
+                .insertion_interval()
+                .expect("Briefing was not inserted");
+            let field_trip = activities[1]
+                .insertion_interval()
+                .expect("Field trip was not inserted");
+            assert_eq!(
+                field_trip.beginning(),
+                briefing.end() + Duration::new(0, 15)
+            );
+            assert!(activities[2].insertion_interval().is_some());
+        }
+    );
+}
@@ -7,7 +7,7 @@
 //! - Changing the duration of the activity (makes sure all entities have enough time)
 //! - Making sure insertion costs change when entities are added / removed
 
-use felix_data::{Time, TimeInterval};
+use felix_data::{Duration, TimeInterval, TimeOfDay};
 use felix_test_utils::{Activity, DataBuilder};
 
 // *** Add entities ***
@@ -24,7 +24,7 @@ fn simple_add_entity() {
             let id = data.activities_sorted()[0].id();
             data.add_entity_to_activity(id, name)
                 .expect("Could not add entity to activity");
-            let entities = data.activity(id).entities_sorted();
+            let entities = data.entities_of_activity(id);
             assert_eq!(entities.len(), 1, "Participant was not added");
             assert_eq!(entities[0], name, "Participant was added with wrong name");
         }
@@ -45,7 +45,7 @@ fn add_entities_check_sorting() {
             }),
         {
             let id = data.activities_sorted()[0].id();
-            let entities = data.activity(id).entities_sorted();
+            let entities = data.entities_of_activity(id);
             assert_eq!(entities.len(), 3, "Participants were not added");
             assert_eq!(entities[0], name1, "Participants are not sorted");
             assert_eq!(entities[1], name2, "Participants are not sorted");
@@ -75,8 +75,8 @@ fn add_entity_not_enough_time() {
 fn add_entity_already_participating() {
     let (entity_name, activity_name) = ("Entity", "Activity");
     // Add an insertion time to make sure that the activity is not detected as overlapping
-    let beginning = Time::new(8, 0);
-    let end = Time::new(10, 0);
+    let beginning = TimeOfDay::new(8, 0);
+    let end = TimeOfDay::new(10, 0);
     let time_interval = TimeInterval::new(beginning, end);
     test_err!(
         data,
@@ -132,8 +132,8 @@ fn add_entity_does_not_exist() {
 fn add_entity_to_inserted_activity_invalid_spot() {
     let (entity1, entity2) = ("Entity1", "Entity2");
     let (activity1, activity2) = ("Activity1", "Activity2");
-    let beginning = Time::new(9, 0);
-    let end = Time::new(13, 0);
+    let beginning = TimeOfDay::new(9, 0);
+    let end = TimeOfDay::new(13, 0);
     let time_interval = TimeInterval::new(beginning, end);
 
     test_err!(
@@ -145,16 +145,18 @@ fn add_entity_to_inserted_activity_invalid_spot() {
                 Activity {
                     name: activity1,
                     entities: vec![entity1],
-                    duration: Time::new(1, 0),
+                    duration: Duration::new(1, 0),
                     groups: Vec::new(),
                     insertion_time: Some(beginning),
+                    ..Default::default()
                 },
                 Activity {
                     name: activity2,
                     entities: vec![entity2],
-                    duration: Time::new(1, 0),
+                    duration: Duration::new(1, 0),
                     groups: Vec::new(),
                     insertion_time: Some(beginning),
+                    ..Default::default()
                 }
             ]),
         {
@@ -172,12 +174,12 @@ fn add_entity_to_inserted_activity_invalid_spot() {
 fn add_entity_to_inserted_activity_spot_not_in_work_hours() {
     let (entity1, entity2) = ("Entity1", "Entity2");
     let activity1 = "Activity1";
-    let beginning1 = Time::new(9, 0);
-    let end1 = Time::new(13, 0);
+    let beginning1 = TimeOfDay::new(9, 0);
+    let end1 = TimeOfDay::new(13, 0);
     let time_interval1 = TimeInterval::new(beginning1, end1);
 
-    let beginning2 = Time::new(11, 0);
-    let end2 = Time::new(13, 0);
+    let beginning2 = TimeOfDay::new(11, 0);
+    let end2 = TimeOfDay::new(13, 0);
     let time_interval2 = TimeInterval::new(beginning2, end2);
 
     test_err!(
@@ -189,9 +191,10 @@ fn add_entity_to_inserted_activity_spot_not_in_work_hours() {
             .with_activity(Activity {
                 name: activity1,
                 entities: vec![entity1],
-                duration: Time::new(1, 0),
+                duration: Duration::new(1, 0),
                 groups: Vec::new(),
                 insertion_time: Some(beginning1),
+                ..Default::default()
             },),
         {
             let id1 = data.activities_sorted()[0].id();
@@ -221,7 +224,7 @@ fn simple_remove_entity() {
             let id = data.activities_sorted()[0].id();
             data.remove_entity_from_activity(id, entity1)
                 .expect("Could not remove entity");
-            let entities = data.activity(id).entities_sorted();
+            let entities = data.entities_of_activity(id);
 
             assert_eq!(entities.len(), 1, "Participant was not removed");
             assert_eq!(entities[0], entity2, "The wrong entity was removed");
@@ -280,8 +283,8 @@ fn remove_entity_wrong_activity_id() {
 #[test]
 fn remove_last_entity_check_activity_uninserted() {
     let (entity1, entity2) = ("Entity1", "Entity2");
-    let (beginning, end) = (Time::new(8, 0), Time::new(12, 0));
-    let duration = Time::new(1, 0);
+    let (beginning, end) = (TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    let duration = Duration::new(1, 0);
     test_ok!(
         data,
         DataBuilder::new()
@@ -331,14 +334,14 @@ fn set_activity_duration_not_enough_free_time() {
             .with_entity(entity_name)
             .with_work_interval_of_duration(1)
             .with_activity(Activity {
-                duration: Time::new(1, 0),
+                duration: Duration::new(1, 0),
                 entities: vec![entity_name],
                 ..Default::default()
             }),
         {
             let id = data.activities_sorted()[0].id();
 
-            data.set_activity_duration(id, Time::new(2, 0))
+            data.set_activity_duration(id, Duration::new(2, 0))
         },
         "Entity will not have enough time if the duration of 'Activity' is increased.",
         "Could set duration where an entity has not enough free time"
@@ -354,7 +357,7 @@ fn add_entity_to_activity_check_insertion_costs_updated() {
              .with_entity(entity_name)
              .with_work_interval_of_duration(1)
              .with_activity(Activity {
-                duration: Time::new(0, 30),
+                duration: Duration::new(0, 30),
                 ..Default::default()
              }),
      {
@@ -373,10 +376,10 @@ fn remove_entity_from_activity_check_insertion_costs_updated() {
     test_ok!(data,
              DataBuilder::new()
              .with_entities(vec![entity1, entity2])
-             .with_custom_work_interval_for(entity1, TimeInterval::new(Time::new(10, 0), Time::new(12, 0)))
-             .with_work_interval(TimeInterval::new(Time::new(8, 0), Time::new(12, 0)))
+             .with_custom_work_interval_for(entity1, TimeInterval::new(TimeOfDay::new(10, 0), TimeOfDay::new(12, 0)))
+             .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0)))
              .with_activity(Activity {
-                duration: Time::new(0, 30),
+                duration: Duration::new(0, 30),
                 entities: vec![entity1, entity2],
                 ..Default::default()
              }),
@@ -403,7 +406,7 @@ fn remove_last_entity_from_activity_check_insertion_costs_updated() {
              .with_entity(entity_name)
              .with_work_interval_of_duration(1)
              .with_activity(Activity {
-                duration: Time::new(0, 30),
+                duration: Duration::new(0, 30),
                 entities: vec![entity_name],
                 ..Default::default()
              }),
@@ -0,0 +1,376 @@
+//! Activities which may last anywhere in a duration range.
+//!
+//! Includes:
+//! - Choice of the duration given the duration objective
+//! - Durations limited by the time the other activities need
+//! - Several activities with ranges in the same schedule
+//! - Insertion with the chosen duration
+//! - Changes of range of inserted activities
+//! - Load limits checked with the longest duration
+
+use felix_data::{Duration, DurationObjective, LoadLimits, TimeInterval, TimeOfDay};
+use felix_test_utils::{Activity, DataBuilder};
+
+/// Returns the duration of the insertion cost of the activity beginning at the given time.
+fn duration_if_inserted_at(data: &felix_data::Data, id: usize, time: TimeOfDay) -> Duration {
+    data.activity(id)
+        .insertion_costs()
+        .expect("Insertion costs were not computed")
+        .iter()
+        .find(|insertion_cost| insertion_cost.beginning == time)
+        .expect("The activity cannot begin at the given time")
+        .duration
+}
+
+#[test]
+fn longest_duration_chosen() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(12, 0)
+            ))
+            .with_activity(Activity {
+                name: "Workshop",
+                entities: vec![entity],
+                duration: Duration::new(1, 0),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_max_duration(id, Some(Duration::new(1, 30)))
+                .expect("Could not set maximum duration");
+            assert_eq!(data.activity(id).duration(), Duration::new(1, 0));
+            assert_eq!(data.activity(id).max_duration(), Duration::new(1, 30));
+            assert_eq!(
+                data.activity(id).duration_objective(),
+                DurationObjective::Longest
+            );
+
+            data.wait_for_possible_insertion_costs_computation(id);
+            assert_eq!(
+                duration_if_inserted_at(&data, id, TimeOfDay::new(8, 0)),
+                Duration::new(1, 30)
+            );
+            assert_eq!(
+                duration_if_inserted_at(&data, id, TimeOfDay::new(10, 45)),
+                Duration::new(1, 15),
+                "The longest duration which fits at the end of the work hours was not chosen"
+            );
+
+            data.insert_activity(id, Some(TimeOfDay::new(8, 0)))
+                .expect("Could not insert activity");
+            assert_eq!(
+                data.activity(id).insertion_interval(),
+                Some(TimeInterval::new(
+                    TimeOfDay::new(8, 0),
+                    TimeOfDay::new(9, 30)
+                ))
+            );
+            assert_eq!(
+                data.free_time_of(entity).unwrap(),
+                Duration::new(3, 0),
+                "Free time does not use the shortest duration"
+            );
+        }
+    );
+}
+
+#[test]
+fn shortest_duration_chosen() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval_of_duration(4)
+            .with_activity(Activity {
+                name: "Workshop",
+                entities: vec![entity],
+                duration: Duration::new(1, 0),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_max_duration(id, Some(Duration::new(1, 30)))
+                .expect("Could not set maximum duration");
+            data.set_activity_duration_objective(id, DurationObjective::Shortest)
+                .expect("Could not set duration objective");
+
+            data.wait_for_possible_insertion_costs_computation(id);
+            assert!(data
+                .activity(id)
+                .insertion_costs()
+                .expect("Insertion costs were not computed")
+                .iter()
+                .all(|insertion_cost| insertion_cost.duration == Duration::new(1, 0)));
+        }
+    );
+}
+
+#[test]
+fn max_duration_shorter_than_duration() {
+    test_err!(
+        data,
+        DataBuilder::new().with_activity(Activity {
+            name: "Workshop",
+            duration: Duration::new(1, 0),
+            ..Default::default()
+        }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_max_duration(id, Some(Duration::new(0, 30)))
+        },
+        "The given duration is too short.",
+        "Could set a maximum duration shorter than the duration"
+    );
+}
+
+#[test]
+fn duration_limited_by_other_activities() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(11, 0)
+            ))
+            .with_activities(vec![
+                Activity {
+                    name: "Workshop",
+                    entities: vec![entity],
+                    duration: Duration::new(1, 0),
+                    ..Default::default()
+                },
+                Activity {
+                    name: "Talk",
+                    entities: vec![entity],
+                    duration: Duration::new(1, 30),
+                    ..Default::default()
+                }
+            ]),
+        {
+            let id = data.activities_sorted()[1].id();
+            assert_eq!(data.activity(id).name(), "Workshop");
+            data.set_activity_max_duration(id, Some(Duration::new(3, 0)))
+                .expect("Could not set maximum duration");
+
+            // The talk needs 01:30, leaving 01:30 at most for the workshop
+            data.wait_for_possible_insertion_costs_computation(id);
+            let longest_duration = data
+                .activity(id)
+                .insertion_costs()
+                .expect("Insertion costs were not computed")
+                .iter()
+                .map(|insertion_cost| insertion_cost.duration)
+                .max();
+            assert_eq!(longest_duration, Some(Duration::new(1, 30)));
+        }
+    );
+}
+
+#[test]
+fn two_ranges_in_the_same_schedule() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(11, 0)
+            ))
+            .with_activities(vec![
+                Activity {
+                    name: "Workshop",
+                    entities: vec![entity],
+                    duration: Duration::new(1, 0),
+                    ..Default::default()
+                },
+                Activity {
+                    name: "Talk",
+                    entities: vec![entity],
+                    duration: Duration::new(1, 0),
+                    ..Default::default()
+                }
+            ]),
+        {
+            let activities = data.activities_sorted();
+            let (talk_id, workshop_id) = (activities[0].id(), activities[1].id());
+            for id in [talk_id, workshop_id] {
+                data.set_activity_max_duration(id, Some(Duration::new(2, 0)))
+                    .expect("Could not set maximum duration");
+            }
+
+            // Each duration is computed with the other activity lasting its shortest duration,
+            // so both may last 02:00 even though they do not fit together this way
+            data.wait_for_possible_insertion_costs_computation(talk_id);
+            data.wait_for_possible_insertion_costs_computation(workshop_id);
+            assert_eq!(
+                duration_if_inserted_at(&data, talk_id, TimeOfDay::new(8, 0)),
+                Duration::new(2, 0)
+            );
+            assert_eq!(
+                duration_if_inserted_at(&data, workshop_id, TimeOfDay::new(8, 0)),
+                Duration::new(2, 0)
+            );
+
+            // Once the talk is inserted, only the remaining time is left for the workshop
+            data.insert_activity(talk_id, Some(TimeOfDay::new(8, 0)))
+                .expect("Could not insert activity");
+            data.wait_for_possible_insertion_costs_computation(workshop_id);
+            assert_eq!(
+                duration_if_inserted_at(&data, workshop_id, TimeOfDay::new(10, 0)),
+                Duration::new(1, 0)
+            );
+        }
+    );
+}
+
+#[test]
+fn change_range_of_inserted_activity() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(12, 0)
+            ))
+            .with_activity(Activity {
+                name: "Workshop",
+                entities: vec![entity],
+                duration: Duration::new(1, 0),
+                insertion_time: Some(TimeOfDay::new(8, 0)),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_max_duration(id, Some(Duration::new(2, 0)))
+                .expect("Could not set maximum duration");
+            assert!(
+                data.activity(id).insertion_interval().is_none(),
+                "Activity whose range changed was not removed from the schedule"
+            );
+
+            data.wait_for_possible_insertion_costs_computation(id);
+            data.insert_activities_removed_because_duration_increased_in_closest_spot();
+            assert_eq!(
+                data.activity(id).insertion_interval(),
+                Some(TimeInterval::new(
+                    TimeOfDay::new(8, 0),
+                    TimeOfDay::new(10, 0)
+                )),
+                "Activity was not inserted again with its longest duration"
+            );
+
+            data.set_activity_max_duration(id, None)
+                .expect("Could not remove maximum duration");
+            assert!(!data.activity(id).has_flexible_duration());
+            assert_eq!(data.activity(id).max_duration(), Duration::new(1, 0));
+        }
+    );
+}
+
+#[test]
+fn range_of_recurring_and_duplicated_activities() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval_of_duration(8)
+            .with_activity(Activity {
+                name: "Snack",
+                entities: vec![entity],
+                duration: Duration::new(0, 15),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            let occurrence_ids = data
+                .set_activity_occurrences(id, 2, Duration::new(2, 0))
+                .expect("Could not set activity occurrences");
+            data.set_activity_max_duration(occurrence_ids[1], Some(Duration::new(0, 30)))
+                .expect("Could not set maximum duration");
+            for &occurrence_id in &occurrence_ids {
+                assert_eq!(
+                    data.activity(occurrence_id).max_duration(),
+                    Duration::new(0, 30)
+                );
+            }
+
+            let copies = data
+                .duplicate_activity(id, 1)
+                .expect("Could not duplicate activity");
+            assert_eq!(copies[0].max_duration(), Duration::new(0, 30));
+        }
+    );
+}
+
+#[test]
+fn load_limits_checked_with_longest_duration() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(12, 0)
+            ))
+            .with_activity(Activity {
+                name: "Workshop",
+                entities: vec![entity],
+                duration: Duration::new(1, 0),
+                ..Default::default()
+            })
+            .with_activity(Activity {
+                name: "Meeting",
+                duration: Duration::new(1, 0),
+                ..Default::default()
+            }),
+        {
+            let limits = LoadLimits {
+                max_activity_time: Some(Duration::new(2, 0)),
+                ..LoadLimits::default()
+            };
+            data.set_entity_load_limits(entity, limits)
+                .expect("Could not set load limits");
+
+            let workshop_id = data.activities_sorted()[1].id();
+            assert_eq!(
+                data.set_activity_max_duration(workshop_id, Some(Duration::new(2, 30)))
+                    .expect_err("Could set a maximum duration past the load limits")
+                    .to_string(),
+                "Entity would spend too much time in activities if the duration of 'Workshop' is increased."
+            );
+
+            data.set_activity_max_duration(workshop_id, Some(Duration::new(1, 30)))
+                .expect("Could not set maximum duration");
+            let meeting_id = data.activities_sorted()[0].id();
+            assert!(
+                data.add_entity_to_activity(meeting_id, entity).is_err(),
+                "The workshop could be inserted past the load limits with its longest duration"
+            );
+
+            data.wait_for_possible_insertion_costs_computation(workshop_id);
+            data.insert_activity(workshop_id, Some(TimeOfDay::new(8, 0)))
+                .expect("Could not insert activity");
+            assert_eq!(
+                data.activity(workshop_id).insertion_interval(),
+                Some(TimeInterval::new(
+                    TimeOfDay::new(8, 0),
+                    TimeOfDay::new(9, 30)
+                )),
+                "Activity was not inserted with its longest duration"
+            );
+        }
+    );
+}
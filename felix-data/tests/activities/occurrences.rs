@@ -0,0 +1,361 @@
+//! Recurring activities which take place several times.
+//!
+//! Includes:
+//! - Addition and removal of occurrences
+//! - Enforcement of time and load limits for every occurrence
+//! - Spacing between occurrences
+
+use felix_data::{Duration, LoadLimits, SessionRules, TimeInterval, TimeOfDay};
+use felix_test_utils::{Activity, DataBuilder};
+
+#[test]
+fn simple_set_activity_occurrences() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval_of_duration(8)
+            .with_activity(Activity {
+                name: "Snack",
+                entities: vec![entity],
+                duration: Duration::new(0, 30),
+                category: Some("Meal"),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            let occurrence_ids = data
+                .set_activity_occurrences(id, 3, Duration::new(2, 0))
+                .expect("Could not set activity occurrences");
+
+            assert_eq!(occurrence_ids.len(), 3);
+            assert_eq!(occurrence_ids[0], id, "The first occurrence changed id");
+            for &occurrence_id in &occurrence_ids {
+                let occurrence = data.activity(occurrence_id);
+                assert_eq!(occurrence.name(), "Snack");
+                assert_eq!(occurrence.duration(), Duration::new(0, 30));
+                assert_eq!(occurrence.category(), Some("Meal".to_owned()));
+                assert_eq!(data.entities_of_activity(occurrence_id), vec![entity]);
+                assert_eq!(occurrence.main_session_id(), id);
+                assert_eq!(occurrence.number_of_occurrences(), 3);
+            }
+            let sessions = data
+                .activity(id)
+                .sessions()
+                .expect("Activity is not recurring");
+            assert!(sessions.is_recurring());
+            assert_eq!(sessions.min_spacing(), Duration::new(2, 0));
+            assert_eq!(
+                data.free_time_of(entity).unwrap(),
+                Duration::new(6, 30),
+                "Every occurrence does not take time"
+            );
+        }
+    );
+}
+
+#[test]
+fn remove_activity_occurrences() {
+    test_ok!(
+        data,
+        DataBuilder::new().with_activity(Activity {
+            name: "Snack",
+            duration: Duration::new(0, 30),
+            ..Default::default()
+        }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_occurrences(id, 3, Duration::new(2, 0))
+                .expect("Could not set activity occurrences");
+
+            let occurrence_ids = data
+                .set_activity_occurrences(id, 2, Duration::new(1, 0))
+                .expect("Could not remove an occurrence");
+            assert_eq!(occurrence_ids.len(), 2);
+            assert_eq!(data.activities_sorted().len(), 2);
+            assert_eq!(
+                data.activity(id).sessions().unwrap().min_spacing(),
+                Duration::new(1, 0)
+            );
+
+            let occurrence_ids = data
+                .set_activity_occurrences(id, 1, Duration::new(1, 0))
+                .expect("Could not make the activity take place once");
+            assert_eq!(occurrence_ids, vec![id]);
+            assert_eq!(data.activities_sorted().len(), 1);
+            assert_eq!(data.activity(id).sessions(), None);
+            assert_eq!(data.activity(id).number_of_occurrences(), 1);
+        }
+    );
+}
+
+#[test]
+fn set_activity_occurrences_to_zero() {
+    test_err!(
+        data,
+        DataBuilder::new().with_activity(Activity {
+            name: "Snack",
+            ..Default::default()
+        }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_occurrences(id, 0, Duration::new(2, 0))
+        },
+        "'Snack' must take place at least once.",
+        "Could make an activity take place zero times"
+    );
+}
+
+#[test]
+fn set_occurrences_of_split_activity() {
+    test_err!(
+        data,
+        DataBuilder::new().with_activity(Activity {
+            name: "Workshop",
+            duration: Duration::new(3, 0),
+            sessions: 2,
+            ..Default::default()
+        }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_occurrences(id, 2, Duration::new(2, 0))
+        },
+        "'Workshop' is already split into sessions.",
+        "Could make a split activity recurring"
+    );
+}
+
+#[test]
+fn split_or_merge_recurring_activity() {
+    test_ok!(
+        data,
+        DataBuilder::new().with_activity(Activity {
+            name: "Snack",
+            duration: Duration::new(1, 0),
+            ..Default::default()
+        }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_occurrences(id, 2, Duration::new(2, 0))
+                .expect("Could not set activity occurrences");
+
+            assert_eq!(
+                data.split_activity(id, 2, SessionRules::default())
+                    .expect_err("Could split a recurring activity")
+                    .to_string(),
+                "'Snack' is a recurring activity."
+            );
+            assert_eq!(
+                data.merge_activity_sessions(id)
+                    .expect_err("Could merge the occurrences of a recurring activity")
+                    .to_string(),
+                "'Snack' is a recurring activity."
+            );
+        }
+    );
+}
+
+#[test]
+fn set_activity_occurrences_not_enough_time() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval_of_duration(2)
+            .with_activity(Activity {
+                name: "Snack",
+                entities: vec![entity],
+                duration: Duration::new(1, 0),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_occurrences(id, 3, Duration::new(0, 0))
+        },
+        "Entity will not have enough time if 'Snack' takes place more often.",
+        "Could add occurrences without enough time for them"
+    );
+}
+
+#[test]
+fn set_activity_occurrences_exceeds_load_limits() {
+    let entity = "Entity";
+    let mut limits = LoadLimits::default();
+    limits
+        .max_activities_per_category
+        .insert("Meal".to_owned(), 2);
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval_of_duration(8)
+            .with_activity(Activity {
+                name: "Snack",
+                entities: vec![entity],
+                duration: Duration::new(0, 30),
+                category: Some("Meal"),
+                ..Default::default()
+            })
+            .with_load_limits_for(entity, limits),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_occurrences(id, 3, Duration::new(2, 0))
+        },
+        "Entity would take part in too many activities of the category 'Meal' if 'Snack' takes place more often.",
+        "Could add occurrences beyond the load limits"
+    );
+}
+
+#[test]
+fn set_duration_of_recurring_activity() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval_of_duration(4)
+            .with_activity(Activity {
+                name: "Snack",
+                entities: vec![entity],
+                duration: Duration::new(1, 0),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            let occurrence_ids = data
+                .set_activity_occurrences(id, 3, Duration::new(0, 0))
+                .expect("Could not set activity occurrences");
+
+            data.set_activity_duration(occurrence_ids[1], Duration::new(0, 30))
+                .expect("Could not set the duration of an occurrence");
+            for &occurrence_id in &occurrence_ids {
+                assert_eq!(
+                    data.activity(occurrence_id).duration(),
+                    Duration::new(0, 30)
+                );
+            }
+
+            assert_eq!(
+                data.set_activity_duration(id, Duration::new(1, 30))
+                    .expect_err("Could make every occurrence longer without enough time")
+                    .to_string(),
+                "Entity will not have enough time if the duration of 'Snack' is increased."
+            );
+        }
+    );
+}
+
+#[test]
+fn add_entity_to_recurring_activity() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval_of_duration(2)
+            .with_activity(Activity {
+                name: "Snack",
+                duration: Duration::new(1, 0),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_occurrences(id, 3, Duration::new(0, 0))
+                .expect("Could not set activity occurrences");
+
+            assert_eq!(
+                data.add_entity_to_activity(id, entity)
+                    .expect_err("Could add entity without enough time for every occurrence")
+                    .to_string(),
+                "Entity will not have enough time if they are added to 'Snack'."
+            );
+        }
+    );
+}
+
+#[test]
+fn occurrences_possible_insertion_costs() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(12, 0)
+            ))
+            .with_activity(Activity {
+                entities: vec![entity],
+                duration: Duration::new(0, 30),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            let occurrence_ids = data
+                .set_activity_occurrences(id, 2, Duration::new(1, 0))
+                .expect("Could not set activity occurrences");
+
+            data.wait_for_possible_insertion_costs_computation(occurrence_ids[1]);
+            data.insert_activity(occurrence_ids[1], Some(TimeOfDay::new(10, 0)))
+                .expect("Could not insert second occurrence");
+
+            data.wait_for_possible_insertion_costs_computation(occurrence_ids[0]);
+            let beginnings = data
+                .activity(occurrence_ids[0])
+                .insertion_costs()
+                .expect("Insertion costs were not computed")
+                .iter()
+                .map(|insertion_cost| insertion_cost.beginning)
+                .collect::<Vec<_>>();
+
+            assert!(beginnings.contains(&TimeOfDay::new(8, 0)));
+            assert!(beginnings.contains(&TimeOfDay::new(8, 30)));
+            assert!(
+                !beginnings.contains(&TimeOfDay::new(9, 0)),
+                "The first occurrence could be inserted too close to the second one"
+            );
+        }
+    );
+}
+
+#[test]
+fn insert_occurrence_too_close_to_previous_one() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(12, 0)
+            ))
+            .with_activity(Activity {
+                name: "Snack",
+                entities: vec![entity],
+                duration: Duration::new(0, 30),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            let occurrence_ids = data
+                .set_activity_occurrences(id, 2, Duration::new(1, 0))
+                .expect("Could not set activity occurrences");
+
+            data.wait_for_possible_insertion_costs_computation(occurrence_ids[0]);
+            data.insert_activity(occurrence_ids[0], Some(TimeOfDay::new(8, 0)))
+                .expect("Could not insert first occurrence");
+
+            data.wait_for_possible_insertion_costs_computation(occurrence_ids[1]);
+            assert_eq!(
+                data.insert_activity(occurrence_ids[1], Some(TimeOfDay::new(9, 0)))
+                    .expect_err("Could insert an occurrence too close to the previous one")
+                    .to_string(),
+                "Snack cannot be inserted with beginning 09:00 because its occurrences would not be in order or not spaced enough."
+            );
+            data.insert_activity(occurrence_ids[1], Some(TimeOfDay::new(9, 30)))
+                .expect("Could not insert second occurrence after the spacing");
+        }
+    );
+}
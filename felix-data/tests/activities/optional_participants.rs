@@ -0,0 +1,364 @@
+//! Optional participants and minimum and maximum numbers of participants.
+//!
+//! Includes:
+//! - Addition and removal of optional participants
+//! - Validation of participant limits
+//! - Beginnings restricted to the times when enough optional participants are free
+//! - Attendance of optional participants
+//! - Export of optional attendance
+
+use felix_data::{Duration, TimeInterval, TimeOfDay};
+use felix_test_utils::{Activity, DataBuilder};
+
+#[test]
+fn simple_add_optional_entity_to_activity() {
+    let (entity, optional_entity) = ("Entity", "Optional");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entities(vec![entity, optional_entity])
+            .with_work_interval_of_duration(4)
+            .with_activity(Activity {
+                name: "Meeting",
+                entities: vec![entity],
+                duration: Duration::new(1, 0),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.add_optional_entity_to_activity(id, optional_entity)
+                .expect("Could not add optional participant");
+
+            assert_eq!(data.entities_of_activity(id), vec![entity]);
+            assert_eq!(
+                data.optional_entities_of_activity(id),
+                vec![optional_entity]
+            );
+            assert_eq!(data.activities_of(optional_entity).unwrap(), vec![]);
+            assert_eq!(
+                data.optional_activities_of(optional_entity).unwrap(),
+                vec![data.activity(id)]
+            );
+            assert_eq!(
+                data.free_time_of(optional_entity).unwrap(),
+                Duration::new(4, 0),
+                "Optional participation takes free time"
+            );
+
+            data.remove_optional_entity_from_activity(id, optional_entity)
+                .expect("Could not remove optional participant");
+            assert!(data.optional_entities_of_activity(id).is_empty());
+        }
+    );
+}
+
+#[test]
+fn add_mandatory_entity_as_optional_participant() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval_of_duration(4)
+            .with_activity(Activity {
+                name: "Meeting",
+                entities: vec![entity],
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.add_optional_entity_to_activity(id, entity)
+        },
+        "Entity is already in the activity 'Meeting'.",
+        "Could add a mandatory participant as optional participant"
+    );
+}
+
+#[test]
+fn optional_participant_becomes_mandatory() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval_of_duration(4)
+            .with_activity(Activity {
+                name: "Meeting",
+                duration: Duration::new(1, 0),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.add_optional_entity_to_activity(id, entity)
+                .expect("Could not add optional participant");
+            data.add_entity_to_activity(id, entity)
+                .expect("Could not make optional participant mandatory");
+
+            assert_eq!(data.entities_of_activity(id), vec![entity]);
+            assert!(data.optional_entities_of_activity(id).is_empty());
+        }
+    );
+}
+
+#[test]
+fn remove_entity_removes_optional_participation() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_activity(Activity {
+                name: "Meeting",
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.add_optional_entity_to_activity(id, entity)
+                .expect("Could not add optional participant");
+            data.remove_entity(entity).expect("Could not remove entity");
+
+            assert!(data.activity(id).optional_entity_ids().is_empty());
+        }
+    );
+}
+
+#[test]
+fn set_participant_limits_min_greater_than_max() {
+    test_err!(
+        data,
+        DataBuilder::new().with_activity(Activity {
+            name: "Meeting",
+            ..Default::default()
+        }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_participant_limits(id, Some(3), Some(2))
+        },
+        "The minimum number of participants of 'Meeting' cannot be greater than its maximum.",
+        "Could set a minimum greater than the maximum"
+    );
+}
+
+#[test]
+fn set_participant_limits_too_many_participants() {
+    let entities = vec!["Entity1", "Entity2", "Entity3"];
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_entities(entities.clone())
+            .with_work_interval_of_duration(4)
+            .with_activity(Activity {
+                name: "Meeting",
+                entities,
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_participant_limits(id, None, Some(2))
+        },
+        "'Meeting' cannot have more than 2 participants.",
+        "Could set a maximum lower than the number of mandatory participants"
+    );
+}
+
+#[test]
+fn set_participant_limits_too_few_participants() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval_of_duration(4)
+            .with_activity(Activity {
+                name: "Meeting",
+                entities: vec![entity],
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_participant_limits(id, Some(2), None)
+        },
+        "'Meeting' needs at least 2 participants.",
+        "Could set a minimum which cannot be reached"
+    );
+}
+
+#[test]
+fn add_entity_beyond_max_participants() {
+    let (entity1, entity2) = ("Entity1", "Entity2");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entities(vec![entity1, entity2])
+            .with_work_interval_of_duration(4)
+            .with_activity(Activity {
+                name: "Meeting",
+                entities: vec![entity1],
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_participant_limits(id, None, Some(1))
+                .expect("Could not set participant limits");
+            assert_eq!(data.activity(id).max_participants(), Some(1));
+
+            assert_eq!(
+                data.add_entity_to_activity(id, entity2)
+                    .expect_err("Could add a participant beyond the maximum")
+                    .to_string(),
+                "'Meeting' cannot have more than 1 participants."
+            );
+            data.add_optional_entity_to_activity(id, entity2)
+                .expect("Could not add optional participant beyond the maximum");
+        }
+    );
+}
+
+#[test]
+fn min_participants_restricts_beginnings() {
+    let (entity, optional_entity) = ("Entity", "Optional");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entities(vec![entity, optional_entity])
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(12, 0)
+            ))
+            .with_custom_work_interval_for(
+                optional_entity,
+                TimeInterval::new(TimeOfDay::new(10, 0), TimeOfDay::new(12, 0))
+            )
+            .with_activity(Activity {
+                name: "Meeting",
+                entities: vec![entity],
+                duration: Duration::new(1, 0),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.add_optional_entity_to_activity(id, optional_entity)
+                .expect("Could not add optional participant");
+            data.set_activity_participant_limits(id, Some(2), None)
+                .expect("Could not set participant limits");
+
+            data.wait_for_possible_insertion_costs_computation(id);
+            let beginnings = data
+                .activity(id)
+                .insertion_costs()
+                .expect("Insertion costs were not computed")
+                .iter()
+                .map(|insertion_cost| insertion_cost.beginning)
+                .collect::<Vec<_>>();
+            assert!(beginnings.contains(&TimeOfDay::new(10, 0)));
+            assert!(beginnings.contains(&TimeOfDay::new(11, 0)));
+            assert!(
+                !beginnings.contains(&TimeOfDay::new(9, 0)),
+                "The activity could begin when the optional participant does not work"
+            );
+
+            assert_eq!(
+                data.insert_activity(id, Some(TimeOfDay::new(9, 0)))
+                    .expect_err("Could insert the activity without enough participants")
+                    .to_string(),
+                "Meeting cannot be inserted with beginning 09:00 because too few of its optional participants would be free."
+            );
+            data.insert_activity(id, Some(TimeOfDay::new(10, 0)))
+                .expect("Could not insert activity with enough participants");
+        }
+    );
+}
+
+#[test]
+fn optional_participants_attend_if_free() {
+    let (entity, busy_entity, free_entity) = ("Entity", "Busy", "Free");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entities(vec![entity, busy_entity, free_entity])
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(12, 0)
+            ))
+            .with_activities(vec![
+                Activity {
+                    name: "Meeting",
+                    entities: vec![entity],
+                    duration: Duration::new(1, 0),
+                    ..Default::default()
+                },
+                Activity {
+                    name: "Workshop",
+                    entities: vec![busy_entity],
+                    duration: Duration::new(1, 0),
+                    ..Default::default()
+                }
+            ]),
+        {
+            let meeting_id = data.activities_sorted()[0].id();
+            let workshop_id = data.activities_sorted()[1].id();
+            data.add_optional_entity_to_activity(meeting_id, busy_entity)
+                .expect("Could not add optional participant");
+            data.add_optional_entity_to_activity(meeting_id, free_entity)
+                .expect("Could not add optional participant");
+            assert!(
+                data.optional_entities_attending_activity(meeting_id)
+                    .is_empty(),
+                "Optional participants attend an activity which is not inserted"
+            );
+
+            data.wait_for_possible_insertion_costs_computation(workshop_id);
+            data.insert_activity(workshop_id, Some(TimeOfDay::new(8, 0)))
+                .expect("Could not insert workshop");
+            data.wait_for_possible_insertion_costs_computation(meeting_id);
+            data.insert_activity(meeting_id, Some(TimeOfDay::new(8, 0)))
+                .expect("Could not insert meeting");
+            assert_eq!(
+                data.optional_entities_attending_activity(meeting_id),
+                vec![free_entity]
+            );
+
+            data.set_activity_participant_limits(meeting_id, None, Some(1))
+                .expect("Could not set participant limits");
+            assert!(
+                data.optional_entities_attending_activity(meeting_id)
+                    .is_empty(),
+                "Optional participants attend beyond the maximum"
+            );
+        }
+    );
+}
+
+#[test]
+fn export_optional_attendance() {
+    let (entity, optional_entity) = ("Entity", "Optional");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entities(vec![entity, optional_entity])
+            .with_work_interval_of_duration(4)
+            .with_activity(Activity {
+                name: "Meeting",
+                entities: vec![entity],
+                duration: Duration::new(1, 0),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.add_optional_entity_to_activity(id, optional_entity)
+                .expect("Could not add optional participant");
+            assert!(
+                data.as_printable()[optional_entity].is_empty(),
+                "Optional participant attends an activity which is not inserted"
+            );
+
+            data.wait_for_possible_insertion_costs_computation(id);
+            data.insert_activity(id, Some(TimeOfDay::new(1, 0)))
+                .expect("Could not insert activity");
+            assert_eq!(
+                data.as_printable()[optional_entity],
+                vec![data.activity(id)]
+            );
+        }
+    );
+}
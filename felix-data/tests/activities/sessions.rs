@@ -0,0 +1,379 @@
+//! Activities split into several sessions.
+//!
+//! Includes:
+//! - Split and merge of activities
+//! - Propagation of changes to every session
+//! - Order of consecutive sessions
+
+use felix_data::{Duration, SessionRules, TimeInterval, TimeOfDay};
+use felix_test_utils::{Activity, DataBuilder};
+
+#[test]
+fn simple_split_activity() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(12, 0)
+            ))
+            .with_activity(Activity {
+                name: "Workshop",
+                entities: vec![entity],
+                duration: Duration::new(3, 0),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            let session_ids = data
+                .split_activity(id, 2, SessionRules::default())
+                .expect("Could not split activity");
+
+            assert_eq!(session_ids.len(), 2);
+            assert_eq!(session_ids[0], id, "The main session changed id");
+            for &session_id in &session_ids {
+                let session = data.activity(session_id);
+                assert_eq!(session.name(), "Workshop");
+                assert_eq!(session.duration(), Duration::new(1, 30));
+                assert_eq!(data.entities_of_activity(session_id), vec![entity]);
+                assert_eq!(session.main_session_id(), id);
+            }
+        }
+    );
+}
+
+#[test]
+fn split_activity_shares_remaining_duration() {
+    test_ok!(
+        data,
+        DataBuilder::new().with_activity(Activity {
+            duration: Duration::new(1, 5),
+            ..Default::default()
+        }),
+        {
+            let id = data.activities_sorted()[0].id();
+            let durations = data
+                .split_activity(id, 3, SessionRules::default())
+                .expect("Could not split activity")
+                .into_iter()
+                .map(|session_id| data.activity(session_id).duration())
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                durations,
+                vec![
+                    Duration::new(0, 25),
+                    Duration::new(0, 20),
+                    Duration::new(0, 20)
+                ]
+            );
+        }
+    );
+}
+
+#[test]
+fn split_activity_already_split() {
+    test_err!(
+        data,
+        DataBuilder::new().with_activity(Activity {
+            name: "Workshop",
+            duration: Duration::new(3, 0),
+            sessions: 2,
+            ..Default::default()
+        }),
+        {
+            let id = data.activities_sorted()[0].main_session_id();
+            data.split_activity(id, 2, SessionRules::default())
+        },
+        "'Workshop' is already split into sessions.",
+        "Could split an activity twice"
+    );
+}
+
+#[test]
+fn split_activity_into_one_session() {
+    test_err!(
+        data,
+        DataBuilder::new().with_activity(Activity {
+            name: "Workshop",
+            duration: Duration::new(3, 0),
+            ..Default::default()
+        }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.split_activity(id, 1, SessionRules::default())
+        },
+        "'Workshop' must be split into at least two sessions.",
+        "Could split an activity into one session"
+    );
+}
+
+#[test]
+fn split_activity_sessions_too_short() {
+    test_err!(
+        data,
+        DataBuilder::new().with_activity(Activity {
+            name: "Workshop",
+            duration: Duration::new(3, 0),
+            ..Default::default()
+        }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.split_activity(
+                id,
+                3,
+                SessionRules {
+                    min_session_duration: Duration::new(1, 30),
+                    ..Default::default()
+                },
+            )
+        },
+        "'Workshop' cannot be split this way because its sessions would be shorter than 01:30.",
+        "Could split an activity into sessions shorter than the minimum"
+    );
+}
+
+#[test]
+fn set_session_duration_too_short() {
+    test_err!(
+        data,
+        DataBuilder::new().with_activity(Activity {
+            name: "Workshop",
+            duration: Duration::new(3, 0),
+            sessions: 2,
+            session_rules: SessionRules {
+                min_session_duration: Duration::new(1, 0),
+                ..Default::default()
+            },
+            ..Default::default()
+        }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_duration(id, Duration::new(0, 45))
+        },
+        "'Workshop' cannot be split this way because its sessions would be shorter than 01:00.",
+        "Could set the duration of a session below the minimum"
+    );
+}
+
+#[test]
+fn merge_activity_sessions() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(12, 0)
+            ))
+            .with_activity(Activity {
+                entities: vec![entity],
+                duration: Duration::new(3, 0),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            let session_ids = data
+                .split_activity(id, 3, SessionRules::default())
+                .expect("Could not split activity");
+
+            data.merge_activity_sessions(session_ids[2])
+                .expect("Could not merge sessions");
+
+            let activities = data.activities_sorted();
+            assert_eq!(activities.len(), 1, "Sessions were not removed");
+            assert_eq!(activities[0].id(), id);
+            assert_eq!(activities[0].duration(), Duration::new(3, 0));
+            assert_eq!(activities[0].sessions(), None);
+        }
+    );
+}
+
+#[test]
+fn merge_activity_not_split() {
+    test_err!(
+        data,
+        DataBuilder::new().with_activity(Activity {
+            name: "Workshop",
+            ..Default::default()
+        }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.merge_activity_sessions(id)
+        },
+        "'Workshop' is not split into sessions.",
+        "Could merge an activity which is not split"
+    );
+}
+
+#[test]
+fn changes_apply_to_every_session() {
+    let (entity1, entity2) = ("Entity1", "Entity2");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entities(vec![entity1, entity2])
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(12, 0)
+            ))
+            .with_activity(Activity {
+                entities: vec![entity1],
+                duration: Duration::new(2, 0),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            let session_ids = data
+                .split_activity(id, 2, SessionRules::default())
+                .expect("Could not split activity");
+
+            data.add_entity_to_activity(id, entity2)
+                .expect("Could not add entity to activity");
+            data.remove_entity_from_activity(id, entity1)
+                .expect("Could not remove entity from activity");
+            data.set_activity_name(id, "New Name")
+                .expect("Could not set activity name");
+
+            for session_id in session_ids {
+                let session = data.activity(session_id);
+                assert_eq!(data.entities_of_activity(session_id), vec![entity2]);
+                assert_eq!(session.name(), "New Name");
+            }
+        }
+    );
+}
+
+#[test]
+fn remove_activity_removes_every_session() {
+    test_ok!(
+        data,
+        DataBuilder::new().with_activity(Activity {
+            duration: Duration::new(2, 0),
+            ..Default::default()
+        }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.split_activity(id, 2, SessionRules::default())
+                .expect("Could not split activity");
+
+            data.remove_activity(id);
+            assert!(data.activities_sorted().is_empty());
+        }
+    );
+}
+
+#[test]
+fn add_entity_without_enough_time_for_every_session() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(10, 0)
+            ))
+            .with_activity(Activity {
+                name: "Workshop",
+                duration: Duration::new(3, 0),
+                sessions: 2,
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.add_entity_to_activity(id, entity)
+        },
+        "Entity will not have enough time if they are added to 'Workshop'.",
+        "Could add entity without enough time for every session"
+    );
+}
+
+#[test]
+fn consecutive_sessions_possible_insertion_costs() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(12, 0)
+            ))
+            .with_activity(Activity {
+                entities: vec![entity],
+                duration: Duration::new(2, 0),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            let session_ids = data
+                .split_activity(
+                    id,
+                    2,
+                    SessionRules {
+                        consecutive: true,
+                        ..Default::default()
+                    },
+                )
+                .expect("Could not split activity");
+
+            data.wait_for_possible_insertion_costs_computation(session_ids[1]);
+            data.insert_activity(session_ids[1], Some(TimeOfDay::new(10, 0)))
+                .expect("Could not insert second session");
+
+            data.wait_for_possible_insertion_costs_computation(session_ids[0]);
+            let beginnings = data
+                .activity(session_ids[0])
+                .insertion_costs()
+                .expect("Insertion costs were not computed")
+                .iter()
+                .map(|insertion_cost| insertion_cost.beginning)
+                .collect::<Vec<_>>();
+
+            assert!(beginnings.contains(&TimeOfDay::new(8, 0)));
+            assert!(beginnings.contains(&TimeOfDay::new(9, 0)));
+            assert!(
+                !beginnings.contains(&TimeOfDay::new(11, 0)),
+                "The first session could be inserted after the second one"
+            );
+        }
+    );
+}
+
+#[test]
+fn insert_consecutive_session_before_previous_one() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0)))
+            .with_activity(Activity {
+                name: "Workshop",
+                entities: vec![entity],
+                duration: Duration::new(2, 0),
+                insertion_time: Some(TimeOfDay::new(10, 0)),
+                sessions: 2,
+                session_rules: SessionRules {
+                    consecutive: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+        {
+            let sessions = data.activities_sorted()[0]
+                .sessions()
+                .expect("Activity was not split");
+            let second_session_id = sessions.session_ids()[1];
+
+            data.wait_for_possible_insertion_costs_computation(second_session_id);
+            data.insert_activity(second_session_id, Some(TimeOfDay::new(8, 0)))
+        },
+        "Workshop cannot be inserted with beginning 08:00 because its sessions would not be in order.",
+        "Could insert a session before the previous one"
+    );
+}
@@ -0,0 +1,320 @@
+//! Activities which must begin at the same time.
+//!
+//! Includes:
+//! - Link and unlink of simultaneous activities
+//! - Insertion and removal from the schedule of simultaneous activities
+//! - Possible insertion costs of simultaneous activities
+//! - Participants shared between simultaneous activities
+//! - Autoinsertion of simultaneous activities
+
+use felix_data::{Duration, TimeInterval, TimeOfDay};
+use felix_test_utils::{Activity, DataBuilder};
+
+/// Two parallel lunches, each one with its own participant.
+fn parallel_lunches() -> DataBuilder {
+    DataBuilder::new()
+        .with_entities(vec!["Entity1", "Entity2"])
+        .with_work_interval(TimeInterval::new(
+            TimeOfDay::new(8, 0),
+            TimeOfDay::new(14, 0),
+        ))
+        .with_activities(vec![
+            Activity {
+                name: "Lunch1",
+                entities: vec!["Entity1"],
+                duration: Duration::new(1, 0),
+                ..Default::default()
+            },
+            Activity {
+                name: "Lunch2",
+                entities: vec!["Entity2"],
+                duration: Duration::new(0, 30),
+                ..Default::default()
+            },
+        ])
+}
+
+#[test]
+fn simple_link_simultaneous_activities() {
+    test_ok!(data, parallel_lunches(), {
+        let activities = data.activities_sorted();
+        let (id1, id2) = (activities[0].id(), activities[1].id());
+
+        data.link_simultaneous_activities(&[id1, id2])
+            .expect("Could not link simultaneous activities");
+
+        assert_eq!(data.activity(id1).simultaneous_activity_ids(), vec![id2]);
+        assert_eq!(data.activity(id2).simultaneous_activity_ids(), vec![id1]);
+    });
+}
+
+#[test]
+fn link_simultaneous_activities_too_few_activities() {
+    test_err!(
+        data,
+        parallel_lunches(),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.link_simultaneous_activities(&[id])
+        },
+        "'Lunch1' must begin at the same time as at least one other activity.",
+        "Could link a single activity"
+    );
+}
+
+#[test]
+fn link_simultaneous_activities_same_activity_twice() {
+    test_err!(
+        data,
+        parallel_lunches(),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.link_simultaneous_activities(&[id, id])
+        },
+        "'Lunch1' must begin at the same time as at least one other activity.",
+        "Could link an activity with itself"
+    );
+}
+
+#[test]
+fn link_simultaneous_activities_with_duplicate_ids() {
+    test_ok!(data, parallel_lunches(), {
+        let activities = data.activities_sorted();
+        let (id1, id2) = (activities[0].id(), activities[1].id());
+
+        data.link_simultaneous_activities(&[id1, id2, id1])
+            .expect("Could not link simultaneous activities");
+
+        assert_eq!(data.activity(id1).simultaneous_activity_ids(), vec![id2]);
+        assert_eq!(data.activity(id2).simultaneous_activity_ids(), vec![id1]);
+    });
+}
+
+#[test]
+fn link_simultaneous_activities_already_simultaneous() {
+    test_err!(
+        data,
+        parallel_lunches()
+            .with_activity(Activity {
+                name: "Lunch3",
+                ..Default::default()
+            })
+            .with_simultaneous_activities(vec!["Lunch1", "Lunch2"]),
+        {
+            let activities = data.activities_sorted();
+            data.link_simultaneous_activities(&[activities[1].id(), activities[2].id()])
+        },
+        "'Lunch2' already begins at the same time as other activities.",
+        "Could link an activity twice"
+    );
+}
+
+#[test]
+fn link_simultaneous_activities_with_shared_participant() {
+    test_err!(
+        data,
+        parallel_lunches().with_activity(Activity {
+            name: "Lunch3",
+            entities: vec!["Entity1"],
+            ..Default::default()
+        }),
+        {
+            let activities = data.activities_sorted();
+            data.link_simultaneous_activities(&[activities[0].id(), activities[2].id()])
+        },
+        "Entity1 cannot take part in 'Lunch3' because they take part in an activity which begins at the same time.",
+        "Could link activities which share a participant"
+    );
+}
+
+#[test]
+fn add_entity_to_simultaneous_activity_with_shared_participant() {
+    test_err!(
+        data,
+        parallel_lunches().with_simultaneous_activities(vec!["Lunch1", "Lunch2"]),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.add_entity_to_activity(id, "Entity2")
+        },
+        "Entity2 cannot take part in 'Lunch1' because they take part in an activity which begins at the same time.",
+        "Could add an entity to two simultaneous activities"
+    );
+}
+
+#[test]
+fn insert_simultaneous_activity_moves_all() {
+    test_ok!(
+        data,
+        parallel_lunches().with_simultaneous_activities(vec!["Lunch1", "Lunch2"]),
+        {
+            let activities = data.activities_sorted();
+            let (id1, id2) = (activities[0].id(), activities[1].id());
+
+            data.insert_activity(id1, Some(TimeOfDay::new(12, 0)))
+                .expect("Could not insert activity");
+            assert_eq!(
+                data.activity(id2).insertion_interval(),
+                Some(TimeInterval::new(
+                    TimeOfDay::new(12, 0),
+                    TimeOfDay::new(12, 30)
+                )),
+                "The simultaneous activity was not inserted"
+            );
+
+            data.wait_for_possible_insertion_costs_computation(id2);
+            data.insert_activity(id2, Some(TimeOfDay::new(9, 0)))
+                .expect("Could not move activity");
+            assert_eq!(
+                data.activity(id1).insertion_interval(),
+                Some(TimeInterval::new(
+                    TimeOfDay::new(9, 0),
+                    TimeOfDay::new(10, 0)
+                )),
+                "The simultaneous activity was not moved"
+            );
+
+            data.insert_activity(id1, None)
+                .expect("Could not remove activity from schedule");
+            assert_eq!(
+                data.activity(id2).insertion_interval(),
+                None,
+                "The simultaneous activity was not removed from the schedule"
+            );
+        }
+    );
+}
+
+#[test]
+fn simultaneous_activities_possible_insertion_costs() {
+    test_ok!(
+        data,
+        parallel_lunches()
+            .with_custom_work_interval_for(
+                "Entity2",
+                TimeInterval::new(TimeOfDay::new(11, 0), TimeOfDay::new(12, 0))
+            )
+            .with_simultaneous_activities(vec!["Lunch1", "Lunch2"]),
+        {
+            let id = data.activities_sorted()[0].id();
+            let beginnings = data
+                .activity(id)
+                .insertion_costs()
+                .expect("Insertion costs were not computed")
+                .iter()
+                .map(|insertion_cost| insertion_cost.beginning)
+                .collect::<Vec<_>>();
+
+            // Lunch2 can only begin between 11:00 and 11:30
+            let expected = (0..=30)
+                .step_by(5)
+                .map(|minutes| TimeOfDay::new(11, minutes))
+                .collect::<Vec<_>>();
+            assert_eq!(beginnings, expected);
+        }
+    );
+}
+
+#[test]
+fn insert_simultaneous_activity_where_other_one_cannot_begin() {
+    test_err!(
+        data,
+        parallel_lunches()
+            .with_activity(Activity {
+                name: "Meeting",
+                entities: vec!["Entity2"],
+                duration: Duration::new(1, 0),
+                insertion_time: Some(TimeOfDay::new(12, 0)),
+                ..Default::default()
+            })
+            .with_simultaneous_activities(vec!["Lunch1", "Lunch2"]),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.insert_activity(id, Some(TimeOfDay::new(12, 0)))
+        },
+        "Lunch1 cannot be inserted with beginning 12:00 because 'Lunch2' cannot begin at the same time.",
+        "Could insert an activity where its simultaneous activity cannot begin"
+    );
+}
+
+#[test]
+fn unlink_simultaneous_activity() {
+    test_ok!(
+        data,
+        parallel_lunches().with_simultaneous_activities(vec!["Lunch1", "Lunch2"]),
+        {
+            let activities = data.activities_sorted();
+            let (id1, id2) = (activities[0].id(), activities[1].id());
+
+            data.unlink_simultaneous_activity(id1)
+                .expect("Could not unlink simultaneous activity");
+            assert!(data.activity(id1).simultaneous_activity_ids().is_empty());
+            assert!(data.activity(id2).simultaneous_activity_ids().is_empty());
+
+            data.wait_for_possible_insertion_costs_computation(id1);
+            data.insert_activity(id1, Some(TimeOfDay::new(12, 0)))
+                .expect("Could not insert activity");
+            assert_eq!(data.activity(id2).insertion_interval(), None);
+        }
+    );
+}
+
+#[test]
+fn unlink_activity_which_is_not_simultaneous() {
+    test_err!(
+        data,
+        parallel_lunches(),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.unlink_simultaneous_activity(id)
+        },
+        "'Lunch1' does not begin at the same time as other activities.",
+        "Could unlink an activity which is not simultaneous"
+    );
+}
+
+#[test]
+fn remove_simultaneous_activity() {
+    test_ok!(
+        data,
+        parallel_lunches().with_simultaneous_activities(vec!["Lunch1", "Lunch2"]),
+        {
+            let activities = data.activities_sorted();
+            let (id1, id2) = (activities[0].id(), activities[1].id());
+
+            data.remove_activity(id1);
+            assert!(data.activity(id2).simultaneous_activity_ids().is_empty());
+        }
+    );
+}
+
+#[test]
+fn autoinsert_simultaneous_activities() {
+    test_ok!(
+        data,
+        parallel_lunches()
+            .with_custom_work_interval_for(
+                "Entity2",
+                TimeInterval::new(TimeOfDay::new(11, 0), TimeOfDay::new(12, 0))
+            )
+            .with_simultaneous_activities(vec!["Lunch1", "Lunch2"]),
+        {
+            let result = data
+                .start_autoinsertion()
+                .expect("Could not start autoinsertion")
+                .get_final_result()
+                .expect("Autoinsertion failed");
+            data.apply_autoinsertion_result(result);
+
+            let activities = data.activities_sorted();
+            let beginning1 = activities[0]
+                .insertion_interval()
+                .expect("Activity was not inserted")
+                .beginning();
+            let beginning2 = activities[1]
+                .insertion_interval()
+                .expect("Activity was not inserted")
+                .beginning();
+            assert_eq!(beginning1, beginning2);
+        }
+    );
+}
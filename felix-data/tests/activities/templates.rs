@@ -0,0 +1,363 @@
+//! Duplication of activities and activity templates.
+//!
+//! Includes:
+//! - Copy of the metadata and participants of activities
+//! - Numbering of created activities
+//! - Adding, removing and using activity templates
+//! - Enforcement of free time and load limits
+//! - A single event for each batch of created activities
+
+use felix_data::{Duration, LoadLimits, Rgba, TimeOfDay};
+use felix_test_utils::{Activity, DataBuilder, Group};
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+const RED: Rgba = Rgba {
+    red: 1.0,
+    green: 0.0,
+    blue: 0.0,
+    alpha: 1.0,
+};
+
+#[test]
+fn simple_duplicate_activity() {
+    let (entity, group) = ("Entity", "Group");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(8)
+            .with_entity(entity)
+            .with_group(Group::default(group))
+            .with_activity(Activity {
+                name: "Lunch",
+                duration: Duration::new(1, 0),
+                entities: vec![entity],
+                groups: vec![group],
+                category: Some("Meals"),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_color(id, RED).unwrap();
+
+            let copies = data
+                .duplicate_activity(id, 2)
+                .expect("Could not duplicate activity");
+            let names = copies.iter().map(|copy| copy.name()).collect::<Vec<_>>();
+            assert_eq!(names, vec!["Lunch 2", "Lunch 3"]);
+            for copy in copies {
+                assert_ne!(copy.id(), id);
+                assert_eq!(copy.duration(), Duration::new(1, 0));
+                assert_eq!(copy.color(), RED);
+                assert_eq!(copy.category(), Some("Meals".to_owned()));
+                assert_eq!(data.entities_of_activity(copy.id()), vec![entity]);
+                assert_eq!(data.groups_of_activity(copy.id()), vec![group]);
+            }
+            assert_eq!(data.activities_sorted().len(), 3);
+        }
+    );
+}
+
+#[test]
+fn duplicate_activity_skips_taken_names() {
+    test_ok!(
+        data,
+        DataBuilder::new().with_activities(vec![
+            Activity {
+                name: "Lunch",
+                ..Default::default()
+            },
+            Activity {
+                name: "Lunch 2",
+                ..Default::default()
+            }
+        ]),
+        {
+            let id = data.activities_sorted()[0].id();
+            let names = data
+                .duplicate_activity(id, 2)
+                .expect("Could not duplicate activity")
+                .iter()
+                .map(|copy| copy.name())
+                .collect::<Vec<_>>();
+            assert_eq!(names, vec!["Lunch 3", "Lunch 4"]);
+        }
+    );
+}
+
+#[test]
+fn duplicate_inserted_activity_copies_are_not_inserted() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(8)
+            .with_entity(entity)
+            .with_activity(Activity {
+                entities: vec![entity],
+                insertion_time: Some(TimeOfDay::new(1, 0)),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            let copies = data
+                .duplicate_activity(id, 1)
+                .expect("Could not duplicate activity");
+            assert_eq!(copies[0].insertion_interval(), None);
+        }
+    );
+}
+
+#[test]
+fn duplicate_activity_zero_times() {
+    test_ok!(
+        data,
+        DataBuilder::new().with_activity(Activity::default()),
+        {
+            let id = data.activities_sorted()[0].id();
+            assert!(data.duplicate_activity(id, 0).unwrap().is_empty());
+            assert_eq!(data.activities_sorted().len(), 1);
+        }
+    );
+}
+
+#[test]
+fn duplicate_activity_not_enough_time() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(4)
+            .with_entity(entity)
+            .with_activity(Activity {
+                name: "Lunch",
+                duration: Duration::new(1, 0),
+                entities: vec![entity],
+                ..Default::default()
+            }),
+        data.duplicate_activity(data.activities_sorted()[0].id(), 4),
+        "Entity will not have enough time if they are added to 'Lunch 5'.",
+        "Could duplicate activity without enough time"
+    );
+}
+
+#[test]
+fn duplicate_activity_error_creates_no_activity() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(4)
+            .with_entity(entity)
+            .with_activity(Activity {
+                name: "Lunch",
+                duration: Duration::new(1, 0),
+                entities: vec![entity],
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            assert!(data.duplicate_activity(id, 4).is_err());
+            assert_eq!(data.activities_sorted().len(), 1);
+            assert_eq!(data.free_time_of(entity).unwrap(), Duration::new(3, 0));
+        }
+    );
+}
+
+#[test]
+fn duplicate_activity_load_limits_exceeded() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(8)
+            .with_entity(entity)
+            .with_load_limits_for(
+                entity,
+                LoadLimits {
+                    max_activity_time: Some(Duration::new(2, 0)),
+                    ..LoadLimits::default()
+                }
+            )
+            .with_activity(Activity {
+                name: "Lunch",
+                duration: Duration::new(1, 0),
+                entities: vec![entity],
+                ..Default::default()
+            }),
+        data.duplicate_activity(data.activities_sorted()[0].id(), 2),
+        "Entity would spend too much time in activities if they are added to 'Lunch 3'.",
+        "Could duplicate activity exceeding load limits"
+    );
+}
+
+#[test]
+fn duplicate_activity_emits_single_event() {
+    test_ok!(
+        data,
+        DataBuilder::new().with_activity(Activity::default()),
+        {
+            let batches = Rc::new(Cell::new(0));
+            let counter = batches.clone();
+            data.events()
+                .borrow_mut()
+                .connect_activities_added(Box::new(move |_, activities| {
+                    assert_eq!(activities.len(), 3);
+                    counter.set(counter.get() + 1);
+                }));
+
+            let id = data.activities_sorted()[0].id();
+            data.duplicate_activity(id, 3)
+                .expect("Could not duplicate activity");
+            assert_eq!(batches.get(), 1);
+        }
+    );
+}
+
+#[test]
+fn simple_add_activity_template() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(8)
+            .with_entity(entity)
+            .with_activity(Activity {
+                name: "Lunch",
+                duration: Duration::new(1, 0),
+                entities: vec![entity],
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            let name = data
+                .add_activity_template(" daily  lunch ", id)
+                .expect("Could not add activity template");
+            assert_eq!(name, "Daily Lunch");
+            assert_eq!(data.activity_templates(), vec![name.clone()]);
+
+            let template = data.activity_template(name).unwrap();
+            assert_eq!(template.activity_name(), "Lunch");
+            assert_eq!(template.duration(), Duration::new(1, 0));
+            assert_eq!(
+                template.entity_ids(),
+                vec![data.entity(entity).unwrap().id()]
+            );
+        }
+    );
+}
+
+#[test]
+fn add_activity_template_name_taken() {
+    test_ok!(
+        data,
+        DataBuilder::new().with_activity(Activity::default()),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.add_activity_template("Template", id).unwrap();
+            assert_eq!(
+                data.add_activity_template("Template", id)
+                    .expect_err("Could add activity template with taken name")
+                    .to_string(),
+                "The name 'Template' is already taken by an activity template."
+            );
+        }
+    );
+}
+
+#[test]
+fn add_activities_from_template() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(8)
+            .with_entity(entity)
+            .with_activity(Activity {
+                name: "Workshop",
+                duration: Duration::new(1, 0),
+                entities: vec![entity],
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.add_activity_template("Template", id).unwrap();
+            data.remove_activity(id);
+
+            let activities = data
+                .add_activities_from_template("Template", 3)
+                .expect("Could not add activities from template");
+            let names = activities
+                .iter()
+                .map(|activity| activity.name())
+                .collect::<Vec<_>>();
+            assert_eq!(names, vec!["Workshop 1", "Workshop 2", "Workshop 3"]);
+            assert_eq!(data.activities_of(entity).unwrap().len(), 3);
+        }
+    );
+}
+
+#[test]
+fn add_activities_from_nonexistent_template() {
+    test_err!(
+        data,
+        DataBuilder::new(),
+        data.add_activities_from_template("Template", 2),
+        "The activity template 'Template' does not exist.",
+        "Could add activities from nonexistent template"
+    );
+}
+
+#[test]
+fn simple_remove_activity_template() {
+    test_ok!(
+        data,
+        DataBuilder::new().with_activity(Activity::default()),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.add_activity_template("Template", id).unwrap();
+            data.remove_activity_template("Template")
+                .expect("Could not remove activity template");
+            assert!(data.activity_templates().is_empty());
+        }
+    );
+}
+
+#[test]
+fn remove_nonexistent_activity_template() {
+    test_err!(
+        data,
+        DataBuilder::new(),
+        data.remove_activity_template("Template"),
+        "The activity template 'Template' does not exist.",
+        "Could remove nonexistent activity template"
+    );
+}
+
+#[test]
+fn remove_entity_removes_it_from_activity_templates() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(8)
+            .with_entity(entity)
+            .with_activity(Activity {
+                entities: vec![entity],
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.add_activity_template("Template", id).unwrap();
+            data.remove_entity(entity).unwrap();
+            assert!(data
+                .activity_template("Template")
+                .unwrap()
+                .entity_ids()
+                .is_empty());
+            let activities = data.add_activities_from_template("Template", 1).unwrap();
+            assert!(data.entities_of_activity(activities[0].id()).is_empty());
+        }
+    );
+}
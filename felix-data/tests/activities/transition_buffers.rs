@@ -0,0 +1,246 @@
+//! Transition buffers between incompatible activities.
+//!
+//! Includes:
+//! - Edition of the transition buffer
+//! - Possible insertion costs taking transition buffers into account
+//! - Activity insertion too close to an incompatible activity
+//! - Removal from the schedule when the transition buffer increases
+//! - Addition of an entity to an inserted activity too close to another activity
+
+use felix_data::{Duration, TimeInterval, TimeOfDay};
+use felix_test_utils::{Activity, DataBuilder};
+
+#[test]
+fn simple_set_activity_transition_buffer() {
+    test_ok!(
+        data,
+        DataBuilder::new().with_activity(Activity::default()),
+        {
+            let id = data.activities_sorted()[0].id();
+            assert_eq!(data.activity(id).transition_buffer(), Duration::new(0, 0));
+
+            data.set_activity_transition_buffer(id, Duration::new(0, 15))
+                .expect("Could not set transition buffer");
+            assert_eq!(
+                data.activity(id).transition_buffer(),
+                Duration::new(0, 15),
+                "Transition buffer was not set"
+            );
+        }
+    );
+}
+
+#[test]
+fn possible_insertion_costs_take_transition_buffer_into_account() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(9, 0),
+                TimeOfDay::new(14, 0)
+            ))
+            .with_activities(vec![
+                Activity {
+                    name: "Activity1",
+                    entities: vec![entity],
+                    duration: Duration::new(1, 0),
+                    insertion_time: Some(TimeOfDay::new(11, 0)),
+                    transition_buffer: Duration::new(0, 30),
+                    ..Default::default()
+                },
+                Activity {
+                    name: "Activity2",
+                    entities: vec![entity],
+                    duration: Duration::new(1, 0),
+                    ..Default::default()
+                }
+            ]),
+        {
+            let id2 = data.activities_sorted()[1].id();
+            data.wait_for_possible_insertion_costs_computation(id2);
+
+            let beginnings = data
+                .activity(id2)
+                .insertion_costs()
+                .expect("Insertion costs were not computed")
+                .iter()
+                .map(|insertion_cost| insertion_cost.beginning)
+                .collect::<Vec<_>>();
+
+            assert!(beginnings.contains(&TimeOfDay::new(9, 30)));
+            assert!(beginnings.contains(&TimeOfDay::new(12, 30)));
+            assert!(!beginnings.contains(&TimeOfDay::new(9, 35)));
+            assert!(!beginnings.contains(&TimeOfDay::new(12, 25)));
+        }
+    );
+}
+
+#[test]
+fn insert_activity_within_transition_buffer() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0)))
+            .with_activities(vec![
+                Activity {
+                    name: "Activity1",
+                    entities: vec![entity],
+                    duration: Duration::new(1, 0),
+                    ..Default::default()
+                },
+                Activity {
+                    name: "Activity2",
+                    entities: vec![entity],
+                    duration: Duration::new(1, 0),
+                    insertion_time: Some(TimeOfDay::new(10, 0)),
+                    transition_buffer: Duration::new(0, 15),
+                    ..Default::default()
+                }
+            ]),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.wait_for_possible_insertion_costs_computation(id);
+
+            data.insert_activity(id, Some(TimeOfDay::new(11, 10)))
+        },
+        "Activity1 cannot be inserted with beginning 11:10 because it would overlap with 'Activity2'.",
+        "Could insert activity within the transition buffer of another activity"
+    );
+}
+
+#[test]
+fn increase_transition_buffer_removes_activity_from_schedule_if_too_close() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(12, 0)
+            ))
+            .with_activities(vec![
+                Activity {
+                    name: "Activity1",
+                    entities: vec![entity],
+                    duration: Duration::new(1, 0),
+                    insertion_time: Some(TimeOfDay::new(8, 0)),
+                    ..Default::default()
+                },
+                Activity {
+                    name: "Activity2",
+                    entities: vec![entity],
+                    duration: Duration::new(1, 0),
+                    insertion_time: Some(TimeOfDay::new(9, 0)),
+                    ..Default::default()
+                }
+            ]),
+        {
+            let id1 = data.activities_sorted()[0].id();
+            let id2 = data.activities_sorted()[1].id();
+
+            data.set_activity_transition_buffer(id2, Duration::new(0, 15))
+                .expect("Could not set transition buffer");
+            assert!(
+                data.activity(id2).insertion_interval().is_none(),
+                "Activity too close to another activity was not removed from the schedule"
+            );
+            assert!(data.activity(id1).insertion_interval().is_some());
+
+            data.wait_for_possible_insertion_costs_computation(id2);
+            data.insert_activities_removed_because_duration_increased_in_closest_spot();
+            assert_eq!(
+                data.activity(id2).insertion_interval(),
+                Some(TimeInterval::new(
+                    TimeOfDay::new(9, 15),
+                    TimeOfDay::new(10, 15)
+                )),
+                "Activity was not inserted again in the closest spot"
+            );
+        }
+    );
+}
+
+#[test]
+fn decrease_transition_buffer_keeps_activity_in_schedule() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(12, 0)
+            ))
+            .with_activities(vec![
+                Activity {
+                    name: "Activity1",
+                    entities: vec![entity],
+                    duration: Duration::new(1, 0),
+                    insertion_time: Some(TimeOfDay::new(8, 0)),
+                    transition_buffer: Duration::new(0, 30),
+                    ..Default::default()
+                },
+                Activity {
+                    name: "Activity2",
+                    entities: vec![entity],
+                    duration: Duration::new(1, 0),
+                    insertion_time: Some(TimeOfDay::new(9, 30)),
+                    ..Default::default()
+                }
+            ]),
+        {
+            let id1 = data.activities_sorted()[0].id();
+            data.set_activity_transition_buffer(id1, Duration::new(0, 15))
+                .expect("Could not set transition buffer");
+            assert_eq!(
+                data.activity(id1).insertion_interval(),
+                Some(TimeInterval::new(
+                    TimeOfDay::new(8, 0),
+                    TimeOfDay::new(9, 0)
+                ))
+            );
+        }
+    );
+}
+
+#[test]
+fn add_entity_to_inserted_activity_within_transition_buffer() {
+    let (entity1, entity2) = ("Entity1", "Entity2");
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_entities(vec![entity1, entity2])
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(12, 0)
+            ))
+            .with_activities(vec![
+                Activity {
+                    name: "Activity1",
+                    entities: vec![entity1],
+                    duration: Duration::new(1, 0),
+                    insertion_time: Some(TimeOfDay::new(8, 0)),
+                    transition_buffer: Duration::new(0, 30),
+                    ..Default::default()
+                },
+                Activity {
+                    name: "Activity2",
+                    entities: vec![entity2],
+                    duration: Duration::new(1, 0),
+                    insertion_time: Some(TimeOfDay::new(9, 15)),
+                    ..Default::default()
+                }
+            ]),
+        {
+            let id1 = data.activities_sorted()[0].id();
+            data.add_entity_to_activity(id1, entity2)
+        },
+        "Entity2 cannot be added to 'Activity1' because it would overlap with 'Activity2'.",
+        "Could add entity to activity within the transition buffer of another activity"
+    );
+}
@@ -1,4 +1,4 @@
-use felix_data::{TimeInterval, Time};
+use felix_data::{Duration, TimeInterval, TimeOfDay};
 
 use felix_test_utils::{DataBuilder, Activity, test_ok};
 
@@ -16,19 +16,19 @@ fn on_work_hours_changed_check_not_insertable_activities_insertion_costs_untouch
 
     test_ok!(data,
             DataBuilder::new()
-            .with_work_interval(TimeInterval::new(Time::new(9, 0), Time::new(12, 0)))
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(9, 0), TimeOfDay::new(12, 0)))
             .with_entity(entity)
             .with_activities(vec![
                  // Duration == zero
                  Activity {
                     entities: vec![entity],
-                    duration: Time::new(0, 0),
+                    duration: Duration::new(0, 0),
                     ..Default::default()
                  },
                  // No participants
                  Activity {
                     entities: Vec::new(),
-                    duration: Time::new(1, 0),
+                    duration: Duration::new(1, 0),
                     ..Default::default()
                  }]),
         {
@@ -39,7 +39,7 @@ fn on_work_hours_changed_check_not_insertable_activities_insertion_costs_untouch
             assert_eq!(data.activity(id2).insertion_costs(), Some(Vec::new()));
 
             // Add new work interval and check that the insertion costs are still valid
-            let new_time_interval = TimeInterval::new(Time::new(13, 0), Time::new(16, 0));
+            let new_time_interval = TimeInterval::new(TimeOfDay::new(13, 0), TimeOfDay::new(16, 0));
             data.add_work_interval(new_time_interval).expect("Could not add time interval");
 
             assert_eq!(data.activity(id1).insertion_costs(), Some(Vec::new()));
@@ -0,0 +1,228 @@
+//! Changes applied at once in a batch.
+//!
+//! Includes:
+//! - Events deferred until the batch is committed
+//! - Computation of possible beginnings once the batch is committed
+//! - Insertions rejected until possible beginnings are computed again
+//! - Rollback of every change if one fails
+//! - Nested batches
+
+#[macro_use]
+extern crate assert_not_modified;
+
+use felix_data::{Data, Duration, Event, TimeOfDay};
+use felix_test_utils::{test_err, test_ok, Activity, DataBuilder};
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn batch_defers_events_until_commit() {
+    let entities = vec!["Entity1", "Entity2", "Entity3"];
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entities(entities.clone())
+            .with_work_interval_of_duration(4)
+            .with_activity(Activity {
+                name: "Activity",
+                duration: Duration::new(1, 0),
+                ..Default::default()
+            }),
+        {
+            let entities_added = Rc::new(RefCell::new(0));
+            let batch_events = Rc::new(RefCell::new(Vec::new()));
+            let entities_added_clone = entities_added.clone();
+            let batch_events_clone = batch_events.clone();
+            data.events()
+                .borrow_mut()
+                .connect_entity_added_to_activity(Box::new(move |_data: &Data, _activity| {
+                    *entities_added_clone.borrow_mut() += 1;
+                }));
+            data.events().borrow_mut().connect_batch_applied(Box::new(
+                move |data: &Data, events: &[Event]| {
+                    assert_eq!(
+                        data.entities_of_activity(data.activities_sorted()[0].id())
+                            .len(),
+                        3,
+                        "The batch was not committed when the event was emitted"
+                    );
+                    batch_events_clone.borrow_mut().extend_from_slice(events);
+                },
+            ));
+
+            let id = data.activities_sorted()[0].id();
+            data.batch(|data| {
+                for &entity in &entities {
+                    data.add_entity_to_activity(id, entity)?;
+                }
+                Ok(())
+            })
+            .expect("Could not apply batch");
+
+            assert_eq!(
+                *entities_added.borrow(),
+                0,
+                "Callbacks were called while the batch was applied"
+            );
+            assert_eq!(
+                *batch_events.borrow(),
+                vec![Event::EntityAddedToActivity; 3]
+            );
+            assert_eq!(data.entities_of_activity(id), entities);
+        }
+    );
+}
+
+#[test]
+fn batch_computes_possible_beginnings_on_commit() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval_of_duration(4)
+            .with_activity(Activity {
+                name: "Activity",
+                duration: Duration::new(1, 0),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.batch(|data| {
+                data.add_entity_to_activity(id, entity)?;
+                data.set_activity_duration(id, Duration::new(2, 0))
+            })
+            .expect("Could not apply batch");
+
+            data.wait_for_possible_insertion_costs_computation(id);
+            data.insert_activity(id, Some(TimeOfDay::new(2, 0)))
+                .expect("Could not insert activity after the batch");
+            assert_eq!(data.free_time_of(entity).unwrap(), Duration::new(2, 0));
+        }
+    );
+}
+
+#[test]
+fn batch_does_not_insert_with_outdated_possible_beginnings() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval_of_duration(4)
+            .with_activities(vec![
+                Activity {
+                    name: "Activity1",
+                    entities: vec![entity],
+                    duration: Duration::new(1, 0),
+                    ..Default::default()
+                },
+                Activity {
+                    name: "Activity2",
+                    entities: vec![entity],
+                    duration: Duration::new(1, 0),
+                    ..Default::default()
+                },
+            ]),
+        {
+            let activities = data.activities_sorted();
+            let (id1, id2) = (activities[0].id(), activities[1].id());
+            data.wait_for_possible_insertion_costs_computation(id1);
+            data.wait_for_possible_insertion_costs_computation(id2);
+
+            let error = data
+                .batch(|data| {
+                    data.insert_activity(id1, Some(TimeOfDay::new(1, 0)))?;
+                    data.insert_activity(id2, Some(TimeOfDay::new(1, 0)))
+                })
+                .expect_err("Two activities of the same entity were inserted at the same time");
+            assert_eq!(
+                error.to_string(),
+                "The possible beginnings of the activity 'Activity2' have not been computed yet."
+            );
+            assert_eq!(data.activity(id1).insertion_interval(), None);
+            assert_eq!(data.activity(id2).insertion_interval(), None);
+        }
+    );
+}
+
+#[test]
+fn batch_rolls_back_if_a_change_fails() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval_of_duration(4)
+            .with_activity(Activity {
+                name: "Activity",
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.batch(|data| {
+                data.add_entity("Other")?;
+                data.add_entity_to_activity(id, entity)?;
+                data.remove_entity("Does not exist")
+            })
+        },
+        "Does Not Exist does not exist.",
+        "A batch with a failing change was applied"
+    );
+}
+
+#[test]
+fn failed_batch_emits_no_event() {
+    test_ok!(data, DataBuilder::new(), {
+        let emitted = Rc::new(RefCell::new(0));
+        let emitted_clone = emitted.clone();
+        data.events()
+            .borrow_mut()
+            .connect_entity_added(Box::new(move |_data: &Data, _entity| {
+                *emitted_clone.borrow_mut() += 1;
+            }));
+
+        data.batch(|data| {
+            data.add_entity("Entity")?;
+            data.add_entity("Entity")
+        })
+        .expect_err("Could add the same entity twice");
+
+        assert_eq!(*emitted.borrow(), 0);
+        assert!(data.entities_sorted().is_empty());
+        assert!(
+            !data.can_undo(),
+            "A failed batch was recorded in the history"
+        );
+    });
+}
+
+#[test]
+fn nested_batch_rolls_back_its_own_changes() {
+    test_ok!(data, DataBuilder::new(), {
+        data.batch(|data| {
+            data.add_entity("Kept")?;
+            let nested_result = data.batch(|data| {
+                data.add_entity("Rolled back")?;
+                data.add_entity("Kept")
+            });
+            assert!(nested_result.is_err());
+            Ok(())
+        })
+        .expect("Could not apply batch");
+
+        let names = data
+            .entities_sorted()
+            .iter()
+            .map(|entity| entity.name())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["Kept"]);
+
+        assert!(data.undo());
+        assert!(
+            data.entities_sorted().is_empty(),
+            "The batch was not undone at once"
+        );
+    });
+}
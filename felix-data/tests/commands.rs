@@ -0,0 +1,289 @@
+//! Changes applied through commands.
+//!
+//! Includes:
+//! - Events returned by applied commands
+//! - Errors of applied commands
+//! - Rollback of commands which fail partway
+//! - Commands applied inside batches
+//! - Serialization of commands
+//! - Insertions found by the computation
+//! - Undo and redo of commands
+
+#[macro_use]
+extern crate assert_not_modified;
+
+use felix_data::{Command, Duration, Event, TimeInterval, TimeOfDay};
+use felix_test_utils::{test_err, test_ok, Activity, DataBuilder, Group};
+
+#[test]
+fn apply_returns_emitted_events() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval_of_duration(4)
+            .with_activity(Activity {
+                name: "Activity",
+                ..Default::default()
+            }),
+        {
+            let events = data
+                .apply(Command::AddEntity {
+                    name: "Other".to_owned(),
+                })
+                .expect("Could not apply command");
+            assert_eq!(events, vec![Event::EntityAdded]);
+            assert!(data.entity("Other").is_ok());
+
+            let id = data.activities_sorted()[0].id();
+            let events = data
+                .apply(Command::AddEntityToActivity {
+                    id,
+                    entity_name: entity.to_owned(),
+                })
+                .expect("Could not apply command");
+            assert_eq!(events, vec![Event::EntityAddedToActivity]);
+            assert_eq!(data.entities_of_activity(id), vec![entity]);
+        }
+    );
+}
+
+#[test]
+fn apply_command_which_fails() {
+    test_err!(
+        data,
+        DataBuilder::new(),
+        {
+            data.apply(Command::RemoveEntity {
+                name: "Entity".to_owned(),
+            })
+        },
+        "Entity does not exist.",
+        "Could remove an entity which does not exist"
+    );
+}
+
+#[test]
+fn apply_rolls_back_command_which_fails_partway() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_group(Group {
+                name: "Group",
+                entities: vec![entity],
+            })
+            .with_work_interval_of_duration(4)
+            .with_activity(Activity {
+                name: "Activity",
+                groups: vec!["Group"],
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.remove_entity_from_activity(id, entity)
+                .expect("Could not remove entity from activity");
+
+            // The member of the group is added before the group is found to be in the activity
+            let error = data
+                .apply(Command::AddGroupToActivity {
+                    id,
+                    group_name: "Group".to_owned(),
+                })
+                .expect_err("Could add a group twice to the same activity");
+            assert_eq!(
+                error.to_string(),
+                "The group 'Group' is already in the activity 'Activity'."
+            );
+            assert!(
+                data.entities_of_activity(id).is_empty(),
+                "The changes of a failed command were kept"
+            );
+
+            let events = data
+                .apply(Command::AddEntity {
+                    name: "Other".to_owned(),
+                })
+                .expect("Could not apply command after a failed one");
+            assert_eq!(events, vec![Event::EntityAdded]);
+        }
+    );
+}
+
+#[test]
+fn apply_inside_batch_returns_its_own_events() {
+    test_ok!(data, DataBuilder::new(), {
+        let batch_events = data
+            .batch(|data| {
+                let mut events = data.apply(Command::AddEntity {
+                    name: "Entity".to_owned(),
+                })?;
+                events.extend(data.apply(Command::AddGroup {
+                    name: "Group".to_owned(),
+                })?);
+                Ok(events)
+            })
+            .expect("Could not apply batch");
+        assert_eq!(batch_events, vec![Event::EntityAdded, Event::GroupAdded]);
+
+        let events = data
+            .apply(Command::AddEntity {
+                name: "Other".to_owned(),
+            })
+            .expect("Could not apply command after the batch");
+        assert_eq!(events, vec![Event::EntityAdded]);
+    });
+}
+
+#[test]
+fn apply_command_with_activity_which_does_not_exist() {
+    test_err!(
+        data,
+        DataBuilder::new(),
+        {
+            data.apply(Command::SetActivityDuration {
+                id: 42,
+                duration: Duration::new(1, 0),
+            })
+        },
+        "The activity with id '42' does not exist.",
+        "Could apply a command to an activity which does not exist"
+    );
+}
+
+#[test]
+fn apply_deserialized_commands() {
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    let commands = vec![
+        Command::AddWorkInterval { interval },
+        Command::AddEntity {
+            name: "Entity".to_owned(),
+        },
+        Command::AddGroup {
+            name: "Group".to_owned(),
+        },
+        Command::AddEntityToGroup {
+            group_name: "Group".to_owned(),
+            entity_name: "Entity".to_owned(),
+        },
+    ];
+    let serialized = serde_json::to_string(&commands).expect("Could not serialize commands");
+    let deserialized: Vec<Command> =
+        serde_json::from_str(&serialized).expect("Could not deserialize commands");
+    assert_eq!(deserialized, commands);
+
+    test_ok!(data, DataBuilder::new(), {
+        for command in deserialized {
+            data.apply(command).expect("Could not apply command");
+        }
+        assert_eq!(data.work_hours(), vec![interval]);
+        assert_eq!(
+            data.members_of_group("Group").expect("Group was not added"),
+            vec!["Entity"]
+        );
+    });
+}
+
+#[test]
+fn undo_and_redo_commands() {
+    test_ok!(
+        data,
+        DataBuilder::new().with_activity(Activity {
+            name: "Activity",
+            ..Default::default()
+        }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.apply(Command::DuplicateActivity { id, count: 3 })
+                .expect("Could not duplicate activity");
+            assert_eq!(data.activities_sorted().len(), 4);
+
+            let events = data.apply(Command::Undo).expect("Could not undo");
+            assert_eq!(events, vec![Event::DataRestored]);
+            assert_eq!(
+                data.activities_sorted().len(),
+                1,
+                "Changes of a command were not undone at once"
+            );
+
+            data.apply(Command::Redo).expect("Could not redo");
+            assert_eq!(data.activities_sorted().len(), 4);
+        }
+    );
+}
+
+#[test]
+fn apply_deserialized_autoinsertion_beginnings() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval_of_duration(4)
+            .with_activity(Activity {
+                name: "Activity",
+                entities: vec![entity],
+                duration: Duration::new(1, 0),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            let command = Command::ApplyAutoinsertionBeginnings {
+                beginnings: vec![(id, TimeOfDay::new(1, 0))],
+            };
+            let serialized = serde_json::to_string(&command).expect("Could not serialize command");
+            let deserialized: Command =
+                serde_json::from_str(&serialized).expect("Could not deserialize command");
+
+            let events = data.apply(deserialized).expect("Could not apply command");
+            assert_eq!(events, vec![Event::AutoinsertionDone]);
+            assert_eq!(
+                data.activity(id).insertion_interval(),
+                Some(TimeInterval::new(
+                    TimeOfDay::new(1, 0),
+                    TimeOfDay::new(2, 0)
+                ))
+            );
+        }
+    );
+}
+
+#[test]
+fn apply_insert_activities_removed_in_closest_spot() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval_of_duration(4)
+            .with_activity(Activity {
+                name: "Activity",
+                entities: vec![entity],
+                duration: Duration::new(1, 0),
+                insertion_time: Some(TimeOfDay::new(1, 0)),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.apply(Command::SetActivityDuration {
+                id,
+                duration: Duration::new(2, 0),
+            })
+            .expect("Could not set activity duration");
+            assert_eq!(data.activity(id).insertion_interval(), None);
+
+            data.wait_for_possible_insertion_costs_computation(id);
+            data.apply(Command::InsertActivitiesRemovedBecauseDurationIncreasedInClosestSpot)
+                .expect("Could not insert activities back");
+            assert_eq!(
+                data.activity(id).insertion_interval(),
+                Some(TimeInterval::new(
+                    TimeOfDay::new(1, 0),
+                    TimeOfDay::new(3, 0)
+                ))
+            );
+        }
+    );
+}
@@ -5,7 +5,7 @@
 //! - Custom work hours on inserted activities
 
 use felix_test_utils::{Activity, DataBuilder};
-use felix_data::{TimeInterval, Time};
+use felix_data::{Duration, TimeInterval, TimeOfDay};
 
 #[test]
 fn rename_entity_check_renamed_in_activity() {
@@ -24,7 +24,7 @@ fn rename_entity_check_renamed_in_activity() {
             let entity3 = data
                 .set_entity_name(entity1, "Entity3")
                 .expect("Could not rename entity");
-            let entities = data.activity(id).entities_sorted();
+            let entities = data.entities_of_activity(id);
 
             assert_eq!(
                 entities[0], entity2,
@@ -54,7 +54,7 @@ fn remove_entity_check_removed_in_activity() {
             let id = data.activities_sorted()[0].id();
             data.remove_entity(entity1)
                 .expect("Could not remove entity");
-            let entities = data.activity(id).entities_sorted();
+            let entities = data.entities_of_activity(id);
             assert_eq!(
                 entities.len(),
                 1,
@@ -88,74 +88,116 @@ fn remove_entity_check_activity_insertion_costs_updated() {
     );
 }
 
-/// Tests that custom work hours cannot be added while activities are inserted
+/// Tests that custom work hours can be added while activities are inserted
 #[test]
 fn add_custom_work_hours_with_inserted_activities() {
     let entity = "Jean";
-    let custom_work_interval = TimeInterval::new(Time::new(8, 0), Time::new(11, 0));
-    test_err!(
+    let custom_work_interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(11, 0));
+    test_ok!(
         data,
         DataBuilder::new()
-            .with_work_interval(TimeInterval::new(Time::new(8, 0), Time::new(12, 0)))
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0)))
             .with_entity(entity)
             .with_activity(Activity {
                 entities: vec![entity],
-                insertion_time: Some(Time::new(8, 0)),
+                insertion_time: Some(TimeOfDay::new(8, 0)),
                 ..Default::default()
             }),
         {
             data.add_custom_work_interval_for(entity, custom_work_interval)
-        },
-        "Work hours cannot be modified while an activity is inserted.",
-        "Could add custom work hours with one inserted activity"
+                .expect("Could not add custom work hours with one inserted activity");
+            // The activity still fits in the work hours of the entity
+            let id = data.activities_sorted()[0].id();
+            assert!(data.activity(id).insertion_interval().is_some());
+        }
     );
 }
 
-/// Tests that custom work hours cannot be removed while activities are inserted
+/// Tests that custom work hours can be removed while activities are inserted
 #[test]
 fn remove_custom_work_hours_with_inserted_activities() {
     let entity = "Jean";
-    let custom_work_interval = TimeInterval::new(Time::new(8, 0), Time::new(11, 0));
-    test_err!(
+    let custom_work_interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(11, 0));
+    test_ok!(
         data,
         DataBuilder::new()
-            .with_work_interval(TimeInterval::new(Time::new(8, 0), Time::new(12, 0)))
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0)))
             .with_entity(entity)
             .with_custom_work_interval_for(entity, custom_work_interval)
             .with_activity(Activity {
                 entities: vec![entity],
-                insertion_time: Some(Time::new(8, 0)),
+                insertion_time: Some(TimeOfDay::new(8, 0)),
                 ..Default::default()
             }),
         {
             data.remove_custom_work_interval_for(entity, custom_work_interval)
-        },
-        "Work hours cannot be modified while an activity is inserted.",
-        "Could remove custom work hours with one inserted activity"
+                .expect("Could not remove custom work hours with one inserted activity");
+            let id = data.activities_sorted()[0].id();
+            assert!(data.activity(id).insertion_interval().is_some());
+        }
     );
 }
 
-/// Tests that custom work hours cannot be updated while activities are inserted
+/// Tests that custom work hours can be updated while activities are inserted
 #[test]
 fn update_custom_work_hours_with_inserted_activities() {
     let entity = "Jean";
-    let custom_work_interval = TimeInterval::new(Time::new(8, 0), Time::new(11, 0));
-    test_err!(
+    let custom_work_interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(11, 0));
+    test_ok!(
         data,
         DataBuilder::new()
-            .with_work_interval(TimeInterval::new(Time::new(8, 0), Time::new(12, 0)))
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0)))
             .with_entity(entity)
             .with_custom_work_interval_for(entity, custom_work_interval)
             .with_activity(Activity {
                 entities: vec![entity],
-                insertion_time: Some(Time::new(8, 0)),
+                insertion_time: Some(TimeOfDay::new(8, 0)),
                 ..Default::default()
             }),
         {
-            let new_work_interval = TimeInterval::new(Time::new(8, 0), Time::new(9, 0));
+            let new_work_interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(9, 0));
             data.update_custom_work_interval_for(entity, custom_work_interval, new_work_interval)
-        },
-        "Work hours cannot be modified while an activity is inserted.",
-        "Could update custom work hours with one inserted activity"
+                .expect("Could not update custom work hours with one inserted activity");
+            let id = data.activities_sorted()[0].id();
+            assert!(data.activity(id).insertion_interval().is_some());
+        }
+    );
+}
+
+/// Tests that activities which no longer fit in the custom work hours are removed from the
+/// schedule, then inserted back in the closest spot
+#[test]
+fn update_custom_work_hours_uninserts_activities_which_no_longer_fit() {
+    let entity = "Jean";
+    let custom_work_interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(11, 0));
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0)))
+            .with_entity(entity)
+            .with_custom_work_interval_for(entity, custom_work_interval)
+            .with_activity(Activity {
+                entities: vec![entity],
+                duration: Duration::new(1, 0),
+                insertion_time: Some(TimeOfDay::new(10, 0)),
+                ..Default::default()
+            }),
+        {
+            let new_work_interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(10, 0));
+            data.update_custom_work_interval_for(entity, custom_work_interval, new_work_interval)
+                .expect("Could not update custom work hours with one inserted activity");
+            let id = data.activities_sorted()[0].id();
+            assert_eq!(data.activity(id).insertion_interval(), None);
+            assert!(data.activities_were_uninserted_and_can_maybe_be_inserted_back());
+
+            data.wait_for_possible_insertion_costs_computation(id);
+            data.insert_activities_removed_because_duration_increased_in_closest_spot();
+            let insertion_beginning = data
+                .activity(id)
+                .insertion_interval()
+                .expect("Activity was not reinserted in the schedule")
+                .beginning();
+            assert_eq!(insertion_beginning, TimeOfDay::new(9, 0));
+        }
     );
 }
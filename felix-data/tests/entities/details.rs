@@ -0,0 +1,207 @@
+//! Free-form details of entities, groups and activities.
+//!
+//! Includes:
+//! - Setting the details of entities, groups and activities
+//! - Formatting of tags, custom field names and emails
+//! - Description and location of activities, shared by sessions
+//! - Text search
+
+use felix_data::{Details, Duration, SessionRules};
+use felix_test_utils::{Activity, DataBuilder, Group};
+
+fn details_with_tag(tag: &str) -> Details {
+    let mut details = Details::default();
+    details.tags.insert(tag.to_owned());
+    details
+}
+
+#[test]
+fn simple_set_entity_details() {
+    let entity = "Entity";
+    test_ok!(data, DataBuilder::new().with_entity(entity), {
+        let mut details = details_with_tag("Counsellor");
+        details.notes = "Allergic to peanuts".to_owned();
+        details.email = Some("entity@example.com".to_owned());
+        details
+            .custom_fields
+            .insert("Phone".to_owned(), "0612345678".to_owned());
+
+        data.set_entity_details(entity, details.clone())
+            .expect("Could not set entity details");
+        assert_eq!(data.entity(entity).unwrap().details(), &details);
+    });
+}
+
+#[test]
+fn set_details_of_nonexistent_entity() {
+    test_err!(
+        data,
+        DataBuilder::new(),
+        data.set_entity_details("Entity", Details::default()),
+        "Entity does not exist.",
+        "Could set details of nonexistent entity"
+    );
+}
+
+#[test]
+fn set_entity_details_check_formatting() {
+    let entity = "Entity";
+    test_ok!(data, DataBuilder::new().with_entity(entity), {
+        let mut details = details_with_tag("  sports  team ");
+        details.notes = "  Notes \n".to_owned();
+        details.email = Some("   ".to_owned());
+        details
+            .custom_fields
+            .insert("phone  number".to_owned(), " 0612345678 ".to_owned());
+
+        data.set_entity_details(entity, details)
+            .expect("Could not set entity details");
+        let details = data.entity(entity).unwrap().details().clone();
+        assert!(details.has_tag("Sports Team"), "Tag was not formatted");
+        assert_eq!(details.notes, "Notes", "Notes were not trimmed");
+        assert_eq!(details.email, None, "Empty email was not removed");
+        assert_eq!(
+            details.custom_field("Phone Number"),
+            Some(&"0612345678".to_owned()),
+            "Custom field was not formatted"
+        );
+    });
+}
+
+#[test]
+fn set_entity_details_empty_tag() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new().with_entity(entity),
+        data.set_entity_details(entity, details_with_tag(" \t")),
+        "The given name is empty.",
+        "Could set empty tag"
+    );
+}
+
+#[test]
+fn entity_details_kept_when_renamed() {
+    let entity = "Entity";
+    test_ok!(data, DataBuilder::new().with_entity(entity), {
+        data.set_entity_details(entity, details_with_tag("Cook"))
+            .expect("Could not set entity details");
+        let new_name = data
+            .set_entity_name(entity, "Other")
+            .expect("Could not rename entity");
+        assert!(data.entity(new_name).unwrap().details().has_tag("Cook"));
+    });
+}
+
+#[test]
+fn simple_set_group_details() {
+    let group = "Group";
+    test_ok!(
+        data,
+        DataBuilder::new().with_group(Group::default(group)),
+        {
+            data.set_group_details(group, details_with_tag("Staff"))
+                .expect("Could not set group details");
+            assert!(data.group(group).unwrap().details().has_tag("Staff"));
+        }
+    );
+}
+
+#[test]
+fn set_details_of_nonexistent_group() {
+    test_err!(
+        data,
+        DataBuilder::new(),
+        data.set_group_details("Group", Details::default()),
+        "The group 'Group' does not exist.",
+        "Could set details of nonexistent group"
+    );
+}
+
+#[test]
+fn set_activity_details_description_and_location() {
+    test_ok!(
+        data,
+        DataBuilder::new().with_activity(Activity::default()),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_details(id, details_with_tag("outdoor"))
+                .expect("Could not set activity details");
+            data.set_activity_description(id, "  Bring a hat  ")
+                .expect("Could not set activity description");
+            data.set_activity_location(id, " Main   field ")
+                .expect("Could not set activity location");
+
+            let activity = data.activity(id);
+            assert!(activity.details().has_tag("Outdoor"));
+            assert_eq!(activity.description(), "Bring a hat");
+            assert_eq!(activity.location(), "Main field");
+        }
+    );
+}
+
+#[test]
+fn activity_details_shared_by_sessions() {
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(8)
+            .with_activity(Activity {
+                duration: Duration::new(2, 0),
+                sessions: 2,
+                session_rules: SessionRules::default(),
+                ..Default::default()
+            }),
+        {
+            let ids = data
+                .activities_sorted()
+                .iter()
+                .map(|activity| activity.id())
+                .collect::<Vec<_>>();
+            data.set_activity_location(ids[0], "Gym")
+                .expect("Could not set activity location");
+            data.set_activity_details(ids[0], details_with_tag("Sport"))
+                .expect("Could not set activity details");
+
+            for id in ids {
+                let session = data.activity(id);
+                assert_eq!(session.location(), "Gym");
+                assert!(session.details().has_tag("Sport"));
+            }
+        }
+    );
+}
+
+#[test]
+fn search_by_name_and_details() {
+    let (entity1, entity2) = ("Anna", "Paul");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entities(vec![entity1, entity2])
+            .with_group(Group::default("Kitchen"))
+            .with_activity(Activity::default()),
+        {
+            let details = Details {
+                notes: "Works in the kitchen".to_owned(),
+                ..Default::default()
+            };
+            data.set_entity_details(entity2, details)
+                .expect("Could not set entity details");
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_location(id, "Kitchen")
+                .expect("Could not set activity location");
+
+            let entities = data
+                .entities_matching("KITCHEN")
+                .iter()
+                .map(|entity| entity.name())
+                .collect::<Vec<_>>();
+            assert_eq!(entities, vec![entity2]);
+            assert_eq!(data.entities_matching("ann").len(), 1);
+            assert_eq!(data.groups_matching("kitchen").len(), 1);
+            assert_eq!(data.activities_matching("kitchen").len(), 1);
+            assert!(data.activities_matching("gym").is_empty());
+        }
+    );
+}
@@ -47,10 +47,7 @@ fn rename_entity_check_renamed_in_group() {
             let entity3 = data
                 .set_entity_name(entity1, "Name3")
                 .expect("Could not rename entity");
-            let group_members = data
-                .group(group)
-                .expect("Could not find group")
-                .entities_sorted();
+            let group_members = data.entities_of_group(group).expect("Could not find group");
             assert_eq!(
                 group_members[0], entity2,
                 "Entity was not renamed in group or names are not sorted"
@@ -78,10 +75,7 @@ fn remove_entity_check_removed_in_group() {
             data.remove_entity(entity1)
                 .expect("Could not remove entity");
 
-            let entities = data
-                .group(group)
-                .expect("Could not find group")
-                .entities_sorted();
+            let entities = data.entities_of_group(group).expect("Could not find group");
             assert_eq!(entities.len(), 1, "Entity was not removed from the group");
             assert_eq!(
                 entities[0], entity2,
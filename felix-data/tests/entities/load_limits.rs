@@ -0,0 +1,385 @@
+//! Caps on the daily load of entities.
+//!
+//! Includes:
+//! - Setting the load limits of entities and groups
+//! - Combination of entity and group limits
+//! - Enforcement when adding entities and groups to activities
+//! - Enforcement when changing the duration or the category of activities
+//! - Enforcement when adding entities to groups
+//! - Enforcement when lowering limits
+
+use felix_data::{Duration, LoadLimits, SessionRules};
+use felix_test_utils::{Activity, DataBuilder, Group};
+
+fn max_activity_time(hours: i8) -> LoadLimits {
+    LoadLimits {
+        max_activity_time: Some(Duration::new(hours, 0)),
+        ..LoadLimits::default()
+    }
+}
+
+fn max_activities_of_category(category: &str, max: usize) -> LoadLimits {
+    let mut limits = LoadLimits::default();
+    limits
+        .max_activities_per_category
+        .insert(category.to_owned(), max);
+    limits
+}
+
+#[test]
+fn simple_set_load_limits() {
+    let entity = "Entity";
+    test_ok!(data, DataBuilder::new().with_entity(entity), {
+        data.set_entity_load_limits(entity, max_activity_time(5))
+            .expect("Could not set load limits");
+        assert_eq!(
+            data.entity(entity).unwrap().load_limits(),
+            &max_activity_time(5)
+        );
+        assert_eq!(data.load_limits_of(entity).unwrap(), max_activity_time(5));
+    });
+}
+
+#[test]
+fn set_load_limits_of_nonexistent_entity() {
+    test_err!(
+        data,
+        DataBuilder::new(),
+        data.set_entity_load_limits("Entity", max_activity_time(5)),
+        "Entity does not exist.",
+        "Could set load limits of nonexistent entity"
+    );
+}
+
+#[test]
+fn load_limits_combined_with_group_limits() {
+    let entity = "Entity";
+    let group = "Group";
+    test_ok!(
+        data,
+        DataBuilder::new().with_entity(entity).with_group(Group {
+            name: group,
+            entities: vec![entity]
+        }),
+        {
+            data.set_entity_load_limits(entity, max_activity_time(5))
+                .expect("Could not set load limits of entity");
+            let mut group_limits = max_activities_of_category("Sport", 2);
+            group_limits.max_activity_time = Some(Duration::new(4, 0));
+            data.set_group_load_limits(group, group_limits)
+                .expect("Could not set load limits of group");
+
+            let limits = data.load_limits_of(entity).unwrap();
+            assert_eq!(limits.max_activity_time, Some(Duration::new(4, 0)));
+            assert_eq!(limits.max_activities_of_category("Sport"), Some(2));
+        }
+    );
+}
+
+#[test]
+fn add_entity_to_activity_exceeds_activity_time() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(8)
+            .with_entity(entity)
+            .with_activity(Activity {
+                name: "Activity1",
+                entities: vec![entity],
+                duration: Duration::new(3, 0),
+                ..Default::default()
+            })
+            .with_activity(Activity {
+                name: "Activity2",
+                duration: Duration::new(2, 30),
+                ..Default::default()
+            })
+            .with_load_limits_for(entity, max_activity_time(5)),
+        data.add_entity_to_activity(data.activities_sorted()[1].id(), entity),
+        "Entity would spend too much time in activities if they are added to 'Activity2'.",
+        "Could exceed max activity time"
+    );
+}
+
+#[test]
+fn add_entity_to_activity_within_activity_time() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(8)
+            .with_entity(entity)
+            .with_activity(Activity {
+                name: "Activity1",
+                entities: vec![entity],
+                duration: Duration::new(3, 0),
+                ..Default::default()
+            })
+            .with_activity(Activity {
+                name: "Activity2",
+                duration: Duration::new(2, 0),
+                ..Default::default()
+            })
+            .with_load_limits_for(entity, max_activity_time(5)),
+        {
+            let id = data.activities_sorted()[1].id();
+            data.add_entity_to_activity(id, entity)
+                .expect("Could not add entity to activity within its load limits");
+        }
+    );
+}
+
+#[test]
+fn add_entity_to_activity_exceeds_category_count() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(8)
+            .with_entity(entity)
+            .with_activity(Activity {
+                name: "Football",
+                entities: vec![entity],
+                category: Some("Sport"),
+                ..Default::default()
+            })
+            .with_activity(Activity {
+                name: "Tennis",
+                category: Some("Sport"),
+                ..Default::default()
+            })
+            .with_load_limits_for(entity, max_activities_of_category("Sport", 1)),
+        data.add_entity_to_activity(data.activities_sorted()[1].id(), entity),
+        "Entity would take part in too many activities of the category 'Sport' if they are added to 'Tennis'.",
+        "Could exceed max activities of category"
+    );
+}
+
+#[test]
+fn sessions_count_as_one_activity_of_category() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(8)
+            .with_entity(entity)
+            .with_load_limits_for(entity, max_activities_of_category("Sport", 1))
+            .with_activity(Activity {
+                name: "Football",
+                entities: vec![entity],
+                duration: Duration::new(1, 0),
+                category: Some("Sport"),
+                sessions: 2,
+                session_rules: SessionRules::default(),
+                ..Default::default()
+            }),
+        {
+            assert_eq!(data.activities_sorted().len(), 2);
+        }
+    );
+}
+
+#[test]
+fn add_group_to_activity_exceeds_group_limits() {
+    let (entity1, entity2) = ("Entity1", "Entity2");
+    let group = "Group";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(8)
+            .with_entities(vec![entity1, entity2])
+            .with_group(Group {
+                name: group,
+                entities: vec![entity1, entity2]
+            })
+            .with_activity(Activity {
+                name: "Activity1",
+                entities: vec![entity2],
+                duration: Duration::new(3, 0),
+                ..Default::default()
+            })
+            .with_activity(Activity {
+                name: "Activity2",
+                duration: Duration::new(2, 0),
+                ..Default::default()
+            })
+            .with_group_load_limits(group, max_activity_time(4)),
+        data.add_group_to_activity(data.activities_sorted()[1].id(), group),
+        "Entity2 would spend too much time in activities if they are added to 'Activity2'.",
+        "Could exceed group load limits"
+    );
+}
+
+#[test]
+fn increase_activity_duration_exceeds_activity_time() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(8)
+            .with_entity(entity)
+            .with_activity(Activity {
+                name: "Activity",
+                entities: vec![entity],
+                duration: Duration::new(3, 0),
+                ..Default::default()
+            })
+            .with_load_limits_for(entity, max_activity_time(4)),
+        data.set_activity_duration(data.activities_sorted()[0].id(), Duration::new(4, 30)),
+        "Entity would spend too much time in activities if the duration of 'Activity' is increased.",
+        "Could exceed max activity time by increasing the duration"
+    );
+}
+
+#[test]
+fn set_activity_category_exceeds_category_count() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(8)
+            .with_entity(entity)
+            .with_activity(Activity {
+                name: "Football",
+                entities: vec![entity],
+                category: Some("Sport"),
+                ..Default::default()
+            })
+            .with_activity(Activity {
+                name: "Lunch",
+                entities: vec![entity],
+                ..Default::default()
+            })
+            .with_load_limits_for(entity, max_activities_of_category("Sport", 1)),
+        data.set_activity_category(data.activities_sorted()[1].id(), Some("sport")),
+        "Entity would take part in too many activities of the category 'Sport' if the category of 'Lunch' is changed.",
+        "Could exceed max activities of category by changing the category"
+    );
+}
+
+#[test]
+fn set_and_remove_activity_category() {
+    test_ok!(
+        data,
+        DataBuilder::new().with_activity(Activity {
+            name: "Football",
+            ..Default::default()
+        }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.set_activity_category(id, Some("sport"))
+                .expect("Could not set activity category");
+            assert_eq!(data.activity(id).category(), Some("Sport".to_owned()));
+
+            data.set_activity_category::<String>(id, None)
+                .expect("Could not remove activity category");
+            assert_eq!(data.activity(id).category(), None);
+        }
+    );
+}
+
+#[test]
+fn add_entity_to_group_exceeds_group_limits() {
+    let entity = "Entity";
+    let group = "Group";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(8)
+            .with_entity(entity)
+            .with_group(Group::default(group))
+            .with_activity(Activity {
+                name: "Football",
+                entities: vec![entity],
+                category: Some("Sport"),
+                ..Default::default()
+            })
+            .with_activity(Activity {
+                name: "Tennis",
+                entities: vec![entity],
+                category: Some("Sport"),
+                ..Default::default()
+            })
+            .with_group_load_limits(group, max_activities_of_category("Sport", 1)),
+        data.add_entity_to_group(group, entity),
+        "Entity would take part in too many activities of the category 'Sport' if they take part in the activities of the group 'Group'.",
+        "Could add entity to group whose limits it exceeds"
+    );
+}
+
+#[test]
+fn lower_load_limits_below_current_load() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(8)
+            .with_entity(entity)
+            .with_activity(Activity {
+                name: "Activity",
+                entities: vec![entity],
+                duration: Duration::new(3, 0),
+                ..Default::default()
+            }),
+        data.set_entity_load_limits(entity, max_activity_time(2)),
+        "Entity would spend too much time in activities if their load limits are lowered.",
+        "Could lower load limits below current load"
+    );
+}
+
+#[test]
+fn lower_group_load_limits_below_current_load() {
+    let entity = "Entity";
+    let group = "Group";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(8)
+            .with_entity(entity)
+            .with_group(Group {
+                name: group,
+                entities: vec![entity]
+            })
+            .with_activity(Activity {
+                name: "Activity",
+                entities: vec![entity],
+                duration: Duration::new(3, 0),
+                ..Default::default()
+            }),
+        data.set_group_load_limits(group, max_activity_time(2)),
+        "Entity would spend too much time in activities if their load limits are lowered.",
+        "Could lower group load limits below current load"
+    );
+}
+
+#[test]
+fn raise_group_load_limits() {
+    let entity = "Entity";
+    let group = "Group";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(8)
+            .with_entity(entity)
+            .with_group(Group {
+                name: group,
+                entities: vec![entity]
+            })
+            .with_activity(Activity {
+                name: "Activity",
+                entities: vec![entity],
+                duration: Duration::new(3, 0),
+                ..Default::default()
+            })
+            .with_group_load_limits(group, max_activity_time(3)),
+        {
+            data.set_group_load_limits(group, max_activity_time(6))
+                .expect("Could not raise group load limits");
+            assert_eq!(
+                data.load_limits_of(entity).unwrap().max_activity_time,
+                Some(Duration::new(6, 0))
+            );
+        }
+    );
+}
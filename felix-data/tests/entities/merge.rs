@@ -0,0 +1,420 @@
+//! Merging of duplicate entities.
+//!
+//! Includes:
+//! - Transfer of group memberships, activity participations and custom work hours
+//! - Combination of details
+//! - Conflicts between inserted activities
+//! - Enforcement of free time and load limits
+
+use felix_data::{Details, Duration, LoadLimits, TimeInterval, TimeOfDay};
+use felix_test_utils::{Activity, DataBuilder, Group};
+
+#[test]
+fn simple_merge_entities() {
+    let (kept, removed) = ("Jean", "Jean Dupont");
+    let group = "Group";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(16)
+            .with_entities(vec![kept, removed])
+            .with_group(Group {
+                name: group,
+                entities: vec![removed],
+            })
+            .with_activity(Activity {
+                name: "Lunch",
+                entities: vec![removed],
+                ..Default::default()
+            }),
+        {
+            data.merge_entities(kept, removed)
+                .expect("Could not merge entities");
+            assert!(data.entity(removed).is_err(), "Removed entity still exists");
+            assert_eq!(data.entities_sorted().len(), 1);
+            assert_eq!(data.members_of_group(group).unwrap(), vec![kept]);
+            let activities = data.activities_of(kept).unwrap();
+            assert_eq!(activities.len(), 1);
+            assert_eq!(activities[0].name(), "Lunch");
+        }
+    );
+}
+
+#[test]
+fn merge_entities_shared_activity() {
+    let (kept, removed) = ("Jean", "Jean Dupont");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(16)
+            .with_entities(vec![kept, removed])
+            .with_activity(Activity {
+                name: "Lunch",
+                entities: vec![kept, removed],
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_of(kept).unwrap()[0].id();
+            data.merge_entities(kept, removed)
+                .expect("Could not merge entities sharing an activity");
+            assert_eq!(data.entities_of_activity(id), vec![kept]);
+        }
+    );
+}
+
+#[test]
+fn merge_entities_takes_part_in_group_activities() {
+    let (kept, removed) = ("Jean", "Jean Dupont");
+    let group = "Group";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(16)
+            .with_entities(vec![kept, removed])
+            .with_group(Group {
+                name: group,
+                entities: vec![removed],
+            })
+            .with_activity(Activity {
+                groups: vec![group],
+                ..Default::default()
+            }),
+        {
+            data.merge_entities(kept, removed)
+                .expect("Could not merge entities");
+            let id = data.activities_of(kept).unwrap()[0].id();
+            assert_eq!(data.entities_of_activity(id), vec![kept]);
+        }
+    );
+}
+
+#[test]
+fn merge_entities_nonexistent_entity() {
+    let kept = "Jean";
+    test_err!(
+        data,
+        DataBuilder::new().with_entity(kept),
+        data.merge_entities(kept, "Jean Dupont"),
+        "Jean Dupont does not exist.",
+        "Could merge nonexistent entity"
+    );
+}
+
+#[test]
+fn merge_entity_with_itself() {
+    let entity = "Jean";
+    test_err!(
+        data,
+        DataBuilder::new().with_entity(entity),
+        data.merge_entities(entity, " jean "),
+        "Jean cannot be merged with themselves.",
+        "Could merge entity with itself"
+    );
+}
+
+#[test]
+fn merge_entities_takes_custom_work_hours_of_removed_entity() {
+    let (kept, removed) = ("Jean", "Jean Dupont");
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entities(vec![kept, removed])
+            .with_custom_work_interval_for(removed, interval),
+        {
+            data.merge_entities(kept, removed)
+                .expect("Could not merge entities");
+            assert_eq!(data.custom_work_hours_of(kept).unwrap(), vec![interval]);
+        }
+    );
+}
+
+#[test]
+fn merge_entities_keeps_custom_work_hours_of_kept_entity() {
+    let (kept, removed) = ("Jean", "Jean Dupont");
+    let kept_interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    let removed_interval = TimeInterval::new(TimeOfDay::new(14, 0), TimeOfDay::new(18, 0));
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entities(vec![kept, removed])
+            .with_custom_work_interval_for(kept, kept_interval)
+            .with_custom_work_interval_for(removed, removed_interval),
+        {
+            data.merge_entities(kept, removed)
+                .expect("Could not merge entities");
+            assert_eq!(
+                data.custom_work_hours_of(kept).unwrap(),
+                vec![kept_interval]
+            );
+        }
+    );
+}
+
+#[test]
+fn merge_entities_takes_settings_of_removed_entity() {
+    let (kept, removed) = ("Jean", "Jean Dupont");
+    let morning = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    let preferred_interval = TimeInterval::new(TimeOfDay::new(9, 0), TimeOfDay::new(10, 0));
+    let unavailable_interval = TimeInterval::new(TimeOfDay::new(11, 0), TimeOfDay::new(12, 0));
+    let load_limits = LoadLimits {
+        max_activity_time: Some(Duration::new(3, 0)),
+        ..LoadLimits::default()
+    };
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entities(vec![kept, removed])
+            .with_work_hour_template("Morning", vec![morning])
+            .with_work_hour_template_for(removed, "Morning")
+            .with_preferred_interval_for(removed, preferred_interval)
+            .with_unavailable_interval_for(removed, unavailable_interval)
+            .with_max_continuous_work_for(removed, Duration::new(2, 0))
+            .with_load_limits_for(removed, load_limits.clone()),
+        {
+            data.merge_entities(kept, removed)
+                .expect("Could not merge entities");
+            assert_eq!(
+                data.work_hour_template_of(kept).unwrap(),
+                Some("Morning".to_string())
+            );
+            assert_eq!(
+                data.preferred_intervals_of(kept).unwrap(),
+                vec![preferred_interval]
+            );
+            assert_eq!(
+                data.unavailable_intervals_of(kept).unwrap(),
+                vec![unavailable_interval]
+            );
+            assert_eq!(
+                data.custom_break_rules_of(kept)
+                    .unwrap()
+                    .max_continuous_work(),
+                Some(Duration::new(2, 0))
+            );
+            assert_eq!(data.load_limits_of(kept).unwrap(), load_limits);
+        }
+    );
+}
+
+#[test]
+fn merge_entities_keeps_settings_of_kept_entity() {
+    let (kept, removed) = ("Jean", "Jean Dupont");
+    let kept_interval = TimeInterval::new(TimeOfDay::new(9, 0), TimeOfDay::new(10, 0));
+    let removed_interval = TimeInterval::new(TimeOfDay::new(14, 0), TimeOfDay::new(15, 0));
+    let kept_limits = LoadLimits {
+        max_activity_time: Some(Duration::new(3, 0)),
+        ..LoadLimits::default()
+    };
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entities(vec![kept, removed])
+            .with_preferred_interval_for(kept, kept_interval)
+            .with_preferred_interval_for(removed, removed_interval)
+            .with_unavailable_interval_for(kept, kept_interval)
+            .with_unavailable_interval_for(removed, removed_interval)
+            .with_load_limits_for(kept, kept_limits.clone())
+            .with_load_limits_for(
+                removed,
+                LoadLimits {
+                    max_activity_time: Some(Duration::new(1, 0)),
+                    ..LoadLimits::default()
+                }
+            ),
+        {
+            data.merge_entities(kept, removed)
+                .expect("Could not merge entities");
+            assert_eq!(
+                data.preferred_intervals_of(kept).unwrap(),
+                vec![kept_interval]
+            );
+            assert_eq!(
+                data.unavailable_intervals_of(kept).unwrap(),
+                vec![kept_interval]
+            );
+            assert_eq!(data.load_limits_of(kept).unwrap(), kept_limits);
+        }
+    );
+}
+
+#[test]
+fn merge_entities_not_enough_time_with_unavailability_of_removed_entity() {
+    let (kept, removed) = ("Jean", "Jean Dupont");
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(4)
+            .with_entities(vec![kept, removed])
+            .with_unavailable_interval_for(
+                removed,
+                TimeInterval::new(TimeOfDay::new(0, 0), TimeOfDay::new(2, 0))
+            )
+            .with_activity(Activity {
+                name: "Meeting",
+                entities: vec![kept],
+                duration: Duration::new(3, 0),
+                ..Default::default()
+            }),
+        data.merge_entities(kept, removed),
+        "Jean will not have enough time if they are merged with 'Jean Dupont'.",
+        "Could merge entities without enough time once unavailable"
+    );
+}
+
+#[test]
+fn merge_entities_combines_details() {
+    let (kept, removed) = ("Jean", "Jean Dupont");
+    test_ok!(
+        data,
+        DataBuilder::new().with_entities(vec![kept, removed]),
+        {
+            let mut kept_details = Details::default();
+            kept_details.tags.insert("Counsellor".to_owned());
+            kept_details
+                .custom_fields
+                .insert("Phone".to_owned(), "0612345678".to_owned());
+            data.set_entity_details(kept, kept_details).unwrap();
+
+            let mut removed_details = Details::default();
+            removed_details.tags.insert("Cook".to_owned());
+            removed_details.email = Some("jean@example.com".to_owned());
+            removed_details
+                .custom_fields
+                .insert("Phone".to_owned(), "0700000000".to_owned());
+            data.set_entity_details(removed, removed_details).unwrap();
+
+            data.merge_entities(kept, removed)
+                .expect("Could not merge entities");
+            let details = data.entity(kept).unwrap().details().clone();
+            assert!(details.has_tag("Counsellor") && details.has_tag("Cook"));
+            assert_eq!(details.email, Some("jean@example.com".to_owned()));
+            assert_eq!(
+                details.custom_field("Phone"),
+                Some(&"0612345678".to_owned()),
+                "Details of the kept entity were overwritten"
+            );
+        }
+    );
+}
+
+#[test]
+fn merge_entities_overlapping_inserted_activities() {
+    let (kept, removed) = ("Jean", "Jean Dupont");
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(16)
+            .with_entities(vec![kept, removed])
+            .with_activities(vec![
+                Activity {
+                    name: "Lunch",
+                    entities: vec![kept],
+                    duration: Duration::new(1, 0),
+                    insertion_time: Some(TimeOfDay::new(12, 0)),
+                    ..Default::default()
+                },
+                Activity {
+                    name: "Meeting",
+                    entities: vec![removed],
+                    duration: Duration::new(1, 0),
+                    insertion_time: Some(TimeOfDay::new(12, 30)),
+                    ..Default::default()
+                }
+            ]),
+        data.merge_entities(kept, removed),
+        "Jean and Jean Dupont cannot be merged because the activities 'Lunch' and 'Meeting' would overlap.",
+        "Could merge entities with overlapping inserted activities"
+    );
+}
+
+#[test]
+fn merge_entities_not_enough_time() {
+    let (kept, removed) = ("Jean", "Jean Dupont");
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(4)
+            .with_entities(vec![kept, removed])
+            .with_activities(vec![
+                Activity {
+                    name: "Lunch",
+                    entities: vec![kept],
+                    duration: Duration::new(3, 0),
+                    ..Default::default()
+                },
+                Activity {
+                    name: "Meeting",
+                    entities: vec![removed],
+                    duration: Duration::new(2, 0),
+                    ..Default::default()
+                }
+            ]),
+        data.merge_entities(kept, removed),
+        "Jean will not have enough time if they are merged with 'Jean Dupont'.",
+        "Could merge entities without enough time"
+    );
+}
+
+#[test]
+fn merge_entities_load_limits_exceeded() {
+    let (kept, removed) = ("Jean", "Jean Dupont");
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(16)
+            .with_entities(vec![kept, removed])
+            .with_load_limits_for(
+                kept,
+                LoadLimits {
+                    max_activity_time: Some(Duration::new(1, 0)),
+                    ..LoadLimits::default()
+                }
+            )
+            .with_activity(Activity {
+                name: "Meeting",
+                entities: vec![removed],
+                duration: Duration::new(2, 0),
+                ..Default::default()
+            }),
+        data.merge_entities(kept, removed),
+        "Jean would spend too much time in activities if they are merged with 'Jean Dupont'.",
+        "Could merge entities exceeding load limits"
+    );
+}
+
+#[test]
+fn merge_entities_error_leaves_data_unchanged() {
+    let (kept, removed) = ("Jean", "Jean Dupont");
+    let group = "Group";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(4)
+            .with_entities(vec![kept, removed])
+            .with_group(Group {
+                name: group,
+                entities: vec![removed],
+            })
+            .with_activities(vec![
+                Activity {
+                    name: "Lunch",
+                    entities: vec![kept],
+                    duration: Duration::new(3, 0),
+                    ..Default::default()
+                },
+                Activity {
+                    name: "Meeting",
+                    entities: vec![removed],
+                    duration: Duration::new(2, 0),
+                    ..Default::default()
+                }
+            ]),
+        {
+            assert!(data.merge_entities(kept, removed).is_err());
+            assert!(data.entity(removed).is_ok(), "Removed entity was removed");
+            assert_eq!(data.members_of_group(group).unwrap(), vec![removed]);
+            assert_eq!(data.activities_of(kept).unwrap().len(), 1);
+        }
+    );
+}
@@ -1,11 +1,11 @@
-use felix_data::{Time, TimeInterval};
+use felix_data::{TimeOfDay, TimeInterval};
 use felix_test_utils::DataBuilder;
 
 #[test]
 fn rename_entity_keeps_custom_work_hours() { 
     let name = "Paul";
     let new_name = "Test";
-    let custom_time_interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let custom_time_interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     test_ok!(
         data,
         DataBuilder::new()
@@ -31,7 +31,7 @@ fn rename_entity_keeps_custom_work_hours() {
  #[test]
 fn remove_entity_resets_custom_work_hours_for_future_entities() {
     let name = "Paul";
-    let custom_time_interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let custom_time_interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     test_ok!(
         data,
         DataBuilder::new()
@@ -1,4 +1,6 @@
-use felix_datatypes::{Time, TimeInterval};
+use felix_collections::Entities;
+use felix_data::ActivityFilter;
+use felix_datatypes::{Duration, TimeInterval, TimeOfDay};
 use felix_export_api::generate_pdf;
 use felix_test_utils::{test_ok, Activity, DataBuilder, Group};
 
@@ -7,7 +9,7 @@ fn test_printable_data_conversion() {
     let (entity1, entity2, entity3) = ("1", "2", "3");
     let entities = vec![entity1, entity2, entity3];
 
-    let work_hours = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let work_hours = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
 
     let group1 = Group {
         name: "Group",
@@ -16,10 +18,11 @@ fn test_printable_data_conversion() {
 
     let activity1 = Activity {
         name: "Activity1",
-        duration: Time::new(0, 30),
+        duration: Duration::new(0, 30),
         entities: vec![entity1, entity3],
         groups: vec![group1.name],
-        insertion_time: Some(Time::new(9, 0)),
+        insertion_time: Some(TimeOfDay::new(9, 0)),
+        ..Default::default()
     };
     let activities = vec![activity1.clone()];
 
@@ -60,12 +63,12 @@ fn test_printable_data_conversion() {
                 "Invalid activity duration"
             );
             assert_eq!(
-                real_activity1.entities_sorted(),
+                data.entities_of_activity(real_activity1.id()),
                 activity1.entities,
                 "Invalid activity participants"
             );
             assert_eq!(
-                real_activity1.groups_sorted(),
+                data.groups_of_activity(real_activity1.id()),
                 activity1.groups,
                 "Invalid activity groups"
             );
@@ -79,12 +82,51 @@ fn test_printable_data_conversion() {
     );
 }
 
+#[test]
+fn printable_data_filtered_by_category() {
+    let (entity1, entity2) = ("Anna", "Paul");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(12, 0)
+            ))
+            .with_entities(vec![entity1, entity2])
+            .with_activity(Activity {
+                name: "Football",
+                entities: vec![entity1],
+                category: Some("Sports"),
+                ..Default::default()
+            })
+            .with_activity(Activity {
+                name: "Lunch",
+                entities: vec![entity1, entity2],
+                category: Some("Meals"),
+                ..Default::default()
+            }),
+        {
+            let printable_data =
+                data.as_printable_filtered(&ActivityFilter::with_category("Sports"));
+
+            assert!(
+                !printable_data.contains_key(entity2),
+                "Entity without matching activities was exported"
+            );
+            let activities_of_entity1 = &printable_data[entity1];
+            assert_eq!(activities_of_entity1.len(), 1);
+            assert_eq!(activities_of_entity1[0].name(), "Football");
+        }
+    );
+}
+
 #[test]
 fn pdfs_are_generated() {
     // TODO create data with two entities then for each entity check that one pdf has been generated
     generate_pdf(
         "Marie-Claudine".to_string(),
         Vec::new(),
+        &Entities::new(),
         std::path::PathBuf::from("/tmp/"),
     );
 }
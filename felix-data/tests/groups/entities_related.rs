@@ -5,7 +5,7 @@
 //! - Deletion of entities
 //! - Renaming groups
 
-use felix_data::Time;
+use felix_data::Duration;
 use felix_test_utils::{Activity, DataBuilder, Group};
 
 // *** Add entity to group ***
@@ -22,9 +22,8 @@ fn simple_add_entity_to_group() {
             data.add_entity_to_group(group_name.clone(), entity_name.clone())
                 .expect("Could not add entity to group");
             let entities = data
-                .group(group_name)
-                .expect("Could not get group by name")
-                .entities_sorted();
+                .entities_of_group(group_name)
+                .expect("Could not get group by name");
             assert_eq!(entities.len(), 1, "Entity was not added to group");
             assert_eq!(
                 entities[0], entity_name,
@@ -112,10 +111,7 @@ fn add_entity_to_group_check_sorting() {
                 entities: vec![entity2, entity1]
             }),
         {
-            let entities = data
-                .group(group)
-                .expect("Could not get group")
-                .entities_sorted();
+            let entities = data.entities_of_group(group).expect("Could not get group");
             assert_eq!(entities[0], entity1, "Group members are not sorted");
             assert_eq!(entities[1], entity2, "Group members are not sorted");
         }
@@ -132,7 +128,7 @@ fn add_entity_to_group_not_enough_time_left() {
             .with_group(Group::default(group))
             .with_activity(Activity {
                 groups: vec![group],
-                duration: Time::new(1, 0),
+                duration: Duration::new(1, 0),
                 ..Default::default()
             }),
         data.add_entity_to_group(group, entity),
@@ -157,9 +153,8 @@ fn simple_remove_entity() {
             data.remove_entity_from_group(group, entity1)
                 .expect("Could not remove entity from group");
             let entities = data
-                .group(group)
-                .expect("Could not get group by name")
-                .entities_sorted();
+                .entities_of_group(group)
+                .expect("Could not get group by name");
             assert_eq!(entities.len(), 1, "Entity was not removed from group");
             assert_eq!(entities[0], entity2, "The wrong entity was removed");
         }
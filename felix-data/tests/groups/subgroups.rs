@@ -0,0 +1,466 @@
+//! Groups which contain other groups.
+//!
+//! Includes:
+//! - Adding and removing subgroups
+//! - Cycle detection
+//! - Transitive membership in activities
+//! - Transitive work hours and load limits
+//! - Removing entities and groups which belong to nested groups
+
+use felix_data::{Duration, LoadLimits, TimeInterval, TimeOfDay};
+use felix_test_utils::{Activity, DataBuilder, Group};
+
+#[test]
+fn simple_add_group_to_group() {
+    let (staff, cooks, entity) = ("Staff", "Cooks", "Anna");
+    test_ok!(
+        data,
+        DataBuilder::new().with_entity(entity).with_groups(vec![
+            Group::default(staff),
+            Group {
+                name: cooks,
+                entities: vec![entity],
+            }
+        ]),
+        {
+            data.add_group_to_group(staff, cooks)
+                .expect("Could not add group to group");
+            assert_eq!(data.subgroups_of_group(staff).unwrap(), vec![cooks]);
+            assert!(
+                data.entities_of_group(staff).unwrap().is_empty(),
+                "Members of the subgroup were added to the group directly"
+            );
+            assert_eq!(data.members_of_group(staff).unwrap(), vec![entity]);
+        }
+    );
+}
+
+#[test]
+fn add_group_to_group_twice() {
+    let (staff, cooks) = ("Staff", "Cooks");
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_groups(vec![Group::default(staff), Group::default(cooks)])
+            .with_subgroup(staff, cooks),
+        data.add_group_to_group(staff, cooks),
+        "The group 'Cooks' is already in the group 'Staff'.",
+        "Could add a group to a group twice"
+    );
+}
+
+#[test]
+fn add_group_to_itself() {
+    let staff = "Staff";
+    test_err!(
+        data,
+        DataBuilder::new().with_group(Group::default(staff)),
+        data.add_group_to_group(staff, staff),
+        "The group 'Staff' cannot contain itself.",
+        "Could add a group to itself"
+    );
+}
+
+#[test]
+fn add_group_to_group_cycle() {
+    let (staff, cooks, chefs) = ("Staff", "Cooks", "Chefs");
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_groups(vec![
+                Group::default(staff),
+                Group::default(cooks),
+                Group::default(chefs)
+            ])
+            .with_subgroup(staff, cooks)
+            .with_subgroup(cooks, chefs),
+        data.add_group_to_group(chefs, staff),
+        "The group 'Chefs' cannot contain the group 'Staff' because it is one of its members.",
+        "Could create a cycle of groups"
+    );
+}
+
+#[test]
+fn add_group_to_nonexistent_group() {
+    let cooks = "Cooks";
+    test_err!(
+        data,
+        DataBuilder::new().with_group(Group::default(cooks)),
+        data.add_group_to_group("Staff", cooks),
+        "The group 'Staff' does not exist.",
+        "Could add a group to a nonexistent group"
+    );
+}
+
+#[test]
+fn members_of_nested_groups() {
+    let (staff, cooks, chefs) = ("Staff", "Cooks", "Chefs");
+    let (entity1, entity2, entity3) = ("Anna", "Paul", "Marie");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entities(vec![entity1, entity2, entity3])
+            .with_groups(vec![
+                Group {
+                    name: staff,
+                    entities: vec![entity3],
+                },
+                Group {
+                    name: cooks,
+                    entities: vec![entity2],
+                },
+                Group {
+                    name: chefs,
+                    entities: vec![entity1],
+                }
+            ])
+            .with_subgroup(staff, cooks)
+            .with_subgroup(cooks, chefs),
+        {
+            assert_eq!(
+                data.members_of_group(staff).unwrap(),
+                vec![entity1, entity3, entity2]
+            );
+            assert_eq!(
+                data.members_of_group(cooks).unwrap(),
+                vec![entity1, entity2]
+            );
+            assert_eq!(data.members_of_group(chefs).unwrap(), vec![entity1]);
+        }
+    );
+}
+
+#[test]
+fn add_group_with_subgroups_to_activity() {
+    let (staff, cooks, entity1, entity2) = ("Staff", "Cooks", "Anna", "Paul");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(4)
+            .with_entities(vec![entity1, entity2])
+            .with_groups(vec![
+                Group {
+                    name: staff,
+                    entities: vec![entity2],
+                },
+                Group {
+                    name: cooks,
+                    entities: vec![entity1],
+                }
+            ])
+            .with_subgroup(staff, cooks)
+            .with_activity(Activity::default()),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.add_group_to_activity(id, staff)
+                .expect("Could not add group to activity");
+            assert_eq!(data.entities_of_activity(id), vec![entity1, entity2]);
+        }
+    );
+}
+
+#[test]
+fn add_group_to_group_adds_members_to_activities() {
+    let (staff, cooks, chefs, entity) = ("Staff", "Cooks", "Chefs", "Anna");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(4)
+            .with_entity(entity)
+            .with_groups(vec![
+                Group::default(staff),
+                Group::default(cooks),
+                Group {
+                    name: chefs,
+                    entities: vec![entity],
+                }
+            ])
+            .with_subgroup(staff, cooks)
+            .with_activity(Activity {
+                groups: vec![staff],
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.add_group_to_group(cooks, chefs)
+                .expect("Could not add group to group");
+            assert_eq!(
+                data.entities_of_activity(id),
+                vec![entity],
+                "Members of the subgroup were not added to the activities of the groups containing it"
+            );
+        }
+    );
+}
+
+#[test]
+fn add_entity_to_subgroup_adds_it_to_activities_of_containing_groups() {
+    let (staff, cooks, entity) = ("Staff", "Cooks", "Anna");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(4)
+            .with_entity(entity)
+            .with_groups(vec![Group::default(staff), Group::default(cooks)])
+            .with_subgroup(staff, cooks)
+            .with_activity(Activity {
+                groups: vec![staff],
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.add_entity_to_group(cooks, entity)
+                .expect("Could not add entity to group");
+            assert_eq!(data.entities_of_activity(id), vec![entity]);
+        }
+    );
+}
+
+#[test]
+fn add_group_to_group_not_enough_time() {
+    let (staff, cooks, entity) = ("Staff", "Cooks", "Anna");
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(2)
+            .with_entity(entity)
+            .with_groups(vec![
+                Group::default(staff),
+                Group {
+                    name: cooks,
+                    entities: vec![entity],
+                }
+            ])
+            .with_activity(Activity {
+                name: "Kitchen",
+                duration: Duration::new(2, 0),
+                entities: vec![entity],
+                ..Default::default()
+            })
+            .with_activity(Activity {
+                name: "Meeting",
+                duration: Duration::new(1, 0),
+                groups: vec![staff],
+                ..Default::default()
+            }),
+        data.add_group_to_group(staff, cooks),
+        "Anna will not have enough time if they take part in the activities of the group 'Staff'.",
+        "Could add group to group when a member does not have enough time"
+    );
+}
+
+#[test]
+fn add_group_to_group_exceeds_load_limits() {
+    let (staff, cooks, entity) = ("Staff", "Cooks", "Anna");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(8)
+            .with_entity(entity)
+            .with_groups(vec![
+                Group::default(staff),
+                Group {
+                    name: cooks,
+                    entities: vec![entity],
+                }
+            ])
+            .with_group_load_limits(
+                staff,
+                LoadLimits {
+                    max_activity_time: Some(Duration::new(1, 0)),
+                    ..LoadLimits::default()
+                }
+            )
+            .with_activity(Activity {
+                duration: Duration::new(2, 0),
+                entities: vec![entity],
+                ..Default::default()
+            }),
+        {
+            assert!(
+                data.add_group_to_group(staff, cooks).is_err(),
+                "Could add group to group when a member exceeds the limits of the group"
+            );
+            assert!(data.subgroups_of_group(staff).unwrap().is_empty());
+        }
+    );
+}
+
+#[test]
+fn work_hours_of_containing_group_apply_to_members_of_subgroups() {
+    let (staff, cooks, entity) = ("Staff", "Cooks", "Anna");
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(10, 0));
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(8)
+            .with_entity(entity)
+            .with_groups(vec![
+                Group::default(staff),
+                Group {
+                    name: cooks,
+                    entities: vec![entity],
+                }
+            ])
+            .with_group_work_interval_for(staff, interval),
+        {
+            data.add_group_to_group(staff, cooks)
+                .expect("Could not add group to group");
+            assert_eq!(data.work_hours_of(entity).unwrap(), vec![interval]);
+
+            data.remove_group_from_group(staff, cooks)
+                .expect("Could not remove group from group");
+            assert_ne!(
+                data.work_hours_of(entity).unwrap(),
+                vec![interval],
+                "Work hours of the group still apply after the subgroup was removed"
+            );
+        }
+    );
+}
+
+#[test]
+fn remove_group_from_group_removes_members_from_activities() {
+    let (staff, cooks, entity1, entity2) = ("Staff", "Cooks", "Anna", "Paul");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(4)
+            .with_entities(vec![entity1, entity2])
+            .with_groups(vec![
+                Group {
+                    name: staff,
+                    entities: vec![entity2],
+                },
+                Group {
+                    name: cooks,
+                    entities: vec![entity1, entity2],
+                }
+            ])
+            .with_subgroup(staff, cooks)
+            .with_activity(Activity {
+                groups: vec![staff],
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.remove_group_from_group(staff, cooks)
+                .expect("Could not remove group from group");
+            assert!(data.subgroups_of_group(staff).unwrap().is_empty());
+            assert_eq!(
+                data.entities_of_activity(id),
+                vec![entity2],
+                "Only members which are still in the group should take part in its activities"
+            );
+        }
+    );
+}
+
+#[test]
+fn remove_group_not_in_group() {
+    let (staff, cooks) = ("Staff", "Cooks");
+    test_err!(
+        data,
+        DataBuilder::new().with_groups(vec![Group::default(staff), Group::default(cooks)]),
+        data.remove_group_from_group(staff, cooks),
+        "The group 'Cooks' is not in the group 'Staff'.",
+        "Could remove a group which is not in the group"
+    );
+}
+
+#[test]
+fn remove_entity_from_subgroup_removes_it_from_activities_of_containing_groups() {
+    let (staff, cooks, entity) = ("Staff", "Cooks", "Anna");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(4)
+            .with_entity(entity)
+            .with_groups(vec![
+                Group::default(staff),
+                Group {
+                    name: cooks,
+                    entities: vec![entity],
+                }
+            ])
+            .with_subgroup(staff, cooks)
+            .with_activity(Activity {
+                groups: vec![staff],
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.remove_entity_from_group(cooks, entity)
+                .expect("Could not remove entity from group");
+            assert!(data.entities_of_activity(id).is_empty());
+        }
+    );
+}
+
+#[test]
+fn remove_entity_from_subgroup_keeps_other_memberships() {
+    let (staff, cooks, entity) = ("Staff", "Cooks", "Anna");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(4)
+            .with_entity(entity)
+            .with_groups(vec![
+                Group {
+                    name: staff,
+                    entities: vec![entity],
+                },
+                Group {
+                    name: cooks,
+                    entities: vec![entity],
+                }
+            ])
+            .with_subgroup(staff, cooks)
+            .with_activity(Activity {
+                groups: vec![staff],
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.remove_entity_from_group(cooks, entity)
+                .expect("Could not remove entity from group");
+            assert_eq!(
+                data.entities_of_activity(id),
+                vec![entity],
+                "Entity is still a member of the group directly"
+            );
+        }
+    );
+}
+
+#[test]
+fn remove_subgroup() {
+    let (staff, cooks, entity) = ("Staff", "Cooks", "Anna");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(4)
+            .with_entity(entity)
+            .with_groups(vec![
+                Group::default(staff),
+                Group {
+                    name: cooks,
+                    entities: vec![entity],
+                }
+            ])
+            .with_subgroup(staff, cooks)
+            .with_activity(Activity {
+                groups: vec![staff],
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.remove_group(cooks).expect("Could not remove group");
+            assert!(data.subgroups_of_group(staff).unwrap().is_empty());
+            assert!(
+                data.entities_of_activity(id).is_empty(),
+                "Members of the removed subgroup still take part in the activities of the group"
+            );
+        }
+    );
+}
@@ -0,0 +1,420 @@
+//! Operations on groups which depend on work hours.
+//!
+//! Includes:
+//! - Addition, removal and update of group work intervals
+//! - Precedence of custom work hours and templates over group work hours
+//! - Members of several groups with work hours
+//! - Addition and removal of members
+//! - Renaming and removal of groups
+//! - Free time checks
+//! - Removal of inserted activities which no longer fit
+
+use felix_data::{Duration, TimeInterval, TimeOfDay};
+use felix_test_utils::{Activity, DataBuilder, Group};
+
+fn day() -> TimeInterval {
+    TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(18, 0))
+}
+
+fn morning() -> TimeInterval {
+    TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0))
+}
+
+#[test]
+fn simple_add_group_work_interval() {
+    let (entity, group) = ("Entity", "Group");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_group(Group {
+                name: group,
+                entities: vec![entity]
+            }),
+        {
+            data.add_group_work_interval_for(group, morning())
+                .expect("Could not add group work interval");
+            assert_eq!(data.group_work_hours_of(group).unwrap(), vec![morning()]);
+            assert_eq!(data.work_hours_of(entity).unwrap(), vec![morning()]);
+            // Global work hours are not modified
+            assert_eq!(data.work_hours(), vec![day()]);
+        }
+    );
+}
+
+#[test]
+fn add_group_work_interval_nonexistent_group() {
+    test_err!(
+        data,
+        DataBuilder::new(),
+        data.add_group_work_interval_for("Group", morning()),
+        "The group 'Group' does not exist.",
+        "Could add work interval to nonexistent group"
+    );
+}
+
+#[test]
+fn add_overlapping_group_work_interval() {
+    let group = "Group";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_group(Group::default(group))
+            .with_group_work_interval_for(group, morning()),
+        data.add_group_work_interval_for(
+            group,
+            TimeInterval::new(TimeOfDay::new(11, 0), TimeOfDay::new(13, 0))
+        ),
+        "The given interval overlaps with others.",
+        "Could add overlapping group work interval"
+    );
+}
+
+#[test]
+fn add_group_work_interval_not_enough_free_time() {
+    let (entity, group) = ("Entity", "Group");
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_group(Group {
+                name: group,
+                entities: vec![entity]
+            })
+            .with_activity(Activity {
+                duration: Duration::new(5, 0),
+                entities: vec![entity],
+                ..Default::default()
+            }),
+        data.add_group_work_interval_for(group, morning()),
+        "Entity will not have enough time if their work hours are shortened.",
+        "Could add group work interval which led to entity not having enough time"
+    );
+}
+
+#[test]
+fn add_group_work_interval_activity_inserted() {
+    let (entity, group) = ("Entity", "Group");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_group(Group {
+                name: group,
+                entities: vec![entity]
+            })
+            .with_activity(Activity {
+                duration: Duration::new(1, 0),
+                entities: vec![entity],
+                insertion_time: Some(TimeOfDay::new(15, 0)),
+                ..Default::default()
+            }),
+        {
+            data.add_group_work_interval_for(group, morning())
+                .expect("Could not add group work interval while an activity is inserted");
+            let id = data.activities_sorted()[0].id();
+            assert_eq!(data.activity(id).insertion_interval(), None);
+
+            data.wait_for_possible_insertion_costs_computation(id);
+            data.insert_activities_removed_because_duration_increased_in_closest_spot();
+            let insertion_beginning = data
+                .activity(id)
+                .insertion_interval()
+                .expect("Activity was not reinserted in the schedule")
+                .beginning();
+            assert_eq!(insertion_beginning, TimeOfDay::new(11, 0));
+        }
+    );
+}
+
+#[test]
+fn remove_last_group_work_interval() {
+    let (entity, group) = ("Entity", "Group");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_group(Group {
+                name: group,
+                entities: vec![entity]
+            })
+            .with_group_work_interval_for(group, morning()),
+        {
+            data.remove_group_work_interval_for(group, morning())
+                .expect("Could not remove group work interval");
+            assert!(data.group_work_hours_of(group).unwrap().is_empty());
+            assert_eq!(data.work_hours_of(entity).unwrap(), vec![day()]);
+        }
+    );
+}
+
+#[test]
+fn remove_nonexistent_group_work_interval() {
+    let group = "Group";
+    test_err!(
+        data,
+        DataBuilder::new().with_group(Group::default(group)),
+        data.remove_group_work_interval_for(group, morning()),
+        "The interval '08:00 - 12:00' does not exist.",
+        "Could remove nonexistent group work interval"
+    );
+}
+
+#[test]
+fn update_group_work_interval_not_enough_free_time() {
+    let (entity, group) = ("Entity", "Group");
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_group(Group {
+                name: group,
+                entities: vec![entity]
+            })
+            .with_group_work_interval_for(group, morning())
+            .with_activity(Activity {
+                duration: Duration::new(3, 0),
+                entities: vec![entity],
+                ..Default::default()
+            }),
+        data.update_group_work_interval_for(
+            group,
+            morning(),
+            TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(10, 0))
+        ),
+        "Entity will not have enough time if their work hours are shortened.",
+        "Could update group work interval which led to entity not having enough time"
+    );
+}
+
+#[test]
+fn custom_work_hours_and_template_take_precedence_over_group() {
+    let (entity1, entity2, entity3) = ("Entity1", "Entity2", "Entity3");
+    let group = "Group";
+    let custom_interval = TimeInterval::new(TimeOfDay::new(14, 0), TimeOfDay::new(16, 0));
+    let early_shift = TimeInterval::new(TimeOfDay::new(6, 0), TimeOfDay::new(10, 0));
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entities(vec![entity1, entity2, entity3])
+            .with_custom_work_interval_for(entity1, custom_interval)
+            .with_work_hour_template("Early Shift", vec![early_shift])
+            .with_work_hour_template_for(entity2, "Early Shift")
+            .with_group(Group {
+                name: group,
+                entities: vec![entity1, entity2, entity3]
+            })
+            .with_group_work_interval_for(group, morning()),
+        {
+            assert_eq!(data.work_hours_of(entity1).unwrap(), vec![custom_interval]);
+            assert_eq!(data.work_hours_of(entity2).unwrap(), vec![early_shift]);
+            assert_eq!(data.work_hours_of(entity3).unwrap(), vec![morning()]);
+        }
+    );
+}
+
+#[test]
+fn member_of_several_groups_works_during_shared_hours() {
+    let entity = "Entity";
+    let (group1, group2) = ("Group1", "Group2");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_groups(vec![
+                Group {
+                    name: group1,
+                    entities: vec![entity]
+                },
+                Group {
+                    name: group2,
+                    entities: vec![entity]
+                }
+            ])
+            .with_group_work_interval_for(group1, morning())
+            .with_group_work_interval_for(
+                group2,
+                TimeInterval::new(TimeOfDay::new(10, 0), TimeOfDay::new(14, 0))
+            ),
+        {
+            assert_eq!(
+                data.work_hours_of(entity).unwrap(),
+                vec![TimeInterval::new(
+                    TimeOfDay::new(10, 0),
+                    TimeOfDay::new(12, 0)
+                )]
+            );
+        }
+    );
+}
+
+#[test]
+fn add_entity_to_group_with_work_hours() {
+    let (entity, group) = ("Entity", "Group");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_group(Group::default(group))
+            .with_group_work_interval_for(group, morning()),
+        {
+            data.add_entity_to_group(group, entity)
+                .expect("Could not add entity to group");
+            assert_eq!(data.work_hours_of(entity).unwrap(), vec![morning()]);
+
+            data.remove_entity_from_group(group, entity)
+                .expect("Could not remove entity from group");
+            assert_eq!(data.work_hours_of(entity).unwrap(), vec![day()]);
+        }
+    );
+}
+
+#[test]
+fn add_entity_to_group_with_work_hours_not_enough_free_time() {
+    let (entity, group) = ("Entity", "Group");
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_group(Group::default(group))
+            .with_group_work_interval_for(group, morning())
+            .with_activity(Activity {
+                duration: Duration::new(5, 0),
+                entities: vec![entity],
+                ..Default::default()
+            }),
+        data.add_entity_to_group(group, entity),
+        "Entity will not have enough time if their work hours are shortened.",
+        "Could add entity to group whose work hours are too short for it"
+    );
+}
+
+#[test]
+fn add_entity_to_group_with_work_hours_activity_inserted() {
+    let (entity, group) = ("Entity", "Group");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_group(Group::default(group))
+            .with_group_work_interval_for(group, morning())
+            .with_activity(Activity {
+                name: "Morning",
+                duration: Duration::new(1, 0),
+                entities: vec![entity],
+                insertion_time: Some(TimeOfDay::new(8, 0)),
+                ..Default::default()
+            })
+            .with_activity(Activity {
+                name: "Afternoon",
+                duration: Duration::new(1, 0),
+                entities: vec![entity],
+                insertion_time: Some(TimeOfDay::new(15, 0)),
+                ..Default::default()
+            }),
+        {
+            data.add_entity_to_group(group, entity)
+                .expect("Could not add entity to group while an activity is inserted");
+            let activities = data.activities_sorted();
+            let (afternoon_id, morning_id) = (activities[0].id(), activities[1].id());
+            assert!(data.activity(morning_id).insertion_interval().is_some());
+            assert_eq!(data.activity(afternoon_id).insertion_interval(), None);
+        }
+    );
+}
+
+#[test]
+fn rename_group_keeps_work_hours() {
+    let (entity, group) = ("Entity", "Group");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_group(Group {
+                name: group,
+                entities: vec![entity]
+            })
+            .with_group_work_interval_for(group, morning()),
+        {
+            data.set_group_name(group, "New Name")
+                .expect("Could not rename group");
+            assert_eq!(
+                data.group_work_hours_of("New Name").unwrap(),
+                vec![morning()]
+            );
+            assert_eq!(data.work_hours_of(entity).unwrap(), vec![morning()]);
+        }
+    );
+}
+
+#[test]
+fn remove_group_with_work_hours() {
+    let (entity, group) = ("Entity", "Group");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_group(Group {
+                name: group,
+                entities: vec![entity]
+            })
+            .with_group_work_interval_for(group, morning()),
+        {
+            data.remove_group(group).expect("Could not remove group");
+            assert_eq!(data.work_hours_of(entity).unwrap(), vec![day()]);
+
+            data.add_group(group).expect("Could not add group");
+            assert!(data.group_work_hours_of(group).unwrap().is_empty());
+        }
+    );
+}
+
+#[test]
+fn group_work_hours_update_insertion_costs() {
+    let (entity, group) = ("Entity", "Group");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_group(Group {
+                name: group,
+                entities: vec![entity]
+            })
+            .with_activity(Activity {
+                duration: Duration::new(1, 0),
+                entities: vec![entity],
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            let beginnings = |data: &felix_data::Data| {
+                data.wait_for_possible_insertion_costs_computation(id);
+                data.activity(id)
+                    .insertion_costs()
+                    .expect("Insertion costs were not computed")
+                    .iter()
+                    .map(|insertion_cost| insertion_cost.beginning)
+                    .collect::<Vec<_>>()
+            };
+            assert!(beginnings(&data).contains(&TimeOfDay::new(15, 0)));
+
+            data.add_group_work_interval_for(group, morning())
+                .expect("Could not add group work interval");
+            assert!(!beginnings(&data).contains(&TimeOfDay::new(15, 0)));
+            assert!(beginnings(&data).contains(&TimeOfDay::new(9, 0)));
+        }
+    );
+}
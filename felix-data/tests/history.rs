@@ -0,0 +1,332 @@
+//! Undo and redo of changes.
+//!
+//! Includes:
+//! - Undo and redo of entities, work hours and activities
+//! - Changes grouped into one step
+//! - Changes recorded again after a panic in a group
+//! - Insertions moving simultaneous and chained activities undone at once
+//! - Redo forgotten after a new change
+//! - Events emitted on undo and redo
+
+use felix_data::{Data, Duration, TimeInterval, TimeOfDay};
+use felix_test_utils::{test_ok, Activity, DataBuilder, Group};
+
+use std::cell::RefCell;
+use std::panic::AssertUnwindSafe;
+use std::rc::Rc;
+
+#[test]
+fn undo_redo_add_entity() {
+    test_ok!(data, DataBuilder::new(), {
+        assert!(!data.can_undo());
+        assert!(!data.undo(), "Could undo without any change");
+
+        let entity = data.add_entity("Entity").expect("Could not add entity");
+        assert!(data.can_undo());
+
+        assert!(data.undo());
+        assert!(data.entities_sorted().is_empty());
+        assert!(!data.can_undo());
+        assert!(data.can_redo());
+
+        assert!(data.redo());
+        assert_eq!(
+            data.entity(entity).expect("Entity was not redone").name(),
+            "Entity"
+        );
+        assert!(!data.can_redo());
+    });
+}
+
+#[test]
+fn undo_remove_work_interval() {
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    test_ok!(data, DataBuilder::new().with_work_interval(interval), {
+        data.remove_work_interval(interval)
+            .expect("Could not remove work interval");
+        assert!(data.work_hours().is_empty());
+
+        assert!(data.undo());
+        assert_eq!(data.work_hours(), vec![interval]);
+    });
+}
+
+#[test]
+fn undo_insert_activity() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval_of_duration(4)
+            .with_activity(Activity {
+                name: "Activity",
+                entities: vec![entity],
+                duration: Duration::new(1, 0),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.wait_for_possible_insertion_costs_computation(id);
+            data.insert_activity(id, Some(TimeOfDay::new(1, 0)))
+                .expect("Could not insert activity");
+
+            assert!(data.undo());
+            assert_eq!(data.activity(id).insertion_interval(), None);
+            assert_eq!(data.free_time_of(entity).unwrap(), Duration::new(3, 0));
+
+            assert!(data.redo());
+            assert_eq!(
+                data.activity(id).insertion_interval(),
+                Some(TimeInterval::new(
+                    TimeOfDay::new(1, 0),
+                    TimeOfDay::new(2, 0)
+                ))
+            );
+
+            data.wait_for_possible_insertion_costs_computation(id);
+            assert!(
+                data.activity(id).insertion_costs().is_some(),
+                "Insertion costs were not computed after redo"
+            );
+        }
+    );
+}
+
+#[test]
+fn undo_grouped_changes_at_once() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval_of_duration(4)
+            .with_activities(vec![
+                Activity {
+                    name: "Activity1",
+                    entities: vec![entity],
+                    insertion_time: Some(TimeOfDay::new(0, 0)),
+                    ..Default::default()
+                },
+                Activity {
+                    name: "Activity2",
+                    entities: vec![entity],
+                    insertion_time: Some(TimeOfDay::new(2, 0)),
+                    ..Default::default()
+                }
+            ]),
+        {
+            let before = data.clone();
+            data.record_as_single_step(|data| {
+                for activity in data.activities_not_sorted() {
+                    data.insert_activity(activity.id(), None)
+                        .expect("Could not remove activity from schedule");
+                }
+            });
+            assert!(data
+                .activities_not_sorted()
+                .iter()
+                .all(|activity| activity.insertion_interval().is_none()));
+
+            assert!(data.undo());
+            assert_eq!(data, before, "Grouped changes were not undone at once");
+        }
+    );
+}
+
+#[test]
+fn undo_add_group_to_activity_at_once() {
+    let (entity1, entity2) = ("Entity1", "Entity2");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entities(vec![entity1, entity2])
+            .with_group(Group {
+                name: "Group",
+                entities: vec![entity1, entity2],
+            })
+            .with_work_interval_of_duration(4)
+            .with_activity(Activity {
+                name: "Activity",
+                ..Default::default()
+            }),
+        {
+            let before = data.clone();
+            let id = data.activities_sorted()[0].id();
+            // Each member is added to the activity before the group itself
+            data.add_group_to_activity(id, "Group")
+                .expect("Could not add group to activity");
+
+            assert!(data.undo());
+            assert_eq!(data, before, "Adding a group was not undone at once");
+        }
+    );
+}
+
+#[test]
+fn history_records_changes_after_a_panic_in_a_group() {
+    test_ok!(data, DataBuilder::new(), {
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            data.record_as_single_step(|data| {
+                data.add_entity("Entity").expect("Could not add entity");
+                panic!("The changes failed");
+            })
+        }));
+        assert!(result.is_err());
+
+        data.add_entity("Other").expect("Could not add entity");
+        assert!(data.undo());
+        assert!(
+            data.entity("Other").is_err(),
+            "Changes were still grouped after a panic"
+        );
+    });
+}
+
+#[test]
+fn undo_insert_simultaneous_activities_at_once() {
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entities(vec!["Entity1", "Entity2"])
+            .with_work_interval_of_duration(4)
+            .with_activities(vec![
+                Activity {
+                    name: "Lunch1",
+                    entities: vec!["Entity1"],
+                    duration: Duration::new(1, 0),
+                    ..Default::default()
+                },
+                Activity {
+                    name: "Lunch2",
+                    entities: vec!["Entity2"],
+                    duration: Duration::new(0, 30),
+                    ..Default::default()
+                },
+            ])
+            .with_simultaneous_activities(vec!["Lunch1", "Lunch2"]),
+        {
+            data.clear_history();
+            let activities = data.activities_sorted();
+            let (id1, id2) = (activities[0].id(), activities[1].id());
+            data.wait_for_possible_insertion_costs_computation(id1);
+            data.insert_activity(id1, Some(TimeOfDay::new(1, 0)))
+                .expect("Could not insert activity");
+            assert!(data.activity(id2).insertion_interval().is_some());
+
+            assert!(data.undo());
+            assert_eq!(data.activity(id1).insertion_interval(), None);
+            assert_eq!(
+                data.activity(id2).insertion_interval(),
+                None,
+                "The simultaneous activity was not removed from the schedule at once"
+            );
+            assert!(
+                !data.can_undo(),
+                "The insertion was recorded as several changes"
+            );
+
+            assert!(data.redo());
+            assert_eq!(
+                data.activity(id2).insertion_interval(),
+                Some(TimeInterval::new(
+                    TimeOfDay::new(1, 0),
+                    TimeOfDay::new(1, 30)
+                ))
+            );
+        }
+    );
+}
+
+#[test]
+fn undo_move_chained_activities_at_once() {
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity("Entity")
+            .with_work_interval_of_duration(4)
+            .with_activities(vec![
+                Activity {
+                    name: "Briefing",
+                    entities: vec!["Entity"],
+                    duration: Duration::new(0, 30),
+                    ..Default::default()
+                },
+                Activity {
+                    name: "Field Trip",
+                    entities: vec!["Entity"],
+                    duration: Duration::new(2, 0),
+                    ..Default::default()
+                },
+            ])
+            .with_chained_activities("Briefing", "Field Trip", Duration::new(0, 15)),
+        {
+            data.clear_history();
+            let activities = data.activities_sorted();
+            let (briefing_id, field_trip_id) = (activities[0].id(), activities[1].id());
+            data.wait_for_possible_insertion_costs_computation(field_trip_id);
+            data.insert_activity(field_trip_id, Some(TimeOfDay::new(1, 0)))
+                .expect("Could not insert activity");
+            data.wait_for_possible_insertion_costs_computation(briefing_id);
+            data.insert_activity(briefing_id, Some(TimeOfDay::new(1, 0)))
+                .expect("Could not move activity");
+
+            assert!(data.undo());
+            assert_eq!(
+                data.activity(briefing_id).insertion_interval(),
+                Some(TimeInterval::new(
+                    TimeOfDay::new(0, 15),
+                    TimeOfDay::new(0, 45)
+                ))
+            );
+            assert_eq!(
+                data.activity(field_trip_id).insertion_interval(),
+                Some(TimeInterval::new(
+                    TimeOfDay::new(1, 0),
+                    TimeOfDay::new(3, 0)
+                )),
+                "The chain was not moved back at once"
+            );
+
+            assert!(data.undo());
+            assert_eq!(data.activity(briefing_id).insertion_interval(), None);
+            assert_eq!(data.activity(field_trip_id).insertion_interval(), None);
+            assert!(
+                !data.can_undo(),
+                "The insertion was recorded as several changes"
+            );
+        }
+    );
+}
+
+#[test]
+fn new_change_clears_redo() {
+    test_ok!(data, DataBuilder::new(), {
+        data.add_entity("Entity1").expect("Could not add entity");
+        assert!(data.undo());
+
+        data.add_entity("Entity2").expect("Could not add entity");
+        assert!(!data.can_redo());
+        assert!(!data.redo(), "Could redo a change after a new change");
+    });
+}
+
+#[test]
+fn undo_emits_data_restored() {
+    test_ok!(data, DataBuilder::new(), {
+        let restored = Rc::new(RefCell::new(0));
+        let restored_clone = restored.clone();
+        data.events()
+            .borrow_mut()
+            .connect_data_restored(Box::new(move |_data: &Data| {
+                *restored_clone.borrow_mut() += 1;
+            }));
+
+        data.add_entity("Entity").expect("Could not add entity");
+        data.undo();
+        data.redo();
+        assert_eq!(*restored.borrow(), 2);
+        assert!(data.can_undo(), "Restoring data was recorded as a change");
+        assert!(!data.can_redo(), "Restoring data was recorded as a change");
+    });
+}
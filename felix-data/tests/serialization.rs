@@ -0,0 +1,172 @@
+use felix_data::{Data, Duration, TimeInterval, TimeOfDay};
+use felix_test_utils::{test_ok, Activity, DataBuilder, Group};
+
+use serde::Deserialize;
+
+/// Data saved by a version which referenced entities and groups by name.
+const DATA_WITH_NAMES: &str = r#"{
+  "work_hours": {
+    "global_work_intervals": {
+      "work_intervals": [
+        { "beginning": { "hours": 8, "minutes": 0 }, "end": { "hours": 12, "minutes": 0 } }
+      ]
+    },
+    "custom_work_intervals": {
+      "Anna": {
+        "work_intervals": [
+          { "beginning": { "hours": 9, "minutes": 0 }, "end": { "hours": 12, "minutes": 0 } }
+        ]
+      },
+      "Paul": { "work_intervals": [] }
+    },
+    "group_work_intervals": {
+      "Band": {
+        "work_intervals": [
+          { "beginning": { "hours": 8, "minutes": 0 }, "end": { "hours": 11, "minutes": 0 } }
+        ]
+      }
+    }
+  },
+  "entities": {
+    "entities": {
+      "Paul": { "name": "Paul" },
+      "Anna": { "name": "Anna" }
+    }
+  },
+  "groups": {
+    "groups": {
+      "Band": { "name": "Band", "entities": ["Paul"] }
+    }
+  },
+  "activities": {
+    "activities": [
+      {
+        "metadata": {
+          "id": 0,
+          "name": "Rehearsal",
+          "entities": ["Anna", "Paul"],
+          "groups": ["Band"],
+          "display_color": { "red": 0.203, "green": 0.396, "blue": 0.643, "alpha": 1.0 }
+        },
+        "computation_data": {
+          "duration": { "hours": 1, "minutes": 0 },
+          "insertion_interval": null,
+          "incompatible_activity_ids": [],
+          "schedules_of_participants": []
+        }
+      }
+    ]
+  }
+}"#;
+
+#[test]
+fn load_data_with_names_migrates_references() {
+    let data = Data::from_json(DATA_WITH_NAMES).expect("Could not load data");
+
+    let entities = data
+        .entities_sorted()
+        .iter()
+        .map(|entity| entity.name())
+        .collect::<Vec<_>>();
+    assert_eq!(entities, vec!["Anna", "Paul"], "Entities were not loaded");
+    assert_eq!(
+        data.entities_of_group("Band").expect("Could not get group"),
+        vec!["Paul"],
+        "Members of the group were not migrated"
+    );
+
+    let id = data.activities_sorted()[0].id();
+    assert_eq!(
+        data.entities_of_activity(id),
+        vec!["Anna", "Paul"],
+        "Participants of the activity were not migrated"
+    );
+    assert_eq!(
+        data.groups_of_activity(id),
+        vec!["Band"],
+        "Groups of the activity were not migrated"
+    );
+
+    assert_eq!(
+        data.custom_work_hours_of("Anna")
+            .expect("Could not get entity"),
+        vec![TimeInterval::new(
+            TimeOfDay::new(9, 0),
+            TimeOfDay::new(12, 0)
+        )],
+        "Custom work hours were not migrated"
+    );
+    assert_eq!(
+        data.group_work_hours_of("Band")
+            .expect("Could not get group"),
+        vec![TimeInterval::new(
+            TimeOfDay::new(8, 0),
+            TimeOfDay::new(11, 0)
+        )],
+        "Work hours of the group were not migrated"
+    );
+}
+
+#[test]
+fn save_and_load_data_with_renamed_entity() {
+    let (entity, group) = ("Paul", "Band");
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(4)
+            .with_entity(entity)
+            .with_group(Group {
+                name: group,
+                entities: vec![entity],
+            })
+            .with_activity(Activity {
+                entities: vec![entity],
+                groups: vec![group],
+                duration: Duration::new(1, 0),
+                ..Default::default()
+            }),
+        {
+            let new_name = data
+                .set_entity_name(entity, "Jean")
+                .expect("Could not rename entity");
+            let serialized = serde_json::to_string(&data).expect("Could not save data");
+            let loaded: Data = serde_json::from_str(&serialized).expect("Could not load data");
+
+            assert_eq!(loaded, data, "Data changed when saved and loaded");
+            let id = loaded.activities_sorted()[0].id();
+            assert_eq!(loaded.entities_of_activity(id), vec![new_name.clone()]);
+            assert_eq!(
+                loaded
+                    .entities_of_group(group)
+                    .expect("Could not get group"),
+                vec![new_name]
+            );
+        }
+    );
+}
+
+#[test]
+fn load_saved_data_with_or_without_migration() {
+    let entity = "Paul";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(4)
+            .with_entity(entity)
+            .with_activity(Activity {
+                entities: vec![entity],
+                duration: Duration::new(1, 0),
+                ..Default::default()
+            }),
+        {
+            let serialized = serde_json::to_string(&data).expect("Could not save data");
+            let migrated = Data::from_json(&serialized).expect("Could not load data");
+            assert_eq!(migrated, data, "Data saved by this version was migrated");
+
+            // Deserialize reads the fields of data directly, without going through a JSON string
+            let value = serde_json::to_value(&data).expect("Could not save data");
+            let loaded = Data::deserialize(value).expect("Could not load data");
+            assert_eq!(loaded, data, "Data changed when saved and loaded");
+        }
+    );
+}
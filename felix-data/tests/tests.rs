@@ -6,15 +6,27 @@ extern crate assert_not_modified;
 
 mod activities {
     mod activities;
+    mod categories;
+    mod chains;
     mod entities_related;
+    mod flexible_durations;
     mod groups_related;
+    mod occurrences;
+    mod optional_participants;
+    mod sessions;
+    mod simultaneity;
+    mod templates;
+    mod transition_buffers;
     mod work_hours_related;
 }
 
 mod entities {
     mod activities_related;
+    mod details;
     mod entities;
     mod groups_related;
+    mod load_limits;
+    mod merge;
     mod work_hours_related;
 }
 
@@ -22,9 +34,15 @@ mod groups {
     mod activities_related;
     mod entities_related;
     mod groups;
+    mod subgroups;
+    mod work_hours_related;
 }
 
 mod time {
+    mod break_rules;
     mod custom_work_hours;
+    mod time_preferences;
+    mod unavailability;
+    mod work_hour_templates;
     mod work_hours;
 }
@@ -0,0 +1,271 @@
+use felix_data::{Duration, MandatoryBreak, TimeInterval, TimeOfDay};
+use felix_test_utils::{Activity, DataBuilder};
+
+fn lunch_break() -> MandatoryBreak {
+    MandatoryBreak::new(
+        TimeInterval::new(TimeOfDay::new(11, 30), TimeOfDay::new(14, 0)),
+        Duration::new(0, 45),
+    )
+}
+
+#[test]
+fn simple_set_max_continuous_work() {
+    test_ok!(data, DataBuilder::new(), {
+        data.set_max_continuous_work(Some(Duration::new(3, 0)))
+            .expect("Could not set max continuous work");
+        assert_eq!(
+            data.global_break_rules().max_continuous_work(),
+            Some(Duration::new(3, 0))
+        );
+
+        data.set_max_continuous_work(None)
+            .expect("Could not remove max continuous work");
+        assert!(data.global_break_rules().is_empty());
+    });
+}
+
+#[test]
+fn set_null_max_continuous_work() {
+    test_err!(
+        data,
+        DataBuilder::new(),
+        data.set_max_continuous_work(Some(Duration::new(0, 0))),
+        "The given duration is too short.",
+        "Could set null max continuous work"
+    );
+}
+
+#[test]
+fn simple_add_and_remove_mandatory_break() {
+    test_ok!(data, DataBuilder::new(), {
+        data.add_mandatory_break(lunch_break())
+            .expect("Could not add mandatory break");
+        assert_eq!(
+            data.global_break_rules().mandatory_breaks(),
+            &vec![lunch_break()]
+        );
+
+        data.remove_mandatory_break(lunch_break())
+            .expect("Could not remove mandatory break");
+        assert!(data.global_break_rules().mandatory_breaks().is_empty());
+    });
+}
+
+#[test]
+fn remove_nonexistent_mandatory_break() {
+    test_err!(
+        data,
+        DataBuilder::new(),
+        data.remove_mandatory_break(lunch_break()),
+        "The interval '11:30 - 14:00' does not exist.",
+        "Could remove nonexistent mandatory break"
+    );
+}
+
+#[test]
+fn custom_break_rules_combined_with_global_break_rules() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_max_continuous_work(Duration::new(3, 0))
+            .with_mandatory_break(lunch_break()),
+        {
+            data.set_max_continuous_work_for(entity, Some(Duration::new(2, 0)))
+                .expect("Could not set max continuous work for entity");
+
+            let break_rules = data
+                .break_rules_of(entity)
+                .expect("Could not get break rules of entity");
+            assert_eq!(break_rules.max_continuous_work(), Some(Duration::new(2, 0)));
+            assert_eq!(break_rules.mandatory_breaks(), &vec![lunch_break()]);
+        }
+    );
+}
+
+#[test]
+fn add_mandatory_break_for_nonexistent_entity() {
+    test_err!(
+        data,
+        DataBuilder::new(),
+        data.add_mandatory_break_for("Name", lunch_break()),
+        "Name does not exist.",
+        "Could add mandatory break for nonexistent entity"
+    );
+}
+
+#[test]
+fn change_break_rules_uninserts_activities_which_break_them() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(12, 0)
+            ))
+            .with_activity(Activity {
+                name: "First",
+                entities: vec![entity],
+                duration: Duration::new(1, 0),
+                insertion_time: Some(TimeOfDay::new(8, 0)),
+                ..Default::default()
+            })
+            .with_activity(Activity {
+                name: "Second",
+                entities: vec![entity],
+                duration: Duration::new(1, 0),
+                insertion_time: Some(TimeOfDay::new(9, 0)),
+                ..Default::default()
+            }),
+        {
+            data.set_max_continuous_work(Some(Duration::new(1, 0)))
+                .expect("Could not change break rules with inserted activities");
+            let activities = data.activities_sorted();
+            let (first_id, second_id) = (activities[0].id(), activities[1].id());
+            // Only one activity is removed from the schedule to give the entity a break
+            assert_eq!(data.activity(first_id).insertion_interval(), None);
+            assert!(data.activity(second_id).insertion_interval().is_some());
+
+            data.wait_for_possible_insertion_costs_computation(first_id);
+            data.insert_activities_removed_because_duration_increased_in_closest_spot();
+            let insertion_beginning = data
+                .activity(first_id)
+                .insertion_interval()
+                .expect("Activity was not reinserted in the schedule")
+                .beginning();
+            // The entity needs a break after the second activity
+            assert_eq!(insertion_beginning, TimeOfDay::new(10, 5));
+        }
+    );
+}
+
+#[test]
+fn activity_longer_than_max_continuous_work_cannot_be_inserted() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(12, 0)
+            ))
+            .with_max_continuous_work(Duration::new(1, 30))
+            .with_activity(Activity {
+                entities: vec![entity],
+                duration: Duration::new(2, 0),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.wait_for_possible_insertion_costs_computation(id);
+            assert_eq!(data.activity(id).insertion_costs(), Some(Vec::new()));
+        }
+    );
+}
+
+#[test]
+fn insert_activity_exceeding_max_continuous_work() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0)))
+            .with_max_continuous_work(Duration::new(2, 0))
+            .with_activities(vec![
+                Activity {
+                    name: "First",
+                    entities: vec![entity],
+                    duration: Duration::new(1, 30),
+                    insertion_time: Some(TimeOfDay::new(8, 0)),
+                    ..Default::default()
+                },
+                Activity {
+                    name: "Second",
+                    entities: vec![entity],
+                    duration: Duration::new(1, 0),
+                    ..Default::default()
+                }
+            ]),
+        {
+            let id = data.activities_sorted()[1].id();
+            data.wait_for_possible_insertion_costs_computation(id);
+            let insertion_costs = data
+                .activity(id)
+                .insertion_costs()
+                .expect("Insertion costs were not computed");
+            assert!(insertion_costs
+                .iter()
+                .any(|insertion_cost| insertion_cost.beginning == TimeOfDay::new(9, 35)));
+            data.insert_activity(id, Some(TimeOfDay::new(9, 30)))
+        },
+        "Second cannot be inserted with beginning 09:30 because Entity would not have the breaks they need.",
+        "Could insert activity exceeding max continuous work"
+    );
+}
+
+#[test]
+fn insert_activity_without_mandatory_break() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(16, 0)))
+            .with_mandatory_break(lunch_break())
+            .with_activity(Activity {
+                name: "Activity",
+                entities: vec![entity],
+                duration: Duration::new(2, 0),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.wait_for_possible_insertion_costs_computation(id);
+            data.insert_activity(id, Some(TimeOfDay::new(11, 30)))
+        },
+        "Activity cannot be inserted with beginning 11:30 because Entity would not have the breaks they need.",
+        "Could insert activity during a mandatory break"
+    );
+}
+
+#[test]
+fn add_entity_to_inserted_activity_without_breaks() {
+    let (entity1, entity2) = ("Entity1", "Entity2");
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_entities(vec![entity1, entity2])
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(12, 0)
+            ))
+            .with_max_continuous_work_for(entity1, Duration::new(2, 0))
+            .with_activities(vec![
+                Activity {
+                    name: "First",
+                    entities: vec![entity1],
+                    duration: Duration::new(2, 0),
+                    insertion_time: Some(TimeOfDay::new(8, 0)),
+                    ..Default::default()
+                },
+                Activity {
+                    name: "Second",
+                    entities: vec![entity2],
+                    duration: Duration::new(1, 0),
+                    insertion_time: Some(TimeOfDay::new(10, 0)),
+                    ..Default::default()
+                }
+            ]),
+        {
+            let id = data.activities_sorted()[1].id();
+            data.add_entity_to_activity(id, entity1)
+        },
+        "Entity1 cannot be added to 'Second' because they would not have the breaks they need.",
+        "Could add entity to inserted activity without respecting their break rules"
+    );
+}
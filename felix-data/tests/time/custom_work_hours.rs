@@ -1,10 +1,10 @@
-use felix_data::{Time, TimeInterval};
+use felix_data::{Duration, TimeInterval, TimeOfDay};
 use felix_test_utils::{Activity, DataBuilder};
 
 #[test]
 fn simple_add_custom_work_interval() {
     let entity = "Entity";
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     test_ok!(data, DataBuilder::new().with_entity(entity), {
         data.add_custom_work_interval_for(entity, interval)
             .expect("Could not add custom work interval");
@@ -27,7 +27,7 @@ fn simple_add_custom_work_interval() {
 
 #[test]
 fn add_custom_work_interval_nonexistent_entity() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     test_err!(
         data,
         DataBuilder::new(),
@@ -40,8 +40,8 @@ fn add_custom_work_interval_nonexistent_entity() {
 #[test]
 fn add_overlapping_custom_work_interval() {
     let entity = "Entity";
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
-    let overlap = TimeInterval::new(Time::new(8, 0), Time::new(9, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    let overlap = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(9, 0));
     test_err!(
         data,
         DataBuilder::new()
@@ -62,13 +62,13 @@ fn add_custom_work_interval_not_enough_free_time() {
             .with_entity(entity)
             .with_work_interval_of_duration(4)
             .with_activity(Activity {
-                duration: Time::new(4, 0),
+                duration: Duration::new(4, 0),
                 entities: vec![entity],
                 ..Default::default()
             }),
         {
-            let custom_interval_too_short = TimeInterval::new(Time::new(8, 0), Time::new(10, 0));
-            assert!(data.activities_sorted()[0].entities_sorted()[0] == entity);
+            let custom_interval_too_short = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(10, 0));
+            assert!(data.entities_of_activity(data.activities_sorted()[0].id())[0] == entity);
             data.add_custom_work_interval_for(entity, custom_interval_too_short)
         },
         "Entity will not have enough time if their work hours are shortened.",
@@ -80,8 +80,8 @@ fn add_custom_work_interval_not_enough_free_time() {
 #[test]
 fn add_custom_adjacent_work_interval() {
     let entity = "Entity";
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
-    let adjacent = TimeInterval::new(Time::new(12, 0), Time::new(14, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    let adjacent = TimeInterval::new(TimeOfDay::new(12, 0), TimeOfDay::new(14, 0));
     test_ok!(
         data,
         DataBuilder::new()
@@ -97,9 +97,9 @@ fn add_custom_adjacent_work_interval() {
 #[test]
 fn check_custom_work_intervals_sorted() {
     let entity = "Entity";
-    let interval1 = TimeInterval::new(Time::new(8, 0), Time::new(10, 0));
-    let interval2 = TimeInterval::new(Time::new(11, 0), Time::new(12, 0));
-    let interval3 = TimeInterval::new(Time::new(14, 0), Time::new(17, 0));
+    let interval1 = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(10, 0));
+    let interval2 = TimeInterval::new(TimeOfDay::new(11, 0), TimeOfDay::new(12, 0));
+    let interval3 = TimeInterval::new(TimeOfDay::new(14, 0), TimeOfDay::new(17, 0));
     test_ok!(
         data,
         DataBuilder::new()
@@ -119,8 +119,8 @@ fn check_custom_work_intervals_sorted() {
 #[test]
 fn simple_remove_custom_work_interval() {
     let entity = "Entity";
-    let interval1 = TimeInterval::new(Time::new(8, 0), Time::new(10, 0));
-    let interval2 = TimeInterval::new(Time::new(11, 0), Time::new(12, 0));
+    let interval1 = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(10, 0));
+    let interval2 = TimeInterval::new(TimeOfDay::new(11, 0), TimeOfDay::new(12, 0));
     test_ok!(
         data,
         DataBuilder::new()
@@ -143,7 +143,7 @@ fn simple_remove_custom_work_interval() {
 
 #[test]
 fn remove_custom_work_interval_nonexistent_entity() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     test_err!(
         data,
         DataBuilder::new(),
@@ -156,7 +156,7 @@ fn remove_custom_work_interval_nonexistent_entity() {
 #[test]
 fn remove_custom_work_interval_not_enough_free_time() {
     let entity = "Entity";
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     test_err!(
         data,
         DataBuilder::new()
@@ -164,7 +164,7 @@ fn remove_custom_work_interval_not_enough_free_time() {
             .with_custom_work_interval_for(entity, interval)
             .with_activity(Activity {
                 entities: vec![entity],
-                duration: Time::new(3, 0),
+                duration: Duration::new(3, 0),
                 ..Default::default()
             }),
         data.remove_custom_work_interval_for(entity, interval),
@@ -175,13 +175,13 @@ fn remove_custom_work_interval_not_enough_free_time() {
 
 #[test]
 fn remove_custom_work_interval_enough_time_with_global_work_hours() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     let custom_interval = interval;
     let entity = "Entity";
     test_ok!(data, DataBuilder::new().with_entity(entity)
              .with_work_interval(interval)
              .with_custom_work_interval_for(entity, custom_interval)
-             .with_activity( Activity { entities: vec![entity], duration: Time::new(3, 0),  ..Default::default() }),
+             .with_activity( Activity { entities: vec![entity], duration: Duration::new(3, 0),  ..Default::default() }),
     data
     .remove_custom_work_interval_for(entity, custom_interval)
     .expect("Could not remove time interval even though the entity will have enough time with the global work hours"));
@@ -189,7 +189,7 @@ fn remove_custom_work_interval_enough_time_with_global_work_hours() {
 
 #[test]
 fn remove_nonexistent_custom_work_interval() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     let entity = "Entity";
     test_err!(
         data,
@@ -202,7 +202,7 @@ fn remove_nonexistent_custom_work_interval() {
 
 #[test]
 fn remove_custom_work_interval_beginning_different() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     let entity = "Entity";
     test_err!(
         data,
@@ -210,7 +210,7 @@ fn remove_custom_work_interval_beginning_different() {
             .with_entity(entity)
             .with_custom_work_interval_for(entity, interval),
         {
-            let same_end = TimeInterval::new(Time::new(9, 0), Time::new(12, 0));
+            let same_end = TimeInterval::new(TimeOfDay::new(9, 0), TimeOfDay::new(12, 0));
             data.remove_custom_work_interval_for(entity, same_end)
         },
         "The interval '09:00 - 12:00' does not exist.",
@@ -220,7 +220,7 @@ fn remove_custom_work_interval_beginning_different() {
 
 #[test]
 fn remove_custom_work_interval_end_different() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     let entity = "Entity";
     test_err!(
         data,
@@ -228,7 +228,7 @@ fn remove_custom_work_interval_end_different() {
             .with_entity(entity)
             .with_custom_work_interval_for(entity, interval),
         {
-            let same_beginning = TimeInterval::new(Time::new(8, 0), Time::new(11, 0));
+            let same_beginning = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(11, 0));
             data.remove_custom_work_interval_for(entity, same_beginning)
         },
         "The interval '08:00 - 11:00' does not exist.",
@@ -238,7 +238,7 @@ fn remove_custom_work_interval_end_different() {
 
 #[test]
 fn simple_update_custom_interval() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     let entity = "Entity";
     test_ok!(
         data,
@@ -246,7 +246,7 @@ fn simple_update_custom_interval() {
             .with_entity(entity)
             .with_custom_work_interval_for(entity, interval),
         {
-            let new_interval = TimeInterval::new(Time::new(8, 0), Time::new(11, 0));
+            let new_interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(11, 0));
             data.update_custom_work_interval_for(entity, interval, new_interval)
                 .expect("Could not update time interval");
 
@@ -260,7 +260,7 @@ fn simple_update_custom_interval() {
 
 #[test]
 fn update_nonexistent_custom_interval() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     let entity = "Entity";
     test_err!(
         data,
@@ -273,7 +273,7 @@ fn update_nonexistent_custom_interval() {
 
 #[test]
 fn update_custom_interval_nonexistent_entity() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     test_err!(
         data,
         DataBuilder::new(),
@@ -285,7 +285,7 @@ fn update_custom_interval_nonexistent_entity() {
 
 #[test]
 fn update_custom_time_interval_not_enough_time_for_activities() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     let entity = "Entity";
     test_err!(
         data,
@@ -294,11 +294,11 @@ fn update_custom_time_interval_not_enough_time_for_activities() {
             .with_custom_work_interval_for(entity, interval)
             .with_activity(Activity {
                 entities: vec![entity],
-                duration: Time::new(4, 0),
+                duration: Duration::new(4, 0),
                 ..Default::default()
             }),
         {
-            let new_interval_too_short = TimeInterval::new(Time::new(9, 0), Time::new(12, 0));
+            let new_interval_too_short = TimeInterval::new(TimeOfDay::new(9, 0), TimeOfDay::new(12, 0));
             data.update_custom_work_interval_for(entity, interval, new_interval_too_short)
         },
         "Entity will not have enough time if their work hours are shortened.",
@@ -308,8 +308,8 @@ fn update_custom_time_interval_not_enough_time_for_activities() {
 
 #[test]
 fn update_custom_time_interval_overlaps() {
-    let interval1 = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
-    let interval2 = TimeInterval::new(Time::new(13, 0), Time::new(15, 0));
+    let interval1 = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    let interval2 = TimeInterval::new(TimeOfDay::new(13, 0), TimeOfDay::new(15, 0));
     let entity = "Entity";
     test_err!(
         data,
@@ -317,7 +317,7 @@ fn update_custom_time_interval_overlaps() {
             .with_entity(entity)
             .with_custom_work_intervals_for(entity, vec![interval1, interval2]),
         {
-            let new_interval_overlaps = TimeInterval::new(Time::new(10, 0), Time::new(15, 0));
+            let new_interval_overlaps = TimeInterval::new(TimeOfDay::new(10, 0), TimeOfDay::new(15, 0));
             data.update_custom_work_interval_for(entity, interval1, new_interval_overlaps)
         },
         "The given interval overlaps with others.",
@@ -327,8 +327,8 @@ fn update_custom_time_interval_overlaps() {
 
 #[test]
 fn update_custom_time_interval_check_sorted() {
-    let interval1 = TimeInterval::new(Time::new(8, 0), Time::new(10, 0));
-    let interval2 = TimeInterval::new(Time::new(11, 0), Time::new(12, 0));
+    let interval1 = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(10, 0));
+    let interval2 = TimeInterval::new(TimeOfDay::new(11, 0), TimeOfDay::new(12, 0));
     let entity = "Entity";
     test_ok!(
         data,
@@ -336,7 +336,7 @@ fn update_custom_time_interval_check_sorted() {
             .with_entity(entity)
             .with_custom_work_intervals_for(entity, vec![interval1, interval2]),
         {
-            let interval3 = TimeInterval::new(Time::new(14, 0), Time::new(15, 0));
+            let interval3 = TimeInterval::new(TimeOfDay::new(14, 0), TimeOfDay::new(15, 0));
 
             data.update_custom_work_interval_for(entity, interval1, interval3)
                 .expect("Could not add custom interval");
@@ -353,7 +353,7 @@ fn update_custom_time_interval_check_sorted() {
 // *** Get work hours of entity ***
 #[test]
 fn work_hours_of_without_custom_work_hours() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     let entity = "Entity";
     test_ok!(
         data,
@@ -376,7 +376,7 @@ fn work_hours_of_without_custom_work_hours() {
 
 #[test]
 fn work_hours_of_with_custom_work_hours() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     let entity = "Entity";
     test_ok!(
         data,
@@ -402,8 +402,8 @@ fn work_hours_of_with_custom_work_hours() {
 // *** Free time ***
 #[test]
 fn free_time_without_activities_with_global_work_hours() {
-    let interval1 = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
-    let interval2 = TimeInterval::new(Time::new(13, 0), Time::new(18, 0));
+    let interval1 = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    let interval2 = TimeInterval::new(TimeOfDay::new(13, 0), TimeOfDay::new(18, 0));
     let entity = "Entity";
     test_ok!(
         data,
@@ -427,8 +427,8 @@ fn free_time_without_activities_with_global_work_hours() {
 #[test]
 fn free_time_without_activities_with_custom_work_hours() {
     let entity = "Entity";
-    let interval1 = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
-    let interval2 = TimeInterval::new(Time::new(13, 0), Time::new(18, 0));
+    let interval1 = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    let interval2 = TimeInterval::new(TimeOfDay::new(13, 0), TimeOfDay::new(18, 0));
     test_ok!(
         data,
         DataBuilder::new()
@@ -451,8 +451,8 @@ fn free_time_without_activities_with_custom_work_hours() {
 #[test]
 fn free_time_with_activities_with_global_work_hours() {
     let entity = "Entity";
-    let interval1 = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
-    let interval2 = TimeInterval::new(Time::new(13, 0), Time::new(18, 0));
+    let interval1 = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    let interval2 = TimeInterval::new(TimeOfDay::new(13, 0), TimeOfDay::new(18, 0));
     test_ok!(
         data,
         DataBuilder::new()
@@ -460,12 +460,12 @@ fn free_time_with_activities_with_global_work_hours() {
             .with_work_intervals(vec![interval1, interval2])
             .with_activities(vec![
                 Activity {
-                    duration: Time::new(1, 0),
+                    duration: Duration::new(1, 0),
                     entities: vec![entity],
                     ..Default::default()
                 },
                 Activity {
-                    duration: Time::new(1, 30),
+                    duration: Duration::new(1, 30),
                     entities: vec![entity],
                     ..Default::default()
                 }
@@ -488,8 +488,8 @@ fn free_time_with_activities_with_global_work_hours() {
 
 #[test]
 fn free_time_with_activities_with_custom_work_hours() {
-    let interval1 = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
-    let interval2 = TimeInterval::new(Time::new(13, 0), Time::new(18, 0));
+    let interval1 = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    let interval2 = TimeInterval::new(TimeOfDay::new(13, 0), TimeOfDay::new(18, 0));
     let entity = "Entity";
     test_ok!(
         data,
@@ -498,12 +498,12 @@ fn free_time_with_activities_with_custom_work_hours() {
             .with_custom_work_intervals_for(entity, vec![interval1, interval2])
             .with_activities(vec![
                 Activity {
-                    duration: Time::new(1, 0),
+                    duration: Duration::new(1, 0),
                     entities: vec![entity],
                     ..Default::default()
                 },
                 Activity {
-                    duration: Time::new(1, 30),
+                    duration: Duration::new(1, 30),
                     entities: vec![entity],
                     ..Default::default()
                 }
@@ -0,0 +1,191 @@
+use felix_data::{Duration, TimeInterval, TimeOfDay};
+use felix_test_utils::{Activity, DataBuilder};
+
+#[test]
+fn simple_add_preferred_and_disliked_intervals() {
+    let entity = "Entity";
+    let preferred = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(10, 0));
+    let disliked = TimeInterval::new(TimeOfDay::new(12, 0), TimeOfDay::new(14, 0));
+    test_ok!(data, DataBuilder::new().with_entity(entity), {
+        data.add_preferred_interval_for(entity, preferred)
+            .expect("Could not add preferred interval");
+        data.add_disliked_interval_for(entity, disliked)
+            .expect("Could not add disliked interval");
+
+        assert_eq!(
+            data.preferred_intervals_of(entity)
+                .expect("Could not get entity by name"),
+            vec![preferred],
+            "Preferred interval was not added right"
+        );
+        assert_eq!(
+            data.disliked_intervals_of(entity)
+                .expect("Could not get entity by name"),
+            vec![disliked],
+            "Disliked interval was not added right"
+        );
+    });
+}
+
+#[test]
+fn add_preferred_interval_nonexistent_entity() {
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    test_err!(
+        data,
+        DataBuilder::new(),
+        data.add_preferred_interval_for("Name", interval),
+        "Name does not exist.",
+        "Could add preferred interval for nonexistent entity"
+    );
+}
+
+#[test]
+fn add_disliked_interval_overlapping_preferred_interval() {
+    let entity = "Entity";
+    let preferred = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(10, 0));
+    let overlap = TimeInterval::new(TimeOfDay::new(9, 0), TimeOfDay::new(11, 0));
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_preferred_interval_for(entity, preferred),
+        data.add_disliked_interval_for(entity, overlap),
+        "The given interval overlaps with others.",
+        "Could add disliked interval overlapping with a preferred interval"
+    );
+}
+
+#[test]
+fn remove_preferred_interval() {
+    let entity = "Entity";
+    let preferred = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(10, 0));
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_preferred_interval_for(entity, preferred),
+        {
+            data.remove_preferred_interval_for(entity, preferred)
+                .expect("Could not remove preferred interval");
+            assert!(data
+                .preferred_intervals_of(entity)
+                .expect("Could not get entity by name")
+                .is_empty());
+        }
+    );
+}
+
+#[test]
+fn remove_nonexistent_disliked_interval() {
+    let entity = "Entity";
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(10, 0));
+    test_err!(
+        data,
+        DataBuilder::new().with_entity(entity),
+        data.remove_disliked_interval_for(entity, interval),
+        "The interval '08:00 - 10:00' does not exist.",
+        "Could remove nonexistent disliked interval"
+    );
+}
+
+#[test]
+fn rename_entity_check_preferences_kept() {
+    let entity = "Entity";
+    let new_name = "New Name";
+    let preferred = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(10, 0));
+    test_ok!(data, DataBuilder::new().with_entity(entity), {
+        data.add_preferred_interval_for(entity, preferred)
+            .expect("Could not add preferred interval");
+        data.set_entity_name(entity, new_name)
+            .expect("Could not rename entity");
+        assert_eq!(
+            data.preferred_intervals_of(new_name)
+                .expect("Could not get entity by name"),
+            vec![preferred]
+        );
+    });
+}
+
+#[test]
+fn remove_entity_check_preferences_removed() {
+    let entity = "Entity";
+    let disliked = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(10, 0));
+    test_ok!(data, DataBuilder::new().with_entity(entity), {
+        data.add_disliked_interval_for(entity, disliked)
+            .expect("Could not add disliked interval");
+        data.remove_entity(entity).expect("Could not remove entity");
+        data.add_entity(entity).expect("Could not add entity");
+        assert!(data
+            .disliked_intervals_of(entity)
+            .expect("Could not get entity by name")
+            .is_empty());
+    });
+}
+
+#[test]
+fn change_preferences_while_activity_inserted() {
+    let entity = "Entity";
+    let preferred = TimeInterval::new(TimeOfDay::new(10, 0), TimeOfDay::new(12, 0));
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(12, 0)
+            ))
+            .with_activity(Activity {
+                entities: vec![entity],
+                duration: Duration::new(1, 0),
+                insertion_time: Some(TimeOfDay::new(8, 0)),
+                ..Default::default()
+            }),
+        {
+            data.add_preferred_interval_for(entity, preferred)
+                .expect("Could not add preferred interval while an activity is inserted");
+        }
+    );
+}
+
+#[test]
+fn preferences_change_insertion_costs() {
+    let entity = "Entity";
+    let disliked = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(10, 0));
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_interval(TimeInterval::new(
+                TimeOfDay::new(8, 0),
+                TimeOfDay::new(12, 0)
+            ))
+            .with_activity(Activity {
+                entities: vec![entity],
+                duration: Duration::new(1, 0),
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.add_disliked_interval_for(entity, disliked)
+                .expect("Could not add disliked interval");
+            data.wait_for_possible_insertion_costs_computation(id);
+
+            let insertion_costs = data
+                .activity(id)
+                .insertion_costs()
+                .expect("Insertion costs were not computed");
+            let cost_at = |beginning: TimeOfDay| {
+                insertion_costs
+                    .iter()
+                    .find(|insertion_cost| insertion_cost.beginning == beginning)
+                    .expect("Beginning is not a possible insertion time")
+                    .cost
+            };
+
+            // Disliked slots are still possible but more costly
+            assert!(cost_at(TimeOfDay::new(8, 0)) > cost_at(TimeOfDay::new(9, 30)));
+            assert!(cost_at(TimeOfDay::new(9, 30)) > cost_at(TimeOfDay::new(10, 0)));
+            assert_eq!(cost_at(TimeOfDay::new(10, 0)), 0);
+        }
+    );
+}
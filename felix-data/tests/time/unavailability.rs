@@ -0,0 +1,373 @@
+//! Intervals during which an entity is unavailable.
+//!
+//! Includes:
+//! - Addition, removal and update of unavailable intervals
+//! - Effective work hours of entities with global and custom work hours
+//! - Free time checks
+//! - Renaming and removal of entities
+
+use felix_data::{Duration, TimeInterval, TimeOfDay};
+use felix_test_utils::{Activity, DataBuilder};
+
+fn day() -> TimeInterval {
+    TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(18, 0))
+}
+
+fn absence() -> TimeInterval {
+    TimeInterval::new(TimeOfDay::new(10, 0), TimeOfDay::new(11, 0))
+}
+
+#[test]
+fn simple_add_unavailable_interval() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new().with_work_interval(day()).with_entity(entity),
+        {
+            data.add_unavailable_interval_for(entity, absence())
+                .expect("Could not add unavailable interval");
+            assert_eq!(
+                data.unavailable_intervals_of(entity).unwrap(),
+                vec![absence()]
+            );
+            assert_eq!(
+                data.work_hours_of(entity).unwrap(),
+                vec![
+                    TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(10, 0)),
+                    TimeInterval::new(TimeOfDay::new(11, 0), TimeOfDay::new(18, 0))
+                ]
+            );
+            assert_eq!(data.free_time_of(entity).unwrap(), Duration::new(9, 0));
+            // Global work hours are not modified
+            assert_eq!(data.work_hours(), vec![day()]);
+        }
+    );
+}
+
+#[test]
+fn unavailable_interval_removed_from_custom_work_hours() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_custom_work_interval_for(
+                entity,
+                TimeInterval::new(TimeOfDay::new(9, 0), TimeOfDay::new(12, 0))
+            ),
+        {
+            data.add_unavailable_interval_for(
+                entity,
+                TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(10, 0)),
+            )
+            .expect("Could not add unavailable interval");
+            assert_eq!(
+                data.work_hours_of(entity).unwrap(),
+                vec![TimeInterval::new(TimeOfDay::new(10, 0), TimeOfDay::new(12, 0))]
+            );
+        }
+    );
+}
+
+#[test]
+fn unavailable_interval_covering_work_interval() {
+    let entity = "Entity";
+    let morning = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    let afternoon = TimeInterval::new(TimeOfDay::new(14, 0), TimeOfDay::new(18, 0));
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_intervals(vec![morning, afternoon])
+            .with_entity(entity),
+        {
+            data.add_unavailable_interval_for(
+                entity,
+                TimeInterval::new(TimeOfDay::new(7, 0), TimeOfDay::new(13, 0)),
+            )
+            .expect("Could not add unavailable interval");
+            assert_eq!(data.work_hours_of(entity).unwrap(), vec![afternoon]);
+        }
+    );
+}
+
+#[test]
+fn add_unavailable_interval_nonexistent_entity() {
+    test_err!(
+        data,
+        DataBuilder::new(),
+        data.add_unavailable_interval_for("Name", absence()),
+        "Name does not exist.",
+        "Could add unavailable interval for nonexistent entity"
+    );
+}
+
+#[test]
+fn add_overlapping_unavailable_interval() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_unavailable_interval_for(entity, absence()),
+        data.add_unavailable_interval_for(
+            entity,
+            TimeInterval::new(TimeOfDay::new(10, 30), TimeOfDay::new(12, 0))
+        ),
+        "The given interval overlaps with others.",
+        "Could add overlapping unavailable interval"
+    );
+}
+
+#[test]
+fn add_unavailable_interval_not_enough_free_time() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(4)
+            .with_entity(entity)
+            .with_activity(Activity {
+                duration: Duration::new(3, 30),
+                entities: vec![entity],
+                ..Default::default()
+            }),
+        data.add_unavailable_interval_for(
+            entity,
+            TimeInterval::new(TimeOfDay::new(0, 0), TimeOfDay::new(1, 0))
+        ),
+        "Entity will not have enough time if their work hours are shortened.",
+        "Could add unavailable interval which led to entity not having enough time"
+    );
+}
+
+#[test]
+fn add_unavailable_interval_activity_inserted() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(4)
+            .with_entity(entity)
+            .with_activity(Activity {
+                duration: Duration::new(1, 0),
+                entities: vec![entity],
+                insertion_time: Some(TimeOfDay::new(0, 0)),
+                ..Default::default()
+            }),
+        {
+            data.add_unavailable_interval_for(
+                entity,
+                TimeInterval::new(TimeOfDay::new(2, 0), TimeOfDay::new(3, 0)),
+            )
+            .expect("Could not add unavailable interval while an activity is inserted");
+            let id = data.activities_sorted()[0].id();
+            assert!(data.activity(id).insertion_interval().is_some());
+        }
+    );
+}
+
+#[test]
+fn add_unavailable_interval_during_inserted_activity() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(4)
+            .with_entity(entity)
+            .with_activity(Activity {
+                duration: Duration::new(1, 0),
+                entities: vec![entity],
+                insertion_time: Some(TimeOfDay::new(0, 0)),
+                ..Default::default()
+            }),
+        {
+            data.add_unavailable_interval_for(
+                entity,
+                TimeInterval::new(TimeOfDay::new(0, 30), TimeOfDay::new(1, 0)),
+            )
+            .expect("Could not add unavailable interval during an inserted activity");
+            let id = data.activities_sorted()[0].id();
+            assert_eq!(data.activity(id).insertion_interval(), None);
+
+            data.wait_for_possible_insertion_costs_computation(id);
+            data.insert_activities_removed_because_duration_increased_in_closest_spot();
+            let insertion_beginning = data
+                .activity(id)
+                .insertion_interval()
+                .expect("Activity was not reinserted in the schedule")
+                .beginning();
+            assert_eq!(insertion_beginning, TimeOfDay::new(1, 0));
+        }
+    );
+}
+
+#[test]
+fn simple_remove_unavailable_interval() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_unavailable_interval_for(entity, absence()),
+        {
+            data.remove_unavailable_interval_for(entity, absence())
+                .expect("Could not remove unavailable interval");
+            assert!(data.unavailable_intervals_of(entity).unwrap().is_empty());
+            assert_eq!(data.work_hours_of(entity).unwrap(), vec![day()]);
+        }
+    );
+}
+
+#[test]
+fn remove_nonexistent_unavailable_interval() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new().with_entity(entity),
+        data.remove_unavailable_interval_for(entity, absence()),
+        "The interval '10:00 - 11:00' does not exist.",
+        "Could remove nonexistent unavailable interval"
+    );
+}
+
+#[test]
+fn simple_update_unavailable_interval() {
+    let entity = "Entity";
+    let new_absence = TimeInterval::new(TimeOfDay::new(15, 0), TimeOfDay::new(17, 0));
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_unavailable_interval_for(entity, absence()),
+        {
+            data.update_unavailable_interval_for(entity, absence(), new_absence)
+                .expect("Could not update unavailable interval");
+            assert_eq!(
+                data.unavailable_intervals_of(entity).unwrap(),
+                vec![new_absence]
+            );
+            assert_eq!(data.free_time_of(entity).unwrap(), Duration::new(8, 0));
+        }
+    );
+}
+
+#[test]
+fn update_unavailable_interval_not_enough_free_time() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(4)
+            .with_entity(entity)
+            .with_unavailable_interval_for(
+                entity,
+                TimeInterval::new(TimeOfDay::new(0, 0), TimeOfDay::new(0, 30))
+            )
+            .with_activity(Activity {
+                duration: Duration::new(3, 0),
+                entities: vec![entity],
+                ..Default::default()
+            }),
+        data.update_unavailable_interval_for(
+            entity,
+            TimeInterval::new(TimeOfDay::new(0, 0), TimeOfDay::new(0, 30)),
+            TimeInterval::new(TimeOfDay::new(0, 0), TimeOfDay::new(1, 30))
+        ),
+        "Entity will not have enough time if their work hours are shortened.",
+        "Could update unavailable interval which led to entity not having enough time"
+    );
+}
+
+#[test]
+fn add_custom_work_interval_covered_by_unavailability() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_unavailable_interval_for(entity, absence())
+            .with_activity(Activity {
+                duration: Duration::new(1, 0),
+                entities: vec![entity],
+                ..Default::default()
+            }),
+        data.add_custom_work_interval_for(entity, absence()),
+        "Entity will not have enough time if their work hours are shortened.",
+        "Could add custom work interval during which the entity is unavailable"
+    );
+}
+
+#[test]
+fn unavailable_intervals_follow_renamed_entity() {
+    let entity = "Entity";
+    let new_name = "New Name";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_unavailable_interval_for(entity, absence()),
+        {
+            data.set_entity_name(entity, new_name)
+                .expect("Could not rename entity");
+            assert_eq!(
+                data.unavailable_intervals_of(new_name).unwrap(),
+                vec![absence()]
+            );
+        }
+    );
+}
+
+#[test]
+fn unavailable_intervals_removed_with_entity() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_unavailable_interval_for(entity, absence()),
+        {
+            data.remove_entity(entity).expect("Could not remove entity");
+            data.add_entity(entity).expect("Could not add entity");
+            assert!(data.unavailable_intervals_of(entity).unwrap().is_empty());
+        }
+    );
+}
+
+#[test]
+fn activity_cannot_be_inserted_during_unavailability() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0)))
+            .with_entity(entity)
+            .with_unavailable_interval_for(entity, absence())
+            .with_activity(Activity {
+                duration: Duration::new(1, 0),
+                entities: vec![entity],
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            data.wait_for_possible_insertion_costs_computation(id);
+            let beginnings = data
+                .activity(id)
+                .insertion_costs()
+                .expect("Insertion costs were not computed")
+                .iter()
+                .map(|insertion_cost| insertion_cost.beginning)
+                .collect::<Vec<_>>();
+            assert!(beginnings.contains(&TimeOfDay::new(9, 0)));
+            assert!(beginnings.contains(&TimeOfDay::new(11, 0)));
+            assert!(!beginnings.contains(&TimeOfDay::new(9, 30)));
+            assert!(!beginnings.contains(&TimeOfDay::new(10, 0)));
+        }
+    );
+}
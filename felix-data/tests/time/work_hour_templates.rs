@@ -0,0 +1,351 @@
+//! Named work hour templates shared by entities.
+//!
+//! Includes:
+//! - Addition, renaming and removal of templates
+//! - Addition, removal and update of template intervals
+//! - Assignment of templates to entities
+//! - Effective work hours of entities with a template
+//! - Free time checks
+//! - Update of insertion costs when a template changes
+
+use felix_data::{Duration, TimeInterval, TimeOfDay};
+use felix_test_utils::{Activity, DataBuilder};
+
+fn early_shift() -> TimeInterval {
+    TimeInterval::new(TimeOfDay::new(6, 0), TimeOfDay::new(10, 0))
+}
+
+fn day() -> TimeInterval {
+    TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(18, 0))
+}
+
+#[test]
+fn simple_add_work_hour_template() {
+    test_ok!(data, DataBuilder::new(), {
+        let name = data
+            .add_work_hour_template("early shift")
+            .expect("Could not add work hour template");
+        assert_eq!(name, "Early Shift");
+        assert_eq!(data.work_hour_templates(), vec!["Early Shift"]);
+        assert!(data.work_hour_template(&name).unwrap().is_empty());
+    });
+}
+
+#[test]
+fn add_work_hour_template_name_taken() {
+    test_err!(
+        data,
+        DataBuilder::new().with_work_hour_template("Early Shift", vec![]),
+        data.add_work_hour_template("Early Shift"),
+        "The name 'Early Shift' is already taken by a work hour template.",
+        "Could add work hour template with taken name"
+    );
+}
+
+#[test]
+fn add_work_hour_template_interval_nonexistent_template() {
+    test_err!(
+        data,
+        DataBuilder::new(),
+        data.add_work_hour_template_interval("Early Shift", early_shift()),
+        "The work hour template 'Early Shift' does not exist.",
+        "Could add interval to nonexistent work hour template"
+    );
+}
+
+#[test]
+fn add_overlapping_work_hour_template_interval() {
+    test_err!(
+        data,
+        DataBuilder::new().with_work_hour_template("Early Shift", vec![early_shift()]),
+        data.add_work_hour_template_interval(
+            "Early Shift",
+            TimeInterval::new(TimeOfDay::new(9, 0), TimeOfDay::new(11, 0))
+        ),
+        "The given interval overlaps with others.",
+        "Could add overlapping interval to work hour template"
+    );
+}
+
+#[test]
+fn entity_with_template_works_during_template_hours() {
+    let entity = "Entity";
+    let template = "Early Shift";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_work_hour_template(template, vec![early_shift()]),
+        {
+            data.set_work_hour_template_of(entity, Some(template))
+                .expect("Could not assign work hour template");
+            assert_eq!(data.work_hours_of(entity).unwrap(), vec![early_shift()]);
+
+            data.set_work_hour_template_of::<_, String>(entity, None)
+                .expect("Could not unassign work hour template");
+            assert_eq!(data.work_hour_template_of(entity).unwrap(), None);
+            assert_eq!(data.work_hours_of(entity).unwrap(), vec![day()]);
+        }
+    );
+}
+
+#[test]
+fn custom_work_hours_take_precedence_over_template() {
+    let entity = "Entity";
+    let template = "Early Shift";
+    let custom_interval = TimeInterval::new(TimeOfDay::new(12, 0), TimeOfDay::new(14, 0));
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_work_hour_template(template, vec![early_shift()])
+            .with_work_hour_template_for(entity, template)
+            .with_custom_work_interval_for(entity, custom_interval),
+        {
+            assert_eq!(data.work_hours_of(entity).unwrap(), vec![custom_interval]);
+        }
+    );
+}
+
+#[test]
+fn assign_nonexistent_work_hour_template() {
+    let entity = "Entity";
+    test_err!(
+        data,
+        DataBuilder::new().with_entity(entity),
+        data.set_work_hour_template_of(entity, Some("Early Shift")),
+        "The work hour template 'Early Shift' does not exist.",
+        "Could assign nonexistent work hour template"
+    );
+}
+
+#[test]
+fn assign_work_hour_template_not_enough_free_time() {
+    let entity = "Entity";
+    let template = "Early Shift";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_work_hour_template(template, vec![early_shift()])
+            .with_activity(Activity {
+                duration: Duration::new(5, 0),
+                entities: vec![entity],
+                ..Default::default()
+            }),
+        data.set_work_hour_template_of(entity, Some(template)),
+        "Entity will not have enough time if their work hours are shortened.",
+        "Could assign work hour template which led to entity not having enough time"
+    );
+}
+
+#[test]
+fn editing_template_updates_every_assigned_entity() {
+    let (entity1, entity2) = ("Entity1", "Entity2");
+    let template = "Early Shift";
+    let new_interval = TimeInterval::new(TimeOfDay::new(6, 0), TimeOfDay::new(12, 0));
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entities(vec![entity1, entity2])
+            .with_work_hour_template(template, vec![early_shift()])
+            .with_work_hour_template_for(entity1, template)
+            .with_work_hour_template_for(entity2, template),
+        {
+            data.update_work_hour_template_interval(template, early_shift(), new_interval)
+                .expect("Could not update work hour template interval");
+            assert_eq!(data.work_hours_of(entity1).unwrap(), vec![new_interval]);
+            assert_eq!(data.work_hours_of(entity2).unwrap(), vec![new_interval]);
+        }
+    );
+}
+
+#[test]
+fn editing_template_updates_insertion_costs() {
+    let entity = "Entity";
+    let template = "Early Shift";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_work_hour_template(template, vec![early_shift()])
+            .with_work_hour_template_for(entity, template)
+            .with_activity(Activity {
+                duration: Duration::new(1, 0),
+                entities: vec![entity],
+                ..Default::default()
+            }),
+        {
+            let id = data.activities_sorted()[0].id();
+            let beginnings = |data: &felix_data::Data| {
+                data.wait_for_possible_insertion_costs_computation(id);
+                data.activity(id)
+                    .insertion_costs()
+                    .expect("Insertion costs were not computed")
+                    .iter()
+                    .map(|insertion_cost| insertion_cost.beginning)
+                    .collect::<Vec<_>>()
+            };
+            assert!(beginnings(&data).contains(&TimeOfDay::new(6, 0)));
+            assert!(!beginnings(&data).contains(&TimeOfDay::new(15, 0)));
+
+            data.add_work_hour_template_interval(
+                template,
+                TimeInterval::new(TimeOfDay::new(15, 0), TimeOfDay::new(17, 0)),
+            )
+            .expect("Could not add work hour template interval");
+            assert!(beginnings(&data).contains(&TimeOfDay::new(15, 0)));
+        }
+    );
+}
+
+#[test]
+fn remove_work_hour_template_interval_not_enough_free_time() {
+    let entity = "Entity";
+    let template = "Split Shift";
+    let afternoon = TimeInterval::new(TimeOfDay::new(14, 0), TimeOfDay::new(16, 0));
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_hour_template(template, vec![early_shift(), afternoon])
+            .with_work_hour_template_for(entity, template)
+            .with_activity(Activity {
+                duration: Duration::new(5, 0),
+                entities: vec![entity],
+                ..Default::default()
+            }),
+        data.remove_work_hour_template_interval(template, afternoon),
+        "Entity will not have enough time if their work hours are shortened.",
+        "Could remove work hour template interval which led to entity not having enough time"
+    );
+}
+
+#[test]
+fn remove_nonexistent_work_hour_template_interval() {
+    test_err!(
+        data,
+        DataBuilder::new().with_work_hour_template("Early Shift", vec![]),
+        data.remove_work_hour_template_interval("Early Shift", early_shift()),
+        "The interval '06:00 - 10:00' does not exist.",
+        "Could remove nonexistent work hour template interval"
+    );
+}
+
+#[test]
+fn edit_work_hour_template_activity_inserted() {
+    let entity = "Entity";
+    let template = "Early Shift";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_hour_template(template, vec![early_shift()])
+            .with_work_hour_template_for(entity, template)
+            .with_activity(Activity {
+                duration: Duration::new(1, 0),
+                entities: vec![entity],
+                insertion_time: Some(TimeOfDay::new(9, 0)),
+                ..Default::default()
+            }),
+        {
+            data.update_work_hour_template_interval(
+                template,
+                early_shift(),
+                TimeInterval::new(TimeOfDay::new(6, 0), TimeOfDay::new(9, 0)),
+            )
+            .expect("Could not edit work hour template while an activity is inserted");
+            let id = data.activities_sorted()[0].id();
+            assert_eq!(data.activity(id).insertion_interval(), None);
+        }
+    );
+}
+
+#[test]
+fn rename_work_hour_template() {
+    let entity = "Entity";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_hour_template("Early Shift", vec![early_shift()])
+            .with_work_hour_template_for(entity, "Early Shift"),
+        {
+            let new_name = data
+                .set_work_hour_template_name("Early Shift", "morning shift")
+                .expect("Could not rename work hour template");
+            assert_eq!(new_name, "Morning Shift");
+            assert_eq!(data.work_hour_templates(), vec![new_name.clone()]);
+            assert_eq!(data.work_hour_template_of(entity).unwrap(), Some(new_name));
+        }
+    );
+}
+
+#[test]
+fn remove_work_hour_template() {
+    let entity = "Entity";
+    let template = "Early Shift";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(day())
+            .with_entity(entity)
+            .with_work_hour_template(template, vec![early_shift()])
+            .with_work_hour_template_for(entity, template),
+        {
+            data.remove_work_hour_template(template)
+                .expect("Could not remove work hour template");
+            assert!(data.work_hour_templates().is_empty());
+            assert_eq!(data.work_hour_template_of(entity).unwrap(), None);
+            assert_eq!(data.work_hours_of(entity).unwrap(), vec![day()]);
+        }
+    );
+}
+
+#[test]
+fn remove_work_hour_template_not_enough_free_time() {
+    let entity = "Entity";
+    let template = "Early Shift";
+    test_err!(
+        data,
+        DataBuilder::new()
+            .with_work_interval_of_duration(2)
+            .with_entity(entity)
+            .with_work_hour_template(template, vec![early_shift()])
+            .with_work_hour_template_for(entity, template)
+            .with_activity(Activity {
+                duration: Duration::new(3, 0),
+                entities: vec![entity],
+                ..Default::default()
+            }),
+        data.remove_work_hour_template(template),
+        "Entity will not have enough time if their work hours are shortened.",
+        "Could remove work hour template which led to entity not having enough time"
+    );
+}
+
+#[test]
+fn template_follows_renamed_entity() {
+    let entity = "Entity";
+    let template = "Early Shift";
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_entity(entity)
+            .with_work_hour_template(template, vec![early_shift()])
+            .with_work_hour_template_for(entity, template),
+        {
+            data.set_entity_name(entity, "New Name")
+                .expect("Could not rename entity");
+            assert_eq!(
+                data.work_hour_template_of("New Name").unwrap(),
+                Some(template.to_owned())
+            );
+        }
+    );
+}
@@ -1,9 +1,9 @@
-use felix_data::{TimeInterval, Time};
+use felix_data::{Duration, TimeInterval, TimeOfDay};
 use felix_test_utils::{Activity, DataBuilder};
 
 #[test]
 fn simple_add_interval() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     test_ok!(data, DataBuilder::new(), {
         data.add_work_interval(interval)
             .expect("Could not add simple work interval");
@@ -17,8 +17,8 @@ fn simple_add_interval() {
 
 #[test]
 fn add_overlapping_interval() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
-    let overlap = TimeInterval::new(Time::new(8, 0), Time::new(9, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    let overlap = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(9, 0));
     test_err!(
         data,
         DataBuilder::new().with_work_interval(interval),
@@ -31,8 +31,8 @@ fn add_overlapping_interval() {
 /// It should be possible to add adjacent work intervals (end == beginning of other)
 #[test]
 fn add_adjacent_interval() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
-    let adjacent = TimeInterval::new(Time::new(12, 0), Time::new(14, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    let adjacent = TimeInterval::new(TimeOfDay::new(12, 0), TimeOfDay::new(14, 0));
     test_ok!(
         data,
         DataBuilder::new().with_work_interval(interval),
@@ -43,9 +43,9 @@ fn add_adjacent_interval() {
 
 #[test]
 fn add_interals_check_sorted() {
-    let interval1 = TimeInterval::new(Time::new(8, 0), Time::new(10, 0));
-    let interval2 = TimeInterval::new(Time::new(11, 0), Time::new(12, 0));
-    let interval3 = TimeInterval::new(Time::new(14, 0), Time::new(17, 0));
+    let interval1 = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(10, 0));
+    let interval2 = TimeInterval::new(TimeOfDay::new(11, 0), TimeOfDay::new(12, 0));
+    let interval3 = TimeInterval::new(TimeOfDay::new(14, 0), TimeOfDay::new(17, 0));
 
     test_ok!(
         data,
@@ -62,8 +62,8 @@ fn add_interals_check_sorted() {
 #[test]
 fn simple_remove_interval() {
     // Add two intervals to check later that we removed the right one
-    let interval1 = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
-    let interval2 = TimeInterval::new(Time::new(17, 0), Time::new(18, 0));
+    let interval1 = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    let interval2 = TimeInterval::new(TimeOfDay::new(17, 0), TimeOfDay::new(18, 0));
     test_ok!(
         data,
         DataBuilder::new().with_work_intervals(vec![interval1, interval2]),
@@ -79,7 +79,7 @@ fn simple_remove_interval() {
 
 #[test]
 fn remove_nonexistent_time_interval() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     test_err!(
         data,
         DataBuilder::new(),
@@ -91,8 +91,8 @@ fn remove_nonexistent_time_interval() {
 
 #[test]
 fn remove_interval_wrong_beginning() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
-    let same_end = TimeInterval::new(Time::new(9, 0), Time::new(12, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    let same_end = TimeInterval::new(TimeOfDay::new(9, 0), TimeOfDay::new(12, 0));
     test_err!(
         data,
         DataBuilder::new().with_work_interval(interval),
@@ -104,8 +104,8 @@ fn remove_interval_wrong_beginning() {
 
 #[test]
 fn remove_interval_wrong_end() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
-    let same_beginning = TimeInterval::new(Time::new(8, 0), Time::new(11, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    let same_beginning = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(11, 0));
     test_err!(
         data,
         DataBuilder::new().with_work_interval(interval),
@@ -119,7 +119,7 @@ fn remove_interval_wrong_end() {
 /// than the time their activities take
 #[test]
 fn remove_time_interval_not_enough_time_for_activities() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     let entity = "Entity";
     test_err!(
         data,
@@ -128,7 +128,7 @@ fn remove_time_interval_not_enough_time_for_activities() {
             .with_entity(entity)
             .with_activity(Activity {
                 entities: vec![entity],
-                duration: Time::new(1, 0),
+                duration: Duration::new(1, 0),
                 ..Default::default()
             }),
         data.remove_work_interval(interval),
@@ -139,8 +139,8 @@ fn remove_time_interval_not_enough_time_for_activities() {
 
 #[test]
 fn simple_update_interval() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
-    let new_interval = TimeInterval::new(Time::new(8, 0), Time::new(11, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    let new_interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(11, 0));
     test_ok!(data, DataBuilder::new().with_work_interval(interval), {
         data.update_work_interval(interval, new_interval)
             .expect("Could not update time interval");
@@ -151,7 +151,7 @@ fn simple_update_interval() {
 
 #[test]
 fn update_nonexistent_interval() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     test_err!(
         data,
         DataBuilder::new(),
@@ -163,7 +163,7 @@ fn update_nonexistent_interval() {
 
 #[test]
 fn update_time_interval_not_enough_time_for_activities() {
-    let interval = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
+    let interval = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
     let entity = "Entity";
     test_err!(
         data,
@@ -171,12 +171,12 @@ fn update_time_interval_not_enough_time_for_activities() {
             .with_work_interval(interval)
             .with_entity(entity)
             .with_activity(Activity {
-                duration: Time::new(4, 0),
+                duration: Duration::new(4, 0),
                 entities: vec![entity],
                 ..Default::default()
             }),
         {
-            let new_interval_too_short = TimeInterval::new(Time::new(9, 0), Time::new(12, 0));
+            let new_interval_too_short = TimeInterval::new(TimeOfDay::new(9, 0), TimeOfDay::new(12, 0));
             data.update_work_interval(interval, new_interval_too_short)
         },
         "Entity will not have enough time if their work hours are shortened.",
@@ -186,9 +186,9 @@ fn update_time_interval_not_enough_time_for_activities() {
 
 #[test]
 fn update_time_interval_overlaps() {
-    let interval1 = TimeInterval::new(Time::new(8, 0), Time::new(12, 0));
-    let interval2 = TimeInterval::new(Time::new(13, 0), Time::new(15, 0));
-    let new_interval_overlaps = TimeInterval::new(Time::new(10, 0), Time::new(15, 0));
+    let interval1 = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(12, 0));
+    let interval2 = TimeInterval::new(TimeOfDay::new(13, 0), TimeOfDay::new(15, 0));
+    let new_interval_overlaps = TimeInterval::new(TimeOfDay::new(10, 0), TimeOfDay::new(15, 0));
     test_err!(
         data,
         DataBuilder::new().with_work_intervals(vec![interval1, interval2]),
@@ -200,9 +200,9 @@ fn update_time_interval_overlaps() {
 
 #[test]
 fn update_time_interval_check_sorted() {
-    let interval1 = TimeInterval::new(Time::new(8, 0), Time::new(10, 0));
-    let interval2 = TimeInterval::new(Time::new(11, 0), Time::new(12, 0));
-    let interval3 = TimeInterval::new(Time::new(14, 0), Time::new(15, 0));
+    let interval1 = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(10, 0));
+    let interval2 = TimeInterval::new(TimeOfDay::new(11, 0), TimeOfDay::new(12, 0));
+    let interval3 = TimeInterval::new(TimeOfDay::new(14, 0), TimeOfDay::new(15, 0));
 
     test_ok!(
         data,
@@ -218,75 +218,170 @@ fn update_time_interval_check_sorted() {
     );
 }
 
-/// Tests that work hours cannot be added while activities are inserted
+/// Tests that work hours can be added while activities are inserted
 #[test]
 fn add_work_hours_with_inserted_activities() {
     let entity = "Jean";
-    let work_interval1 = TimeInterval::new(Time::new(8, 0), Time::new(11, 0));
-    let work_interval2 = TimeInterval::new(Time::new(14, 0), Time::new(16, 0));
-    test_err!(
+    let work_interval1 = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(11, 0));
+    let work_interval2 = TimeInterval::new(TimeOfDay::new(14, 0), TimeOfDay::new(16, 0));
+    test_ok!(
         data,
         DataBuilder::new()
             .with_work_interval(work_interval1)
             .with_entity(entity)
             .with_activity(Activity {
                 entities: vec![entity],
-                insertion_time: Some(Time::new(8, 0)),
+                insertion_time: Some(TimeOfDay::new(8, 0)),
                 ..Default::default()
             }),
         {
             data.add_work_interval(work_interval2)
-        },
-        "Work hours cannot be modified while an activity is inserted.",
-        "Could add work hours with one inserted activity"
+                .expect("Could not add work hours with one inserted activity");
+            let id = data.activities_sorted()[0].id();
+            assert!(data.activity(id).insertion_interval().is_some());
+        }
     );
 }
 
-/// Tests that work hours cannot be removed while activities are inserted
+/// Tests that work hours can be removed while activities are inserted
 #[test]
 fn remove_work_hours_with_inserted_activities() {
     let entity = "Jean";
-    let work_interval1 = TimeInterval::new(Time::new(8, 0), Time::new(11, 0));
-    let work_interval2 = TimeInterval::new(Time::new(14, 0), Time::new(16, 0));
-    test_err!(
+    let work_interval1 = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(11, 0));
+    let work_interval2 = TimeInterval::new(TimeOfDay::new(14, 0), TimeOfDay::new(16, 0));
+    test_ok!(
         data,
         DataBuilder::new()
             .with_work_intervals(vec![work_interval1, work_interval2])
             .with_entity(entity)
             .with_activity(Activity {
                 entities: vec![entity],
-                insertion_time: Some(Time::new(8, 0)),
+                insertion_time: Some(TimeOfDay::new(8, 0)),
                 ..Default::default()
             }),
         {
             data.remove_work_interval(work_interval2)
-        },
-        "Work hours cannot be modified while an activity is inserted.",
-        "Could remove  work hours with one inserted activity"
+                .expect("Could not remove work hours with one inserted activity");
+            // The activity is not in the removed interval
+            let id = data.activities_sorted()[0].id();
+            assert!(data.activity(id).insertion_interval().is_some());
+        }
     );
 }
 
-/// Tests that work hours cannot be updated while activities are inserted
+/// Tests that work hours can be updated while activities are inserted
 #[test]
 fn update_work_hours_with_inserted_activities() {
     let entity = "Jean";
-    let work_interval1 = TimeInterval::new(Time::new(8, 0), Time::new(11, 0));
-    let work_interval2 = TimeInterval::new(Time::new(14, 0), Time::new(16, 0));
-    test_err!(
+    let work_interval1 = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(11, 0));
+    let work_interval2 = TimeInterval::new(TimeOfDay::new(14, 0), TimeOfDay::new(16, 0));
+    test_ok!(
         data,
         DataBuilder::new()
             .with_work_intervals(vec![work_interval1, work_interval2])
             .with_entity(entity)
             .with_activity(Activity {
                 entities: vec![entity],
-                insertion_time: Some(Time::new(8, 0)),
+                insertion_time: Some(TimeOfDay::new(8, 0)),
                 ..Default::default()
             }),
         {
-            let new_work_interval = TimeInterval::new(Time::new(14, 0), Time::new(15, 0));
+            let new_work_interval = TimeInterval::new(TimeOfDay::new(14, 0), TimeOfDay::new(15, 0));
             data.update_work_interval(work_interval2, new_work_interval)
-        },
-        "Work hours cannot be modified while an activity is inserted.",
-        "Could update work hours with one inserted activity"
+                .expect("Could not update work hours with one inserted activity");
+            let id = data.activities_sorted()[0].id();
+            assert!(data.activity(id).insertion_interval().is_some());
+        }
+    );
+}
+
+/// Tests that activities which no longer fit in the work hours are removed from the schedule,
+/// then inserted back in the closest spot
+#[test]
+fn remove_work_hours_uninserts_activities_which_no_longer_fit() {
+    let entity = "Jean";
+    let work_interval1 = TimeInterval::new(TimeOfDay::new(8, 0), TimeOfDay::new(11, 0));
+    let work_interval2 = TimeInterval::new(TimeOfDay::new(14, 0), TimeOfDay::new(16, 0));
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_intervals(vec![work_interval1, work_interval2])
+            .with_entity(entity)
+            .with_activity(Activity {
+                name: "Inserted in the morning",
+                entities: vec![entity],
+                duration: Duration::new(1, 0),
+                insertion_time: Some(TimeOfDay::new(8, 0)),
+                ..Default::default()
+            })
+            .with_activity(Activity {
+                name: "Inserted in the afternoon",
+                entities: vec![entity],
+                duration: Duration::new(1, 0),
+                insertion_time: Some(TimeOfDay::new(14, 0)),
+                ..Default::default()
+            }),
+        {
+            data.remove_work_interval(work_interval2)
+                .expect("Could not remove work hours with inserted activities");
+            let activities = data.activities_sorted();
+            let (afternoon_id, morning_id) = (activities[0].id(), activities[1].id());
+            // Only the activity which does not fit anymore is removed from the schedule
+            assert!(data.activity(morning_id).insertion_interval().is_some());
+            assert_eq!(data.activity(afternoon_id).insertion_interval(), None);
+
+            data.wait_for_possible_insertion_costs_computation(afternoon_id);
+            data.insert_activities_removed_because_duration_increased_in_closest_spot();
+            let insertion_beginning = data
+                .activity(afternoon_id)
+                .insertion_interval()
+                .expect("Activity was not reinserted in the schedule")
+                .beginning();
+            assert_eq!(insertion_beginning, TimeOfDay::new(10, 0));
+        }
+    );
+}
+
+#[test]
+fn activity_inserted_across_midnight() {
+    let entity = "Entity";
+    let night_shift = TimeInterval::overnight(TimeOfDay::new(22, 0), TimeOfDay::new(2, 0));
+    test_ok!(
+        data,
+        DataBuilder::new()
+            .with_work_interval(night_shift)
+            .with_entity(entity)
+            .with_activity(Activity {
+                entities: vec![entity],
+                duration: Duration::new(2, 0),
+                ..Default::default()
+            }),
+        {
+            assert_eq!(data.work_hours_of(entity).unwrap(), vec![night_shift]);
+            assert_eq!(data.free_time_of(entity).unwrap(), Duration::new(2, 0));
+
+            let id = data.activities_sorted()[0].id();
+            data.wait_for_possible_insertion_costs_computation(id);
+            let beginnings = data
+                .activity(id)
+                .insertion_costs()
+                .expect("Insertion costs were not computed")
+                .iter()
+                .map(|insertion_cost| insertion_cost.beginning)
+                .collect::<Vec<_>>();
+            assert!(beginnings.contains(&TimeOfDay::new(22, 0)));
+            assert!(beginnings.contains(&TimeOfDay::new(23, 30)));
+            assert!(beginnings.contains(&TimeOfDay::new(24, 0)));
+            assert!(!beginnings.contains(&TimeOfDay::new(24, 5)));
+
+            data.insert_activity(id, Some(TimeOfDay::new(23, 30)))
+                .expect("Could not insert activity across midnight");
+            let insertion_interval = data
+                .activity(id)
+                .insertion_interval()
+                .expect("Activity was not inserted");
+            assert!(insertion_interval.crosses_midnight());
+            assert_eq!(insertion_interval.end(), TimeOfDay::new(25, 30));
+        }
     );
 }
@@ -20,6 +20,14 @@ impl App {
             }),
         ));
 
+        events.connect_activities_added(Box::new(
+            clone!(@strong self.ui as ui => move |data, activities| {
+                let mut ui = ui.borrow_mut();
+                ui.on_activities_added(data, activities);
+                ui.stop_autoinsertion_if_running();
+            }),
+        ));
+
         events.connect_activity_removed(Box::new(
             clone!(@strong self.ui as ui => move |data, position| {
                 let mut ui = ui.borrow_mut();
@@ -43,6 +51,19 @@ impl App {
         let app = self.clone();
         let polling_duration_counter = Rc::new(RefCell::new(0));
         events.connect_activity_duration_changed(Box::new(
+            clone!(@strong self.ui as ui, @strong polling_duration_counter => move |data, activity| {
+                let mut ui = ui.borrow_mut();
+                ui.on_activity_changed(data, activity);
+                ui.update_schedules(data);
+                ui.stop_autoinsertion_if_running();
+                app.on_activity_duration_changed_start_polling_to_insert_it_again(
+                    data, polling_duration_counter.clone());
+            }),
+        ));
+
+        // Increasing the transition buffer may also remove the activity from the schedule
+        let app = self.clone();
+        events.connect_activity_transition_buffer_changed(Box::new(
             clone!(@strong self.ui as ui => move |data, activity| {
                 let mut ui = ui.borrow_mut();
                 ui.on_activity_changed(data, activity);
@@ -53,6 +74,33 @@ impl App {
             }),
         ));
 
+        events.connect_activity_sessions_changed(Box::new(
+            clone!(@strong self.ui as ui => move |data, activity| {
+                let mut ui = ui.borrow_mut();
+                ui.on_activity_changed(data, activity);
+                ui.update_schedules(data);
+                ui.stop_autoinsertion_if_running();
+            }),
+        ));
+
+        events.connect_simultaneous_activities_changed(Box::new(
+            clone!(@strong self.ui as ui => move |data, activity| {
+                let mut ui = ui.borrow_mut();
+                ui.on_activity_changed(data, activity);
+                ui.update_schedules(data);
+                ui.stop_autoinsertion_if_running();
+            }),
+        ));
+
+        events.connect_activity_chain_changed(Box::new(
+            clone!(@strong self.ui as ui => move |data, activity| {
+                let mut ui = ui.borrow_mut();
+                ui.on_activity_changed(data, activity);
+                ui.update_schedules(data);
+                ui.stop_autoinsertion_if_running();
+            }),
+        ));
+
         events.connect_activity_inserted(Box::new(
             clone!(@strong self.ui as ui => move |data, activity| {
                 let mut ui = ui.borrow_mut();
@@ -70,6 +118,13 @@ impl App {
             }),
         ));
 
+        events.connect_activity_category_changed(Box::new(
+            clone!(@strong self.ui as ui => move |data, activity| {
+                let mut ui = ui.borrow_mut();
+                ui.on_activity_changed(data, activity);
+            }),
+        ));
+
         events.connect_entity_added_to_activity(Box::new(
             clone!(@strong self.ui as ui => move |data, activity| {
                 let mut ui = ui.borrow_mut();
@@ -110,7 +165,7 @@ impl App {
             let mut ui = ui.borrow_mut();
             ui.update_schedules(data);
             ui.refresh_current_activity_view(data);
-            ui.update_activities_treeview(data.activities_sorted());
+            ui.update_activities_treeview(data);
 
             if data.activities_sorted()
                 .iter()
@@ -121,7 +176,7 @@ impl App {
         })));
     }
 
-    fn on_activity_duration_changed_start_polling_to_insert_it_again(
+    pub(super) fn on_activity_duration_changed_start_polling_to_insert_it_again(
         &self,
         data: &Data,
         polling_duration_counter: Rc<RefCell<u32>>,
@@ -0,0 +1,16 @@
+use crate::app::App;
+
+use glib::clone;
+
+impl App {
+    pub(in super::super) fn connect_batch_events(&self) {
+        let events = self.data.borrow().events();
+        let mut events = events.borrow_mut();
+
+        events.connect_batch_applied(Box::new(
+            clone!(@strong self.ui as ui => move |data, _events| {
+                ui.borrow_mut().refresh_every_view(data);
+            }),
+        ));
+    }
+}
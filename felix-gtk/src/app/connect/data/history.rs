@@ -0,0 +1,14 @@
+use crate::app::App;
+
+use glib::clone;
+
+impl App {
+    pub(in super::super) fn connect_history_events(&self) {
+        let events = self.data.borrow().events();
+        let mut events = events.borrow_mut();
+
+        events.connect_data_restored(Box::new(clone!(@strong self.ui as ui => move |data| {
+            ui.borrow_mut().refresh_every_view(data);
+        })));
+    }
+}
@@ -1,4 +1,6 @@
 mod activities;
+mod batch;
 mod entities;
 mod groups;
+mod history;
 mod work_hours;
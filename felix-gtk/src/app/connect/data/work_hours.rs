@@ -1,12 +1,18 @@
 use crate::app::App;
 
 use glib::clone;
+use std::cell::RefCell;
+use std::rc::Rc;
 
 impl App {
     pub(in super::super) fn connect_work_hour_events(&self) {
         let events = self.data.borrow().events();
         let mut events = events.borrow_mut();
 
+        // Activities which no longer fit in the work hours are removed from the schedule.
+        // Poll to insert them again in the closest spot.
+        let app = self.clone();
+        let polling_duration_counter = Rc::new(RefCell::new(0));
         events.connect_work_hours_changed(Box::new(clone!(@strong self.ui as ui => move |data| {
             let mut ui = ui.borrow_mut();
             ui.on_work_hours_changed(data);
@@ -17,6 +23,15 @@ impl App {
             }
 
             ui.update_schedules(data);
+            app.on_activity_duration_changed_start_polling_to_insert_it_again(
+                data, polling_duration_counter.clone());
         })));
+
+        events.connect_time_preferences_changed(Box::new(
+            clone!(@strong self.ui as ui => move |data, _entity| {
+                // Insertion costs changed
+                ui.borrow_mut().update_schedules(data);
+            }),
+        ));
     }
 }
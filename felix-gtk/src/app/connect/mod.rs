@@ -14,6 +14,7 @@ impl App {
         self.connect_activity_insertion();
         self.connect_notifications();
         self.connect_export();
+        self.connect_undo_redo();
     }
 
     pub fn connect_data(&mut self) {
@@ -21,5 +22,7 @@ impl App {
         self.connect_group_events();
         self.connect_activity_events();
         self.connect_work_hour_events();
+        self.connect_history_events();
+        self.connect_batch_events();
     }
 }
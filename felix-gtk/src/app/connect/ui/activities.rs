@@ -10,7 +10,7 @@ use crate::app::{
 };
 
 use felix_data::errors::does_not_exist::DoesNotExist;
-use felix_data::{clean_string, ActivityId, Rgba, Time};
+use felix_data::{clean_string, ActivityId, Duration, Rgba};
 
 use std::convert::TryFrom;
 
@@ -26,6 +26,7 @@ impl App {
         self.connect_set_activity_color();
 
         self.connect_activity_selected();
+        self.connect_filter_activities();
 
         self.connect_autoinsert();
 
@@ -107,6 +108,22 @@ impl App {
         );
     }
 
+    fn connect_filter_activities(&self) {
+        fetch_from!(self.ui.borrow(), activities_filter_entry);
+
+        let data = self.data.clone();
+        let ui = self.ui.clone();
+        app_register_signal!(
+            self,
+            activities_filter_entry,
+            activities_filter_entry.connect_search_changed(move |entry| {
+                let filter = entry.get_text().trim().to_string();
+                ui.borrow_mut()
+                    .on_activities_filter_changed(&data.borrow(), filter);
+            })
+        );
+    }
+
     fn connect_remove_activity(&self) {
         fetch_from!(self.ui.borrow(), activity_remove_button);
 
@@ -170,7 +187,8 @@ impl App {
                     .activity(id)
                     .duration();
 
-                let new_duration = wrap_duration(activity_duration, Time::new(hours, minutes));
+                let (hours, minutes) = wrap_duration(activity_duration.minutes(), (hours, minutes));
+                let new_duration = Duration::new(hours, minutes);
                 let set_duration_result = data.set_activity_duration(id, new_duration);
 
                 if let Err(e) = set_duration_result {
@@ -343,8 +361,7 @@ impl App {
                 .expect("Current activity should be set before performing any action on a group").id();
 
             let mut data = data.borrow_mut();
-            let activity = data.activity(current_activity_id);
-            let activity_entities = activity.entities_sorted();
+            let activity_entities = data.entities_of_activity(current_activity_id);
 
             if activity_entities.contains(&entity_to_remove.to_owned()) {
                 return_if_err!(ui, data.remove_entity_from_activity(current_activity_id, entity_to_remove));
@@ -3,7 +3,7 @@ use crate::app::{
 };
 
 use felix_data::errors::does_not_exist::DoesNotExist;
-use felix_data::{clean_string, ActivityId, Time, MIN_TIME_DISCRETIZATION};
+use felix_data::{clean_string, ActivityId, TimeOfDay, MIN_TIME_DISCRETIZATION};
 
 use std::convert::TryFrom;
 use std::rc::Rc;
@@ -44,8 +44,8 @@ impl App {
                 let data = $data.borrow();
                 if let Ok(entity) = data.entity(&entity_or_group_to_show) {
                     ui.on_show_entity_schedule(EntityToShow::new(entity.name(), &data));
-                } else if let Ok(group) = data.group(&entity_or_group_to_show) {
-                    for entity_name in group.entities_sorted() {
+                } else if let Ok(entity_names) = data.members_of_group(&entity_or_group_to_show) {
+                    for entity_name in entity_names {
                         ui.on_show_entity_schedule(EntityToShow::new(entity_name, &data));
                     }
                 } else {
@@ -90,16 +90,19 @@ impl App {
             self,
             clear_activities_button,
             clear_activities_button.connect_clicked(move |_| {
-                let mut data = data.borrow_mut();
-                for id in data
-                    .activities_not_sorted()
-                    .iter()
-                    .map(|activity| activity.id())
-                {
-                    // TODO faster way (remove all activities from schedule at once)
-                    // We don't care if the activity is already out of the schedule
-                    let _ = data.insert_activity(id, None);
-                }
+                // Clear the schedule at once : possible beginnings are computed once
+                // and clearing the schedule is undone at once
+                let _ = data.borrow_mut().batch(|data| {
+                    for id in data
+                        .activities_not_sorted()
+                        .iter()
+                        .map(|activity| activity.id())
+                    {
+                        // We don't care if the activity is already out of the schedule
+                        let _ = data.insert_activity(id, None);
+                    }
+                    Ok(())
+                });
             })
         );
     }
@@ -257,7 +260,8 @@ impl App {
                     .expect("Changing the beginning of an activity which is not inserted")
                     .beginning();
 
-                let new_beginning = wrap_duration(activity_beginning, Time::new(hours, minutes));
+                let (hours, minutes) = wrap_duration(activity_beginning.minutes(), (hours, minutes));
+                let new_beginning = TimeOfDay::new(hours, minutes);
 
                 if let Err(e) = data.insert_activity(id, Some(new_beginning)) {
                     $ui.borrow().notify_err(e);
@@ -305,11 +309,13 @@ impl App {
         self.ui
             .borrow_mut()
             .set_activity_try_insert_callback(Rc::new(Box::new(
-                move |entity_name: String, activity_id: ActivityId, insertion_time: Time| {
+                move |entity_name: String, activity_id: ActivityId, insertion_time: TimeOfDay| {
                     let mut data = data.borrow_mut();
-                    let activity = data.activity(activity_id);
 
-                    if activity.entities_sorted().contains(&entity_name) {
+                    if data
+                        .entities_of_activity(activity_id)
+                        .contains(&entity_name)
+                    {
                         // Ignore errors - no spamming on the user when he drag drops
                         let _ = data.insert_activity(activity_id, Some(insertion_time));
                     }
@@ -322,7 +328,7 @@ impl App {
         let possible_insertion_times_of_activity_callback =
             Rc::new(Box::new(move |id: ActivityId| {
                 let data = data.borrow();
-                let activity_participants = data.activity(id).entities_sorted();
+                let activity_participants = data.entities_of_activity(id);
 
                 let maybe_possible_insertion_times = data.activity(id).insertion_costs();
 
@@ -360,11 +366,14 @@ impl App {
                     let activity_duration = data.activity(id).duration();
 
                     let new_duration = if increase_duration {
-                        activity_duration + MIN_TIME_DISCRETIZATION
+                        activity_duration.checked_add(MIN_TIME_DISCRETIZATION)
                     } else {
-                        activity_duration - MIN_TIME_DISCRETIZATION
+                        activity_duration.checked_sub(MIN_TIME_DISCRETIZATION)
                     };
-                    return_if_err!(ui, data.set_activity_duration(id, new_duration));
+                    // Scrolling past the shortest or longest duration does nothing
+                    if let Ok(new_duration) = new_duration {
+                        return_if_err!(ui, data.set_activity_duration(id, new_duration));
+                    }
                 },
             )));
     }
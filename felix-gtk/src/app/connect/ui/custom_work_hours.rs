@@ -7,7 +7,7 @@ use std::rc::Rc;
 
 use felix_data::errors::invalid_interval::InvalidInterval;
 use felix_data::errors::Result;
-use felix_data::{Time, TimeInterval, MIN_TIME_DISCRETIZATION};
+use felix_data::{TimeInterval, TimeOfDay};
 
 macro_rules! reset_custom_work_hours_if_err {
     ($ui:ident, $data:ident, $operation:expr) => {
@@ -83,10 +83,10 @@ impl App {
 
             let mut data = data.borrow_mut();
 
-            let beginning = Time::new(begin_hours, begin_minutes);
-            let end = Time::new(end_hours, end_minutes);
+            let beginning = TimeOfDay::new(begin_hours, begin_minutes);
+            let end = TimeOfDay::new(end_hours, end_minutes);
 
-            if beginning > end || end - beginning < MIN_TIME_DISCRETIZATION {
+            if beginning == end {
                 let error: Result<()> = Err(InvalidInterval::new());
                 reset_custom_work_hours_if_err!(ui, data, error);
             }
@@ -103,7 +103,8 @@ impl App {
                         current_entity.name()
                     )
                 });
-            let interval = TimeInterval::new(beginning, end);
+            // An end before the beginning is on the next day
+            let interval = TimeInterval::overnight(beginning, end);
 
             if position < work_hours.len() {
                 reset_custom_work_hours_if_err!(
@@ -71,7 +71,7 @@ impl App {
                 let selected_group = get_selection_from_treeview(&tree_view, GROUP_NAME_COLUMN);
                 if let Some(group_name) = selected_group {
                     assign_or_return!(ui, group, data.borrow().group(group_name));
-                    ui.borrow_mut().on_group_selected(group);
+                    ui.borrow_mut().on_group_selected(&data.borrow(), group);
                 }
             })
         );
@@ -139,17 +139,25 @@ impl App {
                 let group_in_which_to_add = $ui.borrow().current_group().as_ref()
                     .expect("Current group should be selected before accessing any group-related filed")
                     .name();
-                let entity_name = $entity_into_group_name_entry.get_text();
+                let entity_or_group_name = $entity_into_group_name_entry.get_text();
                 with_blocked_signals!(
                     $ui.borrow(),
                     $entity_into_group_name_entry.set_text(""),
                     $entity_into_group_name_entry
                 );
 
-                return_if_err!(
-                    $ui,
-                    data
-                    .add_entity_to_group(group_in_which_to_add, entity_name));
+                // Entities and groups cannot have the same name
+                if data.group(entity_or_group_name.as_str()).is_ok() {
+                    return_if_err!(
+                        $ui,
+                        data
+                        .add_group_to_group(group_in_which_to_add, entity_or_group_name.as_str()));
+                } else {
+                    return_if_err!(
+                        $ui,
+                        data
+                        .add_entity_to_group(group_in_which_to_add, entity_or_group_name));
+                }
                     })
             };
         }
@@ -212,8 +220,15 @@ impl App {
                 .expect("Value should be gchararray");
 
             let current_group_name = ui.borrow().current_group().as_ref().expect("Current group should be set before performing any action on a group").name();
-            return_if_err!(ui, data.borrow_mut()
-                .remove_entity_from_group(current_group_name, entity_to_remove));
+            // Entities and groups cannot have the same name
+            let is_subgroup = data.borrow().group(entity_to_remove).is_ok();
+            if is_subgroup {
+                return_if_err!(ui, data.borrow_mut()
+                    .remove_group_from_group(current_group_name, entity_to_remove));
+            } else {
+                return_if_err!(ui, data.borrow_mut()
+                    .remove_entity_from_group(current_group_name, entity_to_remove));
+            }
         }
             }));
     }
@@ -0,0 +1,39 @@
+use gtk::prelude::*;
+
+use crate::app::App;
+
+use glib::clone;
+
+impl App {
+    pub fn connect_undo_redo(&self) {
+        fetch_from!(self.ui.borrow(), main_window, data_window);
+
+        // Ctrl+Z undoes the last change, Ctrl+Shift+Z redoes it.
+        // Text fields keep these shortcuts to undo their own text.
+        for window in &[main_window, data_window] {
+            window.connect_key_press_event(
+                clone!(@strong self.data as data => move |window, event| {
+                    let text_field_has_focus = window.get_focus().is_some_and(|widget| {
+                        widget.is::<gtk::Editable>() || widget.is::<gtk::TextView>()
+                    });
+                    if text_field_has_focus
+                        || !event.get_state().contains(gdk::ModifierType::CONTROL_MASK)
+                    {
+                        return glib::signal::Inhibit(false);
+                    }
+
+                    let keyval = event.get_keyval();
+                    if keyval == gdk::keys::constants::z {
+                        data.borrow_mut().undo();
+                        glib::signal::Inhibit(true)
+                    } else if keyval == gdk::keys::constants::Z {
+                        data.borrow_mut().redo();
+                        glib::signal::Inhibit(true)
+                    } else {
+                        glib::signal::Inhibit(false)
+                    }
+                }),
+            );
+        }
+    }
+}
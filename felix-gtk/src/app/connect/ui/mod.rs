@@ -8,6 +8,7 @@ mod entities;
 mod export;
 mod groups;
 mod header;
+mod history;
 mod init_ui_with_existing_data;
 mod notification;
 mod recreate_last_ui_state;
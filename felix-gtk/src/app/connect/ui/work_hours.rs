@@ -7,7 +7,7 @@ use std::rc::Rc;
 
 use felix_data::errors::invalid_interval::InvalidInterval;
 use felix_data::errors::Result;
-use felix_data::{Time, TimeInterval, MIN_TIME_DISCRETIZATION};
+use felix_data::{TimeInterval, TimeOfDay};
 
 macro_rules! reset_work_hours_if_err {
     ($ui:ident, $data:ident, $operation:expr) => {
@@ -70,14 +70,15 @@ impl App {
                                        interval_end_hours => end_hours,
                                        interval_end_minutes => end_minutes);
 
-            let beginning = Time::new(begin_hours, begin_minutes);
-            let end = Time::new(end_hours, end_minutes);
-            if beginning > end || end - beginning < MIN_TIME_DISCRETIZATION {
+            let beginning = TimeOfDay::new(begin_hours, begin_minutes);
+            let end = TimeOfDay::new(end_hours, end_minutes);
+            if beginning == end {
                 let error: Result<()> = Err(InvalidInterval::new());
                 reset_work_hours_if_err!(ui, data, error);
             }
 
-            let interval = TimeInterval::new(beginning, end);
+            // An end before the beginning is on the next day
+            let interval = TimeInterval::overnight(beginning, end);
 
             if position < work_hours.len() {
                 reset_work_hours_if_err!(
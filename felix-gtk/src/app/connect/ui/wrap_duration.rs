@@ -1,29 +1,27 @@
-use felix_data::Time;
-
 /// When the minutes skip from 0 to 55 or the inverse, removes or adds an hour
-/// and returns the new result.
+/// and returns the new (hours, minutes).
+///
+/// Works on raw hours and minutes so that it can be used for durations as well as
+/// times of day.
 ///
 /// # Example
 ///
 /// ```
 ///
-/// let (old, new) = (Time::new(1, 0), Time::new(1, 55));
-/// assert_eq!(wrap_duration(old, new), Time::new(0, 55));
-
-/// let (old, new) = (Time::new(0, 55), Time::new(0, 0));
-/// assert_eq!(wrap_duration(old, new), Time::new(1, 0));
+/// assert_eq!(wrap_duration(0, (1, 55)), (0, 55));
+/// assert_eq!(wrap_duration(55, (0, 0)), (1, 0));
 /// ```
-pub fn wrap_duration(old_duration: Time, new_duration: Time) -> Time {
+pub fn wrap_duration(old_minutes: i8, (new_hours, new_minutes): (i8, i8)) -> (i8, i8) {
     // Wrap if the duration goes from 0:55 to 0 or from 0 to 0:55
-    if old_duration.minutes() == 55 && new_duration.minutes() == 0 {
+    if old_minutes == 55 && new_minutes == 0 {
         // Duration goes up from 0:55 to 1:00
-        Time::new((new_duration.hours() + 1).min(23), new_duration.minutes())
-    } else if old_duration.minutes() == 0 && new_duration.minutes() == 55 {
+        ((new_hours + 1).min(23), new_minutes)
+    } else if old_minutes == 0 && new_minutes == 55 {
         // Duration goes down from 1:00 to 0:55
-        Time::new((new_duration.hours() - 1).max(0), new_duration.minutes())
+        ((new_hours - 1).max(0), new_minutes)
     } else {
         // No wrap
-        new_duration
+        (new_hours, new_minutes)
     }
 }
 
@@ -33,13 +31,8 @@ mod tests {
 
     #[test]
     fn test_wrap_duration() {
-        let (old, new) = (Time::new(1, 0), Time::new(1, 55));
-        assert_eq!(wrap_duration(old, new), Time::new(0, 55));
-
-        let (old, new) = (Time::new(0, 55), Time::new(0, 0));
-        assert_eq!(wrap_duration(old, new), Time::new(1, 0));
-
-        let (old, new) = (Time::new(0, 50), Time::new(0, 55));
-        assert_eq!(wrap_duration(old, new), Time::new(0, 55));
+        assert_eq!(wrap_duration(0, (1, 55)), (0, 55));
+        assert_eq!(wrap_duration(55, (0, 0)), (1, 0));
+        assert_eq!(wrap_duration(50, (0, 55)), (0, 55));
     }
 }
@@ -39,7 +39,7 @@ fn init_data() -> Rc<RefCell<Data>> {
     let config_file_contents = std::fs::read_to_string(config::DATA_CONF_FILE);
 
     let data = if let Ok(contents) = config_file_contents {
-        let data_value: serde_json::Result<Data> = serde_json::from_str(&contents);
+        let data_value = Data::from_json(&contents);
         if let Ok(mut data) = data_value {
             data.init_computation_module();
             data
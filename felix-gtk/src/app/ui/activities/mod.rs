@@ -16,7 +16,8 @@ use gtk::prelude::*;
 
 impl Ui {
     pub(super) fn on_init_activities(&mut self) {
-        self.update_current_activity(&Vec::new(), None);
+        self.update_current_activity_without_ui(None);
+        self.hide_current_activity_view();
         self.expand_activity_groups_tree_view_name_col();
         self.expand_activity_entities_tree_view_name_col();
         self.set_duration_spinbutton_format();
@@ -50,36 +51,65 @@ impl Ui {
     }
 
     pub fn on_activity_added(&mut self, data: &Data, activity: &Activity) {
-        self.update_current_activity(&data.groups_sorted(), Some(activity.clone()));
-        self.update_activities_treeview(data.activities_sorted());
+        self.update_current_activity(data, Some(activity.clone()));
+        self.update_activities_treeview(data);
+    }
+
+    pub fn on_activities_added(&mut self, data: &Data, activities: &[Activity]) {
+        if let Some(activity) = activities.first() {
+            self.update_current_activity(data, Some(activity.clone()));
+        }
+        self.update_activities_treeview(data);
+    }
+
+    pub fn on_data_restored_update_activities(&mut self, data: &Data) {
+        let activities = data.activities_sorted();
+        // Keep the current activity if it still exists
+        let new_current_activity = self
+            .current_activity
+            .as_ref()
+            .and_then(|current_activity| {
+                activities
+                    .iter()
+                    .find(|activity| activity.id() == current_activity.id())
+            })
+            .or_else(|| activities.first())
+            .cloned();
+        self.update_current_activity(data, new_current_activity);
+        self.update_activities_treeview(data);
     }
 
     pub fn on_activity_selected(&mut self, data: &Data, activity: Activity) {
-        self.update_current_activity(&data.groups_sorted(), Some(activity));
+        self.update_current_activity(data, Some(activity));
     }
 
     pub fn on_activity_removed(&mut self, data: &Data, position_of_removed_activity: usize) {
         let activities = data.activities_sorted();
         let (new_current_activity, _) = get_next_element(position_of_removed_activity, &activities);
-        self.update_current_activity(&data.groups_sorted(), new_current_activity);
-        self.update_activities_treeview(activities);
+        self.update_current_activity(data, new_current_activity);
+        self.update_activities_treeview(data);
     }
 
     pub fn on_activity_renamed(&mut self, data: &Data, activity: &Activity) {
         self.update_current_activity_without_ui(Some(activity.clone()));
-        self.update_activities_treeview(data.activities_sorted());
+        self.update_activities_treeview(data);
+    }
+
+    pub fn on_activities_filter_changed(&mut self, data: &Data, filter: String) {
+        self.activities_filter = filter;
+        self.update_activities_treeview(data);
     }
 
     pub fn on_group_members_changed_update_activity(&mut self, data: &Data) {
         if let Some(current_activity) = &self.current_activity {
             let activity = data.activity(current_activity.id());
-            self.update_current_activity(&data.groups_sorted(), Some(activity));
+            self.update_current_activity(data, Some(activity));
         }
     }
 
     pub fn on_activity_changed(&mut self, data: &Data, activity: &Activity) {
-        self.update_current_activity(&data.groups_sorted(), Some(activity.clone()));
-        self.update_activities_treeview(data.activities_sorted());
+        self.update_current_activity(data, Some(activity.clone()));
+        self.update_activities_treeview(data);
     }
 
     pub fn on_entities_or_groups_changed(&mut self, data: &Data) {
@@ -94,7 +124,7 @@ impl Ui {
                 .into_iter()
                 .find(|activity| activity.id() == current_activity.id());
 
-            self.update_current_activity(&data.groups_sorted(), new_current_activity);
+            self.update_current_activity(data, new_current_activity);
         }
     }
 }
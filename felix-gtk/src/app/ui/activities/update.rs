@@ -1,6 +1,6 @@
 use crate::app::ui::helpers::tree::tree_path_from_selection_index;
 use crate::app::ui::Ui;
-use felix_data::{Activity, Group};
+use felix_data::{Activity, Data};
 
 use gtk::prelude::*;
 
@@ -10,11 +10,11 @@ impl Ui {
     }
 
     /// Updates the state of AppData and Activity-specific UI.
-    pub fn update_current_activity(&mut self, groups: &[&Group], activity: Option<Activity>) {
+    pub fn update_current_activity(&mut self, data: &Data, activity: Option<Activity>) {
         self.update_current_activity_without_ui(activity);
 
         if self.current_activity.is_some() {
-            self.update_current_activity_view(groups);
+            self.update_current_activity_view(data);
             self.update_activities_treeview_selection();
         } else {
             self.hide_current_activity_view();
@@ -23,7 +23,9 @@ impl Ui {
 
     /// Updates the treeview of activities and selects the given row if not None.
     /// If the given row is None, keeps the originally selected row.
-    pub fn update_activities_treeview(&mut self, activities: Vec<Activity>) {
+    /// Only the activities matching the current filter are shown.
+    pub fn update_activities_treeview(&mut self, data: &Data) {
+        let activities = data.activities_matching(&self.activities_filter);
         self.update_activities_list_store(activities);
         self.update_activities_treeview_selection();
     }
@@ -35,7 +37,12 @@ impl Ui {
             self,
             {
                 activities_list_store.clear();
-                for activity in activities {
+                // The sessions of a split activity and the occurrences of a recurring
+                // activity are shown as one activity
+                for activity in activities
+                    .into_iter()
+                    .filter(|activity| activity.main_session_id() == activity.id())
+                {
                     let inserted_icon = if activity.insertion_interval().is_some() {
                         "object-select-symbolic".to_string()
                     } else {
@@ -48,15 +55,20 @@ impl Ui {
                         "grey".to_string()
                     };
 
+                    let name = if activity.number_of_occurrences() > 1 {
+                        format!(
+                            "{} (×{})",
+                            activity.name(),
+                            activity.number_of_occurrences()
+                        )
+                    } else {
+                        activity.name()
+                    };
+
                     activities_list_store.insert_with_values(
                         None,
                         &[0, 1, 2, 3],
-                        &[
-                            &(activity.id() as u32),
-                            &activity.name(),
-                            &inserted_icon,
-                            &color,
-                        ],
+                        &[&(activity.id() as u32), &name, &inserted_icon, &color],
                     );
                 }
             },
@@ -84,7 +96,7 @@ impl Ui {
         );
     }
 
-    fn update_current_activity_view(&self, groups: &[&Group]) {
+    fn update_current_activity_view(&self, data: &Data) {
         fetch_from!(
             self,
             activity_specific_box,
@@ -158,14 +170,14 @@ impl Ui {
             activity_insertion_time_box
         );
 
-        self.update_current_activity_entities(groups);
-        self.update_current_activity_groups();
+        self.update_current_activity_entities(data);
+        self.update_current_activity_groups(data);
     }
 
-    pub(super) fn update_current_activity_groups(&self) {
+    pub(super) fn update_current_activity_groups(&self, data: &Data) {
         fetch_from!(self, activity_groups_list_store, activity_groups_tree_view);
         if let Some(activity) = &self.current_activity {
-            let groups = activity.groups_sorted();
+            let groups = data.groups_of_activity(activity.id());
 
             with_blocked_signals!(
                 self,
@@ -184,7 +196,7 @@ impl Ui {
         }
     }
 
-    fn hide_current_activity_view(&self) {
+    pub(super) fn hide_current_activity_view(&self) {
         fetch_from!(self, activity_specific_box);
         activity_specific_box.hide();
     }
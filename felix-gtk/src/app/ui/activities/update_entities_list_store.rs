@@ -1,5 +1,5 @@
 use crate::app::ui::Ui;
-use felix_data::{Activity, Group};
+use felix_data::{Activity, Data};
 
 use gtk::prelude::*;
 
@@ -11,7 +11,7 @@ type EntityNotInActivityButInGroup = Option<String>;
 type EntityInActivity = BTreeMap<EntityName, EntityNotInActivityButInGroup>;
 
 impl Ui {
-    pub(super) fn update_current_activity_entities(&self, groups: &[&Group]) {
+    pub(super) fn update_current_activity_entities(&self, data: &Data) {
         fetch_from!(
             self,
             activity_entities_list_store,
@@ -19,7 +19,7 @@ impl Ui {
         );
 
         if let Some(activity) = &self.current_activity {
-            let entities = create_entity_list(activity, groups);
+            let entities = create_entity_list(activity, data);
 
             with_blocked_signals!(
                 self,
@@ -48,17 +48,15 @@ impl Ui {
 /// Creates the list of entities which should be added to the list store.
 /// If any entity is present in an activity's group but not in the activity,
 /// the group in which they are present is returned as well.
-fn create_entity_list(activity: &Activity, groups: &[&Group]) -> EntityInActivity {
-    let activity_entities = activity.entities_sorted();
+fn create_entity_list(activity: &Activity, data: &Data) -> EntityInActivity {
+    let activity_entities = data.entities_of_activity(activity.id());
     let mut entities: EntityInActivity = EntityInActivity::new();
 
-    let activity_group_names = activity.groups_sorted();
-    let activity_groups = groups
-        .iter()
-        .filter(|group| activity_group_names.contains(&group.name()));
-
-    for group in activity_groups {
-        for entity in group.entities_sorted() {
+    for group in data.groups_of_activity(activity.id()) {
+        let members = data
+            .members_of_group(&group)
+            .expect("Group of the activity should exist");
+        for entity in members {
             if entities.contains_key(&entity) {
                 continue;
             }
@@ -67,7 +65,7 @@ fn create_entity_list(activity: &Activity, groups: &[&Group]) -> EntityInActivit
                 None
             } else {
                 // The entity is in one of the activity's groups but not in the activity.
-                let group: EntityNotInActivityButInGroup = Some(group.name());
+                let group: EntityNotInActivityButInGroup = Some(group.clone());
                 group
             };
             entities.insert(entity, group);
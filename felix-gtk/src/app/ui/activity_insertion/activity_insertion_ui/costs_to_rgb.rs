@@ -1,10 +1,10 @@
-use felix_data::{InsertionCost, Rgba, Time};
+use felix_data::{InsertionCost, Rgba, TimeOfDay};
 
 use std::collections::HashMap;
 
 /// From a set of possible insertion beginnings with their associated costs,
 /// returns the color associated to each time.
-pub(super) fn costs_to_rgb(insertion_costs: &[InsertionCost]) -> HashMap<Time, Rgba> {
+pub(super) fn costs_to_rgb(insertion_costs: &[InsertionCost]) -> HashMap<TimeOfDay, Rgba> {
     let mut beginnings_and_associated_costs = HashMap::new();
 
     // If the insertion cost is empty, max_by_key and min_by_key can return None
@@ -59,18 +59,21 @@ pub(super) fn costs_to_rgb(insertion_costs: &[InsertionCost]) -> HashMap<Time, R
 #[cfg(test)]
 mod tests {
     use super::*;
+    use felix_data::Duration;
 
     #[test]
     fn test_costs_to_rgb() {
-        let (time1, time2) = (Time::new(8, 0), Time::new(9, 0));
+        let (time1, time2) = (TimeOfDay::new(8, 0), TimeOfDay::new(9, 0));
         let costs = &[
             InsertionCost {
                 beginning: time1,
                 cost: 5,
+                duration: Duration::new(1, 0),
             },
             InsertionCost {
                 beginning: time2,
                 cost: 0,
+                duration: Duration::new(1, 0),
             },
         ]
         .iter()
@@ -1,6 +1,6 @@
 use super::{costs_to_rgb::costs_to_rgb, ActivityInsertionUi, Schedules, NUM_HOURS_IN_DAY};
 
-use felix_data::{Rgba, Time};
+use felix_data::{Duration, Rgba};
 
 use gtk::prelude::*;
 
@@ -54,12 +54,14 @@ impl ActivityInsertionUi {
 
     fn connect_draw_hours(&self) {
         fetch_from!(self, hours_drawing);
+        let schedules = self.schedules_to_show.clone();
 
         hours_drawing.connect_draw(move |w, c| {
             draw_hours(
                 &c,
                 w.get_allocated_width() as f64,
                 w.get_allocated_height() as f64,
+                schedules.borrow().num_hours_to_show,
             );
             gtk::Inhibit(false)
         });
@@ -116,22 +118,23 @@ impl ActivityInsertionUi {
     }
 }
 
-fn draw_hours(c: &cairo::Context, width: f64, height: f64) {
+fn draw_hours(c: &cairo::Context, width: f64, height: f64, num_hours: i32) {
     paint_background_uniform(c, IN_WORK_HOURS_RGB);
-    draw_hour_lines(c, width, height);
+    draw_hour_lines(c, width, height, num_hours);
 
     // Draw the hour numbers
     c.set_source_rgb(HOUR_FONT_RGB, HOUR_FONT_RGB, HOUR_FONT_RGB);
     c.set_font_size(HOUR_FONT_SIZE);
-    let y_step = get_height_for_one_hour(height);
+    let y_step = get_height_for_one_hour(height, num_hours);
     let mut current_y = y_step / 5.0 + HALF_PIXEL;
-    for hour in 0..NUM_HOURS_IN_DAY {
-        let text_to_display = &format!("{:02}:00", hour);
+    for hour in 0..num_hours {
+        // Hours after midnight are shown as the hours of the next day
+        let text_to_display = &format!("{:02}:00", hour % NUM_HOURS_IN_DAY);
         let size_of_text = c.text_extents(text_to_display).width;
         // Center the text
         let x_offset = (width - size_of_text) / 2.0;
         c.move_to(x_offset, current_y);
-        c.show_text(&format!("{:02}:00", hour));
+        c.show_text(text_to_display);
         current_y += y_step;
     }
 
@@ -170,7 +173,7 @@ fn draw_schedules(c: &cairo::Context, w: &gtk::DrawingArea, schedules: Rc<RefCel
     draw_possible_insertions_background(c, height, &schedules);
 
     let schedules = schedules.borrow();
-    draw_hour_lines(c, width, height);
+    draw_hour_lines(c, width, height, schedules.num_hours_to_show);
     let nb_schedules = schedules.entities_to_show.len();
     // Draw schedule separators
     c.set_source_rgb(FULL_LINE_RGB, FULL_LINE_RGB, FULL_LINE_RGB);
@@ -192,7 +195,9 @@ fn schedule_size_ok_or_resize(
     visible_width: f64,
 ) -> bool {
     let width = w.get_allocated_width() as f64;
-    let height = w.get_allocated_height() as f64;
+    // The requested height may differ from the allocated one if the schedules were
+    // resized to go past midnight
+    let height = w.get_size_request().1;
 
     let mut schedules = schedules.borrow_mut();
     let nb_schedules = schedules.entities_to_show.len();
@@ -202,13 +207,13 @@ fn schedule_size_ok_or_resize(
     match width_taken_by_schedules {
         required_width if required_width > width => {
             // Header is too small
-            w.set_size_request(width_taken_by_schedules as i32, height as i32);
+            w.set_size_request(width_taken_by_schedules as i32, height);
             w.queue_resize();
             false
         }
         required_width if required_width < width && width > visible_width => {
             // Header is too big
-            w.set_size_request(visible_width as i32, height as i32);
+            w.set_size_request(visible_width as i32, height);
             w.queue_resize();
             false
         }
@@ -242,7 +247,7 @@ fn draw_header(c: &cairo::Context, w: &gtk::DrawingArea, schedules: Rc<RefCell<S
     c.set_font_size(SCHEDULE_FONT_SIZE);
     let mut current_x = 0.0;
     for entity in &schedules.entities_to_show {
-        let total_time: Time = entity
+        let total_time: Duration = entity
             .work_hours()
             .iter()
             .map(|interval| interval.duration())
@@ -304,15 +309,15 @@ pub fn get_center_of_remove_button(schedules: &Schedules, schedule_left_edge_x:
     )
 }
 
-fn draw_hour_lines(c: &cairo::Context, width: f64, height: f64) {
+fn draw_hour_lines(c: &cairo::Context, width: f64, height: f64, num_hours: i32) {
     // Draw hour lines
     c.set_line_width(LINE_WIDTH);
     c.set_source_rgb(FULL_LINE_RGB, FULL_LINE_RGB, FULL_LINE_RGB);
 
-    let y_step = get_height_for_one_hour(height);
+    let y_step = get_height_for_one_hour(height, num_hours);
     // Half pixel offset because cairo calculates from the half of a pixel
     let mut current = HALF_PIXEL;
-    for _hour in 0..NUM_HOURS_IN_DAY {
+    for _hour in 0..num_hours {
         c.move_to(0.0, current);
         c.line_to(width, current);
         current += y_step;
@@ -323,7 +328,7 @@ fn draw_hour_lines(c: &cairo::Context, width: f64, height: f64) {
     c.set_dash(&[DASH_SIZE], 0.0);
     c.set_source_rgb(DASH_LINE_RGB, DASH_LINE_RGB, DASH_LINE_RGB);
     let mut current = y_step / 2.0 + HALF_PIXEL;
-    for _half_hour in 0..NUM_HOURS_IN_DAY {
+    for _half_hour in 0..num_hours {
         c.move_to(0.0, current);
         c.line_to(width, current);
         current += y_step;
@@ -336,8 +341,8 @@ fn paint_background_uniform(c: &cairo::Context, color: f64) {
     c.paint();
 }
 
-pub fn get_height_for_one_hour(total_height: f64) -> f64 {
-    total_height / NUM_HOURS_IN_DAY as f64
+pub fn get_height_for_one_hour(total_height: f64, num_hours: i32) -> f64 {
+    total_height / num_hours as f64
 }
 
 fn draw_inside_work_hours_background(
@@ -5,7 +5,7 @@ use super::{
 };
 
 use crate::app::ui::drag_config::*;
-use felix_data::{ActivityId, Time};
+use felix_data::{ActivityId, TimeOfDay};
 
 use gtk::prelude::*;
 
@@ -15,7 +15,7 @@ use std::rc::Rc;
 impl ActivityInsertionUi {
     pub(in super::super) fn enable_drop(
         &self,
-        try_insert_activity_callback: Rc<dyn Fn(String, ActivityId, Time)>,
+        try_insert_activity_callback: Rc<dyn Fn(String, ActivityId, TimeOfDay)>,
     ) {
         self.drag_dest_set();
         self.connect_drag_motion();
@@ -54,7 +54,7 @@ impl ActivityInsertionUi {
 
     fn connect_drag_data_received(
         &self,
-        try_insert_activity_callback: Rc<dyn Fn(String, ActivityId, Time)>,
+        try_insert_activity_callback: Rc<dyn Fn(String, ActivityId, TimeOfDay)>,
     ) {
         fetch_from!(self, schedules_drawing);
         let schedules = self.schedules_to_show.clone();
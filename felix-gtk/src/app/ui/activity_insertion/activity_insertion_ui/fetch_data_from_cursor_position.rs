@@ -2,7 +2,7 @@ use super::Schedules;
 
 use super::drawing::{get_center_of_remove_button, REMOVE_BUTTON_RADIUS};
 use crate::app::ui::ActivityToShow;
-use felix_data::Time;
+use felix_data::TimeOfDay;
 
 #[must_use]
 pub(super) fn get_name_of_entity_from_x(x: i32, schedules: &Schedules) -> Option<String> {
@@ -16,9 +16,9 @@ pub(super) fn get_name_of_entity_from_x(x: i32, schedules: &Schedules) -> Option
 }
 
 #[must_use]
-pub(super) fn get_time_on_y(y: i32, schedules: &Schedules) -> Time {
+pub(super) fn get_time_on_y(y: i32, schedules: &Schedules) -> TimeOfDay {
     let n_times_min_discretization = (y as f64 / schedules.height_per_min_discretization) as i32;
-    Time::from_n_times_min_discretization(n_times_min_discretization)
+    TimeOfDay::from_n_times_min_discretization(n_times_min_discretization)
 }
 
 #[must_use]
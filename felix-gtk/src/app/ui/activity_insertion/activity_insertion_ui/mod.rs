@@ -27,6 +27,7 @@ const NUM_HOURS_IN_DAY: i32 = 24;
 #[derive(Clone)]
 pub struct ActivityInsertionUi {
     builder: gtk::Builder,
+    height_of_day: i32,
     schedules_to_show: Rc<RefCell<Schedules>>,
     last_activity_under_cursor: Rc<RefCell<Option<ActivityToShow>>>,
     possible_insertions_callback: Rc<dyn Fn(ActivityId) -> EntitiesAndInsertionTimes>,
@@ -40,9 +41,13 @@ impl ActivityInsertionUi {
         builder
             .add_from_resource("/com/github/paulmconstant/felix/ui/activity_insertion.ui")
             .expect("Could not load ui file: activity_insertion.ui");
+        fetch_from_builder!(builder, schedules_drawing=gtk::DrawingArea:"SchedulesDrawing");
+        // The ui file gives the drawings the height of a day, before any schedule is shown
+        let (_, height_of_day) = schedules_drawing.get_size_request();
 
         let activity_insertion = ActivityInsertionUi {
             builder,
+            height_of_day,
             schedules_to_show: Rc::new(RefCell::new(Schedules::new())),
             last_activity_under_cursor: Rc::new(RefCell::new(None)),
             possible_insertions_callback: Rc::new(Box::new(|_| {
@@ -140,9 +145,19 @@ impl ActivityInsertionUi {
         schedules_to_show
             .entities_to_show
             .sort_by(|a, b| a.name().cmp(b.name()));
-
-        fetch_from!(self, header_drawing, schedules_drawing);
-        for drawing in &[header_drawing, schedules_drawing] {
+        schedules_to_show.compute_num_hours_to_show();
+
+        // Keep the same height for one hour if the schedules go past midnight
+        let height = self.height_of_day * schedules_to_show.num_hours_to_show / NUM_HOURS_IN_DAY;
+        fetch_from!(self, hours_drawing, header_drawing, schedules_drawing);
+        for drawing in &[&hours_drawing, &schedules_drawing] {
+            let (width_request, height_request) = drawing.get_size_request();
+            if height_request != height {
+                drawing.set_size_request(width_request, height);
+                drawing.queue_resize();
+            }
+        }
+        for drawing in &[hours_drawing, header_drawing, schedules_drawing] {
             drawing.queue_draw();
         }
     }
@@ -1,7 +1,8 @@
 use super::drawing::get_height_for_one_hour;
+use super::NUM_HOURS_IN_DAY;
 use crate::app::ui::EntityToShow;
 
-use felix_data::{ActivityInsertionCosts, Time, MIN_TIME_DISCRETIZATION};
+use felix_data::{ActivityInsertionCosts, TimeOfDay, MIN_TIME_DISCRETIZATION};
 
 const MIN_SCHEDULE_WIDTH: f64 = 350.0;
 const MAX_SCHEDULE_WIDTH: f64 = 550.0;
@@ -9,7 +10,7 @@ const MAX_SCHEDULE_WIDTH: f64 = 550.0;
 pub struct TimeTooltipToDraw {
     pub x_cursor: f64,
     pub y_cursor: f64,
-    pub time: Time,
+    pub time: TimeOfDay,
 }
 
 /// Holds data required to interact with the schedules drawing area.
@@ -17,6 +18,7 @@ pub struct Schedules {
     pub entities_to_show: Vec<EntityToShow>,
     pub width_per_schedule: f64,
     pub height_per_min_discretization: f64,
+    pub num_hours_to_show: i32,
     pub time_tooltip_to_draw: Option<TimeTooltipToDraw>,
     pub possible_activity_insertion_times: ActivityInsertionCosts,
     pub activity_insertion_concerned_entities: Vec<String>,
@@ -29,6 +31,7 @@ impl Schedules {
             entities_to_show: Vec::new(),
             width_per_schedule: 0.0,
             height_per_min_discretization: 0.0,
+            num_hours_to_show: NUM_HOURS_IN_DAY,
             time_tooltip_to_draw: None,
             possible_activity_insertion_times: None,
             activity_insertion_concerned_entities: Vec::new(),
@@ -45,6 +48,22 @@ impl Schedules {
     pub fn compute_height_for_min_discretization(&mut self, visible_height: f64) {
         let num_min_discretization_in_hour = 60 / MIN_TIME_DISCRETIZATION.minutes();
         self.height_per_min_discretization =
-            get_height_for_one_hour(visible_height) / num_min_discretization_in_hour as f64;
+            get_height_for_one_hour(visible_height, self.num_hours_to_show)
+                / num_min_discretization_in_hour as f64;
+    }
+
+    /// Shows the whole day, extended to the end of the latest work interval
+    /// if it crosses midnight.
+    pub fn compute_num_hours_to_show(&mut self) {
+        let latest_end_minutes = self
+            .entities_to_show
+            .iter()
+            .flat_map(|entity| entity.work_hours())
+            .map(|interval| interval.end().total_minutes() as i32)
+            .max()
+            .unwrap_or(0);
+        // Round up to the next hour
+        let latest_end_hours = (latest_end_minutes + 59) / 60;
+        self.num_hours_to_show = latest_end_hours.max(NUM_HOURS_IN_DAY);
     }
 }
@@ -1,5 +1,5 @@
 use crate::app::ui::ActivityToShow;
-use felix_data::{Data, Time, TimeInterval};
+use felix_data::{Data, Duration, TimeInterval};
 
 /// Simple struct holding an entity's name, its activities and work hours.
 #[derive(Debug)]
@@ -7,7 +7,7 @@ pub struct EntityToShow {
     name: String,
     activities: Vec<ActivityToShow>,
     work_hours: Vec<TimeInterval>,
-    free_time: Time,
+    free_time: Duration,
 }
 
 impl EntityToShow {
@@ -46,7 +46,7 @@ impl EntityToShow {
     }
 
     #[must_use]
-    pub fn free_time(&self) -> Time {
+    pub fn free_time(&self) -> Duration {
         self.free_time
     }
 }
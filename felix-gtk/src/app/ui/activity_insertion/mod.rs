@@ -5,7 +5,7 @@ pub mod entity_to_show;
 use crate::app::ui::{EntitiesAndInsertionTimes, Ui};
 use entity_to_show::EntityToShow;
 
-use felix_data::{ActivityId, Data, Entity, Time};
+use felix_data::{ActivityId, Data, Entity, TimeOfDay};
 
 use glib::clone;
 use gtk::prelude::*;
@@ -50,7 +50,7 @@ impl Ui {
 
     pub fn set_activity_try_insert_callback(
         &mut self,
-        try_insert_activity_callback: Rc<dyn Fn(String, ActivityId, Time)>,
+        try_insert_activity_callback: Rc<dyn Fn(String, ActivityId, TimeOfDay)>,
     ) {
         self.activity_insertion
             .borrow()
@@ -131,6 +131,18 @@ impl Ui {
             .remove_entity_schedule(old_name);
     }
 
+    pub fn on_data_restored_update_schedules(&mut self, data: &Data) {
+        let activity_insertion = self.activity_insertion.borrow();
+        // Entities which do not exist anymore are not shown
+        for entity_name in activity_insertion.shown_entities() {
+            if data.entity(&entity_name).is_err() {
+                activity_insertion.remove_entity_schedule(&entity_name);
+            }
+        }
+        drop(activity_insertion);
+        self.update_schedules(data);
+    }
+
     pub fn on_left_click_over_schedules(&mut self, data: Rc<RefCell<Data>>, x: f64, y: f64) {
         self.activity_insertion
             .borrow()
@@ -140,7 +152,7 @@ impl Ui {
         let maybe_activity = self.activity_insertion.borrow().get_activity_under_cursor();
         if let Some(activity) = maybe_activity {
             let activity = data.borrow().activity(activity.id());
-            self.update_current_activity(&data.borrow().groups_sorted(), Some(activity));
+            self.update_current_activity(&data.borrow(), Some(activity));
         }
     }
 
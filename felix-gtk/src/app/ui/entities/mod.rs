@@ -27,6 +27,23 @@ impl Ui {
         self.update_entities_treeview(&entities);
     }
 
+    pub fn on_data_restored_update_entities(&mut self, data: &Data) {
+        let entities = data.entities_sorted();
+        // Keep the current entity if it still exists
+        let new_current_entity = self
+            .current_entity
+            .as_ref()
+            .and_then(|current_entity| {
+                entities
+                    .iter()
+                    .find(|entity| entity.id() == current_entity.id())
+            })
+            .or_else(|| entities.first())
+            .map(|&entity| entity.clone());
+        self.update_current_entity(new_current_entity, data);
+        self.update_entities_treeview(&entities);
+    }
+
     pub fn on_entity_renamed(&mut self, data: &Data, entity: &Entity) {
         self.update_current_entity_without_ui(Some(entity.clone()));
         self.update_entities_treeview(&data.entities_sorted());
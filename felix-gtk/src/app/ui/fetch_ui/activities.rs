@@ -33,6 +33,11 @@ impl Ui {
         fetch_ui_from_builder!(self, "ActivityAddToEntry")
     }
 
+    #[must_use]
+    pub fn activities_filter_entry(&self) -> gtk::SearchEntry {
+        fetch_ui_from_builder!(self, "ActivitiesFilterEntry")
+    }
+
     #[must_use]
     pub fn activities_tree_view(&self) -> gtk::TreeView {
         fetch_ui_from_builder!(self, "ActivitiesTreeView")
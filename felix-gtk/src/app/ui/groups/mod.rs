@@ -9,7 +9,8 @@ use gtk::prelude::*;
 
 impl Ui {
     pub(super) fn on_init_groups(&mut self) {
-        self.update_current_group(None);
+        self.update_current_group_without_ui(None);
+        self.hide_current_group_view();
         self.expand_group_members_tree_view_name_col();
     }
 
@@ -22,18 +23,31 @@ impl Ui {
     }
 
     pub fn on_group_added(&mut self, data: &Data, group: &Group) {
-        self.update_current_group(Some(group.clone()));
+        self.update_current_group(data, Some(group.clone()));
         self.update_groups_treeview(&data.groups_sorted());
     }
 
-    pub fn on_group_selected(&mut self, group: Group) {
-        self.update_current_group(Some(group));
+    pub fn on_group_selected(&mut self, data: &Data, group: Group) {
+        self.update_current_group(data, Some(group));
     }
 
     pub fn on_group_removed(&mut self, data: &Data, position_of_removed_group: usize) {
         let groups = data.groups_sorted();
         let (new_current_group, _) = get_next_element(position_of_removed_group, &groups);
-        self.update_current_group(new_current_group.cloned());
+        self.update_current_group(data, new_current_group.cloned());
+        self.update_groups_treeview(&groups);
+    }
+
+    pub fn on_data_restored_update_groups(&mut self, data: &Data) {
+        let groups = data.groups_sorted();
+        // Keep the current group if it still exists
+        let new_current_group = self
+            .current_group
+            .as_ref()
+            .and_then(|current_group| groups.iter().find(|group| group.id() == current_group.id()))
+            .or_else(|| groups.first())
+            .map(|&group| group.clone());
+        self.update_current_group(data, new_current_group);
         self.update_groups_treeview(&groups);
     }
 
@@ -47,7 +61,7 @@ impl Ui {
             let updated_group = data.group(group.name()).expect(
                 "A group with the current group name should exist if only its members changed",
             );
-            self.update_current_group(Some(updated_group));
+            self.update_current_group(data, Some(updated_group));
         }
     }
 }
@@ -1,5 +1,5 @@
 use crate::app::ui::helpers::tree::tree_path_from_selection_index;
-use felix_data::Group;
+use felix_data::{Data, Group};
 
 use crate::app::ui::Ui;
 use gtk::prelude::*;
@@ -9,17 +9,17 @@ impl Ui {
         self.current_group = group;
     }
 
-    pub(super) fn update_current_group(&mut self, group: Option<Group>) {
+    pub(super) fn update_current_group(&mut self, data: &Data, group: Option<Group>) {
         self.update_current_group_without_ui(group);
 
         if self.current_group.is_some() {
-            self.update_current_group_view();
+            self.update_current_group_view(data);
         } else {
             self.hide_current_group_view();
         };
     }
 
-    fn update_current_group_view(&self) {
+    fn update_current_group_view(&self, data: &Data) {
         fetch_from!(self, group_name_entry);
         let current_group = self
             .current_group
@@ -33,10 +33,10 @@ impl Ui {
             group_name_entry.set_text(&current_group.name()),
             group_name_entry
         );
-        self.update_current_group_members();
+        self.update_current_group_members(data);
     }
 
-    pub(super) fn update_current_group_members(&self) {
+    pub(super) fn update_current_group_members(&self, data: &Data) {
         fetch_from!(self, group_members_tree_view, group_members_list_store);
 
         if let Some(current_group) = self.current_group.as_ref() {
@@ -44,7 +44,14 @@ impl Ui {
                 self,
                 {
                     group_members_list_store.clear();
-                    for entity_name in current_group.entities_sorted() {
+                    // Subgroups are listed first, then the entities added directly
+                    let subgroups = data
+                        .subgroups_of_group(current_group.name())
+                        .expect("Current group should exist");
+                    let entities = data
+                        .entities_of_group(current_group.name())
+                        .expect("Current group should exist");
+                    for entity_name in subgroups.into_iter().chain(entities) {
                         group_members_list_store.insert_with_values(
                             None,
                             &[0, 1],
@@ -63,7 +70,7 @@ impl Ui {
         add_entity_to_group_box.show();
     }
 
-    fn hide_current_group_view(&self) {
+    pub(super) fn hide_current_group_view(&self) {
         fetch_from!(self, group_specific_box, add_entity_to_group_box);
         group_specific_box.hide();
         add_entity_to_group_box.hide();
@@ -28,7 +28,9 @@ use work_hours::WorkHoursBuilder;
 
 pub use state::UiState;
 
-use felix_data::{Activity, ActivityInsertionCosts, AutoinsertionThreadHandle, Entity, Group};
+use felix_data::{
+    Activity, ActivityInsertionCosts, AutoinsertionThreadHandle, Data, Entity, Group,
+};
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -45,6 +47,7 @@ pub struct Ui {
     current_entity: Option<Entity>,
     current_group: Option<Group>,
     current_activity: Option<Activity>,
+    activities_filter: String,
 
     work_hours_builder: WorkHoursBuilder,
     custom_work_hours_builder: WorkHoursBuilder,
@@ -62,6 +65,7 @@ impl Ui {
             current_entity: None,
             current_group: None,
             current_activity: None,
+            activities_filter: String::new(),
 
             work_hours_builder: WorkHoursBuilder::new(),
             custom_work_hours_builder: WorkHoursBuilder::new(),
@@ -87,6 +91,19 @@ impl Ui {
         autoinsertion_running
     }
 
+    /// Refreshes every view. Used when any entity, group, activity or work hour may have
+    /// changed at once.
+    pub(super) fn refresh_every_view(&mut self, data: &Data) {
+        self.on_data_restored_update_entities(data);
+        self.on_data_restored_update_groups(data);
+        self.on_data_restored_update_activities(data);
+        self.on_group_members_changed(data);
+        self.on_entities_or_groups_changed(data);
+        self.on_work_hours_changed(data);
+        self.on_data_restored_update_schedules(data);
+        self.stop_autoinsertion_if_running();
+    }
+
     #[must_use]
     pub(super) fn autoinsertion_handle(&self) -> Rc<RefCell<Option<AutoinsertionThreadHandle>>> {
         self.autoinsertion_handle.clone()
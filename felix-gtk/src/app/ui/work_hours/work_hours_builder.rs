@@ -1,6 +1,6 @@
 use crate::app::ui::helpers::format::format_time_spin_button;
 
-use felix_data::TimeInterval;
+use felix_data::{TimeInterval, ONE_DAY};
 
 use glib::clone;
 use gtk::prelude::*;
@@ -263,7 +263,8 @@ fn update_interval_spinbuttons(builder: &gtk::Builder, interval: TimeInterval) {
     );
     interval_begin_hours.set_value(interval.beginning().hours() as f64);
     interval_begin_minutes.set_value(interval.beginning().minutes() as f64);
-    interval_end_hours.set_value(interval.end().hours() as f64);
+    // Intervals crossing midnight end on the next day
+    interval_end_hours.set_value((interval.end().hours() % ONE_DAY.hours()) as f64);
     interval_end_minutes.set_value(interval.end().minutes() as f64);
 }
 